@@ -36,6 +36,13 @@ fn main() {
         "game_data/PROFILE_SKINS_DEFAULTS.csv",
         "game_data/BALANCE_NAME_MAPPING.csv",
         "game_data/BALANCE_TO_INV_KEY.csv",
+        "game_data/CHALLENGES.csv",
+        "game_data/GAME_STATS.csv",
+        "game_data/ECHO_LOGS.csv",
+        "game_data/VEHICLE_LOADOUT_PARTS.csv",
+        "game_data/REGIONS.csv",
+        "game_data/CREW_QUARTERS_ROOMS.csv",
+        "game_data/PROFILE_TUTORIALS.csv",
     ];
 
     let game_data_inputs_array = vec![
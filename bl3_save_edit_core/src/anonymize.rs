@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use crate::protos::oak_shared::OakMailItem;
+
+/// Hands out a stable pseudonym per identifier so entries which referred to the same player
+/// still do so after anonymizing. Share one between files so a player which shows up in both a
+/// save and the profile gets the same pseudonym in each.
+#[derive(Debug, Default)]
+pub struct Pseudonyms {
+    pseudonyms: HashMap<String, String>,
+}
+
+impl Pseudonyms {
+    pub fn get(&mut self, id: &str) -> String {
+        if id.is_empty() {
+            return String::new();
+        }
+
+        let next = self.pseudonyms.len() + 1;
+
+        self.pseudonyms
+            .entry(id.to_owned())
+            .or_insert_with(|| format!("Player{}", next))
+            .clone()
+    }
+}
+
+/// Mail sent by the game (vendors, Hammerlock etc.) has an INVALID sender id, optionally behind a
+/// platform prefix like `Mcp:`, and keeps its display name.
+pub(crate) fn anonymize_mail_items(mail_items: &mut [OakMailItem], pseudonyms: &mut Pseudonyms) {
+    for mail_item in mail_items {
+        let sender_id = mail_item
+            .from_player_id
+            .rsplit(':')
+            .next()
+            .unwrap_or_default();
+
+        if sender_id.is_empty() || sender_id == "INVALID" {
+            continue;
+        }
+
+        let pseudonym = pseudonyms.get(&mail_item.from_player_id);
+
+        mail_item.sender_display_name = pseudonym.clone();
+        mail_item.from_player_id = pseudonym;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_profile::Bl3Profile;
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+    use crate::protos::oak_profile::{
+        GearSoldByFriendData, Profile_FriendEncountersEntry, RecentlyMetPlayer,
+    };
+    use crate::protos::oak_save::{Character_NicknameMappingsEntry, OakProfileCloudData};
+
+    use super::*;
+
+    const FRIEND_ID: &str = "Steam:76561197960287930";
+    const SHIFT_ID: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f90";
+    const FRIEND_NAME: &str = "SomeFriend";
+
+    fn friend_mail_item() -> OakMailItem {
+        OakMailItem {
+            sender_display_name: FRIEND_NAME.to_owned(),
+            from_player_id: FRIEND_ID.to_owned(),
+            gear_serial_number: "BL3(AwAAAAD)".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn contains(data: &[u8], value: &str) -> bool {
+        data.windows(value.len()).any(|w| w == value.as_bytes())
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let filename = Path::new("./test_files/1prof.sav");
+        let data = fs::read(filename).expect("failed to read test_file");
+        let mut profile = Bl3Profile::from_bytes(filename, &data, HeaderType::PcProfile)
+            .expect("failed to read test profile");
+
+        let filename = Path::new("./test_files/1.sav");
+        let data = fs::read(filename).expect("failed to read test_file");
+        let mut save = Bl3Save::from_bytes(filename, &data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let profile_data = &mut profile.profile_data.profile;
+
+        profile_data
+            .friend_encounters
+            .push(Profile_FriendEncountersEntry {
+                key: FRIEND_ID.to_owned(),
+                ..Default::default()
+            });
+        profile_data.recently_met_players.push(RecentlyMetPlayer {
+            shift_player_id: SHIFT_ID.to_owned(),
+            first_party_player_id: FRIEND_ID.to_owned(),
+            ..Default::default()
+        });
+        profile_data
+            .gear_sold_by_friends
+            .push(GearSoldByFriendData {
+                gear_serial_number: "BL3(AwAAAAD)".to_owned(),
+                friend_net_id: FRIEND_ID.to_owned(),
+                ..Default::default()
+            });
+        profile_data
+            .friend_events
+            .push(format!("{} joined your game", FRIEND_NAME));
+        profile_data.friend_statuses.push(FRIEND_ID.to_owned());
+        profile_data.npc_mail_items.push(friend_mail_item());
+
+        let character = &mut save.character_data.character;
+
+        character
+            .nickname_mappings
+            .push(Character_NicknameMappingsEntry {
+                key: FRIEND_ID.to_owned(),
+                value: FRIEND_NAME.to_owned(),
+                ..Default::default()
+            });
+        character.profile_cloud_data = Some(OakProfileCloudData {
+            npc_mail_items: vec![
+                OakMailItem {
+                    sender_display_name: "Jakobs".to_owned(),
+                    from_player_id: "Mcp:INVALID".to_owned(),
+                    ..Default::default()
+                },
+                friend_mail_item(),
+            ]
+            .into(),
+            ..Default::default()
+        })
+        .into();
+
+        let original_profile = profile.profile_data.profile.clone();
+        let original_character = save.character_data.character.clone();
+
+        // Shared so the friend gets the same pseudonym in the profile and the save
+        let mut pseudonyms = Pseudonyms::default();
+
+        profile.anonymize(&mut pseudonyms);
+        save.anonymize(&mut pseudonyms);
+
+        let (_, profile) = profile.as_bytes().expect("failed to write profile");
+        let (_, save) = save.as_bytes().expect("failed to write save");
+
+        // Everything apart from the identifiers is kept as is
+        let mut expected_profile = original_profile;
+        expected_profile.friend_encounters[0].key = "Player1".to_owned();
+        expected_profile.recently_met_players[0].shift_player_id = "Player2".to_owned();
+        expected_profile.recently_met_players[0].first_party_player_id = "Player1".to_owned();
+        expected_profile.gear_sold_by_friends[0].friend_net_id = "Player1".to_owned();
+        expected_profile.friend_events.clear();
+        expected_profile.friend_statuses.clear();
+
+        let mail_item = expected_profile.npc_mail_items.last_mut().unwrap();
+        mail_item.sender_display_name = "Player1".to_owned();
+        mail_item.from_player_id = "Player1".to_owned();
+
+        assert_eq!(profile.profile_data.profile, expected_profile);
+
+        let character = &save.character_data.character;
+
+        assert_ne!(character.save_game_guid, original_character.save_game_guid);
+
+        let mut expected_character = original_character;
+        expected_character.preferred_character_name = "Siren".to_owned();
+        expected_character.save_game_guid = character.save_game_guid.clone();
+        expected_character.nickname_mappings.clear();
+
+        let mail_item = expected_character
+            .mut_profile_cloud_data()
+            .npc_mail_items
+            .last_mut()
+            .unwrap();
+        mail_item.sender_display_name = "Player1".to_owned();
+        mail_item.from_player_id = "Player1".to_owned();
+
+        assert_eq!(*character, expected_character);
+
+        let profile_data = protobuf::Message::write_to_bytes(&profile.profile_data.profile)
+            .expect("failed to serialize profile");
+        let character_data =
+            protobuf::Message::write_to_bytes(character).expect("failed to serialize character");
+
+        for value in [FRIEND_ID, SHIFT_ID, FRIEND_NAME, "Amara"] {
+            assert!(!contains(&profile_data, value), "{} left in profile", value);
+            assert!(!contains(&character_data, value), "{} left in save", value);
+        }
+    }
+}
@@ -0,0 +1,624 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use crate::file_helper::{write_file_atomic, write_file_atomic_with};
+
+pub const INDEX_FILE_NAME: &str = "backups.ron";
+const LOCK_FILE_NAME: &str = "backups.lock";
+const OBJECTS_DIR_NAME: &str = "objects";
+const OBJECT_EXTENSION: &str = "sz";
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    pub id: u64,
+    pub hash: String,
+    pub original_path: PathBuf,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub editor_version: String,
+}
+
+impl BackupEntry {
+    pub fn created(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.timestamp)
+    }
+
+    pub fn original_file_name(&self) -> String {
+        self.original_path
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// A backup is kept while it is one of the newest `keep_per_file` backups of its file or is
+/// younger than `max_age_days`, a value of 0 disables that rule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BackupRetention {
+    pub keep_per_file: usize,
+    pub max_age_days: u64,
+}
+
+impl BackupRetention {
+    pub fn is_enabled(&self) -> bool {
+        self.keep_per_file > 0 || self.max_age_days > 0
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BackupIndex {
+    entries: Vec<BackupEntry>,
+}
+
+/// Backups stored once per unique file content, snap compressed and named by their sha256 hash.
+///
+/// `backups.ron` records every backup taken, several entries can point to the same content. More
+/// than one editor can use the same folder so it is re-read while locked before every change.
+#[derive(Debug)]
+pub struct BackupStore {
+    dir: PathBuf,
+    index: BackupIndex,
+}
+
+impl BackupStore {
+    pub fn open(dir: &Path) -> Result<Self> {
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            index: read_index(dir)?,
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn entries(&self) -> &[BackupEntry] {
+        &self.index.entries
+    }
+
+    pub fn entry(&self, id: u64) -> Option<&BackupEntry> {
+        self.index.entries.iter().find(|e| e.id == id)
+    }
+
+    pub fn add(
+        &mut self,
+        original_path: &Path,
+        data: &[u8],
+        editor_version: &str,
+    ) -> Result<BackupEntry> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.add_with_timestamp(original_path, data, editor_version, timestamp)
+    }
+
+    pub fn add_with_timestamp(
+        &mut self,
+        original_path: &Path,
+        data: &[u8],
+        editor_version: &str,
+        timestamp: u64,
+    ) -> Result<BackupEntry> {
+        let _lock = self.lock()?;
+        self.index = read_index(&self.dir)?;
+
+        let hash = format!("{:x}", Sha256::digest(data));
+
+        let object_path = self.object_path(&hash);
+
+        if !object_path.exists() {
+            let mut compressed = snap::write::FrameEncoder::new(Vec::new());
+            compressed.write_all(data)?;
+
+            let compressed = compressed
+                .into_inner()
+                .context("failed to compress backup")?;
+
+            std::fs::create_dir_all(self.dir.join(OBJECTS_DIR_NAME))?;
+
+            write_file_atomic_with(&object_path, &compressed, |written| {
+                if decompress(written)? != data {
+                    bail!("backup written could not be read back");
+                }
+
+                Ok(())
+            })?;
+        }
+
+        let entry = BackupEntry {
+            id: self
+                .index
+                .entries
+                .iter()
+                .map(|e| e.id + 1)
+                .max()
+                .unwrap_or(1),
+            hash,
+            original_path: original_path.to_path_buf(),
+            timestamp,
+            editor_version: editor_version.to_owned(),
+        };
+
+        info!(
+            "Stored backup {} of: {}",
+            entry.id,
+            entry.original_path.display()
+        );
+
+        self.index.entries.push(entry.clone());
+        self.save_index()?;
+
+        Ok(entry)
+    }
+
+    pub fn read(&self, entry: &BackupEntry) -> Result<Vec<u8>> {
+        let object_path = self.object_path(&entry.hash);
+
+        let compressed = std::fs::read(&object_path)
+            .with_context(|| format!("failed to read backup: {}", object_path.display()))?;
+
+        let data = decompress(&compressed)
+            .with_context(|| format!("failed to decompress backup: {}", object_path.display()))?;
+
+        if format!("{:x}", Sha256::digest(&data)) != entry.hash {
+            bail!("backup is corrupted: {}", object_path.display());
+        }
+
+        Ok(data)
+    }
+
+    /// Writes backup `id` to `output_file`, or its original path, after backing up the file it
+    /// replaces.
+    pub fn restore(
+        &mut self,
+        id: u64,
+        output_file: Option<&Path>,
+        editor_version: &str,
+    ) -> Result<PathBuf> {
+        let entry = self
+            .entry(id)
+            .cloned()
+            .with_context(|| format!("no backup with id: {}", id))?;
+
+        let output_file = output_file
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| entry.original_path.clone());
+
+        let data = self.read(&entry)?;
+
+        if let Ok(existing) = std::fs::read(&output_file) {
+            self.add(&output_file, &existing, editor_version)?;
+        }
+
+        info!(
+            "Restoring backup {} to: {}",
+            entry.id,
+            output_file.display()
+        );
+
+        write_file_atomic(&output_file, &data)?;
+
+        Ok(output_file)
+    }
+
+    /// The ids of the backups `retention` no longer keeps as of `now`, nothing when it is disabled.
+    pub fn select_prunable(&self, retention: BackupRetention, now: SystemTime) -> Vec<u64> {
+        if !retention.is_enabled() {
+            return vec![];
+        }
+
+        let mut entries_by_file = BTreeMap::<_, Vec<_>>::new();
+
+        for entry in &self.index.entries {
+            entries_by_file
+                .entry(&entry.original_path)
+                .or_default()
+                .push(entry);
+        }
+
+        let max_age = Duration::from_secs(retention.max_age_days * 24 * 60 * 60);
+
+        let mut prunable = vec![];
+
+        for (_, mut file_entries) in entries_by_file {
+            file_entries.sort_by_key(|e| Reverse(e.timestamp));
+
+            for (i, entry) in file_entries.into_iter().enumerate() {
+                let keep_for_count = retention.keep_per_file > 0 && i < retention.keep_per_file;
+
+                let keep_for_age = retention.max_age_days > 0
+                    && now
+                        .duration_since(entry.created())
+                        .map(|age| age < max_age)
+                        .unwrap_or(true);
+
+                if !keep_for_count && !keep_for_age {
+                    prunable.push(entry.id);
+                }
+            }
+        }
+
+        prunable
+    }
+
+    /// Removes the given backups from the index and deletes any content no longer referenced.
+    pub fn remove(&mut self, ids: &[u64]) -> Result<usize> {
+        let _lock = self.lock()?;
+        self.index = read_index(&self.dir)?;
+
+        let before = self.index.entries.len();
+
+        self.index.entries.retain(|e| !ids.contains(&e.id));
+
+        let removed = before - self.index.entries.len();
+
+        self.save_index()?;
+
+        let referenced = self
+            .index
+            .entries
+            .iter()
+            .map(|e| e.hash.as_str())
+            .collect::<HashSet<_>>();
+
+        let objects_dir = self.dir.join(OBJECTS_DIR_NAME);
+
+        if objects_dir.exists() {
+            for object in std::fs::read_dir(&objects_dir)? {
+                let object_path = object?.path();
+
+                let is_referenced = object_path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .map(|hash| referenced.contains(hash))
+                    .unwrap_or(true);
+
+                if !is_referenced {
+                    std::fs::remove_file(&object_path).with_context(|| {
+                        format!("failed to remove backup: {}", object_path.display())
+                    })?;
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.dir
+            .join(OBJECTS_DIR_NAME)
+            .join(format!("{}.{}", hash, OBJECT_EXTENSION))
+    }
+
+    /// Held while the index is being changed, released when dropped.
+    fn lock(&self) -> Result<File> {
+        std::fs::create_dir_all(&self.dir)?;
+
+        let lock_file = self.dir.join(LOCK_FILE_NAME);
+
+        let lock = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_file)
+            .with_context(|| format!("failed to open lock file: {}", lock_file.display()))?;
+
+        lock.lock_exclusive()
+            .with_context(|| format!("failed to lock backups: {}", self.dir.display()))?;
+
+        Ok(lock)
+    }
+
+    fn save_index(&self) -> Result<()> {
+        let index_data = ron::ser::to_string_pretty(&self.index, Default::default())
+            .context("failed to serialize backup index")?;
+
+        write_file_atomic_with(
+            &self.dir.join(INDEX_FILE_NAME),
+            index_data.as_bytes(),
+            |written| {
+                ron::de::from_bytes::<BackupIndex>(written)
+                    .context("backup index written could not be read back")?;
+
+                Ok(())
+            },
+        )
+    }
+}
+
+fn read_index(dir: &Path) -> Result<BackupIndex> {
+    let index_file = dir.join(INDEX_FILE_NAME);
+
+    if !index_file.exists() {
+        return Ok(BackupIndex::default());
+    }
+
+    let index_data = std::fs::read_to_string(&index_file)
+        .with_context(|| format!("failed to read backup index: {}", index_file.display()))?;
+
+    ron::de::from_str(&index_data)
+        .with_context(|| format!("failed to parse backup index: {}", index_file.display()))
+}
+
+fn decompress(compressed: &[u8]) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+
+    snap::read::FrameDecoder::new(compressed).read_to_end(&mut data)?;
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn temp_store_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("bl3_save_edit_{}", Uuid::new_v4()))
+    }
+
+    fn object_count(dir: &Path) -> usize {
+        std::fs::read_dir(dir.join(OBJECTS_DIR_NAME))
+            .map(|objects| objects.count())
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_add_deduplicates_content() {
+        let dir = temp_store_dir();
+
+        let save_data = std::fs::read("./test_files/1.sav").expect("failed to read test_file");
+        let profile_data =
+            std::fs::read("./test_files/profile.sav").expect("failed to read test_file");
+
+        let mut store = BackupStore::open(&dir).expect("failed to open store");
+
+        let first = store
+            .add(Path::new("1.sav"), &save_data, "1.0.0")
+            .expect("failed to add backup");
+        let second = store
+            .add(Path::new("1.sav"), &save_data, "1.0.1")
+            .expect("failed to add backup");
+
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.hash, second.hash);
+        assert_eq!(object_count(&dir), 1);
+
+        let third = store
+            .add(Path::new("profile.sav"), &profile_data, "1.0.1")
+            .expect("failed to add backup");
+
+        assert_ne!(first.hash, third.hash);
+        assert_eq!(object_count(&dir), 2);
+
+        // The index is persisted and every entry reads back
+        let store = BackupStore::open(&dir).expect("failed to open store");
+
+        assert_eq!(store.entries(), &[first.clone(), second, third.clone()]);
+        assert_eq!(
+            store.read(&first).expect("failed to read backup"),
+            save_data
+        );
+        assert_eq!(
+            store.read(&third).expect("failed to read backup"),
+            profile_data
+        );
+
+        std::fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_read_rejects_corrupted_backup() {
+        let dir = temp_store_dir();
+
+        let save_data = std::fs::read("./test_files/1.sav").expect("failed to read test_file");
+
+        let mut store = BackupStore::open(&dir).expect("failed to open store");
+
+        let entry = store
+            .add(Path::new("1.sav"), &save_data, "1.0.0")
+            .expect("failed to add backup");
+
+        let object_path = store.object_path(&entry.hash);
+
+        // Valid compressed data which doesn't match the hash
+        let mut compressed = snap::write::FrameEncoder::new(Vec::new());
+        compressed
+            .write_all(&save_data[..save_data.len() - 1])
+            .expect("failed to compress data");
+
+        std::fs::write(
+            &object_path,
+            compressed.into_inner().expect("failed to compress data"),
+        )
+        .expect("failed to write object");
+
+        assert!(store.read(&entry).is_err());
+
+        // Data which can't be decompressed
+        std::fs::write(&object_path, b"not a backup").expect("failed to write object");
+
+        assert!(store.read(&entry).is_err());
+
+        std::fs::remove_file(&object_path).expect("failed to remove object");
+
+        assert!(store.read(&entry).is_err());
+
+        std::fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_remove_keeps_referenced_objects() {
+        let dir = temp_store_dir();
+
+        let save_data = std::fs::read("./test_files/1.sav").expect("failed to read test_file");
+        let profile_data =
+            std::fs::read("./test_files/profile.sav").expect("failed to read test_file");
+
+        let mut store = BackupStore::open(&dir).expect("failed to open store");
+
+        let first = store
+            .add(Path::new("1.sav"), &save_data, "1.0.0")
+            .expect("failed to add backup");
+        let second = store
+            .add(Path::new("1.sav"), &save_data, "1.0.0")
+            .expect("failed to add backup");
+        let third = store
+            .add(Path::new("profile.sav"), &profile_data, "1.0.0")
+            .expect("failed to add backup");
+
+        // The save content is still referenced by the second entry
+        assert_eq!(store.remove(&[first.id]).expect("failed to remove"), 1);
+        assert_eq!(store.entries(), &[second.clone(), third.clone()]);
+        assert_eq!(object_count(&dir), 2);
+        assert_eq!(
+            store.read(&second).expect("failed to read backup"),
+            save_data
+        );
+
+        assert_eq!(store.remove(&[second.id]).expect("failed to remove"), 1);
+        assert_eq!(object_count(&dir), 1);
+        assert!(store.read(&second).is_err());
+        assert_eq!(
+            store.read(&third).expect("failed to read backup"),
+            profile_data
+        );
+
+        // Unknown ids are ignored
+        assert_eq!(store.remove(&[first.id]).expect("failed to remove"), 0);
+
+        assert_eq!(store.remove(&[third.id]).expect("failed to remove"), 1);
+        assert!(store.entries().is_empty());
+        assert_eq!(object_count(&dir), 0);
+
+        std::fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_select_prunable() {
+        const DAY: u64 = 24 * 60 * 60;
+
+        let dir = temp_store_dir();
+
+        let save_data = std::fs::read("./test_files/1.sav").expect("failed to read test_file");
+        let profile_data =
+            std::fs::read("./test_files/profile.sav").expect("failed to read test_file");
+
+        let mut store = BackupStore::open(&dir).expect("failed to open store");
+
+        let now_secs = 100 * DAY;
+        let now = UNIX_EPOCH + Duration::from_secs(now_secs);
+
+        // Three backups of the save taken 1, 5 and 10 days ago and one of the profile 20 days ago
+        let save_ids = [10, 5, 1]
+            .into_iter()
+            .map(|days| {
+                store
+                    .add_with_timestamp(
+                        Path::new("1.sav"),
+                        &save_data,
+                        "1.0.0",
+                        now_secs - days * DAY,
+                    )
+                    .expect("failed to add backup")
+                    .id
+            })
+            .collect::<Vec<_>>();
+
+        let profile_id = store
+            .add_with_timestamp(
+                Path::new("profile.sav"),
+                &profile_data,
+                "1.0.0",
+                now_secs - 20 * DAY,
+            )
+            .expect("failed to add backup")
+            .id;
+
+        let select = |keep_per_file, max_age_days| {
+            let mut prunable = store.select_prunable(
+                BackupRetention {
+                    keep_per_file,
+                    max_age_days,
+                },
+                now,
+            );
+
+            prunable.sort_unstable();
+            prunable
+        };
+
+        // Disabled
+        assert!(select(0, 0).is_empty());
+
+        // Count only, the newest backups of each file are kept
+        assert_eq!(select(1, 0), vec![save_ids[0], save_ids[1]]);
+        assert!(select(3, 0).is_empty());
+
+        // Age only
+        assert_eq!(select(0, 7), vec![save_ids[0], profile_id]);
+        assert!(select(0, 30).is_empty());
+
+        // Combined, a backup is kept if either rule keeps it
+        assert_eq!(select(1, 7), vec![save_ids[0]]);
+        assert_eq!(select(2, 2), vec![save_ids[0]]);
+
+        std::fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_shared_store() {
+        let dir = temp_store_dir();
+
+        let save_data = std::fs::read("./test_files/1.sav").expect("failed to read test_file");
+        let profile_data =
+            std::fs::read("./test_files/profile.sav").expect("failed to read test_file");
+
+        // Two editors with the store open keep each other's backups
+        let mut first_store = BackupStore::open(&dir).expect("failed to open store");
+        let mut second_store = BackupStore::open(&dir).expect("failed to open store");
+
+        let first = first_store
+            .add(Path::new("1.sav"), &save_data, "1.0.0")
+            .expect("failed to add backup");
+        let second = second_store
+            .add(Path::new("profile.sav"), &profile_data, "1.0.0")
+            .expect("failed to add backup");
+
+        assert_ne!(first.id, second.id);
+
+        let store = BackupStore::open(&dir).expect("failed to open store");
+
+        assert_eq!(store.entries(), &[first.clone(), second.clone()]);
+
+        // Removing through a store which hasn't seen the other backup doesn't lose it
+        assert_eq!(
+            first_store.remove(&[first.id]).expect("failed to remove"),
+            1
+        );
+        assert_eq!(first_store.entries(), std::slice::from_ref(&second));
+
+        let store = BackupStore::open(&dir).expect("failed to open store");
+
+        assert_eq!(store.entries(), std::slice::from_ref(&second));
+        assert_eq!(
+            store.read(&second).expect("failed to read backup"),
+            profile_data
+        );
+
+        std::fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+    }
+}
@@ -0,0 +1,219 @@
+use strum::Display;
+
+use crate::game_data::PROFILE_TUTORIALS;
+use crate::protos::oak_profile::Profile;
+
+/// Everything the profile tracks to decide what a new player is shown, along with the seasonal
+/// event toggles.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ProfileFirstRunData {
+    pub has_seen_first_boot: bool,
+    pub seen_tutorials: Vec<String>,
+    pub tutorials_disabled: bool,
+    pub seen_news_items: Vec<String>,
+    pub news_hashes: Vec<u32>,
+    pub rare_chest_event_enabled: bool,
+    pub badass_event_enabled: bool,
+    pub pinata_event_enabled: bool,
+    pub moxxis_drink_event_enabled: bool,
+    pub disable_event_content: bool,
+}
+
+impl ProfileFirstRunData {
+    pub fn from_profile(profile: &Profile) -> Self {
+        let tutorial_info = profile.get_tutorial_info();
+
+        Self {
+            has_seen_first_boot: profile.has_seen_first_boot,
+            seen_tutorials: tutorial_info.seen_tutorials.to_vec(),
+            tutorials_disabled: tutorial_info.tutorials_disabled,
+            seen_news_items: profile.seen_news_items.to_vec(),
+            news_hashes: profile.news_hashes.clone(),
+            rare_chest_event_enabled: profile.rare_chest_event_enabled,
+            badass_event_enabled: profile.badass_event_enabled,
+            pinata_event_enabled: profile.pinata_event_enabled,
+            moxxis_drink_event_enabled: profile.moxxis_drink_event_enabled,
+            disable_event_content: profile.disable_event_content,
+        }
+    }
+
+    pub fn is_tutorial_seen(&self, tutorial: &str) -> bool {
+        self.seen_tutorials
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(tutorial))
+    }
+
+    pub fn set_tutorial_seen(&mut self, tutorial: &str, seen: bool) {
+        if seen {
+            if !self.is_tutorial_seen(tutorial) {
+                self.seen_tutorials.push(tutorial.to_owned());
+            }
+        } else {
+            self.seen_tutorials
+                .retain(|t| !t.eq_ignore_ascii_case(tutorial));
+        }
+    }
+
+    /// Tutorials the game has recorded as seen which aren't in the game data.
+    pub fn unknown_seen_tutorials(&self) -> Vec<String> {
+        self.seen_tutorials
+            .iter()
+            .filter(|seen| {
+                !PROFILE_TUTORIALS
+                    .iter()
+                    .any(|t| t.ident.eq_ignore_ascii_case(seen))
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn mark_all_tutorials_seen(&mut self) {
+        for tutorial in PROFILE_TUTORIALS {
+            self.set_tutorial_seen(tutorial.ident, true);
+        }
+    }
+
+    /// Puts the profile back to how the game leaves it on a fresh account so the first boot,
+    /// tutorials and news are shown again.
+    pub fn reset_onboarding(&mut self) {
+        self.has_seen_first_boot = false;
+        self.seen_tutorials.clear();
+        self.seen_news_items.clear();
+        self.news_hashes.clear();
+    }
+
+    pub fn event_enabled(&self, event: ProfileEvent) -> bool {
+        match event {
+            ProfileEvent::RareChest => self.rare_chest_event_enabled,
+            ProfileEvent::Badass => self.badass_event_enabled,
+            ProfileEvent::Pinata => self.pinata_event_enabled,
+            ProfileEvent::MoxxisDrink => self.moxxis_drink_event_enabled,
+        }
+    }
+
+    pub fn set_event_enabled(&mut self, event: ProfileEvent, enabled: bool) {
+        match event {
+            ProfileEvent::RareChest => self.rare_chest_event_enabled = enabled,
+            ProfileEvent::Badass => self.badass_event_enabled = enabled,
+            ProfileEvent::Pinata => self.pinata_event_enabled = enabled,
+            ProfileEvent::MoxxisDrink => self.moxxis_drink_event_enabled = enabled,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Display, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ProfileEvent {
+    #[strum(to_string = "Rare Chest Event")]
+    RareChest,
+    #[strum(to_string = "Badass Event")]
+    Badass,
+    #[strum(to_string = "Pinata Event")]
+    Pinata,
+    #[strum(to_string = "Moxxi's Drink Event")]
+    MoxxisDrink,
+}
+
+impl ProfileEvent {
+    pub const ALL: [ProfileEvent; 4] = [
+        ProfileEvent::RareChest,
+        ProfileEvent::Badass,
+        ProfileEvent::Pinata,
+        ProfileEvent::MoxxisDrink,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_profile;
+
+    use super::*;
+
+    #[test]
+    fn test_first_run_data() {
+        let profile = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let first_run_data = profile.profile_data.first_run_data();
+
+        assert!(first_run_data.has_seen_first_boot);
+        assert!(!first_run_data.tutorials_disabled);
+        assert_eq!(first_run_data.seen_tutorials.len(), 10);
+        assert!(first_run_data.is_tutorial_seen("FastTravel"));
+        assert!(first_run_data.is_tutorial_seen("fasttravel"));
+        assert!(first_run_data.unknown_seen_tutorials().is_empty());
+
+        for event in ProfileEvent::ALL {
+            assert!(!first_run_data.event_enabled(event));
+        }
+
+        let profile = read_test_profile("2profps4.sav", HeaderType::Ps4Profile);
+
+        let first_run_data = profile.profile_data.first_run_data();
+
+        assert!(first_run_data.has_seen_first_boot);
+        assert!(first_run_data.seen_tutorials.is_empty());
+    }
+
+    #[test]
+    fn test_tutorials() {
+        let mut first_run_data = ProfileFirstRunData::default();
+
+        first_run_data.set_tutorial_seen("FastTravel", true);
+        first_run_data.set_tutorial_seen("FASTTRAVEL", true);
+        first_run_data.set_tutorial_seen("SomeNewTutorial", true);
+
+        assert_eq!(
+            first_run_data.seen_tutorials,
+            vec!["FastTravel", "SomeNewTutorial"]
+        );
+        assert_eq!(
+            first_run_data.unknown_seen_tutorials(),
+            vec!["SomeNewTutorial"]
+        );
+
+        first_run_data.set_tutorial_seen("fasttravel", false);
+
+        assert!(!first_run_data.is_tutorial_seen("FastTravel"));
+
+        first_run_data.mark_all_tutorials_seen();
+
+        assert_eq!(
+            first_run_data.seen_tutorials.len(),
+            PROFILE_TUTORIALS.len() + 1
+        );
+        assert!(PROFILE_TUTORIALS
+            .iter()
+            .all(|t| first_run_data.is_tutorial_seen(t.ident)));
+
+        first_run_data.reset_onboarding();
+
+        assert!(!first_run_data.has_seen_first_boot);
+        assert!(first_run_data.seen_tutorials.is_empty());
+    }
+
+    #[test]
+    fn test_set_first_run_data() {
+        let mut profile = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let bank_items = profile.profile_data.bank_items().to_vec();
+
+        let mut first_run_data = profile.profile_data.first_run_data();
+        first_run_data.reset_onboarding();
+        first_run_data.tutorials_disabled = true;
+        first_run_data.seen_news_items = vec!["News_Item_1".to_owned()];
+        first_run_data.news_hashes = vec![1234];
+        first_run_data.set_event_enabled(ProfileEvent::Pinata, true);
+        first_run_data.set_event_enabled(ProfileEvent::MoxxisDrink, true);
+
+        profile.profile_data.set_first_run_data(&first_run_data);
+
+        let (_, profile) = profile.as_bytes().expect("failed to write profile");
+
+        let saved = profile.profile_data.first_run_data();
+
+        assert_eq!(saved, first_run_data);
+        assert!(saved.event_enabled(ProfileEvent::Pinata));
+        assert!(!saved.event_enabled(ProfileEvent::RareChest));
+        assert_eq!(*profile.profile_data.bank_items(), bank_items);
+    }
+}
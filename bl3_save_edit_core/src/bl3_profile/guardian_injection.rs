@@ -0,0 +1,205 @@
+use anyhow::Result;
+
+use crate::bl3_profile::guardian_reward::GuardianReward;
+use crate::bl3_profile::profile_data::ProfileData;
+use crate::bl3_save::player_class::PlayerClass;
+use crate::bl3_save::Bl3Save;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GuardianRewardChange {
+    pub reward: GuardianReward,
+    pub current: i32,
+    pub new: i32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GuardianInjectionPlan {
+    pub file_name: String,
+    pub character_name: String,
+    pub player_class: PlayerClass,
+    pub current_rank: i32,
+    pub new_rank: i32,
+    pub current_tokens: i32,
+    pub new_tokens: i32,
+    pub reward_changes: Vec<GuardianRewardChange>,
+}
+
+impl GuardianInjectionPlan {
+    pub fn new(profile_data: &ProfileData, save: &Bl3Save) -> Self {
+        let character_data = &save.character_data;
+
+        let current_rewards = character_data.guardian_rewards();
+
+        let reward_changes = profile_data
+            .guardian_rewards()
+            .iter()
+            .filter_map(|new_reward| {
+                let current = current_rewards
+                    .iter()
+                    .find(|r| r.reward == new_reward.reward)
+                    .map(|r| r.current)
+                    .unwrap_or(0);
+
+                if current != new_reward.current {
+                    Some(GuardianRewardChange {
+                        reward: new_reward.reward.clone(),
+                        current,
+                        new: new_reward.current,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            file_name: save.file_name.clone(),
+            character_name: character_data.character.preferred_character_name.clone(),
+            player_class: character_data.player_class(),
+            current_rank: character_data.guardian_rank(),
+            new_rank: profile_data.guardian_rank(),
+            current_tokens: character_data.guardian_tokens(),
+            new_tokens: profile_data.guardian_tokens(),
+            reward_changes,
+        }
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.current_rank != self.new_rank
+            || self.current_tokens != self.new_tokens
+            || !self.reward_changes.is_empty()
+    }
+
+    pub fn apply(&self, save: &mut Bl3Save) -> Result<()> {
+        save.character_data
+            .set_guardian_rank(self.new_rank, Some(self.new_tokens));
+
+        for r in &self.reward_changes {
+            save.character_data.set_guardian_reward(&r.reward, r.new)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn guardian_injection_plans<'a>(
+    profile_data: &ProfileData,
+    saves: impl IntoIterator<Item = &'a Bl3Save>,
+) -> Vec<GuardianInjectionPlan> {
+    saves
+        .into_iter()
+        .map(|s| GuardianInjectionPlan::new(profile_data, s))
+        .filter(|p| p.has_changes())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bl3_profile::Bl3Profile;
+    use crate::parser::HeaderType;
+    use crate::test_helper::{read_test_profile, read_test_save};
+
+    use super::*;
+
+    fn read_test_files() -> (Bl3Profile, Bl3Save, Bl3Save) {
+        (
+            read_test_profile("1prof.sav", HeaderType::PcProfile),
+            read_test_save("1.sav", HeaderType::PcSave),
+            read_test_save("19.sav", HeaderType::PcSave),
+        )
+    }
+
+    #[test]
+    fn test_guardian_injection_plan() {
+        let (mut bl3_profile, bl3_save, _) = read_test_files();
+
+        let current_gun_damage = bl3_save
+            .character_data
+            .guardian_rewards()
+            .into_iter()
+            .find(|r| r.reward == GuardianReward::GunDamage)
+            .map(|r| r.current)
+            .unwrap();
+
+        bl3_profile.profile_data.set_guardian_rank(300, Some(42));
+
+        bl3_profile
+            .profile_data
+            .set_guardian_reward(&GuardianReward::GunDamage, current_gun_damage + 5)
+            .expect("failed to set guardian reward");
+
+        let plan = GuardianInjectionPlan::new(&bl3_profile.profile_data, &bl3_save);
+
+        assert!(plan.has_changes());
+        assert_eq!(plan.file_name, bl3_save.file_name);
+        assert_eq!(plan.current_rank, 200);
+        assert_eq!(plan.new_rank, 300);
+        assert_eq!(
+            plan.current_tokens,
+            bl3_save.character_data.guardian_tokens()
+        );
+        assert_eq!(plan.new_tokens, 42);
+        assert!(plan.reward_changes.contains(&GuardianRewardChange {
+            reward: GuardianReward::GunDamage,
+            current: current_gun_damage,
+            new: current_gun_damage + 5,
+        }));
+
+        for change in &plan.reward_changes {
+            assert_ne!(change.current, change.new);
+        }
+    }
+
+    #[test]
+    fn test_guardian_injection_plans_apply_selected() {
+        let (mut bl3_profile, mut first_save, second_save) = read_test_files();
+
+        bl3_profile.profile_data.set_guardian_rank(300, Some(42));
+
+        bl3_profile
+            .profile_data
+            .set_guardian_reward(&GuardianReward::GunDamage, 7)
+            .expect("failed to set guardian reward");
+
+        let plans =
+            guardian_injection_plans(&bl3_profile.profile_data, [&first_save, &second_save]);
+
+        assert_eq!(plans.len(), 2);
+
+        let untouched_save = second_save.clone();
+
+        let first_plan = plans
+            .iter()
+            .find(|p| p.file_name == first_save.file_name)
+            .unwrap();
+
+        first_plan
+            .apply(&mut first_save)
+            .expect("failed to apply guardian injection plan");
+
+        assert_eq!(first_save.character_data.guardian_rank(), 300);
+        assert_eq!(first_save.character_data.guardian_tokens(), 42);
+
+        let plans =
+            guardian_injection_plans(&bl3_profile.profile_data, [&first_save, &second_save]);
+
+        assert_eq!(plans.len(), 1);
+        assert_eq!(plans[0].file_name, second_save.file_name);
+        assert_eq!(second_save, untouched_save);
+    }
+
+    #[test]
+    fn test_guardian_injection_plan_no_changes() {
+        let (bl3_profile, mut bl3_save, _) = read_test_files();
+
+        GuardianInjectionPlan::new(&bl3_profile.profile_data, &bl3_save)
+            .apply(&mut bl3_save)
+            .expect("failed to apply guardian injection plan");
+
+        let plan = GuardianInjectionPlan::new(&bl3_profile.profile_data, &bl3_save);
+
+        assert!(!plan.has_changes());
+        assert!(plan.reward_changes.is_empty());
+        assert!(guardian_injection_plans(&bl3_profile.profile_data, [&bl3_save]).is_empty());
+    }
+}
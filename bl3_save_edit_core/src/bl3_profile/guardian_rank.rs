@@ -0,0 +1,178 @@
+pub const GUARDIAN_RANK_EXPERIENCE_SCALE: f64 = 9.5;
+pub const GUARDIAN_RANK_EXPERIENCE_OFFSET: f64 = 50.0;
+pub const GUARDIAN_RANK_EXPERIENCE_EXPONENT: f64 = 2.8;
+
+// Same shape as the player level curve, fitted against the rank/experience pairs found in real saves.
+pub fn guardian_rank_to_experience(rank: i32) -> i64 {
+    if rank <= 0 {
+        return 0;
+    }
+
+    let offset = GUARDIAN_RANK_EXPERIENCE_OFFSET;
+    let exponent = GUARDIAN_RANK_EXPERIENCE_EXPONENT;
+
+    (GUARDIAN_RANK_EXPERIENCE_SCALE
+        * ((rank as f64 + offset).powf(exponent) - offset.powf(exponent)))
+    .ceil() as i64
+}
+
+pub fn guardian_experience_to_rank(experience: i64) -> i32 {
+    let mut low = 0;
+    let mut high = i32::MAX;
+
+    while low < high {
+        let mid = low + (high - low) / 2 + 1;
+
+        if guardian_rank_to_experience(mid) <= experience {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    low
+}
+
+pub fn guardian_tokens_for_rank(rank: i32, spent_tokens: i32) -> i32 {
+    rank.saturating_sub(spent_tokens).max(0)
+}
+
+pub fn guardian_experience_for_rank(rank: i32, current_experience: i64) -> i64 {
+    if guardian_experience_to_rank(current_experience) == rank {
+        current_experience
+    } else {
+        guardian_rank_to_experience(rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_profile::Bl3Profile;
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_guardian_rank_experience_round_trip() {
+        assert_eq!(guardian_rank_to_experience(-1), 0);
+        assert_eq!(guardian_rank_to_experience(0), 0);
+        assert_eq!(guardian_experience_to_rank(-46771), 0);
+
+        for rank in [1, 2, 200, 226, 255, 69420, 1_000_000] {
+            let experience = guardian_rank_to_experience(rank);
+
+            assert!(experience > guardian_rank_to_experience(rank - 1));
+            assert_eq!(guardian_experience_to_rank(experience), rank);
+            assert_eq!(guardian_experience_to_rank(experience - 1), rank - 1);
+        }
+    }
+
+    #[test]
+    fn test_guardian_rank_experience_matches_saves() {
+        for (file_name, rank) in [
+            ("19.sav", 226),
+            ("1.sav", 200),
+            ("5.sav", 200),
+            ("1012.sav", 255),
+        ] {
+            let filename = Path::new("./test_files").join(file_name);
+
+            let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+            let bl3_save = Bl3Save::from_bytes(&filename, &save_file_data, HeaderType::PcSave)
+                .expect("failed to read test save");
+
+            let guardian_rank_character_data = bl3_save
+                .character_data
+                .character
+                .get_guardian_rank_character_data();
+
+            assert_eq!(guardian_rank_character_data.guardian_rank, rank);
+            assert_eq!(
+                guardian_experience_to_rank(guardian_rank_character_data.new_guardian_experience),
+                rank
+            );
+        }
+    }
+
+    #[test]
+    fn test_guardian_rank_experience_matches_profiles() {
+        for (file_name, rank) in [("1prof.sav", 226), ("profile.sav", 200)] {
+            let filename = Path::new("./test_files").join(file_name);
+
+            let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+            let bl3_profile =
+                Bl3Profile::from_bytes(&filename, &profile_file_data, HeaderType::PcProfile)
+                    .expect("failed to read test profile");
+
+            assert_eq!(bl3_profile.profile_data.guardian_rank(), rank);
+            assert_eq!(
+                guardian_experience_to_rank(bl3_profile.profile_data.guardian_experience()),
+                rank
+            );
+            assert_eq!(
+                guardian_experience_for_rank(rank, bl3_profile.profile_data.guardian_experience()),
+                bl3_profile.profile_data.guardian_experience()
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_guardian_rank_keeps_experience_and_tokens() {
+        let filename = Path::new("./test_files").join("19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(&filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let experience = character_data.guardian_experience();
+        let tokens = character_data.guardian_tokens();
+
+        character_data.set_guardian_rank(300, None);
+
+        assert_eq!(character_data.guardian_rank(), 300);
+        assert_eq!(character_data.guardian_experience(), experience);
+        assert_eq!(character_data.guardian_tokens(), tokens);
+
+        character_data.set_guardian_experience_for_rank(300);
+
+        assert_eq!(
+            character_data.guardian_experience(),
+            guardian_rank_to_experience(300)
+        );
+
+        let filename = Path::new("./test_files").join("1prof.sav");
+
+        let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(&filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        let experience = profile_data.guardian_experience();
+        let tokens = profile_data.guardian_tokens();
+
+        profile_data.set_guardian_rank(300, None);
+
+        assert_eq!(profile_data.guardian_rank(), 300);
+        assert_eq!(profile_data.guardian_experience(), experience);
+        assert_eq!(profile_data.guardian_tokens(), tokens);
+
+        profile_data.set_guardian_experience_for_rank(300);
+
+        assert_eq!(
+            profile_data.guardian_experience(),
+            guardian_rank_to_experience(300)
+        );
+    }
+}
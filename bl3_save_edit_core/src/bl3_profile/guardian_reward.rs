@@ -4,7 +4,8 @@ use strum::{Display, EnumIter, EnumMessage, EnumString};
 pub struct GuardianRewardData {
     pub reward: GuardianReward,
     pub current: i32,
-    pub max: i32,
+    /// Guardian rewards have no token cap in game, so this is always `None` for now.
+    pub max: Option<i32>,
 }
 
 #[derive(
@@ -0,0 +1,647 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::parser::HeaderType;
+use crate::protos::oak_profile::{
+    PlayerInputBinding_Axis, PlayerInputBinding_Axis_Key, PlayerInputBinding_Button,
+    PlayerInputBinding_Category, PlayerInputBindings,
+};
+use crate::protos::oak_shared::Vec3;
+
+const GAMEPAD_KEY_PREFIX: &str = "Gamepad_";
+
+const BUTTON_PREFIX: &str = "InputRebind_Button_";
+const AXIS_PREFIX: &str = "InputRebind_Axis_";
+const CATEGORY_PREFIX: &str = "InputRebindCategory_";
+const CONTEXT_PREFIX: &str = "InputRebindContext_";
+
+const NAME_PREFIXES: [&str; 4] = [BUTTON_PREFIX, AXIS_PREFIX, CATEGORY_PREFIX, CONTEXT_PREFIX];
+
+/// The key names Unreal Engine 4 writes for keyboard, mouse and gamepad input (`EKeys`).
+pub const KEY_NAMES: [&str; 158] = [
+    // Mouse
+    "MouseX",
+    "MouseY",
+    "MouseScrollUp",
+    "MouseScrollDown",
+    "MouseWheelAxis",
+    "LeftMouseButton",
+    "RightMouseButton",
+    "MiddleMouseButton",
+    "ThumbMouseButton",
+    "ThumbMouseButton2",
+    // Keyboard
+    "BackSpace",
+    "Tab",
+    "Enter",
+    "Pause",
+    "CapsLock",
+    "Escape",
+    "SpaceBar",
+    "PageUp",
+    "PageDown",
+    "End",
+    "Home",
+    "Left",
+    "Up",
+    "Right",
+    "Down",
+    "Insert",
+    "Delete",
+    "Zero",
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "NumPadZero",
+    "NumPadOne",
+    "NumPadTwo",
+    "NumPadThree",
+    "NumPadFour",
+    "NumPadFive",
+    "NumPadSix",
+    "NumPadSeven",
+    "NumPadEight",
+    "NumPadNine",
+    "Multiply",
+    "Add",
+    "Subtract",
+    "Decimal",
+    "Divide",
+    "F1",
+    "F2",
+    "F3",
+    "F4",
+    "F5",
+    "F6",
+    "F7",
+    "F8",
+    "F9",
+    "F10",
+    "F11",
+    "F12",
+    "NumLock",
+    "ScrollLock",
+    "LeftShift",
+    "RightShift",
+    "LeftControl",
+    "RightControl",
+    "LeftAlt",
+    "RightAlt",
+    "LeftCommand",
+    "RightCommand",
+    "Semicolon",
+    "Equals",
+    "Comma",
+    "Underscore",
+    "Hyphen",
+    "Period",
+    "Slash",
+    "Tilde",
+    "LeftBracket",
+    "Backslash",
+    "RightBracket",
+    "Apostrophe",
+    "Ampersand",
+    "Asterix",
+    "Caret",
+    "Colon",
+    "Dollar",
+    "Exclamation",
+    "LeftParantheses",
+    "RightParantheses",
+    "Quote",
+    "A_AccentGrave",
+    "E_AccentGrave",
+    "E_AccentAigu",
+    "C_Cedille",
+    "Section",
+    // Gamepad
+    "Gamepad_LeftX",
+    "Gamepad_LeftY",
+    "Gamepad_RightX",
+    "Gamepad_RightY",
+    "Gamepad_LeftTriggerAxis",
+    "Gamepad_RightTriggerAxis",
+    "Gamepad_LeftThumbstick",
+    "Gamepad_RightThumbstick",
+    "Gamepad_Special_Left",
+    "Gamepad_Special_Left_X",
+    "Gamepad_Special_Left_Y",
+    "Gamepad_Special_Right",
+    "Gamepad_FaceButton_Bottom",
+    "Gamepad_FaceButton_Right",
+    "Gamepad_FaceButton_Left",
+    "Gamepad_FaceButton_Top",
+    "Gamepad_LeftShoulder",
+    "Gamepad_RightShoulder",
+    "Gamepad_LeftTrigger",
+    "Gamepad_RightTrigger",
+    "Gamepad_DPad_Up",
+    "Gamepad_DPad_Down",
+    "Gamepad_DPad_Right",
+    "Gamepad_DPad_Left",
+    "Gamepad_LeftStick_Up",
+    "Gamepad_LeftStick_Down",
+    "Gamepad_LeftStick_Right",
+    "Gamepad_LeftStick_Left",
+    "Gamepad_RightStick_Up",
+    "Gamepad_RightStick_Down",
+    "Gamepad_RightStick_Right",
+    "Gamepad_RightStick_Left",
+];
+
+/// The bindings a player has changed from the game defaults, grouped the same way the game stores
+/// them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyBindings {
+    pub categories: Vec<KeyBindingCategory>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyBindingCategory {
+    #[serde(default)]
+    pub name: String,
+    pub category_path: String,
+    #[serde(default)]
+    pub context: String,
+    pub context_path: String,
+    #[serde(default)]
+    pub buttons: Vec<ButtonBinding>,
+    #[serde(default)]
+    pub axes: Vec<AxisBinding>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ButtonBinding {
+    #[serde(default)]
+    pub action: String,
+    pub rebind_data_path: String,
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AxisBinding {
+    #[serde(default)]
+    pub action: String,
+    pub rebind_data_path: String,
+    pub keys: Vec<AxisKey>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AxisKey {
+    pub key: String,
+    pub scale: [f32; 3],
+}
+
+impl KeyBindings {
+    pub fn from_player_input_bindings(bindings: &PlayerInputBindings) -> Self {
+        let categories = bindings
+            .categories
+            .iter()
+            .map(|c| KeyBindingCategory {
+                name: binding_name(&c.category_data_path),
+                category_path: c.category_data_path.clone(),
+                context: binding_name(&c.context_data_path),
+                context_path: c.context_data_path.clone(),
+                buttons: c
+                    .button_bindings
+                    .iter()
+                    .map(|b| ButtonBinding {
+                        action: binding_name(&b.rebind_data_path),
+                        rebind_data_path: b.rebind_data_path.clone(),
+                        keys: b.key_names.to_vec(),
+                    })
+                    .collect(),
+                axes: c
+                    .axis_bindings
+                    .iter()
+                    .map(|a| AxisBinding {
+                        action: binding_name(&a.rebind_data_path),
+                        rebind_data_path: a.rebind_data_path.clone(),
+                        keys: a
+                            .keys
+                            .iter()
+                            .map(|k| {
+                                let scale = k.get_scale_3d();
+
+                                AxisKey {
+                                    key: k.key_name.clone(),
+                                    scale: [scale.x, scale.y, scale.z],
+                                }
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self { categories }
+    }
+
+    pub fn to_player_input_bindings(&self) -> PlayerInputBindings {
+        let mut bindings = PlayerInputBindings::new();
+
+        bindings.categories = self
+            .categories
+            .iter()
+            .map(|c| {
+                let mut category = PlayerInputBinding_Category::new();
+                category.category_data_path = c.category_path.clone();
+                category.context_data_path = c.context_path.clone();
+
+                category.button_bindings = c
+                    .buttons
+                    .iter()
+                    .map(|b| {
+                        let mut button = PlayerInputBinding_Button::new();
+                        button.rebind_data_path = b.rebind_data_path.clone();
+                        button.key_names = b.keys.clone().into();
+                        button
+                    })
+                    .collect();
+
+                category.axis_bindings = c
+                    .axes
+                    .iter()
+                    .map(|a| {
+                        let mut axis = PlayerInputBinding_Axis::new();
+                        axis.rebind_data_path = a.rebind_data_path.clone();
+
+                        axis.keys = a
+                            .keys
+                            .iter()
+                            .map(|k| {
+                                let mut scale = Vec3::new();
+                                scale.x = k.scale[0];
+                                scale.y = k.scale[1];
+                                scale.z = k.scale[2];
+
+                                let mut key = PlayerInputBinding_Axis_Key::new();
+                                key.key_name = k.key.clone();
+                                key.set_scale_3d(scale);
+                                key
+                            })
+                            .collect();
+
+                        axis
+                    })
+                    .collect();
+
+                category
+            })
+            .collect();
+
+        bindings
+    }
+
+    pub fn from_json(data: &str) -> Result<Self> {
+        let mut key_bindings: KeyBindings =
+            serde_json::from_str(data).context("failed to read key bindings")?;
+
+        key_bindings.validate()?;
+
+        for category in key_bindings.categories.iter_mut() {
+            category.name = binding_name(&category.category_path);
+            category.context = binding_name(&category.context_path);
+
+            for button in category.buttons.iter_mut() {
+                button.action = binding_name(&button.rebind_data_path);
+            }
+
+            for axis in category.axes.iter_mut() {
+                axis.action = binding_name(&axis.rebind_data_path);
+            }
+        }
+
+        Ok(key_bindings)
+    }
+
+    /// Checks every category, context and action is a well-formed binding asset path and every key
+    /// is in [`KEY_NAMES`]. Actions aren't checked against the game's own list, only the paths are
+    /// checked so the readable names can be left empty.
+    pub fn validate(&self) -> Result<()> {
+        for category in &self.categories {
+            if !is_binding_path(&category.category_path, CATEGORY_PREFIX) {
+                bail!(
+                    "{:?} is not a valid key binding category path",
+                    category.category_path
+                );
+            }
+
+            if !is_binding_path(&category.context_path, CONTEXT_PREFIX) {
+                bail!(
+                    "{:?} is not a valid key binding context path",
+                    category.context_path
+                );
+            }
+
+            for button in &category.buttons {
+                if !is_binding_path(&button.rebind_data_path, BUTTON_PREFIX) {
+                    bail!(
+                        "{:?} is not a valid button action path",
+                        button.rebind_data_path
+                    );
+                }
+
+                validate_keys(&button.rebind_data_path, button.keys.iter())?;
+            }
+
+            for axis in &category.axes {
+                if !is_binding_path(&axis.rebind_data_path, AXIS_PREFIX) {
+                    bail!(
+                        "{:?} is not a valid axis action path",
+                        axis.rebind_data_path
+                    );
+                }
+
+                validate_keys(&axis.rebind_data_path, axis.keys.iter().map(|k| &k.key))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("failed to write key bindings")
+    }
+
+    /// Removes the keys a profile of `header_type` can't use, i.e. keyboard and mouse keys on PS4,
+    /// returning how many were removed. Bindings left without any keys are dropped so the game
+    /// falls back to its defaults for them.
+    pub fn retain_supported_keys(&mut self, header_type: &HeaderType) -> usize {
+        if *header_type != HeaderType::Ps4Profile {
+            return 0;
+        }
+
+        let mut removed = 0;
+
+        for category in self.categories.iter_mut() {
+            for button in category.buttons.iter_mut() {
+                let before = button.keys.len();
+                button.keys.retain(|k| is_gamepad_key(k));
+                removed += before - button.keys.len();
+            }
+
+            for axis in category.axes.iter_mut() {
+                let before = axis.keys.len();
+                axis.keys.retain(|k| is_gamepad_key(&k.key));
+                removed += before - axis.keys.len();
+            }
+
+            category.buttons.retain(|b| !b.keys.is_empty());
+            category.axes.retain(|a| !a.keys.is_empty());
+        }
+
+        removed
+    }
+}
+
+pub fn is_gamepad_key(key: &str) -> bool {
+    key.starts_with(GAMEPAD_KEY_PREFIX)
+}
+
+pub fn is_known_key(key: &str) -> bool {
+    KEY_NAMES.contains(&key)
+}
+
+fn validate_keys<'a>(path: &str, mut keys: impl Iterator<Item = &'a String>) -> Result<()> {
+    if let Some(key) = keys.find(|k| !is_known_key(k)) {
+        bail!("{} has an unknown key: {:?}", binding_name(path), key);
+    }
+
+    Ok(())
+}
+
+/// Binding assets are written as `/Game/<package>/<name>.<name>`, `name` starting with `prefix`.
+fn is_binding_path(path: &str, prefix: &str) -> bool {
+    let (package, object_name) = match path.rsplit_once('.') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    package.starts_with("/Game/")
+        && package.rsplit('/').next() == Some(object_name)
+        && object_name.len() > prefix.len()
+        && object_name.starts_with(prefix)
+}
+
+/// A readable name for a binding asset, i.e. `InputRebind_Button_WeaponToggle` becomes
+/// `Weapon Toggle`.
+pub fn binding_name(path: &str) -> String {
+    let asset_name = path.rsplit('.').next().unwrap_or(path);
+
+    let asset_name = NAME_PREFIXES
+        .iter()
+        .find_map(|p| asset_name.strip_prefix(p))
+        .unwrap_or(asset_name);
+
+    let mut name = String::with_capacity(asset_name.len() + 8);
+    let mut previous: Option<char> = None;
+
+    for c in asset_name.chars() {
+        if c == '_' {
+            if !name.is_empty() && !name.ends_with(' ') {
+                name.push(' ');
+            }
+        } else {
+            if c.is_uppercase()
+                && previous
+                    .map(|p| p.is_lowercase() || p.is_ascii_digit())
+                    .unwrap_or(false)
+            {
+                name.push(' ');
+            }
+
+            name.push(c);
+        }
+
+        previous = Some(c);
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::read_test_profile;
+
+    use super::*;
+
+    #[test]
+    fn test_export_import() {
+        let source = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let key_bindings = source.profile_data.key_bindings();
+
+        assert_eq!(
+            key_bindings.to_player_input_bindings(),
+            *source.profile_data.profile.get_player_input_bindings()
+        );
+
+        let melee = key_bindings
+            .categories
+            .iter()
+            .flat_map(|c| c.buttons.iter())
+            .find(|b| b.action == "Melee")
+            .expect("failed to find melee binding");
+
+        assert_eq!(
+            melee.keys,
+            vec!["Gamepad_RightThumbstick", "MiddleMouseButton"]
+        );
+
+        let exported = key_bindings
+            .to_json()
+            .expect("failed to export key bindings");
+
+        let imported = KeyBindings::from_json(&exported).expect("failed to import key bindings");
+
+        assert_eq!(imported, key_bindings);
+
+        let mut target = read_test_profile("profile.sav", HeaderType::PcProfile);
+
+        assert_ne!(target.profile_data.key_bindings(), key_bindings);
+
+        target
+            .profile_data
+            .set_key_bindings(&imported)
+            .expect("failed to set key bindings");
+
+        let (_, target) = target.as_bytes().expect("failed to write test profile");
+
+        assert_eq!(target.profile_data.key_bindings(), key_bindings);
+    }
+
+    #[test]
+    fn test_import_without_names() {
+        // Only the paths are needed, the names are filled in from them
+        let key_bindings = KeyBindings::from_json(
+            r#"{"categories": [{
+                "category_path": "/Game/PlayerCharacters/_Shared/_Design/Input/Bindings/InputRebindCategory_Walking.InputRebindCategory_Walking",
+                "context_path": "/Game/PlayerCharacters/_Shared/_Design/Input/Bindings/InputRebindContext_Player_Default.InputRebindContext_Player_Default",
+                "buttons": [{
+                    "rebind_data_path": "/Game/PlayerCharacters/_Shared/_Design/Input/Bindings/InputRebind_Button_WeaponToggle.InputRebind_Button_WeaponToggle",
+                    "keys": ["ThumbMouseButton"]
+                }]
+            }]}"#,
+        )
+        .expect("failed to import key bindings");
+
+        let category = &key_bindings.categories[0];
+
+        assert_eq!(category.name, "Walking");
+        assert_eq!(category.context, "Player Default");
+        assert_eq!(category.buttons[0].action, "Weapon Toggle");
+        assert!(category.axes.is_empty());
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_bindings() {
+        let source = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let key_bindings = source.profile_data.key_bindings();
+
+        let import = |edit: &dyn Fn(&mut KeyBindings)| {
+            let mut edited = key_bindings.clone();
+            edit(&mut edited);
+
+            KeyBindings::from_json(&edited.to_json().expect("failed to export key bindings"))
+        };
+
+        assert!(import(&|_| {}).is_ok());
+
+        // Unknown keys
+        assert!(import(&|k| k.categories[2].buttons[0].keys[0] = "NotAKey".to_owned()).is_err());
+        assert!(import(&|k| k.categories[2].buttons[0].keys[0] = String::new()).is_err());
+        assert!(import(&|k| k.categories[2].buttons[0].keys[0] = "a".to_owned()).is_err());
+
+        // Malformed action paths
+        assert!(import(&|k| {
+            k.categories[2].buttons[0].rebind_data_path = "/Game/PlayerCharacters/_Shared/_Design/Input/Bindings/InputRebind_Button_Fly.InputRebind_Button_Flying".to_owned()
+        })
+        .is_err());
+        assert!(
+            import(&|k| k.categories[2].buttons[0].rebind_data_path = "Melee".to_owned()).is_err()
+        );
+        assert!(import(&|k| {
+            // An axis is not a button
+            k.categories[2].buttons[0].rebind_data_path = "/Game/PlayerCharacters/_Shared/_Design/Input/Bindings/InputRebind_Axis_MoveForward.InputRebind_Axis_MoveForward".to_owned()
+        })
+        .is_err());
+
+        // Malformed category and context paths
+        assert!(import(&|k| k.categories[0].category_path = String::new()).is_err());
+        assert!(import(&|k| k.categories[0].context_path = "Controller".to_owned()).is_err());
+
+        assert!(KeyBindings::from_json("{\"categories\": [{}]}").is_err());
+        assert!(KeyBindings::from_json("not key bindings").is_err());
+
+        // The same checks apply when setting them directly
+        let mut target = read_test_profile("profile.sav", HeaderType::PcProfile);
+
+        let before = target.profile_data.key_bindings();
+
+        let mut edited = key_bindings;
+        edited.categories[2].buttons[0].keys[0] = "NotAKey".to_owned();
+
+        assert!(target.profile_data.set_key_bindings(&edited).is_err());
+        assert_eq!(target.profile_data.key_bindings(), before);
+    }
+
+    #[test]
+    fn test_retain_supported_keys() {
+        let source = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let mut key_bindings = source.profile_data.key_bindings();
+
+        assert_eq!(
+            key_bindings.retain_supported_keys(&HeaderType::PcProfile),
+            0
+        );
+        assert_eq!(key_bindings, source.profile_data.key_bindings());
+
+        // Weapon Toggle only has a mouse key so it's dropped, Melee keeps its gamepad key
+        assert_eq!(
+            key_bindings.retain_supported_keys(&HeaderType::Ps4Profile),
+            2
+        );
+
+        let buttons = &key_bindings.categories[2].buttons;
+
+        assert_eq!(buttons.len(), 1);
+        assert_eq!(buttons[0].action, "Melee");
+        assert_eq!(buttons[0].keys, vec!["Gamepad_RightThumbstick"]);
+    }
+}
@@ -6,8 +6,9 @@ use std::path::Path;
 use anyhow::{Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
 
+use crate::anonymize::{anonymize_mail_items, Pseudonyms};
 use crate::bl3_profile::profile_data::ProfileData;
-use crate::file_helper::FileData;
+use crate::file_helper::{FileData, FileFingerprint};
 use crate::game_data::{
     PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
     PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_ROOM_DECORATIONS, PROFILE_SKINS,
@@ -18,13 +19,19 @@ use crate::parser::{decrypt, encrypt, HeaderType};
 use crate::protos::oak_profile::Profile;
 use crate::{file_helper, parser};
 
+pub mod first_run;
+pub mod guardian_injection;
+pub mod guardian_rank;
 pub mod guardian_reward;
+pub mod key_bindings;
 pub mod profile_currency;
 pub mod profile_data;
 pub mod science_levels;
 pub mod sdu;
+pub mod settings;
 pub mod skins;
 pub mod util;
+pub mod vault_card;
 
 #[derive(Debug, Clone, Default, Eq, Ord, PartialOrd)]
 pub struct Bl3Profile {
@@ -42,6 +49,7 @@ pub struct Bl3Profile {
     pub save_game_type: String,
     pub header_type: HeaderType,
     pub profile_data: ProfileData,
+    pub file_fingerprint: Option<FileFingerprint>,
 }
 
 impl std::cmp::PartialEq for Bl3Profile {
@@ -94,6 +102,7 @@ impl Bl3Profile {
             save_game_type,
             header_type,
             profile_data,
+            file_fingerprint: None,
         })
     }
 
@@ -137,6 +146,30 @@ impl Bl3Profile {
 
         Ok((output, new_profile))
     }
+
+    /// Replaces the ids of other players with pseudonyms and drops the cached friend activity,
+    /// everything that affects the game itself is kept.
+    pub fn anonymize(&mut self, pseudonyms: &mut Pseudonyms) {
+        let profile = &mut self.profile_data.profile;
+
+        for friend_encounter in profile.friend_encounters.iter_mut() {
+            friend_encounter.key = pseudonyms.get(&friend_encounter.key);
+        }
+
+        for player in profile.recently_met_players.iter_mut() {
+            player.shift_player_id = pseudonyms.get(&player.shift_player_id);
+            player.first_party_player_id = pseudonyms.get(&player.first_party_player_id);
+        }
+
+        for gear in profile.gear_sold_by_friends.iter_mut() {
+            gear.friend_net_id = pseudonyms.get(&gear.friend_net_id);
+        }
+
+        profile.friend_events.clear();
+        profile.friend_statuses.clear();
+
+        anonymize_mail_items(&mut profile.npc_mail_items, pseudonyms);
+    }
 }
 
 impl fmt::Display for Bl3Profile {
@@ -266,6 +299,7 @@ mod tests {
 
     use crate::bl3_profile::science_levels::BorderlandsScienceLevel;
     use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
+    use crate::error::ContainerFullError;
 
     use super::*;
 
@@ -442,4 +476,54 @@ mod tests {
         assert_eq!(bl3_profile.profile_data.weapon_skins_unlocked(), 24);
         assert_eq!(bl3_profile.profile_data.weapon_trinkets_unlocked(), 63);
     }
+
+    #[test]
+    fn test_add_bank_item_over_capacity() {
+        // 1prof.sav has a full bank at Bank SDU level 23
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        assert_eq!(profile_data.bank_capacity(), 399);
+        assert_eq!(profile_data.bank_items().len(), 399);
+
+        let item = profile_data.bank_items()[0].clone();
+
+        let err = profile_data
+            .add_bank_item(&item, false)
+            .expect_err("added an item to a full bank");
+
+        assert!(err.is::<ContainerFullError>());
+        assert_eq!(profile_data.bank_items().len(), 399);
+        assert_eq!(profile_data.profile.bank_inventory_list.len(), 399);
+
+        let err = profile_data
+            .insert_bank_item(0, &item, false)
+            .expect_err("inserted an item into a full bank");
+
+        assert!(err.is::<ContainerFullError>());
+
+        // Allowing it for one call does not allow it for the next
+        profile_data
+            .add_bank_item(&item, true)
+            .expect("failed to add item over capacity");
+
+        assert_eq!(profile_data.bank_items().len(), 400);
+        assert_eq!(profile_data.profile.bank_inventory_list.len(), 400);
+
+        profile_data
+            .add_bank_item(&item, false)
+            .expect_err("added an item to a full bank");
+
+        // Replacing an item doesn't change the item count so it isn't checked
+        profile_data
+            .replace_bank_item(0, &item)
+            .expect("failed to replace item in a full bank");
+    }
 }
@@ -1,18 +1,32 @@
 use std::convert::TryInto;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use derivative::Derivative;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
-use tracing::error;
+use tracing::{error, warn};
 
 use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::first_run::ProfileFirstRunData;
+use crate::bl3_profile::guardian_rank::guardian_experience_for_rank;
 use crate::bl3_profile::guardian_reward::{GuardianReward, GuardianRewardData};
+use crate::bl3_profile::key_bindings::KeyBindings;
 use crate::bl3_profile::profile_currency::ProfileCurrency;
-use crate::bl3_profile::science_levels::{BorderlandsScienceInfo, BorderlandsScienceLevel};
+use crate::bl3_profile::science_levels::{
+    BorderlandsScienceBooster, BorderlandsScienceBoosterSlot, BorderlandsScienceInfo,
+    BorderlandsScienceLevel,
+};
 use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
-use crate::bl3_profile::skins::{ProfileSkinType, SkinSet, WeaponSkinSet};
+use crate::bl3_profile::settings::{ProfileSetting, ProfileSettingValue, ProfileSettingsPreset};
+use crate::bl3_profile::skins::{ProfileCosmetic, ProfileSkinType, SkinSet, WeaponSkinSet};
 use crate::bl3_profile::util::get_checksum_hash;
+use crate::bl3_profile::vault_card::{
+    vault_card_experience_for_level, vault_card_gear, VaultCardData, VaultCardRewardData,
+    VAULT_CARD_IDS, VAULT_CARD_MAX_LEVEL,
+};
+use crate::bl3_save::challenge_data::ChallengeProgress;
+use crate::bl3_save::game_stats::{game_stats_from_save_game_data, set_game_stat_value, GameStat};
+use crate::error::ContainerFullError;
 use crate::game_data::{
     PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
     PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
@@ -24,7 +38,7 @@ use crate::protos::oak_profile::{
 use crate::protos::oak_shared::{
     CrewQuartersDecorationItemSaveGameData, InventoryCategorySaveData,
     OakCustomizationSaveGameData, OakInventoryCustomizationPartInfo, OakSDUSaveGameData,
-    VaultCardRewardList, VaultCardSaveGameData,
+    VaultCardGearReward, VaultCardReward, VaultCardRewardList,
 };
 
 #[derive(Derivative)]
@@ -131,7 +145,7 @@ impl ProfileData {
 
                 GuardianRewardData {
                     current,
-                    max: i32::MAX,
+                    max: None,
                     reward,
                 }
             })
@@ -149,6 +163,24 @@ impl ProfileData {
                 science_level: level,
                 solves,
                 tokens: profile.CitizenScienceCSBucksAmount,
+                active_booster: BorderlandsScienceBooster::new(
+                    profile.CitizenScienceActiveBoosterIndex,
+                    profile.CitizenScienceActiveBoosterRemainingTime,
+                    profile.CitizenScienceActiveBoosterTotalTime,
+                ),
+                streamer_primary_booster: BorderlandsScienceBooster::new(
+                    profile.StreamerPrimaryActiveBoosterIndex,
+                    profile.StreamerPrimaryActiveBoosterRemainingTime,
+                    profile.StreamerPrimaryActiveBoosterTotalTime,
+                ),
+                streamer_secondary_booster: BorderlandsScienceBooster::new(
+                    profile.StreamerSecondaryActiveBoosterIndex,
+                    profile.StreamerSecondaryActiveBoosterRemainingTime,
+                    profile.StreamerSecondaryActiveBoosterTotalTime,
+                ),
+                streamer_booster_tier: profile.StreamerBoosterTier,
+                has_seen_intro_video: profile.bCitizenScienceHasSeenIntroVideo,
+                tutorial_done: profile.bCitizenScienceTutorialDone,
             }
         };
 
@@ -348,60 +380,181 @@ impl ProfileData {
     }
 
     pub fn set_vault_card_chests(&mut self, vault_card_id: u32, vault_card_chests: i32) {
-        let vault_card_reward_list = VaultCardRewardList {
-            vault_card_id,
-            vault_card_experience: 0,
-            unlocked_reward_list: Default::default(),
-            redeemed_reward_list: Default::default(),
-            vault_card_chests,
-            vault_card_chests_opened: 0,
-            vault_card_keys_spent: 0,
-            gear_rewards: Default::default(),
-            unknown_fields: Default::default(),
-            cached_size: Default::default(),
-        };
+        self.vault_card_reward_list_mut(vault_card_id)
+            .vault_card_chests = vault_card_chests;
+
+        match vault_card_id {
+            1 => self.vault_card_1_chests = vault_card_chests,
+            2 => self.vault_card_2_chests = vault_card_chests,
+            3 => self.vault_card_3_chests = vault_card_chests,
+            _ => (),
+        }
+    }
+
+    pub fn vault_card_data(&self) -> VaultCardData {
+        VaultCardData::from_save_game_data(self.profile.vault_card.as_ref())
+    }
+
+    pub fn set_last_active_vault_card(&mut self, vault_card_id: u32) -> Result<()> {
+        check_vault_card_id(vault_card_id)?;
+
+        self.vault_card_reward_list_mut(vault_card_id);
 
         if let Some(vault_card) = self.profile.vault_card.as_mut() {
-            if vault_card.last_active_vault_card_id == 0 {
-                vault_card.last_active_vault_card_id = vault_card_id;
-            }
+            vault_card.last_active_vault_card_id = vault_card_id;
+        }
 
-            if let Some(claimed_rewards) = vault_card
-                .vault_card_claimed_rewards
-                .iter_mut()
-                .find(|v| v.vault_card_id == vault_card_id)
-            {
-                claimed_rewards.vault_card_chests = vault_card_chests;
-            } else {
-                vault_card
-                    .vault_card_claimed_rewards
-                    .push(vault_card_reward_list);
-            }
-        } else {
-            self.profile.vault_card = Some(VaultCardSaveGameData {
-                last_active_vault_card_id: vault_card_id,
-                current_day_seed: 0,
-                current_week_seed: 0,
-                vault_card_previous_challenges: Default::default(),
-                vault_card_claimed_rewards: vec![vault_card_reward_list].into(),
+        Ok(())
+    }
+
+    pub fn set_vault_card_experience(&mut self, vault_card_id: u32, experience: i64) -> Result<()> {
+        check_vault_card_id(vault_card_id)?;
+
+        self.vault_card_reward_list_mut(vault_card_id)
+            .vault_card_experience = experience.max(0);
+
+        Ok(())
+    }
+
+    pub fn set_vault_card_level(&mut self, vault_card_id: u32, level: i32) -> Result<()> {
+        check_vault_card_id(vault_card_id)?;
+
+        if !(1..=VAULT_CARD_MAX_LEVEL).contains(&level) {
+            bail!(
+                "Vault Card level must be between 1 and {}",
+                VAULT_CARD_MAX_LEVEL
+            );
+        }
+
+        let reward_list = self.vault_card_reward_list_mut(vault_card_id);
+
+        reward_list.vault_card_experience =
+            vault_card_experience_for_level(level, reward_list.vault_card_experience);
+
+        Ok(())
+    }
+
+    // Locking a reward also takes back its redemption, redeeming a reward unlocks it first.
+    pub fn set_vault_card_reward_unlocked(
+        &mut self,
+        vault_card_id: u32,
+        reward: VaultCardRewardData,
+        unlocked: bool,
+    ) -> Result<()> {
+        check_vault_card_id(vault_card_id)?;
+
+        let reward_list = self.vault_card_reward_list_mut(vault_card_id);
+
+        set_vault_card_reward_in_list(&mut reward_list.unlocked_reward_list, reward, unlocked);
+
+        if !unlocked {
+            set_vault_card_reward_in_list(&mut reward_list.redeemed_reward_list, reward, false);
+        }
+
+        Ok(())
+    }
+
+    pub fn set_vault_card_reward_redeemed(
+        &mut self,
+        vault_card_id: u32,
+        reward: VaultCardRewardData,
+        redeemed: bool,
+    ) -> Result<()> {
+        check_vault_card_id(vault_card_id)?;
+
+        let reward_list = self.vault_card_reward_list_mut(vault_card_id);
+
+        if redeemed {
+            set_vault_card_reward_in_list(&mut reward_list.unlocked_reward_list, reward, true);
+        }
+
+        set_vault_card_reward_in_list(&mut reward_list.redeemed_reward_list, reward, redeemed);
+
+        Ok(())
+    }
+
+    pub fn set_vault_card_gear_reward_unlocked(
+        &mut self,
+        vault_card_id: u32,
+        gear_index: i32,
+        unlocked: bool,
+    ) -> Result<()> {
+        check_vault_card_id(vault_card_id)?;
+
+        if !vault_card_gear(vault_card_id)
+            .iter()
+            .any(|g| g.gear_index == gear_index)
+        {
+            bail!(
+                "Vault Card {} does not have gear at index {}",
+                vault_card_id,
+                gear_index
+            );
+        }
+
+        let gear_rewards = &mut self.vault_card_reward_list_mut(vault_card_id).gear_rewards;
+
+        let existing = gear_rewards.iter().position(|g| g.gear_index == gear_index);
+
+        match (existing, unlocked) {
+            (None, true) => gear_rewards.push(VaultCardGearReward {
+                gear_index,
+                repurchase_count: 0,
                 unknown_fields: Default::default(),
                 cached_size: Default::default(),
-            })
-            .into();
+            }),
+            (Some(i), false) => {
+                gear_rewards.remove(i);
+            }
+            _ => (),
         }
 
-        match vault_card_id {
-            1 => self.vault_card_1_chests = vault_card_chests,
-            2 => self.vault_card_2_chests = vault_card_chests,
-            3 => self.vault_card_3_chests = vault_card_chests,
-            _ => (),
+        Ok(())
+    }
+
+    fn vault_card_reward_list_mut(&mut self, vault_card_id: u32) -> &mut VaultCardRewardList {
+        let vault_card = self.profile.mut_vault_card();
+
+        if vault_card.last_active_vault_card_id == 0 {
+            vault_card.last_active_vault_card_id = vault_card_id;
         }
+
+        let index = match vault_card
+            .vault_card_claimed_rewards
+            .iter()
+            .position(|v| v.vault_card_id == vault_card_id)
+        {
+            Some(index) => index,
+            None => {
+                let mut reward_list = VaultCardRewardList::new();
+                reward_list.vault_card_id = vault_card_id;
+
+                vault_card.vault_card_claimed_rewards.push(reward_list);
+                vault_card.vault_card_claimed_rewards.len() - 1
+            }
+        };
+
+        &mut vault_card.vault_card_claimed_rewards[index]
     }
 
     pub fn guardian_rank(&self) -> i32 {
         self.guardian_rank
     }
 
+    pub fn guardian_experience(&self) -> i64 {
+        self.profile
+            .guardian_rank
+            .as_ref()
+            .map(|g| g.new_guardian_experience.max(g.guardian_experience as i64))
+            .unwrap_or(0)
+    }
+
+    pub fn guardian_tokens_spent(&self) -> i32 {
+        self.guardian_rewards
+            .iter()
+            .fold(0_i32, |acc, r| acc.saturating_add(r.current))
+    }
+
     pub fn set_guardian_rank(&mut self, new_rank: i32, tokens: Option<i32>) {
         if let Some(guardian_rank) = self.profile.guardian_rank.as_mut() {
             guardian_rank.guardian_rank = new_rank;
@@ -431,6 +584,16 @@ impl ProfileData {
         }
     }
 
+    // Opt-in: the experience curve is fitted against real profiles and is not verified for every rank.
+    pub fn set_guardian_experience_for_rank(&mut self, rank: i32) {
+        let experience = guardian_experience_for_rank(rank, self.guardian_experience());
+
+        if let Some(guardian_rank) = self.profile.guardian_rank.as_mut() {
+            guardian_rank.guardian_experience = experience.min(i32::MAX as i64) as i32;
+            guardian_rank.new_guardian_experience = experience;
+        }
+    }
+
     pub fn guardian_tokens(&self) -> i32 {
         self.guardian_tokens
     }
@@ -486,10 +649,12 @@ impl ProfileData {
 
     pub fn set_borderlands_science_level(&mut self, science_level: &BorderlandsScienceLevel) {
         self.profile.CitizenScienceLevelProgression = science_level.progression();
-        self.profile.bCitizenScienceHasSeenIntroVideo = true;
-        self.profile.bCitizenScienceTutorialDone = true;
+        self.set_borderlands_science_intro_video_seen(true);
+        self.set_borderlands_science_tutorial_done(true);
 
         self.borderlands_science_info.science_level = science_level.to_owned();
+        self.borderlands_science_info.solves =
+            self.profile.CitizenScienceLevelProgression.iter().sum();
     }
 
     pub fn set_borderlands_science_tokens(&mut self, tokens: i32) {
@@ -498,6 +663,195 @@ impl ProfileData {
         self.borderlands_science_info.tokens = tokens;
     }
 
+    pub fn set_borderlands_science_intro_video_seen(&mut self, seen: bool) {
+        self.profile.bCitizenScienceHasSeenIntroVideo = seen;
+
+        self.borderlands_science_info.has_seen_intro_video = seen;
+    }
+
+    pub fn set_borderlands_science_tutorial_done(&mut self, done: bool) {
+        self.profile.bCitizenScienceTutorialDone = done;
+
+        self.borderlands_science_info.tutorial_done = done;
+    }
+
+    pub fn set_borderlands_science_streamer_booster_tier(&mut self, tier: i32) -> Result<()> {
+        if tier < 0 {
+            bail!("Streamer booster tier cannot be negative, got: {}", tier);
+        }
+
+        self.profile.StreamerBoosterTier = tier;
+
+        self.borderlands_science_info.streamer_booster_tier = tier;
+
+        Ok(())
+    }
+
+    /// Starts the booster with a full timer of `duration_seconds`.
+    pub fn apply_borderlands_science_booster(
+        &mut self,
+        slot: BorderlandsScienceBoosterSlot,
+        index: i32,
+        duration_seconds: u32,
+    ) -> Result<()> {
+        if index < 0 {
+            bail!("Booster index cannot be negative, got: {}", index);
+        }
+
+        if duration_seconds == 0 {
+            bail!("Booster duration must be greater than 0 seconds");
+        }
+
+        self.set_borderlands_science_booster(
+            slot,
+            BorderlandsScienceBooster {
+                index,
+                remaining_seconds: duration_seconds,
+                total_seconds: duration_seconds,
+            },
+        );
+
+        Ok(())
+    }
+
+    pub fn clear_borderlands_science_booster(&mut self, slot: BorderlandsScienceBoosterSlot) {
+        self.set_borderlands_science_booster(slot, BorderlandsScienceBooster::default());
+    }
+
+    fn set_borderlands_science_booster(
+        &mut self,
+        slot: BorderlandsScienceBoosterSlot,
+        booster: BorderlandsScienceBooster,
+    ) {
+        let (index, remaining_time, total_time) = match slot {
+            BorderlandsScienceBoosterSlot::Active => (
+                &mut self.profile.CitizenScienceActiveBoosterIndex,
+                &mut self.profile.CitizenScienceActiveBoosterRemainingTime,
+                &mut self.profile.CitizenScienceActiveBoosterTotalTime,
+            ),
+            BorderlandsScienceBoosterSlot::StreamerPrimary => (
+                &mut self.profile.StreamerPrimaryActiveBoosterIndex,
+                &mut self.profile.StreamerPrimaryActiveBoosterRemainingTime,
+                &mut self.profile.StreamerPrimaryActiveBoosterTotalTime,
+            ),
+            BorderlandsScienceBoosterSlot::StreamerSecondary => (
+                &mut self.profile.StreamerSecondaryActiveBoosterIndex,
+                &mut self.profile.StreamerSecondaryActiveBoosterRemainingTime,
+                &mut self.profile.StreamerSecondaryActiveBoosterTotalTime,
+            ),
+        };
+
+        *index = booster.index;
+        *remaining_time = booster.remaining_seconds as f32;
+        *total_time = booster.total_seconds as f32;
+
+        *self.borderlands_science_info.booster_mut(slot) = booster;
+    }
+
+    pub fn first_run_data(&self) -> ProfileFirstRunData {
+        ProfileFirstRunData::from_profile(&self.profile)
+    }
+
+    pub fn set_first_run_data(&mut self, first_run_data: &ProfileFirstRunData) {
+        let tutorial_info = self.profile.mut_tutorial_info();
+        tutorial_info.seen_tutorials = first_run_data.seen_tutorials.clone().into();
+        tutorial_info.tutorials_disabled = first_run_data.tutorials_disabled;
+
+        let profile = &mut self.profile;
+        profile.has_seen_first_boot = first_run_data.has_seen_first_boot;
+        profile.seen_news_items = first_run_data.seen_news_items.clone().into();
+        profile.news_hashes = first_run_data.news_hashes.clone();
+        profile.rare_chest_event_enabled = first_run_data.rare_chest_event_enabled;
+        profile.badass_event_enabled = first_run_data.badass_event_enabled;
+        profile.pinata_event_enabled = first_run_data.pinata_event_enabled;
+        profile.moxxis_drink_event_enabled = first_run_data.moxxis_drink_event_enabled;
+        profile.disable_event_content = first_run_data.disable_event_content;
+    }
+
+    pub fn challenges(&self) -> Vec<ChallengeProgress> {
+        let mut challenges = self
+            .profile
+            .challenge_data
+            .par_iter()
+            .map(ChallengeProgress::from_save_game_data)
+            .collect::<Vec<_>>();
+
+        challenges.sort();
+
+        challenges
+    }
+
+    pub fn set_challenge(&mut self, challenge: &ChallengeProgress) -> Result<()> {
+        let challenge_data = self
+            .profile
+            .challenge_data
+            .iter_mut()
+            .find(|c| c.challenge_class_path == challenge.path)
+            .with_context(|| format!("failed to read profile challenge: {}", challenge.path))?;
+
+        challenge.apply_to_save_game_data(challenge_data);
+
+        Ok(())
+    }
+
+    pub fn game_stats(&self) -> Vec<GameStat> {
+        game_stats_from_save_game_data(&self.profile.profile_stats_data)
+    }
+
+    pub fn set_game_stat(&mut self, stat_path: &str, stat_value: i32) {
+        set_game_stat_value(&mut self.profile.profile_stats_data, stat_path, stat_value);
+    }
+
+    pub fn total_playtime_seconds(&self) -> i32 {
+        self.profile.total_playtime_seconds
+    }
+
+    pub fn set_total_playtime_seconds(&mut self, total_playtime_seconds: i32) {
+        self.profile.total_playtime_seconds = total_playtime_seconds;
+    }
+
+    pub fn setting(&self, setting: &ProfileSetting) -> ProfileSettingValue {
+        setting.value(&self.profile)
+    }
+
+    pub fn set_setting(
+        &mut self,
+        setting: &ProfileSetting,
+        value: ProfileSettingValue,
+    ) -> Result<()> {
+        setting.apply(&mut self.profile, value)
+    }
+
+    pub fn settings_preset(&self) -> ProfileSettingsPreset {
+        ProfileSettingsPreset::from_profile(&self.profile)
+    }
+
+    pub fn apply_settings_preset(&mut self, preset: &ProfileSettingsPreset) -> Result<()> {
+        // Check everything first so a bad preset doesn't leave the profile half changed
+        for (setting, value) in &preset.settings {
+            setting.validate(*value)?;
+        }
+
+        for (setting, value) in &preset.settings {
+            self.set_setting(setting, *value)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn key_bindings(&self) -> KeyBindings {
+        KeyBindings::from_player_input_bindings(self.profile.get_player_input_bindings())
+    }
+
+    pub fn set_key_bindings(&mut self, key_bindings: &KeyBindings) -> Result<()> {
+        key_bindings.validate()?;
+
+        self.profile
+            .set_player_input_bindings(key_bindings.to_player_input_bindings());
+
+        Ok(())
+    }
+
     pub fn sdu_slots(&self) -> &Vec<ProfileSduSlotData> {
         &self.sdu_slots
     }
@@ -550,7 +904,46 @@ impl ProfileData {
         }
     }
 
-    pub fn add_bank_item(&mut self, item: &Bl3Item) -> Result<()> {
+    pub fn bank_capacity(&self) -> i32 {
+        let level = self
+            .sdu_slots
+            .iter()
+            .find(|s| s.sdu == ProfileSduSlot::Bank)
+            .map(|s| s.current)
+            .unwrap_or(0);
+
+        ProfileSduSlot::bank_capacity(level)
+    }
+
+    /// With `allow_over_capacity` adding to a full bank logs a warning instead of returning a
+    /// [`ContainerFullError`].
+    fn check_bank_capacity(&self, allow_over_capacity: bool) -> Result<()> {
+        let capacity = self.bank_capacity();
+        let count = self.profile.bank_inventory_list.len();
+
+        if count as i32 >= capacity {
+            if !allow_over_capacity {
+                return Err(ContainerFullError {
+                    container: "Bank",
+                    count,
+                    capacity,
+                }
+                .into());
+            }
+
+            warn!(
+                "Bank is over capacity ({}/{} items), items may be lost in game",
+                count + 1,
+                capacity
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn add_bank_item(&mut self, item: &Bl3Item, allow_over_capacity: bool) -> Result<()> {
+        self.check_bank_capacity(allow_over_capacity)?;
+
         let item_serial_number = item.get_serial_number(true)?;
 
         self.profile.bank_inventory_list.push(item_serial_number);
@@ -560,7 +953,18 @@ impl ProfileData {
         Ok(())
     }
 
-    pub fn insert_bank_item(&mut self, item_index: usize, item: &Bl3Item) -> Result<()> {
+    pub fn insert_bank_item(
+        &mut self,
+        item_index: usize,
+        item: &Bl3Item,
+        allow_over_capacity: bool,
+    ) -> Result<()> {
+        self.check_bank_capacity(allow_over_capacity)?;
+
+        self.insert_bank_item_unchecked(item_index, item)
+    }
+
+    fn insert_bank_item_unchecked(&mut self, item_index: usize, item: &Bl3Item) -> Result<()> {
         let item_serial_number = item.get_serial_number(true)?;
 
         self.profile
@@ -573,7 +977,7 @@ impl ProfileData {
     }
 
     pub fn replace_bank_item(&mut self, item_index: usize, new_item: &Bl3Item) -> Result<()> {
-        self.insert_bank_item(item_index, new_item)?;
+        self.insert_bank_item_unchecked(item_index, new_item)?;
 
         // Remove old item
         self.remove_bank_item(item_index + 1);
@@ -613,6 +1017,149 @@ impl ProfileData {
         self.weapon_trinkets_unlocked
     }
 
+    /// Every item that can be unlocked, along with whether this profile has it.
+    pub fn cosmetics(&self) -> Vec<ProfileCosmetic> {
+        ProfileSkinType::ALL
+            .iter()
+            .flat_map(|skin_type| {
+                skin_type
+                    .unlockables()
+                    .iter()
+                    .map(|gd| {
+                        ProfileCosmetic::new(
+                            *skin_type,
+                            gd,
+                            self.is_cosmetic_unlocked(skin_type, gd.ident),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    pub fn is_cosmetic_unlocked(&self, skin_type: &ProfileSkinType, ident: &str) -> bool {
+        match skin_type {
+            ProfileSkinType::Regular(SkinSet::RoomDecorations) => self
+                .profile
+                .unlocked_crew_quarters_decorations
+                .iter()
+                .any(|d| d.decoration_item_asset_path.eq_ignore_ascii_case(ident)),
+            ProfileSkinType::Regular(_) => self
+                .profile
+                .unlocked_customizations
+                .iter()
+                .any(|c| c.customization_asset_path.eq_ignore_ascii_case(ident)),
+            ProfileSkinType::Weapon(_) => match weapon_customization_hash(ident) {
+                Ok(hash) => self
+                    .profile
+                    .unlocked_inventory_customization_parts
+                    .iter()
+                    .any(|p| p.customization_part_hash == hash),
+                Err(_) => false,
+            },
+        }
+    }
+
+    pub fn set_cosmetic_unlocked(
+        &mut self,
+        skin_type: &ProfileSkinType,
+        ident: &str,
+        unlocked: bool,
+    ) -> Result<()> {
+        let gd = skin_type
+            .unlockables()
+            .iter()
+            .find(|gd| gd.ident.eq_ignore_ascii_case(ident))
+            .with_context(|| format!("{} is not one of the {}", ident, skin_type))?;
+
+        if self.is_cosmetic_unlocked(skin_type, gd.ident) == unlocked {
+            return Ok(());
+        }
+
+        match skin_type {
+            ProfileSkinType::Regular(SkinSet::RoomDecorations) => {
+                if unlocked {
+                    self.profile.unlocked_crew_quarters_decorations.push(
+                        CrewQuartersDecorationItemSaveGameData {
+                            is_new: true,
+                            decoration_item_asset_path: gd.ident.to_owned(),
+                            unknown_fields: Default::default(),
+                            cached_size: Default::default(),
+                        },
+                    );
+                } else {
+                    self.profile
+                        .unlocked_crew_quarters_decorations
+                        .retain(|d| !d.decoration_item_asset_path.eq_ignore_ascii_case(gd.ident));
+                }
+            }
+            ProfileSkinType::Regular(_) => {
+                if unlocked {
+                    self.profile
+                        .unlocked_customizations
+                        .push(OakCustomizationSaveGameData {
+                            is_new: true,
+                            customization_asset_path: gd.ident.to_owned(),
+                            unknown_fields: Default::default(),
+                            cached_size: Default::default(),
+                        });
+                } else {
+                    self.profile
+                        .unlocked_customizations
+                        .retain(|c| !c.customization_asset_path.eq_ignore_ascii_case(gd.ident));
+                }
+            }
+            ProfileSkinType::Weapon(_) => {
+                let hash = weapon_customization_hash(gd.ident)?;
+
+                if unlocked {
+                    self.profile.unlocked_inventory_customization_parts.push(
+                        OakInventoryCustomizationPartInfo {
+                            customization_part_hash: hash,
+                            is_new: true,
+                            unknown_fields: Default::default(),
+                            cached_size: Default::default(),
+                        },
+                    );
+                } else {
+                    self.profile
+                        .unlocked_inventory_customization_parts
+                        .retain(|p| p.customization_part_hash != hash);
+                }
+            }
+        }
+
+        let unlocked_count = match skin_type {
+            ProfileSkinType::Regular(r) => match r {
+                SkinSet::CharacterSkins => &mut self.character_skins_unlocked,
+                SkinSet::CharacterHeads => &mut self.character_heads_unlocked,
+                SkinSet::EchoThemes => &mut self.echo_themes_unlocked,
+                SkinSet::Emotes => &mut self.emotes_unlocked,
+                SkinSet::RoomDecorations => &mut self.room_decorations_unlocked,
+            },
+            ProfileSkinType::Weapon(w) => match w {
+                WeaponSkinSet::WeaponSkins => &mut self.weapon_skins_unlocked,
+                WeaponSkinSet::WeaponTrinkets => &mut self.weapon_trinkets_unlocked,
+            },
+        };
+
+        if unlocked {
+            *unlocked_count += 1;
+        } else {
+            *unlocked_count = unlocked_count.saturating_sub(1);
+        }
+
+        Ok(())
+    }
+
+    pub fn unlocked_crew_quarters_decorations(&self) -> Vec<String> {
+        self.profile
+            .unlocked_crew_quarters_decorations
+            .iter()
+            .map(|d| d.decoration_item_asset_path.clone())
+            .collect()
+    }
+
     pub fn unlock_skin_set(&mut self, skin_type: &ProfileSkinType) {
         let mut skins = skin_type.skin_set();
 
@@ -719,3 +1266,40 @@ impl ProfileData {
         }
     }
 }
+
+fn weapon_customization_hash(ident: &str) -> Result<u32> {
+    get_checksum_hash(ident)?
+        .try_into()
+        .with_context(|| format!("failed to get hash for: {}", ident))
+}
+
+fn check_vault_card_id(vault_card_id: u32) -> Result<()> {
+    if !VAULT_CARD_IDS.contains(&vault_card_id) {
+        bail!("{} is not a known Vault Card", vault_card_id);
+    }
+
+    Ok(())
+}
+
+fn set_vault_card_reward_in_list(
+    rewards: &mut protobuf::RepeatedField<VaultCardReward>,
+    reward: VaultCardRewardData,
+    present: bool,
+) {
+    let existing = rewards
+        .iter()
+        .position(|r| r.column_index == reward.column && r.row_index == reward.row);
+
+    match (existing, present) {
+        (None, true) => rewards.push(VaultCardReward {
+            column_index: reward.column,
+            row_index: reward.row,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        }),
+        (Some(i), false) => {
+            rewards.remove(i);
+        }
+        _ => (),
+    }
+}
@@ -5,7 +5,77 @@ use strum::Display;
 pub struct BorderlandsScienceInfo {
     pub science_level: BorderlandsScienceLevel,
     pub solves: i32,
+    /// Stored in the profile as `CitizenScienceCSBucksAmount`.
     pub tokens: i32,
+    pub active_booster: BorderlandsScienceBooster,
+    pub streamer_primary_booster: BorderlandsScienceBooster,
+    pub streamer_secondary_booster: BorderlandsScienceBooster,
+    pub streamer_booster_tier: i32,
+    pub has_seen_intro_video: bool,
+    pub tutorial_done: bool,
+}
+
+impl BorderlandsScienceInfo {
+    pub fn booster(&self, slot: BorderlandsScienceBoosterSlot) -> &BorderlandsScienceBooster {
+        match slot {
+            BorderlandsScienceBoosterSlot::Active => &self.active_booster,
+            BorderlandsScienceBoosterSlot::StreamerPrimary => &self.streamer_primary_booster,
+            BorderlandsScienceBoosterSlot::StreamerSecondary => &self.streamer_secondary_booster,
+        }
+    }
+
+    pub fn booster_mut(
+        &mut self,
+        slot: BorderlandsScienceBoosterSlot,
+    ) -> &mut BorderlandsScienceBooster {
+        match slot {
+            BorderlandsScienceBoosterSlot::Active => &mut self.active_booster,
+            BorderlandsScienceBoosterSlot::StreamerPrimary => &mut self.streamer_primary_booster,
+            BorderlandsScienceBoosterSlot::StreamerSecondary => {
+                &mut self.streamer_secondary_booster
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Display, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BorderlandsScienceBoosterSlot {
+    #[strum(to_string = "Active Booster")]
+    Active,
+    #[strum(to_string = "Streamer Primary Booster")]
+    StreamerPrimary,
+    #[strum(to_string = "Streamer Secondary Booster")]
+    StreamerSecondary,
+}
+
+impl BorderlandsScienceBoosterSlot {
+    pub const ALL: [BorderlandsScienceBoosterSlot; 3] = [
+        BorderlandsScienceBoosterSlot::Active,
+        BorderlandsScienceBoosterSlot::StreamerPrimary,
+        BorderlandsScienceBoosterSlot::StreamerSecondary,
+    ];
+}
+
+/// The profile stores booster timers as float seconds, they are kept here as whole seconds.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct BorderlandsScienceBooster {
+    pub index: i32,
+    pub remaining_seconds: u32,
+    pub total_seconds: u32,
+}
+
+impl BorderlandsScienceBooster {
+    pub fn new(index: i32, remaining_time: f32, total_time: f32) -> Self {
+        Self {
+            index,
+            remaining_seconds: remaining_time.max(0.0).round() as u32,
+            total_seconds: total_time.max(0.0).round() as u32,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.remaining_seconds > 0
+    }
 }
 
 #[derive(Copy, Clone, Debug, Display, Eq, PartialEq, Ord, PartialOrd)]
@@ -96,7 +166,14 @@ impl BorderlandsScienceLevel {
 
 #[cfg(test)]
 mod tests {
-    use crate::bl3_profile::science_levels::BorderlandsScienceLevel;
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_profile::science_levels::{
+        BorderlandsScienceBooster, BorderlandsScienceBoosterSlot, BorderlandsScienceLevel,
+    };
+    use crate::bl3_profile::Bl3Profile;
+    use crate::parser::HeaderType;
 
     #[test]
     pub fn test_science_level_progression() {
@@ -145,4 +222,110 @@ mod tests {
             vec![5, 10, 15, 20, 25, 30, 35, 40, 50, 0]
         );
     }
+
+    #[test]
+    pub fn test_booster() {
+        let booster = BorderlandsScienceBooster::new(2, 1799.6, 3600.0);
+
+        assert_eq!(
+            booster,
+            BorderlandsScienceBooster {
+                index: 2,
+                remaining_seconds: 1800,
+                total_seconds: 3600,
+            }
+        );
+        assert!(booster.is_active());
+
+        // Expired timers can be left slightly negative
+        let booster = BorderlandsScienceBooster::new(2, -0.5, 3600.0);
+
+        assert_eq!(booster.remaining_seconds, 0);
+        assert!(!booster.is_active());
+        assert!(!BorderlandsScienceBooster::default().is_active());
+    }
+
+    #[test]
+    pub fn test_set_boosters() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        for (i, slot) in BorderlandsScienceBoosterSlot::ALL.iter().enumerate() {
+            profile_data
+                .apply_borderlands_science_booster(*slot, i as i32 + 1, (i as u32 + 1) * 600)
+                .expect("failed to apply booster");
+        }
+
+        profile_data
+            .set_borderlands_science_streamer_booster_tier(3)
+            .expect("failed to set streamer booster tier");
+        profile_data.set_borderlands_science_level(&BorderlandsScienceLevel::Tannis);
+        profile_data.set_borderlands_science_tokens(1234);
+
+        // Invalid values leave the boosters as they are
+        let before = profile_data.borderlands_science_info().clone();
+
+        assert!(profile_data
+            .apply_borderlands_science_booster(BorderlandsScienceBoosterSlot::Active, -1, 600)
+            .is_err());
+        assert!(profile_data
+            .apply_borderlands_science_booster(BorderlandsScienceBoosterSlot::Active, 1, 0)
+            .is_err());
+        assert!(profile_data
+            .set_borderlands_science_streamer_booster_tier(-1)
+            .is_err());
+        assert_eq!(*profile_data.borderlands_science_info(), before);
+
+        let (_, mut bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let info = bl3_profile.profile_data.borderlands_science_info();
+
+        assert_eq!(*info, before);
+        assert_eq!(info.science_level, BorderlandsScienceLevel::Tannis);
+        assert_eq!(info.tokens, 1234);
+        assert_eq!(info.streamer_booster_tier, 3);
+        assert!(info.has_seen_intro_video);
+        assert!(info.tutorial_done);
+
+        for (i, slot) in BorderlandsScienceBoosterSlot::ALL.iter().enumerate() {
+            assert_eq!(
+                *info.booster(*slot),
+                BorderlandsScienceBooster {
+                    index: i as i32 + 1,
+                    remaining_seconds: (i as u32 + 1) * 600,
+                    total_seconds: (i as u32 + 1) * 600,
+                }
+            );
+        }
+
+        bl3_profile
+            .profile_data
+            .clear_borderlands_science_booster(BorderlandsScienceBoosterSlot::StreamerPrimary);
+
+        let (_, bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let info = bl3_profile.profile_data.borderlands_science_info();
+
+        assert_eq!(
+            *info.booster(BorderlandsScienceBoosterSlot::StreamerPrimary),
+            BorderlandsScienceBooster::default()
+        );
+        assert!(info
+            .booster(BorderlandsScienceBoosterSlot::Active)
+            .is_active());
+        assert!(info
+            .booster(BorderlandsScienceBoosterSlot::StreamerSecondary)
+            .is_active());
+    }
 }
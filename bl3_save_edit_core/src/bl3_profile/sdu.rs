@@ -1,5 +1,11 @@
 use strum::{Display, EnumIter, EnumMessage, EnumString};
 
+// The bank starts with 31 slots and every Bank SDU adds 16 more (479 at level 28), this is what
+// the game shows in the bank menu and matches `test_files/1prof.sav` which has a full bank of 399
+// items at Bank SDU level 23.
+const BANK_BASE_CAPACITY: i32 = 31;
+const BANK_CAPACITY_PER_LEVEL: i32 = 16;
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct ProfileSduSlotData {
     pub sdu: ProfileSduSlot,
@@ -27,6 +33,12 @@ impl ProfileSduSlot {
             ProfileSduSlot::LostLoot => 10,
         }
     }
+
+    pub fn bank_capacity(level: i32) -> i32 {
+        let level = level.clamp(0, ProfileSduSlot::Bank.maximum());
+
+        BANK_BASE_CAPACITY + level * BANK_CAPACITY_PER_LEVEL
+    }
 }
 
 impl std::default::Default for ProfileSduSlot {
@@ -34,3 +46,25 @@ impl std::default::Default for ProfileSduSlot {
         Self::Bank
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bank_capacity() {
+        assert_eq!(ProfileSduSlot::bank_capacity(0), 31);
+        assert_eq!(ProfileSduSlot::bank_capacity(23), 399);
+        assert_eq!(
+            ProfileSduSlot::bank_capacity(ProfileSduSlot::Bank.maximum()),
+            479
+        );
+
+        // Levels outside of the SDU range are clamped
+        assert_eq!(ProfileSduSlot::bank_capacity(-1), 31);
+        assert_eq!(
+            ProfileSduSlot::bank_capacity(ProfileSduSlot::Bank.maximum() + 1),
+            479
+        );
+    }
+}
@@ -0,0 +1,1064 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::protos::oak_profile::Profile;
+
+#[derive(Debug, Display, EnumIter, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ProfileSettingGroup {
+    Audio,
+    Graphics,
+    Gameplay,
+    Crosshair,
+    Mouse,
+    Gamepad,
+    Accessibility,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ProfileSettingValue {
+    Bool(bool),
+    Float(f32),
+    Integer(i32),
+}
+
+impl fmt::Display for ProfileSettingValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileSettingValue::Bool(v) => write!(f, "{}", v),
+            ProfileSettingValue::Float(v) => write!(f, "{}", v),
+            ProfileSettingValue::Integer(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProfileSettingKind {
+    Bool,
+    Float { minimum: f32, maximum: f32 },
+    Integer { minimum: i32, maximum: i32 },
+}
+
+impl fmt::Display for ProfileSettingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileSettingKind::Bool => write!(f, "true or false"),
+            ProfileSettingKind::Float { minimum, maximum } => {
+                write!(f, "a number from {} to {}", minimum, maximum)
+            }
+            ProfileSettingKind::Integer { minimum, maximum } => {
+                write!(f, "a whole number from {} to {}", minimum, maximum)
+            }
+        }
+    }
+}
+
+enum ProfileSettingField<'a> {
+    Bool(&'a mut bool),
+    Float(&'a mut f32),
+    Unsigned(&'a mut u32),
+    Signed(&'a mut i32),
+}
+
+#[derive(
+    Debug,
+    Display,
+    EnumIter,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum ProfileSetting {
+    #[strum(to_string = "Master Volume")]
+    MasterVolume,
+    #[strum(to_string = "Music Volume")]
+    MusicVolume,
+    #[strum(to_string = "Sound Effects Volume")]
+    SoundEffectsVolume,
+    #[strum(to_string = "Dialog Volume")]
+    DialogVolume,
+    #[strum(to_string = "Voice Chat Volume")]
+    VoiceChatVolume,
+    #[strum(to_string = "Optional Dialog")]
+    EnableOptionalDialog,
+    #[strum(to_string = "Push To Talk")]
+    PushToTalk,
+    #[strum(to_string = "Mute Audio On Focus Loss")]
+    MuteAudioOnFocusLoss,
+    #[strum(to_string = "Field Of View")]
+    BaseFov,
+    #[strum(to_string = "Vehicle Field Of View")]
+    BaseVehicleFov,
+    #[strum(to_string = "Graphics Quality")]
+    GraphicsQuality,
+    #[strum(to_string = "Texture Detail")]
+    TextureDetail,
+    #[strum(to_string = "Shadow Quality")]
+    ShadowQuality,
+    #[strum(to_string = "Draw Distance")]
+    DrawDistance,
+    #[strum(to_string = "Anisotropic Filtering")]
+    AnisotropicFiltering,
+    #[strum(to_string = "Environment Clutter")]
+    Clutter,
+    #[strum(to_string = "Terrain Detail")]
+    Tessellation,
+    #[strum(to_string = "Foliage Detail")]
+    Foliage,
+    #[strum(to_string = "Volumetric Fog")]
+    VolumetricFog,
+    #[strum(to_string = "Screen Space Reflections")]
+    ScreenSpaceReflections,
+    #[strum(to_string = "Character Texture Detail")]
+    CharacterTextureDetail,
+    #[strum(to_string = "Character Detail")]
+    CharacterDetail,
+    #[strum(to_string = "Ambient Occlusion")]
+    AmbientOcclusionQuality,
+    #[strum(to_string = "Foliage Shadows")]
+    FoliageShadows,
+    #[strum(to_string = "Planar Reflections")]
+    PlanarReflections,
+    #[strum(to_string = "Object Motion Blur")]
+    ObjectMotionBlur,
+    #[strum(to_string = "Lens Flare")]
+    LensFlare,
+    #[strum(to_string = "Difficulty")]
+    Difficulty,
+    #[strum(to_string = "Damage Numbers")]
+    ShowDamageNumbers,
+    #[strum(to_string = "Damage Number Icons")]
+    ShowDamageNumberIcons,
+    #[strum(to_string = "Long Combat Numbers")]
+    CombatNumberLongFormat,
+    #[strum(to_string = "Training Messages")]
+    EnableTrainingMessages,
+    #[strum(to_string = "Text Chat")]
+    ShowTextChat,
+    #[strum(to_string = "Toggle Sprint")]
+    ToggleSprint,
+    #[strum(to_string = "Toggle Crouch")]
+    ToggleCrouch,
+    #[strum(to_string = "Toggle Aim")]
+    WeaponAimToggle,
+    #[strum(to_string = "Mantle Requires Button")]
+    MantleRequiresButton,
+    #[strum(to_string = "Fixed Minimap Rotation")]
+    FixedMinimapRotation,
+    #[strum(to_string = "Legendaries On Minimap")]
+    ShowMinimapLegendaries,
+    #[strum(to_string = "Player Callouts")]
+    UsePlayerCallouts,
+    #[strum(to_string = "Censor Content")]
+    CensorContent,
+    #[strum(to_string = "Use MPH")]
+    UseMph,
+    #[strum(to_string = "Head Bob")]
+    HeadBobScale,
+    #[strum(to_string = "HUD Scale")]
+    HudScaleMultiplier,
+    #[strum(to_string = "Center Crosshair")]
+    CenterCrosshair,
+    #[strum(to_string = "Neutral Colour")]
+    CrosshairNeutralColor,
+    #[strum(to_string = "Enemy Colour")]
+    CrosshairEnemyColor,
+    #[strum(to_string = "Ally Colour")]
+    CrosshairAllyColor,
+    #[strum(to_string = "Sensitivity")]
+    MouseSensitivity,
+    #[strum(to_string = "Aim Sensitivity")]
+    MouseAdsSensitivity,
+    #[strum(to_string = "Vehicle Sensitivity")]
+    MouseVehicleSensitivity,
+    #[strum(to_string = "Invert Pitch")]
+    InvertMousePitch,
+    #[strum(to_string = "Smoothing")]
+    EnableMouseSmoothing,
+    #[strum(to_string = "Acceleration")]
+    EnableMouseAcceleration,
+    #[strum(to_string = "Aim Assist")]
+    MouseIronsightAimAssist,
+    #[strum(to_string = "Aim Assist")]
+    EnableAimAssist,
+    #[strum(to_string = "Aim Down Sights Assist")]
+    IronsightAimAssist,
+    #[strum(to_string = "Vibration")]
+    EnableVibration,
+    #[strum(to_string = "Trigger Feedback")]
+    EnableTriggerFeedback,
+    #[strum(to_string = "Invert Look")]
+    GamepadInvertLook,
+    #[strum(to_string = "Sensitivity")]
+    GamepadHipSensitivity,
+    #[strum(to_string = "Aim Sensitivity")]
+    GamepadZoomedSensitivity,
+    #[strum(to_string = "Vehicle Sensitivity")]
+    GamepadVehicleSensitivity,
+    #[strum(to_string = "Movement Dead Zone X")]
+    GamepadMovementDeadZoneX,
+    #[strum(to_string = "Movement Dead Zone Y")]
+    GamepadMovementDeadZoneY,
+    #[strum(to_string = "Look Inner Dead Zone X")]
+    GamepadLookDeadZoneInnerX,
+    #[strum(to_string = "Look Inner Dead Zone Y")]
+    GamepadLookDeadZoneInnerY,
+    #[strum(to_string = "Look Outer Dead Zone X")]
+    GamepadLookDeadZoneOuterX,
+    #[strum(to_string = "Look Outer Dead Zone Y")]
+    GamepadLookDeadZoneOuterY,
+    #[strum(to_string = "Left Stick Inner Dead Zone")]
+    GamepadLeftDeadZoneInner,
+    #[strum(to_string = "Left Stick Outer Dead Zone")]
+    GamepadLeftDeadZoneOuter,
+    #[strum(to_string = "Right Stick Inner Dead Zone")]
+    GamepadRightDeadZoneInner,
+    #[strum(to_string = "Right Stick Outer Dead Zone")]
+    GamepadRightDeadZoneOuter,
+    #[strum(to_string = "Subtitles")]
+    EnableSubtitles,
+    #[strum(to_string = "Closed Captions")]
+    EnableClosedCaptions,
+    #[strum(to_string = "Subtitle Size")]
+    SubtitleSize,
+    #[strum(to_string = "Subtitle Background Opacity")]
+    SubtitleBackgroundOpacity,
+}
+
+impl ProfileSetting {
+    pub fn group(&self) -> ProfileSettingGroup {
+        match self {
+            ProfileSetting::MasterVolume
+            | ProfileSetting::MusicVolume
+            | ProfileSetting::SoundEffectsVolume
+            | ProfileSetting::DialogVolume
+            | ProfileSetting::VoiceChatVolume
+            | ProfileSetting::EnableOptionalDialog
+            | ProfileSetting::PushToTalk
+            | ProfileSetting::MuteAudioOnFocusLoss => ProfileSettingGroup::Audio,
+            ProfileSetting::BaseFov
+            | ProfileSetting::BaseVehicleFov
+            | ProfileSetting::GraphicsQuality
+            | ProfileSetting::TextureDetail
+            | ProfileSetting::ShadowQuality
+            | ProfileSetting::DrawDistance
+            | ProfileSetting::AnisotropicFiltering
+            | ProfileSetting::Clutter
+            | ProfileSetting::Tessellation
+            | ProfileSetting::Foliage
+            | ProfileSetting::VolumetricFog
+            | ProfileSetting::ScreenSpaceReflections
+            | ProfileSetting::CharacterTextureDetail
+            | ProfileSetting::CharacterDetail
+            | ProfileSetting::AmbientOcclusionQuality
+            | ProfileSetting::FoliageShadows
+            | ProfileSetting::PlanarReflections
+            | ProfileSetting::ObjectMotionBlur
+            | ProfileSetting::LensFlare => ProfileSettingGroup::Graphics,
+            ProfileSetting::Difficulty
+            | ProfileSetting::ShowDamageNumbers
+            | ProfileSetting::ShowDamageNumberIcons
+            | ProfileSetting::CombatNumberLongFormat
+            | ProfileSetting::EnableTrainingMessages
+            | ProfileSetting::ShowTextChat
+            | ProfileSetting::ToggleSprint
+            | ProfileSetting::ToggleCrouch
+            | ProfileSetting::WeaponAimToggle
+            | ProfileSetting::MantleRequiresButton
+            | ProfileSetting::FixedMinimapRotation
+            | ProfileSetting::ShowMinimapLegendaries
+            | ProfileSetting::UsePlayerCallouts
+            | ProfileSetting::CensorContent
+            | ProfileSetting::UseMph
+            | ProfileSetting::HeadBobScale
+            | ProfileSetting::HudScaleMultiplier => ProfileSettingGroup::Gameplay,
+            ProfileSetting::CenterCrosshair
+            | ProfileSetting::CrosshairNeutralColor
+            | ProfileSetting::CrosshairEnemyColor
+            | ProfileSetting::CrosshairAllyColor => ProfileSettingGroup::Crosshair,
+            ProfileSetting::MouseSensitivity
+            | ProfileSetting::MouseAdsSensitivity
+            | ProfileSetting::MouseVehicleSensitivity
+            | ProfileSetting::InvertMousePitch
+            | ProfileSetting::EnableMouseSmoothing
+            | ProfileSetting::EnableMouseAcceleration
+            | ProfileSetting::MouseIronsightAimAssist => ProfileSettingGroup::Mouse,
+            ProfileSetting::EnableAimAssist
+            | ProfileSetting::IronsightAimAssist
+            | ProfileSetting::EnableVibration
+            | ProfileSetting::EnableTriggerFeedback
+            | ProfileSetting::GamepadInvertLook
+            | ProfileSetting::GamepadHipSensitivity
+            | ProfileSetting::GamepadZoomedSensitivity
+            | ProfileSetting::GamepadVehicleSensitivity
+            | ProfileSetting::GamepadMovementDeadZoneX
+            | ProfileSetting::GamepadMovementDeadZoneY
+            | ProfileSetting::GamepadLookDeadZoneInnerX
+            | ProfileSetting::GamepadLookDeadZoneInnerY
+            | ProfileSetting::GamepadLookDeadZoneOuterX
+            | ProfileSetting::GamepadLookDeadZoneOuterY
+            | ProfileSetting::GamepadLeftDeadZoneInner
+            | ProfileSetting::GamepadLeftDeadZoneOuter
+            | ProfileSetting::GamepadRightDeadZoneInner
+            | ProfileSetting::GamepadRightDeadZoneOuter => ProfileSettingGroup::Gamepad,
+            ProfileSetting::EnableSubtitles
+            | ProfileSetting::EnableClosedCaptions
+            | ProfileSetting::SubtitleSize
+            | ProfileSetting::SubtitleBackgroundOpacity => ProfileSettingGroup::Accessibility,
+        }
+    }
+
+    pub fn kind(&self) -> ProfileSettingKind {
+        match self {
+            ProfileSetting::MasterVolume
+            | ProfileSetting::MusicVolume
+            | ProfileSetting::SoundEffectsVolume
+            | ProfileSetting::DialogVolume
+            | ProfileSetting::VoiceChatVolume
+            | ProfileSetting::SubtitleBackgroundOpacity => ProfileSettingKind::Float {
+                minimum: 0.0,
+                maximum: 100.0,
+            },
+            ProfileSetting::EnableOptionalDialog
+            | ProfileSetting::PushToTalk
+            | ProfileSetting::MuteAudioOnFocusLoss
+            | ProfileSetting::FoliageShadows
+            | ProfileSetting::PlanarReflections
+            | ProfileSetting::ObjectMotionBlur
+            | ProfileSetting::LensFlare
+            | ProfileSetting::ShowDamageNumbers
+            | ProfileSetting::ShowDamageNumberIcons
+            | ProfileSetting::CombatNumberLongFormat
+            | ProfileSetting::EnableTrainingMessages
+            | ProfileSetting::ShowTextChat
+            | ProfileSetting::ToggleSprint
+            | ProfileSetting::ToggleCrouch
+            | ProfileSetting::WeaponAimToggle
+            | ProfileSetting::MantleRequiresButton
+            | ProfileSetting::FixedMinimapRotation
+            | ProfileSetting::ShowMinimapLegendaries
+            | ProfileSetting::UsePlayerCallouts
+            | ProfileSetting::CensorContent
+            | ProfileSetting::UseMph
+            | ProfileSetting::CenterCrosshair
+            | ProfileSetting::InvertMousePitch
+            | ProfileSetting::EnableMouseSmoothing
+            | ProfileSetting::EnableMouseAcceleration
+            | ProfileSetting::MouseIronsightAimAssist
+            | ProfileSetting::EnableAimAssist
+            | ProfileSetting::IronsightAimAssist
+            | ProfileSetting::EnableVibration
+            | ProfileSetting::EnableTriggerFeedback
+            | ProfileSetting::GamepadInvertLook
+            | ProfileSetting::EnableSubtitles
+            | ProfileSetting::EnableClosedCaptions => ProfileSettingKind::Bool,
+            ProfileSetting::BaseFov | ProfileSetting::BaseVehicleFov => ProfileSettingKind::Float {
+                minimum: 70.0,
+                maximum: 120.0,
+            },
+            ProfileSetting::GraphicsQuality
+            | ProfileSetting::TextureDetail
+            | ProfileSetting::ShadowQuality
+            | ProfileSetting::DrawDistance
+            | ProfileSetting::AnisotropicFiltering
+            | ProfileSetting::Clutter
+            | ProfileSetting::Tessellation
+            | ProfileSetting::Foliage
+            | ProfileSetting::VolumetricFog
+            | ProfileSetting::ScreenSpaceReflections
+            | ProfileSetting::CharacterTextureDetail
+            | ProfileSetting::CharacterDetail
+            | ProfileSetting::AmbientOcclusionQuality => ProfileSettingKind::Integer {
+                minimum: 0,
+                maximum: 5,
+            },
+            ProfileSetting::Difficulty => ProfileSettingKind::Integer {
+                minimum: 0,
+                maximum: 2,
+            },
+            ProfileSetting::HeadBobScale
+            | ProfileSetting::GamepadMovementDeadZoneX
+            | ProfileSetting::GamepadMovementDeadZoneY
+            | ProfileSetting::GamepadLookDeadZoneInnerX
+            | ProfileSetting::GamepadLookDeadZoneInnerY
+            | ProfileSetting::GamepadLookDeadZoneOuterX
+            | ProfileSetting::GamepadLookDeadZoneOuterY
+            | ProfileSetting::GamepadLeftDeadZoneInner
+            | ProfileSetting::GamepadLeftDeadZoneOuter
+            | ProfileSetting::GamepadRightDeadZoneInner
+            | ProfileSetting::GamepadRightDeadZoneOuter => ProfileSettingKind::Float {
+                minimum: 0.0,
+                maximum: 1.0,
+            },
+            ProfileSetting::HudScaleMultiplier => ProfileSettingKind::Float {
+                minimum: 0.5,
+                maximum: 2.0,
+            },
+            ProfileSetting::CrosshairNeutralColor
+            | ProfileSetting::CrosshairEnemyColor
+            | ProfileSetting::CrosshairAllyColor => ProfileSettingKind::Integer {
+                minimum: 0,
+                maximum: 15,
+            },
+            ProfileSetting::MouseSensitivity
+            | ProfileSetting::MouseAdsSensitivity
+            | ProfileSetting::MouseVehicleSensitivity => ProfileSettingKind::Float {
+                minimum: 0.1,
+                maximum: 100.0,
+            },
+            ProfileSetting::GamepadHipSensitivity
+            | ProfileSetting::GamepadZoomedSensitivity
+            | ProfileSetting::GamepadVehicleSensitivity => ProfileSettingKind::Integer {
+                minimum: 1,
+                maximum: 10,
+            },
+            ProfileSetting::SubtitleSize => ProfileSettingKind::Float {
+                minimum: 50.0,
+                maximum: 200.0,
+            },
+        }
+    }
+
+    pub fn value(&self, profile: &Profile) -> ProfileSettingValue {
+        match self {
+            ProfileSetting::MasterVolume => ProfileSettingValue::Float(profile.master_volume),
+            ProfileSetting::MusicVolume => ProfileSettingValue::Float(profile.music_volume),
+            ProfileSetting::SoundEffectsVolume => {
+                ProfileSettingValue::Float(profile.sound_effects_volume)
+            }
+            ProfileSetting::DialogVolume => ProfileSettingValue::Float(profile.vo_volume),
+            ProfileSetting::VoiceChatVolume => ProfileSettingValue::Float(profile.voice_volume),
+            ProfileSetting::EnableOptionalDialog => {
+                ProfileSettingValue::Bool(profile.enable_optional_vo)
+            }
+            ProfileSetting::PushToTalk => ProfileSettingValue::Bool(profile.push_to_talk),
+            ProfileSetting::MuteAudioOnFocusLoss => {
+                ProfileSettingValue::Bool(profile.mute_audio_on_focus_loss)
+            }
+            ProfileSetting::BaseFov => ProfileSettingValue::Float(profile.base_fov),
+            ProfileSetting::BaseVehicleFov => ProfileSettingValue::Float(profile.base_vehicle_fov),
+            ProfileSetting::GraphicsQuality => {
+                ProfileSettingValue::Integer(profile.graphics_quality as i32)
+            }
+            ProfileSetting::TextureDetail => {
+                ProfileSettingValue::Integer(profile.texture_detail as i32)
+            }
+            ProfileSetting::ShadowQuality => {
+                ProfileSettingValue::Integer(profile.shadow_quality as i32)
+            }
+            ProfileSetting::DrawDistance => {
+                ProfileSettingValue::Integer(profile.draw_distance as i32)
+            }
+            ProfileSetting::AnisotropicFiltering => {
+                ProfileSettingValue::Integer(profile.anisotropic_filtering as i32)
+            }
+            ProfileSetting::Clutter => ProfileSettingValue::Integer(profile.clutter as i32),
+            ProfileSetting::Tessellation => {
+                ProfileSettingValue::Integer(profile.tessellation as i32)
+            }
+            ProfileSetting::Foliage => ProfileSettingValue::Integer(profile.foliage as i32),
+            ProfileSetting::VolumetricFog => {
+                ProfileSettingValue::Integer(profile.volumetric_fog as i32)
+            }
+            ProfileSetting::ScreenSpaceReflections => {
+                ProfileSettingValue::Integer(profile.screen_space_reflections as i32)
+            }
+            ProfileSetting::CharacterTextureDetail => {
+                ProfileSettingValue::Integer(profile.character_texture_detail as i32)
+            }
+            ProfileSetting::CharacterDetail => {
+                ProfileSettingValue::Integer(profile.character_detail as i32)
+            }
+            ProfileSetting::AmbientOcclusionQuality => {
+                ProfileSettingValue::Integer(profile.ambient_occlusion_quality as i32)
+            }
+            ProfileSetting::FoliageShadows => ProfileSettingValue::Bool(profile.foliage_shadows),
+            ProfileSetting::PlanarReflections => {
+                ProfileSettingValue::Bool(profile.planar_reflections)
+            }
+            ProfileSetting::ObjectMotionBlur => {
+                ProfileSettingValue::Bool(profile.object_motion_blur)
+            }
+            ProfileSetting::LensFlare => ProfileSettingValue::Bool(profile.lens_flare),
+            ProfileSetting::Difficulty => ProfileSettingValue::Integer(profile.difficulty as i32),
+            ProfileSetting::ShowDamageNumbers => {
+                ProfileSettingValue::Bool(profile.show_damage_numbers)
+            }
+            ProfileSetting::ShowDamageNumberIcons => {
+                ProfileSettingValue::Bool(profile.show_damage_number_icons)
+            }
+            ProfileSetting::CombatNumberLongFormat => {
+                ProfileSettingValue::Bool(profile.combat_number_long_format)
+            }
+            ProfileSetting::EnableTrainingMessages => {
+                ProfileSettingValue::Bool(profile.enable_training_messages)
+            }
+            ProfileSetting::ShowTextChat => ProfileSettingValue::Bool(profile.show_text_chat),
+            ProfileSetting::ToggleSprint => ProfileSettingValue::Bool(profile.toggle_sprint),
+            ProfileSetting::ToggleCrouch => ProfileSettingValue::Bool(profile.toggle_crouch),
+            ProfileSetting::WeaponAimToggle => ProfileSettingValue::Bool(profile.weapon_aim_toggle),
+            ProfileSetting::MantleRequiresButton => {
+                ProfileSettingValue::Bool(profile.mantle_requires_button)
+            }
+            ProfileSetting::FixedMinimapRotation => {
+                ProfileSettingValue::Bool(profile.fixed_minimap_rotation)
+            }
+            ProfileSetting::ShowMinimapLegendaries => {
+                ProfileSettingValue::Bool(profile.show_minimap_legendaries)
+            }
+            ProfileSetting::UsePlayerCallouts => {
+                ProfileSettingValue::Bool(profile.use_player_callouts)
+            }
+            ProfileSetting::CensorContent => ProfileSettingValue::Bool(profile.censor_content),
+            ProfileSetting::UseMph => ProfileSettingValue::Bool(profile.use_MPH),
+            ProfileSetting::HeadBobScale => ProfileSettingValue::Float(profile.head_bob_scale),
+            ProfileSetting::HudScaleMultiplier => {
+                ProfileSettingValue::Float(profile.hud_scale_multiplier)
+            }
+            ProfileSetting::CenterCrosshair => ProfileSettingValue::Bool(profile.center_crosshair),
+            ProfileSetting::CrosshairNeutralColor => {
+                ProfileSettingValue::Integer(profile.crosshair_neutral_color_frame as i32)
+            }
+            ProfileSetting::CrosshairEnemyColor => {
+                ProfileSettingValue::Integer(profile.crosshair_enemy_color_frame as i32)
+            }
+            ProfileSetting::CrosshairAllyColor => {
+                ProfileSettingValue::Integer(profile.crosshair_ally_color_frame as i32)
+            }
+            ProfileSetting::MouseSensitivity => ProfileSettingValue::Float(profile.mouse_scale),
+            ProfileSetting::MouseAdsSensitivity => {
+                ProfileSettingValue::Float(profile.mouse_ads_scale)
+            }
+            ProfileSetting::MouseVehicleSensitivity => {
+                ProfileSettingValue::Float(profile.mouse_vehicle_scale)
+            }
+            ProfileSetting::InvertMousePitch => {
+                ProfileSettingValue::Bool(profile.invert_mouse_pitch)
+            }
+            ProfileSetting::EnableMouseSmoothing => {
+                ProfileSettingValue::Bool(profile.enable_mouse_smoothing)
+            }
+            ProfileSetting::EnableMouseAcceleration => {
+                ProfileSettingValue::Bool(profile.enable_mouse_acceleration)
+            }
+            ProfileSetting::MouseIronsightAimAssist => {
+                ProfileSettingValue::Bool(profile.mouse_ironsight_aim_assist)
+            }
+            ProfileSetting::EnableAimAssist => ProfileSettingValue::Bool(profile.enable_aim_assist),
+            ProfileSetting::IronsightAimAssist => {
+                ProfileSettingValue::Bool(profile.ironsight_aim_assist)
+            }
+            ProfileSetting::EnableVibration => ProfileSettingValue::Bool(profile.enable_vibration),
+            ProfileSetting::EnableTriggerFeedback => {
+                ProfileSettingValue::Bool(profile.enable_trigger_feedback)
+            }
+            ProfileSetting::GamepadInvertLook => {
+                ProfileSettingValue::Bool(profile.gamepad_invert_look)
+            }
+            ProfileSetting::GamepadHipSensitivity => {
+                ProfileSettingValue::Integer(profile.gamepad_hip_sensitivity_level)
+            }
+            ProfileSetting::GamepadZoomedSensitivity => {
+                ProfileSettingValue::Integer(profile.gamepad_zoomed_sensitivity_level)
+            }
+            ProfileSetting::GamepadVehicleSensitivity => {
+                ProfileSettingValue::Integer(profile.gamepad_vehicle_sensitivity_level)
+            }
+            ProfileSetting::GamepadMovementDeadZoneX => {
+                ProfileSettingValue::Float(profile.gamepad_movement_dead_zone_x)
+            }
+            ProfileSetting::GamepadMovementDeadZoneY => {
+                ProfileSettingValue::Float(profile.gamepad_movement_dead_zone_y)
+            }
+            ProfileSetting::GamepadLookDeadZoneInnerX => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_inner_x)
+            }
+            ProfileSetting::GamepadLookDeadZoneInnerY => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_inner_y)
+            }
+            ProfileSetting::GamepadLookDeadZoneOuterX => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_outer_x)
+            }
+            ProfileSetting::GamepadLookDeadZoneOuterY => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_outer_y)
+            }
+            ProfileSetting::GamepadLeftDeadZoneInner => {
+                ProfileSettingValue::Float(profile.gamepad_left_dead_zone_inner)
+            }
+            ProfileSetting::GamepadLeftDeadZoneOuter => {
+                ProfileSettingValue::Float(profile.gamepad_left_dead_zone_outer)
+            }
+            ProfileSetting::GamepadRightDeadZoneInner => {
+                ProfileSettingValue::Float(profile.gamepad_right_dead_zone_inner)
+            }
+            ProfileSetting::GamepadRightDeadZoneOuter => {
+                ProfileSettingValue::Float(profile.gamepad_right_dead_zone_outer)
+            }
+            ProfileSetting::EnableSubtitles => ProfileSettingValue::Bool(profile.enable_subtitles),
+            ProfileSetting::EnableClosedCaptions => {
+                ProfileSettingValue::Bool(profile.enable_closed_captions)
+            }
+            ProfileSetting::SubtitleSize => ProfileSettingValue::Float(profile.subs_cc_size),
+            ProfileSetting::SubtitleBackgroundOpacity => {
+                ProfileSettingValue::Float(profile.cc_subs_background_opacity)
+            }
+        }
+    }
+
+    fn field<'a>(&self, profile: &'a mut Profile) -> ProfileSettingField<'a> {
+        match self {
+            ProfileSetting::MasterVolume => ProfileSettingField::Float(&mut profile.master_volume),
+            ProfileSetting::MusicVolume => ProfileSettingField::Float(&mut profile.music_volume),
+            ProfileSetting::SoundEffectsVolume => {
+                ProfileSettingField::Float(&mut profile.sound_effects_volume)
+            }
+            ProfileSetting::DialogVolume => ProfileSettingField::Float(&mut profile.vo_volume),
+            ProfileSetting::VoiceChatVolume => {
+                ProfileSettingField::Float(&mut profile.voice_volume)
+            }
+            ProfileSetting::EnableOptionalDialog => {
+                ProfileSettingField::Bool(&mut profile.enable_optional_vo)
+            }
+            ProfileSetting::PushToTalk => ProfileSettingField::Bool(&mut profile.push_to_talk),
+            ProfileSetting::MuteAudioOnFocusLoss => {
+                ProfileSettingField::Bool(&mut profile.mute_audio_on_focus_loss)
+            }
+            ProfileSetting::BaseFov => ProfileSettingField::Float(&mut profile.base_fov),
+            ProfileSetting::BaseVehicleFov => {
+                ProfileSettingField::Float(&mut profile.base_vehicle_fov)
+            }
+            ProfileSetting::GraphicsQuality => {
+                ProfileSettingField::Unsigned(&mut profile.graphics_quality)
+            }
+            ProfileSetting::TextureDetail => {
+                ProfileSettingField::Unsigned(&mut profile.texture_detail)
+            }
+            ProfileSetting::ShadowQuality => {
+                ProfileSettingField::Unsigned(&mut profile.shadow_quality)
+            }
+            ProfileSetting::DrawDistance => {
+                ProfileSettingField::Unsigned(&mut profile.draw_distance)
+            }
+            ProfileSetting::AnisotropicFiltering => {
+                ProfileSettingField::Unsigned(&mut profile.anisotropic_filtering)
+            }
+            ProfileSetting::Clutter => ProfileSettingField::Unsigned(&mut profile.clutter),
+            ProfileSetting::Tessellation => {
+                ProfileSettingField::Unsigned(&mut profile.tessellation)
+            }
+            ProfileSetting::Foliage => ProfileSettingField::Unsigned(&mut profile.foliage),
+            ProfileSetting::VolumetricFog => {
+                ProfileSettingField::Unsigned(&mut profile.volumetric_fog)
+            }
+            ProfileSetting::ScreenSpaceReflections => {
+                ProfileSettingField::Unsigned(&mut profile.screen_space_reflections)
+            }
+            ProfileSetting::CharacterTextureDetail => {
+                ProfileSettingField::Unsigned(&mut profile.character_texture_detail)
+            }
+            ProfileSetting::CharacterDetail => {
+                ProfileSettingField::Unsigned(&mut profile.character_detail)
+            }
+            ProfileSetting::AmbientOcclusionQuality => {
+                ProfileSettingField::Unsigned(&mut profile.ambient_occlusion_quality)
+            }
+            ProfileSetting::FoliageShadows => {
+                ProfileSettingField::Bool(&mut profile.foliage_shadows)
+            }
+            ProfileSetting::PlanarReflections => {
+                ProfileSettingField::Bool(&mut profile.planar_reflections)
+            }
+            ProfileSetting::ObjectMotionBlur => {
+                ProfileSettingField::Bool(&mut profile.object_motion_blur)
+            }
+            ProfileSetting::LensFlare => ProfileSettingField::Bool(&mut profile.lens_flare),
+            ProfileSetting::Difficulty => ProfileSettingField::Unsigned(&mut profile.difficulty),
+            ProfileSetting::ShowDamageNumbers => {
+                ProfileSettingField::Bool(&mut profile.show_damage_numbers)
+            }
+            ProfileSetting::ShowDamageNumberIcons => {
+                ProfileSettingField::Bool(&mut profile.show_damage_number_icons)
+            }
+            ProfileSetting::CombatNumberLongFormat => {
+                ProfileSettingField::Bool(&mut profile.combat_number_long_format)
+            }
+            ProfileSetting::EnableTrainingMessages => {
+                ProfileSettingField::Bool(&mut profile.enable_training_messages)
+            }
+            ProfileSetting::ShowTextChat => ProfileSettingField::Bool(&mut profile.show_text_chat),
+            ProfileSetting::ToggleSprint => ProfileSettingField::Bool(&mut profile.toggle_sprint),
+            ProfileSetting::ToggleCrouch => ProfileSettingField::Bool(&mut profile.toggle_crouch),
+            ProfileSetting::WeaponAimToggle => {
+                ProfileSettingField::Bool(&mut profile.weapon_aim_toggle)
+            }
+            ProfileSetting::MantleRequiresButton => {
+                ProfileSettingField::Bool(&mut profile.mantle_requires_button)
+            }
+            ProfileSetting::FixedMinimapRotation => {
+                ProfileSettingField::Bool(&mut profile.fixed_minimap_rotation)
+            }
+            ProfileSetting::ShowMinimapLegendaries => {
+                ProfileSettingField::Bool(&mut profile.show_minimap_legendaries)
+            }
+            ProfileSetting::UsePlayerCallouts => {
+                ProfileSettingField::Bool(&mut profile.use_player_callouts)
+            }
+            ProfileSetting::CensorContent => ProfileSettingField::Bool(&mut profile.censor_content),
+            ProfileSetting::UseMph => ProfileSettingField::Bool(&mut profile.use_MPH),
+            ProfileSetting::HeadBobScale => ProfileSettingField::Float(&mut profile.head_bob_scale),
+            ProfileSetting::HudScaleMultiplier => {
+                ProfileSettingField::Float(&mut profile.hud_scale_multiplier)
+            }
+            ProfileSetting::CenterCrosshair => {
+                ProfileSettingField::Bool(&mut profile.center_crosshair)
+            }
+            ProfileSetting::CrosshairNeutralColor => {
+                ProfileSettingField::Unsigned(&mut profile.crosshair_neutral_color_frame)
+            }
+            ProfileSetting::CrosshairEnemyColor => {
+                ProfileSettingField::Unsigned(&mut profile.crosshair_enemy_color_frame)
+            }
+            ProfileSetting::CrosshairAllyColor => {
+                ProfileSettingField::Unsigned(&mut profile.crosshair_ally_color_frame)
+            }
+            ProfileSetting::MouseSensitivity => {
+                ProfileSettingField::Float(&mut profile.mouse_scale)
+            }
+            ProfileSetting::MouseAdsSensitivity => {
+                ProfileSettingField::Float(&mut profile.mouse_ads_scale)
+            }
+            ProfileSetting::MouseVehicleSensitivity => {
+                ProfileSettingField::Float(&mut profile.mouse_vehicle_scale)
+            }
+            ProfileSetting::InvertMousePitch => {
+                ProfileSettingField::Bool(&mut profile.invert_mouse_pitch)
+            }
+            ProfileSetting::EnableMouseSmoothing => {
+                ProfileSettingField::Bool(&mut profile.enable_mouse_smoothing)
+            }
+            ProfileSetting::EnableMouseAcceleration => {
+                ProfileSettingField::Bool(&mut profile.enable_mouse_acceleration)
+            }
+            ProfileSetting::MouseIronsightAimAssist => {
+                ProfileSettingField::Bool(&mut profile.mouse_ironsight_aim_assist)
+            }
+            ProfileSetting::EnableAimAssist => {
+                ProfileSettingField::Bool(&mut profile.enable_aim_assist)
+            }
+            ProfileSetting::IronsightAimAssist => {
+                ProfileSettingField::Bool(&mut profile.ironsight_aim_assist)
+            }
+            ProfileSetting::EnableVibration => {
+                ProfileSettingField::Bool(&mut profile.enable_vibration)
+            }
+            ProfileSetting::EnableTriggerFeedback => {
+                ProfileSettingField::Bool(&mut profile.enable_trigger_feedback)
+            }
+            ProfileSetting::GamepadInvertLook => {
+                ProfileSettingField::Bool(&mut profile.gamepad_invert_look)
+            }
+            ProfileSetting::GamepadHipSensitivity => {
+                ProfileSettingField::Signed(&mut profile.gamepad_hip_sensitivity_level)
+            }
+            ProfileSetting::GamepadZoomedSensitivity => {
+                ProfileSettingField::Signed(&mut profile.gamepad_zoomed_sensitivity_level)
+            }
+            ProfileSetting::GamepadVehicleSensitivity => {
+                ProfileSettingField::Signed(&mut profile.gamepad_vehicle_sensitivity_level)
+            }
+            ProfileSetting::GamepadMovementDeadZoneX => {
+                ProfileSettingField::Float(&mut profile.gamepad_movement_dead_zone_x)
+            }
+            ProfileSetting::GamepadMovementDeadZoneY => {
+                ProfileSettingField::Float(&mut profile.gamepad_movement_dead_zone_y)
+            }
+            ProfileSetting::GamepadLookDeadZoneInnerX => {
+                ProfileSettingField::Float(&mut profile.gamepad_look_dead_zone_inner_x)
+            }
+            ProfileSetting::GamepadLookDeadZoneInnerY => {
+                ProfileSettingField::Float(&mut profile.gamepad_look_dead_zone_inner_y)
+            }
+            ProfileSetting::GamepadLookDeadZoneOuterX => {
+                ProfileSettingField::Float(&mut profile.gamepad_look_dead_zone_outer_x)
+            }
+            ProfileSetting::GamepadLookDeadZoneOuterY => {
+                ProfileSettingField::Float(&mut profile.gamepad_look_dead_zone_outer_y)
+            }
+            ProfileSetting::GamepadLeftDeadZoneInner => {
+                ProfileSettingField::Float(&mut profile.gamepad_left_dead_zone_inner)
+            }
+            ProfileSetting::GamepadLeftDeadZoneOuter => {
+                ProfileSettingField::Float(&mut profile.gamepad_left_dead_zone_outer)
+            }
+            ProfileSetting::GamepadRightDeadZoneInner => {
+                ProfileSettingField::Float(&mut profile.gamepad_right_dead_zone_inner)
+            }
+            ProfileSetting::GamepadRightDeadZoneOuter => {
+                ProfileSettingField::Float(&mut profile.gamepad_right_dead_zone_outer)
+            }
+            ProfileSetting::EnableSubtitles => {
+                ProfileSettingField::Bool(&mut profile.enable_subtitles)
+            }
+            ProfileSetting::EnableClosedCaptions => {
+                ProfileSettingField::Bool(&mut profile.enable_closed_captions)
+            }
+            ProfileSetting::SubtitleSize => ProfileSettingField::Float(&mut profile.subs_cc_size),
+            ProfileSetting::SubtitleBackgroundOpacity => {
+                ProfileSettingField::Float(&mut profile.cc_subs_background_opacity)
+            }
+        }
+    }
+
+    /// Checks `value` has the right type and is within the range the game allows for this setting.
+    pub fn validate(&self, value: ProfileSettingValue) -> Result<ProfileSettingValue> {
+        let is_valid = match (self.kind(), value) {
+            (ProfileSettingKind::Bool, ProfileSettingValue::Bool(_)) => true,
+            (ProfileSettingKind::Float { minimum, maximum }, ProfileSettingValue::Float(v)) => {
+                v.is_finite() && v >= minimum && v <= maximum
+            }
+            (ProfileSettingKind::Integer { minimum, maximum }, ProfileSettingValue::Integer(v)) => {
+                v >= minimum && v <= maximum
+            }
+            _ => false,
+        };
+
+        if !is_valid {
+            bail!("{} must be {}, got: {}", self, self.kind(), value);
+        }
+
+        Ok(value)
+    }
+
+    pub fn parse(&self, value: &str) -> Result<ProfileSettingValue> {
+        let value = value.trim();
+
+        let parsed = match self.kind() {
+            ProfileSettingKind::Bool => value.parse().map(ProfileSettingValue::Bool).ok(),
+            ProfileSettingKind::Float { .. } => value.parse().map(ProfileSettingValue::Float).ok(),
+            ProfileSettingKind::Integer { .. } => {
+                value.parse().map(ProfileSettingValue::Integer).ok()
+            }
+        }
+        .with_context(|| format!("{} has an invalid value: {}", self, value))?;
+
+        self.validate(parsed)
+    }
+
+    pub fn apply(&self, profile: &mut Profile, value: ProfileSettingValue) -> Result<()> {
+        let value = self.validate(value)?;
+
+        match (self.field(profile), value) {
+            (ProfileSettingField::Bool(f), ProfileSettingValue::Bool(v)) => *f = v,
+            (ProfileSettingField::Float(f), ProfileSettingValue::Float(v)) => *f = v,
+            (ProfileSettingField::Unsigned(f), ProfileSettingValue::Integer(v)) => *f = v as u32,
+            (ProfileSettingField::Signed(f), ProfileSettingValue::Integer(v)) => *f = v,
+            _ => bail!("{} has an invalid value: {}", self, value),
+        }
+
+        Ok(())
+    }
+}
+
+/// A set of profile settings that can be shared between profiles, stored as ron.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileSettingsPreset {
+    pub settings: BTreeMap<ProfileSetting, ProfileSettingValue>,
+}
+
+impl ProfileSettingsPreset {
+    /// Settings the game has never written are left at 0 which may be out of range, these are
+    /// left out of the preset.
+    pub fn from_profile(profile: &Profile) -> Self {
+        Self {
+            settings: ProfileSetting::iter()
+                .filter_map(|s| s.validate(s.value(profile)).ok().map(|v| (s, v)))
+                .collect(),
+        }
+    }
+
+    pub fn from_ron(data: &str) -> Result<Self> {
+        let preset: Self = ron::de::from_str(data).context("failed to read settings preset")?;
+
+        for (setting, value) in &preset.settings {
+            setting.validate(*value)?;
+        }
+
+        Ok(preset)
+    }
+
+    pub fn to_ron(&self) -> Result<String> {
+        ron::ser::to_string_pretty(self, Default::default())
+            .context("failed to write settings preset")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_profile;
+
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        for setting in ProfileSetting::iter() {
+            match setting.kind() {
+                ProfileSettingKind::Bool => {
+                    assert!(setting.validate(ProfileSettingValue::Bool(true)).is_ok());
+                    assert!(setting.validate(ProfileSettingValue::Integer(1)).is_err());
+                }
+                ProfileSettingKind::Float { minimum, maximum } => {
+                    assert!(setting
+                        .validate(ProfileSettingValue::Float(minimum))
+                        .is_ok());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Float(maximum))
+                        .is_ok());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Float(minimum - 0.01))
+                        .is_err());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Float(maximum + 0.01))
+                        .is_err());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Float(f32::NAN))
+                        .is_err());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Float(f32::INFINITY))
+                        .is_err());
+                    assert!(setting.validate(ProfileSettingValue::Bool(true)).is_err());
+                }
+                ProfileSettingKind::Integer { minimum, maximum } => {
+                    assert!(setting
+                        .validate(ProfileSettingValue::Integer(minimum))
+                        .is_ok());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Integer(maximum))
+                        .is_ok());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Integer(minimum - 1))
+                        .is_err());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Integer(maximum + 1))
+                        .is_err());
+                    assert!(setting
+                        .validate(ProfileSettingValue::Float(minimum as f32))
+                        .is_err());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            ProfileSetting::ToggleSprint
+                .parse("true")
+                .expect("failed to parse setting"),
+            ProfileSettingValue::Bool(true)
+        );
+        assert_eq!(
+            ProfileSetting::BaseFov
+                .parse(" 95.5 ")
+                .expect("failed to parse setting"),
+            ProfileSettingValue::Float(95.5)
+        );
+        assert_eq!(
+            ProfileSetting::GraphicsQuality
+                .parse("3")
+                .expect("failed to parse setting"),
+            ProfileSettingValue::Integer(3)
+        );
+
+        assert!(ProfileSetting::ToggleSprint.parse("yes").is_err());
+        assert!(ProfileSetting::BaseFov.parse("").is_err());
+        assert!(ProfileSetting::BaseFov.parse("60").is_err());
+        assert!(ProfileSetting::GraphicsQuality.parse("2.5").is_err());
+        assert!(ProfileSetting::GraphicsQuality.parse("6").is_err());
+    }
+
+    #[test]
+    fn test_preset_round_trip() {
+        let source = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let preset = source.profile_data.settings_preset();
+
+        assert!(!preset.settings.is_empty());
+
+        for (setting, value) in &preset.settings {
+            assert!(setting.validate(*value).is_ok());
+        }
+
+        let preset_ron = preset.to_ron().expect("failed to write preset");
+
+        assert_eq!(
+            ProfileSettingsPreset::from_ron(&preset_ron).expect("failed to read preset"),
+            preset
+        );
+
+        let mut target = read_test_profile("2profps4.sav", HeaderType::Ps4Profile);
+
+        target
+            .profile_data
+            .apply_settings_preset(&preset)
+            .expect("failed to apply preset");
+
+        for (setting, value) in &preset.settings {
+            assert_eq!(target.profile_data.setting(setting), *value);
+        }
+
+        let (_, target) = target.as_bytes().expect("failed to write test profile");
+
+        for (setting, value) in &preset.settings {
+            assert_eq!(target.profile_data.setting(setting), *value);
+        }
+    }
+
+    #[test]
+    fn test_invalid_preset_rejected() {
+        // Out of range
+        assert!(ProfileSettingsPreset::from_ron("(settings: {BaseFov: Float(200.0)})").is_err());
+        // Wrong type
+        assert!(ProfileSettingsPreset::from_ron("(settings: {BaseFov: Bool(true)})").is_err());
+        // Unknown setting
+        assert!(ProfileSettingsPreset::from_ron("(settings: {NotASetting: Bool(true)})").is_err());
+        assert!(ProfileSettingsPreset::from_ron("not a preset").is_err());
+
+        assert_eq!(
+            ProfileSettingsPreset::from_ron("(settings: {BaseFov: Float(90.0)})")
+                .expect("failed to read preset")
+                .settings
+                .get(&ProfileSetting::BaseFov),
+            Some(&ProfileSettingValue::Float(90.0))
+        );
+
+        // A bad value doesn't leave the profile half changed
+        let mut bl3_profile = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let before = bl3_profile.profile_data.settings_preset();
+
+        let mut preset = ProfileSettingsPreset::default();
+
+        preset
+            .settings
+            .insert(ProfileSetting::BaseFov, ProfileSettingValue::Float(90.0));
+        preset
+            .settings
+            .insert(ProfileSetting::Difficulty, ProfileSettingValue::Integer(7));
+
+        assert!(bl3_profile
+            .profile_data
+            .apply_settings_preset(&preset)
+            .is_err());
+        assert_eq!(bl3_profile.profile_data.settings_preset(), before);
+    }
+}
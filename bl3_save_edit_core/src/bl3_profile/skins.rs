@@ -1,3 +1,6 @@
+use std::fmt;
+
+use crate::bl3_save::player_class::PlayerClass;
 use crate::game_data::{
     GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES,
     PROFILE_EMOTES_DEFAULTS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_ROOM_DECORATIONS,
@@ -16,7 +19,7 @@ impl ProfileSkinData {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ProfileSkinType {
     Regular(SkinSet),
     Weapon(WeaponSkinSet),
@@ -28,7 +31,7 @@ impl std::default::Default for ProfileSkinType {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum SkinSet {
     CharacterSkins,
     CharacterHeads,
@@ -37,13 +40,39 @@ pub enum SkinSet {
     RoomDecorations,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum WeaponSkinSet {
     WeaponSkins,
     WeaponTrinkets,
 }
 
+impl fmt::Display for ProfileSkinType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ProfileSkinType::Regular(SkinSet::CharacterSkins) => "Character Skins",
+            ProfileSkinType::Regular(SkinSet::CharacterHeads) => "Character Heads",
+            ProfileSkinType::Regular(SkinSet::EchoThemes) => "Echo Themes",
+            ProfileSkinType::Regular(SkinSet::Emotes) => "Emotes",
+            ProfileSkinType::Regular(SkinSet::RoomDecorations) => "Room Decorations",
+            ProfileSkinType::Weapon(WeaponSkinSet::WeaponSkins) => "Weapon Skins",
+            ProfileSkinType::Weapon(WeaponSkinSet::WeaponTrinkets) => "Weapon Trinkets",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
 impl ProfileSkinType {
+    pub const ALL: [ProfileSkinType; 7] = [
+        ProfileSkinType::Regular(SkinSet::CharacterSkins),
+        ProfileSkinType::Regular(SkinSet::CharacterHeads),
+        ProfileSkinType::Regular(SkinSet::EchoThemes),
+        ProfileSkinType::Regular(SkinSet::Emotes),
+        ProfileSkinType::Regular(SkinSet::RoomDecorations),
+        ProfileSkinType::Weapon(WeaponSkinSet::WeaponSkins),
+        ProfileSkinType::Weapon(WeaponSkinSet::WeaponTrinkets),
+    ];
+
     pub fn maximum(&self) -> usize {
         match self {
             ProfileSkinType::Regular(regular_skin_set) => match regular_skin_set {
@@ -62,6 +91,24 @@ impl ProfileSkinType {
         }
     }
 
+    /// The items of this type that have to be unlocked, the defaults every profile has are left
+    /// out.
+    pub fn unlockables(&self) -> &'static [GameDataKv] {
+        match self {
+            ProfileSkinType::Regular(regular_skin_set) => match regular_skin_set {
+                SkinSet::CharacterSkins => &PROFILE_SKINS,
+                SkinSet::CharacterHeads => &PROFILE_HEADS,
+                SkinSet::EchoThemes => &PROFILE_ECHO_THEMES,
+                SkinSet::Emotes => &PROFILE_EMOTES,
+                SkinSet::RoomDecorations => &PROFILE_ROOM_DECORATIONS,
+            },
+            ProfileSkinType::Weapon(weapon_skin_set) => match weapon_skin_set {
+                WeaponSkinSet::WeaponSkins => &PROFILE_WEAPON_SKINS,
+                WeaponSkinSet::WeaponTrinkets => &PROFILE_WEAPON_TRINKETS,
+            },
+        }
+    }
+
     pub fn skin_set(&self) -> Vec<GameDataKv> {
         match self {
             ProfileSkinType::Regular(regular_skin_set) => match regular_skin_set {
@@ -88,3 +135,225 @@ impl ProfileSkinType {
         }
     }
 }
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ProfileCosmetic {
+    pub skin_type: ProfileSkinType,
+    pub ident: &'static str,
+    pub name: &'static str,
+    /// `None` when every class can use it.
+    pub class: Option<PlayerClass>,
+    pub dlc: String,
+    pub unlocked: bool,
+}
+
+impl ProfileCosmetic {
+    pub fn new(skin_type: ProfileSkinType, gd: &GameDataKv, unlocked: bool) -> Self {
+        let class = match skin_type {
+            ProfileSkinType::Regular(SkinSet::CharacterSkins)
+            | ProfileSkinType::Regular(SkinSet::CharacterHeads)
+            | ProfileSkinType::Regular(SkinSet::Emotes) => PlayerClass::from_asset_path(gd.ident),
+            _ => None,
+        };
+
+        Self {
+            skin_type,
+            ident: gd.ident,
+            name: gd.name,
+            class,
+            dlc: cosmetic_dlc(gd.ident),
+            unlocked,
+        }
+    }
+}
+
+/// The content an item was released with, taken from the `PatchDLC` folder it is stored in.
+pub fn cosmetic_dlc(ident: &str) -> String {
+    let dlc_folder = match ident
+        .strip_prefix("/Game/PatchDLC/")
+        .and_then(|p| p.split('/').next())
+    {
+        Some(dlc_folder) => dlc_folder,
+        None => return "Base Game".to_owned(),
+    };
+
+    let name = match dlc_folder {
+        "Alisma" => "Psycho Krieg and the Fantastic Fustercluck",
+        "BloodyHarvest" => "Bloody Harvest",
+        "CitizenScience" => "Borderlands Science",
+        "Customizations" => "Bonus Customizations",
+        "Dandelion" => "Moxxi's Heist of the Handsome Jackpot",
+        "Event2" => "Revenge of the Cartels",
+        "EventVDay" => "Broken Hearts Day",
+        "Geranium" => "Bounty of Blood",
+        "Hibiscus" => "Guns, Love, and Tentacles",
+        "Ixora" => "Designer's Cut",
+        "Raid1" => "Takedown at the Maliwan Blacksite",
+        "Steam" => "Steam",
+        "Takedown2" => "Takedown at the Guardian Breach",
+        "VaultCard" => "Vault Card 1",
+        "VaultCard2" => "Vault Card 2",
+        "VaultCard3" => "Vault Card 3",
+        other => other,
+    };
+
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bl3_profile::profile_data::ProfileData;
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_profile;
+
+    use super::*;
+
+    fn unlocked_count(profile_data: &ProfileData, skin_type: &ProfileSkinType) -> usize {
+        match skin_type {
+            ProfileSkinType::Regular(SkinSet::CharacterSkins) => {
+                profile_data.character_skins_unlocked()
+            }
+            ProfileSkinType::Regular(SkinSet::CharacterHeads) => {
+                profile_data.character_heads_unlocked()
+            }
+            ProfileSkinType::Regular(SkinSet::EchoThemes) => profile_data.echo_themes_unlocked(),
+            ProfileSkinType::Regular(SkinSet::Emotes) => profile_data.profile_emotes_unlocked(),
+            ProfileSkinType::Regular(SkinSet::RoomDecorations) => {
+                profile_data.room_decorations_unlocked()
+            }
+            ProfileSkinType::Weapon(WeaponSkinSet::WeaponSkins) => {
+                profile_data.weapon_skins_unlocked()
+            }
+            ProfileSkinType::Weapon(WeaponSkinSet::WeaponTrinkets) => {
+                profile_data.weapon_trinkets_unlocked()
+            }
+        }
+    }
+
+    #[test]
+    fn test_cosmetics() {
+        let bl3_profile = read_test_profile("1prof.sav", HeaderType::PcProfile);
+
+        let cosmetics = bl3_profile.profile_data.cosmetics();
+
+        for skin_type in ProfileSkinType::ALL.iter() {
+            let of_type = cosmetics
+                .iter()
+                .filter(|c| c.skin_type == *skin_type)
+                .collect::<Vec<_>>();
+
+            assert_eq!(of_type.len(), skin_type.unlockables().len());
+            assert!(of_type
+                .iter()
+                .all(|c| !c.name.is_empty() && !c.dlc.is_empty()));
+            assert!(of_type.iter().any(|c| c.unlocked), "{}", skin_type);
+
+            // Only character specific items belong to a class
+            match skin_type {
+                ProfileSkinType::Regular(SkinSet::CharacterSkins)
+                | ProfileSkinType::Regular(SkinSet::CharacterHeads) => {
+                    assert!(of_type.iter().all(|c| c.class.is_some()), "{}", skin_type)
+                }
+                ProfileSkinType::Regular(SkinSet::Emotes) => {
+                    assert!(of_type.iter().any(|c| c.class.is_some()))
+                }
+                _ => assert!(of_type.iter().all(|c| c.class.is_none()), "{}", skin_type),
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_cosmetic_unlocked() {
+        let mut bl3_profile = read_test_profile("profile.sav", HeaderType::PcProfile);
+
+        let mut unlocked = vec![];
+
+        for skin_type in ProfileSkinType::ALL.iter() {
+            let cosmetic = bl3_profile
+                .profile_data
+                .cosmetics()
+                .into_iter()
+                .find(|c| c.skin_type == *skin_type && !c.unlocked)
+                .unwrap_or_else(|| panic!("failed to find a locked {}", skin_type));
+
+            let count = unlocked_count(&bl3_profile.profile_data, skin_type);
+
+            bl3_profile
+                .profile_data
+                .set_cosmetic_unlocked(skin_type, cosmetic.ident, true)
+                .expect("failed to unlock cosmetic");
+
+            // Unlocking twice doesn't add it again
+            bl3_profile
+                .profile_data
+                .set_cosmetic_unlocked(skin_type, cosmetic.ident, true)
+                .expect("failed to unlock cosmetic");
+
+            assert!(bl3_profile
+                .profile_data
+                .is_cosmetic_unlocked(skin_type, cosmetic.ident));
+            assert_eq!(
+                unlocked_count(&bl3_profile.profile_data, skin_type),
+                count + 1
+            );
+
+            unlocked.push((*skin_type, cosmetic.ident, count + 1));
+        }
+
+        let (_, mut bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        for (skin_type, ident, count) in unlocked {
+            assert!(bl3_profile
+                .profile_data
+                .is_cosmetic_unlocked(&skin_type, ident));
+            assert_eq!(unlocked_count(&bl3_profile.profile_data, &skin_type), count);
+
+            bl3_profile
+                .profile_data
+                .set_cosmetic_unlocked(&skin_type, ident, false)
+                .expect("failed to lock cosmetic");
+
+            assert!(!bl3_profile
+                .profile_data
+                .is_cosmetic_unlocked(&skin_type, ident));
+            assert_eq!(
+                unlocked_count(&bl3_profile.profile_data, &skin_type),
+                count - 1
+            );
+        }
+
+        // Items of another type or that don't exist can't be unlocked
+        let skin = ProfileSkinType::Regular(SkinSet::CharacterSkins).unlockables()[0].ident;
+
+        assert!(bl3_profile
+            .profile_data
+            .set_cosmetic_unlocked(&ProfileSkinType::Regular(SkinSet::EchoThemes), skin, true)
+            .is_err());
+        assert!(bl3_profile
+            .profile_data
+            .set_cosmetic_unlocked(
+                &ProfileSkinType::Regular(SkinSet::CharacterSkins),
+                "/Game/Not/A/Skin.Skin",
+                true
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_cosmetic_dlc() {
+        assert_eq!(
+            cosmetic_dlc("/Game/PlayerCharacters/_Customizations/Beastmaster/Heads/CustomHead_Beastmaster_4.CustomHead_Beastmaster_4"),
+            "Base Game"
+        );
+        assert_eq!(
+            cosmetic_dlc("/Game/PatchDLC/Dandelion/Gear/WeaponSkins/WeaponSkin_Dandelion.WeaponSkin_Dandelion"),
+            "Moxxi's Heist of the Handsome Jackpot"
+        );
+        assert_eq!(
+            cosmetic_dlc("/Game/PatchDLC/SomethingNew/Skin.Skin"),
+            "SomethingNew"
+        );
+    }
+}
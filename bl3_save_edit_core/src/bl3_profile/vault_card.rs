@@ -0,0 +1,392 @@
+use crate::bl3_item::Bl3Item;
+use crate::game_data::BALANCE_NAME_MAPPING;
+use crate::protos::oak_shared::{VaultCardRewardList, VaultCardSaveGameData};
+use crate::resources::LOOTLEMON_ITEMS;
+
+pub const VAULT_CARD_IDS: [u32; 3] = [1, 2, 3];
+pub const VAULT_CARD_MAX_LEVEL: i32 = 100;
+/// Approximate, not taken from game data or checked against a profile with Vault Card progress.
+pub const VAULT_CARD_EXPERIENCE_PER_LEVEL: i64 = 10_000;
+
+pub fn vault_card_level_to_experience(level: i32) -> i64 {
+    (level.clamp(1, VAULT_CARD_MAX_LEVEL) - 1) as i64 * VAULT_CARD_EXPERIENCE_PER_LEVEL
+}
+
+pub fn vault_card_experience_to_level(experience: i64) -> i32 {
+    let level = experience.max(0) / VAULT_CARD_EXPERIENCE_PER_LEVEL + 1;
+
+    level.min(VAULT_CARD_MAX_LEVEL as i64) as i32
+}
+
+pub fn vault_card_experience_for_level(level: i32, current_experience: i64) -> i64 {
+    if vault_card_experience_to_level(current_experience) == level {
+        current_experience
+    } else {
+        vault_card_level_to_experience(level)
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VaultCardData {
+    pub last_active_vault_card_id: u32,
+    pub cards: Vec<VaultCard>,
+    pub previous_challenges: Vec<VaultCardPreviousChallengeData>,
+}
+
+impl VaultCardData {
+    /// Every known card is included, cards the profile hasn't started yet are left empty.
+    pub fn from_save_game_data(data: Option<&VaultCardSaveGameData>) -> Self {
+        let cards = VAULT_CARD_IDS
+            .iter()
+            .map(|id| {
+                data.and_then(|d| {
+                    d.vault_card_claimed_rewards
+                        .iter()
+                        .find(|r| r.vault_card_id == *id)
+                })
+                .map(VaultCard::from_reward_list)
+                .unwrap_or_else(|| VaultCard::new(*id))
+            })
+            .collect();
+
+        let previous_challenges = data
+            .map(|d| {
+                d.vault_card_previous_challenges
+                    .iter()
+                    .map(|c| VaultCardPreviousChallengeData {
+                        seed: c.previous_challenge_seed,
+                        challenge_id: c.previous_challenge_id,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            last_active_vault_card_id: data.map(|d| d.last_active_vault_card_id).unwrap_or(0),
+            cards,
+            previous_challenges,
+        }
+    }
+
+    pub fn card(&self, vault_card_id: u32) -> Option<&VaultCard> {
+        self.cards.iter().find(|c| c.id == vault_card_id)
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VaultCard {
+    pub id: u32,
+    pub experience: i64,
+    pub chests: i32,
+    pub chests_opened: u32,
+    pub keys_spent: u32,
+    pub unlocked_rewards: Vec<VaultCardRewardData>,
+    pub redeemed_rewards: Vec<VaultCardRewardData>,
+    pub gear_rewards: Vec<VaultCardGearRewardData>,
+}
+
+impl VaultCard {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+
+    fn from_reward_list(reward_list: &VaultCardRewardList) -> Self {
+        Self {
+            id: reward_list.vault_card_id,
+            experience: reward_list.vault_card_experience,
+            chests: reward_list.vault_card_chests,
+            chests_opened: reward_list.vault_card_chests_opened,
+            keys_spent: reward_list.vault_card_keys_spent,
+            unlocked_rewards: reward_list
+                .unlocked_reward_list
+                .iter()
+                .map(|r| VaultCardRewardData {
+                    column: r.column_index,
+                    row: r.row_index,
+                })
+                .collect(),
+            redeemed_rewards: reward_list
+                .redeemed_reward_list
+                .iter()
+                .map(|r| VaultCardRewardData {
+                    column: r.column_index,
+                    row: r.row_index,
+                })
+                .collect(),
+            gear_rewards: reward_list
+                .gear_rewards
+                .iter()
+                .map(|g| VaultCardGearRewardData {
+                    gear_index: g.gear_index,
+                    repurchase_count: g.repurchase_count,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn level(&self) -> i32 {
+        vault_card_experience_to_level(self.experience)
+    }
+
+    pub fn is_reward_unlocked(&self, reward: &VaultCardRewardData) -> bool {
+        self.unlocked_rewards.contains(reward)
+    }
+
+    pub fn is_reward_redeemed(&self, reward: &VaultCardRewardData) -> bool {
+        self.redeemed_rewards.contains(reward)
+    }
+
+    pub fn gear_reward(&self, gear_index: i32) -> Option<&VaultCardGearRewardData> {
+        self.gear_rewards
+            .iter()
+            .find(|g| g.gear_index == gear_index)
+    }
+}
+
+/// A reward on the card grid, rewards are stored by their position rather than by asset.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct VaultCardRewardData {
+    pub column: i32,
+    pub row: i32,
+}
+
+impl std::fmt::Display for VaultCardRewardData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Column {}, Row {}", self.column + 1, self.row + 1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct VaultCardGearRewardData {
+    pub gear_index: i32,
+    pub repurchase_count: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct VaultCardPreviousChallengeData {
+    pub seed: i32,
+    pub challenge_id: u32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VaultCardGear {
+    pub vault_card_id: u32,
+    pub gear_index: i32,
+    pub name: &'static str,
+    pub balance: &'static str,
+}
+
+impl VaultCardGear {
+    /// A copy of the item to hand out, taken from the Lootlemon items as the card doesn't store
+    /// the item itself.
+    pub fn item(&self) -> Option<Bl3Item> {
+        let balance_short_name = self.balance.rsplit('/').next().unwrap_or(self.balance);
+
+        LOOTLEMON_ITEMS
+            .iter()
+            .find(|i| {
+                i.item
+                    .balance_part()
+                    .short_ident
+                    .as_ref()
+                    .map(|s| s.to_lowercase() == balance_short_name)
+                    .unwrap_or(false)
+            })
+            .map(|i| i.item.clone())
+    }
+}
+
+/// The gear a card offers. The game's gear index isn't known, so `gear_index` is an approximation:
+/// the position of the balance in the card's gear folder, sorted by path.
+pub fn vault_card_gear(vault_card_id: u32) -> Vec<VaultCardGear> {
+    let gear_folder = match vault_card_id {
+        1 => "/game/patchdlc/vaultcard/gear/".to_owned(),
+        id => format!("/game/patchdlc/vaultcard{}/gear/", id),
+    };
+
+    BALANCE_NAME_MAPPING
+        .iter()
+        .filter(|gd| gd.ident.starts_with(&gear_folder))
+        .enumerate()
+        .map(|(i, gd)| VaultCardGear {
+            vault_card_id,
+            gear_index: i as i32,
+            name: gd.name,
+            balance: gd.ident,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_profile;
+
+    use super::*;
+
+    #[test]
+    fn test_level_experience() {
+        assert_eq!(vault_card_level_to_experience(1), 0);
+        assert_eq!(vault_card_level_to_experience(2), 10_000);
+        assert_eq!(
+            vault_card_level_to_experience(VAULT_CARD_MAX_LEVEL),
+            990_000
+        );
+        assert_eq!(vault_card_level_to_experience(0), 0);
+        assert_eq!(
+            vault_card_level_to_experience(VAULT_CARD_MAX_LEVEL + 1),
+            990_000
+        );
+
+        assert_eq!(vault_card_experience_to_level(-1), 1);
+        assert_eq!(vault_card_experience_to_level(0), 1);
+        assert_eq!(vault_card_experience_to_level(9_999), 1);
+        assert_eq!(vault_card_experience_to_level(10_000), 2);
+        assert_eq!(
+            vault_card_experience_to_level(i64::MAX),
+            VAULT_CARD_MAX_LEVEL
+        );
+
+        for level in 1..=VAULT_CARD_MAX_LEVEL {
+            assert_eq!(
+                vault_card_experience_to_level(vault_card_level_to_experience(level)),
+                level
+            );
+        }
+
+        // Experience part way through the current level is kept
+        assert_eq!(vault_card_experience_for_level(2, 15_000), 15_000);
+        assert_eq!(vault_card_experience_for_level(3, 15_000), 20_000);
+    }
+
+    #[test]
+    fn test_vault_card_gear() {
+        for id in VAULT_CARD_IDS {
+            let gear = vault_card_gear(id);
+
+            assert!(!gear.is_empty(), "Vault Card {} has no gear", id);
+
+            let gear_folder = match id {
+                1 => "/game/patchdlc/vaultcard/gear/".to_owned(),
+                id => format!("/game/patchdlc/vaultcard{}/gear/", id),
+            };
+
+            for g in &gear {
+                assert_eq!(g.vault_card_id, id);
+                assert!(g.balance.starts_with(&gear_folder));
+                assert!(!g.name.is_empty());
+            }
+
+            // Every gear reward can be told apart by its index
+            let mut indexes = gear.iter().map(|g| g.gear_index).collect::<Vec<_>>();
+            indexes.dedup();
+
+            assert_eq!(indexes.len(), gear.len());
+        }
+
+        // Vault Card 1 gear isn't included with the other cards
+        assert!(vault_card_gear(2)
+            .iter()
+            .all(|g| !vault_card_gear(1).iter().any(|o| o.balance == g.balance)));
+        assert!(vault_card_gear(4).is_empty());
+    }
+
+    #[test]
+    fn test_set_vault_card() {
+        let mut bl3_profile = read_test_profile("profile.sav", HeaderType::PcProfile);
+
+        // Cards the profile hasn't started are still listed
+        let vault_card_data = bl3_profile.profile_data.vault_card_data();
+
+        assert_eq!(vault_card_data.last_active_vault_card_id, 0);
+        assert_eq!(
+            vault_card_data.cards,
+            VAULT_CARD_IDS
+                .iter()
+                .map(|id| VaultCard::new(*id))
+                .collect::<Vec<_>>()
+        );
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        let reward = VaultCardRewardData { column: 2, row: 1 };
+        let redeemed_reward = VaultCardRewardData { column: 0, row: 0 };
+
+        profile_data
+            .set_vault_card_level(2, 25)
+            .expect("failed to set level");
+        profile_data
+            .set_vault_card_reward_unlocked(2, reward, true)
+            .expect("failed to unlock reward");
+        profile_data
+            .set_vault_card_reward_redeemed(2, redeemed_reward, true)
+            .expect("failed to redeem reward");
+        profile_data
+            .set_vault_card_gear_reward_unlocked(2, 1, true)
+            .expect("failed to unlock gear");
+        profile_data.set_vault_card_chests(2, 4);
+        profile_data
+            .set_last_active_vault_card(3)
+            .expect("failed to set active card");
+
+        // Invalid cards, levels and gear are rejected
+        assert!(profile_data.set_vault_card_level(4, 25).is_err());
+        assert!(profile_data.set_vault_card_level(2, 0).is_err());
+        assert!(profile_data
+            .set_vault_card_level(2, VAULT_CARD_MAX_LEVEL + 1)
+            .is_err());
+        assert!(profile_data.set_last_active_vault_card(0).is_err());
+        assert!(profile_data
+            .set_vault_card_gear_reward_unlocked(2, vault_card_gear(2).len() as i32, true)
+            .is_err());
+
+        let (_, bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let mut profile_data = bl3_profile.profile_data;
+
+        let vault_card_data = profile_data.vault_card_data();
+
+        assert_eq!(vault_card_data.last_active_vault_card_id, 3);
+
+        let card = vault_card_data.card(2).expect("failed to find card");
+
+        assert_eq!(card.level(), 25);
+        assert_eq!(card.chests, 4);
+        assert_eq!(profile_data.vault_card_2_chests(), 4);
+        assert!(card.is_reward_unlocked(&reward));
+        assert!(!card.is_reward_redeemed(&reward));
+        // Redeeming a reward unlocks it too
+        assert!(card.is_reward_unlocked(&redeemed_reward));
+        assert!(card.is_reward_redeemed(&redeemed_reward));
+        assert_eq!(
+            card.gear_reward(1),
+            Some(&VaultCardGearRewardData {
+                gear_index: 1,
+                repurchase_count: 0
+            })
+        );
+
+        assert_eq!(vault_card_data.card(1), Some(&VaultCard::new(1)));
+
+        // Locking a reward takes back its redemption
+        profile_data
+            .set_vault_card_reward_unlocked(2, redeemed_reward, false)
+            .expect("failed to lock reward");
+        profile_data
+            .set_vault_card_gear_reward_unlocked(2, 1, false)
+            .expect("failed to lock gear");
+
+        let vault_card_data = profile_data.vault_card_data();
+        let card = vault_card_data.card(2).expect("failed to find card");
+
+        assert!(!card.is_reward_unlocked(&redeemed_reward));
+        assert!(!card.is_reward_redeemed(&redeemed_reward));
+        assert!(card.is_reward_unlocked(&reward));
+        assert_eq!(card.gear_reward(1), None);
+    }
+}
@@ -1,4 +1,16 @@
-use strum::{Display, EnumMessage, EnumString};
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+use crate::bl3_save::sdu::SaveSduSlot;
+
+const GRENADE_CAPACITY: [i32; 11] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+const PISTOL_CAPACITY: [i32; 11] = [200, 300, 400, 500, 600, 700, 800, 900, 1000, 1100, 1200];
+const SHOTGUN_CAPACITY: [i32; 11] = [80, 100, 120, 140, 160, 180, 200, 220, 240, 260, 280];
+const SMG_CAPACITY: [i32; 11] = [360, 540, 720, 900, 1080, 1260, 1440, 1620, 1800, 1980, 2160];
+const AR_CAPACITY: [i32; 11] = [280, 420, 560, 700, 840, 980, 1120, 1260, 1400, 1540, 1680];
+const SNIPER_CAPACITY: [i32; 14] = [
+    48, 60, 72, 84, 96, 108, 120, 132, 144, 156, 168, 180, 192, 204,
+];
+const HEAVY_CAPACITY: [i32; 14] = [12, 15, 18, 21, 24, 27, 30, 33, 36, 39, 42, 45, 48, 51];
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct AmmoPoolData {
@@ -7,7 +19,9 @@ pub struct AmmoPoolData {
     pub max: i32,
 }
 
-#[derive(Debug, Display, EnumString, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone)]
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone,
+)]
 pub enum AmmoPool {
     #[strum(
         serialize = "/Game/GameData/Weapons/Ammo/Resource_Ammo_Grenade.Resource_Ammo_Grenade",
@@ -54,14 +68,70 @@ impl std::default::Default for AmmoPool {
 
 impl AmmoPool {
     pub fn maximum(&self) -> i32 {
+        self.capacities()[self.capacities().len() - 1]
+    }
+
+    pub fn sdu_slot(&self) -> SaveSduSlot {
+        match self {
+            AmmoPool::Grenade => SaveSduSlot::Grenade,
+            AmmoPool::Pistol => SaveSduSlot::Pistol,
+            AmmoPool::Shotgun => SaveSduSlot::Shotgun,
+            AmmoPool::Smg => SaveSduSlot::Smg,
+            AmmoPool::Ar => SaveSduSlot::Ar,
+            AmmoPool::Sniper => SaveSduSlot::Sniper,
+            AmmoPool::Heavy => SaveSduSlot::Heavy,
+        }
+    }
+
+    /// Ammo capacity indexed by the level of the pool's SDU, index 0 being no SDUs.
+    pub fn capacities(&self) -> &'static [i32] {
         match self {
-            AmmoPool::Grenade => 13,
-            AmmoPool::Pistol => 1200,
-            AmmoPool::Shotgun => 280,
-            AmmoPool::Smg => 2160,
-            AmmoPool::Ar => 1680,
-            AmmoPool::Sniper => 204,
-            AmmoPool::Heavy => 51,
+            AmmoPool::Grenade => &GRENADE_CAPACITY,
+            AmmoPool::Pistol => &PISTOL_CAPACITY,
+            AmmoPool::Shotgun => &SHOTGUN_CAPACITY,
+            AmmoPool::Smg => &SMG_CAPACITY,
+            AmmoPool::Ar => &AR_CAPACITY,
+            AmmoPool::Sniper => &SNIPER_CAPACITY,
+            AmmoPool::Heavy => &HEAVY_CAPACITY,
+        }
+    }
+
+    pub fn capacity(&self, sdu_level: i32) -> i32 {
+        let capacities = self.capacities();
+
+        capacities[(sdu_level.max(0) as usize).min(capacities.len() - 1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_capacities() {
+        for ammo_pool in AmmoPool::iter() {
+            let capacities = ammo_pool.capacities();
+
+            assert_eq!(
+                capacities.len() as i32,
+                ammo_pool.sdu_slot().maximum() + 1,
+                "{} has a capacity for every SDU level",
+                ammo_pool
+            );
+            assert!(capacities.windows(2).all(|w| w[0] < w[1]));
+
+            assert_eq!(ammo_pool.capacity(-1), capacities[0]);
+            assert_eq!(
+                ammo_pool.capacity(ammo_pool.sdu_slot().maximum() + 1),
+                ammo_pool.maximum()
+            );
         }
+
+        assert_eq!(AmmoPool::Pistol.capacity(0), 200);
+        assert_eq!(AmmoPool::Pistol.capacity(3), 500);
+        assert_eq!(AmmoPool::Sniper.maximum(), 204);
+        assert_eq!(AmmoPool::Heavy.capacity(13), 51);
     }
 }
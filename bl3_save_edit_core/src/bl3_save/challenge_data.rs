@@ -1,5 +1,41 @@
+use std::str::FromStr;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{Display, EnumIter, EnumMessage, EnumString};
 
+use crate::game_data::CHALLENGES;
+use crate::protos::oak_shared::ChallengeSaveGameData;
+
+// One percentage per category in the in game challenge menu
+pub const CHALLENGE_CATEGORY_COMPLETION_COUNT: usize = 8;
+
+/// The categories of the in game challenge menu, in the order the save stores their completion.
+#[derive(Debug, Display, EnumIter, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub enum ChallengeMenuCategory {
+    #[strum(to_string = "Combat")]
+    Combat,
+    #[strum(to_string = "Exploration")]
+    Exploration,
+    #[strum(to_string = "Loot")]
+    Loot,
+    #[strum(to_string = "Character")]
+    Character,
+    #[strum(to_string = "Events")]
+    Events,
+    #[strum(to_string = "Category 6")]
+    Category6,
+    #[strum(to_string = "Category 7")]
+    Category7,
+    #[strum(to_string = "Category 8")]
+    Category8,
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub struct ChallengeCategoryCompletion {
+    pub category: ChallengeMenuCategory,
+    pub percentage: u8,
+}
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct ChallengeData {
     pub challenge: Challenge,
@@ -51,3 +87,262 @@ pub enum Challenge {
     )]
     SirenClassModSlot,
 }
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ChallengeProgress {
+    pub category: ChallengeCategory,
+    pub name: String,
+    pub path: String,
+    pub currently_completed: bool,
+    pub is_active: bool,
+    pub completed_count: i32,
+    pub completed_progress_level: i32,
+    pub progress_counter: i32,
+    pub stats: Vec<ChallengeStat>,
+    pub rewards_claimed: Vec<bool>,
+}
+
+impl ChallengeProgress {
+    pub fn from_save_game_data(challenge: &ChallengeSaveGameData) -> Self {
+        let path = challenge.challenge_class_path.to_owned();
+
+        let name = CHALLENGES
+            .par_iter()
+            .find_first(|gd| path.eq_ignore_ascii_case(gd.ident))
+            .map(|gd| gd.name.to_owned())
+            .unwrap_or_else(|| path.clone());
+
+        let stats = challenge
+            .stat_instance_state
+            .iter()
+            .map(|s| ChallengeStat {
+                path: s.challenge_stat_path.to_owned(),
+                value: s.current_stat_value,
+            })
+            .collect();
+
+        let rewards_claimed = challenge
+            .challenge_reward_info
+            .iter()
+            .map(|r| r.challenge_reward_claimed)
+            .collect();
+
+        Self {
+            category: ChallengeCategory::from_path(&path),
+            name,
+            path,
+            currently_completed: challenge.currently_completed,
+            is_active: challenge.is_active,
+            completed_count: challenge.completed_count,
+            completed_progress_level: challenge.completed_progress_level,
+            progress_counter: challenge.progress_counter,
+            stats,
+            rewards_claimed,
+        }
+    }
+
+    pub fn apply_to_save_game_data(&self, challenge: &mut ChallengeSaveGameData) {
+        challenge.currently_completed = self.currently_completed;
+        challenge.is_active = self.is_active;
+        challenge.completed_count = self.completed_count;
+        challenge.completed_progress_level = self.completed_progress_level;
+        challenge.progress_counter = self.progress_counter;
+
+        for stat in challenge.stat_instance_state.iter_mut() {
+            if let Some(s) = self
+                .stats
+                .iter()
+                .find(|s| s.path == stat.challenge_stat_path)
+            {
+                stat.current_stat_value = s.value;
+            }
+        }
+
+        for (reward, claimed) in challenge
+            .challenge_reward_info
+            .iter_mut()
+            .zip(self.rewards_claimed.iter())
+        {
+            reward.challenge_reward_claimed = *claimed;
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ChallengeStat {
+    pub path: String,
+    pub value: i32,
+}
+
+impl ChallengeStat {
+    pub fn name(&self) -> &str {
+        self.path.rsplit('.').next().unwrap_or(&self.path)
+    }
+}
+
+#[derive(Debug, Display, EnumString, EnumIter, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub enum ChallengeCategory {
+    #[strum(serialize = "Account")]
+    Account,
+    #[strum(serialize = "Achievements", serialize = "1stParty")]
+    Achievements,
+    #[strum(serialize = "Character")]
+    Character,
+    #[strum(
+        serialize = "GeneralCombat",
+        serialize = "Grenade",
+        to_string = "Combat"
+    )]
+    Combat,
+    #[strum(serialize = "CrewChallenges", to_string = "Crew Challenges")]
+    CrewChallenges,
+    #[strum(serialize = "Discovery")]
+    Discovery,
+    #[strum(
+        serialize = "EchoLogs",
+        serialize = "EchoLog_NonMission",
+        to_string = "Echo Logs"
+    )]
+    EchoLogs,
+    #[strum(serialize = "Ecomony", to_string = "Economy")]
+    Economy,
+    #[strum(serialize = "Elemental")]
+    Elemental,
+    #[strum(serialize = "Enemy")]
+    Enemy,
+    #[strum(
+        serialize = "EridianCrystal",
+        serialize = "EridianWriting",
+        to_string = "Eridian"
+    )]
+    Eridian,
+    #[strum(serialize = "EventChallenges", to_string = "Events")]
+    Events,
+    #[strum(serialize = "FastTravel", to_string = "Fast Travel")]
+    FastTravel,
+    #[strum(
+        serialize = "HealthAndRecovery",
+        serialize = "Shield",
+        to_string = "Health And Shields"
+    )]
+    HealthAndShields,
+    #[strum(
+        serialize = "IO",
+        serialize = "InteractiveObjects",
+        to_string = "Interactive Objects"
+    )]
+    InteractiveObjects,
+    #[strum(serialize = "JackHolos", to_string = "Jack Holos")]
+    JackHolos,
+    #[strum(serialize = "LeagueChallenges", to_string = "League")]
+    League,
+    #[strum(serialize = "Loot")]
+    Loot,
+    #[strum(serialize = "Manufacturer")]
+    Manufacturer,
+    #[strum(serialize = "Map")]
+    Map,
+    #[strum(serialize = "Missions")]
+    Missions,
+    #[strum(
+        serialize = "RedChest",
+        serialize = "RedChests",
+        to_string = "Red Chests"
+    )]
+    RedChests,
+    #[strum(serialize = "System")]
+    System,
+    #[strum(serialize = "Daily", to_string = "Vault Card")]
+    VaultCard,
+    #[strum(serialize = "Vehicle")]
+    Vehicle,
+    #[strum(serialize = "WeaponChallenges", to_string = "Weapons")]
+    Weapons,
+    #[strum(serialize = "_Test", to_string = "Test")]
+    Test,
+    #[strum(to_string = "Other")]
+    Other,
+}
+
+impl std::default::Default for ChallengeCategory {
+    fn default() -> Self {
+        Self::Other
+    }
+}
+
+impl ChallengeCategory {
+    pub fn from_path(path: &str) -> Self {
+        path.split('/')
+            .find_map(|segment| ChallengeCategory::from_str(segment).ok())
+            .unwrap_or(ChallengeCategory::Other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_challenge_category_completion() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let completion = bl3_save.character_data.challenge_category_completion();
+
+        assert_eq!(completion.len(), CHALLENGE_CATEGORY_COMPLETION_COUNT);
+        assert_eq!(
+            completion
+                .iter()
+                .map(|c| (c.category.to_string(), c.percentage))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Combat".to_owned(), 84),
+                ("Exploration".to_owned(), 100),
+                ("Loot".to_owned(), 100),
+                ("Character".to_owned(), 88),
+                ("Events".to_owned(), 14),
+                ("Category 6".to_owned(), 0),
+                ("Category 7".to_owned(), 0),
+                ("Category 8".to_owned(), 0),
+            ]
+        );
+
+        bl3_save
+            .character_data
+            .set_challenge_category_completion(ChallengeMenuCategory::Events, 150);
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        assert_eq!(
+            bl3_save.character_data.challenge_category_completion()[4],
+            ChallengeCategoryCompletion {
+                category: ChallengeMenuCategory::Events,
+                percentage: 100,
+            }
+        );
+    }
+
+    #[test]
+    fn test_challenge_category_from_path() {
+        assert_eq!(
+            ChallengeCategory::from_path(
+                "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_AtlasHQ1.Challenge_EchoLog_NonMission_AtlasHQ1_C"
+            ),
+            ChallengeCategory::EchoLogs
+        );
+        assert_eq!(
+            ChallengeCategory::from_path("/Game/Somewhere/Else.Else_C"),
+            ChallengeCategory::Other
+        );
+    }
+}
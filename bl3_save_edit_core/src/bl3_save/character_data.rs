@@ -1,40 +1,58 @@
 use std::str::FromStr;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use derivative::Derivative;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
+use tracing::warn;
 
 use crate::bl3_item::{Bl3Item, ItemFlags};
-use crate::bl3_profile::guardian_reward::GuardianReward;
+use crate::bl3_profile::guardian_rank::guardian_experience_for_rank;
+use crate::bl3_profile::guardian_reward::{GuardianReward, GuardianRewardData};
 use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData};
-use crate::bl3_save::challenge_data::Challenge;
-use crate::bl3_save::challenge_data::ChallengeData;
+use crate::bl3_save::challenge_data::{
+    Challenge, ChallengeCategoryCompletion, ChallengeData, ChallengeMenuCategory,
+    ChallengeProgress, CHALLENGE_CATEGORY_COMPLETION_COUNT,
+};
+use crate::bl3_save::crew_quarters::CrewQuartersData;
+use crate::bl3_save::echo_log_data::EchoLog;
+use crate::bl3_save::game_stats::{game_stats_from_save_game_data, set_game_stat_value, GameStat};
+use crate::bl3_save::guardian_perk::{GuardianPerk, GuardianPerkData};
 use crate::bl3_save::inventory_slot::{InventorySlot, InventorySlotData};
 use crate::bl3_save::level_data::{LEVEL_CHALLENGES, LEVEL_STAT};
 use crate::bl3_save::models::Currency;
-use crate::bl3_save::player_class::PlayerClass;
+use crate::bl3_save::player_class::{PlayerClass, PlayerClassChange};
+use crate::bl3_save::player_color::PlayerColor;
 use crate::bl3_save::playthrough::Playthrough;
+use crate::bl3_save::region_data::{LevelPersistenceActor, RegionGameStage};
 use crate::bl3_save::sdu::{SaveSduSlot, SaveSduSlotData};
+use crate::bl3_save::ui_tracking::UiTrackingData;
 use crate::bl3_save::util::{currency_amount_from_character, experience_to_level};
+use crate::bl3_save::zone_map_fod::{FodData, ZoneMapLevel};
+use crate::error::ContainerFullError;
 use crate::game_data::{
-    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS,
-    PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS, VEHICLE_CHASSIS_CYCLONE,
-    VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER, VEHICLE_CHASSIS_TECHNICAL,
-    VEHICLE_PARTS_CYCLONE, VEHICLE_PARTS_JETBEAST, VEHICLE_PARTS_OUTRUNNER,
-    VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE, VEHICLE_SKINS_JETBEAST,
-    VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
+    GameDataKv, ECHO_LOGS, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES,
+    PROFILE_EMOTES_DEFAULTS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS,
+    PROFILE_SKINS_DEFAULTS, VEHICLE_CHASSIS_CYCLONE, VEHICLE_CHASSIS_JETBEAST,
+    VEHICLE_CHASSIS_OUTRUNNER, VEHICLE_CHASSIS_TECHNICAL, VEHICLE_PARTS_CYCLONE,
+    VEHICLE_PARTS_JETBEAST, VEHICLE_PARTS_OUTRUNNER, VEHICLE_PARTS_TECHNICAL,
+    VEHICLE_SKINS_CYCLONE, VEHICLE_SKINS_JETBEAST, VEHICLE_SKINS_OUTRUNNER,
+    VEHICLE_SKINS_TECHNICAL,
 };
 use crate::protos::oak_save::{
-    Character, GuardianRankCharacterSaveGameData, GuardianRankRewardCharacterSaveGameData,
-    GuardianRankSaveGameData, OakInventoryItemSaveGameData, VehicleUnlockedSaveGameData,
+    Character, CrewQuartersDecorationSaveData, CustomPlayerColorSaveGameData, EchoLogSaveGameData,
+    GuardianRankCharacterSaveGameData, GuardianRankPerkCharacterSaveGameData,
+    GuardianRankRewardCharacterSaveGameData, GuardianRankSaveGameData,
+    OakInventoryItemSaveGameData, VehicleUnlockedSaveGameData,
 };
-use crate::protos::oak_shared::{
-    GameStatSaveGameData, InventoryCategorySaveData, OakSDUSaveGameData,
+use crate::protos::oak_shared::{InventoryCategorySaveData, OakSDUSaveGameData};
+use crate::vehicle_data::{
+    loadout_asset_vehicle_name, VehicleAsset, VehicleData, VehicleLoadout, VehicleLoadoutSlot,
+    VehicleSubType, VehicleType, VEHICLE_LOADOUT_CATALOGUE,
 };
-use crate::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
 
 pub const MAX_CHARACTER_LEVEL: usize = 72;
+pub const EQUIPPED_EMOTE_COUNT: usize = 4;
 
 #[derive(Derivative)]
 #[derivative(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
@@ -191,154 +209,9 @@ impl CharacterData {
 
         ammo_pools.sort();
 
-        let mut challenge_milestones = Challenge::iter()
-            .filter(|challenge| {
-                let challenge_path = challenge.get_serializations()[0];
-
-                if challenge_path.contains("Character") {
-                    challenge_path.contains(&player_class.to_string())
-                } else {
-                    true
-                }
-            })
-            .map(|challenge| {
-                let chall_path = challenge.get_serializations()[0];
-
-                let unlocked = character
-                    .challenge_data
-                    .par_iter()
-                    .find_first(|cd| cd.challenge_class_path.contains(&chall_path))
-                    .map(|cd| cd.currently_completed)
-                    .context("failed to read challenge milestones")?;
-
-                Ok(ChallengeData {
-                    challenge,
-                    unlocked,
-                })
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        challenge_milestones.sort();
-
-        let mut outrunner_chassis = 0;
-        let mut jetbeast_chassis = 0;
-        let mut technical_chassis = 0;
-        let mut cyclone_chassis = 0;
-
-        character.vehicles_unlocked_data.iter().for_each(|vu| {
-            let vu = &vu.asset_path;
-            let vu = &vu.as_str();
-
-            match vu {
-                vu if VEHICLE_CHASSIS_OUTRUNNER.contains(vu) => {
-                    outrunner_chassis += 1;
-                }
-                vu if VEHICLE_CHASSIS_JETBEAST.contains(vu) => {
-                    jetbeast_chassis += 1;
-                }
-                vu if VEHICLE_CHASSIS_TECHNICAL.contains(vu) => {
-                    technical_chassis += 1;
-                }
-                vu if VEHICLE_CHASSIS_CYCLONE.contains(vu) => {
-                    cyclone_chassis += 1;
-                }
-                _ => (),
-            };
-        });
-
-        let mut outrunner_parts = 0;
-        let mut jetbeast_parts = 0;
-        let mut technical_parts = 0;
-        let mut cyclone_parts = 0;
-
-        let mut outrunner_skins = 0;
-        let mut jetbeast_skins = 0;
-        let mut technical_skins = 0;
-        let mut cyclone_skins = 0;
-
-        character.vehicle_parts_unlocked.iter().for_each(|vp| {
-            let vp = vp;
-            let vp = &vp.as_str();
-
-            match vp {
-                vp if VEHICLE_PARTS_OUTRUNNER.contains(vp) => {
-                    outrunner_parts += 1;
-                }
-                vp if VEHICLE_PARTS_JETBEAST.contains(vp) => {
-                    jetbeast_parts += 1;
-                }
-                vp if VEHICLE_PARTS_TECHNICAL.contains(vp) => {
-                    technical_parts += 1;
-                }
-                vp if VEHICLE_PARTS_CYCLONE.contains(vp) => {
-                    cyclone_parts += 1;
-                }
-                vp if VEHICLE_SKINS_OUTRUNNER.contains(vp) => {
-                    outrunner_skins += 1;
-                }
-                vp if VEHICLE_SKINS_JETBEAST.contains(vp) => {
-                    jetbeast_skins += 1;
-                }
-                vp if VEHICLE_SKINS_TECHNICAL.contains(vp) => {
-                    technical_skins += 1;
-                }
-                vp if VEHICLE_SKINS_CYCLONE.contains(vp) => {
-                    cyclone_skins += 1;
-                }
-                _ => (),
-            };
-        });
+        let challenge_milestones = challenge_milestones_from_character(&character, player_class)?;
 
-        let vehicle_data = [
-            VehicleData {
-                vehicle_type: VehicleType::Outrunner(VehicleSubType::Chassis),
-                current: outrunner_chassis,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Outrunner(VehicleSubType::Parts),
-                current: outrunner_parts,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Outrunner(VehicleSubType::Skins),
-                current: outrunner_skins,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Jetbeast(VehicleSubType::Chassis),
-                current: jetbeast_chassis,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Jetbeast(VehicleSubType::Parts),
-                current: jetbeast_parts,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Jetbeast(VehicleSubType::Skins),
-                current: jetbeast_skins,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Technical(VehicleSubType::Chassis),
-                current: technical_chassis,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Technical(VehicleSubType::Parts),
-                current: technical_parts,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Technical(VehicleSubType::Skins),
-                current: technical_skins,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Cyclone(VehicleSubType::Chassis),
-                current: cyclone_chassis,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Cyclone(VehicleSubType::Parts),
-                current: cyclone_parts,
-            },
-            VehicleData {
-                vehicle_type: VehicleType::Cyclone(VehicleSubType::Skins),
-                current: cyclone_skins,
-            },
-        ];
+        let vehicle_data = vehicle_data_from_character(&character);
 
         let inventory_items = character
             .inventory_items
@@ -374,40 +247,184 @@ impl CharacterData {
         self.player_class
     }
 
-    pub fn set_player_class(&mut self, player_class: PlayerClass) -> Result<()> {
-        if player_class != self.player_class {
-            let player_class_data = self
-                .character
-                .player_class_data
-                .as_mut()
-                .with_context(|| "failed to read Player Class data")?;
+    // Also moves everything tied to the class over, anything belonging to the previous class
+    // would otherwise be left pointing at assets the new class can't use
+    pub fn set_player_class(
+        &mut self,
+        player_class: PlayerClass,
+    ) -> Result<Option<PlayerClassChange>> {
+        if player_class == self.player_class {
+            return Ok(None);
+        }
 
-            player_class_data.player_class_path = player_class.get_serializations()[0].to_string();
+        let previous_class = self.player_class;
 
-            let ability_data = self
-                .character
-                .ability_data
-                .as_mut()
-                .context("failed to read Player ability data")?;
+        let player_class_data = self
+            .character
+            .player_class_data
+            .as_mut()
+            .with_context(|| "failed to read Player Class data")?;
 
-            //Reset our skill tree also
-            ability_data
-                .tree_item_list
-                .iter_mut()
-                .for_each(|ti| ti.points = 0);
+        player_class_data.player_class_path = player_class.get_serializations()[0].to_string();
 
-            if self.player_level > 2 {
-                let new_ability_points = self.player_level - 2;
+        let ability_data = self
+            .character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?;
 
-                ability_data.ability_points = new_ability_points;
+        //Reset our skill tree also
+        let skill_tree_items = ability_data.tree_item_list.len();
 
-                self.ability_points = new_ability_points;
+        ability_data
+            .tree_item_list
+            .retain(|ti| !player_class.is_other_class_asset(&ti.item_asset_path));
+
+        ability_data
+            .tree_item_list
+            .iter_mut()
+            .for_each(|ti| ti.points = 0);
+
+        let skill_tree_items_removed = skill_tree_items - ability_data.tree_item_list.len();
+
+        let ability_slots = ability_data.ability_slot_list.len();
+
+        ability_data.ability_slot_list.retain(|a| {
+            !player_class.is_other_class_asset(&a.ability_class_path)
+                && !player_class.is_other_class_asset(&a.slot_asset_path)
+        });
+
+        let ability_slots_removed = ability_slots - ability_data.ability_slot_list.len();
+
+        let augments =
+            ability_data.augment_slot_list.len() + ability_data.augment_configuration_list.len();
+
+        ability_data.augment_slot_list.retain(|a| {
+            !player_class.is_other_class_asset(&a.action_ability_class_path)
+                && !player_class.is_other_class_asset(&a.augment_asset_path)
+        });
+
+        ability_data.augment_configuration_list.retain(|a| {
+            !player_class.is_other_class_asset(&a.ability_class_path)
+                && !player_class.is_other_class_asset(&a.augment_asset_path)
+        });
+
+        let mut augments_removed = augments
+            - ability_data.augment_slot_list.len()
+            - ability_data.augment_configuration_list.len();
+
+        if self.player_level > 2 {
+            let new_ability_points = self.player_level - 2;
+
+            ability_data.ability_points = new_ability_points;
+
+            self.ability_points = new_ability_points;
+        }
+
+        if let Some(character_slot_data) = self.character.character_slot_save_game_data.as_mut() {
+            let character_augments = character_slot_data.augment_slot_list.len();
+
+            character_slot_data.augment_slot_list.retain(|a| {
+                !player_class.is_other_class_asset(&a.slot_asset_path)
+                    && !player_class.is_other_class_asset(&a.augment_asset_path)
+            });
+
+            augments_removed += character_augments - character_slot_data.augment_slot_list.len();
+        }
+
+        // Equipped emotes are indices into selected_customizations so they are removed along with
+        // the previous class's customizations and equipped again below
+        let customizations = self.character.selected_customizations.len();
+
+        self.character
+            .selected_customizations
+            .retain(|c| !player_class.is_other_class_asset(c));
+
+        self.character.equipped_emote_customizations.clear();
+
+        let customizations_removed = customizations - self.character.selected_customizations.len();
+
+        let head_skin = player_class.default_head_skin();
+        let character_skin = player_class.default_character_skin();
+
+        self.set_head_skin_selected(&head_skin);
+        self.set_character_skin_selected(&character_skin);
+        self.set_equipped_emotes(&player_class.default_emotes())?;
+
+        let class_mod_slot_moved = self.move_class_mod_slot_challenge(previous_class, player_class);
+
+        self.player_class = player_class;
+
+        self.challenge_milestones =
+            challenge_milestones_from_character(&self.character, player_class)?;
+
+        Ok(Some(PlayerClassChange {
+            previous_class,
+            new_class: player_class,
+            head_skin,
+            character_skin,
+            class_mod_slot_moved,
+            customizations_removed,
+            skill_tree_items_removed,
+            ability_slots_removed,
+            augments_removed,
+        }))
+    }
+
+    fn move_class_mod_slot_challenge(
+        &mut self,
+        previous_class: PlayerClass,
+        new_class: PlayerClass,
+    ) -> bool {
+        let previous_path = previous_class
+            .class_mod_slot_challenge()
+            .get_serializations()[0];
+        let new_path = new_class.class_mod_slot_challenge().get_serializations()[0];
+
+        let previous = self
+            .character
+            .challenge_data
+            .iter_mut()
+            .find(|c| c.challenge_class_path == previous_path);
+
+        let previous_state = match previous {
+            Some(previous) if previous.currently_completed => {
+                let state = previous.clone();
+
+                previous.currently_completed = false;
+                previous.is_active = true;
+                previous.completed_count = 0;
+                previous.completed_progress_level = 0;
+                previous.progress_counter = 0;
+
+                state
+            }
+            _ => return false,
+        };
+
+        match self
+            .character
+            .challenge_data
+            .iter_mut()
+            .find(|c| c.challenge_class_path == new_path)
+        {
+            Some(new) => {
+                new.currently_completed = previous_state.currently_completed;
+                new.is_active = previous_state.is_active;
+                new.completed_count = previous_state.completed_count;
+                new.completed_progress_level = previous_state.completed_progress_level;
+                new.progress_counter = previous_state.progress_counter;
             }
+            None => {
+                let mut new = previous_state;
 
-            self.player_class = player_class;
+                new.challenge_class_path = new_path.to_owned();
+
+                self.character.challenge_data.push(new);
+            }
         }
 
-        Ok(())
+        true
     }
 
     pub fn player_level(&self) -> i32 {
@@ -486,6 +503,34 @@ impl CharacterData {
         self.guardian_rank
     }
 
+    pub fn guardian_experience(&self) -> i64 {
+        self.character
+            .guardian_rank_character_data
+            .as_ref()
+            .map(|g| g.new_guardian_experience.max(g.guardian_experience as i64))
+            .unwrap_or(0)
+    }
+
+    pub fn guardian_tokens(&self) -> i32 {
+        self.character
+            .guardian_rank_character_data
+            .as_ref()
+            .map(|g| g.guardian_available_tokens)
+            .unwrap_or(0)
+    }
+
+    pub fn guardian_tokens_spent(&self) -> i32 {
+        self.character
+            .guardian_rank_character_data
+            .as_ref()
+            .map(|g| {
+                g.rank_rewards
+                    .iter()
+                    .fold(0_i32, |acc, r| acc.saturating_add(r.num_tokens))
+            })
+            .unwrap_or(0)
+    }
+
     pub fn set_guardian_rank(&mut self, new_rank: i32, tokens: Option<i32>) {
         if let Some(guardian_rank) = self.character.guardian_rank.as_mut() {
             guardian_rank.guardian_rank = new_rank;
@@ -502,6 +547,7 @@ impl CharacterData {
 
         if let Some(guardian_data) = self.character.guardian_rank_character_data.as_mut() {
             guardian_data.guardian_rank = new_rank;
+            guardian_data.is_rank_system_enabled = true;
 
             if let Some(tokens) = tokens {
                 guardian_data.guardian_available_tokens = tokens;
@@ -515,7 +561,7 @@ impl CharacterData {
                 rank_perks: Default::default(),
                 guardian_reward_random_seed: 0,
                 new_guardian_experience: 0,
-                is_rank_system_enabled: false,
+                is_rank_system_enabled: true,
                 unknown_fields: Default::default(),
                 cached_size: Default::default(),
             };
@@ -526,6 +572,100 @@ impl CharacterData {
         self.guardian_rank = new_rank;
     }
 
+    // Opt-in: the experience curve is fitted against real saves and is not verified for every rank.
+    pub fn set_guardian_experience_for_rank(&mut self, rank: i32) {
+        let experience = guardian_experience_for_rank(rank, self.guardian_experience());
+        let experience_i32 = experience.min(i32::MAX as i64) as i32;
+
+        if let Some(guardian_rank) = self.character.guardian_rank.as_mut() {
+            guardian_rank.guardian_experience = experience_i32;
+        }
+
+        if let Some(guardian_data) = self.character.guardian_rank_character_data.as_mut() {
+            guardian_data.guardian_experience = experience_i32;
+            guardian_data.new_guardian_experience = experience;
+        }
+    }
+
+    pub fn guardian_perks(&self) -> Vec<GuardianPerkData> {
+        let rank_perks = self
+            .character
+            .guardian_rank_character_data
+            .as_ref()
+            .map(|g| g.rank_perks.as_slice())
+            .unwrap_or_default();
+
+        GuardianPerk::iter()
+            .map(|perk| {
+                let perk_path = perk.get_serializations()[0];
+
+                let enabled = rank_perks
+                    .iter()
+                    .find(|p| p.perk_data_path.eq_ignore_ascii_case(perk_path))
+                    .map(|p| p.is_enabled)
+                    .unwrap_or(false);
+
+                GuardianPerkData { perk, enabled }
+            })
+            .collect()
+    }
+
+    pub fn set_guardian_perk(&mut self, guardian_perk: &GuardianPerk, enabled: bool) -> Result<()> {
+        let perk_path = guardian_perk.get_serializations()[0];
+
+        let guardian_rank_character_data = self
+            .character
+            .guardian_rank_character_data
+            .as_mut()
+            .context("failed to read character Guardian Rank character data.")?;
+
+        if let Some(perk) = guardian_rank_character_data
+            .rank_perks
+            .iter_mut()
+            .find(|p| p.perk_data_path.eq_ignore_ascii_case(perk_path))
+        {
+            perk.is_enabled = enabled;
+        } else if enabled {
+            guardian_rank_character_data
+                .rank_perks
+                .push(GuardianRankPerkCharacterSaveGameData {
+                    is_enabled: true,
+                    perk_data_path: perk_path.to_owned(),
+                    unknown_fields: Default::default(),
+                    cached_size: Default::default(),
+                });
+        }
+
+        Ok(())
+    }
+
+    pub fn guardian_rewards(&self) -> Vec<GuardianRewardData> {
+        let rank_rewards = self
+            .character
+            .guardian_rank_character_data
+            .as_ref()
+            .map(|g| g.rank_rewards.as_slice())
+            .unwrap_or_default();
+
+        GuardianReward::iter()
+            .map(|reward| {
+                let path = reward.get_serializations()[0];
+
+                let current = rank_rewards
+                    .iter()
+                    .find(|eg| eg.reward_data_path == path)
+                    .map(|eg| eg.num_tokens)
+                    .unwrap_or(0);
+
+                GuardianRewardData {
+                    current,
+                    max: None,
+                    reward,
+                }
+            })
+            .collect()
+    }
+
     pub fn set_guardian_reward(
         &mut self,
         guardian_reward: &GuardianReward,
@@ -632,6 +772,107 @@ impl CharacterData {
         self.echo_theme_selected = echo_theme_selected.to_owned();
     }
 
+    pub fn player_colors(&self) -> Vec<PlayerColor> {
+        self.character
+            .selected_color_customizations
+            .iter()
+            .map(PlayerColor::from_save_data)
+            .collect()
+    }
+
+    pub fn set_player_color(&mut self, player_color: &PlayerColor) {
+        if let Some(existing) = self
+            .character
+            .selected_color_customizations
+            .iter_mut()
+            .find(|c| c.color_parameter == player_color.parameter)
+        {
+            player_color.apply_to_save_data(existing);
+        } else {
+            let mut color_save_data = CustomPlayerColorSaveGameData::new();
+
+            player_color.apply_to_save_data(&mut color_save_data);
+
+            self.character
+                .selected_color_customizations
+                .push(color_save_data);
+        }
+    }
+
+    // Equipped emotes are stored as indices into selected_customizations
+    pub fn equipped_emotes(&self) -> Vec<GameDataKv> {
+        let available_emotes = PROFILE_EMOTES_DEFAULTS
+            .par_iter()
+            .chain(PROFILE_EMOTES.par_iter())
+            .filter(|e| e.ident.contains(&self.player_class.to_string()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        (0..EQUIPPED_EMOTE_COUNT)
+            .map(|slot| {
+                self.character
+                    .equipped_emote_customizations
+                    .get(slot)
+                    .and_then(|i| usize::try_from(*i).ok())
+                    .and_then(|i| self.character.selected_customizations.get(i))
+                    .and_then(|path| available_emotes.iter().find(|e| e.ident == path))
+                    .or_else(|| available_emotes.get(slot))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    pub fn set_equipped_emotes(&mut self, emotes: &[GameDataKv]) -> Result<()> {
+        if emotes.len() > EQUIPPED_EMOTE_COUNT {
+            bail!(
+                "a character can only have {} emotes equipped",
+                EQUIPPED_EMOTE_COUNT
+            );
+        }
+
+        let mut equipped_indices = self
+            .character
+            .equipped_emote_customizations
+            .iter()
+            .filter_map(|i| usize::try_from(*i).ok())
+            .filter(|i| *i < self.character.selected_customizations.len())
+            .collect::<Vec<_>>();
+
+        equipped_indices.sort_unstable();
+        equipped_indices.dedup();
+
+        for i in equipped_indices.into_iter().rev() {
+            self.character.selected_customizations.remove(i);
+        }
+
+        let mut equipped_emote_customizations = Vec::with_capacity(emotes.len());
+
+        for emote in emotes {
+            let index = match self
+                .character
+                .selected_customizations
+                .iter()
+                .position(|c| c == emote.ident)
+            {
+                Some(index) => index,
+                None => {
+                    self.character
+                        .selected_customizations
+                        .push(emote.ident.to_owned());
+
+                    self.character.selected_customizations.len() - 1
+                }
+            };
+
+            equipped_emote_customizations.push(index as i32);
+        }
+
+        self.character.equipped_emote_customizations = equipped_emote_customizations;
+
+        Ok(())
+    }
+
     pub fn money(&self) -> i32 {
         self.money
     }
@@ -732,12 +973,7 @@ impl CharacterData {
         //Unlock in character data
         slot.enabled = true;
 
-        let class_mod_challenge = match self.player_class {
-            PlayerClass::BeastMaster => Challenge::BeastMasterClassModSlot,
-            PlayerClass::Gunner => Challenge::GunnerClassModSlot,
-            PlayerClass::Operative => Challenge::OperativeClassModSlot,
-            PlayerClass::Siren => Challenge::SirenClassModSlot,
-        };
+        let class_mod_challenge = self.player_class.class_mod_slot_challenge();
 
         let class_mod_challenge_path = class_mod_challenge.get_serializations()[0];
 
@@ -799,13 +1035,52 @@ impl CharacterData {
                 max: sdu_slot.maximum(),
             });
         }
+
+        if let Some(ammo_pool) = AmmoPool::iter().find(|a| a.sdu_slot() == *sdu_slot) {
+            let capacity = ammo_pool.capacity(level);
+
+            let over_capacity = self
+                .ammo_pools
+                .iter()
+                .any(|a| a.pool == ammo_pool && a.current > capacity);
+
+            if over_capacity {
+                // The pool must exist if we've found it above so this can't fail.
+                let _ = self.set_ammo_pool(&ammo_pool, capacity);
+            }
+        }
+    }
+
+    pub fn sdu_level(&self, sdu_slot: &SaveSduSlot) -> i32 {
+        self.sdu_slots
+            .iter()
+            .find(|s| s.sdu == *sdu_slot)
+            .map(|s| s.current)
+            .unwrap_or(0)
     }
 
     pub fn ammo_pools(&self) -> &Vec<AmmoPoolData> {
         &self.ammo_pools
     }
 
+    pub fn ammo_pool_capacity(&self, ammo_pool: &AmmoPool) -> i32 {
+        ammo_pool.capacity(self.sdu_level(&ammo_pool.sdu_slot()))
+    }
+
     pub fn set_ammo_pool(&mut self, ammo_pool: &AmmoPool, amount: i32) -> Result<()> {
+        let capacity = self.ammo_pool_capacity(ammo_pool);
+
+        let amount = if amount > capacity {
+            warn!(
+                "{} ammo of {} is above the capacity of {} for the current SDU level, clamping",
+                ammo_pool, amount, capacity
+            );
+
+            capacity
+        } else {
+            amount.max(0)
+        };
+
         let pool_path = ammo_pool.get_serializations()[0];
 
         let pool = self
@@ -830,20 +1105,92 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn fill_ammo_pool(&mut self, ammo_pool: &AmmoPool) -> Result<()> {
+        self.set_ammo_pool(ammo_pool, self.ammo_pool_capacity(ammo_pool))
+    }
+
     pub fn challenge_milestones(&self) -> &Vec<ChallengeData> {
         &self.challenge_milestones
     }
 
-    pub fn vehicle_data(&self) -> &[VehicleData; 12] {
-        &self.vehicle_data
+    pub fn challenges(&self) -> Vec<ChallengeProgress> {
+        let mut challenges = self
+            .character
+            .challenge_data
+            .par_iter()
+            .map(ChallengeProgress::from_save_game_data)
+            .collect::<Vec<_>>();
+
+        challenges.sort();
+
+        challenges
     }
 
-    pub fn unlock_vehicle_data(&mut self, vehicle_type: &VehicleType) {
-        let data_set = vehicle_type.data_set();
+    pub fn set_challenge(&mut self, challenge: &ChallengeProgress) -> Result<()> {
+        let challenge_data = self
+            .character
+            .challenge_data
+            .iter_mut()
+            .find(|c| c.challenge_class_path == challenge.path)
+            .with_context(|| format!("failed to read challenge: {}", challenge.path))?;
 
-        match vehicle_type.subtype() {
-            VehicleSubType::Chassis => {
-                for d in data_set {
+        challenge.apply_to_save_game_data(challenge_data);
+
+        if let Some(milestone) = self
+            .challenge_milestones
+            .iter_mut()
+            .find(|m| challenge.path.contains(m.challenge.get_serializations()[0]))
+        {
+            milestone.unlocked = challenge.currently_completed;
+        }
+
+        Ok(())
+    }
+
+    pub fn challenge_category_completion(&self) -> Vec<ChallengeCategoryCompletion> {
+        let category_progress = &self
+            .character
+            .get_challenge_category_completion_pcts()
+            .category_progress;
+
+        ChallengeMenuCategory::iter()
+            .map(|category| ChallengeCategoryCompletion {
+                category,
+                percentage: category_progress
+                    .get(category as usize)
+                    .copied()
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    pub fn set_challenge_category_completion(
+        &mut self,
+        category: ChallengeMenuCategory,
+        percentage: u8,
+    ) {
+        let category_progress = &mut self
+            .character
+            .mut_challenge_category_completion_pcts()
+            .category_progress;
+
+        if category_progress.len() < CHALLENGE_CATEGORY_COMPLETION_COUNT {
+            category_progress.resize(CHALLENGE_CATEGORY_COMPLETION_COUNT, 0);
+        }
+
+        category_progress[category as usize] = percentage.min(100);
+    }
+
+    pub fn vehicle_data(&self) -> &[VehicleData; 12] {
+        &self.vehicle_data
+    }
+
+    pub fn unlock_vehicle_data(&mut self, vehicle_type: &VehicleType) {
+        let data_set = vehicle_type.data_set();
+
+        match vehicle_type.subtype() {
+            VehicleSubType::Chassis => {
+                for d in data_set {
                     if !self
                         .character
                         .vehicles_unlocked_data
@@ -884,6 +1231,172 @@ impl CharacterData {
         }
     }
 
+    pub fn vehicle_assets(&self, vehicle_type: &VehicleType) -> Vec<VehicleAsset> {
+        vehicle_type
+            .data_set()
+            .into_iter()
+            .map(|d| VehicleAsset::new(d, self.is_vehicle_asset_unlocked(d)))
+            .collect()
+    }
+
+    pub fn is_vehicle_asset_unlocked(&self, asset_path: &str) -> bool {
+        self.character
+            .vehicles_unlocked_data
+            .iter()
+            .any(|vd| vd.asset_path.eq_ignore_ascii_case(asset_path))
+            || self
+                .character
+                .vehicle_parts_unlocked
+                .iter()
+                .any(|vp| vp.eq_ignore_ascii_case(asset_path))
+    }
+
+    pub fn set_vehicle_asset_unlocked(
+        &mut self,
+        vehicle_type: &VehicleType,
+        asset_path: &str,
+        unlocked: bool,
+    ) -> Result<()> {
+        let asset_path = vehicle_type
+            .data_set()
+            .into_iter()
+            .find(|d| d.eq_ignore_ascii_case(asset_path))
+            .with_context(|| {
+                format!(
+                    "{} is not a {} {} asset",
+                    asset_path,
+                    vehicle_type,
+                    vehicle_type.subtype_name()
+                )
+            })?;
+
+        match vehicle_type.subtype() {
+            VehicleSubType::Chassis => {
+                self.character
+                    .vehicles_unlocked_data
+                    .retain(|vd| !vd.asset_path.eq_ignore_ascii_case(asset_path));
+
+                if unlocked {
+                    self.character
+                        .vehicles_unlocked_data
+                        .push(VehicleUnlockedSaveGameData {
+                            asset_path: asset_path.to_owned(),
+                            just_unlocked: true,
+                            unknown_fields: Default::default(),
+                            cached_size: Default::default(),
+                        });
+                }
+            }
+            VehicleSubType::Skins | VehicleSubType::Parts => {
+                self.character
+                    .vehicle_parts_unlocked
+                    .retain(|vp| !vp.eq_ignore_ascii_case(asset_path));
+
+                if unlocked {
+                    self.character
+                        .vehicle_parts_unlocked
+                        .push(asset_path.to_owned());
+                }
+            }
+        }
+
+        self.vehicle_data = vehicle_data_from_character(&self.character);
+
+        Ok(())
+    }
+
+    pub fn vehicle_loadouts(&self) -> Vec<VehicleLoadout> {
+        self.character
+            .vehicle_loadouts
+            .iter()
+            .map(VehicleLoadout::from_save_data)
+            .collect()
+    }
+
+    pub fn set_vehicle_loadout(&mut self, index: usize, loadout: &VehicleLoadout) -> Result<()> {
+        let save_data = self
+            .character
+            .vehicle_loadouts
+            .get_mut(index)
+            .with_context(|| format!("failed to find vehicle loadout {}", index))?;
+
+        loadout.apply_to_save_data(save_data);
+
+        Ok(())
+    }
+
+    pub fn vehicle_last_loadout_index(&self) -> i32 {
+        self.character.vehicle_last_loadout_index
+    }
+
+    pub fn set_vehicle_last_loadout_index(&mut self, index: i32) -> Result<()> {
+        if index >= self.character.vehicle_loadouts.len() as i32 || index < -1 {
+            bail!(
+                "vehicle loadout index must be between -1 and {}",
+                self.character.vehicle_loadouts.len() as i32 - 1
+            );
+        }
+
+        self.character.vehicle_last_loadout_index = index;
+
+        Ok(())
+    }
+
+    // Options for a loadout slot are limited to Catch-A-Ride assets whose unlock is present in the
+    // save, bodies require at least one unlocked chassis for that vehicle. Parts already used by a
+    // loadout are unlocked by definition so they're offered as well, this is the only source for
+    // ornaments and decals.
+    pub fn vehicle_loadout_options(
+        &self,
+        slot: VehicleLoadoutSlot,
+        vehicle_name: Option<&str>,
+    ) -> Vec<String> {
+        let mut options = VEHICLE_LOADOUT_CATALOGUE
+            .iter()
+            .filter(|part| part.slot == slot)
+            .filter(|part| {
+                let asset_vehicle_name = loadout_asset_vehicle_name(&part.path);
+
+                if slot == VehicleLoadoutSlot::Body {
+                    asset_vehicle_name
+                        .and_then(|v| {
+                            VehicleType::from_loadout_vehicle_name(v, VehicleSubType::Chassis)
+                        })
+                        .map(|vt| {
+                            vt.data_set()
+                                .into_iter()
+                                .any(|d| self.is_vehicle_asset_unlocked(d))
+                        })
+                        .unwrap_or(false)
+                } else {
+                    asset_vehicle_name == vehicle_name
+                        && part
+                            .unlock_path
+                            .map(|u| self.is_vehicle_asset_unlocked(u))
+                            .unwrap_or(true)
+                }
+            })
+            .map(|part| part.path.clone())
+            .collect::<Vec<_>>();
+
+        if slot == VehicleLoadoutSlot::Body {
+            return options;
+        }
+
+        for loadout in self.vehicle_loadouts() {
+            let path = loadout.slot(slot);
+
+            if !path.is_empty()
+                && loadout_asset_vehicle_name(path) == vehicle_name
+                && !options.iter().any(|o| o == path)
+            {
+                options.push(path.to_owned());
+            }
+        }
+
+        options
+    }
+
     pub fn inventory_items(&self) -> &Vec<Bl3Item> {
         &self.inventory_items
     }
@@ -934,7 +1447,61 @@ impl CharacterData {
         }
     }
 
-    pub fn add_inventory_item(&mut self, pickup_order_index: i32, item: &Bl3Item) -> Result<()> {
+    pub fn backpack_capacity(&self) -> i32 {
+        SaveSduSlot::backpack_capacity(self.sdu_level(&SaveSduSlot::Backpack))
+    }
+
+    pub fn backpack_item_count(&self) -> usize {
+        let equipped = self
+            .character
+            .equipped_inventory_list
+            .iter()
+            .filter(|e| {
+                e.inventory_list_index >= 0
+                    && (e.inventory_list_index as usize) < self.character.inventory_items.len()
+            })
+            .count();
+
+        self.character
+            .inventory_items
+            .len()
+            .saturating_sub(equipped)
+    }
+
+    /// With `allow_over_capacity` adding to a full backpack logs a warning instead of returning a
+    /// [`ContainerFullError`].
+    fn check_backpack_capacity(&self, allow_over_capacity: bool) -> Result<()> {
+        let capacity = self.backpack_capacity();
+        let count = self.backpack_item_count();
+
+        if count as i32 >= capacity {
+            if !allow_over_capacity {
+                return Err(ContainerFullError {
+                    container: "Backpack",
+                    count,
+                    capacity,
+                }
+                .into());
+            }
+
+            warn!(
+                "Backpack is over capacity ({}/{} items), items may be lost in game",
+                count + 1,
+                capacity
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn add_inventory_item(
+        &mut self,
+        pickup_order_index: i32,
+        item: &Bl3Item,
+        allow_over_capacity: bool,
+    ) -> Result<()> {
+        self.check_backpack_capacity(allow_over_capacity)?;
+
         let new_oak_item = Self::create_inventory_item(pickup_order_index, item, true)?;
 
         self.character.inventory_items.push(new_oak_item);
@@ -949,6 +1516,18 @@ impl CharacterData {
         pickup_order_index: i32,
         item_index: usize,
         item: &Bl3Item,
+        allow_over_capacity: bool,
+    ) -> Result<()> {
+        self.check_backpack_capacity(allow_over_capacity)?;
+
+        self.insert_inventory_item_unchecked(pickup_order_index, item_index, item)
+    }
+
+    fn insert_inventory_item_unchecked(
+        &mut self,
+        pickup_order_index: i32,
+        item_index: usize,
+        item: &Bl3Item,
     ) -> Result<()> {
         let new_oak_item = Self::create_inventory_item(pickup_order_index, item, true)?;
 
@@ -967,7 +1546,7 @@ impl CharacterData {
         item_index: usize,
         new_item: &Bl3Item,
     ) -> Result<()> {
-        self.insert_inventory_item(pickup_order_index, item_index, new_item)?;
+        self.insert_inventory_item_unchecked(pickup_order_index, item_index, new_item)?;
 
         // Remove old item
         self.remove_inventory_item(item_index + 1);
@@ -1012,21 +1591,807 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn game_stats(&self) -> Vec<GameStat> {
+        game_stats_from_save_game_data(&self.character.game_stats_data)
+    }
+
     pub fn set_game_stat(&mut self, stat_path: &str, stat_value: i32) {
-        if let Some(game_stat) = self
+        set_game_stat_value(&mut self.character.game_stats_data, stat_path, stat_value);
+    }
+
+    pub fn time_played_seconds(&self) -> u32 {
+        self.character.time_played_seconds
+    }
+
+    pub fn set_time_played_seconds(&mut self, time_played_seconds: u32) {
+        self.character.time_played_seconds = time_played_seconds;
+    }
+
+    pub fn echo_logs(&self) -> Vec<EchoLog> {
+        let unlocked_echo_logs = &self.character.unlocked_echo_logs;
+
+        let mut echo_log_paths = ECHO_LOGS.iter().map(|gd| gd.ident).collect::<Vec<_>>();
+
+        // Keep any unlocked logs we don't know about so they can still be locked
+        for echo_log in unlocked_echo_logs.iter() {
+            if !echo_log_paths
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(&echo_log.echo_log_path))
+            {
+                echo_log_paths.push(&echo_log.echo_log_path);
+            }
+        }
+
+        let mut echo_logs = echo_log_paths
+            .par_iter()
+            .map(|path| EchoLog::new(path, unlocked_echo_logs))
+            .collect::<Vec<_>>();
+
+        echo_logs.sort();
+
+        echo_logs
+    }
+
+    pub fn unlock_echo_log(&mut self, echo_log_path: &str) {
+        let already_unlocked = self
             .character
-            .game_stats_data
-            .iter_mut()
-            .find(|s| s.stat_path == stat_path)
-        {
-            game_stat.stat_value = stat_value;
-        } else {
-            self.character.game_stats_data.push(GameStatSaveGameData {
-                stat_path: stat_path.to_owned(),
-                stat_value,
+            .unlocked_echo_logs
+            .iter()
+            .any(|e| e.echo_log_path.eq_ignore_ascii_case(echo_log_path));
+
+        if !already_unlocked {
+            self.character.unlocked_echo_logs.push(EchoLogSaveGameData {
+                has_been_seen_in_log: false,
+                echo_log_path: echo_log_path.to_owned(),
                 unknown_fields: Default::default(),
                 cached_size: Default::default(),
             });
         }
     }
+
+    pub fn lock_echo_log(&mut self, echo_log_path: &str) {
+        self.character
+            .unlocked_echo_logs
+            .retain(|e| !e.echo_log_path.eq_ignore_ascii_case(echo_log_path));
+    }
+
+    pub fn set_echo_log_seen(&mut self, echo_log_path: &str, seen: bool) -> Result<()> {
+        let echo_log = self
+            .character
+            .unlocked_echo_logs
+            .iter_mut()
+            .find(|e| e.echo_log_path.eq_ignore_ascii_case(echo_log_path))
+            .with_context(|| format!("echo log is not unlocked: {}", echo_log_path))?;
+
+        echo_log.has_been_seen_in_log = seen;
+
+        Ok(())
+    }
+
+    pub fn unlock_all_echo_logs(&mut self) {
+        for gd in ECHO_LOGS {
+            self.unlock_echo_log(gd.ident);
+        }
+    }
+
+    pub fn lock_all_echo_logs(&mut self) {
+        self.character.unlocked_echo_logs.clear();
+    }
+
+    pub fn mark_all_echo_logs_seen(&mut self) {
+        self.character
+            .unlocked_echo_logs
+            .iter_mut()
+            .for_each(|e| e.has_been_seen_in_log = true);
+    }
+
+    pub fn region_game_stages(&self) -> Vec<RegionGameStage> {
+        let mut region_game_stages = self
+            .character
+            .saved_regions
+            .iter()
+            .map(RegionGameStage::new)
+            .collect::<Vec<_>>();
+
+        region_game_stages.sort();
+
+        region_game_stages
+    }
+
+    pub fn set_region_game_stage(
+        &mut self,
+        region_path: &str,
+        playthrough_index: i32,
+        game_stage: i32,
+    ) -> Result<()> {
+        if game_stage < 1 || game_stage > MAX_CHARACTER_LEVEL as i32 {
+            bail!(
+                "Game stage must be between 1 and {}, got: {}",
+                MAX_CHARACTER_LEVEL,
+                game_stage
+            );
+        }
+
+        let region = self
+            .character
+            .saved_regions
+            .iter_mut()
+            .find(|r| {
+                r.play_through_idx == playthrough_index
+                    && r.region_path.eq_ignore_ascii_case(region_path)
+            })
+            .with_context(|| {
+                format!(
+                    "region does not exist for playthrough {}: {}",
+                    playthrough_index, region_path
+                )
+            })?;
+
+        region.game_stage = game_stage;
+
+        Ok(())
+    }
+
+    pub fn level_persistence_actors(&self) -> Vec<LevelPersistenceActor> {
+        self.character
+            .level_persistence_data
+            .iter()
+            .flat_map(|l| {
+                l.saved_actors.iter().map(move |a| LevelPersistenceActor {
+                    level_name: l.level_name.clone(),
+                    actor_name: a.actor_name.clone(),
+                    timer_remaining: a.timer_remaining,
+                })
+            })
+            .collect()
+    }
+
+    pub fn reset_level_persistence(&mut self) {
+        self.character.level_persistence_data.clear();
+        self.character
+            .accumulated_level_persistence_reset_timer_seconds = 0;
+    }
+
+    pub fn zone_map_levels(&self) -> Vec<ZoneMapLevel> {
+        self.character
+            .get_gbx_zone_map_fod_save_game_data()
+            .level_data
+            .iter()
+            .map(|l| ZoneMapLevel {
+                level_name: l.level_name.clone(),
+                discovery_percentage: l.discovery_percentage,
+                fully_revealed: FodData::from_bytes(&l.fod_data)
+                    .map(|fod| fod.is_fully_revealed())
+                    .unwrap_or(false),
+            })
+            .collect()
+    }
+
+    pub fn reveal_zone_map_levels(&mut self, level_names: &[String]) -> Result<()> {
+        let fod_save_game_data = self.character.get_gbx_zone_map_fod_save_game_data();
+
+        for level_name in level_names {
+            if !fod_save_game_data
+                .level_data
+                .iter()
+                .any(|l| l.level_name.eq_ignore_ascii_case(level_name))
+            {
+                bail!("Zone map data does not exist for level: {}", level_name);
+            }
+        }
+
+        let playthrough_bit = 1_u32
+            .checked_shl(self.character.last_play_through_index.max(0) as u32)
+            .unwrap_or(0);
+
+        let is_selected = |level_name: &str| {
+            level_names
+                .iter()
+                .any(|n| n.eq_ignore_ascii_case(level_name))
+        };
+
+        // Decode every level before changing any so a corrupt level leaves the save untouched
+        let revealed_fod_data = fod_save_game_data
+            .level_data
+            .iter()
+            .filter(|l| is_selected(&l.level_name))
+            .map(|l| {
+                let mut fod = FodData::from_bytes(&l.fod_data).with_context(|| {
+                    format!("failed to read zone map data for level: {}", l.level_name)
+                })?;
+
+                fod.reveal_all();
+
+                fod.to_bytes()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for (level_data, fod_data) in self
+            .character
+            .mut_gbx_zone_map_fod_save_game_data()
+            .level_data
+            .iter_mut()
+            .filter(|l| is_selected(&l.level_name))
+            .zip(revealed_fod_data)
+        {
+            level_data.fod_data = fod_data;
+            level_data.discovery_percentage = 1.0;
+            level_data.data_state = 4;
+            level_data.data_revision = level_data.data_revision.wrapping_add(1);
+        }
+
+        // Discovery data uses the full map path, i.e. /Game/Maps/Zone_0/Prologue/Prologue_P
+        let discovered_level_suffixes = level_names
+            .iter()
+            .map(|n| format!("/{}_p", n.to_lowercase()))
+            .collect::<Vec<_>>();
+
+        self.character
+            .mut_discovery_data()
+            .discovered_level_info
+            .iter_mut()
+            .filter(|d| {
+                let discovered_level_name = d.discovered_level_name.to_lowercase();
+
+                discovered_level_suffixes
+                    .iter()
+                    .any(|s| discovered_level_name.ends_with(s))
+            })
+            .for_each(|d| d.discovered_playthroughs |= playthrough_bit);
+
+        Ok(())
+    }
+
+    pub fn reveal_all_zone_maps(&mut self) -> Result<()> {
+        let level_names = self
+            .character
+            .get_gbx_zone_map_fod_save_game_data()
+            .level_data
+            .iter()
+            .map(|l| l.level_name.clone())
+            .collect::<Vec<_>>();
+
+        self.reveal_zone_map_levels(&level_names)
+    }
+
+    pub fn crew_quarters(&self) -> CrewQuartersData {
+        CrewQuartersData::from_save_data(self.character.crew_quarters_room.as_ref())
+    }
+
+    pub fn set_crew_quarters(
+        &mut self,
+        crew_quarters: &CrewQuartersData,
+        unlocked_decorations: Option<&[String]>,
+    ) -> Result<()> {
+        crew_quarters.validate(&self.crew_quarters(), unlocked_decorations)?;
+
+        let crew_quarters_room = self.character.mut_crew_quarters_room();
+
+        crew_quarters_room.room_data_path = crew_quarters.room_data_path.clone();
+        crew_quarters_room.preferred_room_assignment = crew_quarters.preferred_room_assignment;
+        crew_quarters_room.decorations = crew_quarters
+            .decorations
+            .iter()
+            .map(|d| CrewQuartersDecorationSaveData {
+                decoration_index: d.index,
+                decoration_data_path: d.decoration_path.clone(),
+                ..Default::default()
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    pub fn ui_tracking(&self) -> UiTrackingData {
+        UiTrackingData::from_save_data(self.character.ui_tracking_save_game_data.as_ref())
+    }
+
+    /// `saved_spin_offset` isn't part of the first run prompts so it is left as it is.
+    pub fn set_ui_tracking(&mut self, ui_tracking: &UiTrackingData) {
+        let ui_tracking_data = self.character.mut_ui_tracking_save_game_data();
+
+        ui_tracking_data.has_seen_skill_menu_unlock = ui_tracking.has_seen_skill_menu_unlock;
+        ui_tracking_data.has_seen_guardian_rank_menu_unlock =
+            ui_tracking.has_seen_guardian_rank_menu_unlock;
+        ui_tracking_data.has_seen_echo_boot_ammo_bar = ui_tracking.has_seen_echo_boot_ammo_bar;
+        ui_tracking_data.has_seen_echo_boot_shield_bar = ui_tracking.has_seen_echo_boot_shield_bar;
+        ui_tracking_data.has_seen_echo_boot_grenades = ui_tracking.has_seen_echo_boot_grenades;
+        ui_tracking_data.highest_thvm_breadcrumb_seen = ui_tracking.highest_thvm_breadcrumb_seen;
+        ui_tracking_data.inventory_slot_unlocks_seen =
+            ui_tracking.inventory_slot_unlocks_seen.clone().into();
+    }
+}
+
+fn challenge_milestones_from_character(
+    character: &Character,
+    player_class: PlayerClass,
+) -> Result<Vec<ChallengeData>> {
+    let mut challenge_milestones = Challenge::iter()
+        .filter(|challenge| {
+            let challenge_path = challenge.get_serializations()[0];
+
+            if challenge_path.contains("Character") {
+                challenge_path.contains(&player_class.to_string())
+            } else {
+                true
+            }
+        })
+        .map(|challenge| {
+            let chall_path = challenge.get_serializations()[0];
+
+            let unlocked = character
+                .challenge_data
+                .par_iter()
+                .find_first(|cd| cd.challenge_class_path.contains(&chall_path))
+                .map(|cd| cd.currently_completed)
+                .context("failed to read challenge milestones")?;
+
+            Ok(ChallengeData {
+                challenge,
+                unlocked,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    challenge_milestones.sort();
+
+    Ok(challenge_milestones)
+}
+
+fn vehicle_data_from_character(character: &Character) -> [VehicleData; 12] {
+    let mut outrunner_chassis = 0;
+    let mut jetbeast_chassis = 0;
+    let mut technical_chassis = 0;
+    let mut cyclone_chassis = 0;
+
+    character.vehicles_unlocked_data.iter().for_each(|vu| {
+        let vu = &vu.asset_path;
+        let vu = &vu.as_str();
+
+        match vu {
+            vu if VEHICLE_CHASSIS_OUTRUNNER.contains(vu) => {
+                outrunner_chassis += 1;
+            }
+            vu if VEHICLE_CHASSIS_JETBEAST.contains(vu) => {
+                jetbeast_chassis += 1;
+            }
+            vu if VEHICLE_CHASSIS_TECHNICAL.contains(vu) => {
+                technical_chassis += 1;
+            }
+            vu if VEHICLE_CHASSIS_CYCLONE.contains(vu) => {
+                cyclone_chassis += 1;
+            }
+            _ => (),
+        };
+    });
+
+    let mut outrunner_parts = 0;
+    let mut jetbeast_parts = 0;
+    let mut technical_parts = 0;
+    let mut cyclone_parts = 0;
+
+    let mut outrunner_skins = 0;
+    let mut jetbeast_skins = 0;
+    let mut technical_skins = 0;
+    let mut cyclone_skins = 0;
+
+    character.vehicle_parts_unlocked.iter().for_each(|vp| {
+        let vp = vp;
+        let vp = &vp.as_str();
+
+        match vp {
+            vp if VEHICLE_PARTS_OUTRUNNER.contains(vp) => {
+                outrunner_parts += 1;
+            }
+            vp if VEHICLE_PARTS_JETBEAST.contains(vp) => {
+                jetbeast_parts += 1;
+            }
+            vp if VEHICLE_PARTS_TECHNICAL.contains(vp) => {
+                technical_parts += 1;
+            }
+            vp if VEHICLE_PARTS_CYCLONE.contains(vp) => {
+                cyclone_parts += 1;
+            }
+            vp if VEHICLE_SKINS_OUTRUNNER.contains(vp) => {
+                outrunner_skins += 1;
+            }
+            vp if VEHICLE_SKINS_JETBEAST.contains(vp) => {
+                jetbeast_skins += 1;
+            }
+            vp if VEHICLE_SKINS_TECHNICAL.contains(vp) => {
+                technical_skins += 1;
+            }
+            vp if VEHICLE_SKINS_CYCLONE.contains(vp) => {
+                cyclone_skins += 1;
+            }
+            _ => (),
+        };
+    });
+
+    [
+        VehicleData {
+            vehicle_type: VehicleType::Outrunner(VehicleSubType::Chassis),
+            current: outrunner_chassis,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Outrunner(VehicleSubType::Parts),
+            current: outrunner_parts,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Outrunner(VehicleSubType::Skins),
+            current: outrunner_skins,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Jetbeast(VehicleSubType::Chassis),
+            current: jetbeast_chassis,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Jetbeast(VehicleSubType::Parts),
+            current: jetbeast_parts,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Jetbeast(VehicleSubType::Skins),
+            current: jetbeast_skins,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Technical(VehicleSubType::Chassis),
+            current: technical_chassis,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Technical(VehicleSubType::Parts),
+            current: technical_parts,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Technical(VehicleSubType::Skins),
+            current: technical_skins,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Cyclone(VehicleSubType::Chassis),
+            current: cyclone_chassis,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Cyclone(VehicleSubType::Parts),
+            current: cyclone_parts,
+        },
+        VehicleData {
+            vehicle_type: VehicleType::Cyclone(VehicleSubType::Skins),
+            current: cyclone_skins,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::file_helper::Bl3FileType;
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_save;
+
+    use super::*;
+
+    fn assert_equipped_emotes_valid(character_data: &CharacterData) {
+        let character = &character_data.character;
+        let player_class = character_data.player_class().to_string();
+
+        assert!(character.equipped_emote_customizations.len() <= EQUIPPED_EMOTE_COUNT);
+
+        for i in character.equipped_emote_customizations.iter() {
+            let emote = usize::try_from(*i)
+                .ok()
+                .and_then(|i| character.selected_customizations.get(i))
+                .expect("equipped emote index is out of range");
+
+            assert!(emote.contains("Emote") && emote.contains(&player_class));
+        }
+    }
+
+    #[test]
+    fn test_equipped_emotes() {
+        let mut bl3_save = read_test_save("19.sav", HeaderType::PcSave);
+
+        let equipped_emotes = bl3_save.character_data.equipped_emotes();
+
+        assert_eq!(equipped_emotes.len(), EQUIPPED_EMOTE_COUNT);
+        assert!(equipped_emotes.iter().all(|e| e.ident.contains("Siren")));
+
+        let mut emotes = PROFILE_EMOTES
+            .iter()
+            .filter(|e| e.ident.contains("Siren"))
+            .take(EQUIPPED_EMOTE_COUNT)
+            .cloned()
+            .collect::<Vec<_>>();
+        emotes.reverse();
+
+        bl3_save
+            .character_data
+            .set_equipped_emotes(&emotes)
+            .expect("failed to set equipped emotes");
+
+        let too_many_emotes = vec![emotes[0]; EQUIPPED_EMOTE_COUNT + 1];
+
+        assert!(bl3_save
+            .character_data
+            .set_equipped_emotes(&too_many_emotes)
+            .is_err());
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        assert_eq!(bl3_save.character_data.equipped_emotes(), emotes);
+        assert_equipped_emotes_valid(&bl3_save.character_data);
+
+        // The previously equipped emotes are replaced rather than kept alongside
+        let character = &bl3_save.character_data.character;
+
+        assert_eq!(
+            character
+                .selected_customizations
+                .iter()
+                .filter(|c| c.contains("Emote"))
+                .count(),
+            EQUIPPED_EMOTE_COUNT
+        );
+    }
+
+    fn assert_no_other_class_assets(character_data: &CharacterData) {
+        let player_class = character_data.player_class();
+        let character = &character_data.character;
+        let ability_data = character.get_ability_data();
+
+        let paths = ability_data
+            .tree_item_list
+            .iter()
+            .map(|ti| &ti.item_asset_path)
+            .chain(
+                ability_data
+                    .ability_slot_list
+                    .iter()
+                    .flat_map(|a| [&a.ability_class_path, &a.slot_asset_path]),
+            )
+            .chain(
+                ability_data
+                    .augment_slot_list
+                    .iter()
+                    .flat_map(|a| [&a.action_ability_class_path, &a.augment_asset_path]),
+            )
+            .chain(
+                ability_data
+                    .augment_configuration_list
+                    .iter()
+                    .flat_map(|a| [&a.ability_class_path, &a.augment_asset_path]),
+            )
+            .chain(
+                character
+                    .get_character_slot_save_game_data()
+                    .augment_slot_list
+                    .iter()
+                    .flat_map(|a| [&a.slot_asset_path, &a.augment_asset_path]),
+            )
+            .chain(character.selected_customizations.iter());
+
+        for path in paths {
+            assert!(
+                !player_class.is_other_class_asset(path),
+                "{} asset left after changing class: {}",
+                player_class,
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_player_class() {
+        let mut saves_read = 0;
+        let mut class_mod_slots_moved = 0;
+
+        for entry in fs::read_dir("./test_files").expect("failed to read test_files") {
+            let filename = entry.expect("failed to read test_file").path();
+
+            let file_data = fs::read(&filename).expect("failed to read test_file");
+
+            let bl3_save = match Bl3FileType::from_unknown_data(&filename, &file_data) {
+                Ok(Bl3FileType::PcSave(save)) | Ok(Bl3FileType::Ps4Save(save)) => save,
+                _ => continue,
+            };
+
+            let previous_class = bl3_save.character_data.player_class();
+
+            let class_mod_slot_unlocked = |character_data: &CharacterData, class: PlayerClass| {
+                character_data
+                    .challenge_milestones()
+                    .iter()
+                    .find(|c| c.challenge == class.class_mod_slot_challenge())
+                    .map(|c| c.unlocked)
+                    .unwrap_or(false)
+            };
+
+            let previous_class_mod_slot =
+                class_mod_slot_unlocked(&bl3_save.character_data, previous_class);
+
+            for player_class in PlayerClass::ALL.iter().filter(|c| **c != previous_class) {
+                let mut new_save = bl3_save.clone();
+
+                let change = new_save
+                    .character_data
+                    .set_player_class(*player_class)
+                    .expect("failed to change class")
+                    .expect("class was not changed");
+
+                assert_eq!(change.previous_class, previous_class);
+                assert_eq!(change.class_mod_slot_moved, previous_class_mod_slot);
+
+                if change.class_mod_slot_moved {
+                    class_mod_slots_moved += 1;
+                }
+
+                let (_, new_save) = new_save.as_bytes().expect("failed to write test save");
+
+                let character_data = &new_save.character_data;
+
+                assert_eq!(character_data.player_class(), *player_class);
+                assert_eq!(
+                    class_mod_slot_unlocked(character_data, *player_class),
+                    previous_class_mod_slot,
+                    "class mod slot not carried over in {}",
+                    new_save.file_name
+                );
+                assert!(character_data
+                    .character
+                    .get_ability_data()
+                    .tree_item_list
+                    .iter()
+                    .all(|ti| ti.points == 0));
+
+                assert_no_other_class_assets(character_data);
+                assert_equipped_emotes_valid(character_data);
+            }
+
+            let mut same_class = bl3_save.clone();
+
+            assert!(same_class
+                .character_data
+                .set_player_class(previous_class)
+                .expect("failed to change class")
+                .is_none());
+
+            saves_read += 1;
+        }
+
+        assert!(saves_read > 0);
+        assert!(class_mod_slots_moved > 0);
+    }
+
+    #[test]
+    fn test_add_inventory_item_over_capacity() {
+        // 1.sav has a full backpack without any Backpack SDU
+        let mut bl3_save = read_test_save("1.sav", HeaderType::PcSave);
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.backpack_capacity(), 20);
+        assert_eq!(character_data.backpack_item_count(), 20);
+
+        let item = character_data.inventory_items()[0].clone();
+        let item_count = character_data.inventory_items().len();
+
+        let err = character_data
+            .add_inventory_item(item_count as i32, &item, false)
+            .expect_err("added an item to a full backpack");
+
+        assert!(err.is::<ContainerFullError>());
+        assert_eq!(character_data.inventory_items().len(), item_count);
+        assert_eq!(character_data.character.inventory_items.len(), item_count);
+
+        let err = character_data
+            .insert_inventory_item(0, 0, &item, false)
+            .expect_err("inserted an item into a full backpack");
+
+        assert!(err.is::<ContainerFullError>());
+
+        // Allowing it for one call does not allow it for the next
+        character_data
+            .add_inventory_item(item_count as i32, &item, true)
+            .expect("failed to add item over capacity");
+
+        assert_eq!(character_data.backpack_item_count(), 21);
+
+        character_data
+            .add_inventory_item(item_count as i32 + 1, &item, false)
+            .expect_err("added an item to a full backpack");
+
+        // Raising the Backpack SDU makes room again
+        character_data.set_sdu_slot(&SaveSduSlot::Backpack, 1);
+
+        character_data
+            .add_inventory_item(item_count as i32 + 1, &item, false)
+            .expect("failed to add item after raising the Backpack SDU");
+
+        assert_eq!(character_data.backpack_item_count(), 22);
+    }
+
+    #[test]
+    fn test_ammo_pool_clamped_to_capacity() {
+        let mut bl3_save = read_test_save("19.sav", HeaderType::PcSave);
+
+        let character_data = &mut bl3_save.character_data;
+
+        character_data.set_sdu_slot(&SaveSduSlot::Sniper, 5);
+
+        let capacity = AmmoPool::Sniper.capacity(5);
+
+        assert_eq!(
+            character_data.ammo_pool_capacity(&AmmoPool::Sniper),
+            capacity
+        );
+
+        character_data
+            .set_ammo_pool(&AmmoPool::Sniper, capacity + 100)
+            .expect("failed to set ammo pool");
+
+        assert_eq!(
+            ammo_pool_amount(character_data, &AmmoPool::Sniper),
+            capacity
+        );
+
+        character_data
+            .set_ammo_pool(&AmmoPool::Sniper, -5)
+            .expect("failed to set ammo pool");
+
+        assert_eq!(ammo_pool_amount(character_data, &AmmoPool::Sniper), 0);
+
+        // Lowering the SDU level brings the pool down to the new capacity
+        character_data
+            .fill_ammo_pool(&AmmoPool::Sniper)
+            .expect("failed to fill ammo pool");
+        character_data.set_sdu_slot(&SaveSduSlot::Sniper, 0);
+
+        assert_eq!(
+            ammo_pool_amount(character_data, &AmmoPool::Sniper),
+            AmmoPool::Sniper.capacity(0)
+        );
+
+        // Raising it keeps the current amount
+        character_data.set_sdu_slot(&SaveSduSlot::Sniper, SaveSduSlot::Sniper.maximum());
+
+        assert_eq!(
+            ammo_pool_amount(character_data, &AmmoPool::Sniper),
+            AmmoPool::Sniper.capacity(0)
+        );
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let sniper = bl3_save
+            .character_data
+            .ammo_pools()
+            .iter()
+            .find(|a| a.pool == AmmoPool::Sniper)
+            .cloned()
+            .expect("failed to find sniper ammo");
+
+        assert_eq!(sniper.current, AmmoPool::Sniper.capacity(0));
+        assert_eq!(sniper.max, AmmoPool::Sniper.maximum());
+    }
+
+    fn ammo_pool_amount(character_data: &CharacterData, ammo_pool: &AmmoPool) -> i32 {
+        let pool_path = ammo_pool.get_serializations()[0];
+
+        let amount = character_data
+            .character
+            .resource_pools
+            .iter()
+            .find(|rp| rp.resource_path == pool_path)
+            .map(|rp| rp.amount as i32)
+            .expect("failed to find ammo pool");
+
+        assert_eq!(
+            character_data
+                .ammo_pools()
+                .iter()
+                .find(|a| a.pool == *ammo_pool)
+                .map(|a| a.current),
+            Some(amount)
+        );
+
+        amount
+    }
 }
@@ -0,0 +1,332 @@
+use anyhow::{bail, Context, Result};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::game_data::{GameDataKv, CREW_QUARTERS_ROOMS, PROFILE_ROOM_DECORATIONS};
+use crate::protos::oak_save::CrewQuartersSaveData;
+
+pub const CREW_QUARTERS_DEFAULT_DECORATION: &str = "/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_0_DEFAULT.PlayerQuarterDecoration_0_DEFAULT";
+
+const PLACED_DECORATION_PREFIX: &str = "PlayerQuarterDecoration_";
+const UNLOCKED_DECORATION_PREFIX: &str = "/Game/Pickups/RoomDecoration/RoomDecoration_";
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CrewQuartersData {
+    pub room_data_path: String,
+    pub preferred_room_assignment: i32,
+    pub decorations: Vec<CrewQuartersDecorationData>,
+}
+
+impl CrewQuartersData {
+    pub fn from_save_data(crew_quarters: Option<&CrewQuartersSaveData>) -> Self {
+        crew_quarters
+            .map(|c| Self {
+                room_data_path: c.room_data_path.clone(),
+                preferred_room_assignment: c.preferred_room_assignment,
+                decorations: c
+                    .decorations
+                    .iter()
+                    .map(|d| CrewQuartersDecorationData {
+                        index: d.decoration_index,
+                        decoration_path: d.decoration_data_path.clone(),
+                    })
+                    .collect(),
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn room_name(&self) -> String {
+        crew_quarters_room_name(&self.room_data_path)
+    }
+
+    /// Placements already present in `existing` are trusted as the game put them there, only new
+    /// placements are checked against the profile's unlocked decorations.
+    pub fn validate(
+        &self,
+        existing: &CrewQuartersData,
+        unlocked_decorations: Option<&[String]>,
+    ) -> Result<()> {
+        if !self.room_data_path.is_empty()
+            && !CREW_QUARTERS_ROOMS
+                .iter()
+                .any(|r| r.ident.eq_ignore_ascii_case(&self.room_data_path))
+        {
+            bail!("Unknown crew quarters room: {}", self.room_data_path);
+        }
+
+        for (i, decoration) in self.decorations.iter().enumerate() {
+            if decoration.index < 1 {
+                bail!(
+                    "Decoration index must be at least 1, got: {}",
+                    decoration.index
+                );
+            }
+
+            if self.decorations[..i]
+                .iter()
+                .any(|d| d.index == decoration.index)
+            {
+                bail!(
+                    "More than one decoration is placed at index {}",
+                    decoration.index
+                );
+            }
+
+            if decoration.is_default()
+                || existing.decorations.iter().any(|d| {
+                    d.decoration_path
+                        .eq_ignore_ascii_case(&decoration.decoration_path)
+                })
+            {
+                continue;
+            }
+
+            let unlock = decoration
+                .unlock()
+                .with_context(|| format!("Unknown decoration: {}", decoration.decoration_path))?;
+
+            if let Some(unlocked_decorations) = unlocked_decorations {
+                if !unlocked_decorations
+                    .iter()
+                    .any(|d| d.eq_ignore_ascii_case(unlock.ident))
+                {
+                    bail!(
+                        "{} has not been unlocked in the profile and cannot be placed",
+                        unlock.name
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CrewQuartersDecorationData {
+    pub index: i32,
+    pub decoration_path: String,
+}
+
+impl CrewQuartersDecorationData {
+    pub fn is_default(&self) -> bool {
+        self.decoration_path
+            .eq_ignore_ascii_case(CREW_QUARTERS_DEFAULT_DECORATION)
+    }
+
+    pub fn unlock(&self) -> Option<GameDataKv> {
+        crew_quarters_decoration_unlock(&self.decoration_path)
+    }
+
+    pub fn name(&self) -> String {
+        if self.is_default() {
+            return "Empty".to_owned();
+        }
+
+        self.unlock()
+            .map(|u| u.name.to_owned())
+            .unwrap_or_else(|| asset_name(&self.decoration_path).to_owned())
+    }
+}
+
+pub fn crew_quarters_room_name(room_data_path: &str) -> String {
+    CREW_QUARTERS_ROOMS
+        .par_iter()
+        .find_first(|gd| room_data_path.eq_ignore_ascii_case(gd.ident))
+        .map(|gd| gd.name.to_owned())
+        .unwrap_or_else(|| asset_name(room_data_path).to_owned())
+}
+
+/// Placed decorations share their number with the base game pickup that unlocks them, i.e.
+/// RoomDecoration_31 is placed as PlayerQuarterDecoration_31. DLC decorations don't follow this
+/// pattern so they can't be placed from here.
+pub fn crew_quarters_decoration_path(unlocked_decoration_path: &str) -> Option<String> {
+    let number = unlocked_decoration_path
+        .strip_prefix(UNLOCKED_DECORATION_PREFIX)?
+        .split('.')
+        .next()?
+        .parse::<u32>()
+        .ok()?;
+
+    Some(format!(
+        "/Game/InteractiveObjects/PlayerQuarters/Decoration/{prefix}{n}.{prefix}{n}",
+        prefix = PLACED_DECORATION_PREFIX,
+        n = number
+    ))
+}
+
+pub fn crew_quarters_decoration_unlock(decoration_path: &str) -> Option<GameDataKv> {
+    PROFILE_ROOM_DECORATIONS.iter().copied().find(|gd| {
+        crew_quarters_decoration_path(gd.ident)
+            .map(|p| p.eq_ignore_ascii_case(decoration_path))
+            .unwrap_or(false)
+    })
+}
+
+/// Decorations that can be placed from the given unlocked decorations, sorted by name.
+pub fn placeable_crew_quarters_decorations(unlocked_decorations: &[String]) -> Vec<GameDataKv> {
+    let mut decorations = PROFILE_ROOM_DECORATIONS
+        .iter()
+        .copied()
+        .filter(|gd| crew_quarters_decoration_path(gd.ident).is_some())
+        .filter(|gd| {
+            unlocked_decorations
+                .iter()
+                .any(|d| d.eq_ignore_ascii_case(gd.ident))
+        })
+        .collect::<Vec<_>>();
+
+    decorations.sort_by_key(|gd| gd.name);
+
+    decorations
+}
+
+fn asset_name(path: &str) -> &str {
+    path.rsplit('.').next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_save;
+
+    use super::*;
+
+    fn all_unlocked_decorations() -> Vec<String> {
+        PROFILE_ROOM_DECORATIONS
+            .iter()
+            .map(|gd| gd.ident.to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_decoration_paths() {
+        assert_eq!(
+            crew_quarters_decoration_path(
+                "/Game/Pickups/RoomDecoration/RoomDecoration_31.RoomDecoration_31"
+            ),
+            Some("/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_31.PlayerQuarterDecoration_31".to_owned())
+        );
+        assert_eq!(
+            crew_quarters_decoration_path("/Game/PatchDLC/Geranium/Customizations/RoomDeco/RoomDecoration_Geranium_2.RoomDecoration_Geranium_2"),
+            None
+        );
+
+        let placeable = placeable_crew_quarters_decorations(&all_unlocked_decorations());
+
+        assert!(!placeable.is_empty());
+        assert!(placeable.windows(2).all(|w| w[0].name <= w[1].name));
+
+        // Every placeable decoration maps back to the item that unlocks it
+        for gd in &placeable {
+            let decoration_path =
+                crew_quarters_decoration_path(gd.ident).expect("failed to get decoration path");
+
+            assert_eq!(crew_quarters_decoration_unlock(&decoration_path), Some(*gd));
+        }
+
+        assert!(placeable_crew_quarters_decorations(&[]).is_empty());
+        assert_eq!(
+            placeable_crew_quarters_decorations(&[placeable[0].ident.to_lowercase()]),
+            vec![placeable[0]]
+        );
+    }
+
+    #[test]
+    fn test_crew_quarters() {
+        let bl3_save = read_test_save("19.sav", HeaderType::PcSave);
+
+        let crew_quarters = bl3_save.character_data.crew_quarters();
+
+        assert_eq!(crew_quarters.decorations.len(), 3);
+        assert_ne!(
+            crew_quarters.room_name(),
+            asset_name(&crew_quarters.room_data_path)
+        );
+        assert!(crew_quarters
+            .decorations
+            .iter()
+            .all(|d| d.unlock().is_some() && d.name() != asset_name(&d.decoration_path)));
+
+        // A character that hasn't been to Sanctuary yet has no room
+        let crew_quarters = read_test_save("1.sav", HeaderType::PcSave)
+            .character_data
+            .crew_quarters();
+
+        assert!(crew_quarters.room_data_path.is_empty());
+        assert!(crew_quarters.decorations.is_empty());
+        assert!(crew_quarters.validate(&crew_quarters, Some(&[])).is_ok());
+    }
+
+    #[test]
+    fn test_set_crew_quarters() {
+        let mut bl3_save = read_test_save("19.sav", HeaderType::PcSave);
+
+        let existing = bl3_save.character_data.crew_quarters();
+
+        let new_decoration = placeable_crew_quarters_decorations(&all_unlocked_decorations())
+            .into_iter()
+            .find(|gd| !existing.decorations.iter().any(|d| d.unlock() == Some(*gd)))
+            .expect("failed to find a decoration to place");
+
+        let mut crew_quarters = existing.clone();
+
+        crew_quarters.decorations.push(CrewQuartersDecorationData {
+            index: 4,
+            decoration_path: crew_quarters_decoration_path(new_decoration.ident)
+                .expect("failed to get decoration path"),
+        });
+        crew_quarters.decorations.push(CrewQuartersDecorationData {
+            index: 5,
+            decoration_path: CREW_QUARTERS_DEFAULT_DECORATION.to_owned(),
+        });
+
+        // New placements have to be unlocked in the profile, existing ones are kept as they are
+        assert!(bl3_save
+            .character_data
+            .set_crew_quarters(&crew_quarters, Some(&[]))
+            .is_err());
+
+        bl3_save
+            .character_data
+            .set_crew_quarters(&crew_quarters, Some(&[new_decoration.ident.to_owned()]))
+            .expect("failed to set crew quarters");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let mut character_data = bl3_save.character_data;
+
+        assert_eq!(character_data.crew_quarters(), crew_quarters);
+        assert_eq!(
+            character_data.crew_quarters().decorations[4].name(),
+            "Empty"
+        );
+
+        let invalid = |edit: &dyn Fn(&mut CrewQuartersData)| {
+            let mut edited = crew_quarters.clone();
+            edit(&mut edited);
+            edited
+        };
+
+        for edited in [
+            invalid(&|c| c.room_data_path = "/Game/Not/A/Room.Room".to_owned()),
+            invalid(&|c| c.decorations[0].index = 0),
+            invalid(&|c| c.decorations[1].index = 1),
+            invalid(&|c| {
+                c.decorations[0].decoration_path = "/Game/Not/A/Decoration.Decoration".to_owned()
+            }),
+        ] {
+            assert!(character_data.set_crew_quarters(&edited, None).is_err());
+        }
+
+        assert_eq!(character_data.crew_quarters(), crew_quarters);
+
+        // Without a profile any known decoration can be placed
+        crew_quarters.decorations.truncate(1);
+
+        character_data
+            .set_crew_quarters(&crew_quarters, None)
+            .expect("failed to set crew quarters");
+
+        assert_eq!(character_data.crew_quarters(), crew_quarters);
+    }
+}
@@ -0,0 +1,215 @@
+use std::str::FromStr;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use strum::{Display, EnumIter, EnumString};
+
+use crate::game_data::ECHO_LOGS;
+use crate::protos::oak_save::EchoLogSaveGameData;
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct EchoLog {
+    pub group: EchoLogGroup,
+    pub name: String,
+    pub path: String,
+    pub unlocked: bool,
+    pub seen: bool,
+}
+
+impl EchoLog {
+    pub fn new(path: &str, unlocked_echo_logs: &[EchoLogSaveGameData]) -> Self {
+        let name = ECHO_LOGS
+            .par_iter()
+            .find_first(|gd| path.eq_ignore_ascii_case(gd.ident))
+            .map(|gd| gd.name.to_owned())
+            .unwrap_or_else(|| path.to_owned());
+
+        let unlocked_echo_log = unlocked_echo_logs
+            .par_iter()
+            .find_first(|e| path.eq_ignore_ascii_case(&e.echo_log_path));
+
+        Self {
+            group: EchoLogGroup::from_path(path),
+            name,
+            path: path.to_owned(),
+            unlocked: unlocked_echo_log.is_some(),
+            seen: unlocked_echo_log
+                .map(|e| e.has_been_seen_in_log)
+                .unwrap_or(false),
+        }
+    }
+}
+
+#[derive(Debug, Display, EnumString, EnumIter, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+#[strum(ascii_case_insensitive)]
+pub enum EchoLogGroup {
+    #[strum(serialize = "Prologue", to_string = "Droughts")]
+    Droughts,
+    #[strum(serialize = "Sacrifice", to_string = "Ascension Bluff")]
+    AscensionBluff,
+    #[strum(serialize = "Motorcade", to_string = "Splinterlands")]
+    Splinterlands,
+    #[strum(serialize = "MotorcadeFestival", to_string = "Carnivora")]
+    Carnivora,
+    #[strum(serialize = "MotorcadeInterior", to_string = "Guts of Carnivora")]
+    GutsOfCarnivora,
+    #[strum(serialize = "Desert", to_string = "Devil's Razor")]
+    DevilsRazor,
+    #[strum(serialize = "DesertVault", to_string = "Cathedral of the Twin Gods")]
+    CathedralOfTheTwinGods,
+    #[strum(serialize = "Outskirts", to_string = "Meridian Outskirts")]
+    MeridianOutskirts,
+    #[strum(serialize = "City", to_string = "Meridian Metroplex")]
+    MeridianMetroplex,
+    #[strum(serialize = "Towers", to_string = "Lectra City")]
+    LectraCity,
+    #[strum(serialize = "OrbitalPlatform", to_string = "Skywell-27")]
+    Skywell27,
+    #[strum(serialize = "AtlasHQ", to_string = "Atlas HQ")]
+    AtlasHq,
+    #[strum(serialize = "CityVault", to_string = "Neon Arterial")]
+    NeonArterial,
+    #[strum(
+        serialize = "Monastery",
+        serialize = "Monestary",
+        to_string = "Athenas"
+    )]
+    Athenas,
+    #[strum(serialize = "Sanctuary")]
+    Sanctuary,
+    #[strum(serialize = "Wetlands", to_string = "Floodmoor Basin")]
+    FloodmoorBasin,
+    #[strum(serialize = "Watership", to_string = "Voracious Canopy")]
+    VoraciousCanopy,
+    #[strum(serialize = "Mansion", to_string = "Jakobs Estate")]
+    JakobsEstate,
+    #[strum(serialize = "Marshfields", to_string = "Ambermire")]
+    Ambermire,
+    #[strum(serialize = "WetlandsVault", to_string = "Blackbarrel Cellars")]
+    BlackbarrelCellars,
+    #[strum(serialize = "Prison", to_string = "Anvil")]
+    Anvil,
+    #[strum(serialize = "Beach", to_string = "Tazendeer Ruins")]
+    TazendeerRuins,
+    #[strum(serialize = "Mine", to_string = "Konrad's Hold")]
+    KonradsHold,
+    #[strum(serialize = "Desolate", to_string = "Desolation's Edge")]
+    DesolationsEdge,
+    #[strum(serialize = "EridianWriting", to_string = "Eridian Writing")]
+    EridianWriting,
+    #[strum(
+        serialize = "Dandelion",
+        to_string = "DLC1 - Moxxi's Heist of the Handsome Jackpot"
+    )]
+    Dlc1,
+    #[strum(serialize = "Hibiscus", to_string = "DLC2 - Guns, Love, and Tentacles")]
+    Dlc2,
+    #[strum(serialize = "Geranium", to_string = "DLC3 - Bounty of Blood")]
+    Dlc3,
+    #[strum(
+        serialize = "Alisma",
+        to_string = "DLC4 - Psycho Krieg and the Fantastic Fustercluck"
+    )]
+    Dlc4,
+    #[strum(serialize = "Ixora", to_string = "DLC5 - Designer's Cut")]
+    Dlc5,
+    #[strum(serialize = "Event", to_string = "Revenge of the Cartels")]
+    RevengeOfTheCartels,
+    #[strum(serialize = "Other")]
+    Other,
+}
+
+impl std::default::Default for EchoLogGroup {
+    fn default() -> Self {
+        Self::Other
+    }
+}
+
+impl EchoLogGroup {
+    // Map/DLC names are embedded in both the directory and file names, e.g. EchoLogData_NonMission_Beach1
+    pub fn from_path(path: &str) -> Self {
+        path.split(['/', '_', '.'])
+            .map(|token| token.trim_end_matches(|c: char| c.is_ascii_digit()))
+            .find_map(|token| EchoLogGroup::from_str(token).ok())
+            .unwrap_or(EchoLogGroup::Other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_echo_log_group_from_path() {
+        assert_eq!(
+            EchoLogGroup::from_path("/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade1.EchoLog_Journal_Motorcade1"),
+            EchoLogGroup::Splinterlands
+        );
+        assert_eq!(
+            EchoLogGroup::from_path("/Game/Test/EchoLog_Unknown.EchoLog_Unknown"),
+            EchoLogGroup::Other
+        );
+    }
+
+    #[test]
+    fn test_unlock_echo_logs() {
+        let filename = Path::new("./test_files/5.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let first_echo_log = ECHO_LOGS[0].ident;
+
+        bl3_save.character_data.lock_all_echo_logs();
+
+        assert!(bl3_save
+            .character_data
+            .set_echo_log_seen(first_echo_log, true)
+            .is_err());
+
+        bl3_save.character_data.unlock_all_echo_logs();
+        bl3_save
+            .character_data
+            .set_echo_log_seen(first_echo_log, true)
+            .expect("failed to set echo log seen");
+
+        let (_, mut bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let echo_logs = bl3_save.character_data.echo_logs();
+
+        assert_eq!(echo_logs.len(), ECHO_LOGS.len());
+        assert!(echo_logs.iter().all(|e| e.unlocked));
+        assert_eq!(
+            echo_logs
+                .iter()
+                .filter(|e| e.seen)
+                .map(|e| e.path.as_str())
+                .collect::<Vec<_>>(),
+            vec![first_echo_log]
+        );
+
+        bl3_save.character_data.mark_all_echo_logs_seen();
+
+        assert!(bl3_save.character_data.echo_logs().iter().all(|e| e.seen));
+
+        bl3_save.character_data.lock_echo_log(first_echo_log);
+
+        assert_eq!(
+            bl3_save
+                .character_data
+                .echo_logs()
+                .iter()
+                .filter(|e| !e.unlocked)
+                .map(|e| e.path.as_str())
+                .collect::<Vec<_>>(),
+            vec![first_echo_log]
+        );
+    }
+}
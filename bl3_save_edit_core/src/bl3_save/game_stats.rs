@@ -0,0 +1,169 @@
+use protobuf::RepeatedField;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::game_data::GAME_STATS;
+use crate::protos::oak_shared::GameStatSaveGameData;
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct GameStat {
+    pub name: String,
+    pub path: String,
+    pub value: i32,
+    pub is_known: bool,
+}
+
+impl GameStat {
+    pub fn from_save_game_data(stat: &GameStatSaveGameData) -> Self {
+        let known_name = GAME_STATS
+            .par_iter()
+            .find_first(|gd| stat.stat_path.eq_ignore_ascii_case(gd.ident))
+            .map(|gd| gd.name.to_owned());
+
+        Self {
+            is_known: known_name.is_some(),
+            name: known_name.unwrap_or_else(|| stat.stat_path.to_owned()),
+            path: stat.stat_path.to_owned(),
+            value: stat.stat_value,
+        }
+    }
+}
+
+pub fn game_stats_from_save_game_data(stats: &[GameStatSaveGameData]) -> Vec<GameStat> {
+    let mut game_stats = stats
+        .par_iter()
+        .map(GameStat::from_save_game_data)
+        .collect::<Vec<_>>();
+
+    // Known stats first, then anything we only have a raw path for
+    game_stats.sort_by(|a, b| {
+        b.is_known
+            .cmp(&a.is_known)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    game_stats
+}
+
+pub fn set_game_stat_value(
+    stats: &mut RepeatedField<GameStatSaveGameData>,
+    stat_path: &str,
+    stat_value: i32,
+) {
+    if let Some(game_stat) = stats.iter_mut().find(|s| s.stat_path == stat_path) {
+        game_stat.stat_value = stat_value;
+    } else {
+        stats.push(GameStatSaveGameData {
+            stat_path: stat_path.to_owned(),
+            stat_value,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_profile::Bl3Profile;
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    const UNKNOWN_STAT_PATH: &str = "/Game/Test/Stat_Unknown.Stat_Unknown";
+
+    #[test]
+    fn test_save_game_stats() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let game_stats = bl3_save.character_data.game_stats();
+
+        assert_eq!(
+            game_stats.len(),
+            bl3_save.character_data.character.game_stats_data.len()
+        );
+
+        // Known stats are listed first and by name
+        let known_count = game_stats.iter().take_while(|s| s.is_known).count();
+
+        assert!(known_count > 0);
+        assert!(game_stats[known_count..].iter().all(|s| !s.is_known));
+        assert!(game_stats[..known_count]
+            .windows(2)
+            .all(|w| w[0].name <= w[1].name));
+
+        let known_stat = game_stats[0].clone();
+
+        assert_ne!(known_stat.name, known_stat.path);
+
+        bl3_save
+            .character_data
+            .set_game_stat(&known_stat.path, known_stat.value + 1);
+        bl3_save.character_data.set_game_stat(UNKNOWN_STAT_PATH, 5);
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let game_stats = bl3_save.character_data.game_stats();
+
+        assert_eq!(
+            game_stats
+                .iter()
+                .find(|s| s.path == known_stat.path)
+                .map(|s| s.value),
+            Some(known_stat.value + 1)
+        );
+        assert_eq!(
+            game_stats.last(),
+            Some(&GameStat {
+                name: UNKNOWN_STAT_PATH.to_owned(),
+                path: UNKNOWN_STAT_PATH.to_owned(),
+                value: 5,
+                is_known: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_profile_game_stats() {
+        let filename = Path::new("./test_files/profile.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let game_stats = bl3_profile.profile_data.game_stats();
+
+        assert_eq!(
+            game_stats.len(),
+            bl3_profile.profile_data.profile.profile_stats_data.len()
+        );
+
+        bl3_profile
+            .profile_data
+            .set_game_stat(UNKNOWN_STAT_PATH, 10);
+
+        let (_, bl3_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let game_stats = bl3_profile.profile_data.game_stats();
+
+        assert_eq!(
+            game_stats
+                .iter()
+                .filter(|s| s.path == UNKNOWN_STAT_PATH)
+                .map(|s| s.value)
+                .collect::<Vec<_>>(),
+            vec![10]
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct GuardianPerkData {
+    pub perk: GuardianPerk,
+    pub enabled: bool,
+}
+
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum GuardianPerk {
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_10.GuardianPerk_Enforcer_10",
+        to_string = "Enforcer 10"
+    )]
+    Enforcer10,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_15.GuardianPerk_Enforcer_15",
+        to_string = "Enforcer 15"
+    )]
+    Enforcer15,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_25.GuardianPerk_Enforcer_25",
+        to_string = "Enforcer 25"
+    )]
+    Enforcer25,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_35.GuardianPerk_Enforcer_35",
+        to_string = "Enforcer 35"
+    )]
+    Enforcer35,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_50.GuardianPerk_Enforcer_50",
+        to_string = "Enforcer 50"
+    )]
+    Enforcer50,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_75.GuardianPerk_Enforcer_75",
+        to_string = "Enforcer 75"
+    )]
+    Enforcer75,
+    #[strum(
+        serialize = "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_100.GuardianPerk_Enforcer_100",
+        to_string = "Enforcer 100"
+    )]
+    Enforcer100,
+    #[strum(
+        serialize = "/Game/PatchDLC/Geranium/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Enforcer_125.GuardianPerk_Enforcer_125",
+        to_string = "Enforcer 125"
+    )]
+    Enforcer125,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_10.GuardianPerk_Hunter_10",
+        to_string = "Hunter 10"
+    )]
+    Hunter10,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_15.GuardianPerk_Hunter_15",
+        to_string = "Hunter 15"
+    )]
+    Hunter15,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_25.GuardianPerk_Hunter_25",
+        to_string = "Hunter 25"
+    )]
+    Hunter25,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_35.GuardianPerk_Hunter_35",
+        to_string = "Hunter 35"
+    )]
+    Hunter35,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_50.GuardianPerk_Hunter_50",
+        to_string = "Hunter 50"
+    )]
+    Hunter50,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_75.GuardianPerk_Hunter_75",
+        to_string = "Hunter 75"
+    )]
+    Hunter75,
+    #[strum(
+        serialize = "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_100.GuardianPerk_Hunter_100",
+        to_string = "Hunter 100"
+    )]
+    Hunter100,
+    #[strum(
+        serialize = "/Game/PatchDLC/Geranium/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Hunter_125.GuardianPerk_Hunter_125",
+        to_string = "Hunter 125"
+    )]
+    Hunter125,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_10.GuardianPerk_Survivor_10",
+        to_string = "Survivor 10"
+    )]
+    Survivor10,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_15.GuardianPerk_Survivor_15",
+        to_string = "Survivor 15"
+    )]
+    Survivor15,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_25.GuardianPerk_Survivor_25",
+        to_string = "Survivor 25"
+    )]
+    Survivor25,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_35.GuardianPerk_Survivor_35",
+        to_string = "Survivor 35"
+    )]
+    Survivor35,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_50.GuardianPerk_Survivor_50",
+        to_string = "Survivor 50"
+    )]
+    Survivor50,
+    #[strum(
+        serialize = "/Game/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_75.GuardianPerk_Survivor_75",
+        to_string = "Survivor 75"
+    )]
+    Survivor75,
+    #[strum(
+        serialize = "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_100.GuardianPerk_Survivor_100",
+        to_string = "Survivor 100"
+    )]
+    Survivor100,
+    #[strum(
+        serialize = "/Game/PatchDLC/Geranium/PlayerCharacters/_Shared/_Design/GuardianRank/GuardianPerk_Survivor_125.GuardianPerk_Survivor_125",
+        to_string = "Survivor 125"
+    )]
+    Survivor125,
+}
+
+impl std::default::Default for GuardianPerk {
+    fn default() -> Self {
+        Self::Enforcer10
+    }
+}
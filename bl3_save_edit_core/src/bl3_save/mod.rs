@@ -1,28 +1,44 @@
 use std::io::Write;
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use strum::IntoEnumIterator;
+use uuid::Uuid;
 
-use crate::bl3_save::character_data::CharacterData;
+use crate::anonymize::{anonymize_mail_items, Pseudonyms};
+use crate::bl3_save::ammo::AmmoPool;
+use crate::bl3_save::character_data::{CharacterData, MAX_CHARACTER_LEVEL};
 use crate::bl3_save::inventory_slot::InventorySlot;
-use crate::file_helper::FileData;
+use crate::bl3_save::player_class::PlayerClass;
+use crate::bl3_save::util::REQUIRED_XP_LIST;
+use crate::file_helper::{FileData, FileFingerprint};
 use crate::models::CustomFormatData;
 use crate::parser::{decrypt, encrypt, HeaderType};
+use crate::resources::NEW_CHARACTER_HEADER;
 use crate::{file_helper, parser};
 
 pub mod ammo;
 pub mod challenge_data;
 pub mod character_data;
+pub mod crew_quarters;
+pub mod echo_log_data;
 pub mod fast_travel_unlock_data;
+pub mod game_stats;
+pub mod guardian_perk;
 pub mod inventory_slot;
 pub mod level_data;
 pub mod models;
+mod new_character;
 pub mod player_class;
+pub mod player_color;
 pub mod playthrough;
+pub mod region_data;
 pub mod sdu;
+pub mod ui_tracking;
 pub mod util;
+pub mod zone_map_fod;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Bl3Save {
@@ -40,6 +56,7 @@ pub struct Bl3Save {
     pub save_game_type: String,
     pub header_type: HeaderType,
     pub character_data: CharacterData,
+    pub file_fingerprint: Option<FileFingerprint>,
 }
 
 impl Bl3Save {
@@ -86,6 +103,7 @@ impl Bl3Save {
             save_game_type,
             header_type,
             character_data,
+            file_fingerprint: None,
         })
     }
 
@@ -129,6 +147,127 @@ impl Bl3Save {
 
         Ok((output, new_save))
     }
+
+    // Only the header is kept from a game-written save, the character itself is built from
+    // scratch. The new character isn't written anywhere until it is passed to clone_as
+    pub fn new_character(
+        player_class: PlayerClass,
+        level: i32,
+        header_type: HeaderType,
+    ) -> Result<Bl3Save> {
+        if level < 1 || level > MAX_CHARACTER_LEVEL as i32 {
+            bail!(
+                "Level must be between 1 and {}, got: {}",
+                MAX_CHARACTER_LEVEL,
+                level
+            );
+        }
+
+        if !matches!(header_type, HeaderType::PcSave | HeaderType::Ps4Save) {
+            bail!("A new character can't be created as a {}.", header_type);
+        }
+
+        let header =
+            file_helper::read_bytes(Path::new("NEW_CHARACTER_HEADER.sav"), NEW_CHARACTER_HEADER)
+                .context("failed to read new character header")?;
+
+        let head_skin = player_class.default_head_skin();
+
+        let mut character = new_character::new_character(player_class);
+
+        character.save_game_guid = generate_save_game_guid();
+        character.preferred_character_name = head_skin.name.to_owned();
+
+        let mut character_data = CharacterData::from_character(character)?;
+
+        character_data.set_head_skin_selected(&head_skin);
+        character_data.set_character_skin_selected(&player_class.default_character_skin());
+        character_data.set_equipped_emotes(&player_class.default_emotes())?;
+        character_data.set_player_level(REQUIRED_XP_LIST[level as usize - 1][0])?;
+
+        for ammo_pool in AmmoPool::iter() {
+            character_data.fill_ammo_pool(&ammo_pool)?;
+        }
+
+        Ok(Bl3Save {
+            file_name: String::new(),
+            save_game_version: header.file_version,
+            package_version: header.package_version,
+            engine_major: header.engine_major,
+            engine_minor: header.engine_minor,
+            engine_patch: header.engine_patch,
+            engine_build: header.engine_build,
+            build_id: header.build_id,
+            custom_format_version: header.custom_format_version,
+            custom_format_data_count: header.custom_format_data_count,
+            custom_format_data: header.custom_format_data,
+            save_game_type: header.save_game_type,
+            header_type,
+            character_data,
+            file_fingerprint: None,
+        })
+    }
+
+    pub fn clone_as(&self, save_dir: &Path, slot: u32, name: &str) -> Result<Bl3Save> {
+        if slot < 1 {
+            bail!("Save slot must be 1 or greater.");
+        }
+
+        let name = name.trim();
+
+        if name.is_empty() {
+            bail!("Character name must not be empty.");
+        }
+
+        let file_name = save_file_name(slot);
+        let output_file = save_dir.join(&file_name);
+
+        if output_file.exists() {
+            bail!("Save slot {} already exists: {}", slot, file_name);
+        }
+
+        let mut new_save = self.clone();
+
+        new_save.file_name = file_name.clone();
+
+        let character = &mut new_save.character_data.character;
+        character.save_game_id = slot;
+        character.save_game_guid = generate_save_game_guid();
+        character.preferred_character_name = name.to_owned();
+
+        let (output, mut new_save) = new_save.as_bytes()?;
+
+        // Fails if the slot was taken while the save was being encoded
+        file_helper::write_new_file_atomic(&output_file, &output)?;
+
+        new_save.file_fingerprint = Some(FileFingerprint::new(&output_file, &output));
+
+        Ok(new_save)
+    }
+
+    /// Renames the character after its class, gives it a new guid and replaces the ids of other
+    /// players in its copy of the profile mail with pseudonyms.
+    pub fn anonymize(&mut self, pseudonyms: &mut Pseudonyms) {
+        let player_class = self.character_data.player_class().to_string();
+
+        let character = &mut self.character_data.character;
+        character.preferred_character_name = player_class;
+        character.save_game_guid = generate_save_game_guid();
+        character.nickname_mappings.clear();
+
+        // The save carries a copy of the profile's mail for cross-save cloud syncing
+        if let Some(profile_cloud_data) = character.profile_cloud_data.as_mut() {
+            anonymize_mail_items(&mut profile_cloud_data.npc_mail_items, pseudonyms);
+        }
+    }
+}
+
+pub fn save_file_name(slot: u32) -> String {
+    format!("{:x}.sav", slot)
+}
+
+pub fn generate_save_game_guid() -> String {
+    Uuid::new_v4().to_simple().to_string().to_uppercase()
 }
 
 impl std::fmt::Display for Bl3Save {
@@ -2312,4 +2451,142 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_clone_as() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let save_dir = std::env::temp_dir().join(format!("bl3_save_edit_{}", Uuid::new_v4()));
+
+        fs::create_dir_all(&save_dir).expect("failed to create temp dir");
+
+        let new_save = bl3_save
+            .clone_as(&save_dir, 26, "Clone")
+            .expect("failed to clone save");
+
+        assert_eq!(new_save.file_name, "1a.sav");
+        assert_eq!(new_save.character_data.character.save_game_id, 26);
+        assert_eq!(
+            new_save.character_data.character.preferred_character_name,
+            "Clone"
+        );
+        assert_ne!(
+            new_save.character_data.character.save_game_guid,
+            bl3_save.character_data.character.save_game_guid
+        );
+
+        let output_file = save_dir.join("1a.sav");
+
+        new_save
+            .file_fingerprint
+            .as_ref()
+            .expect("missing file fingerprint")
+            .ensure_unchanged(&output_file)
+            .expect("file changed after cloning");
+
+        let written = fs::read(&output_file).expect("failed to read cloned save");
+
+        let written_save = Bl3Save::from_bytes(&output_file, &written, HeaderType::PcSave)
+            .expect("failed to read cloned save");
+
+        assert_eq!(written_save.character_data, new_save.character_data);
+
+        // Never overwrite an existing slot
+        assert!(bl3_save.clone_as(&save_dir, 26, "Another Clone").is_err());
+        assert_eq!(
+            fs::read(&output_file).expect("failed to read cloned save"),
+            written
+        );
+
+        assert!(bl3_save.clone_as(&save_dir, 0, "Clone").is_err());
+        assert!(bl3_save.clone_as(&save_dir, 27, "  ").is_err());
+
+        fs::remove_dir_all(&save_dir).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_new_character() {
+        let save_dir = std::env::temp_dir().join(format!("bl3_save_edit_{}", Uuid::new_v4()));
+
+        fs::create_dir_all(&save_dir).expect("failed to create temp dir");
+
+        let levels = [1, 2, 50, MAX_CHARACTER_LEVEL as i32];
+
+        let mut guids = Vec::new();
+
+        for (slot, (player_class, level)) in PlayerClass::ALL.iter().zip(levels).enumerate() {
+            let new_save = Bl3Save::new_character(*player_class, level, HeaderType::PcSave)
+                .expect("failed to create new character");
+
+            let name = &new_save.character_data.character.preferred_character_name;
+
+            assert_eq!(name, player_class.default_head_skin().name);
+
+            let new_save = new_save
+                .clone_as(&save_dir, slot as u32 + 1, name)
+                .expect("failed to write new character");
+
+            let output_file = save_dir.join(&new_save.file_name);
+
+            let written = fs::read(&output_file).expect("failed to read new character");
+
+            let new_save = Bl3Save::from_bytes(&output_file, &written, HeaderType::PcSave)
+                .expect("failed to re-read new character");
+
+            let character_data = &new_save.character_data;
+
+            assert_eq!(character_data.player_class(), *player_class);
+            assert_eq!(character_data.player_level(), level);
+            assert_eq!(character_data.ability_points(), (level - 2).max(0));
+            assert_eq!(
+                character_data.head_skin_selected(),
+                player_class.default_head_skin()
+            );
+            assert!(character_data
+                .challenge_milestones()
+                .iter()
+                .all(|c| !c.unlocked));
+            assert!(character_data
+                .character
+                .challenge_data
+                .iter()
+                .all(|c| !player_class.is_other_class_asset(&c.challenge_class_path)));
+            assert!(character_data
+                .ammo_pools()
+                .iter()
+                .all(|a| a.current == character_data.ammo_pool_capacity(&a.pool)));
+            assert_eq!(character_data.unlockable_inventory_slots().len(), 8);
+            assert_eq!(character_data.playthroughs().len(), 1);
+
+            let guid = character_data.character.save_game_guid.clone();
+
+            assert_eq!(guid.len(), 32);
+            assert!(!guids.contains(&guid));
+
+            guids.push(guid);
+        }
+
+        let ps4_save = Bl3Save::new_character(PlayerClass::Siren, 1, HeaderType::Ps4Save)
+            .expect("failed to create new character")
+            .clone_as(&save_dir, 5, "Siren")
+            .expect("failed to write new character");
+
+        let output_file = save_dir.join(&ps4_save.file_name);
+
+        let written = fs::read(&output_file).expect("failed to read new character");
+
+        assert!(Bl3Save::from_bytes(&output_file, &written, HeaderType::Ps4Save).is_ok());
+        assert!(Bl3Save::from_bytes(&output_file, &written, HeaderType::PcSave).is_err());
+
+        assert!(Bl3Save::new_character(PlayerClass::Siren, 0, HeaderType::PcSave).is_err());
+        assert!(Bl3Save::new_character(PlayerClass::Siren, 73, HeaderType::PcSave).is_err());
+        assert!(Bl3Save::new_character(PlayerClass::Siren, 1, HeaderType::PcProfile).is_err());
+
+        fs::remove_dir_all(&save_dir).expect("failed to remove temp dir");
+    }
 }
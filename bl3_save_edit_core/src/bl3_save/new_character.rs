@@ -0,0 +1,148 @@
+use strum::{EnumMessage, IntoEnumIterator};
+
+use crate::bl3_save::ammo::AmmoPool;
+use crate::bl3_save::challenge_data::Challenge;
+use crate::bl3_save::inventory_slot::InventorySlot;
+use crate::bl3_save::level_data::LEVEL_CHALLENGES;
+use crate::bl3_save::player_class::PlayerClass;
+use crate::protos::oak_save::{
+    ActiveFastTravelSaveData, ChallengeCategoryProgressSaveData, Character,
+    CustomPlayerColorSaveGameData, EquippedInventorySaveGameData, GameStateSaveData, MapIDData,
+    MissionPlaythroughSaveGameData, OakPlayerAbilitySaveGameData, PlayerClassSaveGameData,
+    PlaythroughActiveFastTravelSaveData, RegionSaveGameData, ResourcePoolSavegameData,
+};
+use crate::protos::oak_shared::ChallengeSaveGameData;
+
+const STARTING_REGION: &str =
+    "/Game/GameData/Regions/Zone0/Region_Zone0_Recruitment_A.Region_Zone0_Recruitment_A";
+
+const STARTING_TRAVEL_STATION: &str = "/Game/GameData/FastTravel/FTS_Recruitment.FTS_Recruitment";
+
+const STARTING_RESURRECT_STATION: &str = "/Game/GameData/FastTravel/ResurrectTravelStations/AutoGen/Recruitment/RTS_AUTOGEN_ResurrectTravelStationObject_CoVCamp.RTS_AUTOGEN_ResurrectTravelStationObject_CoVCamp";
+
+// Recruitment (Covenant Pass)
+const STARTING_MAP_ID: (u32, u32) = (1, 43);
+
+const COLOR_PARAMETERS: [&str; 3] = ["*Color_Primary1", "*Color_Secondary1", "*Color_Tertiary1"];
+
+/// Builds the character a fresh playthrough starts with in Covenant Pass, only holding what the
+/// game writes before the first mission. Anything else (discovery, missions, vehicles etc.) is
+/// filled in by the game the first time the character is loaded.
+pub(crate) fn new_character(player_class: PlayerClass) -> Character {
+    let player_class_data = PlayerClassSaveGameData {
+        player_class_path: player_class.get_serializations()[0].to_owned(),
+        ..Default::default()
+    };
+
+    let resource_pools = AmmoPool::iter()
+        .map(|pool| ResourcePoolSavegameData {
+            resource_path: pool.get_serializations()[0].to_owned(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    let saved_regions = vec![RegionSaveGameData {
+        game_stage: 1,
+        region_path: STARTING_REGION.to_owned(),
+        ..Default::default()
+    }];
+
+    // The first four slots are available from the start, the rest are unlocked through the story
+    let equipped_inventory_list = [
+        (InventorySlot::Grenade, true),
+        (InventorySlot::Shield, true),
+        (InventorySlot::Weapon1, true),
+        (InventorySlot::Weapon2, true),
+        (InventorySlot::Weapon3, false),
+        (InventorySlot::Weapon4, false),
+        (InventorySlot::Artifact, false),
+        (InventorySlot::ClassMod, false),
+    ]
+    .iter()
+    .map(|(slot, enabled)| EquippedInventorySaveGameData {
+        inventory_list_index: -1,
+        enabled: *enabled,
+        slot_data_path: slot.get_serializations()[0].to_owned(),
+        ..Default::default()
+    })
+    .collect::<Vec<_>>();
+
+    // Only the challenges the editor reads are added, the game adds the rest when loading
+    let challenge_data = LEVEL_CHALLENGES
+        .iter()
+        .map(|(_, path)| path.to_owned())
+        .chain(
+            Challenge::iter()
+                .map(|c| c.get_serializations()[0])
+                .filter(|path| !player_class.is_other_class_asset(path)),
+        )
+        .map(|path| ChallengeSaveGameData {
+            is_active: true,
+            challenge_class_path: path.to_owned(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    let selected_color_customizations = COLOR_PARAMETERS
+        .iter()
+        .map(|parameter| CustomPlayerColorSaveGameData {
+            color_parameter: parameter.to_string(),
+            applied_color: Some(Default::default()).into(),
+            split_color: Some(Default::default()).into(),
+            use_default_color: true,
+            use_default_split_color: true,
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    let last_traveled_map_id = MapIDData {
+        zone_name_id: STARTING_MAP_ID.0,
+        map_name_id: STARTING_MAP_ID.1,
+        ..Default::default()
+    };
+
+    let active_travel_stations = PlaythroughActiveFastTravelSaveData {
+        active_travel_stations: vec![ActiveFastTravelSaveData {
+            active_travel_station_name: STARTING_TRAVEL_STATION.to_owned(),
+            ..Default::default()
+        }]
+        .into(),
+        ..Default::default()
+    };
+
+    Character {
+        player_class_data: Some(player_class_data).into(),
+        resource_pools: resource_pools.into(),
+        saved_regions: saved_regions.into(),
+        equipped_inventory_list: equipped_inventory_list.into(),
+        active_weapon_list: vec![-1],
+        ability_data: Some(OakPlayerAbilitySaveGameData::default()).into(),
+        mission_playthroughs_data: vec![MissionPlaythroughSaveGameData::default()].into(),
+        vehicle_last_loadout_index: -1,
+        challenge_data: challenge_data.into(),
+        selected_color_customizations: selected_color_customizations.into(),
+        crew_quarters_room: Some(Default::default()).into(),
+        crew_quarters_gun_rack: Some(Default::default()).into(),
+        challenge_category_completion_pcts: Some(ChallengeCategoryProgressSaveData {
+            category_progress: vec![0; 8],
+            ..Default::default()
+        })
+        .into(),
+        character_slot_save_game_data: Some(Default::default()).into(),
+        ui_tracking_save_game_data: Some(Default::default()).into(),
+        name_character_limit: 20,
+        preferred_group_mode: 1,
+        last_active_travel_station_for_playthrough: vec![STARTING_RESURRECT_STATION.to_owned()]
+            .into(),
+        game_state_save_data_for_playthrough: vec![GameStateSaveData {
+            last_traveled_map_id: Some(last_traveled_map_id).into(),
+            ..Default::default()
+        }]
+        .into(),
+        active_travel_stations_for_playthrough: vec![active_travel_stations].into(),
+        optional_objective_reward_fixup_applied: true,
+        vehicle_part_rewards_fixup_applied: true,
+        levelled_save_vehicle_part_rewards_fixup_applied: true,
+        ..Default::default()
+    }
+}
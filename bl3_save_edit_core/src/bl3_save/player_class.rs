@@ -1,5 +1,10 @@
 use strum::{Display, EnumMessage, EnumString};
 
+use crate::bl3_save::challenge_data::Challenge;
+use crate::game_data::{
+    GameDataKv, PROFILE_EMOTES_DEFAULTS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS_DEFAULTS,
+};
+
 #[derive(Clone, Copy, Debug, Display, EnumString, EnumMessage, Eq, PartialEq, Ord, PartialOrd)]
 pub enum PlayerClass {
     #[strum(
@@ -37,4 +42,111 @@ impl PlayerClass {
         PlayerClass::Operative,
         PlayerClass::Siren,
     ];
+
+    // Folder name used by this class for its assets and customizations
+    pub fn asset_folder(&self) -> &'static str {
+        match self {
+            PlayerClass::BeastMaster => "Beastmaster",
+            PlayerClass::Gunner => "Gunner",
+            PlayerClass::Operative => "Operative",
+            PlayerClass::Siren => "SirenBrawler",
+        }
+    }
+
+    // Some DLC assets aren't stored in a class folder but still carry the class in their name
+    pub fn is_other_class_asset(&self, path: &str) -> bool {
+        PlayerClass::ALL
+            .iter()
+            .filter(|c| *c != self)
+            .any(|c| c.is_class_asset(path))
+    }
+
+    pub fn from_asset_path(path: &str) -> Option<PlayerClass> {
+        PlayerClass::ALL
+            .iter()
+            .find(|c| c.is_class_asset(path))
+            .copied()
+    }
+
+    // Paths aren't consistently cased, i.e. `CustomHead_BeastMaster_Twitch`
+    fn is_class_asset(&self, path: &str) -> bool {
+        let path = path.to_lowercase();
+        let asset_folder = self.asset_folder().to_lowercase();
+        let name = self.to_string().to_lowercase();
+
+        path.contains(&format!("/{}/", asset_folder))
+            || path.contains(&format!("/{}/", name))
+            || path.contains(&format!("_{}_", name))
+    }
+
+    pub fn class_mod_slot_challenge(&self) -> Challenge {
+        match self {
+            PlayerClass::BeastMaster => Challenge::BeastMasterClassModSlot,
+            PlayerClass::Gunner => Challenge::GunnerClassModSlot,
+            PlayerClass::Operative => Challenge::OperativeClassModSlot,
+            PlayerClass::Siren => Challenge::SirenClassModSlot,
+        }
+    }
+
+    pub fn default_head_skin(&self) -> GameDataKv {
+        self.find_default(&PROFILE_HEADS_DEFAULTS)
+    }
+
+    pub fn default_character_skin(&self) -> GameDataKv {
+        self.find_default(&PROFILE_SKINS_DEFAULTS)
+    }
+
+    pub fn default_emotes(&self) -> Vec<GameDataKv> {
+        let folder = format!("/{}/", self.asset_folder());
+
+        PROFILE_EMOTES_DEFAULTS
+            .iter()
+            .filter(|e| e.ident.contains(&folder))
+            .cloned()
+            .collect()
+    }
+
+    fn find_default(&self, defaults: &[GameDataKv]) -> GameDataKv {
+        let folder = format!("/{}/", self.asset_folder());
+
+        defaults
+            .iter()
+            .find(|d| d.ident.contains(&folder))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PlayerClassChange {
+    pub previous_class: PlayerClass,
+    pub new_class: PlayerClass,
+    pub head_skin: GameDataKv,
+    pub character_skin: GameDataKv,
+    pub class_mod_slot_moved: bool,
+    pub customizations_removed: usize,
+    pub skill_tree_items_removed: usize,
+    pub ability_slots_removed: usize,
+    pub augments_removed: usize,
+}
+
+impl std::fmt::Display for PlayerClassChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Changed class from {} to {}",
+            self.previous_class, self.new_class
+        )?;
+        writeln!(f, "Head: {}", self.head_skin.name)?;
+        writeln!(f, "Skin: {}", self.character_skin.name)?;
+        writeln!(f, "Class Mod slot moved: {}", self.class_mod_slot_moved)?;
+        writeln!(f, "Customizations removed: {}", self.customizations_removed)?;
+        writeln!(
+            f,
+            "Skill tree items removed: {}",
+            self.skill_tree_items_removed
+        )?;
+        writeln!(f, "Action skills removed: {}", self.ability_slots_removed)?;
+        write!(f, "Augments removed: {}", self.augments_removed)
+    }
 }
@@ -0,0 +1,127 @@
+use crate::protos::oak_save::CustomPlayerColorSaveGameData;
+use crate::protos::oak_shared::Vec3;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PlayerColor {
+    pub parameter: String,
+    pub applied_color: [f32; 3],
+    pub split_color: [f32; 3],
+    pub use_default_color: bool,
+    pub use_default_split_color: bool,
+}
+
+impl PlayerColor {
+    pub fn from_save_data(save_data: &CustomPlayerColorSaveGameData) -> Self {
+        Self {
+            parameter: save_data.color_parameter.clone(),
+            applied_color: vec3_to_color(save_data.applied_color.as_ref()),
+            split_color: vec3_to_color(save_data.split_color.as_ref()),
+            use_default_color: save_data.use_default_color,
+            use_default_split_color: save_data.use_default_split_color,
+        }
+    }
+
+    pub fn apply_to_save_data(&self, save_data: &mut CustomPlayerColorSaveGameData) {
+        save_data.color_parameter = self.parameter.clone();
+        save_data.applied_color = Some(color_to_vec3(self.applied_color)).into();
+        save_data.split_color = Some(color_to_vec3(self.split_color)).into();
+        save_data.use_default_color = self.use_default_color;
+        save_data.use_default_split_color = self.use_default_split_color;
+    }
+
+    // *Color_Primary1 -> Primary
+    pub fn name(&self) -> String {
+        self.parameter
+            .trim_start_matches('*')
+            .trim_start_matches("Color_")
+            .trim_end_matches(|c: char| c.is_ascii_digit())
+            .to_owned()
+    }
+}
+
+pub fn color_to_rgb8(color: [f32; 3]) -> [u8; 3] {
+    color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+pub fn rgb8_to_color(rgb: [u8; 3]) -> [f32; 3] {
+    rgb.map(|c| c as f32 / 255.0)
+}
+
+fn vec3_to_color(vec3: Option<&Vec3>) -> [f32; 3] {
+    vec3.map(|v| [v.x, v.y, v.z]).unwrap_or_default()
+}
+
+fn color_to_vec3(color: [f32; 3]) -> Vec3 {
+    Vec3 {
+        x: color[0],
+        y: color[1],
+        z: color[2],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_color_conversion() {
+        assert_eq!(color_to_rgb8([0.0, 0.5, 1.0]), [0, 128, 255]);
+        assert_eq!(color_to_rgb8([-1.0, 2.0, 0.2]), [0, 255, 51]);
+        assert_eq!(color_to_rgb8(rgb8_to_color([12, 200, 255])), [12, 200, 255]);
+    }
+
+    #[test]
+    fn test_player_colors() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character = &bl3_save.character_data.character;
+
+        assert!(!character.selected_color_customizations.is_empty());
+
+        for save_data in character.selected_color_customizations.iter() {
+            let mut applied = save_data.clone();
+
+            PlayerColor::from_save_data(save_data).apply_to_save_data(&mut applied);
+
+            assert_eq!(applied, *save_data);
+        }
+
+        let mut player_color = bl3_save.character_data.player_colors()[0].clone();
+
+        assert!(!player_color.name().starts_with('*'));
+        assert!(!player_color.name().starts_with("Color_"));
+
+        player_color.applied_color = rgb8_to_color([255, 0, 0]);
+        player_color.use_default_color = false;
+
+        bl3_save.character_data.set_player_color(&player_color);
+
+        let new_color = PlayerColor {
+            parameter: "*Color_Test1".to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(new_color.name(), "Test");
+
+        bl3_save.character_data.set_player_color(&new_color);
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let player_colors = bl3_save.character_data.player_colors();
+
+        assert_eq!(player_colors[0], player_color);
+        assert_eq!(player_colors.last(), Some(&new_color));
+    }
+}
@@ -0,0 +1,156 @@
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::game_data::REGIONS;
+use crate::protos::oak_save::RegionSaveGameData;
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct RegionGameStage {
+    pub playthrough_index: i32,
+    pub name: String,
+    pub region_path: String,
+    pub game_stage: i32,
+}
+
+impl RegionGameStage {
+    pub fn new(region: &RegionSaveGameData) -> Self {
+        Self {
+            playthrough_index: region.play_through_idx,
+            name: region_name(&region.region_path),
+            region_path: region.region_path.clone(),
+            game_stage: region.game_stage,
+        }
+    }
+}
+
+pub fn region_name(region_path: &str) -> String {
+    REGIONS
+        .par_iter()
+        .find_first(|gd| region_path.eq_ignore_ascii_case(gd.ident))
+        .map(|gd| gd.name.to_owned())
+        .unwrap_or_else(|| {
+            // Fall back to the asset name, i.e. Region_Zone3_FinalVault
+            region_path
+                .rsplit('.')
+                .next()
+                .unwrap_or(region_path)
+                .to_owned()
+        })
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct LevelPersistenceActor {
+    pub level_name: String,
+    pub actor_name: String,
+    pub timer_remaining: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_save;
+
+    use super::*;
+
+    #[test]
+    fn test_region_name() {
+        assert_eq!(region_name(REGIONS[0].ident), REGIONS[0].name);
+        assert_eq!(
+            region_name(&REGIONS[0].ident.to_uppercase()),
+            REGIONS[0].name
+        );
+        assert_eq!(
+            region_name("/Game/Test/Region_Unknown.Region_Unknown"),
+            "Region_Unknown"
+        );
+    }
+
+    #[test]
+    fn test_region_game_stages() {
+        let mut bl3_save = read_test_save("19.sav", HeaderType::PcSave);
+
+        let region_game_stages = bl3_save.character_data.region_game_stages();
+
+        assert_eq!(
+            region_game_stages.len(),
+            bl3_save.character_data.character.saved_regions.len()
+        );
+        assert!(region_game_stages.windows(2).all(|w| w[0] <= w[1]));
+
+        let region = region_game_stages
+            .last()
+            .cloned()
+            .expect("failed to find a region");
+
+        for invalid_game_stage in [0, MAX_CHARACTER_LEVEL as i32 + 1] {
+            assert!(bl3_save
+                .character_data
+                .set_region_game_stage(
+                    &region.region_path,
+                    region.playthrough_index,
+                    invalid_game_stage
+                )
+                .is_err());
+        }
+
+        assert!(bl3_save
+            .character_data
+            .set_region_game_stage(&region.region_path, 99, 10)
+            .is_err());
+
+        bl3_save
+            .character_data
+            .set_region_game_stage(&region.region_path, region.playthrough_index, 10)
+            .expect("failed to set region game stage");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let region_game_stages = bl3_save.character_data.region_game_stages();
+
+        assert!(region_game_stages.contains(&RegionGameStage {
+            game_stage: 10,
+            ..region
+        }));
+    }
+
+    #[test]
+    fn test_reset_level_persistence() {
+        let mut bl3_save = read_test_save("1.sav", HeaderType::PcSave);
+
+        bl3_save
+            .character_data
+            .character
+            .accumulated_level_persistence_reset_timer_seconds = 3600;
+
+        let level_persistence_actors = bl3_save.character_data.level_persistence_actors();
+
+        assert!(!level_persistence_actors.is_empty());
+
+        assert_eq!(
+            level_persistence_actors.len(),
+            bl3_save
+                .character_data
+                .character
+                .level_persistence_data
+                .iter()
+                .map(|l| l.saved_actors.len())
+                .sum::<usize>()
+        );
+
+        bl3_save.character_data.reset_level_persistence();
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        assert!(bl3_save
+            .character_data
+            .level_persistence_actors()
+            .is_empty());
+        assert_eq!(
+            bl3_save
+                .character_data
+                .character
+                .accumulated_level_persistence_reset_timer_seconds,
+            0
+        );
+    }
+}
@@ -1,5 +1,11 @@
 use strum::{Display, EnumIter, EnumMessage, EnumString};
 
+// A new character can carry 20 items and every Backpack SDU adds 3 more (59 at level 13), this is
+// what the game shows in the inventory menu and matches `test_files/1.sav` which has a full
+// backpack of 20 items without any Backpack SDU.
+const BACKPACK_BASE_CAPACITY: i32 = 20;
+const BACKPACK_CAPACITY_PER_LEVEL: i32 = 3;
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct SaveSduSlotData {
     pub sdu: SaveSduSlot,
@@ -67,4 +73,32 @@ impl SaveSduSlot {
             | SaveSduSlot::Ar => 10,
         }
     }
+    /// Number of items the backpack holds at `level`, not counting equipped items.
+    pub fn backpack_capacity(level: i32) -> i32 {
+        let level = level.clamp(0, SaveSduSlot::Backpack.maximum());
+
+        BACKPACK_BASE_CAPACITY + level * BACKPACK_CAPACITY_PER_LEVEL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backpack_capacity() {
+        assert_eq!(SaveSduSlot::backpack_capacity(0), 20);
+        assert_eq!(SaveSduSlot::backpack_capacity(1), 23);
+        assert_eq!(
+            SaveSduSlot::backpack_capacity(SaveSduSlot::Backpack.maximum()),
+            59
+        );
+
+        // Levels outside of the SDU range are clamped
+        assert_eq!(SaveSduSlot::backpack_capacity(-1), 20);
+        assert_eq!(
+            SaveSduSlot::backpack_capacity(SaveSduSlot::Backpack.maximum() + 1),
+            59
+        );
+    }
 }
@@ -0,0 +1,181 @@
+use strum::EnumMessage;
+
+use crate::bl3_save::inventory_slot::{InventorySlot, InventorySlotData};
+use crate::protos::oak_save::UITrackingSaveGameData;
+
+/// The one-off prompts a character is shown the first time a menu or HUD element unlocks.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct UiTrackingData {
+    pub has_seen_skill_menu_unlock: bool,
+    pub has_seen_guardian_rank_menu_unlock: bool,
+    pub has_seen_echo_boot_ammo_bar: bool,
+    pub has_seen_echo_boot_shield_bar: bool,
+    pub has_seen_echo_boot_grenades: bool,
+    pub highest_thvm_breadcrumb_seen: i32,
+    pub inventory_slot_unlocks_seen: Vec<String>,
+}
+
+impl UiTrackingData {
+    pub fn from_save_data(ui_tracking: Option<&UITrackingSaveGameData>) -> Self {
+        ui_tracking
+            .map(|u| Self {
+                has_seen_skill_menu_unlock: u.has_seen_skill_menu_unlock,
+                has_seen_guardian_rank_menu_unlock: u.has_seen_guardian_rank_menu_unlock,
+                has_seen_echo_boot_ammo_bar: u.has_seen_echo_boot_ammo_bar,
+                has_seen_echo_boot_shield_bar: u.has_seen_echo_boot_shield_bar,
+                has_seen_echo_boot_grenades: u.has_seen_echo_boot_grenades,
+                highest_thvm_breadcrumb_seen: u.highest_thvm_breadcrumb_seen,
+                inventory_slot_unlocks_seen: u.inventory_slot_unlocks_seen.to_vec(),
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn prompts(&self) -> [(&'static str, bool); 5] {
+        [
+            ("Skill Menu Unlock", self.has_seen_skill_menu_unlock),
+            (
+                "Guardian Rank Menu Unlock",
+                self.has_seen_guardian_rank_menu_unlock,
+            ),
+            ("ECHO Boot Ammo Bar", self.has_seen_echo_boot_ammo_bar),
+            ("ECHO Boot Shield Bar", self.has_seen_echo_boot_shield_bar),
+            ("ECHO Boot Grenades", self.has_seen_echo_boot_grenades),
+        ]
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Only inventory slots the character has unlocked are marked as seen, the game shows the
+    /// prompt for the others when they unlock. Saves only ever record the slots unlocked during
+    /// the story, the starting slots have no prompt.
+    pub fn mark_all_seen(&mut self, unlockable_inventory_slots: &[InventorySlotData]) {
+        self.has_seen_skill_menu_unlock = true;
+        self.has_seen_guardian_rank_menu_unlock = true;
+        self.has_seen_echo_boot_ammo_bar = true;
+        self.has_seen_echo_boot_shield_bar = true;
+        self.has_seen_echo_boot_grenades = true;
+
+        for slot in unlockable_inventory_slots.iter().filter(|s| {
+            s.unlocked
+                && matches!(
+                    s.slot,
+                    InventorySlot::Weapon3
+                        | InventorySlot::Weapon4
+                        | InventorySlot::ClassMod
+                        | InventorySlot::Artifact
+                )
+        }) {
+            let slot_path = slot.slot.get_serializations()[0];
+
+            if !self
+                .inventory_slot_unlocks_seen
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(slot_path))
+            {
+                self.inventory_slot_unlocks_seen.push(slot_path.to_owned());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_save;
+
+    use super::*;
+
+    fn slot(slot: InventorySlot, unlocked: bool) -> InventorySlotData {
+        InventorySlotData { slot, unlocked }
+    }
+
+    #[test]
+    fn test_ui_tracking() {
+        assert_eq!(
+            UiTrackingData::from_save_data(None),
+            UiTrackingData::default()
+        );
+
+        let save = read_test_save("1.sav", HeaderType::PcSave);
+
+        let ui_tracking = save.character_data.ui_tracking();
+
+        assert!(ui_tracking.prompts().iter().all(|(_, seen)| *seen));
+        assert_eq!(ui_tracking.highest_thvm_breadcrumb_seen, 0);
+        assert_eq!(
+            ui_tracking.inventory_slot_unlocks_seen,
+            vec![
+                InventorySlot::Weapon3.get_serializations()[0],
+                InventorySlot::ClassMod.get_serializations()[0],
+                InventorySlot::Weapon4.get_serializations()[0],
+                InventorySlot::Artifact.get_serializations()[0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mark_all_seen() {
+        let slots = [
+            slot(InventorySlot::Weapon1, true),
+            slot(InventorySlot::Weapon3, true),
+            slot(InventorySlot::Weapon4, false),
+            slot(InventorySlot::Artifact, true),
+        ];
+
+        let mut ui_tracking = UiTrackingData::default();
+        ui_tracking.mark_all_seen(&slots);
+        ui_tracking.mark_all_seen(&slots);
+
+        assert!(ui_tracking.prompts().iter().all(|(_, seen)| *seen));
+        assert_eq!(
+            ui_tracking.inventory_slot_unlocks_seen,
+            vec![
+                InventorySlot::Weapon3.get_serializations()[0],
+                InventorySlot::Artifact.get_serializations()[0],
+            ]
+        );
+
+        ui_tracking.reset();
+
+        assert_eq!(ui_tracking, UiTrackingData::default());
+    }
+
+    #[test]
+    fn test_set_ui_tracking() {
+        let mut save = read_test_save("1.sav", HeaderType::PcSave);
+
+        let mut ui_tracking = save.character_data.ui_tracking();
+        ui_tracking.reset();
+
+        save.character_data.set_ui_tracking(&ui_tracking);
+
+        let (_, mut save) = save.as_bytes().expect("failed to write save");
+
+        assert_eq!(save.character_data.ui_tracking(), UiTrackingData::default());
+
+        // Fields the editor doesn't manage are kept
+        assert_eq!(
+            save.character_data
+                .character
+                .get_ui_tracking_save_game_data()
+                .saved_spin_offset,
+            1
+        );
+
+        ui_tracking.mark_all_seen(save.character_data.unlockable_inventory_slots());
+        ui_tracking.highest_thvm_breadcrumb_seen = 3;
+
+        save.character_data.set_ui_tracking(&ui_tracking);
+
+        let (_, save) = save.as_bytes().expect("failed to write save");
+
+        let saved = save.character_data.ui_tracking();
+
+        assert_eq!(saved, ui_tracking);
+        assert!(saved.prompts().iter().all(|(_, seen)| *seen));
+        assert_eq!(saved.highest_thvm_breadcrumb_seen, 3);
+        assert_eq!(saved.inventory_slot_unlocks_seen.len(), 4);
+    }
+}
@@ -0,0 +1,421 @@
+use std::io::Write;
+
+use anyhow::{bail, Result};
+use byteorder::{LittleEndian, WriteBytesExt};
+use nom::Finish;
+
+use crate::error::BL3ParserError;
+use crate::parser::read_int;
+
+const FOD_MAGIC: &[u8] = b"GBXFOD";
+const FOD_CHUNK_MIN_LEN: usize = 9;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FodChunkState {
+    OutOfBounds,
+    Hidden,
+    Partial,
+    Revealed,
+    Unknown(u32),
+}
+
+impl From<u32> for FodChunkState {
+    fn from(v: u32) -> Self {
+        match v {
+            1 => FodChunkState::OutOfBounds,
+            2 => FodChunkState::Hidden,
+            3 => FodChunkState::Partial,
+            4 => FodChunkState::Revealed,
+            v => FodChunkState::Unknown(v),
+        }
+    }
+}
+
+impl From<FodChunkState> for u32 {
+    fn from(s: FodChunkState) -> Self {
+        match s {
+            FodChunkState::OutOfBounds => 1,
+            FodChunkState::Hidden => 2,
+            FodChunkState::Partial => 3,
+            FodChunkState::Revealed => 4,
+            FodChunkState::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FodChunk {
+    pub state: FodChunkState,
+    // Number of pixels in this chunk which are part of the map
+    pub weight: u32,
+    pub format: u8,
+    // Zlib compressed alpha mask, only present for partially discovered chunks
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FodData {
+    pub version: u32,
+    pub unknown_1: u32,
+    pub unknown_2: u32,
+    pub chunk_width: u32,
+    pub chunk_height: u32,
+    pub chunks_x: u32,
+    pub chunks_y: u32,
+    pub total_pixels: u32,
+    pub chunks: Vec<FodChunk>,
+}
+
+fn read_fod_chunk(i: &[u8]) -> nom::IResult<&[u8], FodChunk, BL3ParserError<String>> {
+    let (i, state) = read_int(i)?;
+    let (i, weight) = read_int(i)?;
+    let (i, format) = nom::number::complete::le_u8(i)?;
+
+    let state = FodChunkState::from(state);
+
+    let (i, data) = if state == FodChunkState::Partial {
+        let (i, data_len) = read_int(i)?;
+        let (i, data) = nom::bytes::complete::take(data_len)(i)?;
+
+        (i, data.to_vec())
+    } else {
+        (i, Vec::new())
+    };
+
+    Ok((
+        i,
+        FodChunk {
+            state,
+            weight,
+            format,
+            data,
+        },
+    ))
+}
+
+impl FodData {
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let (r, magic_len) = read_int(data).finish()?;
+        let (r, magic) =
+            nom::bytes::complete::take::<_, _, BL3ParserError<String>>(magic_len)(r).finish()?;
+
+        if magic != FOD_MAGIC {
+            bail!("Fog of discovery data has an invalid header.");
+        }
+
+        let (r, version) = read_int(r).finish()?;
+        let (r, unknown_1) = read_int(r).finish()?;
+        let (r, unknown_2) = read_int(r).finish()?;
+        let (r, chunk_width) = read_int(r).finish()?;
+        let (r, chunk_height) = read_int(r).finish()?;
+        let (r, chunks_x) = read_int(r).finish()?;
+        let (r, chunks_y) = read_int(r).finish()?;
+        let (r, num_chunks) = read_int(r).finish()?;
+        let (r, total_pixels) = read_int(r).finish()?;
+
+        // Every chunk is at least a state, weight and format, reject counts the data can't hold
+        // before `count` allocates for them.
+        if num_chunks as usize > r.len() / FOD_CHUNK_MIN_LEN {
+            bail!(
+                "Fog of discovery data has {} chunks but only {} bytes remaining.",
+                num_chunks,
+                r.len()
+            );
+        }
+
+        let (r, chunks) = nom::multi::count(read_fod_chunk, num_chunks as usize)(r).finish()?;
+
+        if !r.is_empty() {
+            bail!(
+                "Fog of discovery data has {} unexpected trailing bytes.",
+                r.len()
+            );
+        }
+
+        Ok(Self {
+            version,
+            unknown_1,
+            unknown_2,
+            chunk_width,
+            chunk_height,
+            chunks_x,
+            chunks_y,
+            total_pixels,
+            chunks,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut output = Vec::new();
+
+        output.write_u32::<LittleEndian>(FOD_MAGIC.len() as u32)?;
+        output.write_all(FOD_MAGIC)?;
+        output.write_u32::<LittleEndian>(self.version)?;
+        output.write_u32::<LittleEndian>(self.unknown_1)?;
+        output.write_u32::<LittleEndian>(self.unknown_2)?;
+        output.write_u32::<LittleEndian>(self.chunk_width)?;
+        output.write_u32::<LittleEndian>(self.chunk_height)?;
+        output.write_u32::<LittleEndian>(self.chunks_x)?;
+        output.write_u32::<LittleEndian>(self.chunks_y)?;
+        output.write_u32::<LittleEndian>(self.chunks.len() as u32)?;
+        output.write_u32::<LittleEndian>(self.total_pixels)?;
+
+        for chunk in &self.chunks {
+            output.write_u32::<LittleEndian>(chunk.state.into())?;
+            output.write_u32::<LittleEndian>(chunk.weight)?;
+            output.write_u8(chunk.format)?;
+
+            if chunk.state == FodChunkState::Partial {
+                output.write_u32::<LittleEndian>(chunk.data.len() as u32)?;
+                output.write_all(&chunk.data)?;
+            }
+        }
+
+        Ok(output)
+    }
+
+    pub fn is_fully_revealed(&self) -> bool {
+        self.chunks.iter().all(|c| {
+            matches!(
+                c.state,
+                FodChunkState::OutOfBounds | FodChunkState::Revealed
+            )
+        })
+    }
+
+    pub fn reveal_all(&mut self) {
+        self.chunks
+            .iter_mut()
+            .filter(|c| matches!(c.state, FodChunkState::Hidden | FodChunkState::Partial))
+            .for_each(|c| {
+                c.state = FodChunkState::Revealed;
+                c.data.clear();
+            });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneMapLevel {
+    pub level_name: String,
+    pub discovery_percentage: f32,
+    pub fully_revealed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::file_helper::Bl3FileType;
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_fod_data_round_trip() {
+        let mut levels_read = 0;
+
+        for entry in fs::read_dir("./test_files").expect("failed to read test_files") {
+            let filename = entry.expect("failed to read test_file").path();
+
+            let file_data = fs::read(&filename).expect("failed to read test_file");
+
+            let bl3_save = match Bl3FileType::from_unknown_data(&filename, &file_data) {
+                Ok(Bl3FileType::PcSave(save)) | Ok(Bl3FileType::Ps4Save(save)) => save,
+                _ => continue,
+            };
+
+            for level_data in bl3_save
+                .character_data
+                .character
+                .get_gbx_zone_map_fod_save_game_data()
+                .level_data
+                .iter()
+            {
+                let fod = FodData::from_bytes(&level_data.fod_data).unwrap_or_else(|e| {
+                    panic!(
+                        "failed to read {} in {}: {}",
+                        level_data.level_name,
+                        filename.display(),
+                        e
+                    )
+                });
+
+                assert_eq!(fod.to_bytes().unwrap(), level_data.fod_data);
+
+                levels_read += 1;
+            }
+        }
+
+        assert!(levels_read > 0);
+    }
+
+    #[test]
+    fn test_fod_data_rejects_invalid_header() {
+        let mut data = Vec::new();
+        data.write_u32::<LittleEndian>(6).unwrap();
+        data.write_all(b"NOTFOD").unwrap();
+
+        assert!(FodData::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn test_fod_data_rejects_corrupt_chunk_count() {
+        let fod = FodData {
+            version: 1,
+            unknown_1: 0,
+            unknown_2: 0,
+            chunk_width: 8,
+            chunk_height: 8,
+            chunks_x: 1,
+            chunks_y: 1,
+            total_pixels: 64,
+            chunks: vec![FodChunk {
+                state: FodChunkState::Revealed,
+                weight: 64,
+                format: 0,
+                data: Vec::new(),
+            }],
+        };
+
+        let mut data = fod.to_bytes().unwrap();
+
+        assert_eq!(FodData::from_bytes(&data).unwrap(), fod);
+
+        // The chunk count follows the magic and the eight header fields
+        let num_chunks_offset = 4 + FOD_MAGIC.len() + 7 * 4;
+
+        data[num_chunks_offset..num_chunks_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(FodData::from_bytes(&data).is_err());
+
+        data[num_chunks_offset..num_chunks_offset + 4].copy_from_slice(&2_u32.to_le_bytes());
+
+        assert!(FodData::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn test_reveal_zone_map_levels() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let level = bl3_save
+            .character_data
+            .zone_map_levels()
+            .into_iter()
+            .find(|l| !l.fully_revealed)
+            .expect("failed to find a level which isn't fully revealed");
+
+        let level_suffix = format!("/{}_p", level.level_name.to_lowercase());
+
+        bl3_save
+            .character_data
+            .character
+            .mut_discovery_data()
+            .discovered_level_info
+            .iter_mut()
+            .filter(|d| {
+                d.discovered_level_name
+                    .to_lowercase()
+                    .ends_with(&level_suffix)
+            })
+            .for_each(|d| d.discovered_playthroughs = 0);
+
+        assert!(bl3_save
+            .character_data
+            .reveal_zone_map_levels(&["Not_A_Level".to_owned()])
+            .is_err());
+
+        bl3_save
+            .character_data
+            .reveal_zone_map_levels(&[level.level_name.clone()])
+            .expect("failed to reveal zone map");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let character = &bl3_save.character_data.character;
+
+        let level_data = character
+            .get_gbx_zone_map_fod_save_game_data()
+            .level_data
+            .iter()
+            .find(|l| l.level_name == level.level_name)
+            .expect("failed to find revealed level");
+
+        assert_eq!(level_data.discovery_percentage, 1.0);
+        assert_eq!(level_data.data_state, 4);
+        assert!(FodData::from_bytes(&level_data.fod_data)
+            .unwrap()
+            .is_fully_revealed());
+
+        let playthrough_bit = 1 << character.last_play_through_index;
+
+        let discovered_level_info = character
+            .get_discovery_data()
+            .discovered_level_info
+            .iter()
+            .filter(|d| {
+                d.discovered_level_name
+                    .to_lowercase()
+                    .ends_with(&level_suffix)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(!discovered_level_info.is_empty());
+        assert!(discovered_level_info
+            .iter()
+            .all(|d| d.discovered_playthroughs & playthrough_bit != 0));
+
+        assert!(bl3_save
+            .character_data
+            .zone_map_levels()
+            .iter()
+            .any(|l| l.level_name == level.level_name && l.fully_revealed));
+    }
+
+    #[test]
+    fn test_reveal_zone_map_levels_corrupt_level() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let level_names = bl3_save
+            .character_data
+            .zone_map_levels()
+            .into_iter()
+            .filter(|l| !l.fully_revealed)
+            .map(|l| l.level_name)
+            .take(2)
+            .collect::<Vec<_>>();
+
+        assert_eq!(level_names.len(), 2);
+
+        bl3_save
+            .character_data
+            .character
+            .mut_gbx_zone_map_fod_save_game_data()
+            .level_data
+            .iter_mut()
+            .find(|l| l.level_name == level_names[1])
+            .unwrap()
+            .fod_data
+            .truncate(8);
+
+        let character = bl3_save.character_data.character.clone();
+
+        assert!(bl3_save
+            .character_data
+            .reveal_zone_map_levels(&level_names)
+            .is_err());
+
+        assert_eq!(bl3_save.character_data.character, character);
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::path::PathBuf;
 
 use thiserror::Error;
 
@@ -10,6 +11,22 @@ pub enum BL3ParserError<I: Debug> {
     Other(anyhow::Error),
 }
 
+#[derive(Debug, Error)]
+#[error("{0:?} has changed on disk since it was loaded")]
+pub struct FileChangedError(pub PathBuf);
+
+#[derive(Debug, Error)]
+#[error("{0:?} already exists")]
+pub struct FileExistsError(pub PathBuf);
+
+#[derive(Debug, Error)]
+#[error("{container} is full ({count}/{capacity} items), increase the {container} SDU level or remove some items")]
+pub struct ContainerFullError {
+    pub container: &'static str,
+    pub count: usize,
+    pub capacity: i32,
+}
+
 impl nom::error::ParseError<&[u8]> for BL3ParserError<String> {
     fn from_error_kind(_: &[u8], kind: nom::error::ErrorKind) -> Self {
         BL3ParserError::NomError("Binary Data".to_owned(), kind)
@@ -1,11 +1,15 @@
 use std::fmt::Formatter;
-use std::path::Path;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use nom::Finish;
 
 use crate::bl3_profile::Bl3Profile;
 use crate::bl3_save::Bl3Save;
+use crate::error::{FileChangedError, FileExistsError};
 use crate::models::CustomFormatData;
 use crate::parser::{
     read_custom_format_data, read_header, read_int, read_short, read_str, HeaderType,
@@ -68,6 +72,187 @@ pub fn read_bytes<'a>(file_location: &'a Path, data: &'a [u8]) -> Result<FileDat
     })
 }
 
+/// The state of a file on disk when it was read, used to detect writes made by the game since.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct FileFingerprint {
+    pub modified: Option<SystemTime>,
+    pub hash: u32,
+}
+
+impl FileFingerprint {
+    pub fn new(file_location: &Path, data: &[u8]) -> Self {
+        let modified = std::fs::metadata(file_location)
+            .and_then(|m| m.modified())
+            .ok();
+
+        Self {
+            modified,
+            hash: crc32(data),
+        }
+    }
+
+    pub fn has_changed(&self, file_location: &Path) -> Result<bool> {
+        let modified = std::fs::metadata(file_location)
+            .and_then(|m| m.modified())
+            .ok();
+
+        if modified.is_some() && modified == self.modified {
+            return Ok(false);
+        }
+
+        match std::fs::read(file_location) {
+            Ok(data) => Ok(crc32(&data) != self.hash),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(true),
+            Err(e) => {
+                Err(e).with_context(|| format!("failed to read file: {}", file_location.display()))
+            }
+        }
+    }
+
+    pub fn ensure_unchanged(&self, file_location: &Path) -> Result<()> {
+        if self.has_changed(file_location)? {
+            return Err(FileChangedError(file_location.to_path_buf()).into());
+        }
+
+        Ok(())
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// Writes `data` next to `output_file`, syncs it and checks it reads back as a valid file before
+/// renaming it over `output_file`, so a failure part way through never leaves a broken file.
+pub fn write_file_atomic(output_file: &Path, data: &[u8]) -> Result<()> {
+    write_file_atomic_with(output_file, data, |written| {
+        validate_written_file(output_file, written)
+    })
+}
+
+/// Same as [`write_file_atomic`] but never replaces an existing file, fails with
+/// [`FileExistsError`] if `output_file` exists by the time the new file is moved into place.
+pub fn write_new_file_atomic(output_file: &Path, data: &[u8]) -> Result<()> {
+    write_temp_file_and(
+        output_file,
+        data,
+        |written| validate_written_file(output_file, written),
+        |temp_file| {
+            // Unlike a rename, a hard link fails rather than replacing a file created in the meantime
+            std::fs::hard_link(temp_file, output_file).map_err(|e| {
+                if e.kind() == std::io::ErrorKind::AlreadyExists {
+                    FileExistsError(output_file.to_path_buf()).into()
+                } else {
+                    anyhow::Error::new(e)
+                        .context(format!("failed to create file: {}", output_file.display()))
+                }
+            })?;
+
+            let _ = std::fs::remove_file(temp_file);
+
+            Ok(())
+        },
+    )
+}
+
+/// Same as [`write_file_atomic`] for files which aren't saves or profiles, `validate` is given the
+/// data read back before it replaces `output_file`.
+pub fn write_file_atomic_with<F>(output_file: &Path, data: &[u8], validate: F) -> Result<()>
+where
+    F: FnOnce(&[u8]) -> Result<()>,
+{
+    write_temp_file_and(output_file, data, validate, |temp_file| {
+        std::fs::rename(temp_file, output_file)
+            .with_context(|| format!("failed to replace file: {}", output_file.display()))
+    })
+}
+
+fn validate_written_file(output_file: &Path, written: &[u8]) -> Result<()> {
+    Bl3FileType::from_unknown_data(output_file, written)
+        .context("file written could not be read back")?;
+
+    Ok(())
+}
+
+fn write_temp_file_and<F, P>(output_file: &Path, data: &[u8], validate: F, persist: P) -> Result<()>
+where
+    F: FnOnce(&[u8]) -> Result<()>,
+    P: FnOnce(&Path) -> Result<()>,
+{
+    let file_name = output_file
+        .file_name()
+        .map(|s| s.to_string_lossy().to_string())
+        .context("failed to read file name")?;
+
+    let temp_file = output_file.with_file_name(format!(".{}.tmp", file_name));
+
+    let result = File::create(&temp_file)
+        .and_then(|mut f| {
+            f.write_all(data)?;
+            f.sync_all()
+        })
+        .with_context(|| format!("failed to write file: {}", temp_file.display()))
+        .and_then(|_| {
+            let written = std::fs::read(&temp_file)
+                .with_context(|| format!("failed to read back file: {}", temp_file.display()))?;
+
+            if written != data {
+                bail!("file read back did not match: {}", temp_file.display());
+            }
+
+            validate(&written)
+        })
+        .and_then(|_| persist(&temp_file));
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_file);
+    }
+
+    result?;
+
+    #[cfg(unix)]
+    if let Some(parent) = output_file.parent() {
+        // Persist the rename itself, not every filesystem supports this so ignore failures
+        let _ = File::open(parent).and_then(|d| d.sync_all());
+    }
+
+    Ok(())
+}
+
+/// Files replaced through [`WrittenFiles::write`] along with their original contents, so a group of
+/// writes can be undone together when a later one fails.
+#[derive(Debug, Default)]
+pub struct WrittenFiles {
+    files: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl WrittenFiles {
+    pub fn write(&mut self, output_file: &Path, data: &[u8]) -> Result<()> {
+        let original = std::fs::read(output_file)
+            .with_context(|| format!("failed to read existing file: {}", output_file.display()))?;
+
+        self.files.push((output_file.to_path_buf(), original));
+
+        write_file_atomic(output_file, data)
+    }
+
+    /// Restores the original contents in reverse order of writing, returning every file which
+    /// could not be restored.
+    pub fn rollback(self) -> Vec<(PathBuf, anyhow::Error)> {
+        self.files
+            .into_iter()
+            .rev()
+            .filter_map(|(path, original)| {
+                write_file_atomic_with(&path, &original, |_| Ok(()))
+                    .err()
+                    .map(|e| (path, e))
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Ord, PartialOrd)]
 pub enum Bl3FileType {
     PcSave(Bl3Save),
@@ -109,16 +294,94 @@ impl Bl3FileType {
     pub fn from_unknown_data(file_location: &Path, data: &[u8]) -> Result<Bl3FileType> {
         let file_data = read_bytes(file_location, data)?;
 
-        if let Ok(save) = Bl3Save::from_file_data(&file_data, HeaderType::PcSave) {
-            Ok(Bl3FileType::PcSave(save))
+        let mut file_type = if let Ok(save) =
+            Bl3Save::from_file_data(&file_data, HeaderType::PcSave)
+        {
+            Bl3FileType::PcSave(save)
         } else if let Ok(profile) = Bl3Profile::from_file_data(&file_data, HeaderType::PcProfile) {
-            Ok(Bl3FileType::PcProfile(profile))
+            Bl3FileType::PcProfile(profile)
         } else if let Ok(save) = Bl3Save::from_file_data(&file_data, HeaderType::Ps4Save) {
-            Ok(Bl3FileType::Ps4Save(save))
+            Bl3FileType::Ps4Save(save)
         } else if let Ok(profile) = Bl3Profile::from_file_data(&file_data, HeaderType::Ps4Profile) {
-            Ok(Bl3FileType::Ps4Profile(profile))
+            Bl3FileType::Ps4Profile(profile)
         } else {
             bail!("Could not recognize file type.")
+        };
+
+        let file_fingerprint = Some(FileFingerprint::new(file_location, data));
+
+        match &mut file_type {
+            Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s) => {
+                s.file_fingerprint = file_fingerprint
+            }
+            Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => {
+                p.file_fingerprint = file_fingerprint
+            }
+        }
+
+        Ok(file_type)
+    }
+
+    /// Key figures of the file, used to show and compare files at a glance.
+    pub fn summary(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+                let character_data = &save.character_data;
+
+                vec![
+                    (
+                        "Name",
+                        character_data.character.preferred_character_name.clone(),
+                    ),
+                    ("Class", character_data.player_class().to_string()),
+                    ("Level", character_data.player_level().to_string()),
+                    (
+                        "Experience",
+                        character_data.character.experience_points.to_string(),
+                    ),
+                    ("Money", character_data.money().to_string()),
+                    ("Eridium", character_data.eridium().to_string()),
+                    ("Guardian Rank", character_data.guardian_rank().to_string()),
+                    (
+                        "Playthroughs",
+                        character_data.playthroughs().len().to_string(),
+                    ),
+                    (
+                        "Inventory Items",
+                        character_data.inventory_items().len().to_string(),
+                    ),
+                    (
+                        "Time Played",
+                        format!("{}h", character_data.time_played_seconds() / 3600),
+                    ),
+                ]
+            }
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+                let profile_data = &profile.profile_data;
+
+                vec![
+                    ("Golden Keys", profile_data.golden_keys().to_string()),
+                    ("Diamond Keys", profile_data.diamond_keys().to_string()),
+                    ("Guardian Rank", profile_data.guardian_rank().to_string()),
+                    (
+                        "Guardian Tokens",
+                        profile_data.guardian_tokens().to_string(),
+                    ),
+                    ("Bank Items", profile_data.bank_items().len().to_string()),
+                    (
+                        "Lost Loot Items",
+                        profile_data.lost_loot_items().len().to_string(),
+                    ),
+                    (
+                        "Character Skins",
+                        profile_data.character_skins_unlocked().to_string(),
+                    ),
+                    (
+                        "Weapon Skins",
+                        profile_data.weapon_skins_unlocked().to_string(),
+                    ),
+                ]
+            }
         }
     }
 
@@ -131,3 +394,163 @@ impl Bl3FileType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bl3_save_edit_{}", uuid::Uuid::new_v4()));
+
+        fs::create_dir_all(&dir).expect("failed to create temp dir");
+
+        dir
+    }
+
+    #[test]
+    fn test_write_file_atomic() {
+        let dir = temp_dir();
+        let output_file = dir.join("19.sav");
+
+        fs::write(&output_file, b"original").expect("failed to write original file");
+
+        let data = fs::read("./test_files/19.sav").expect("failed to read test_file");
+
+        write_file_atomic(&output_file, &data).expect("failed to write file");
+
+        assert_eq!(fs::read(&output_file).unwrap(), data);
+        assert!(!dir.join(".19.sav.tmp").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_file_atomic_rejects_invalid_data() {
+        let dir = temp_dir();
+        let output_file = dir.join("19.sav");
+
+        let original = fs::read("./test_files/19.sav").expect("failed to read test_file");
+
+        fs::write(&output_file, &original).expect("failed to write original file");
+
+        let mut data = original.clone();
+        data.truncate(data.len() / 2);
+
+        assert!(write_file_atomic(&output_file, &data).is_err());
+
+        assert_eq!(fs::read(&output_file).unwrap(), original);
+        assert!(!dir.join(".19.sav.tmp").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_new_file_atomic() {
+        let dir = temp_dir();
+        let output_file = dir.join("19.sav");
+
+        let data = fs::read("./test_files/19.sav").expect("failed to read test_file");
+
+        write_new_file_atomic(&output_file, &data).expect("failed to write file");
+
+        assert_eq!(fs::read(&output_file).unwrap(), data);
+        assert!(!dir.join(".19.sav.tmp").exists());
+
+        fs::write(&output_file, b"original").expect("failed to write original file");
+
+        let err = write_new_file_atomic(&output_file, &data).unwrap_err();
+
+        assert!(err.is::<FileExistsError>());
+        assert_eq!(fs::read(&output_file).unwrap(), b"original");
+        assert!(!dir.join(".19.sav.tmp").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_written_files_rollback() {
+        let dir = temp_dir();
+        let first_file = dir.join("19.sav");
+        let second_file = dir.join("1.sav");
+
+        fs::write(&first_file, b"first").expect("failed to write original file");
+        fs::write(&second_file, b"second").expect("failed to write original file");
+
+        let first_data = fs::read("./test_files/19.sav").expect("failed to read test_file");
+        let second_data = fs::read("./test_files/1.sav").expect("failed to read test_file");
+
+        let mut written_files = WrittenFiles::default();
+
+        written_files
+            .write(&first_file, &first_data)
+            .expect("failed to write file");
+        written_files
+            .write(&second_file, &second_data)
+            .expect("failed to write file");
+        written_files
+            .write(&first_file, &second_data)
+            .expect("failed to write file");
+
+        assert!(written_files
+            .write(&dir.join("missing.sav"), &first_data)
+            .is_err());
+        assert!(written_files.write(&second_file, b"invalid").is_err());
+
+        assert_eq!(fs::read(&first_file).unwrap(), second_data);
+        assert_eq!(fs::read(&second_file).unwrap(), second_data);
+
+        assert!(written_files.rollback().is_empty());
+
+        assert_eq!(fs::read(&first_file).unwrap(), b"first");
+        assert_eq!(fs::read(&second_file).unwrap(), b"second");
+        assert!(!dir.join("missing.sav").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_fingerprint_has_changed() {
+        let dir = temp_dir();
+        let output_file = dir.join("19.sav");
+
+        let data = fs::read("./test_files/19.sav").expect("failed to read test_file");
+
+        fs::write(&output_file, &data).expect("failed to write file");
+
+        let file_fingerprint = FileFingerprint::new(&output_file, &data);
+
+        assert!(!file_fingerprint.has_changed(&output_file).unwrap());
+        assert!(file_fingerprint.ensure_unchanged(&output_file).is_ok());
+
+        let mut modified_data = data.clone();
+        *modified_data.last_mut().unwrap() ^= 0xFF;
+
+        fs::write(&output_file, &modified_data).expect("failed to modify file");
+
+        // Timestamps can be coarse, make sure the write looks like a later save by the game
+        let modified = file_fingerprint.modified.unwrap() + Duration::from_secs(5);
+
+        fs::File::options()
+            .write(true)
+            .open(&output_file)
+            .and_then(|f| f.set_modified(modified))
+            .expect("failed to set modified time");
+
+        assert!(file_fingerprint.has_changed(&output_file).unwrap());
+        assert!(file_fingerprint
+            .ensure_unchanged(&output_file)
+            .unwrap_err()
+            .downcast_ref::<FileChangedError>()
+            .is_some());
+
+        fs::remove_file(&output_file).unwrap();
+
+        assert!(file_fingerprint.has_changed(&output_file).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,7 +1,6 @@
 use std::fmt::Formatter;
 
 use anyhow::Result;
-
 pub const FAST_TRAVEL: [GameDataKv; 864] = [
     GameDataKv { ident: "/game/gamedata/fasttravel/fts_atlashq.fts_atlashq", name: "Atlas HQ" },
     GameDataKv { ident: "/game/gamedata/fasttravel/fts_atlashq_sendonly.fts_atlashq_sendonly", name: "Atlas HQ" },
@@ -4200,6 +4199,2474 @@ pub const BALANCE_TO_INV_KEY: [GameDataKv; 1435] = [
     GameDataKv { ident: "/hibiscus/enemies/zealot/pilfer/_design/weapon/balance_sr_mal_etech_pilfer.balance_sr_mal_etech_pilfer", name: "BPInvPart_MAL_SR_C" },
 ];
 
+pub const CHALLENGES: [GameDataKv; 1833] = [
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/Challenge_Collection_DeadDrop_ZoneMapTest.Challenge_Collection_DeadDrop_ZoneMapTest_C", name: "Collection Dead Drop Zone Map Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/Challenge_Collection_Journal_ZoneMapTest.Challenge_Collection_Journal_ZoneMapTest_C", name: "Collection Journal Zone Map Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/Challenge_HiJack_ZoneMapTest.Challenge_HiJack_ZoneMapTest_C", name: "Hi Jack Zone Map Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/Challenge_Hunt_ZoneMapTest.Challenge_Hunt_ZoneMapTest_C", name: "Hunt Zone Map Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/Challenge_KillTarget_ZoneMapTest.Challenge_KillTarget_ZoneMapTest_C", name: "Kill Target Zone Map Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/Challenge_Sabotage_ZoneMapTest.Challenge_Sabotage_ZoneMapTest_C", name: "Sabotage Zone Map Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/Challenge_Salvage_ZoneMapTest.Challenge_Salvage_ZoneMapTest_C", name: "Salvage Zone Map Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Collection_DeadDrop_ChallengePlayground.Challenge_Collection_DeadDrop_ChallengePlayground_C", name: "Collection Dead Drop Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Collection_DeadDrop_ChallengePlaygroundActive.Challenge_Collection_DeadDrop_ChallengePlaygroundActive_C", name: "Collection Dead Drop Challenge Playground Active" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Collection_DeadDrop_LootableTest.Challenge_Collection_DeadDrop_LootableTest_C", name: "Collection Dead Drop Lootable Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Collection_DeadDrop_LootableTest2.Challenge_Collection_DeadDrop_LootableTest2_C", name: "Collection Dead Drop Lootable Test 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Collection_DeadDrop_LootableTest3.Challenge_Collection_DeadDrop_LootableTest3_C", name: "Collection Dead Drop Lootable Test 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Collection_Journal_ChallengePlayground.Challenge_Collection_Journal_ChallengePlayground_C", name: "Collection Journal Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Collection_Journal_ChallengePlayground2.Challenge_Collection_Journal_ChallengePlayground2_C", name: "Collection Journal Challenge Playground 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Discovery_Test_00.Challenge_Discovery_Test_00_C", name: "Discovery Test 00" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_FastTravel_Test_00.Challenge_FastTravel_Test_00_C", name: "Fast Travel Test 00" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_HiJack_Challenge_Playground.Challenge_HiJack_Challenge_Playground_C", name: "Hi Jack Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Hunt_ChallengePlayground.Challenge_Hunt_ChallengePlayground_C", name: "Hunt Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_KillTarget_ChallengePlayground.Challenge_KillTarget_ChallengePlayground_C", name: "Kill Target Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Sabotage_ChallengePlayground.Challenge_Sabotage_ChallengePlayground_C", name: "Sabotage Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengePlayground/Challenge_Salvage_ChallengePlayground.Challenge_Salvage_ChallengePlayground_C", name: "Salvage Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengeRewardTest/Challenge_HiJack_CrewChallengesReward.Challenge_HiJack_CrewChallengesReward_C", name: "Hi Jack Crew Challenges Reward" },
+    GameDataKv { ident: "/Game/GameData/Challenges/_Test/ChallengeRewardTest/Challenge_Salvage_RewardTest.Challenge_Salvage_RewardTest_C", name: "Salvage Reward Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Account/Challenge_VaultReward_Analyzer.Challenge_VaultReward_Analyzer_C", name: "Vault Reward Analyzer" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Account/Challenge_VaultReward_ArtifactSlot.Challenge_VaultReward_ArtifactSlot_C", name: "Vault Reward Artifact Slot" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Account/Challenge_VaultReward_Fabricator.Challenge_VaultReward_Fabricator_C", name: "Vault Reward Fabricator" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Account/Challenge_VaultReward_Mayhem.Challenge_VaultReward_Mayhem_C", name: "Vault Reward Mayhem" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Account/Challenge_VaultReward_Resonator.Challenge_VaultReward_Resonator_C", name: "Vault Reward Resonator" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Beastmaster/BP_Challenge_Beastmaster_ClassMod.BP_Challenge_Beastmaster_ClassMod_C", name: "Beastmaster Class Mod" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Beastmaster/BP_Challenge_Beastmaster_DominateEnemy.BP_Challenge_Beastmaster_DominateEnemy_C", name: "Beastmaster Dominate Enemy" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Beastmaster/BP_Challenge_Beastmaster_FinishGame.BP_Challenge_Beastmaster_FinishGame_C", name: "Beastmaster Finish Game" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Beastmaster/BP_Challenge_Beastmaster_JabberKills.BP_Challenge_Beastmaster_JabberKills_C", name: "Beastmaster Jabber Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Beastmaster/BP_Challenge_Beastmaster_RakkAttack.BP_Challenge_Beastmaster_RakkAttack_C", name: "Beastmaster Rakk Attack" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Beastmaster/BP_Challenge_Beastmaster_SkagKills.BP_Challenge_Beastmaster_SkagKills_C", name: "Beastmaster Skag Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Beastmaster/BP_Challenge_Beastmaster_SpiderantKills.BP_Challenge_Beastmaster_SpiderantKills_C", name: "Beastmaster Spiderant Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_ClassMod.BP_Challenge_Gunner_ClassMod_C", name: "Gunner Class Mod" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_FinishGame.BP_Challenge_Gunner_FinishGame_C", name: "Gunner Finish Game" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_IronBearBearFist.BP_Challenge_Gunner_IronBearBearFist_C", name: "Gunner Iron Bear Bear Fist" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_IronBearGrenadeLauncher.BP_Challenge_Gunner_IronBearGrenadeLauncher_C", name: "Gunner Iron Bear Grenade Launcher" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_IronBearMinigun.BP_Challenge_Gunner_IronBearMinigun_C", name: "Gunner Iron Bear Minigun" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_IronBearRailgun.BP_Challenge_Gunner_IronBearRailgun_C", name: "Gunner Iron Bear Railgun" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_IronBearSalamander.BP_Challenge_Gunner_IronBearSalamander_C", name: "Gunner Iron Bear Salamander" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Gunner/BP_Challenge_Gunner_IronBearVanquisherPod.BP_Challenge_Gunner_IronBearVanquisherPod_C", name: "Gunner Iron Bear Vanquisher Pod" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_Barrier.BP_Challenge_Operative_Barrier_C", name: "Operative Barrier" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_CalledShot.BP_Challenge_Operative_CalledShot_C", name: "Operative Called Shot" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_ClassMod.BP_Challenge_Operative_ClassMod_C", name: "Operative Class Mod" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_DeterrenceField.BP_Challenge_Operative_DeterrenceField_C", name: "Operative Deterrence Field" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_Digiclone.BP_Challenge_Operative_Digiclone_C", name: "Operative Digiclone" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_FinishGame.BP_Challenge_Operative_FinishGame_C", name: "Operative Finish Game" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_PanicButton.BP_Challenge_Operative_PanicButton_C", name: "Operative Panic Button" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Operative/BP_Challenge_Operative_SNTRY.BP_Challenge_Operative_SNTRY_C", name: "Operative SNTRY" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_Blitz.BP_Challenge_Siren_Blitz_C", name: "Siren Blitz" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_ClassMod.BP_Challenge_Siren_ClassMod_C", name: "Siren Class Mod" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_FinishGame.BP_Challenge_Siren_FinishGame_C", name: "Siren Finish Game" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_Glamour.BP_Challenge_Siren_Glamour_C", name: "Siren Glamour" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_Phasecast.BP_Challenge_Siren_Phasecast_C", name: "Siren Phasecast" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_Phasegrasp.BP_Challenge_Siren_Phasegrasp_C", name: "Siren Phasegrasp" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_SoulSap.BP_Challenge_Siren_SoulSap_C", name: "Siren Soul Sap" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Character/Siren/BP_Challenge_Siren_Stillness.BP_Challenge_Siren_Stillness_C", name: "Siren Stillness" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/Challenge_Collection_ActiveDeadDrop_AtlasHQ.Challenge_Collection_ActiveDeadDrop_AtlasHQ_C", name: "Collection Active Dead Drop Atlas HQ" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/Challenge_Collection_DeadDrop_AtlasHQ.Challenge_Collection_DeadDrop_AtlasHQ_C", name: "Collection Dead Drop Atlas HQ" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/Challenge_Collection_Journal_AtlasHQ1.Challenge_Collection_Journal_AtlasHQ1_C", name: "Collection Journal Atlas HQ 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/Challenge_Collection_Journal_AtlasHQ2.Challenge_Collection_Journal_AtlasHQ2_C", name: "Collection Journal Atlas HQ 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/Challenge_Collection_Journal_AtlasHQ3.Challenge_Collection_Journal_AtlasHQ3_C", name: "Collection Journal Atlas HQ 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/Challenge_Collection_ActiveDeadDrop_Beach.Challenge_Collection_ActiveDeadDrop_Beach_C", name: "Collection Active Dead Drop Beach" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/Challenge_Collection_DeadDrop_Beach.Challenge_Collection_DeadDrop_Beach_C", name: "Collection Dead Drop Beach" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/Challenge_Collection_Journal_Beach1.Challenge_Collection_Journal_Beach1_C", name: "Collection Journal Beach 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/Challenge_Collection_Journal_Beach2.Challenge_Collection_Journal_Beach2_C", name: "Collection Journal Beach 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/Challenge_Collection_Journal_Beach3.Challenge_Collection_Journal_Beach3_C", name: "Collection Journal Beach 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/Challenge_Collection_ActiveDeadDrop_City.Challenge_Collection_ActiveDeadDrop_City_C", name: "Collection Active Dead Drop City" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/Challenge_Collection_DeadDrop_City.Challenge_Collection_DeadDrop_City_C", name: "Collection Dead Drop City" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/Challenge_Collection_Journal_City1.Challenge_Collection_Journal_City1_C", name: "Collection Journal City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/Challenge_Collection_Journal_City2.Challenge_Collection_Journal_City2_C", name: "Collection Journal City 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/Challenge_Collection_Journal_City3.Challenge_Collection_Journal_City3_C", name: "Collection Journal City 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/Challenge_Collection_ActiveDeadDrop_CityVault.Challenge_Collection_ActiveDeadDrop_CityVault_C", name: "Collection Active Dead Drop City Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/Challenge_Collection_DeadDrop_CityVault.Challenge_Collection_DeadDrop_CityVault_C", name: "Collection Dead Drop City Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/Challenge_Collection_Journal_CityVault1.Challenge_Collection_Journal_CityVault1_C", name: "Collection Journal City Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/Challenge_Collection_Journal_CityVault2.Challenge_Collection_Journal_CityVault2_C", name: "Collection Journal City Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/Challenge_Collection_Journal_CityVault3.Challenge_Collection_Journal_CityVault3_C", name: "Collection Journal City Vault 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Crypt/Challenge_Collection_ActiveDeadDrop_Crypt.Challenge_Collection_ActiveDeadDrop_Crypt_C", name: "Collection Active Dead Drop Crypt" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Crypt/Challenge_Collection_DeadDrop_Crypt.Challenge_Collection_DeadDrop_Crypt_C", name: "Collection Dead Drop Crypt" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Crypt/Challenge_Collection_Journal_Crypt1.Challenge_Collection_Journal_Crypt1_C", name: "Collection Journal Crypt 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Crypt/Challenge_Collection_Journal_Crypt2.Challenge_Collection_Journal_Crypt2_C", name: "Collection Journal Crypt 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Crypt/Challenge_Collection_Journal_Crypt3.Challenge_Collection_Journal_Crypt3_C", name: "Collection Journal Crypt 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/Challenge_Collection_ActiveDeadDrop_Desert.Challenge_Collection_ActiveDeadDrop_Desert_C", name: "Collection Active Dead Drop Desert" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/Challenge_Collection_DeadDrop_Desert.Challenge_Collection_DeadDrop_Desert_C", name: "Collection Dead Drop Desert" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/Challenge_Collection_Journal_Desert1.Challenge_Collection_Journal_Desert1_C", name: "Collection Journal Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/Challenge_Collection_Journal_Desert2.Challenge_Collection_Journal_Desert2_C", name: "Collection Journal Desert 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/Challenge_Collection_Journal_Desert3.Challenge_Collection_Journal_Desert3_C", name: "Collection Journal Desert 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/Challenge_Collection_ActiveDeadDrop_DesertVault.Challenge_Collection_ActiveDeadDrop_DesertVault_C", name: "Collection Active Dead Drop Desert Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/Challenge_Collection_DeadDrop_DesertVault.Challenge_Collection_DeadDrop_DesertVault_C", name: "Collection Dead Drop Desert Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/Challenge_Collection_Journal_DesertVault1.Challenge_Collection_Journal_DesertVault1_C", name: "Collection Journal Desert Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/Challenge_Collection_Journal_DesertVault2.Challenge_Collection_Journal_DesertVault2_C", name: "Collection Journal Desert Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/Challenge_Collection_Journal_DesertVault3.Challenge_Collection_Journal_DesertVault3_C", name: "Collection Journal Desert Vault 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/Challenge_Collection_ActiveDeadDrop_Desolate.Challenge_Collection_ActiveDeadDrop_Desolate_C", name: "Collection Active Dead Drop Desolate" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/Challenge_Collection_DeadDrop_Desolate.Challenge_Collection_DeadDrop_Desolate_C", name: "Collection Dead Drop Desolate" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/Challenge_Collection_Journal_Desolate1.Challenge_Collection_Journal_Desolate1_C", name: "Collection Journal Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/Challenge_Collection_Journal_Desolate2.Challenge_Collection_Journal_Desolate2_C", name: "Collection Journal Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/Challenge_Collection_Journal_Desolate3.Challenge_Collection_Journal_Desolate3_C", name: "Collection Journal Desolate 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/Challenge_Collection_ActiveDeadDrop_Mansion.Challenge_Collection_ActiveDeadDrop_Mansion_C", name: "Collection Active Dead Drop Mansion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/Challenge_Collection_DeadDrop_Mansion.Challenge_Collection_DeadDrop_Mansion_C", name: "Collection Dead Drop Mansion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/Challenge_Collection_Journal_Mansion1.Challenge_Collection_Journal_Mansion1_C", name: "Collection Journal Mansion 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/Challenge_Collection_Journal_Mansion2.Challenge_Collection_Journal_Mansion2_C", name: "Collection Journal Mansion 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/Challenge_Collection_Journal_Mansion3.Challenge_Collection_Journal_Mansion3_C", name: "Collection Journal Mansion 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/Challenge_Collection_ActiveDeadDrop_Marshfields.Challenge_Collection_ActiveDeadDrop_Marshfields_C", name: "Collection Active Dead Drop Marshfields" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/Challenge_Collection_DeadDrop_Marshfields.Challenge_Collection_DeadDrop_Marshfields_C", name: "Collection Dead Drop Marshfields" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/Challenge_Collection_Journal_Marshfields1.Challenge_Collection_Journal_Marshfields1_C", name: "Collection Journal Marshfields 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/Challenge_Collection_Journal_Marshfields2.Challenge_Collection_Journal_Marshfields2_C", name: "Collection Journal Marshfields 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/Challenge_Collection_Journal_Marshfields3.Challenge_Collection_Journal_Marshfields3_C", name: "Collection Journal Marshfields 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/Challenge_Collection_ActiveDeadDrop_Mine.Challenge_Collection_ActiveDeadDrop_Mine_C", name: "Collection Active Dead Drop Mine" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/Challenge_Collection_DeadDrop_Mine.Challenge_Collection_DeadDrop_Mine_C", name: "Collection Dead Drop Mine" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/Challenge_Collection_Journal_Mine1.Challenge_Collection_Journal_Mine1_C", name: "Collection Journal Mine 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/Challenge_Collection_Journal_Mine2.Challenge_Collection_Journal_Mine2_C", name: "Collection Journal Mine 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/Challenge_Collection_Journal_Mine3.Challenge_Collection_Journal_Mine3_C", name: "Collection Journal Mine 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/Challenge_Collection_ActiveDeadDrop_Monastery.Challenge_Collection_ActiveDeadDrop_Monastery_C", name: "Collection Active Dead Drop Monastery" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/Challenge_Collection_DeadDrop_Monastery.Challenge_Collection_DeadDrop_Monastery_C", name: "Collection Dead Drop Monastery" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/Challenge_Collection_Journal_Monastery1.Challenge_Collection_Journal_Monastery1_C", name: "Collection Journal Monastery 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/Challenge_Collection_Journal_Monastery2.Challenge_Collection_Journal_Monastery2_C", name: "Collection Journal Monastery 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/Challenge_Collection_Journal_Monastery3.Challenge_Collection_Journal_Monastery3_C", name: "Collection Journal Monastery 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/Challenge_Collection_ActiveDeadDrop_Motorcade.Challenge_Collection_ActiveDeadDrop_Motorcade_C", name: "Collection Active Dead Drop Motorcade" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/Challenge_Collection_DeadDrop_Motorcade.Challenge_Collection_DeadDrop_Motorcade_C", name: "Collection Dead Drop Motorcade" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/Challenge_Collection_Journal_Motorcade1.Challenge_Collection_Journal_Motorcade1_C", name: "Collection Journal Motorcade 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/Challenge_Collection_Journal_Motorcade2.Challenge_Collection_Journal_Motorcade2_C", name: "Collection Journal Motorcade 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/Challenge_Collection_Journal_Motorcade3.Challenge_Collection_Journal_Motorcade3_C", name: "Collection Journal Motorcade 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/Challenge_Collection_ActiveDeadDrop_MotorcadeFestival.Challenge_Collection_ActiveDeadDrop_MotorcadeFestival_C", name: "Collection Active Dead Drop Motorcade Festival" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/Challenge_Collection_DeadDrop_MotorcadeFestival.Challenge_Collection_DeadDrop_MotorcadeFestival_C", name: "Collection Dead Drop Motorcade Festival" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/Challenge_Collection_Journal_MotorcadeFestival1.Challenge_Collection_Journal_MotorcadeFestival1_C", name: "Collection Journal Motorcade Festival 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/Challenge_Collection_Journal_MotorcadeFestival2.Challenge_Collection_Journal_MotorcadeFestival2_C", name: "Collection Journal Motorcade Festival 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/Challenge_Collection_Journal_MotorcadeFestival3.Challenge_Collection_Journal_MotorcadeFestival3_C", name: "Collection Journal Motorcade Festival 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/Challenge_Collection_ActiveDeadDrop_OrbitalPlatform.Challenge_Collection_ActiveDeadDrop_OrbitalPlatform_C", name: "Collection Active Dead Drop Orbital Platform" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/Challenge_Collection_DeadDrop_OrbitalPlatform.Challenge_Collection_DeadDrop_OrbitalPlatform_C", name: "Collection Dead Drop Orbital Platform" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/Challenge_Collection_Journal_OrbitalPlatform1.Challenge_Collection_Journal_OrbitalPlatform1_C", name: "Collection Journal Orbital Platform 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/Challenge_Collection_Journal_OrbitalPlatform2.Challenge_Collection_Journal_OrbitalPlatform2_C", name: "Collection Journal Orbital Platform 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/Challenge_Collection_Journal_OrbitalPlatform3.Challenge_Collection_Journal_OrbitalPlatform3_C", name: "Collection Journal Orbital Platform 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/Challenge_Collection_ActiveDeadDrop_Outskirts.Challenge_Collection_ActiveDeadDrop_Outskirts_C", name: "Collection Active Dead Drop Outskirts" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/Challenge_Collection_DeadDrop_Outskirts.Challenge_Collection_DeadDrop_Outskirts_C", name: "Collection Dead Drop Outskirts" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/Challenge_Collection_Journal_Outskirts1.Challenge_Collection_Journal_Outskirts1_C", name: "Collection Journal Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/Challenge_Collection_Journal_Outskirts2.Challenge_Collection_Journal_Outskirts2_C", name: "Collection Journal Outskirts 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/Challenge_Collection_Journal_Outskirts3.Challenge_Collection_Journal_Outskirts3_C", name: "Collection Journal Outskirts 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/Challenge_Collection_ActiveDeadDrop_Prison.Challenge_Collection_ActiveDeadDrop_Prison_C", name: "Collection Active Dead Drop Prison" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/Challenge_Collection_DeadDrop_Prison.Challenge_Collection_DeadDrop_Prison_C", name: "Collection Dead Drop Prison" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/Challenge_Collection_Journal_Prison1.Challenge_Collection_Journal_Prison1_C", name: "Collection Journal Prison 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/Challenge_Collection_Journal_Prison2.Challenge_Collection_Journal_Prison2_C", name: "Collection Journal Prison 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/Challenge_Collection_Journal_Prison3.Challenge_Collection_Journal_Prison3_C", name: "Collection Journal Prison 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/Challenge_Collection_ActiveDeadDrop_Prologue.Challenge_Collection_ActiveDeadDrop_Prologue_C", name: "Collection Active Dead Drop Prologue" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/Challenge_Collection_DeadDrop_Prologue.Challenge_Collection_DeadDrop_Prologue_C", name: "Collection Dead Drop Prologue" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/Challenge_Collection_Journal_Prologue1.Challenge_Collection_Journal_Prologue1_C", name: "Collection Journal Prologue 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/Challenge_Collection_Journal_Prologue2.Challenge_Collection_Journal_Prologue2_C", name: "Collection Journal Prologue 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/Challenge_Collection_Journal_Prologue3.Challenge_Collection_Journal_Prologue3_C", name: "Collection Journal Prologue 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/Challenge_Collection_ActiveDeadDrop_Sacrifice.Challenge_Collection_ActiveDeadDrop_Sacrifice_C", name: "Collection Active Dead Drop Sacrifice" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/Challenge_Collection_DeadDrop_Sacrifice.Challenge_Collection_DeadDrop_Sacrifice_C", name: "Collection Dead Drop Sacrifice" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/Challenge_Collection_Journal_Sacrifice1.Challenge_Collection_Journal_Sacrifice1_C", name: "Collection Journal Sacrifice 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/Challenge_Collection_Journal_Sacrifice2.Challenge_Collection_Journal_Sacrifice2_C", name: "Collection Journal Sacrifice 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/Challenge_Collection_Journal_Sacrifice3.Challenge_Collection_Journal_Sacrifice3_C", name: "Collection Journal Sacrifice 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/Challenge_Collection_ActiveDeadDrop_Towers.Challenge_Collection_ActiveDeadDrop_Towers_C", name: "Collection Active Dead Drop Towers" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/Challenge_Collection_DeadDrop_Towers.Challenge_Collection_DeadDrop_Towers_C", name: "Collection Dead Drop Towers" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/Challenge_Collection_Journal_Towers1.Challenge_Collection_Journal_Towers1_C", name: "Collection Journal Towers 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/Challenge_Collection_Journal_Towers2.Challenge_Collection_Journal_Towers2_C", name: "Collection Journal Towers 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/Challenge_Collection_Journal_Towers3.Challenge_Collection_Journal_Towers3_C", name: "Collection Journal Towers 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/Challenge_Collection_ActiveDeadDrop_Watership.Challenge_Collection_ActiveDeadDrop_Watership_C", name: "Collection Active Dead Drop Watership" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/Challenge_Collection_DeadDrop_Watership.Challenge_Collection_DeadDrop_Watership_C", name: "Collection Dead Drop Watership" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/Challenge_Collection_Journal_Watership1.Challenge_Collection_Journal_Watership1_C", name: "Collection Journal Watership 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/Challenge_Collection_Journal_Watership2.Challenge_Collection_Journal_Watership2_C", name: "Collection Journal Watership 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/Challenge_Collection_Journal_Watership3.Challenge_Collection_Journal_Watership3_C", name: "Collection Journal Watership 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/Challenge_Collection_ActiveDeadDrop_Wetlands.Challenge_Collection_ActiveDeadDrop_Wetlands_C", name: "Collection Active Dead Drop Wetlands" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/Challenge_Collection_DeadDrop_Wetlands.Challenge_Collection_DeadDrop_Wetlands_C", name: "Collection Dead Drop Wetlands" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/Challenge_Collection_Journal_Wetlands1.Challenge_Collection_Journal_Wetlands1_C", name: "Collection Journal Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/Challenge_Collection_Journal_Wetlands2.Challenge_Collection_Journal_Wetlands2_C", name: "Collection Journal Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/Challenge_Collection_Journal_Wetlands3.Challenge_Collection_Journal_Wetlands3_C", name: "Collection Journal Wetlands 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/Challenge_Collection_ActiveDeadDrop_WetlandsVault.Challenge_Collection_ActiveDeadDrop_WetlandsVault_C", name: "Collection Active Dead Drop Wetlands Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/Challenge_Collection_DeadDrop_WetlandsVault.Challenge_Collection_DeadDrop_WetlandsVault_C", name: "Collection Dead Drop Wetlands Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/Challenge_Collection_Journal_WetlandsVault1.Challenge_Collection_Journal_WetlandsVault1_C", name: "Collection Journal Wetlands Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/Challenge_Collection_Journal_WetlandsVault2.Challenge_Collection_Journal_WetlandsVault2_C", name: "Collection Journal Wetlands Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/Challenge_Collection_Journal_WetlandsVault3.Challenge_Collection_Journal_WetlandsVault3_C", name: "Collection Journal Wetlands Vault 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/City/Challenge_HiJack_City1.Challenge_HiJack_City1_C", name: "Hi Jack City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Desert/Challenge_HiJack_Desert1.Challenge_HiJack_Desert1_C", name: "Hi Jack Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Desert/Challenge_HiJack_Desert2.Challenge_HiJack_Desert2_C", name: "Hi Jack Desert 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Desolate/Challenge_HiJack_Desolate1.Challenge_HiJack_Desolate1_C", name: "Hi Jack Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Desolate/Challenge_HiJack_Desolate2.Challenge_HiJack_Desolate2_C", name: "Hi Jack Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Motorcade/Challenge_HiJack_Motorcade1.Challenge_HiJack_Motorcade1_C", name: "Hi Jack Motorcade 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Outskirts/Challenge_HiJack_Outskirts1.Challenge_HiJack_Outskirts1_C", name: "Hi Jack Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Sacrifice/Challenge_HiJack_Sacrifice1.Challenge_HiJack_Sacrifice1_C", name: "Hi Jack Sacrifice 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Wetlands/Challenge_HiJack_Wetlands1.Challenge_HiJack_Wetlands1_C", name: "Hi Jack Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/HiJack/Wetlands/Challenge_HiJack_Wetlands2.Challenge_HiJack_Wetlands2_C", name: "Hi Jack Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Crypt.Challenge_Hunt_Crypt_C", name: "Hunt Crypt" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Desert.Challenge_Hunt_Desert_C", name: "Hunt Desert" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Desolate.Challenge_Hunt_Desolate_C", name: "Hunt Desolate" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Mine.Challenge_Hunt_Mine_C", name: "Hunt Mine" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Monastery.Challenge_Hunt_Monastery_C", name: "Hunt Monastery" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Motorcade.Challenge_Hunt_Motorcade_C", name: "Hunt Motorcade" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Prologue.Challenge_Hunt_Prologue_C", name: "Hunt Prologue" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Sacrifice.Challenge_Hunt_Sacrifice_C", name: "Hunt Sacrifice" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Watership.Challenge_Hunt_Watership_C", name: "Hunt Watership" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Hunt/Challenge_Hunt_Wetlands.Challenge_Hunt_Wetlands_C", name: "Hunt Wetlands" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/Beach/Challenge_KillTarget_09_Beach.Challenge_KillTarget_09_Beach_C", name: "Kill Target 09 Beach" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/City/Challenge_KillTarget_City.Challenge_KillTarget_City_C", name: "Kill Target City" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/Desert/Challenge_KillTarget_06_Desert.Challenge_KillTarget_06_Desert_C", name: "Kill Target 06 Desert" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/DesertVault/Challenge_KillTarget_05_DesertVault.Challenge_KillTarget_05_DesertVault_C", name: "Kill Target 05 Desert Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/Mansion/Challenge_KillTarget_08_Mansion.Challenge_KillTarget_08_Mansion_C", name: "Kill Target 08 Mansion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/Marshfields/Challenge_KillTarget_07_Marshfields.Challenge_KillTarget_07_Marshfields_C", name: "Kill Target 07 Marshfields" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/Motorcade/Challenge_KillTarget_10_Motorcade.Challenge_KillTarget_10_Motorcade_C", name: "Kill Target 10 Motorcade" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/OrbitalPlatform/Challenge_KillTarget_01_OrbitalPlatform.Challenge_KillTarget_01_OrbitalPlatform_C", name: "Kill Target 01 Orbital Platform" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/Prison/Challenge_KillTarget_04_Prison.Challenge_KillTarget_04_Prison_C", name: "Kill Target 04 Prison" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/KillTarget/Towers/Challenge_KillTarget_03_Towers.Challenge_KillTarget_03_Towers_C", name: "Kill Target 03 Towers" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/AtlasHQ/Challenge_Sabotage_AtlasHQ.Challenge_Sabotage_AtlasHQ_C", name: "Sabotage Atlas HQ" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Beach/Challenge_Sabotage_Beach.Challenge_Sabotage_Beach_C", name: "Sabotage Beach" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/ChallengeSabotageRewardStat/Challenge_Sabotage_RewardStat13.Challenge_Sabotage_RewardStat13_C", name: "Sabotage Reward Stat 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/ChallengeSabotageRewardStat/Challenge_Sabotage_RewardStat17.Challenge_Sabotage_RewardStat17_C", name: "Sabotage Reward Stat 17" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/ChallengeSabotageRewardStat/Challenge_Sabotage_RewardStat22.Challenge_Sabotage_RewardStat22_C", name: "Sabotage Reward Stat 22" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/ChallengeSabotageRewardStat/Challenge_Sabotage_RewardStat3.Challenge_Sabotage_RewardStat3_C", name: "Sabotage Reward Stat 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/ChallengeSabotageRewardStat/Challenge_Sabotage_RewardStat8.Challenge_Sabotage_RewardStat8_C", name: "Sabotage Reward Stat 8" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/City/Challenge_Sabotage_City1.Challenge_Sabotage_City1_C", name: "Sabotage City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Desert/Challenge_Sabotage_Desert1.Challenge_Sabotage_Desert1_C", name: "Sabotage Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Desert/Challenge_Sabotage_Desert2.Challenge_Sabotage_Desert2_C", name: "Sabotage Desert 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/DesertVault/Challenge_Sabotage_DesertVault.Challenge_Sabotage_DesertVault_C", name: "Sabotage Desert Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Desolate/Challenge_Sabotage_Desolate1.Challenge_Sabotage_Desolate1_C", name: "Sabotage Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Desolate/Challenge_Sabotage_Desolate2.Challenge_Sabotage_Desolate2_C", name: "Sabotage Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Mansion/Challenge_Sabotage_Mansion.Challenge_Sabotage_Mansion_C", name: "Sabotage Mansion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Marshfields/Challenge_Sabotage_Marshfields1.Challenge_Sabotage_Marshfields1_C", name: "Sabotage Marshfields 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Marshfields/Challenge_Sabotage_Marshfields2.Challenge_Sabotage_Marshfields2_C", name: "Sabotage Marshfields 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Mine/Challenge_Sabotage_Mine.Challenge_Sabotage_Mine_C", name: "Sabotage Mine" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Monastery/Challenge_Sabotage_Monastery.Challenge_Sabotage_Monastery_C", name: "Sabotage Monastery" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Motorcade/Challenge_Sabotage_Motorcade.Challenge_Sabotage_Motorcade_C", name: "Sabotage Motorcade" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/MotorcadeFestival/Challenge_Sabotage_MotorcadeFestival.Challenge_Sabotage_MotorcadeFestival_C", name: "Sabotage Motorcade Festival" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Outskirts/Challenge_Sabotage_Outskirts.Challenge_Sabotage_Outskirts_C", name: "Sabotage Outskirts" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Prison/Challenge_Sabotage_Prison1.Challenge_Sabotage_Prison1_C", name: "Sabotage Prison 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Prison/Challenge_Sabotage_Prison2.Challenge_Sabotage_Prison2_C", name: "Sabotage Prison 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Sacrifice/Challenge_Sabotage_Sacrifice.Challenge_Sabotage_Sacrifice_C", name: "Sabotage Sacrifice" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Towers/Challenge_Sabotage_Towers.Challenge_Sabotage_Towers_C", name: "Sabotage Towers" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Wetlands/Challenge_Sabotage_Wetlands1.Challenge_Sabotage_Wetlands1_C", name: "Sabotage Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Sabotage/Wetlands/Challenge_Sabotage_Wetlands2.Challenge_Sabotage_Wetlands2_C", name: "Sabotage Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_AtlasHQ.Challenge_Salvage_AtlasHQ_C", name: "Salvage Atlas HQ" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Beach1.Challenge_Salvage_Beach1_C", name: "Salvage Beach 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Beach2.Challenge_Salvage_Beach2_C", name: "Salvage Beach 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_City.Challenge_Salvage_City_C", name: "Salvage City" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_CityVault.Challenge_Salvage_CityVault_C", name: "Salvage City Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Crypt.Challenge_Salvage_Crypt_C", name: "Salvage Crypt" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Desert1.Challenge_Salvage_Desert1_C", name: "Salvage Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_DesertVault.Challenge_Salvage_DesertVault_C", name: "Salvage Desert Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Desolate1.Challenge_Salvage_Desolate1_C", name: "Salvage Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Desolate2.Challenge_Salvage_Desolate2_C", name: "Salvage Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Mansion1.Challenge_Salvage_Mansion1_C", name: "Salvage Mansion 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Mansion2.Challenge_Salvage_Mansion2_C", name: "Salvage Mansion 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Marshfields1.Challenge_Salvage_Marshfields1_C", name: "Salvage Marshfields 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Marshfields2.Challenge_Salvage_Marshfields2_C", name: "Salvage Marshfields 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Mine1.Challenge_Salvage_Mine1_C", name: "Salvage Mine 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Mine2.Challenge_Salvage_Mine2_C", name: "Salvage Mine 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Monestary1.Challenge_Salvage_Monestary1_C", name: "Salvage Monestary 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Monestary2.Challenge_Salvage_Monestary2_C", name: "Salvage Monestary 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Motorcade.Challenge_Salvage_Motorcade_C", name: "Salvage Motorcade" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_MotorcadeFestival.Challenge_Salvage_MotorcadeFestival_C", name: "Salvage Motorcade Festival" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_MotorcadeInterior.Challenge_Salvage_MotorcadeInterior_C", name: "Salvage Motorcade Interior" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_OrbitalPlatform1.Challenge_Salvage_OrbitalPlatform1_C", name: "Salvage Orbital Platform 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_OrbitalPlatform2.Challenge_Salvage_OrbitalPlatform2_C", name: "Salvage Orbital Platform 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Outskirts.Challenge_Salvage_Outskirts_C", name: "Salvage Outskirts" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Prison1.Challenge_Salvage_Prison1_C", name: "Salvage Prison 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Prison2.Challenge_Salvage_Prison2_C", name: "Salvage Prison 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Prologue1.Challenge_Salvage_Prologue1_C", name: "Salvage Prologue 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Prologue2.Challenge_Salvage_Prologue2_C", name: "Salvage Prologue 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Sacrifice.Challenge_Salvage_Sacrifice_C", name: "Salvage Sacrifice" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Towers1.Challenge_Salvage_Towers1_C", name: "Salvage Towers 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Towers2.Challenge_Salvage_Towers2_C", name: "Salvage Towers 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Watership.Challenge_Salvage_Watership_C", name: "Salvage Watership" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Wetlands1.Challenge_Salvage_Wetlands1_C", name: "Salvage Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_Wetlands2.Challenge_Salvage_Wetlands2_C", name: "Salvage Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/Challenge_Salvage_WetlandsVault.Challenge_Salvage_WetlandsVault_C", name: "Salvage Wetlands Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Salvage/SalvageRewards/Challenge_Salvage_Reward.Challenge_Salvage_Reward_C", name: "Salvage Reward" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ01.Challenge_Discovery_AtlasHQ01_C", name: "Discovery Atlas HQ 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ02.Challenge_Discovery_AtlasHQ02_C", name: "Discovery Atlas HQ 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ03.Challenge_Discovery_AtlasHQ03_C", name: "Discovery Atlas HQ 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ04.Challenge_Discovery_AtlasHQ04_C", name: "Discovery Atlas HQ 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ05.Challenge_Discovery_AtlasHQ05_C", name: "Discovery Atlas HQ 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ06.Challenge_Discovery_AtlasHQ06_C", name: "Discovery Atlas HQ 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ07.Challenge_Discovery_AtlasHQ07_C", name: "Discovery Atlas HQ 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ08.Challenge_Discovery_AtlasHQ08_C", name: "Discovery Atlas HQ 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/AtlasHQ/Challenge_Discovery_AtlasHQ09.Challenge_Discovery_AtlasHQ09_C", name: "Discovery Atlas HQ 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach01.Challenge_Discovery_Beach01_C", name: "Discovery Beach 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach02.Challenge_Discovery_Beach02_C", name: "Discovery Beach 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach03.Challenge_Discovery_Beach03_C", name: "Discovery Beach 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach04.Challenge_Discovery_Beach04_C", name: "Discovery Beach 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach05.Challenge_Discovery_Beach05_C", name: "Discovery Beach 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach06.Challenge_Discovery_Beach06_C", name: "Discovery Beach 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach07.Challenge_Discovery_Beach07_C", name: "Discovery Beach 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Beach/Challenge_Discovery_Beach08.Challenge_Discovery_Beach08_C", name: "Discovery Beach 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/City/Challenge_Discovery_City4.Challenge_Discovery_City4_C", name: "Discovery City 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/City/Challenge_Discovery_City5.Challenge_Discovery_City5_C", name: "Discovery City 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/City/Challenge_Discovery_City6.Challenge_Discovery_City6_C", name: "Discovery City 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/City/Challenge_Discovery_City7.Challenge_Discovery_City7_C", name: "Discovery City 7" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/City/Challenge_Discovery_City8.Challenge_Discovery_City8_C", name: "Discovery City 8" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/City/Challenge_Discovery_City9.Challenge_Discovery_City9_C", name: "Discovery City 9" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityBoss/Challenge_Discovery_CityBoss01.Challenge_Discovery_CityBoss01_C", name: "Discovery City Boss 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityBoss/Challenge_Discovery_CityBoss02.Challenge_Discovery_CityBoss02_C", name: "Discovery City Boss 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityVault/Challenge_Discovery_CityVault01.Challenge_Discovery_CityVault01_C", name: "Discovery City Vault 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityVault/Challenge_Discovery_CityVault02.Challenge_Discovery_CityVault02_C", name: "Discovery City Vault 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityVault/Challenge_Discovery_CityVault03.Challenge_Discovery_CityVault03_C", name: "Discovery City Vault 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityVault/Challenge_Discovery_CityVault04.Challenge_Discovery_CityVault04_C", name: "Discovery City Vault 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityVault/Challenge_Discovery_CityVault05.Challenge_Discovery_CityVault05_C", name: "Discovery City Vault 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/CityVault/Challenge_Discovery_CityVault06.Challenge_Discovery_CityVault06_C", name: "Discovery City Vault 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Convoy/Challenge_Discovery_Convoy01.Challenge_Discovery_Convoy01_C", name: "Discovery Convoy 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Convoy/Challenge_Discovery_Convoy02.Challenge_Discovery_Convoy02_C", name: "Discovery Convoy 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Convoy/Challenge_Discovery_Convoy03.Challenge_Discovery_Convoy03_C", name: "Discovery Convoy 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Convoy/Challenge_Discovery_Convoy04.Challenge_Discovery_Convoy04_C", name: "Discovery Convoy 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt01.Challenge_Discovery_Crypt01_C", name: "Discovery Crypt 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt02.Challenge_Discovery_Crypt02_C", name: "Discovery Crypt 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt03.Challenge_Discovery_Crypt03_C", name: "Discovery Crypt 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt04.Challenge_Discovery_Crypt04_C", name: "Discovery Crypt 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt05.Challenge_Discovery_Crypt05_C", name: "Discovery Crypt 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt06.Challenge_Discovery_Crypt06_C", name: "Discovery Crypt 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt07.Challenge_Discovery_Crypt07_C", name: "Discovery Crypt 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt08.Challenge_Discovery_Crypt08_C", name: "Discovery Crypt 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt09.Challenge_Discovery_Crypt09_C", name: "Discovery Crypt 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Crypt/Challenge_Discovery_Crypt10.Challenge_Discovery_Crypt10_C", name: "Discovery Crypt 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert01.Challenge_Discovery_Desert01_C", name: "Discovery Desert 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert02.Challenge_Discovery_Desert02_C", name: "Discovery Desert 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert03.Challenge_Discovery_Desert03_C", name: "Discovery Desert 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert04.Challenge_Discovery_Desert04_C", name: "Discovery Desert 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert05.Challenge_Discovery_Desert05_C", name: "Discovery Desert 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert06.Challenge_Discovery_Desert06_C", name: "Discovery Desert 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert07.Challenge_Discovery_Desert07_C", name: "Discovery Desert 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert08.Challenge_Discovery_Desert08_C", name: "Discovery Desert 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert09.Challenge_Discovery_Desert09_C", name: "Discovery Desert 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert10.Challenge_Discovery_Desert10_C", name: "Discovery Desert 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert11.Challenge_Discovery_Desert11_C", name: "Discovery Desert 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert12.Challenge_Discovery_Desert12_C", name: "Discovery Desert 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desert/Challenge_Discovery_Desert13.Challenge_Discovery_Desert13_C", name: "Discovery Desert 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/DesertBoss/Challenge_Discovery_DesertBoss01.Challenge_Discovery_DesertBoss01_C", name: "Discovery Desert Boss 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/DesertBoss/Challenge_Discovery_DesertBoss02.Challenge_Discovery_DesertBoss02_C", name: "Discovery Desert Boss 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/DesertVault/Challenge_Discovery_DesertVault01.Challenge_Discovery_DesertVault01_C", name: "Discovery Desert Vault 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/DesertVault/Challenge_Discovery_DesertVault02.Challenge_Discovery_DesertVault02_C", name: "Discovery Desert Vault 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/DesertVault/Challenge_Discovery_DesertVault03.Challenge_Discovery_DesertVault03_C", name: "Discovery Desert Vault 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/DesertVault/Challenge_Discovery_DesertVault04.Challenge_Discovery_DesertVault04_C", name: "Discovery Desert Vault 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/DesertVault/Challenge_Discovery_DesertVault05.Challenge_Discovery_DesertVault05_C", name: "Discovery Desert Vault 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate01.Challenge_Discovery_Desolate01_C", name: "Discovery Desolate 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate02.Challenge_Discovery_Desolate02_C", name: "Discovery Desolate 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate03.Challenge_Discovery_Desolate03_C", name: "Discovery Desolate 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate04.Challenge_Discovery_Desolate04_C", name: "Discovery Desolate 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate05.Challenge_Discovery_Desolate05_C", name: "Discovery Desolate 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate06.Challenge_Discovery_Desolate06_C", name: "Discovery Desolate 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate07.Challenge_Discovery_Desolate07_C", name: "Discovery Desolate 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate08.Challenge_Discovery_Desolate08_C", name: "Discovery Desolate 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Desolate/Challenge_Discovery_Desolate09.Challenge_Discovery_Desolate09_C", name: "Discovery Desolate 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/FinalBoss/Challenge_Discovery_FinalBoss01.Challenge_Discovery_FinalBoss01_C", name: "Discovery Final Boss 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/FinalBoss/Challenge_Discovery_FinalBoss02.Challenge_Discovery_FinalBoss02_C", name: "Discovery Final Boss 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/FinalBoss/Challenge_Discovery_FinalBoss03.Challenge_Discovery_FinalBoss03_C", name: "Discovery Final Boss 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/FinalBoss/Challenge_Discovery_FinalBoss04.Challenge_Discovery_FinalBoss04_C", name: "Discovery Final Boss 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion01.Challenge_Discovery_Mansion01_C", name: "Discovery Mansion 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion02.Challenge_Discovery_Mansion02_C", name: "Discovery Mansion 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion03.Challenge_Discovery_Mansion03_C", name: "Discovery Mansion 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion04.Challenge_Discovery_Mansion04_C", name: "Discovery Mansion 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion05.Challenge_Discovery_Mansion05_C", name: "Discovery Mansion 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion06.Challenge_Discovery_Mansion06_C", name: "Discovery Mansion 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion07.Challenge_Discovery_Mansion07_C", name: "Discovery Mansion 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion08.Challenge_Discovery_Mansion08_C", name: "Discovery Mansion 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion09.Challenge_Discovery_Mansion09_C", name: "Discovery Mansion 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mansion/Challenge_Discovery_Mansion10.Challenge_Discovery_Mansion10_C", name: "Discovery Mansion 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields01.Challenge_Discovery_Marshfields01_C", name: "Discovery Marshfields 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields02.Challenge_Discovery_Marshfields02_C", name: "Discovery Marshfields 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields03.Challenge_Discovery_Marshfields03_C", name: "Discovery Marshfields 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields04.Challenge_Discovery_Marshfields04_C", name: "Discovery Marshfields 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields05.Challenge_Discovery_Marshfields05_C", name: "Discovery Marshfields 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields06.Challenge_Discovery_Marshfields06_C", name: "Discovery Marshfields 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields07.Challenge_Discovery_Marshfields07_C", name: "Discovery Marshfields 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields08.Challenge_Discovery_Marshfields08_C", name: "Discovery Marshfields 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields09.Challenge_Discovery_Marshfields09_C", name: "Discovery Marshfields 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Marshfields/Challenge_Discovery_Marshfields10.Challenge_Discovery_Marshfields10_C", name: "Discovery Marshfields 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine01.Challenge_Discovery_Mine01_C", name: "Discovery Mine 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine02.Challenge_Discovery_Mine02_C", name: "Discovery Mine 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine03.Challenge_Discovery_Mine03_C", name: "Discovery Mine 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine04.Challenge_Discovery_Mine04_C", name: "Discovery Mine 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine05.Challenge_Discovery_Mine05_C", name: "Discovery Mine 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine06.Challenge_Discovery_Mine06_C", name: "Discovery Mine 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine07.Challenge_Discovery_Mine07_C", name: "Discovery Mine 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine08.Challenge_Discovery_Mine08_C", name: "Discovery Mine 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine09.Challenge_Discovery_Mine09_C", name: "Discovery Mine 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine10.Challenge_Discovery_Mine10_C", name: "Discovery Mine 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine11.Challenge_Discovery_Mine11_C", name: "Discovery Mine 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Mine/Challenge_Discovery_Mine12.Challenge_Discovery_Mine12_C", name: "Discovery Mine 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Monastery/Challenge_Discovery_Monastery01.Challenge_Discovery_Monastery01_C", name: "Discovery Monastery 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Monastery/Challenge_Discovery_Monastery02.Challenge_Discovery_Monastery02_C", name: "Discovery Monastery 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Monastery/Challenge_Discovery_Monastery03.Challenge_Discovery_Monastery03_C", name: "Discovery Monastery 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Monastery/Challenge_Discovery_Monastery04.Challenge_Discovery_Monastery04_C", name: "Discovery Monastery 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Monastery/Challenge_Discovery_Monastery05.Challenge_Discovery_Monastery05_C", name: "Discovery Monastery 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Motorcade/Challenge_Discovery_Motorcade01.Challenge_Discovery_Motorcade01_C", name: "Discovery Motorcade 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Motorcade/Challenge_Discovery_Motorcade02.Challenge_Discovery_Motorcade02_C", name: "Discovery Motorcade 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Motorcade/Challenge_Discovery_Motorcade03.Challenge_Discovery_Motorcade03_C", name: "Discovery Motorcade 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Motorcade/Challenge_Discovery_Motorcade04.Challenge_Discovery_Motorcade04_C", name: "Discovery Motorcade 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Motorcade/Challenge_Discovery_Motorcade05.Challenge_Discovery_Motorcade05_C", name: "Discovery Motorcade 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Motorcade/Challenge_Discovery_Motorcade06.Challenge_Discovery_Motorcade06_C", name: "Discovery Motorcade 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeFestival/Challenge_Discovery_MotorcadeFestival01.Challenge_Discovery_MotorcadeFestival01_C", name: "Discovery Motorcade Festival 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeFestival/Challenge_Discovery_MotorcadeFestival02.Challenge_Discovery_MotorcadeFestival02_C", name: "Discovery Motorcade Festival 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeFestival/Challenge_Discovery_MotorcadeFestival03.Challenge_Discovery_MotorcadeFestival03_C", name: "Discovery Motorcade Festival 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeFestival/Challenge_Discovery_MotorcadeFestival04.Challenge_Discovery_MotorcadeFestival04_C", name: "Discovery Motorcade Festival 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeFestival/Challenge_Discovery_MotorcadeFestival05.Challenge_Discovery_MotorcadeFestival05_C", name: "Discovery Motorcade Festival 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeInterior/Challenge_Discovery_MotorcadeInterior01.Challenge_Discovery_MotorcadeInterior01_C", name: "Discovery Motorcade Interior 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeInterior/Challenge_Discovery_MotorcadeInterior02.Challenge_Discovery_MotorcadeInterior02_C", name: "Discovery Motorcade Interior 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeInterior/Challenge_Discovery_MotorcadeInterior03.Challenge_Discovery_MotorcadeInterior03_C", name: "Discovery Motorcade Interior 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeInterior/Challenge_Discovery_MotorcadeInterior04.Challenge_Discovery_MotorcadeInterior04_C", name: "Discovery Motorcade Interior 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/MotorcadeInterior/Challenge_Discovery_MotorcadeInterior05.Challenge_Discovery_MotorcadeInterior05_C", name: "Discovery Motorcade Interior 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform01.Challenge_Discovery_OrbitalPlatform01_C", name: "Discovery Orbital Platform 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform02.Challenge_Discovery_OrbitalPlatform02_C", name: "Discovery Orbital Platform 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform03.Challenge_Discovery_OrbitalPlatform03_C", name: "Discovery Orbital Platform 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform04.Challenge_Discovery_OrbitalPlatform04_C", name: "Discovery Orbital Platform 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform05.Challenge_Discovery_OrbitalPlatform05_C", name: "Discovery Orbital Platform 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform06.Challenge_Discovery_OrbitalPlatform06_C", name: "Discovery Orbital Platform 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform07.Challenge_Discovery_OrbitalPlatform07_C", name: "Discovery Orbital Platform 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/OrbitalPlatform/Challenge_Discovery_OrbitalPlatform08.Challenge_Discovery_OrbitalPlatform08_C", name: "Discovery Orbital Platform 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Outskirts/Challenge_Discovery_Outskirts1.Challenge_Discovery_Outskirts1_C", name: "Discovery Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Outskirts/Challenge_Discovery_Outskirts2.Challenge_Discovery_Outskirts2_C", name: "Discovery Outskirts 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Outskirts/Challenge_Discovery_Outskirts3.Challenge_Discovery_Outskirts3_C", name: "Discovery Outskirts 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prison/Challenge_Discovery_Prison01.Challenge_Discovery_Prison01_C", name: "Discovery Prison 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prison/Challenge_Discovery_Prison02.Challenge_Discovery_Prison02_C", name: "Discovery Prison 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prison/Challenge_Discovery_Prison03.Challenge_Discovery_Prison03_C", name: "Discovery Prison 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prison/Challenge_Discovery_Prison04.Challenge_Discovery_Prison04_C", name: "Discovery Prison 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prison/Challenge_Discovery_Prison05.Challenge_Discovery_Prison05_C", name: "Discovery Prison 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prison/Challenge_Discovery_Prison06.Challenge_Discovery_Prison06_C", name: "Discovery Prison 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prison/Challenge_Discovery_Prison07.Challenge_Discovery_Prison07_C", name: "Discovery Prison 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue01.Challenge_Discovery_Prologue01_C", name: "Discovery Prologue 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue02.Challenge_Discovery_Prologue02_C", name: "Discovery Prologue 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue03.Challenge_Discovery_Prologue03_C", name: "Discovery Prologue 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue04.Challenge_Discovery_Prologue04_C", name: "Discovery Prologue 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue05.Challenge_Discovery_Prologue05_C", name: "Discovery Prologue 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue06.Challenge_Discovery_Prologue06_C", name: "Discovery Prologue 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue07.Challenge_Discovery_Prologue07_C", name: "Discovery Prologue 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue08.Challenge_Discovery_Prologue08_C", name: "Discovery Prologue 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue09.Challenge_Discovery_Prologue09_C", name: "Discovery Prologue 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue10.Challenge_Discovery_Prologue10_C", name: "Discovery Prologue 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue11.Challenge_Discovery_Prologue11_C", name: "Discovery Prologue 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue12.Challenge_Discovery_Prologue12_C", name: "Discovery Prologue 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Prologue/Challenge_Discovery_Prologue13.Challenge_Discovery_Prologue13_C", name: "Discovery Prologue 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/ProvingGrounds1/Challenge_Discovery_ProvingGrounds1_1.Challenge_Discovery_ProvingGrounds1_1_C", name: "Discovery Proving Grounds 1 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/ProvingGrounds4/Challenge_Discovery_ProvingGrounds4_1.Challenge_Discovery_ProvingGrounds4_1_C", name: "Discovery Proving Grounds 4 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/ProvingGrounds5/Challenge_Discovery_ProvingGrounds5_1.Challenge_Discovery_ProvingGrounds5_1_C", name: "Discovery Proving Grounds 5 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/ProvingGrounds6/Challenge_Discovery_ProvingGrounds6_1.Challenge_Discovery_ProvingGrounds6_1_C", name: "Discovery Proving Grounds 6 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/ProvingGrounds7/Challenge_Discovery_ProvingGrounds7_1.Challenge_Discovery_ProvingGrounds7_1_C", name: "Discovery Proving Grounds 7 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/ProvingGrounds8/Challenge_Discovery_ProvingGrounds8_1.Challenge_Discovery_ProvingGrounds8_1_C", name: "Discovery Proving Grounds 8 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Recruitment/Challenge_Discovery_Recruitment01.Challenge_Discovery_Recruitment01_C", name: "Discovery Recruitment 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Recruitment/Challenge_Discovery_Recruitment02.Challenge_Discovery_Recruitment02_C", name: "Discovery Recruitment 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Recruitment/Challenge_Discovery_Recruitment03.Challenge_Discovery_Recruitment03_C", name: "Discovery Recruitment 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Sacrifice/Challenge_Discovery_Sacrifice1.Challenge_Discovery_Sacrifice1_C", name: "Discovery Sacrifice 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Sacrifice/Challenge_Discovery_Sacrifice2.Challenge_Discovery_Sacrifice2_C", name: "Discovery Sacrifice 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Sacrifice/Challenge_Discovery_Sacrifice3.Challenge_Discovery_Sacrifice3_C", name: "Discovery Sacrifice 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Sacrifice/Challenge_Discovery_Sacrifice4.Challenge_Discovery_Sacrifice4_C", name: "Discovery Sacrifice 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Sacrifice/Challenge_Discovery_Sacrifice5.Challenge_Discovery_Sacrifice5_C", name: "Discovery Sacrifice 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Slaughter_COV/Challenge_Discovery_COVSlaughter1.Challenge_Discovery_COVSlaughter1_C", name: "Discovery COV Slaughter 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Slaughter_Creature/Challenge_Discovery_CreatureSlaughter1.Challenge_Discovery_CreatureSlaughter1_C", name: "Discovery Creature Slaughter 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Slaughter_Tech/Challenge_Discovery_TechSlaughter1.Challenge_Discovery_TechSlaughter1_C", name: "Discovery Tech Slaughter 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers01.Challenge_Discovery_Towers01_C", name: "Discovery Towers 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers02.Challenge_Discovery_Towers02_C", name: "Discovery Towers 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers03.Challenge_Discovery_Towers03_C", name: "Discovery Towers 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers04.Challenge_Discovery_Towers04_C", name: "Discovery Towers 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers05.Challenge_Discovery_Towers05_C", name: "Discovery Towers 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers06.Challenge_Discovery_Towers06_C", name: "Discovery Towers 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers07.Challenge_Discovery_Towers07_C", name: "Discovery Towers 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Towers/Challenge_Discovery_Towers08.Challenge_Discovery_Towers08_C", name: "Discovery Towers 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership01.Challenge_Discovery_Watership01_C", name: "Discovery Watership 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership02.Challenge_Discovery_Watership02_C", name: "Discovery Watership 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership03.Challenge_Discovery_Watership03_C", name: "Discovery Watership 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership04.Challenge_Discovery_Watership04_C", name: "Discovery Watership 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership05.Challenge_Discovery_Watership05_C", name: "Discovery Watership 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership06.Challenge_Discovery_Watership06_C", name: "Discovery Watership 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership07.Challenge_Discovery_Watership07_C", name: "Discovery Watership 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership08.Challenge_Discovery_Watership08_C", name: "Discovery Watership 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership09.Challenge_Discovery_Watership09_C", name: "Discovery Watership 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership10.Challenge_Discovery_Watership10_C", name: "Discovery Watership 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Watership/Challenge_Discovery_Watership11.Challenge_Discovery_Watership11_C", name: "Discovery Watership 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands01.Challenge_Discovery_Wetlands01_C", name: "Discovery Wetlands 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands02.Challenge_Discovery_Wetlands02_C", name: "Discovery Wetlands 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands03.Challenge_Discovery_Wetlands03_C", name: "Discovery Wetlands 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands04.Challenge_Discovery_Wetlands04_C", name: "Discovery Wetlands 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands05.Challenge_Discovery_Wetlands05_C", name: "Discovery Wetlands 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands06.Challenge_Discovery_Wetlands06_C", name: "Discovery Wetlands 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands07.Challenge_Discovery_Wetlands07_C", name: "Discovery Wetlands 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands08.Challenge_Discovery_Wetlands08_C", name: "Discovery Wetlands 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands09.Challenge_Discovery_Wetlands09_C", name: "Discovery Wetlands 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands10.Challenge_Discovery_Wetlands10_C", name: "Discovery Wetlands 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands11.Challenge_Discovery_Wetlands11_C", name: "Discovery Wetlands 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands12.Challenge_Discovery_Wetlands12_C", name: "Discovery Wetlands 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands13.Challenge_Discovery_Wetlands13_C", name: "Discovery Wetlands 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands14.Challenge_Discovery_Wetlands14_C", name: "Discovery Wetlands 14" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/Wetlands/Challenge_Discovery_Wetlands15.Challenge_Discovery_Wetlands15_C", name: "Discovery Wetlands 15" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsBoss/Challenge_Discovery_WetlandsBoss01.Challenge_Discovery_WetlandsBoss01_C", name: "Discovery Wetlands Boss 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsBoss/Challenge_Discovery_WetlandsBoss02.Challenge_Discovery_WetlandsBoss02_C", name: "Discovery Wetlands Boss 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault01.Challenge_Discovery_WetlandsVault01_C", name: "Discovery Wetlands Vault 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault02.Challenge_Discovery_WetlandsVault02_C", name: "Discovery Wetlands Vault 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault03.Challenge_Discovery_WetlandsVault03_C", name: "Discovery Wetlands Vault 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault04.Challenge_Discovery_WetlandsVault04_C", name: "Discovery Wetlands Vault 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault05.Challenge_Discovery_WetlandsVault05_C", name: "Discovery Wetlands Vault 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault06.Challenge_Discovery_WetlandsVault06_C", name: "Discovery Wetlands Vault 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault07.Challenge_Discovery_WetlandsVault07_C", name: "Discovery Wetlands Vault 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Discovery/WetlandsVault/Challenge_Discovery_WetlandsVault08.Challenge_Discovery_WetlandsVault08_C", name: "Discovery Wetlands Vault 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_AtlasHQ1.Challenge_EchoLog_NonMission_AtlasHQ1_C", name: "Echo Log Non Mission Atlas HQ 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_AtlasHQ2.Challenge_EchoLog_NonMission_AtlasHQ2_C", name: "Echo Log Non Mission Atlas HQ 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_AtlasHQ3.Challenge_EchoLog_NonMission_AtlasHQ3_C", name: "Echo Log Non Mission Atlas HQ 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_AtlasHQ4.Challenge_EchoLog_NonMission_AtlasHQ4_C", name: "Echo Log Non Mission Atlas HQ 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_AtlasHQ5.Challenge_EchoLog_NonMission_AtlasHQ5_C", name: "Echo Log Non Mission Atlas HQ 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Beach1.Challenge_EchoLog_NonMission_Beach1_C", name: "Echo Log Non Mission Beach 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Beach2.Challenge_EchoLog_NonMission_Beach2_C", name: "Echo Log Non Mission Beach 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Beach3.Challenge_EchoLog_NonMission_Beach3_C", name: "Echo Log Non Mission Beach 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_City1.Challenge_EchoLog_NonMission_City1_C", name: "Echo Log Non Mission City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_City2.Challenge_EchoLog_NonMission_City2_C", name: "Echo Log Non Mission City 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_City3.Challenge_EchoLog_NonMission_City3_C", name: "Echo Log Non Mission City 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_City4.Challenge_EchoLog_NonMission_City4_C", name: "Echo Log Non Mission City 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_City5.Challenge_EchoLog_NonMission_City5_C", name: "Echo Log Non Mission City 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_CityVault1.Challenge_EchoLog_NonMission_CityVault1_C", name: "Echo Log Non Mission City Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_CityVault2.Challenge_EchoLog_NonMission_CityVault2_C", name: "Echo Log Non Mission City Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_CityVault3.Challenge_EchoLog_NonMission_CityVault3_C", name: "Echo Log Non Mission City Vault 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desert1.Challenge_EchoLog_NonMission_Desert1_C", name: "Echo Log Non Mission Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desert2.Challenge_EchoLog_NonMission_Desert2_C", name: "Echo Log Non Mission Desert 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desert3.Challenge_EchoLog_NonMission_Desert3_C", name: "Echo Log Non Mission Desert 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desert4.Challenge_EchoLog_NonMission_Desert4_C", name: "Echo Log Non Mission Desert 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desert5.Challenge_EchoLog_NonMission_Desert5_C", name: "Echo Log Non Mission Desert 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desert6.Challenge_EchoLog_NonMission_Desert6_C", name: "Echo Log Non Mission Desert 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_DesertVault1.Challenge_EchoLog_NonMission_DesertVault1_C", name: "Echo Log Non Mission Desert Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_DesertVault2.Challenge_EchoLog_NonMission_DesertVault2_C", name: "Echo Log Non Mission Desert Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desolate1.Challenge_EchoLog_NonMission_Desolate1_C", name: "Echo Log Non Mission Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Desolate2.Challenge_EchoLog_NonMission_Desolate2_C", name: "Echo Log Non Mission Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Example.Challenge_EchoLog_NonMission_Example_C", name: "Echo Log Non Mission Example" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mansion1.Challenge_EchoLog_NonMission_Mansion1_C", name: "Echo Log Non Mission Mansion 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mansion2.Challenge_EchoLog_NonMission_Mansion2_C", name: "Echo Log Non Mission Mansion 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Marshfields1.Challenge_EchoLog_NonMission_Marshfields1_C", name: "Echo Log Non Mission Marshfields 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Marshfields2.Challenge_EchoLog_NonMission_Marshfields2_C", name: "Echo Log Non Mission Marshfields 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Marshfields3.Challenge_EchoLog_NonMission_Marshfields3_C", name: "Echo Log Non Mission Marshfields 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Marshfields4.Challenge_EchoLog_NonMission_Marshfields4_C", name: "Echo Log Non Mission Marshfields 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mine1.Challenge_EchoLog_NonMission_Mine1_C", name: "Echo Log Non Mission Mine 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mine2.Challenge_EchoLog_NonMission_Mine2_C", name: "Echo Log Non Mission Mine 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mine3.Challenge_EchoLog_NonMission_Mine3_C", name: "Echo Log Non Mission Mine 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mine4.Challenge_EchoLog_NonMission_Mine4_C", name: "Echo Log Non Mission Mine 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mine5.Challenge_EchoLog_NonMission_Mine5_C", name: "Echo Log Non Mission Mine 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Mine6.Challenge_EchoLog_NonMission_Mine6_C", name: "Echo Log Non Mission Mine 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Monastery1.Challenge_EchoLog_NonMission_Monastery1_C", name: "Echo Log Non Mission Monastery 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Monastery2.Challenge_EchoLog_NonMission_Monastery2_C", name: "Echo Log Non Mission Monastery 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Monastery3.Challenge_EchoLog_NonMission_Monastery3_C", name: "Echo Log Non Mission Monastery 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Motorcade1.Challenge_EchoLog_NonMission_Motorcade1_C", name: "Echo Log Non Mission Motorcade 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Motorcade2.Challenge_EchoLog_NonMission_Motorcade2_C", name: "Echo Log Non Mission Motorcade 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Motorcade3.Challenge_EchoLog_NonMission_Motorcade3_C", name: "Echo Log Non Mission Motorcade 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Motorcade4.Challenge_EchoLog_NonMission_Motorcade4_C", name: "Echo Log Non Mission Motorcade 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Motorcade5.Challenge_EchoLog_NonMission_Motorcade5_C", name: "Echo Log Non Mission Motorcade 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_MotorcadeFestival1.Challenge_EchoLog_NonMission_MotorcadeFestival1_C", name: "Echo Log Non Mission Motorcade Festival 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_MotorcadeInterior1.Challenge_EchoLog_NonMission_MotorcadeInterior1_C", name: "Echo Log Non Mission Motorcade Interior 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_MotorcadeInterior2.Challenge_EchoLog_NonMission_MotorcadeInterior2_C", name: "Echo Log Non Mission Motorcade Interior 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_MotorcadeInterior3.Challenge_EchoLog_NonMission_MotorcadeInterior3_C", name: "Echo Log Non Mission Motorcade Interior 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_OrbitalPlatform1.Challenge_EchoLog_NonMission_OrbitalPlatform1_C", name: "Echo Log Non Mission Orbital Platform 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_OrbitalPlatform2.Challenge_EchoLog_NonMission_OrbitalPlatform2_C", name: "Echo Log Non Mission Orbital Platform 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Outskirts1.Challenge_EchoLog_NonMission_Outskirts1_C", name: "Echo Log Non Mission Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Outskirts2.Challenge_EchoLog_NonMission_Outskirts2_C", name: "Echo Log Non Mission Outskirts 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Outskirts3.Challenge_EchoLog_NonMission_Outskirts3_C", name: "Echo Log Non Mission Outskirts 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Outskirts4.Challenge_EchoLog_NonMission_Outskirts4_C", name: "Echo Log Non Mission Outskirts 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prison1.Challenge_EchoLog_NonMission_Prison1_C", name: "Echo Log Non Mission Prison 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prison2.Challenge_EchoLog_NonMission_Prison2_C", name: "Echo Log Non Mission Prison 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prologue1.Challenge_EchoLog_NonMission_Prologue1_C", name: "Echo Log Non Mission Prologue 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prologue2.Challenge_EchoLog_NonMission_Prologue2_C", name: "Echo Log Non Mission Prologue 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prologue3.Challenge_EchoLog_NonMission_Prologue3_C", name: "Echo Log Non Mission Prologue 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prologue4.Challenge_EchoLog_NonMission_Prologue4_C", name: "Echo Log Non Mission Prologue 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prologue5.Challenge_EchoLog_NonMission_Prologue5_C", name: "Echo Log Non Mission Prologue 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Prologue6.Challenge_EchoLog_NonMission_Prologue6_C", name: "Echo Log Non Mission Prologue 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Sacrifice1.Challenge_EchoLog_NonMission_Sacrifice1_C", name: "Echo Log Non Mission Sacrifice 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Sacrifice2.Challenge_EchoLog_NonMission_Sacrifice2_C", name: "Echo Log Non Mission Sacrifice 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Sacrifice3.Challenge_EchoLog_NonMission_Sacrifice3_C", name: "Echo Log Non Mission Sacrifice 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Sanctuary1.Challenge_EchoLog_NonMission_Sanctuary1_C", name: "Echo Log Non Mission Sanctuary 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Sanctuary2.Challenge_EchoLog_NonMission_Sanctuary2_C", name: "Echo Log Non Mission Sanctuary 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Sanctuary3.Challenge_EchoLog_NonMission_Sanctuary3_C", name: "Echo Log Non Mission Sanctuary 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Sanctuary4.Challenge_EchoLog_NonMission_Sanctuary4_C", name: "Echo Log Non Mission Sanctuary 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Towers1.Challenge_EchoLog_NonMission_Towers1_C", name: "Echo Log Non Mission Towers 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Towers2.Challenge_EchoLog_NonMission_Towers2_C", name: "Echo Log Non Mission Towers 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Watership1.Challenge_EchoLog_NonMission_Watership1_C", name: "Echo Log Non Mission Watership 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Watership2.Challenge_EchoLog_NonMission_Watership2_C", name: "Echo Log Non Mission Watership 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Wetlands1.Challenge_EchoLog_NonMission_Wetlands1_C", name: "Echo Log Non Mission Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Wetlands2.Challenge_EchoLog_NonMission_Wetlands2_C", name: "Echo Log Non Mission Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Wetlands3.Challenge_EchoLog_NonMission_Wetlands3_C", name: "Echo Log Non Mission Wetlands 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Wetlands4.Challenge_EchoLog_NonMission_Wetlands4_C", name: "Echo Log Non Mission Wetlands 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_WetlandsVault1.Challenge_EchoLog_NonMission_WetlandsVault1_C", name: "Echo Log Non Mission Wetlands Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_WetlandsVault2.Challenge_EchoLog_NonMission_WetlandsVault2_C", name: "Echo Log Non Mission Wetlands Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Ecomony/Challenge_Economy_BuyItemsOfTheDay.Challenge_Economy_BuyItemsOfTheDay_C", name: "Economy Buy Items Of The Day" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Ecomony/Challenge_Economy_CashLooted.Challenge_Economy_CashLooted_C", name: "Economy Cash Looted" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Ecomony/Challenge_Economy_EridiumPurchases.Challenge_Economy_EridiumPurchases_C", name: "Economy Eridium Purchases" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Ecomony/Challenge_Economy_ItemsSold.Challenge_Economy_ItemsSold_C", name: "Economy Items Sold" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Ecomony/Challenge_Economy_Trades.Challenge_Economy_Trades_C", name: "Economy Trades" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Damage_Corrosive.Challenge_Elemental_Damage_Corrosive_C", name: "Elemental Damage Corrosive" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Damage_Cryo.Challenge_Elemental_Damage_Cryo_C", name: "Elemental Damage Cryo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Damage_Fire.Challenge_Elemental_Damage_Fire_C", name: "Elemental Damage Fire" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Damage_Radiation.Challenge_Elemental_Damage_Radiation_C", name: "Elemental Damage Radiation" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Damage_Shock.Challenge_Elemental_Damage_Shock_C", name: "Elemental Damage Shock" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Kills_Corrosive.Challenge_Elemental_Kills_Corrosive_C", name: "Elemental Kills Corrosive" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Kills_Cryo.Challenge_Elemental_Kills_Cryo_C", name: "Elemental Kills Cryo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Kills_ExplosiveBarrel.Challenge_Elemental_Kills_ExplosiveBarrel_C", name: "Elemental Kills Explosive Barrel" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Kills_Fire.Challenge_Elemental_Kills_Fire_C", name: "Elemental Kills Fire" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Kills_PuddlesShocked.Challenge_Elemental_Kills_PuddlesShocked_C", name: "Elemental Kills Puddles Shocked" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Kills_Radiation.Challenge_Elemental_Kills_Radiation_C", name: "Elemental Kills Radiation" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Elemental/Challenge_Elemental_Kills_Shock.Challenge_Elemental_Kills_Shock_C", name: "Elemental Kills Shock" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Challenge_BadassKills.Challenge_BadassKills_C", name: "Badass Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Challenge_EnemiesKilled.Challenge_EnemiesKilled_C", name: "Enemies Killed" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_CoV_Enforcer.Challenge_Enemy_CoV_Enforcer_C", name: "Enemy Co V Enforcer" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_CoV_Goliath.Challenge_Enemy_CoV_Goliath_C", name: "Enemy Co V Goliath" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_CoV_Goon.Challenge_Enemy_CoV_Goon_C", name: "Enemy Co V Goon" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_CoV_Psycho.Challenge_Enemy_CoV_Psycho_C", name: "Enemy Co V Psycho" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_CoV_Punk.Challenge_Enemy_CoV_Punk_C", name: "Enemy Co V Punk" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_CoV_Tink.Challenge_Enemy_CoV_Tink_C", name: "Enemy Co V Tink" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_CoV_Turret.Challenge_Enemy_CoV_Turret_C", name: "Enemy Co V Turret" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/CoV/Challenge_Enemy_Group_CoV.Challenge_Enemy_Group_CoV_C", name: "Enemy Group Co V" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Guardians/Challenge_Enemy_Guardians_Guardian.Challenge_Enemy_Guardians_Guardian_C", name: "Enemy Guardians Guardian" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Group_Indigenous.Challenge_Enemy_Group_Indigenous_C", name: "Enemy Group Indigenous" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Ape.Challenge_Enemy_Indigenous_Ape_C", name: "Enemy Indigenous Ape" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Eden6.Challenge_Enemy_Indigenous_Eden6_C", name: "Enemy Indigenous Eden 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Nekro.Challenge_Enemy_Indigenous_Nekro_C", name: "Enemy Indigenous Nekro" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Nekrobug.Challenge_Enemy_Indigenous_Nekrobug_C", name: "Enemy Indigenous Nekrobug" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Pandora.Challenge_Enemy_Indigenous_Pandora_C", name: "Enemy Indigenous Pandora" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Promethea.Challenge_Enemy_Indigenous_Promethea_C", name: "Enemy Indigenous Promethea" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Rakk.Challenge_Enemy_Indigenous_Rakk_C", name: "Enemy Indigenous Rakk" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Ratch.Challenge_Enemy_Indigenous_Ratch_C", name: "Enemy Indigenous Ratch" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Saurian.Challenge_Enemy_Indigenous_Saurian_C", name: "Enemy Indigenous Saurian" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_ServiceBot.Challenge_Enemy_Indigenous_ServiceBot_C", name: "Enemy Indigenous Service Bot" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Skag.Challenge_Enemy_Indigenous_Skag_C", name: "Enemy Indigenous Skag" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Spiderant.Challenge_Enemy_Indigenous_Spiderant_C", name: "Enemy Indigenous Spiderant" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Indigenous/Challenge_Enemy_Indigenous_Varkid.Challenge_Enemy_Indigenous_Varkid_C", name: "Enemy Indigenous Varkid" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Maliwan/Challenge_Enemy_Group_Maliwan.Challenge_Enemy_Group_Maliwan_C", name: "Enemy Group Maliwan" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Maliwan/Challenge_Enemy_Maliwan_Heavy.Challenge_Enemy_Maliwan_Heavy_C", name: "Enemy Maliwan Heavy" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Maliwan/Challenge_Enemy_Maliwan_Mech.Challenge_Enemy_Maliwan_Mech_C", name: "Enemy Maliwan Mech" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Maliwan/Challenge_Enemy_Maliwan_Nog.Challenge_Enemy_Maliwan_Nog_C", name: "Enemy Maliwan Nog" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Maliwan/Challenge_Enemy_Maliwan_Oversphere.Challenge_Enemy_Maliwan_Oversphere_C", name: "Enemy Maliwan Oversphere" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Maliwan/Challenge_Enemy_Maliwan_RobotDog.Challenge_Enemy_Maliwan_RobotDog_C", name: "Enemy Maliwan Robot Dog" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Enemy/Maliwan/Challenge_Enemy_Maliwan_Trooper.Challenge_Enemy_Maliwan_Trooper_C", name: "Enemy Maliwan Trooper" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_01.Challenge_EridianCrystal_01_C", name: "Eridian Crystal 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_02.Challenge_EridianCrystal_02_C", name: "Eridian Crystal 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_03.Challenge_EridianCrystal_03_C", name: "Eridian Crystal 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_05.Challenge_EridianCrystal_05_C", name: "Eridian Crystal 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_06.Challenge_EridianCrystal_06_C", name: "Eridian Crystal 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_07.Challenge_EridianCrystal_07_C", name: "Eridian Crystal 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_08.Challenge_EridianCrystal_08_C", name: "Eridian Crystal 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_09.Challenge_EridianCrystal_09_C", name: "Eridian Crystal 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_10.Challenge_EridianCrystal_10_C", name: "Eridian Crystal 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_11.Challenge_EridianCrystal_11_C", name: "Eridian Crystal 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_12.Challenge_EridianCrystal_12_C", name: "Eridian Crystal 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_13.Challenge_EridianCrystal_13_C", name: "Eridian Crystal 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_14.Challenge_EridianCrystal_14_C", name: "Eridian Crystal 14" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_15.Challenge_EridianCrystal_15_C", name: "Eridian Crystal 15" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianCrystal/Challenge_EridianCrystal_ChallengePlayground.Challenge_EridianCrystal_ChallengePlayground_C", name: "Eridian Crystal Challenge Playground" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_00_Test.Challenge_EridianWriting_00_Test_C", name: "Eridian Writing 00 Test" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_01.Challenge_EridianWriting_01_C", name: "Eridian Writing 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_02.Challenge_EridianWriting_02_C", name: "Eridian Writing 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_03.Challenge_EridianWriting_03_C", name: "Eridian Writing 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_04.Challenge_EridianWriting_04_C", name: "Eridian Writing 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_05.Challenge_EridianWriting_05_C", name: "Eridian Writing 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_06.Challenge_EridianWriting_06_C", name: "Eridian Writing 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_07.Challenge_EridianWriting_07_C", name: "Eridian Writing 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_08.Challenge_EridianWriting_08_C", name: "Eridian Writing 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_09.Challenge_EridianWriting_09_C", name: "Eridian Writing 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_10.Challenge_EridianWriting_10_C", name: "Eridian Writing 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_11.Challenge_EridianWriting_11_C", name: "Eridian Writing 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_12.Challenge_EridianWriting_12_C", name: "Eridian Writing 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_13.Challenge_EridianWriting_13_C", name: "Eridian Writing 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_14.Challenge_EridianWriting_14_C", name: "Eridian Writing 14" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_15.Challenge_EridianWriting_15_C", name: "Eridian Writing 15" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_16.Challenge_EridianWriting_16_C", name: "Eridian Writing 16" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_17.Challenge_EridianWriting_17_C", name: "Eridian Writing 17" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_18.Challenge_EridianWriting_18_C", name: "Eridian Writing 18" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_19.Challenge_EridianWriting_19_C", name: "Eridian Writing 19" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_20.Challenge_EridianWriting_20_C", name: "Eridian Writing 20" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_21.Challenge_EridianWriting_21_C", name: "Eridian Writing 21" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_22.Challenge_EridianWriting_22_C", name: "Eridian Writing 22" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_23.Challenge_EridianWriting_23_C", name: "Eridian Writing 23" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_24.Challenge_EridianWriting_24_C", name: "Eridian Writing 24" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_25.Challenge_EridianWriting_25_C", name: "Eridian Writing 25" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_26.Challenge_EridianWriting_26_C", name: "Eridian Writing 26" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_27.Challenge_EridianWriting_27_C", name: "Eridian Writing 27" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_28.Challenge_EridianWriting_28_C", name: "Eridian Writing 28" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_29.Challenge_EridianWriting_29_C", name: "Eridian Writing 29" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_30.Challenge_EridianWriting_30_C", name: "Eridian Writing 30" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_AllPlanets.Challenge_EridianWriting_AllPlanets_C", name: "Eridian Writing All Planets" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_Eden6.Challenge_EridianWriting_Eden6_C", name: "Eridian Writing Eden 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_Nekrotafeyo.Challenge_EridianWriting_Nekrotafeyo_C", name: "Eridian Writing Nekrotafeyo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_Pandora.Challenge_EridianWriting_Pandora_C", name: "Eridian Writing Pandora" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_EridianWriting_Promethea.Challenge_EridianWriting_Promethea_C", name: "Eridian Writing Promethea" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_KeystoneActive_AllPlanets.Challenge_KeystoneActive_AllPlanets_C", name: "Keystone Active All Planets" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_KeystoneActive_Eden6.Challenge_KeystoneActive_Eden6_C", name: "Keystone Active Eden 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_KeystoneActive_Nekrotafeyo.Challenge_KeystoneActive_Nekrotafeyo_C", name: "Keystone Active Nekrotafeyo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_KeystoneActive_Pandora.Challenge_KeystoneActive_Pandora_C", name: "Keystone Active Pandora" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/Challenge_KeystoneActive_Promethea.Challenge_KeystoneActive_Promethea_C", name: "Keystone Active Promethea" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_AtlasHQ1.Challenge_FastTravel_AtlasHQ1_C", name: "Fast Travel Atlas HQ 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_AtlasHQ2.Challenge_FastTravel_AtlasHQ2_C", name: "Fast Travel Atlas HQ 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Beach1.Challenge_FastTravel_Beach1_C", name: "Fast Travel Beach 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Beach2.Challenge_FastTravel_Beach2_C", name: "Fast Travel Beach 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_City1.Challenge_FastTravel_City1_C", name: "Fast Travel City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_City2.Challenge_FastTravel_City2_C", name: "Fast Travel City 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CityBoss1.Challenge_FastTravel_CityBoss1_C", name: "Fast Travel City Boss 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CityBoss2.Challenge_FastTravel_CityBoss2_C", name: "Fast Travel City Boss 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CityVault1.Challenge_FastTravel_CityVault1_C", name: "Fast Travel City Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Convoy1.Challenge_FastTravel_Convoy1_C", name: "Fast Travel Convoy 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_COVSlaughter1.Challenge_FastTravel_COVSlaughter1_C", name: "Fast Travel COV Slaughter 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CreatureSlaughter1.Challenge_FastTravel_CreatureSlaughter1_C", name: "Fast Travel Creature Slaughter 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Crypt1.Challenge_FastTravel_Crypt1_C", name: "Fast Travel Crypt 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desert1.Challenge_FastTravel_Desert1_C", name: "Fast Travel Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desert2.Challenge_FastTravel_Desert2_C", name: "Fast Travel Desert 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_DesertBoss1.Challenge_FastTravel_DesertBoss1_C", name: "Fast Travel Desert Boss 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_DesertVault1.Challenge_FastTravel_DesertVault1_C", name: "Fast Travel Desert Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desolate1.Challenge_FastTravel_Desolate1_C", name: "Fast Travel Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desolate2.Challenge_FastTravel_Desolate2_C", name: "Fast Travel Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_FinalBoss1.Challenge_FastTravel_FinalBoss1_C", name: "Fast Travel Final Boss 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_FinalBoss2.Challenge_FastTravel_FinalBoss2_C", name: "Fast Travel Final Boss 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Mansion1.Challenge_FastTravel_Mansion1_C", name: "Fast Travel Mansion 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Marshfields1.Challenge_FastTravel_Marshfields1_C", name: "Fast Travel Marshfields 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Marshfields2.Challenge_FastTravel_Marshfields2_C", name: "Fast Travel Marshfields 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Mine1.Challenge_FastTravel_Mine1_C", name: "Fast Travel Mine 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Monastery1.Challenge_FastTravel_Monastery1_C", name: "Fast Travel Monastery 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Monastery2.Challenge_FastTravel_Monastery2_C", name: "Fast Travel Monastery 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Motorcade1.Challenge_FastTravel_Motorcade1_C", name: "Fast Travel Motorcade 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Motorcade2.Challenge_FastTravel_Motorcade2_C", name: "Fast Travel Motorcade 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_MotorcadeFestival1.Challenge_FastTravel_MotorcadeFestival1_C", name: "Fast Travel Motorcade Festival 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_MotorcadeInterior1.Challenge_FastTravel_MotorcadeInterior1_C", name: "Fast Travel Motorcade Interior 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_MotorcadeInterior2.Challenge_FastTravel_MotorcadeInterior2_C", name: "Fast Travel Motorcade Interior 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_OrbitalPlatform1.Challenge_FastTravel_OrbitalPlatform1_C", name: "Fast Travel Orbital Platform 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_OrbitalPlatform2.Challenge_FastTravel_OrbitalPlatform2_C", name: "Fast Travel Orbital Platform 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Outskirts1.Challenge_FastTravel_Outskirts1_C", name: "Fast Travel Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Outskirts2.Challenge_FastTravel_Outskirts2_C", name: "Fast Travel Outskirts 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Prison1.Challenge_FastTravel_Prison1_C", name: "Fast Travel Prison 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Prison2.Challenge_FastTravel_Prison2_C", name: "Fast Travel Prison 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Prologue1.Challenge_FastTravel_Prologue1_C", name: "Fast Travel Prologue 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Prologue2.Challenge_FastTravel_Prologue2_C", name: "Fast Travel Prologue 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds1_1.Challenge_FastTravel_ProvingGrounds1_1_C", name: "Fast Travel Proving Grounds 1 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds1_2.Challenge_FastTravel_ProvingGrounds1_2_C", name: "Fast Travel Proving Grounds 1 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds4_1.Challenge_FastTravel_ProvingGrounds4_1_C", name: "Fast Travel Proving Grounds 4 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds4_2.Challenge_FastTravel_ProvingGrounds4_2_C", name: "Fast Travel Proving Grounds 4 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds5_1.Challenge_FastTravel_ProvingGrounds5_1_C", name: "Fast Travel Proving Grounds 5 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds5_2.Challenge_FastTravel_ProvingGrounds5_2_C", name: "Fast Travel Proving Grounds 5 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds6_1.Challenge_FastTravel_ProvingGrounds6_1_C", name: "Fast Travel Proving Grounds 6 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds6_2.Challenge_FastTravel_ProvingGrounds6_2_C", name: "Fast Travel Proving Grounds 6 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds7_1.Challenge_FastTravel_ProvingGrounds7_1_C", name: "Fast Travel Proving Grounds 7 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds7_2.Challenge_FastTravel_ProvingGrounds7_2_C", name: "Fast Travel Proving Grounds 7 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds8_1.Challenge_FastTravel_ProvingGrounds8_1_C", name: "Fast Travel Proving Grounds 8 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds8_2.Challenge_FastTravel_ProvingGrounds8_2_C", name: "Fast Travel Proving Grounds 8 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Recruitment1.Challenge_FastTravel_Recruitment1_C", name: "Fast Travel Recruitment 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Sacrifice1.Challenge_FastTravel_Sacrifice1_C", name: "Fast Travel Sacrifice 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Sanctuary3_1.Challenge_FastTravel_Sanctuary3_1_C", name: "Fast Travel Sanctuary 3 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Sanctuary3_2.Challenge_FastTravel_Sanctuary3_2_C", name: "Fast Travel Sanctuary 3 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_TechSlaughter1.Challenge_FastTravel_TechSlaughter1_C", name: "Fast Travel Tech Slaughter 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Towers1.Challenge_FastTravel_Towers1_C", name: "Fast Travel Towers 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Watership1.Challenge_FastTravel_Watership1_C", name: "Fast Travel Watership 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Watership2.Challenge_FastTravel_Watership2_C", name: "Fast Travel Watership 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Wetlands1.Challenge_FastTravel_Wetlands1_C", name: "Fast Travel Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Wetlands2.Challenge_FastTravel_Wetlands2_C", name: "Fast Travel Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Wetlands3.Challenge_FastTravel_Wetlands3_C", name: "Fast Travel Wetlands 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_WetlandsBoss.Challenge_FastTravel_WetlandsBoss_C", name: "Fast Travel Wetlands Boss" },
+    GameDataKv { ident: "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_WetlandsVault1.Challenge_FastTravel_WetlandsVault1_C", name: "Fast Travel Wetlands Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/GeneralCombat/Challenge_Combat_KillWith_Crits.Challenge_Combat_KillWith_Crits_C", name: "Combat Kill With Crits" },
+    GameDataKv { ident: "/Game/GameData/Challenges/GeneralCombat/Challenge_DuelsWon.Challenge_DuelsWon_C", name: "Duels Won" },
+    GameDataKv { ident: "/Game/GameData/Challenges/GeneralCombat/Challenge_MovingKills.Challenge_MovingKills_C", name: "Moving Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/GeneralCombat/Challenge_Sanc3_FiringRange_PerfectScore.Challenge_Sanc3_FiringRange_PerfectScore_C", name: "Sanc 3 Firing Range Perfect Score" },
+    GameDataKv { ident: "/Game/GameData/Challenges/GeneralCombat/Challenge_SlidingKills.Challenge_SlidingKills_C", name: "Sliding Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Grenade/Challenge_Grenade_Kills.Challenge_Grenade_Kills_C", name: "Grenade Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Grenade/Challenge_Grenade_PayloadKill_Artillery.Challenge_Grenade_PayloadKill_Artillery_C", name: "Grenade Payload Kill Artillery" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Grenade/Challenge_Grenade_PayloadKill_Mirv.Challenge_Grenade_PayloadKill_Mirv_C", name: "Grenade Payload Kill Mirv" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Grenade/Challenge_Grenade_PayloadKill_Singularity.Challenge_Grenade_PayloadKill_Singularity_C", name: "Grenade Payload Kill Singularity" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Grenade/Challenge_Grenade_PayloadKill_Transfusion.Challenge_Grenade_PayloadKill_Transfusion_C", name: "Grenade Payload Kill Transfusion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_CoopRevives.Challenge_Recovery_CoopRevives_C", name: "Recovery Coop Revives" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_CorrosiveDoTSecondWinds.Challenge_Recovery_CorrosiveDoTSecondWinds_C", name: "Recovery Corrosive Do T Second Winds" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_DoTSecondWinds_Frozen.Challenge_Recovery_DoTSecondWinds_Frozen_C", name: "Recovery Do T Second Winds Frozen" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_FireDoTSecondWinds.Challenge_Recovery_FireDoTSecondWinds_C", name: "Recovery Fire Do T Second Winds" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_HealthVialPickups.Challenge_Recovery_HealthVialPickups_C", name: "Recovery Health Vial Pickups" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_SecondWinds.Challenge_Recovery_SecondWinds_C", name: "Recovery Second Winds" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_SecondWinds_Badass.Challenge_Recovery_SecondWinds_Badass_C", name: "Recovery Second Winds Badass" },
+    GameDataKv { ident: "/Game/GameData/Challenges/HealthAndRecovery/Challenge_Recovery_ShockDoTSecondWinds.Challenge_Recovery_ShockDoTSecondWinds_C", name: "Recovery Shock Do T Second Winds" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_BlueItems.Challenge_Loot_BlueItems_C", name: "Loot Blue Items" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_GreenItems.Challenge_Loot_GreenItems_C", name: "Loot Green Items" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_OpenLootables.Challenge_Loot_OpenLootables_C", name: "Loot Open Lootables" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_OpenRedChests.Challenge_Loot_OpenRedChests_C", name: "Loot Open Red Chests" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_OrangeItems.Challenge_Loot_OrangeItems_C", name: "Loot Orange Items" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_PurpleItems.Challenge_Loot_PurpleItems_C", name: "Loot Purple Items" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_Weapons.Challenge_Loot_Weapons_C", name: "Loot Weapons" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Loot/Challenge_Loot_WhiteItems.Challenge_Loot_WhiteItems_C", name: "Loot White Items" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Atlas_DartTags.Challenge_Manufacturer_Atlas_DartTags_C", name: "Manufacturer Atlas Dart Tags" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Atlas_GrenadeKills.Challenge_Manufacturer_Atlas_GrenadeKills_C", name: "Manufacturer Atlas Grenade Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Atlas_GrenadeTags.Challenge_Manufacturer_Atlas_GrenadeTags_C", name: "Manufacturer Atlas Grenade Tags" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Atlas_PuckTags.Challenge_Manufacturer_Atlas_PuckTags_C", name: "Manufacturer Atlas Puck Tags" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_CoV_CoVKills.Challenge_Manufacturer_CoV_CoVKills_C", name: "Manufacturer Co V Co V Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_CoV_HeavyKills.Challenge_Manufacturer_CoV_HeavyKills_C", name: "Manufacturer Co V Heavy Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_CoV_NotOverheatedKills.Challenge_Manufacturer_CoV_NotOverheatedKills_C", name: "Manufacturer Co V Not Overheated Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_CoV_OverheatedKills.Challenge_Manufacturer_CoV_OverheatedKills_C", name: "Manufacturer Co V Overheated Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Dahl_Auto.Challenge_Manufacturer_Dahl_Auto_C", name: "Manufacturer Dahl Auto" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Dahl_Burst.Challenge_Manufacturer_Dahl_Burst_C", name: "Manufacturer Dahl Burst" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Dahl_Scoped.Challenge_Manufacturer_Dahl_Scoped_C", name: "Manufacturer Dahl Scoped" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Dahl_Single.Challenge_Manufacturer_Dahl_Single_C", name: "Manufacturer Dahl Single" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Hyperion_AbsorbAmmo.Challenge_Manufacturer_Hyperion_AbsorbAmmo_C", name: "Manufacturer Hyperion Absorb Ammo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Hyperion_AmpKills.Challenge_Manufacturer_Hyperion_AmpKills_C", name: "Manufacturer Hyperion Amp Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Hyperion_GrenadeKills.Challenge_Manufacturer_Hyperion_GrenadeKills_C", name: "Manufacturer Hyperion Grenade Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Hyperion_HighAccKills.Challenge_Manufacturer_Hyperion_HighAccKills_C", name: "Manufacturer Hyperion High Acc Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Jakobs_CritHits.Challenge_Manufacturer_Jakobs_CritHits_C", name: "Manufacturer Jakobs Crit Hits" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Jakobs_CritKills.Challenge_Manufacturer_Jakobs_CritKills_C", name: "Manufacturer Jakobs Crit Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Jakobs_Hipfire.Challenge_Manufacturer_Jakobs_Hipfire_C", name: "Manufacturer Jakobs Hipfire" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Jakobs_RicochetKills.Challenge_Manufacturer_Jakobs_RicochetKills_C", name: "Manufacturer Jakobs Ricochet Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Maliwan_Beam.Challenge_Manufacturer_Maliwan_Beam_C", name: "Manufacturer Maliwan Beam" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Maliwan_DotKill.Challenge_Manufacturer_Maliwan_DotKill_C", name: "Manufacturer Maliwan Dot Kill" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Maliwan_Plasma.Challenge_Manufacturer_Maliwan_Plasma_C", name: "Manufacturer Maliwan Plasma" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Maliwan_Shockwave.Challenge_Manufacturer_Maliwan_Shockwave_C", name: "Manufacturer Maliwan Shockwave" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Tediore_ComboKills.Challenge_Manufacturer_Tediore_ComboKills_C", name: "Manufacturer Tediore Combo Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Tediore_EmptyClipKills.Challenge_Manufacturer_Tediore_EmptyClipKills_C", name: "Manufacturer Tediore Empty Clip Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Tediore_LoadedClipKills.Challenge_Manufacturer_Tediore_LoadedClipKills_C", name: "Manufacturer Tediore Loaded Clip Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Tediore_TurretKills.Challenge_Manufacturer_Tediore_TurretKills_C", name: "Manufacturer Tediore Turret Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Torgue_GrenadeKills.Challenge_Manufacturer_Torgue_GrenadeKills_C", name: "Manufacturer Torgue Grenade Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Torgue_ImpactKills.Challenge_Manufacturer_Torgue_ImpactKills_C", name: "Manufacturer Torgue Impact Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Torgue_SplashKills.Challenge_Manufacturer_Torgue_SplashKills_C", name: "Manufacturer Torgue Splash Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Torgue_StickyKills.Challenge_Manufacturer_Torgue_StickyKills_C", name: "Manufacturer Torgue Sticky Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Vladof_Bipod.Challenge_Manufacturer_Vladof_Bipod_C", name: "Manufacturer Vladof Bipod" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Vladof_GrenadeUnderbarrel.Challenge_Manufacturer_Vladof_GrenadeUnderbarrel_C", name: "Manufacturer Vladof Grenade Underbarrel" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Vladof_Rockets.Challenge_Manufacturer_Vladof_Rockets_C", name: "Manufacturer Vladof Rockets" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/Challenge_Manufacturer_Vladof_ShotgunUnderbarrel.Challenge_Manufacturer_Vladof_ShotgunUnderbarrel_C", name: "Manufacturer Vladof Shotgun Underbarrel" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Atlas.Challenge_ManufacturerRewardReward_Atlas_C", name: "Manufacturer Reward Reward Atlas" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_CoV.Challenge_ManufacturerRewardReward_CoV_C", name: "Manufacturer Reward Reward Co V" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Dahl.Challenge_ManufacturerRewardReward_Dahl_C", name: "Manufacturer Reward Reward Dahl" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Hyperion.Challenge_ManufacturerRewardReward_Hyperion_C", name: "Manufacturer Reward Reward Hyperion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Jakobs.Challenge_ManufacturerRewardReward_Jakobs_C", name: "Manufacturer Reward Reward Jakobs" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Maliwan.Challenge_ManufacturerRewardReward_Maliwan_C", name: "Manufacturer Reward Reward Maliwan" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Tediore.Challenge_ManufacturerRewardReward_Tediore_C", name: "Manufacturer Reward Reward Tediore" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Torgue.Challenge_ManufacturerRewardReward_Torgue_C", name: "Manufacturer Reward Reward Torgue" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Manufacturer/ManufacturerRewards/Challenge_ManufacturerRewardReward_Vladof.Challenge_ManufacturerRewardReward_Vladof_C", name: "Manufacturer Reward Reward Vladof" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial1/Challenge_Map_ProvingGrounds_Trial1_1.Challenge_Map_ProvingGrounds_Trial1_1_C", name: "Map Proving Grounds Trial 1 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial1/Challenge_Map_ProvingGrounds_Trial1_2.Challenge_Map_ProvingGrounds_Trial1_2_C", name: "Map Proving Grounds Trial 1 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial1/Challenge_Map_ProvingGrounds_Trial1_3.Challenge_Map_ProvingGrounds_Trial1_3_C", name: "Map Proving Grounds Trial 1 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial4/Challenge_Map_ProvingGrounds_Trial4_1.Challenge_Map_ProvingGrounds_Trial4_1_C", name: "Map Proving Grounds Trial 4 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial4/Challenge_Map_ProvingGrounds_Trial4_2.Challenge_Map_ProvingGrounds_Trial4_2_C", name: "Map Proving Grounds Trial 4 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial4/Challenge_Map_ProvingGrounds_Trial4_3.Challenge_Map_ProvingGrounds_Trial4_3_C", name: "Map Proving Grounds Trial 4 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial5/Challenge_Map_ProvingGrounds_Trial5_1.Challenge_Map_ProvingGrounds_Trial5_1_C", name: "Map Proving Grounds Trial 5 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial5/Challenge_Map_ProvingGrounds_Trial5_2.Challenge_Map_ProvingGrounds_Trial5_2_C", name: "Map Proving Grounds Trial 5 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial5/Challenge_Map_ProvingGrounds_Trial5_3.Challenge_Map_ProvingGrounds_Trial5_3_C", name: "Map Proving Grounds Trial 5 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial6/Challenge_Map_ProvingGrounds_Trial6_1.Challenge_Map_ProvingGrounds_Trial6_1_C", name: "Map Proving Grounds Trial 6 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial6/Challenge_Map_ProvingGrounds_Trial6_2.Challenge_Map_ProvingGrounds_Trial6_2_C", name: "Map Proving Grounds Trial 6 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial6/Challenge_Map_ProvingGrounds_Trial6_3.Challenge_Map_ProvingGrounds_Trial6_3_C", name: "Map Proving Grounds Trial 6 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial7/Challenge_Map_ProvingGrounds_Trial7_1.Challenge_Map_ProvingGrounds_Trial7_1_C", name: "Map Proving Grounds Trial 7 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial7/Challenge_Map_ProvingGrounds_Trial7_2.Challenge_Map_ProvingGrounds_Trial7_2_C", name: "Map Proving Grounds Trial 7 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial7/Challenge_Map_ProvingGrounds_Trial7_3.Challenge_Map_ProvingGrounds_Trial7_3_C", name: "Map Proving Grounds Trial 7 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial8/Challenge_Map_ProvingGrounds_Trial8_1.Challenge_Map_ProvingGrounds_Trial8_1_C", name: "Map Proving Grounds Trial 8 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial8/Challenge_Map_ProvingGrounds_Trial8_2.Challenge_Map_ProvingGrounds_Trial8_2_C", name: "Map Proving Grounds Trial 8 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Map/ProvingGrounds/Trial8/Challenge_Map_ProvingGrounds_Trial8_3.Challenge_Map_ProvingGrounds_Trial8_3_C", name: "Map Proving Grounds Trial 8 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Challenge_Mission_OptionalObjectives.Challenge_Mission_OptionalObjectives_C", name: "Mission Optional Objectives" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Challenge_Mission_SideMissionsCompleted_Main.Challenge_Mission_SideMissionsCompleted_Main_C", name: "Mission Side Missions Completed Main" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_01_ChildrenOfTheVault.Challenge_Mission_Plot_01_ChildrenOfTheVault_C", name: "Mission Plot 01 Children Of The Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_02_Sacrifice.Challenge_Mission_Plot_02_Sacrifice_C", name: "Mission Plot 02 Sacrifice" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_03_GetVaultMap.Challenge_Mission_Plot_03_GetVaultMap_C", name: "Mission Plot 03 Get Vault Map" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_04_EarnSpaceship.Challenge_Mission_Plot_04_EarnSpaceship_C", name: "Mission Plot 04 Earn Spaceship" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_05_TakingSanctuary.Challenge_Mission_Plot_05_TakingSanctuary_C", name: "Mission Plot 05 Taking Sanctuary" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_06_OvercomeHQBlockade.Challenge_Mission_Plot_06_OvercomeHQBlockade_C", name: "Mission Plot 06 Overcome HQ Blockade" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_07_MeetMaya.Challenge_Mission_Plot_07_MeetMaya_C", name: "Mission Plot 07 Meet Maya" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_08_OrbitalPlatform.Challenge_Mission_Plot_08_OrbitalPlatform_C", name: "Mission Plot 08 Orbital Platform" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_09_AtlasHQ.Challenge_Mission_Plot_09_AtlasHQ_C", name: "Mission Plot 09 Atlas HQ" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_10_CityVault.Challenge_Mission_Plot_10_CityVault_C", name: "Mission Plot 10 City Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_11_PrisonBreak.Challenge_Mission_Plot_11_PrisonBreak_C", name: "Mission Plot 11 Prison Break" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_12_GrandTour.Challenge_Mission_Plot_12_GrandTour_C", name: "Mission Plot 12 Grand Tour" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_13_JakobsRebellion.Challenge_Mission_Plot_13_JakobsRebellion_C", name: "Mission Plot 13 Jakobs Rebellion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_14_Watership.Challenge_Mission_Plot_14_Watership_C", name: "Mission Plot 14 Watership" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_15_Marshfields.Challenge_Mission_Plot_15_Marshfields_C", name: "Mission Plot 15 Marshfields" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_16_SiblingRivalry.Challenge_Mission_Plot_16_SiblingRivalry_C", name: "Mission Plot 16 Sibling Rivalry" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_17_BigChase.Challenge_Mission_Plot_17_BigChase_C", name: "Mission Plot 17 Big Chase" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_18_MinerDetails.Challenge_Mission_Plot_18_MinerDetails_C", name: "Mission Plot 18 Miner Details" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_19_DesertVault.Challenge_Mission_Plot_19_DesertVault_C", name: "Mission Plot 19 Desert Vault" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_20_FirstVaultHunter.Challenge_Mission_Plot_20_FirstVaultHunter_C", name: "Mission Plot 20 First Vault Hunter" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_21_Beachhead.Challenge_Mission_Plot_21_Beachhead_C", name: "Mission Plot 21 Beachhead" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_22_TheMachine.Challenge_Mission_Plot_22_TheMachine_C", name: "Mission Plot 22 The Machine" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_23_TyreenFinalBoss.Challenge_Mission_Plot_23_TyreenFinalBoss_C", name: "Mission Plot 23 Tyreen Final Boss" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGrounds01.Challenge_Mission_Side_ProvingGrounds01_C", name: "Mission Side Proving Grounds 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGrounds04.Challenge_Mission_Side_ProvingGrounds04_C", name: "Mission Side Proving Grounds 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGrounds05.Challenge_Mission_Side_ProvingGrounds05_C", name: "Mission Side Proving Grounds 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGrounds06.Challenge_Mission_Side_ProvingGrounds06_C", name: "Mission Side Proving Grounds 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGrounds07.Challenge_Mission_Side_ProvingGrounds07_C", name: "Mission Side Proving Grounds 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGrounds08.Challenge_Mission_Side_ProvingGrounds08_C", name: "Mission Side Proving Grounds 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGroundsDiscovery01.Challenge_Mission_Side_ProvingGroundsDiscovery01_C", name: "Mission Side Proving Grounds Discovery 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGroundsDiscovery04.Challenge_Mission_Side_ProvingGroundsDiscovery04_C", name: "Mission Side Proving Grounds Discovery 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGroundsDiscovery05.Challenge_Mission_Side_ProvingGroundsDiscovery05_C", name: "Mission Side Proving Grounds Discovery 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGroundsDiscovery06.Challenge_Mission_Side_ProvingGroundsDiscovery06_C", name: "Mission Side Proving Grounds Discovery 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGroundsDiscovery07.Challenge_Mission_Side_ProvingGroundsDiscovery07_C", name: "Mission Side Proving Grounds Discovery 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/ProvingGrounds/Challenge_Mission_Side_ProvingGroundsDiscovery08.Challenge_Mission_Side_ProvingGroundsDiscovery08_C", name: "Mission Side Proving Grounds Discovery 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_BormanNates.Challenge_Mission_Side_Rare_BormanNates_C", name: "Mission Side Rare Borman Nates" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_CaptainThunkAndSloth.Challenge_Mission_Side_Rare_CaptainThunkAndSloth_C", name: "Mission Side Rare Captain Thunk And Sloth" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_DemoSkag.Challenge_Mission_Side_Rare_DemoSkag_C", name: "Mission Side Rare Demo Skag" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_Dinklebot.Challenge_Mission_Side_Rare_Dinklebot_C", name: "Mission Side Rare Dinklebot" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_ElDragonJr.Challenge_Mission_Side_Rare_ElDragonJr_C", name: "Mission Side Rare El Dragon Jr" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_EnforcerUrist.Challenge_Mission_Side_Rare_EnforcerUrist_C", name: "Mission Side Rare Enforcer Urist" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_IndoTyrant.Challenge_Mission_Side_Rare_IndoTyrant_C", name: "Mission Side Rare Indo Tyrant" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_Maxitrillion.Challenge_Mission_Side_Rare_Maxitrillion_C", name: "Mission Side Rare Maxitrillion" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_PowerTroopers.Challenge_Mission_Side_Rare_PowerTroopers_C", name: "Mission Side Rare Power Troopers" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_Rakkman.Challenge_Mission_Side_Rare_Rakkman_C", name: "Mission Side Rare Rakkman" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_RedJabber.Challenge_Mission_Side_Rare_RedJabber_C", name: "Mission Side Rare Red Jabber" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_RoadDog.Challenge_Mission_Side_Rare_RoadDog_C", name: "Mission Side Rare Road Dog" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_TheUnstoppable.Challenge_Mission_Side_Rare_TheUnstoppable_C", name: "Mission Side Rare The Unstoppable" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_ThreeDragons.Challenge_Mission_Side_Rare_ThreeDragons_C", name: "Mission Side Rare Three Dragons" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_Rare_VicAndWarty.Challenge_Mission_Side_Rare_VicAndWarty_C", name: "Mission Side Rare Vic And Warty" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Sanctuary3/Challenge_Mission_Side_Sanc3_Infestation.Challenge_Mission_Side_Sanc3_Infestation_C", name: "Mission Side Sanc 3 Infestation" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Sanctuary3/Challenge_Mission_Side_Sanc3_JustAPrick.Challenge_Mission_Side_Sanc3_JustAPrick_C", name: "Mission Side Sanc 3 Just A Prick" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Slaughters/Challenge_Mission_Side_CoVSlaughter.Challenge_Mission_Side_CoVSlaughter_C", name: "Mission Side Co V Slaughter" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Slaughters/Challenge_Mission_Side_CreatureSlaughter.Challenge_Mission_Side_CreatureSlaughter_C", name: "Mission Side Creature Slaughter" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Slaughters/Challenge_Mission_Side_TechSlaughter.Challenge_Mission_Side_TechSlaughter_C", name: "Mission Side Tech Slaughter" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Prologue_BadReception.Challenge_Mission_Side_Prologue_BadReception_C", name: "Mission Side Prologue Bad Reception" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Prologue_Scoopers_Bully.Challenge_Mission_Side_Prologue_Scoopers_Bully_C", name: "Mission Side Prologue Scoopers Bully" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Prologue_SkagDogDays.Challenge_Mission_Side_Prologue_SkagDogDays_C", name: "Mission Side Prologue Skag Dog Days" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Prologue_UnderwearTink.Challenge_Mission_Side_Prologue_UnderwearTink_C", name: "Mission Side Prologue Underwear Tink" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Prologue_VendingMachineRepair.Challenge_Mission_Side_Prologue_VendingMachineRepair_C", name: "Mission Side Prologue Vending Machine Repair" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Sacrifice_GoldenCalves.Challenge_Mission_Side_Sacrifice_GoldenCalves_C", name: "Mission Side Sacrifice Golden Calves" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Sacrifice_HeadCase.Challenge_Mission_Side_Sacrifice_HeadCase_C", name: "Mission Side Sacrifice Head Case" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_0/Challenge_Mission_Side_Sacrifice_PandorasNextTopMouthpiece.Challenge_Mission_Side_Sacrifice_PandorasNextTopMouthpiece_C", name: "Mission Side Sacrifice Pandoras Next Top Mouthpiece" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_AtlasHQ_RatchetItUp.Challenge_Mission_Side_AtlasHQ_RatchetItUp_C", name: "Mission Side Atlas HQ Ratchet It Up" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_City_BitterPillToSwallow.Challenge_Mission_Side_City_BitterPillToSwallow_C", name: "Mission Side City Bitter Pill To Swallow" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_City_DynastyDiner.Challenge_Mission_Side_City_DynastyDiner_C", name: "Mission Side City Dynasty Diner" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_City_PissingContest.Challenge_Mission_Side_City_PissingContest_C", name: "Mission Side City Pissing Contest" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_City_RiseAndGrind.Challenge_Mission_Side_City_RiseAndGrind_C", name: "Mission Side City Rise And Grind" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_City_WizardOfNogs.Challenge_Mission_Side_City_WizardOfNogs_C", name: "Mission Side City Wizard Of Nogs" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_Monastery_DirtySecrets.Challenge_Mission_Side_Monastery_DirtySecrets_C", name: "Mission Side Monastery Dirty Secrets" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_Monastery_InvasionOfPrivacy.Challenge_Mission_Side_Monastery_InvasionOfPrivacy_C", name: "Mission Side Monastery Invasion Of Privacy" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_OrbitalPlatform_OppositionResearch.Challenge_Mission_Side_OrbitalPlatform_OppositionResearch_C", name: "Mission Side Orbital Platform Opposition Research" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_Towers_KillKillavolt.Challenge_Mission_Side_Towers_KillKillavolt_C", name: "Mission Side Towers Kill Killavolt" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_Towers_LastKatagawa.Challenge_Mission_Side_Towers_LastKatagawa_C", name: "Mission Side Towers Last Katagawa" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_1/Challenge_Mission_Side_Towers_Porta-Prison.Challenge_Mission_Side_Towers_Porta-Prison_C", name: "Mission Side Towers Porta-Prison" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Mansion_Sacked.Challenge_Mission_Side_Mansion_Sacked_C", name: "Mission Side Mansion Sacked" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Mansion_WitchesBrew.Challenge_Mission_Side_Mansion_WitchesBrew_C", name: "Mission Side Mansion Witches Brew" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Marshfields_RockOnPandora.Challenge_Mission_Side_Marshfields_RockOnPandora_C", name: "Mission Side Marshfields Rock On Pandora" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Marshfields_SuckerPunch.Challenge_Mission_Side_Marshfields_SuckerPunch_C", name: "Mission Side Marshfields Sucker Punch" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Prison_FreeHolder.Challenge_Mission_Side_Prison_FreeHolder_C", name: "Mission Side Prison Free Holder" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Prison_MalevolentPractice.Challenge_Mission_Side_Prison_MalevolentPractice_C", name: "Mission Side Prison Malevolent Practice" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Watership_RumbleJungle.Challenge_Mission_Side_Watership_RumbleJungle_C", name: "Mission Side Watership Rumble Jungle" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Wetlands_DriveAwayThePain.Challenge_Mission_Side_Wetlands_DriveAwayThePain_C", name: "Mission Side Wetlands Drive Away The Pain" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Wetlands_DudeBro.Challenge_Mission_Side_Wetlands_DudeBro_C", name: "Mission Side Wetlands Dude Bro" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Wetlands_DynastyDash.Challenge_Mission_Side_Wetlands_DynastyDash_C", name: "Mission Side Wetlands Dynasty Dash" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Wetlands_RidingHigh.Challenge_Mission_Side_Wetlands_RidingHigh_C", name: "Mission Side Wetlands Riding High" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Wetlands_SpecialDelivery.Challenge_Mission_Side_Wetlands_SpecialDelivery_C", name: "Mission Side Wetlands Special Delivery" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_2/Challenge_Mission_Side_Wetlands_TheHangover.Challenge_Mission_Side_Wetlands_TheHangover_C", name: "Mission Side Wetlands The Hangover" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_BabyDancer.Challenge_Mission_Side_Desert_BabyDancer_C", name: "Mission Side Desert Baby Dancer" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_BirthdaySurprise.Challenge_Mission_Side_Desert_BirthdaySurprise_C", name: "Mission Side Desert Birthday Surprise" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_BuffFilmBuff.Challenge_Mission_Side_Desert_BuffFilmBuff_C", name: "Mission Side Desert Buff Film Buff" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_CaringForElderly.Challenge_Mission_Side_Desert_CaringForElderly_C", name: "Mission Side Desert Caring For Elderly" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_ChangeOfHeart.Challenge_Mission_Side_Desert_ChangeOfHeart_C", name: "Mission Side Desert Change Of Heart" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_DynastyDash_Desert.Challenge_Mission_Side_Desert_DynastyDash_Desert_C", name: "Mission Side Desert Dynasty Dash Desert" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_EchoNetNeutrality.Challenge_Mission_Side_Desert_EchoNetNeutrality_C", name: "Mission Side Desert Echo Net Neutrality" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Desert_ItsComplicated.Challenge_Mission_Side_Desert_ItsComplicated_C", name: "Mission Side Desert Its Complicated" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Mine_BridgeInTheDark.Challenge_Mission_Side_Mine_BridgeInTheDark_C", name: "Mission Side Mine Bridge In The Dark" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Mine_GrowingPains.Challenge_Mission_Side_Mine_GrowingPains_C", name: "Mission Side Mine Growing Pains" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Mine_WildlifeConservation.Challenge_Mission_Side_Mine_WildlifeConservation_C", name: "Mission Side Mine Wildlife Conservation" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Motorcade_Gameshow.Challenge_Mission_Side_Motorcade_Gameshow_C", name: "Mission Side Motorcade Gameshow" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Motorcade_Homestead_Part1.Challenge_Mission_Side_Motorcade_Homestead_Part1_C", name: "Mission Side Motorcade Homestead Part 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Motorcade_Homestead_Part2.Challenge_Mission_Side_Motorcade_Homestead_Part2_C", name: "Mission Side Motorcade Homestead Part 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Motorcade_Homestead_Part3.Challenge_Mission_Side_Motorcade_Homestead_Part3_C", name: "Mission Side Motorcade Homestead Part 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_3/Challenge_Mission_Side_Motorcade_Just_Desserts.Challenge_Mission_Side_Motorcade_Just_Desserts_C", name: "Mission Side Motorcade Just Desserts" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_4/Challenge_Mission_Side_Desolate_BetterTimes.Challenge_Mission_Side_Desolate_BetterTimes_C", name: "Mission Side Desolate Better Times" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_4/Challenge_Mission_Side_Desolate_BoneDeep.Challenge_Mission_Side_Desolate_BoneDeep_C", name: "Mission Side Desolate Bone Deep" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_4/Challenge_Mission_Side_Desolate_Cannonization.Challenge_Mission_Side_Desolate_Cannonization_C", name: "Mission Side Desolate Cannonization" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_4/Challenge_Mission_Side_Desolate_DestroyerOfWorlds.Challenge_Mission_Side_Desolate_DestroyerOfWorlds_C", name: "Mission Side Desolate Destroyer Of Worlds" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_4/Challenge_Mission_Side_Desolate_Homeopathological.Challenge_Mission_Side_Desolate_Homeopathological_C", name: "Mission Side Desolate Homeopathological" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Side/Zone_4/Challenge_Mission_Side_Desolate_ItsAlive.Challenge_Mission_Side_Desolate_ItsAlive_C", name: "Mission Side Desolate Its Alive" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Unique/Challenge_BeachCubeSolved.Challenge_BeachCubeSolved_C", name: "Beach Cube Solved" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Missions/Unique/Challenge_MIssion_SuckerPunch_Suicide.Challenge_MIssion_SuckerPunch_Suicide_C", name: "M Ission Sucker Punch Suicide" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_AtlasHQ1.Challenge_RedChests_AtlasHQ1_C", name: "Red Chests Atlas HQ 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_AtlasHQ2.Challenge_RedChests_AtlasHQ2_C", name: "Red Chests Atlas HQ 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_AtlasHQ3.Challenge_RedChests_AtlasHQ3_C", name: "Red Chests Atlas HQ 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Beach1.Challenge_RedChests_Beach1_C", name: "Red Chests Beach 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Beach2.Challenge_RedChests_Beach2_C", name: "Red Chests Beach 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_City1.Challenge_RedChests_City1_C", name: "Red Chests City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_City2.Challenge_RedChests_City2_C", name: "Red Chests City 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_City3.Challenge_RedChests_City3_C", name: "Red Chests City 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_CityBoss1.Challenge_RedChests_CityBoss1_C", name: "Red Chests City Boss 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_CityBoss2.Challenge_RedChests_CityBoss2_C", name: "Red Chests City Boss 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_CityVault1.Challenge_RedChests_CityVault1_C", name: "Red Chests City Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_CityVault2.Challenge_RedChests_CityVault2_C", name: "Red Chests City Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Convoy1.Challenge_RedChests_Convoy1_C", name: "Red Chests Convoy 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Convoy2.Challenge_RedChests_Convoy2_C", name: "Red Chests Convoy 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Crypt1.Challenge_RedChests_Crypt1_C", name: "Red Chests Crypt 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Crypt2.Challenge_RedChests_Crypt2_C", name: "Red Chests Crypt 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Desert1.Challenge_RedChests_Desert1_C", name: "Red Chests Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Desert2.Challenge_RedChests_Desert2_C", name: "Red Chests Desert 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_DesertVault1.Challenge_RedChests_DesertVault1_C", name: "Red Chests Desert Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_DesertVault2.Challenge_RedChests_DesertVault2_C", name: "Red Chests Desert Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Desolate1.Challenge_RedChests_Desolate1_C", name: "Red Chests Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Desolate2.Challenge_RedChests_Desolate2_C", name: "Red Chests Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Desolate3.Challenge_RedChests_Desolate3_C", name: "Red Chests Desolate 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_FinalBoss1.Challenge_RedChests_FinalBoss1_C", name: "Red Chests Final Boss 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_FinalBoss2.Challenge_RedChests_FinalBoss2_C", name: "Red Chests Final Boss 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Mansion1.Challenge_RedChests_Mansion1_C", name: "Red Chests Mansion 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Mansion2.Challenge_RedChests_Mansion2_C", name: "Red Chests Mansion 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Marshfields1.Challenge_RedChests_Marshfields1_C", name: "Red Chests Marshfields 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Marshfields2.Challenge_RedChests_Marshfields2_C", name: "Red Chests Marshfields 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Marshfields3.Challenge_RedChests_Marshfields3_C", name: "Red Chests Marshfields 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Mine1.Challenge_RedChests_Mine1_C", name: "Red Chests Mine 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Mine2.Challenge_RedChests_Mine2_C", name: "Red Chests Mine 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Monastery1.Challenge_RedChests_Monastery1_C", name: "Red Chests Monastery 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Monastery2.Challenge_RedChests_Monastery2_C", name: "Red Chests Monastery 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Motorcade1.Challenge_RedChests_Motorcade1_C", name: "Red Chests Motorcade 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Motorcade2.Challenge_RedChests_Motorcade2_C", name: "Red Chests Motorcade 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_MotorcadeFestival1.Challenge_RedChests_MotorcadeFestival1_C", name: "Red Chests Motorcade Festival 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_MotorcadeFestival2.Challenge_RedChests_MotorcadeFestival2_C", name: "Red Chests Motorcade Festival 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_MotorcadeInterior1.Challenge_RedChests_MotorcadeInterior1_C", name: "Red Chests Motorcade Interior 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_MotorcadeInterior2.Challenge_RedChests_MotorcadeInterior2_C", name: "Red Chests Motorcade Interior 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_OrbitalPlatform1.Challenge_RedChests_OrbitalPlatform1_C", name: "Red Chests Orbital Platform 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_OrbitalPlatform2.Challenge_RedChests_OrbitalPlatform2_C", name: "Red Chests Orbital Platform 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Outskirts1.Challenge_RedChests_Outskirts1_C", name: "Red Chests Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Prison1.Challenge_RedChests_Prison1_C", name: "Red Chests Prison 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Prison2.Challenge_RedChests_Prison2_C", name: "Red Chests Prison 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Prison3.Challenge_RedChests_Prison3_C", name: "Red Chests Prison 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Prologue1.Challenge_RedChests_Prologue1_C", name: "Red Chests Prologue 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Prologue2.Challenge_RedChests_Prologue2_C", name: "Red Chests Prologue 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Prologue3.Challenge_RedChests_Prologue3_C", name: "Red Chests Prologue 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Sacrifice1.Challenge_RedChests_Sacrifice1_C", name: "Red Chests Sacrifice 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Sacrifice2.Challenge_RedChests_Sacrifice2_C", name: "Red Chests Sacrifice 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Towers1.Challenge_RedChests_Towers1_C", name: "Red Chests Towers 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Towers2.Challenge_RedChests_Towers2_C", name: "Red Chests Towers 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Towers3.Challenge_RedChests_Towers3_C", name: "Red Chests Towers 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Watership1.Challenge_RedChests_Watership1_C", name: "Red Chests Watership 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Watership2.Challenge_RedChests_Watership2_C", name: "Red Chests Watership 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Watership3.Challenge_RedChests_Watership3_C", name: "Red Chests Watership 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Wetlands1.Challenge_RedChests_Wetlands1_C", name: "Red Chests Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Wetlands2.Challenge_RedChests_Wetlands2_C", name: "Red Chests Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_Wetlands3.Challenge_RedChests_Wetlands3_C", name: "Red Chests Wetlands 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_WetlandsVault1.Challenge_RedChests_WetlandsVault1_C", name: "Red Chests Wetlands Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/RedChests/Challenge_RedChests_WetlandsVault2.Challenge_RedChests_WetlandsVault2_C", name: "Red Chests Wetlands Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_AmmoAbsorbed.Challenge_Shield_AmmoAbsorbed_C", name: "Shield Ammo Absorbed" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_AmpKills.Challenge_Shield_AmpKills_C", name: "Shield Amp Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_BoostersPickedUp.Challenge_Shield_BoostersPickedUp_C", name: "Shield Boosters Picked Up" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_DamageAbsorbed.Challenge_Shield_DamageAbsorbed_C", name: "Shield Damage Absorbed" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_Kills.Challenge_Shield_Kills_C", name: "Shield Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_RoidKills.Challenge_Shield_RoidKills_C", name: "Shield Roid Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_TypeKill_Nova.Challenge_Shield_TypeKill_Nova_C", name: "Shield Type Kill Nova" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Shield/Challenge_Shield_TypeKill_Spike.Challenge_Shield_TypeKill_Spike_C", name: "Shield Type Kill Spike" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_1.BP_Challenge_Console_1_C", name: "Console 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_10.BP_Challenge_Console_10_C", name: "Console 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_11.BP_Challenge_Console_11_C", name: "Console 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_12.BP_Challenge_Console_12_C", name: "Console 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_13.BP_Challenge_Console_13_C", name: "Console 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_14.BP_Challenge_Console_14_C", name: "Console 14" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_15.BP_Challenge_Console_15_C", name: "Console 15" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_16.BP_Challenge_Console_16_C", name: "Console 16" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_17.BP_Challenge_Console_17_C", name: "Console 17" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_18.BP_Challenge_Console_18_C", name: "Console 18" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_19.BP_Challenge_Console_19_C", name: "Console 19" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_2.BP_Challenge_Console_2_C", name: "Console 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_20.BP_Challenge_Console_20_C", name: "Console 20" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_21.BP_Challenge_Console_21_C", name: "Console 21" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_22.BP_Challenge_Console_22_C", name: "Console 22" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_23.BP_Challenge_Console_23_C", name: "Console 23" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_24.BP_Challenge_Console_24_C", name: "Console 24" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_25.BP_Challenge_Console_25_C", name: "Console 25" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_26.BP_Challenge_Console_26_C", name: "Console 26" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_27.BP_Challenge_Console_27_C", name: "Console 27" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_28.BP_Challenge_Console_28_C", name: "Console 28" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_29.BP_Challenge_Console_29_C", name: "Console 29" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_3.BP_Challenge_Console_3_C", name: "Console 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_31.BP_Challenge_Console_31_C", name: "Console 31" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_32.BP_Challenge_Console_32_C", name: "Console 32" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_33.BP_Challenge_Console_33_C", name: "Console 33" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_34.BP_Challenge_Console_34_C", name: "Console 34" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_35.BP_Challenge_Console_35_C", name: "Console 35" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_36.BP_Challenge_Console_36_C", name: "Console 36" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_37.BP_Challenge_Console_37_C", name: "Console 37" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_38.BP_Challenge_Console_38_C", name: "Console 38" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_39.BP_Challenge_Console_39_C", name: "Console 39" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_4.BP_Challenge_Console_4_C", name: "Console 4" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_40.BP_Challenge_Console_40_C", name: "Console 40" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_41.BP_Challenge_Console_41_C", name: "Console 41" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_42.BP_Challenge_Console_42_C", name: "Console 42" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_43.BP_Challenge_Console_43_C", name: "Console 43" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_44.BP_Challenge_Console_44_C", name: "Console 44" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_45.BP_Challenge_Console_45_C", name: "Console 45" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_5.BP_Challenge_Console_5_C", name: "Console 5" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_6.BP_Challenge_Console_6_C", name: "Console 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_8.BP_Challenge_Console_8_C", name: "Console 8" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_9.BP_Challenge_Console_9_C", name: "Console 9" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_Backup_ManufacturerMail.BP_Challenge_Console_Backup_ManufacturerMail_C", name: "Console Backup Manufacturer Mail" },
+    GameDataKv { ident: "/Game/GameData/Challenges/System/BP_Challenge_Console_Backup_MoxxiTip.BP_Challenge_Console_Backup_MoxxiTip_C", name: "Console Backup Moxxi Tip" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Vehicle/Challenge_Vehicle_KillsWith_Ramming.Challenge_Vehicle_KillsWith_Ramming_C", name: "Vehicle Kills With Ramming" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Vehicle/Challenge_Vehicle_KillsWith_VehicleWeapons.Challenge_Vehicle_KillsWith_VehicleWeapons_C", name: "Vehicle Kills With Vehicle Weapons" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Vehicle/Challenge_VehicleKill_Outrunner.Challenge_VehicleKill_Outrunner_C", name: "Vehicle Kill Outrunner" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Vehicle/Challenge_VehicleKill_Revolver.Challenge_VehicleKill_Revolver_C", name: "Vehicle Kill Revolver" },
+    GameDataKv { ident: "/Game/GameData/Challenges/Vehicle/Challenge_VehicleKill_Technical.Challenge_VehicleKill_Technical_C", name: "Vehicle Kill Technical" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/AssaultRifle/Challenge_Weapon_AssaultRifle_CritHits.Challenge_Weapon_AssaultRifle_CritHits_C", name: "Weapon Assault Rifle Crit Hits" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/AssaultRifle/Challenge_Weapon_AssaultRifle_CritKills.Challenge_Weapon_AssaultRifle_CritKills_C", name: "Weapon Assault Rifle Crit Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/AssaultRifle/Challenge_Weapon_AssaultRifle_CrouchKills.Challenge_Weapon_AssaultRifle_CrouchKills_C", name: "Weapon Assault Rifle Crouch Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/AssaultRifle/Challenge_Weapon_AssaultRifle_Kills.Challenge_Weapon_AssaultRifle_Kills_C", name: "Weapon Assault Rifle Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/AssaultRifle/Challenge_Weapon_AssaultRifle_ScopeKills.Challenge_Weapon_AssaultRifle_ScopeKills_C", name: "Weapon Assault Rifle Scope Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/AssaultRifle/Challenge_Weapon_AssualtRifle_SecondWind.Challenge_Weapon_AssualtRifle_SecondWind_C", name: "Weapon Assualt Rifle Second Wind" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Heavy/Challenge_Weapon_Heavy_DirectKills.Challenge_Weapon_Heavy_DirectKills_C", name: "Weapon Heavy Direct Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Heavy/Challenge_Weapon_Heavy_FullShieldKills.Challenge_Weapon_Heavy_FullShieldKills_C", name: "Weapon Heavy Full Shield Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Heavy/Challenge_Weapon_Heavy_IndirectKills.Challenge_Weapon_Heavy_IndirectKills_C", name: "Weapon Heavy Indirect Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Heavy/Challenge_Weapon_Heavy_Kills.Challenge_Weapon_Heavy_Kills_C", name: "Weapon Heavy Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Heavy/Challenge_Weapon_Heavy_LongRange.Challenge_Weapon_Heavy_LongRange_C", name: "Weapon Heavy Long Range" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Heavy/Challenge_Weapon_HeavyWeapon_SecondWind.Challenge_Weapon_HeavyWeapon_SecondWind_C", name: "Weapon Heavy Weapon Second Wind" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Melee/Challenge_Melee_BladedKills.Challenge_Melee_BladedKills_C", name: "Melee Bladed Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Melee/Challenge_Melee_GroundSlamKills.Challenge_Melee_GroundSlamKills_C", name: "Melee Ground Slam Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Melee/Challenge_Melee_Kills.Challenge_Melee_Kills_C", name: "Melee Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Pistol/Challenge_Weapon_Pistol_CritHits.Challenge_Weapon_Pistol_CritHits_C", name: "Weapon Pistol Crit Hits" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Pistol/Challenge_Weapon_Pistol_CritKills.Challenge_Weapon_Pistol_CritKills_C", name: "Weapon Pistol Crit Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Pistol/Challenge_Weapon_Pistol_Kills.Challenge_Weapon_Pistol_Kills_C", name: "Weapon Pistol Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Pistol/Challenge_Weapon_Pistol_SecondWind.Challenge_Weapon_Pistol_SecondWind_C", name: "Weapon Pistol Second Wind" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Pistol/Challenge_Weapon_Pistol_ZoomedKills.Challenge_Weapon_Pistol_ZoomedKills_C", name: "Weapon Pistol Zoomed Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Shotgun/Challenge_Weapon_Shotgun_CloseRange.Challenge_Weapon_Shotgun_CloseRange_C", name: "Weapon Shotgun Close Range" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Shotgun/Challenge_Weapon_Shotgun_CritHits.Challenge_Weapon_Shotgun_CritHits_C", name: "Weapon Shotgun Crit Hits" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Shotgun/Challenge_Weapon_Shotgun_CritKills.Challenge_Weapon_Shotgun_CritKills_C", name: "Weapon Shotgun Crit Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Shotgun/Challenge_Weapon_Shotgun_Kills.Challenge_Weapon_Shotgun_Kills_C", name: "Weapon Shotgun Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Shotgun/Challenge_Weapon_Shotgun_LongRange.Challenge_Weapon_Shotgun_LongRange_C", name: "Weapon Shotgun Long Range" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Shotgun/Challenge_Weapon_Shotgun_SecondWind.Challenge_Weapon_Shotgun_SecondWind_C", name: "Weapon Shotgun Second Wind" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Shotgun/Challenge_Weapon_Shotgun_SlidingKills.Challenge_Weapon_Shotgun_SlidingKills_C", name: "Weapon Shotgun Sliding Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SMG/Challenge_Weapon_SMG_CritHits.Challenge_Weapon_SMG_CritHits_C", name: "Weapon SMG Crit Hits" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SMG/Challenge_Weapon_SMG_CritKills.Challenge_Weapon_SMG_CritKills_C", name: "Weapon SMG Crit Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SMG/Challenge_Weapon_SMG_Kills.Challenge_Weapon_SMG_Kills_C", name: "Weapon SMG Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SMG/Challenge_Weapon_SMG_MovingKills.Challenge_Weapon_SMG_MovingKills_C", name: "Weapon SMG Moving Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SMG/Challenge_Weapon_SMG_SecondWindKill.Challenge_Weapon_SMG_SecondWindKill_C", name: "Weapon SMG Second Wind Kill" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SMG/Challenge_Weapon_SMG_SlidingKills.Challenge_Weapon_SMG_SlidingKills_C", name: "Weapon SMG Sliding Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_Sniper_FullShieldKills.Challenge_Weapon_Sniper_FullShieldKills_C", name: "Weapon Sniper Full Shield Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_CloseRangeKills.Challenge_Weapon_SniperRifle_CloseRangeKills_C", name: "Weapon Sniper Rifle Close Range Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_CritHits.Challenge_Weapon_SniperRifle_CritHits_C", name: "Weapon Sniper Rifle Crit Hits" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_CritKills.Challenge_Weapon_SniperRifle_CritKills_C", name: "Weapon Sniper Rifle Crit Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_Kills.Challenge_Weapon_SniperRifle_Kills_C", name: "Weapon Sniper Rifle Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_LongRangeKills.Challenge_Weapon_SniperRifle_LongRangeKills_C", name: "Weapon Sniper Rifle Long Range Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_NoScopeKills.Challenge_Weapon_SniperRifle_NoScopeKills_C", name: "Weapon Sniper Rifle No Scope Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_SecondWind.Challenge_Weapon_SniperRifle_SecondWind_C", name: "Weapon Sniper Rifle Second Wind" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/SniperRifle/Challenge_Weapon_SniperRifle_UnawareKills.Challenge_Weapon_SniperRifle_UnawareKills_C", name: "Weapon Sniper Rifle Unaware Kills" },
+    GameDataKv { ident: "/Game/GameData/Challenges/WeaponChallenges/Unique/Challenge_Weapon_FabricatorKill.Challenge_Weapon_FabricatorKill_C", name: "Weapon Fabricator Kill" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_AllComplete.ALI_Challenge_Crew_AllComplete_C", name: "Crew All Complete" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Anger1.ALI_Challenge_Crew_Anger1_C", name: "Crew Anger 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Anger2.ALI_Challenge_Crew_Anger2_C", name: "Crew Anger 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Anger3.ALI_Challenge_Crew_Anger3_C", name: "Crew Anger 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Chase1.ALI_Challenge_Crew_Chase1_C", name: "Crew Chase 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Chase2.ALI_Challenge_Crew_Chase2_C", name: "Crew Chase 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Chase3.ALI_Challenge_Crew_Chase3_C", name: "Crew Chase 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Experiment1.ALI_Challenge_Crew_Experiment1_C", name: "Crew Experiment 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Experiment2.ALI_Challenge_Crew_Experiment2_C", name: "Crew Experiment 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/CrewChallenges/ALI_Challenge_Crew_Experiment3.ALI_Challenge_Crew_Experiment3_C", name: "Crew Experiment 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Anger/Challenge_Discovery_Anger_Battlefield.Challenge_Discovery_Anger_Battlefield_C", name: "Discovery Anger Battlefield" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Anger/Challenge_Discovery_Anger_BloodBasin.Challenge_Discovery_Anger_BloodBasin_C", name: "Discovery Anger Blood Basin" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Anger/Challenge_Discovery_Anger_Castle.Challenge_Discovery_Anger_Castle_C", name: "Discovery Anger Castle" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Anger/Challenge_Discovery_Anger_EscherStairs.Challenge_Discovery_Anger_EscherStairs_C", name: "Discovery Anger Escher Stairs" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Anger/Challenge_Discovery_Anger_Meatscape.Challenge_Discovery_Anger_Meatscape_C", name: "Discovery Anger Meatscape" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Anger/Challenge_Discovery_Anger_TopOfTheSpire.Challenge_Discovery_Anger_TopOfTheSpire_C", name: "Discovery Anger Top Of The Spire" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_Anomaly.Challenge_Discovery_Chase_Anomaly_C", name: "Discovery Chase Anomaly" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_Chessboard.Challenge_Discovery_Chase_Chessboard_C", name: "Discovery Chase Chessboard" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_Cliff.Challenge_Discovery_Chase_Cliff_C", name: "Discovery Chase Cliff" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_GolfCourse.Challenge_Discovery_Chase_GolfCourse_C", name: "Discovery Chase Golf Course" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_Hangar.Challenge_Discovery_Chase_Hangar_C", name: "Discovery Chase Hangar" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_Lair.Challenge_Discovery_Chase_Lair_C", name: "Discovery Chase Lair" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_Pandora.Challenge_Discovery_Chase_Pandora_C", name: "Discovery Chase Pandora" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_Rainbow.Challenge_Discovery_Chase_Rainbow_C", name: "Discovery Chase Rainbow" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Chase/Challenge_Discovery_Chase_TiltedStation.Challenge_Discovery_Chase_TiltedStation_C", name: "Discovery Chase Tilted Station" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Eldorado/Challenge_Discovery_Eldorado_Boss.Challenge_Discovery_Eldorado_Boss_C", name: "Discovery Eldorado Boss" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Eldorado/Challenge_Discovery_Eldorado_Intro.Challenge_Discovery_Eldorado_Intro_C", name: "Discovery Eldorado Intro" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Eldorado/Challenge_Discovery_Eldorado_LootRoom.Challenge_Discovery_Eldorado_LootRoom_C", name: "Discovery Eldorado Loot Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_BossRoom.Challenge_Discovery_Experiment_BossRoom_C", name: "Discovery Experiment Boss Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_Courtyard.Challenge_Discovery_Experiment_Courtyard_C", name: "Discovery Experiment Courtyard" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_Lab.Challenge_Discovery_Experiment_Lab_C", name: "Discovery Experiment Lab" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_LabTwisted.Challenge_Discovery_Experiment_LabTwisted_C", name: "Discovery Experiment Lab Twisted" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_Landing.Challenge_Discovery_Experiment_Landing_C", name: "Discovery Experiment Landing" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_Lobby.Challenge_Discovery_Experiment_Lobby_C", name: "Discovery Experiment Lobby" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_MindChaos.Challenge_Discovery_Experiment_MindChaos_C", name: "Discovery Experiment Mind Chaos" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_PsychWard.Challenge_Discovery_Experiment_PsychWard_C", name: "Discovery Experiment Psych Ward" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_Sanitarium.Challenge_Discovery_Experiment_Sanitarium_C", name: "Discovery Experiment Sanitarium" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_SecretLair.Challenge_Discovery_Experiment_SecretLair_C", name: "Discovery Experiment Secret Lair" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Experiment/Challenge_Discovery_Experiment_SplitLane.Challenge_Discovery_Experiment_SplitLane_C", name: "Discovery Experiment Split Lane" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Sanctum/Challenge_Discovery_Sanctum_Sanctum.Challenge_Discovery_Sanctum_Sanctum_C", name: "Discovery Sanctum Sanctum" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Discovery/Sanctum/Challenge_Discovery_Sanctum_Screaming.Challenge_Discovery_Sanctum_Screaming_C", name: "Discovery Sanctum Screaming" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Anger1.Challenge_DLC4_EchoLog_Anger1_C", name: "DLC 4 Echo Log Anger 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Anger2.Challenge_DLC4_EchoLog_Anger2_C", name: "DLC 4 Echo Log Anger 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Anger3.Challenge_DLC4_EchoLog_Anger3_C", name: "DLC 4 Echo Log Anger 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Anger4.Challenge_DLC4_EchoLog_Anger4_C", name: "DLC 4 Echo Log Anger 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Anger5.Challenge_DLC4_EchoLog_Anger5_C", name: "DLC 4 Echo Log Anger 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Chase1.Challenge_DLC4_EchoLog_Chase1_C", name: "DLC 4 Echo Log Chase 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Chase2.Challenge_DLC4_EchoLog_Chase2_C", name: "DLC 4 Echo Log Chase 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Chase3.Challenge_DLC4_EchoLog_Chase3_C", name: "DLC 4 Echo Log Chase 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Chase4.Challenge_DLC4_EchoLog_Chase4_C", name: "DLC 4 Echo Log Chase 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Chase5.Challenge_DLC4_EchoLog_Chase5_C", name: "DLC 4 Echo Log Chase 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Chase6.Challenge_DLC4_EchoLog_Chase6_C", name: "DLC 4 Echo Log Chase 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Eldorado1.Challenge_DLC4_EchoLog_Eldorado1_C", name: "DLC 4 Echo Log Eldorado 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Eldorado2.Challenge_DLC4_EchoLog_Eldorado2_C", name: "DLC 4 Echo Log Eldorado 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Experiment1.Challenge_DLC4_EchoLog_Experiment1_C", name: "DLC 4 Echo Log Experiment 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Experiment2.Challenge_DLC4_EchoLog_Experiment2_C", name: "DLC 4 Echo Log Experiment 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Experiment3.Challenge_DLC4_EchoLog_Experiment3_C", name: "DLC 4 Echo Log Experiment 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Experiment4.Challenge_DLC4_EchoLog_Experiment4_C", name: "DLC 4 Echo Log Experiment 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Experiment5.Challenge_DLC4_EchoLog_Experiment5_C", name: "DLC 4 Echo Log Experiment 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Experiment6.Challenge_DLC4_EchoLog_Experiment6_C", name: "DLC 4 Echo Log Experiment 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Experiment7.Challenge_DLC4_EchoLog_Experiment7_C", name: "DLC 4 Echo Log Experiment 7" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Sanctum1.Challenge_DLC4_EchoLog_Sanctum1_C", name: "DLC 4 Echo Log Sanctum 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Sanctum2.Challenge_DLC4_EchoLog_Sanctum2_C", name: "DLC 4 Echo Log Sanctum 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/EchoLogs/Challenge_DLC4_EchoLog_Sanctum3.Challenge_DLC4_EchoLog_Sanctum3_C", name: "DLC 4 Echo Log Sanctum 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Enemy/Challenge_Enemy_ALI_HyperionOfficers.Challenge_Enemy_ALI_HyperionOfficers_C", name: "Enemy ALI Hyperion Officers" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Enemy/Challenge_Enemy_ALI_Psychos.Challenge_Enemy_ALI_Psychos_C", name: "Enemy ALI Psychos" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Enemy/Challenge_Enemy_BulletRider.Challenge_Enemy_BulletRider_C", name: "Enemy Bullet Rider" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Enemy/Challenge_Enemy_Chess.Challenge_Enemy_Chess_C", name: "Enemy Chess" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Enemy/Challenge_Enemy_Willow2.Challenge_Enemy_Willow2_C", name: "Enemy Willow 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Anger1.Challenge_FastTravel_Anger1_C", name: "Fast Travel Anger 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Anger2.Challenge_FastTravel_Anger2_C", name: "Fast Travel Anger 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Chase1.Challenge_FastTravel_Chase1_C", name: "Fast Travel Chase 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Eldorado1.Challenge_FastTravel_Eldorado1_C", name: "Fast Travel Eldorado 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Experiment1.Challenge_FastTravel_Experiment1_C", name: "Fast Travel Experiment 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Sanctum1.Challenge_FastTravel_Sanctum1_C", name: "Fast Travel Sanctum 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Plot/ALI_Challenge_Mission_Plot_EP01.ALI_Challenge_Mission_Plot_EP01_C", name: "Mission Plot EP 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Plot/ALI_Challenge_Mission_Plot_EP02.ALI_Challenge_Mission_Plot_EP02_C", name: "Mission Plot EP 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Plot/ALI_Challenge_Mission_Plot_EP03.ALI_Challenge_Mission_Plot_EP03_C", name: "Mission Plot EP 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Plot/ALI_Challenge_Mission_Plot_EP04.ALI_Challenge_Mission_Plot_EP04_C", name: "Mission Plot EP 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Plot/ALI_Challenge_Mission_Plot_EP05.ALI_Challenge_Mission_Plot_EP05_C", name: "Mission Plot EP 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_AllShapeAndCalibers.Ali_Challenge_Mission_Side_AllShapeAndCalibers_C", name: "Ali Challenge Mission Side All Shape And Calibers" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_BlastRequests.Ali_Challenge_Mission_Side_BlastRequests_C", name: "Ali Challenge Mission Side Blast Requests" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_ChecksAndBalances.Ali_Challenge_Mission_Side_ChecksAndBalances_C", name: "Ali Challenge Mission Side Checks And Balances" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_ExposureTherapy.Ali_Challenge_Mission_Side_ExposureTherapy_C", name: "Ali Challenge Mission Side Exposure Therapy" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_GoodbyeOldFriend.Ali_Challenge_Mission_Side_GoodbyeOldFriend_C", name: "Ali Challenge Mission Side Goodbye Old Friend" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_HotNBothered.Ali_Challenge_Mission_Side_HotNBothered_C", name: "Ali Challenge Mission Side Hot N Bothered" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_InkBlots.Ali_Challenge_Mission_Side_InkBlots_C", name: "Ali Challenge Mission Side Ink Blots" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_ParadeHarpoon.Ali_Challenge_Mission_Side_ParadeHarpoon_C", name: "Ali Challenge Mission Side Parade Harpoon" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_ShadesOfTruth.Ali_Challenge_Mission_Side_ShadesOfTruth_C", name: "Ali Challenge Mission Side Shades Of Truth" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_SpineTingler.Ali_Challenge_Mission_Side_SpineTingler_C", name: "Ali Challenge Mission Side Spine Tingler" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_StrongBones.Ali_Challenge_Mission_Side_StrongBones_C", name: "Ali Challenge Mission Side Strong Bones" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_ThatRingsABell.Ali_Challenge_Mission_Side_ThatRingsABell_C", name: "Ali Challenge Mission Side That Rings A Bell" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/Missions/Side/Ali_Challenge_Mission_Side_WhenItRains.Ali_Challenge_Mission_Side_WhenItRains_C", name: "Ali Challenge Mission Side When It Rains" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/RedChests/Experiment/Challenge_RedChests_Experiment1.Challenge_RedChests_Experiment1_C", name: "Red Chests Experiment 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/Challenges/RedChests/Experiment/Challenge_RedChests_Experiment2.Challenge_RedChests_Experiment2_C", name: "Red Chests Experiment 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/System/BP_Challenge_Console_69_DLC4.BP_Challenge_Console_69_DLC4_C", name: "Console 69 DLC 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/System/BP_Challenge_Console_70_DLC4.BP_Challenge_Console_70_DLC4_C", name: "Console 70 DLC 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/System/BP_Challenge_Console_71_DLC4.BP_Challenge_Console_71_DLC4_C", name: "Console 71 DLC 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/System/BP_Challenge_Console_72_DLC4.BP_Challenge_Console_72_DLC4_C", name: "Console 72 DLC 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/System/BP_Challenge_Console_73_DLC4.BP_Challenge_Console_73_DLC4_C", name: "Console 73 DLC 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/System/BP_Challenge_Console_74_DLC4.BP_Challenge_Console_74_DLC4_C", name: "Console 74 DLC 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Alisma/GameData/System/BP_Challenge_Console_75_DLC4.BP_Challenge_Console_75_DLC4_C", name: "Console 75 DLC 4" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_00_MetaChallenge.Challenge_BloodyHarvest_00_MetaChallenge_C", name: "Bloody Harvest 00 Meta Challenge" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_01_HauntedBadass.Challenge_BloodyHarvest_01_HauntedBadass_C", name: "Bloody Harvest 01 Haunted Badass" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_02_CaptainHaunt.Challenge_BloodyHarvest_02_CaptainHaunt_C", name: "Bloody Harvest 02 Captain Haunt" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_03_LeagueLootCollected.Challenge_BloodyHarvest_03_LeagueLootCollected_C", name: "Bloody Harvest 03 League Loot Collected" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_04_KillGhosts.Challenge_BloodyHarvest_04_KillGhosts_C", name: "Bloody Harvest 04 Kill Ghosts" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_05_Mayhem_Ghosts.Challenge_BloodyHarvest_05_Mayhem_Ghosts_C", name: "Bloody Harvest 05 Mayhem Ghosts" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_07_Rare_BormanNates.Challenge_BloodyHarvest_07_Rare_BormanNates_C", name: "Bloody Harvest 07 Rare Borman Nates" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_08_Rare_Demoskag.Challenge_BloodyHarvest_08_Rare_Demoskag_C", name: "Bloody Harvest 08 Rare Demoskag" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_09_Rare_ElDragonJr.Challenge_BloodyHarvest_09_Rare_ElDragonJr_C", name: "Bloody Harvest 09 Rare El Dragon Jr" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_10_Rare_Rakkman.Challenge_BloodyHarvest_10_Rare_Rakkman_C", name: "Bloody Harvest 10 Rare Rakkman" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_11_LootGhosts.Challenge_BloodyHarvest_11_LootGhosts_C", name: "Bloody Harvest 11 Loot Ghosts" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_12_HauntedLegendaries.Challenge_BloodyHarvest_12_HauntedLegendaries_C", name: "Bloody Harvest 12 Haunted Legendaries" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_13_AnointedLegendary.Challenge_BloodyHarvest_13_AnointedLegendary_C", name: "Bloody Harvest 13 Anointed Legendary" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_14_KillCaptainWithHauntWeapon.Challenge_BloodyHarvest_14_KillCaptainWithHauntWeapon_C", name: "Bloody Harvest 14 Kill Captain With Haunt Weapon" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_15_Dogleg_PumpkinChest.Challenge_BloodyHarvest_15_Dogleg_PumpkinChest_C", name: "Bloody Harvest 15 Dogleg Pumpkin Chest" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Challenges/LeagueChallenges/Challenge_BloodyHarvest_17_Dogleg_CryoHaunted.Challenge_BloodyHarvest_17_Dogleg_CryoHaunted_C", name: "Bloody Harvest 17 Dogleg Cryo Haunted" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Collect/_Stat/Challenge_Collect_RewardStat_DLC1_Done.Challenge_Collect_RewardStat_DLC1_Done_C", name: "Collect Reward Stat DLC 1 Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Collect/Core/Challenge_Collect_DLC1_Core_01.Challenge_Collect_DLC1_Core_01_C", name: "Collect DLC 1 Core 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Collect/Strip/Challenge_Collect_DLC1_Strip_01.Challenge_Collect_DLC1_Strip_01_C", name: "Collect DLC 1 Strip 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Collect/Strip/Challenge_Collect_DLC1_Strip_02.Challenge_Collect_DLC1_Strip_02_C", name: "Collect DLC 1 Strip 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Collect/Strip/Challenge_Collect_Group_DLC1_Strip.Challenge_Collect_Group_DLC1_Strip_C", name: "Collect Group DLC 1 Strip" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Collect/TowerLair/Challenge_Collect_DLC1_TowerLair_01.Challenge_Collect_DLC1_TowerLair_01_C", name: "Collect DLC 1 Tower Lair 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Collect/Trashtown/Challenge_Collect_DLC1_Trash_01.Challenge_Collect_DLC1_Trash_01_C", name: "Collect DLC 1 Trash 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Kill/_Stat/Challenge_Kill_RewardStat_DLC1_Done.Challenge_Kill_RewardStat_DLC1_Done_C", name: "Kill Reward Stat DLC 1 Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Kill/Impound/Challenge_Kill_DLC1_Impound_01.Challenge_Kill_DLC1_Impound_01_C", name: "Kill DLC 1 Impound 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Kill/Strip/Challenge_Kill_DLC1_Strip_01.Challenge_Kill_DLC1_Strip_01_C", name: "Kill DLC 1 Strip 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Kill/Strip/Challenge_Kill_DLC1_Strip_02.Challenge_Kill_DLC1_Strip_02_C", name: "Kill DLC 1 Strip 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Kill/TowerLair/Challenge_Kill_DLC1_TowerLair_01.Challenge_Kill_DLC1_TowerLair_01_C", name: "Kill DLC 1 Tower Lair 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Kill/Trashtown/Challenge_Kill_DLC1_Trash_01.Challenge_Kill_DLC1_Trash_01_C", name: "Kill DLC 1 Trash 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Sabotage/_Stat/Challenge_Sabotage_RewardStat_DLC1_Done.Challenge_Sabotage_RewardStat_DLC1_Done_C", name: "Sabotage Reward Stat DLC 1 Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Sabotage/Core/Challenge_Sabotage_DLC1_Core_01.Challenge_Sabotage_DLC1_Core_01_C", name: "Sabotage DLC 1 Core 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Sabotage/Impound/Challenge_Sabotage_DLC1_Impound_01.Challenge_Sabotage_DLC1_Impound_01_C", name: "Sabotage DLC 1 Impound 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Sabotage/Strip/Challenge_Sabotage_DLC1_Group_Strip.Challenge_Sabotage_DLC1_Group_Strip_C", name: "Sabotage DLC 1 Group Strip" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Sabotage/Strip/Challenge_Sabotage_DLC1_Strip_01.Challenge_Sabotage_DLC1_Strip_01_C", name: "Sabotage DLC 1 Strip 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Sabotage/Strip/Challenge_Sabotage_DLC1_Strip_02.Challenge_Sabotage_DLC1_Strip_02_C", name: "Sabotage DLC 1 Strip 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/CrewChallenges/Sabotage/TowerLair/Challenge_Sabotage_DLC1_TowerLair_01.Challenge_Sabotage_DLC1_TowerLair_01_C", name: "Sabotage DLC 1 Tower Lair 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/CasinoIntro/Challenge_Discovery_CasinoIntro_Bullion.Challenge_Discovery_CasinoIntro_Bullion_C", name: "Discovery Casino Intro Bullion" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/CasinoIntro/Challenge_Discovery_CasinoIntro_ChumpSlots.Challenge_Discovery_CasinoIntro_ChumpSlots_C", name: "Discovery Casino Intro Chump Slots" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/CasinoIntro/Challenge_Discovery_CasinoIntro_Hangar.Challenge_Discovery_CasinoIntro_Hangar_C", name: "Discovery Casino Intro Hangar" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/CasinoIntro/Challenge_Discovery_CasinoIntro_Lobby.Challenge_Discovery_CasinoIntro_Lobby_C", name: "Discovery Casino Intro Lobby" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/CasinoIntro/Challenge_Discovery_CasinoIntro_Plaza.Challenge_Discovery_CasinoIntro_Plaza_C", name: "Discovery Casino Intro Plaza" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/CasinoIntro/Challenge_Discovery_CasinoIntro_Subway.Challenge_Discovery_CasinoIntro_Subway_C", name: "Discovery Casino Intro Subway" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_AssemblyLine.Challenge_Discovery_Core_AssemblyLine_C", name: "Discovery Core Assembly Line" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_BossFightArena.Challenge_Discovery_Core_BossFightArena_C", name: "Discovery Core Boss Fight Arena" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_CrossRoads.Challenge_Discovery_Core_CrossRoads_C", name: "Discovery Core Cross Roads" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_Foundry.Challenge_Discovery_Core_Foundry_C", name: "Discovery Core Foundry" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_Intro.Challenge_Discovery_Core_Intro_C", name: "Discovery Core Intro" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_Lab.Challenge_Discovery_Core_Lab_C", name: "Discovery Core Lab" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_PackingRoom.Challenge_Discovery_Core_PackingRoom_C", name: "Discovery Core Packing Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_RoutingRoom.Challenge_Discovery_Core_RoutingRoom_C", name: "Discovery Core Routing Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_Scrapyard.Challenge_Discovery_Core_Scrapyard_C", name: "Discovery Core Scrapyard" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Core/Challenge_Discovery_Core_TheActualCore.Challenge_Discovery_Core_TheActualCore_C", name: "Discovery Core The Actual Core" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_Airlock.Challenge_Discovery_Impound_Airlock_C", name: "Discovery Impound Airlock" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_CargoShip.Challenge_Discovery_Impound_CargoShip_C", name: "Discovery Impound Cargo Ship" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_CustomerCare.Challenge_Discovery_Impound_CustomerCare_C", name: "Discovery Impound Customer Care" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_DockA.Challenge_Discovery_Impound_DockA_C", name: "Discovery Impound Dock A" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_ExternalPlatforms.Challenge_Discovery_Impound_ExternalPlatforms_C", name: "Discovery Impound External Platforms" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_FreddieHangar.Challenge_Discovery_Impound_FreddieHangar_C", name: "Discovery Impound Freddie Hangar" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_Impound.Challenge_Discovery_Impound_Impound_C", name: "Discovery Impound Impound" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_ShipRailSystem.Challenge_Discovery_Impound_ShipRailSystem_C", name: "Discovery Impound Ship Rail System" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Impound/Challenge_Discovery_Impound_WaitingArea.Challenge_Discovery_Impound_WaitingArea_C", name: "Discovery Impound Waiting Area" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_Cabaret.Challenge_Discovery_Strip_Cabaret_C", name: "Discovery Strip Cabaret" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_CentralPlaza.Challenge_Discovery_Strip_CentralPlaza_C", name: "Discovery Strip Central Plaza" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_Checkpoint.Challenge_Discovery_Strip_Checkpoint_C", name: "Discovery Strip Checkpoint" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_FoodCourt.Challenge_Discovery_Strip_FoodCourt_C", name: "Discovery Strip Food Court" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_FountainPlaza.Challenge_Discovery_Strip_FountainPlaza_C", name: "Discovery Strip Fountain Plaza" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_Gardens.Challenge_Discovery_Strip_Gardens_C", name: "Discovery Strip Gardens" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_Hideout.Challenge_Discovery_Strip_Hideout_C", name: "Discovery Strip Hideout" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_JackSpa.Challenge_Discovery_Strip_JackSpa_C", name: "Discovery Strip Jack Spa" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_JSpot.Challenge_Discovery_Strip_JSpot_C", name: "Discovery Strip J Spot" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_LootedShop.Challenge_Discovery_Strip_LootedShop_C", name: "Discovery Strip Looted Shop" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_MarketIntro.Challenge_Discovery_Strip_MarketIntro_C", name: "Discovery Strip Market Intro" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_PleasureCanal.Challenge_Discovery_Strip_PleasureCanal_C", name: "Discovery Strip Pleasure Canal" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_RagingBot.Challenge_Discovery_Strip_RagingBot_C", name: "Discovery Strip Raging Bot" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_SisterlyA.Challenge_Discovery_Strip_SisterlyA_C", name: "Discovery Strip Sisterly A" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_SisterlyB.Challenge_Discovery_Strip_SisterlyB_C", name: "Discovery Strip Sisterly B" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_SubwayArrival.Challenge_Discovery_Strip_SubwayArrival_C", name: "Discovery Strip Subway Arrival" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_TheSpas.Challenge_Discovery_Strip_TheSpas_C", name: "Discovery Strip The Spas" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_TrickyRickPad.Challenge_Discovery_Strip_TrickyRickPad_C", name: "Discovery Strip Tricky Rick Pad" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_Turrets.Challenge_Discovery_Strip_Turrets_C", name: "Discovery Strip Turrets" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_ViceIntro.Challenge_Discovery_Strip_ViceIntro_C", name: "Discovery Strip Vice Intro" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Strip/Challenge_Discovery_Strip_WelcomePlaza.Challenge_Discovery_Strip_WelcomePlaza_C", name: "Discovery Strip Welcome Plaza" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/TowerLair/Challenge_Discovery_TowerLair_HouseOfJack.Challenge_Discovery_TowerLair_HouseOfJack_C", name: "Discovery Tower Lair House Of Jack" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/TowerLair/Challenge_Discovery_TowerLair_KingEye.Challenge_Discovery_TowerLair_KingEye_C", name: "Discovery Tower Lair King Eye" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/TowerLair/Challenge_Discovery_TowerLair_Lounge.Challenge_Discovery_TowerLair_Lounge_C", name: "Discovery Tower Lair Lounge" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/TowerLair/Challenge_Discovery_TowerLair_Luxuria.Challenge_Discovery_TowerLair_Luxuria_C", name: "Discovery Tower Lair Luxuria" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/TowerLair/Challenge_Discovery_TowerLair_Shaft.Challenge_Discovery_TowerLair_Shaft_C", name: "Discovery Tower Lair Shaft" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_Beam.Challenge_Discovery_Trashtown_Beam_C", name: "Discovery Trashtown Beam" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_Canyon.Challenge_Discovery_Trashtown_Canyon_C", name: "Discovery Trashtown Canyon" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_ClapTrapCave.Challenge_Discovery_Trashtown_ClapTrapCave_C", name: "Discovery Trashtown Clap Trap Cave" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_CubeLand.Challenge_Discovery_Trashtown_CubeLand_C", name: "Discovery Trashtown Cube Land" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_FireLand.Challenge_Discovery_Trashtown_FireLand_C", name: "Discovery Trashtown Fire Land" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_GreatGap.Challenge_Discovery_Trashtown_GreatGap_C", name: "Discovery Trashtown Great Gap" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_ShredderGaze.Challenge_Discovery_Trashtown_ShredderGaze_C", name: "Discovery Trashtown Shredder Gaze" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_StinkLands.Challenge_Discovery_Trashtown_StinkLands_C", name: "Discovery Trashtown Stink Lands" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_Stronghold.Challenge_Discovery_Trashtown_Stronghold_C", name: "Discovery Trashtown Stronghold" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_TheRuns.Challenge_Discovery_Trashtown_TheRuns_C", name: "Discovery Trashtown The Runs" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_ThroneRoom.Challenge_Discovery_Trashtown_ThroneRoom_C", name: "Discovery Trashtown Throne Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Discovery/Trashtown/Challenge_Discovery_Trashtown_Trashlantis.Challenge_Discovery_Trashtown_Trashlantis_C", name: "Discovery Trashtown Trashlantis" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Casino1.Challenge_DLC1_EchoLog_Casino1_C", name: "DLC 1 Echo Log Casino 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Casino2.Challenge_DLC1_EchoLog_Casino2_C", name: "DLC 1 Echo Log Casino 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Casino3.Challenge_DLC1_EchoLog_Casino3_C", name: "DLC 1 Echo Log Casino 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Core1.Challenge_DLC1_EchoLog_Core1_C", name: "DLC 1 Echo Log Core 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Core2.Challenge_DLC1_EchoLog_Core2_C", name: "DLC 1 Echo Log Core 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Core3.Challenge_DLC1_EchoLog_Core3_C", name: "DLC 1 Echo Log Core 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Core4.Challenge_DLC1_EchoLog_Core4_C", name: "DLC 1 Echo Log Core 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Impound1.Challenge_DLC1_EchoLog_Impound1_C", name: "DLC 1 Echo Log Impound 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Impound2.Challenge_DLC1_EchoLog_Impound2_C", name: "DLC 1 Echo Log Impound 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Impound3.Challenge_DLC1_EchoLog_Impound3_C", name: "DLC 1 Echo Log Impound 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Impound4.Challenge_DLC1_EchoLog_Impound4_C", name: "DLC 1 Echo Log Impound 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Impound5.Challenge_DLC1_EchoLog_Impound5_C", name: "DLC 1 Echo Log Impound 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Strip1.Challenge_DLC1_EchoLog_Strip1_C", name: "DLC 1 Echo Log Strip 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Strip2.Challenge_DLC1_EchoLog_Strip2_C", name: "DLC 1 Echo Log Strip 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Strip3.Challenge_DLC1_EchoLog_Strip3_C", name: "DLC 1 Echo Log Strip 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Strip4.Challenge_DLC1_EchoLog_Strip4_C", name: "DLC 1 Echo Log Strip 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Strip5.Challenge_DLC1_EchoLog_Strip5_C", name: "DLC 1 Echo Log Strip 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Tower1.Challenge_DLC1_EchoLog_Tower1_C", name: "DLC 1 Echo Log Tower 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown1.Challenge_DLC1_EchoLog_Trashtown1_C", name: "DLC 1 Echo Log Trashtown 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown10.Challenge_DLC1_EchoLog_Trashtown10_C", name: "DLC 1 Echo Log Trashtown 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown11.Challenge_DLC1_EchoLog_Trashtown11_C", name: "DLC 1 Echo Log Trashtown 11" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown2.Challenge_DLC1_EchoLog_Trashtown2_C", name: "DLC 1 Echo Log Trashtown 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown3.Challenge_DLC1_EchoLog_Trashtown3_C", name: "DLC 1 Echo Log Trashtown 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown4.Challenge_DLC1_EchoLog_Trashtown4_C", name: "DLC 1 Echo Log Trashtown 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown5.Challenge_DLC1_EchoLog_Trashtown5_C", name: "DLC 1 Echo Log Trashtown 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown6.Challenge_DLC1_EchoLog_Trashtown6_C", name: "DLC 1 Echo Log Trashtown 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown7.Challenge_DLC1_EchoLog_Trashtown7_C", name: "DLC 1 Echo Log Trashtown 7" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown8.Challenge_DLC1_EchoLog_Trashtown8_C", name: "DLC 1 Echo Log Trashtown 8" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/EchoLogs/Challenge_DLC1_EchoLog_Trashtown9.Challenge_DLC1_EchoLog_Trashtown9_C", name: "DLC 1 Echo Log Trashtown 9" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_CasinoBots.Challenge_Enemy_CasinoBots_C", name: "Enemy Casino Bots" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_FeralClaptrap.Challenge_Enemy_FeralClaptrap_C", name: "Enemy Feral Claptrap" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_Group_Hyperion.Challenge_Enemy_Group_Hyperion_C", name: "Enemy Group Hyperion" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_Group_Looter.Challenge_Enemy_Group_Looter_C", name: "Enemy Group Looter" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_Hyperion_Constructors.Challenge_Enemy_Hyperion_Constructors_C", name: "Enemy Hyperion Constructors" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_Hyperion_Loaders.Challenge_Enemy_Hyperion_Loaders_C", name: "Enemy Hyperion Loaders" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_Hyperion_SlotMachines.Challenge_Enemy_Hyperion_SlotMachines_C", name: "Enemy Hyperion Slot Machines" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_Hyperion_Surveyors.Challenge_Enemy_Hyperion_Surveyors_C", name: "Enemy Hyperion Surveyors" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_Hyperion_Turrets.Challenge_Enemy_Hyperion_Turrets_C", name: "Enemy Hyperion Turrets" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Enemy/Challenge_Enemy_LoaderCorrosiveKill.Challenge_Enemy_LoaderCorrosiveKill_C", name: "Enemy Loader Corrosive Kill" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/CasinoIntro/Challenge_FastTravel_CasinoIntro1.Challenge_FastTravel_CasinoIntro1_C", name: "Fast Travel Casino Intro 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Core/Challenge_FastTravel_Core1.Challenge_FastTravel_Core1_C", name: "Fast Travel Core 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Core/Challenge_FastTravel_Core2.Challenge_FastTravel_Core2_C", name: "Fast Travel Core 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Impound/Challenge_FastTravel_Impound1.Challenge_FastTravel_Impound1_C", name: "Fast Travel Impound 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Impound/Challenge_FastTravel_Impound2.Challenge_FastTravel_Impound2_C", name: "Fast Travel Impound 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip1.Challenge_FastTravel_Strip1_C", name: "Fast Travel Strip 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip2.Challenge_FastTravel_Strip2_C", name: "Fast Travel Strip 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip3.Challenge_FastTravel_Strip3_C", name: "Fast Travel Strip 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip4.Challenge_FastTravel_Strip4_C", name: "Fast Travel Strip 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/TowerLair/Challenge_FastTravel_TowerLair1.Challenge_FastTravel_TowerLair1_C", name: "Fast Travel Tower Lair 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/TowerLair/Challenge_FastTravel_TowerLair2.Challenge_FastTravel_TowerLair2_C", name: "Fast Travel Tower Lair 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Trashtown/Challenge_FastTravel_Trashtown1.Challenge_FastTravel_Trashtown1_C", name: "Fast Travel Trashtown 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Trashtown/Challenge_FastTravel_Trashtown2.Challenge_FastTravel_Trashtown2_C", name: "Fast Travel Trashtown 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/InteractiveObjects/Challenge_IO_BlackjackChests.Challenge_IO_BlackjackChests_C", name: "IO Blackjack Chests" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/InteractiveObjects/Challenge_IO_FightClubWins.Challenge_IO_FightClubWins_C", name: "IO Fight Club Wins" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/InteractiveObjects/Challenge_IO_JackHolograms.Challenge_IO_JackHolograms_C", name: "IO Jack Holograms" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/InteractiveObjects/Challenge_IO_PrizeDoors.Challenge_IO_PrizeDoors_C", name: "IO Prize Doors" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/InteractiveObjects/Challenge_IO_SlotMachines.Challenge_IO_SlotMachines_C", name: "IO Slot Machines" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/_Stat/Challenge_JackHolo_AllDone.Challenge_JackHolo_AllDone_C", name: "Jack Holo All Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/CasinoIntro/Challenge_JackHolo_Intro_01.Challenge_JackHolo_Intro_01_C", name: "Jack Holo Intro 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/CasinoIntro/Challenge_JackHolo_Intro_05.Challenge_JackHolo_Intro_05_C", name: "Jack Holo Intro 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/CasinoIntro/Challenge_JackHolo_Intro_08.Challenge_JackHolo_Intro_08_C", name: "Jack Holo Intro 08" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Impound/Challenge_JackHolo_Impound_06.Challenge_JackHolo_Impound_06_C", name: "Jack Holo Impound 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Strip/Challenge_JackHolo_Strip_02.Challenge_JackHolo_Strip_02_C", name: "Jack Holo Strip 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Strip/Challenge_JackHolo_Strip_03.Challenge_JackHolo_Strip_03_C", name: "Jack Holo Strip 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Strip/Challenge_JackHolo_Strip_04.Challenge_JackHolo_Strip_04_C", name: "Jack Holo Strip 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Strip/Challenge_JackHolo_Strip_07.Challenge_JackHolo_Strip_07_C", name: "Jack Holo Strip 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Strip/Challenge_JackHolo_Strip_09.Challenge_JackHolo_Strip_09_C", name: "Jack Holo Strip 09" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Strip/Challenge_JackHolo_Strip_10.Challenge_JackHolo_Strip_10_C", name: "Jack Holo Strip 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/JackHolos/Strip/Challenge_JackHolo_Strip_11.Challenge_JackHolo_Strip_11_C", name: "Jack Holo Strip 11" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC1_EP01_MeetTimothy.Challenge_Mission_Plot_DLC1_EP01_MeetTimothy_C", name: "Mission Plot DLC 1 EP 01 Meet Timothy" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC1_Ep02_MeetCrad.Challenge_Mission_Plot_DLC1_Ep02_MeetCrad_C", name: "Mission Plot DLC 1 Ep 02 Meet Crad" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC1_Ep03_Impound.Challenge_Mission_Plot_DLC1_Ep03_Impound_C", name: "Mission Plot DLC 1 Ep 03 Impound" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC1_Ep04_Trashtown.Challenge_Mission_Plot_DLC1_Ep04_Trashtown_C", name: "Mission Plot DLC 1 Ep 04 Trashtown" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC1_Ep05_ThePlan.Challenge_Mission_Plot_DLC1_Ep05_ThePlan_C", name: "Mission Plot DLC 1 Ep 05 The Plan" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC1_Ep06_TheCore.Challenge_Mission_Plot_DLC1_Ep06_TheCore_C", name: "Mission Plot DLC 1 Ep 06 The Core" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC1_Ep07_TheHeist.Challenge_Mission_Plot_DLC1_Ep07_TheHeist_C", name: "Mission Plot DLC 1 Ep 07 The Heist" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_AcidTrip.Challenge_Mission_Side_AcidTrip_C", name: "Mission Side Acid Trip" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_BrotherlyLove.Challenge_Mission_Side_BrotherlyLove_C", name: "Mission Side Brotherly Love" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DigbyPart1.Challenge_Mission_Side_DigbyPart1_C", name: "Mission Side Digby Part 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DigbyPart2.Challenge_Mission_Side_DigbyPart2_C", name: "Mission Side Digby Part 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DigbyPart3.Challenge_Mission_Side_DigbyPart3_C", name: "Mission Side Digby Part 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DoubleDown.Challenge_Mission_Side_DoubleDown_C", name: "Mission Side Double Down" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_GreatEscape.Challenge_Mission_Side_GreatEscape_C", name: "Mission Side Great Escape" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_HeartOfGold.Challenge_Mission_Side_HeartOfGold_C", name: "Mission Side Heart Of Gold" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_RagingBot.Challenge_Mission_Side_RagingBot_C", name: "Mission Side Raging Bot" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_RegainingOnesFeet.Challenge_Mission_Side_RegainingOnesFeet_C", name: "Mission Side Regaining Ones Feet" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/Missions/Side/Challenge_Mission_Side_TooMuchOfAGoodThing.Challenge_Mission_Side_TooMuchOfAGoodThing_C", name: "Mission Side Too Much Of A Good Thing" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/RedChests/Impound/Challenge_RedChests_Impound1.Challenge_RedChests_Impound1_C", name: "Red Chests Impound 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/RedChests/Impound/Challenge_RedChests_Impound2.Challenge_RedChests_Impound2_C", name: "Red Chests Impound 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/RedChests/Strip/Challenge_RedChests_Strip1.Challenge_RedChests_Strip1_C", name: "Red Chests Strip 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/RedChests/Strip/Challenge_RedChests_Strip3.Challenge_RedChests_Strip3_C", name: "Red Chests Strip 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/RedChests/TowerLair/Challenge_RedChests_TowerLair1.Challenge_RedChests_TowerLair1_C", name: "Red Chests Tower Lair 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/RedChests/TowerLair/Challenge_RedChests_TowerLair2.Challenge_RedChests_TowerLair2_C", name: "Red Chests Tower Lair 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/System/BP_Challenge_Console_46_DLC1.BP_Challenge_Console_46_DLC1_C", name: "Console 46 DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/System/BP_Challenge_Console_47_DLC1.BP_Challenge_Console_47_DLC1_C", name: "Console 47 DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/System/BP_Challenge_Console_48_DLC1.BP_Challenge_Console_48_DLC1_C", name: "Console 48 DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/System/BP_Challenge_Console_49_DLC1.BP_Challenge_Console_49_DLC1_C", name: "Console 49 DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/System/BP_Challenge_Console_50_DLC1.BP_Challenge_Console_50_DLC1_C", name: "Console 50 DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/System/BP_Challenge_Console_51_DLC1.BP_Challenge_Console_51_DLC1_C", name: "Console 51 DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/GameData/Challenges/System/BP_Challenge_Console_52_DLC1.BP_Challenge_Console_52_DLC1_C", name: "Console 52 DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_00_MetaChallenge.BP_Challenge_Cartels_00_MetaChallenge_C", name: "Cartels 00 Meta Challenge" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_01_KillJoeyUltraviolet.BP_Challenge_Cartels_01_KillJoeyUltraviolet_C", name: "Cartels 01 Kill Joey Ultraviolet" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_02_KillJoeyUltraviolet_Mayhem.BP_Challenge_Cartels_02_KillJoeyUltraviolet_Mayhem_C", name: "Cartels 02 Kill Joey Ultraviolet Mayhem" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_03_Pupsicles.BP_Challenge_Cartels_03_Pupsicles_C", name: "Cartels 03 Pupsicles" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_04_KillJoeyUltraviolet_TimeLimit.BP_Challenge_Cartels_04_KillJoeyUltraviolet_TimeLimit_C", name: "Cartels 04 Kill Joey Ultraviolet Time Limit" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_05_KillJoeyUltraviolet_TrapsNoDamage.BP_Challenge_Cartels_05_KillJoeyUltraviolet_TrapsNoDamage_C", name: "Cartels 05 Kill Joey Ultraviolet Traps No Damage" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_06_EridiumDustPiles.BP_Challenge_Cartels_06_EridiumDustPiles_C", name: "Cartels 06 Eridium Dust Piles" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_07_HiddenPuzzle.BP_Challenge_Cartels_07_HiddenPuzzle_C", name: "Cartels 07 Hidden Puzzle" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_08_FlushToilets.BP_Challenge_Cartels_08_FlushToilets_C", name: "Cartels 08 Flush Toilets" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_09_KillCartelEnemies.BP_Challenge_Cartels_09_KillCartelEnemies_C", name: "Cartels 09 Kill Cartel Enemies" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_10_CompleteCartelsNoDying.BP_Challenge_Cartels_10_CompleteCartelsNoDying_C", name: "Cartels 10 Complete Cartels No Dying" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_11_KillUnderbosses.BP_Challenge_Cartels_11_KillUnderbosses_C", name: "Cartels 11 Kill Underbosses" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_12_KillUnderbosses_OneRun.BP_Challenge_Cartels_12_KillUnderbosses_OneRun_C", name: "Cartels 12 Kill Underbosses One Run" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_13_KillUnderbosses_Mayhem.BP_Challenge_Cartels_13_KillUnderbosses_Mayhem_C", name: "Cartels 13 Kill Underbosses Mayhem" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_14_KillFishSlapMelee.BP_Challenge_Cartels_14_KillFishSlapMelee_C", name: "Cartels 14 Kill Fish Slap Melee" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_15_KillTenderizer_Special.BP_Challenge_Cartels_15_KillTenderizer_Special_C", name: "Cartels 15 Kill Tenderizer Special" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_16_KillFrancoFirewall_Special.BP_Challenge_Cartels_16_KillFrancoFirewall_Special_C", name: "Cartels 16 Kill Franco Firewall Special" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_17_ShootMeat.BP_Challenge_Cartels_17_ShootMeat_C", name: "Cartels 17 Shoot Meat" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Challenges/EventChallenges/BP_Challenge_Cartels_18_ShootTiny.BP_Challenge_Cartels_18_ShootTiny_C", name: "Cartels 18 Shoot Tiny" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Challenges/BP_Challenge_ValentinesDayEvent_MetaChallenge.BP_Challenge_ValentinesDayEvent_MetaChallenge_C", name: "Valentines Day Event Meta Challenge" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Challenges/BP_Challenge_ValentinesDayEvent_Tier1.BP_Challenge_ValentinesDayEvent_Tier1_C", name: "Valentines Day Event Tier 1" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Challenges/BP_Challenge_ValentinesDayEvent_Tier2.BP_Challenge_ValentinesDayEvent_Tier2_C", name: "Valentines Day Event Tier 2" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Challenges/BP_Challenge_ValentinesDayEvent_Tier3.BP_Challenge_ValentinesDayEvent_Tier3_C", name: "Valentines Day Event Tier 3" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Challenges/BP_Challenge_ValentinesDayEvent_Tier4.BP_Challenge_ValentinesDayEvent_Tier4_C", name: "Valentines Day Event Tier 4" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Challenges/BP_Challenge_ValentinesDayEvent_Tier5.BP_Challenge_ValentinesDayEvent_Tier5_C", name: "Valentines Day Event Tier 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_AllAchievements.BP_Challenge_Console_ID_Ger_AllAchievements_C", name: "Console ID Ger All Achievements" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_CrewChallenges.BP_Challenge_Console_ID_Ger_CrewChallenges_C", name: "Console ID Ger Crew Challenges" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_Mission1.BP_Challenge_Console_ID_Ger_Mission1_C", name: "Console ID Ger Mission 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_Mission2.BP_Challenge_Console_ID_Ger_Mission2_C", name: "Console ID Ger Mission 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_Mission3.BP_Challenge_Console_ID_Ger_Mission3_C", name: "Console ID Ger Mission 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_Mission4.BP_Challenge_Console_ID_Ger_Mission4_C", name: "Console ID Ger Mission 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_Mission5.BP_Challenge_Console_ID_Ger_Mission5_C", name: "Console ID Ger Mission 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/1stParty/BP_Challenge_Console_ID_Ger_SideMissions.BP_Challenge_Console_ID_Ger_SideMissions_C", name: "Console ID Ger Side Missions" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_Cemetery_00_AllComplete.Challenge_Crew_Ger_Cemetery_00_AllComplete_C", name: "Crew Ger Cemetery 00 All Complete" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_01.Challenge_Crew_Ger_CemeteryOpportunity_01_C", name: "Crew Ger Cemetery Opportunity 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_02.Challenge_Crew_Ger_CemeteryOpportunity_02_C", name: "Crew Ger Cemetery Opportunity 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_03.Challenge_Crew_Ger_CemeteryOpportunity_03_C", name: "Crew Ger Cemetery Opportunity 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_04.Challenge_Crew_Ger_CemeteryOpportunity_04_C", name: "Crew Ger Cemetery Opportunity 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_05.Challenge_Crew_Ger_CemeteryOpportunity_05_C", name: "Crew Ger Cemetery Opportunity 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_06.Challenge_Crew_Ger_CemeteryOpportunity_06_C", name: "Crew Ger Cemetery Opportunity 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_07.Challenge_Crew_Ger_CemeteryOpportunity_07_C", name: "Crew Ger Cemetery Opportunity 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_08.Challenge_Crew_Ger_CemeteryOpportunity_08_C", name: "Crew Ger Cemetery Opportunity 08" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_09.Challenge_Crew_Ger_CemeteryOpportunity_09_C", name: "Crew Ger Cemetery Opportunity 09" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CemeteryOpportunity/Challenge_Crew_Ger_CemeteryOpportunity_10.Challenge_Crew_Ger_CemeteryOpportunity_10_C", name: "Crew Ger Cemetery Opportunity 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CreatureFeature/Challenge_Crew_Ger_CreatureFeature_00_AllDone.Challenge_Crew_Ger_CreatureFeature_00_AllDone_C", name: "Crew Ger Creature Feature 00 All Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CreatureFeature/Challenge_Crew_Ger_CreatureFeature_01.Challenge_Crew_Ger_CreatureFeature_01_C", name: "Crew Ger Creature Feature 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CreatureFeature/Challenge_Crew_Ger_CreatureFeature_02.Challenge_Crew_Ger_CreatureFeature_02_C", name: "Crew Ger Creature Feature 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CreatureFeature/Challenge_Crew_Ger_CreatureFeature_03.Challenge_Crew_Ger_CreatureFeature_03_C", name: "Crew Ger Creature Feature 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CreatureFeature/Challenge_Crew_Ger_CreatureFeature_04.Challenge_Crew_Ger_CreatureFeature_04_C", name: "Crew Ger Creature Feature 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CreatureFeature/Challenge_Crew_Ger_CreatureFeature_05.Challenge_Crew_Ger_CreatureFeature_05_C", name: "Crew Ger Creature Feature 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/CreatureFeature/Challenge_Crew_Ger_CreatureFeature_06.Challenge_Crew_Ger_CreatureFeature_06_C", name: "Crew Ger Creature Feature 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_01.Challenge_Crew_Ger_JakobsJournal_01_C", name: "Crew Ger Jakobs Journal 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_02.Challenge_Crew_Ger_JakobsJournal_02_C", name: "Crew Ger Jakobs Journal 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_03.Challenge_Crew_Ger_JakobsJournal_03_C", name: "Crew Ger Jakobs Journal 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_04.Challenge_Crew_Ger_JakobsJournal_04_C", name: "Crew Ger Jakobs Journal 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_05.Challenge_Crew_Ger_JakobsJournal_05_C", name: "Crew Ger Jakobs Journal 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_06.Challenge_Crew_Ger_JakobsJournal_06_C", name: "Crew Ger Jakobs Journal 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_07.Challenge_Crew_Ger_JakobsJournal_07_C", name: "Crew Ger Jakobs Journal 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_08.Challenge_Crew_Ger_JakobsJournal_08_C", name: "Crew Ger Jakobs Journal 08" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_09.Challenge_Crew_Ger_JakobsJournal_09_C", name: "Crew Ger Jakobs Journal 09" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Ger_JakobsJournal_10.Challenge_Crew_Ger_JakobsJournal_10_C", name: "Crew Ger Jakobs Journal 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_JakobsVaults_AllOpened.Challenge_Crew_Geranium_JakobsVaults_AllOpened_C", name: "Crew Geranium Jakobs Vaults All Opened" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_OpenJakobsVault_01.Challenge_Crew_Geranium_OpenJakobsVault_01_C", name: "Crew Geranium Open Jakobs Vault 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_OpenJakobsVault_02.Challenge_Crew_Geranium_OpenJakobsVault_02_C", name: "Crew Geranium Open Jakobs Vault 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_OpenJakobsVault_03.Challenge_Crew_Geranium_OpenJakobsVault_03_C", name: "Crew Geranium Open Jakobs Vault 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_OpenJakobsVault_04.Challenge_Crew_Geranium_OpenJakobsVault_04_C", name: "Crew Geranium Open Jakobs Vault 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_OpenJakobsVault_05.Challenge_Crew_Geranium_OpenJakobsVault_05_C", name: "Crew Geranium Open Jakobs Vault 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_UnlockJakobsVault_01.Challenge_Crew_Geranium_UnlockJakobsVault_01_C", name: "Crew Geranium Unlock Jakobs Vault 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_UnlockJakobsVault_02.Challenge_Crew_Geranium_UnlockJakobsVault_02_C", name: "Crew Geranium Unlock Jakobs Vault 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_UnlockJakobsVault_03.Challenge_Crew_Geranium_UnlockJakobsVault_03_C", name: "Crew Geranium Unlock Jakobs Vault 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_UnlockJakobsVault_04.Challenge_Crew_Geranium_UnlockJakobsVault_04_C", name: "Crew Geranium Unlock Jakobs Vault 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Challenge_Crew_Geranium_UnlockJakobsVault_05.Challenge_Crew_Geranium_UnlockJakobsVault_05_C", name: "Crew Geranium Unlock Jakobs Vault 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_00_AllComplete.Challenge_Crew_Ger_Tannery_00_AllComplete_C", name: "Crew Ger Tannery 00 All Complete" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_01.Challenge_Crew_Ger_Tannery_01_C", name: "Crew Ger Tannery 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_02.Challenge_Crew_Ger_Tannery_02_C", name: "Crew Ger Tannery 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_03.Challenge_Crew_Ger_Tannery_03_C", name: "Crew Ger Tannery 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_04.Challenge_Crew_Ger_Tannery_04_C", name: "Crew Ger Tannery 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_05.Challenge_Crew_Ger_Tannery_05_C", name: "Crew Ger Tannery 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_06.Challenge_Crew_Ger_Tannery_06_C", name: "Crew Ger Tannery 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_07.Challenge_Crew_Ger_Tannery_07_C", name: "Crew Ger Tannery 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/Tannery/Challenge_Crew_Ger_Tannery_08.Challenge_Crew_Ger_Tannery_08_C", name: "Crew Ger Tannery 08" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Ger_TreasureMap_00_AllComplete.Challenge_Crew_Ger_TreasureMap_00_AllComplete_C", name: "Crew Ger Treasure Map 00 All Complete" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Ger_TreasureMap_01.Challenge_Crew_Ger_TreasureMap_01_C", name: "Crew Ger Treasure Map 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Ger_TreasureMap_02.Challenge_Crew_Ger_TreasureMap_02_C", name: "Crew Ger Treasure Map 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Ger_TreasureMap_03.Challenge_Crew_Ger_TreasureMap_03_C", name: "Crew Ger Treasure Map 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Ger_TreasureMap_04.Challenge_Crew_Ger_TreasureMap_04_C", name: "Crew Ger Treasure Map 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Ger_TreasureMap_05.Challenge_Crew_Ger_TreasureMap_05_C", name: "Crew Ger Treasure Map 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Ger_TreasureMap_06.Challenge_Crew_Ger_TreasureMap_06_C", name: "Crew Ger Treasure Map 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Geranium_TreasureMap_Open.Challenge_Crew_Geranium_TreasureMap_Open_C", name: "Crew Geranium Treasure Map Open" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/TreasureMap/Challenge_Crew_Geranium_TreasureMap_Unlock.Challenge_Crew_Geranium_TreasureMap_Unlock_C", name: "Crew Geranium Treasure Map Unlock" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/CraterBoss/Challenge_Discovery_CraterBoss_01.Challenge_Discovery_CraterBoss_01_C", name: "Discovery Crater Boss 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/CraterBoss/Challenge_Discovery_CraterBoss_BirthOfRuin.Challenge_Discovery_CraterBoss_BirthOfRuin_C", name: "Discovery Crater Boss Birth Of Ruin" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_01.Challenge_Discovery_Facility_01_C", name: "Discovery Facility 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_AccountsUnpayable.Challenge_Discovery_Facility_AccountsUnpayable_C", name: "Discovery Facility Accounts Unpayable" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_InvestorPresentationRoom.Challenge_Discovery_Facility_InvestorPresentationRoom_C", name: "Discovery Facility Investor Presentation Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_MaterialsTransport.Challenge_Discovery_Facility_MaterialsTransport_C", name: "Discovery Facility Materials Transport" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_ProjectEndlessHorizons.Challenge_Discovery_Facility_ProjectEndlessHorizons_C", name: "Discovery Facility Project Endless Horizons" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_TalbotsFootsteps.Challenge_Discovery_Facility_TalbotsFootsteps_C", name: "Discovery Facility Talbots Footsteps" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_TheRedolentResidence.Challenge_Discovery_Facility_TheRedolentResidence_C", name: "Discovery Facility The Redolent Residence" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Facility/Challenge_Discovery_Facility_TopsideAccessWest.Challenge_Discovery_Facility_TopsideAccessWest_C", name: "Discovery Facility Topside Access West" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Forest/Challenge_Discovery_Forest_01.Challenge_Discovery_Forest_01_C", name: "Discovery Forest 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Forest/Challenge_Discovery_Forest_CronesContentment.Challenge_Discovery_Forest_CronesContentment_C", name: "Discovery Forest Crones Contentment" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Forest/Challenge_Discovery_Forest_TallywayStation.Challenge_Discovery_Forest_TallywayStation_C", name: "Discovery Forest Tallyway Station" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Forest/Challenge_Discovery_Forest_TheLastDig.Challenge_Discovery_Forest_TheLastDig_C", name: "Discovery Forest The Last Dig" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Forest/Challenge_Discovery_Forest_TheShrapnel.Challenge_Discovery_Forest_TheShrapnel_C", name: "Discovery Forest The Shrapnel" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_01.Challenge_Discovery_Frontier_01_C", name: "Discovery Frontier 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_AmplecoreVeins.Challenge_Discovery_Frontier_AmplecoreVeins_C", name: "Discovery Frontier Amplecore Veins" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_CinderwatchStation.Challenge_Discovery_Frontier_CinderwatchStation_C", name: "Discovery Frontier Cinderwatch Station" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_FortKickwater.Challenge_Discovery_Frontier_FortKickwater_C", name: "Discovery Frontier Fort Kickwater" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_FrackMesa.Challenge_Discovery_Frontier_FrackMesa_C", name: "Discovery Frontier Frack Mesa" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_HinasHacienda.Challenge_Discovery_Frontier_HinasHacienda_C", name: "Discovery Frontier Hinas Hacienda" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_KingsTeeth.Challenge_Discovery_Frontier_KingsTeeth_C", name: "Discovery Frontier Kings Teeth" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_SaurdewValleyRanch.Challenge_Discovery_Frontier_SaurdewValleyRanch_C", name: "Discovery Frontier Saurdew Valley Ranch" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Frontier/Challenge_Discovery_Frontier_TheHastyRetreat.Challenge_Discovery_Frontier_TheHastyRetreat_C", name: "Discovery Frontier The Hasty Retreat" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_01.Challenge_Discovery_Lodge_01_C", name: "Discovery Lodge 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_Babelbrook.Challenge_Discovery_Lodge_Babelbrook_C", name: "Discovery Lodge Babelbrook" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_CalderaStronghold.Challenge_Discovery_Lodge_CalderaStronghold_C", name: "Discovery Lodge Caldera Stronghold" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_HailHeroPass.Challenge_Discovery_Lodge_HailHeroPass_C", name: "Discovery Lodge Hail Hero Pass" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_KormashTheDecapitatorsResortAndSpa.Challenge_Discovery_Lodge_KormashTheDecapitatorsResortAndSpa_C", name: "Discovery Lodge Kormash The Decapitators Resort And Spa" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_Pilfertown.Challenge_Discovery_Lodge_Pilfertown_C", name: "Discovery Lodge Pilfertown" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_RosesPrivateAlcove.Challenge_Discovery_Lodge_RosesPrivateAlcove_C", name: "Discovery Lodge Roses Private Alcove" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_TheDevilsGreatroom.Challenge_Discovery_Lodge_TheDevilsGreatroom_C", name: "Discovery Lodge The Devils Greatroom" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_TheScaldingScrub.Challenge_Discovery_Lodge_TheScaldingScrub_C", name: "Discovery Lodge The Scalding Scrub" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Lodge/Challenge_Discovery_Lodge_TheTopOfAllThings.Challenge_Discovery_Lodge_TheTopOfAllThings_C", name: "Discovery Lodge The Top Of All Things" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_01.Challenge_Discovery_Town_01_C", name: "Discovery Town 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_BlackstonePlaza.Challenge_Discovery_Town_BlackstonePlaza_C", name: "Discovery Town Blackstone Plaza" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_CoxTheatre.Challenge_Discovery_Town_CoxTheatre_C", name: "Discovery Town Cox Theatre" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_FirstBankOfVestige.Challenge_Discovery_Town_FirstBankOfVestige_C", name: "Discovery Town First Bank Of Vestige" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_GatherdayMarket.Challenge_Discovery_Town_GatherdayMarket_C", name: "Discovery Town Gatherday Market" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_NondeSaloon.Challenge_Discovery_Town_NondeSaloon_C", name: "Discovery Town Nonde Saloon" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_PenitentsWatch.Challenge_Discovery_Town_PenitentsWatch_C", name: "Discovery Town Penitents Watch" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Discovery/Town/Challenge_Discovery_Town_SheriffsOffice.Challenge_Discovery_Town_SheriffsOffice_C", name: "Discovery Town Sheriffs Office" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium.Challenge_EchoLog_NonMission_Geranium_C", name: "Echo Log Non Mission Geranium" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Facility01.Challenge_EchoLog_NonMission_Geranium_Facility01_C", name: "Echo Log Non Mission Geranium Facility 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Facility02.Challenge_EchoLog_NonMission_Geranium_Facility02_C", name: "Echo Log Non Mission Geranium Facility 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Facility03.Challenge_EchoLog_NonMission_Geranium_Facility03_C", name: "Echo Log Non Mission Geranium Facility 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Forest01.Challenge_EchoLog_NonMission_Geranium_Forest01_C", name: "Echo Log Non Mission Geranium Forest 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Forest02.Challenge_EchoLog_NonMission_Geranium_Forest02_C", name: "Echo Log Non Mission Geranium Forest 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Forest03.Challenge_EchoLog_NonMission_Geranium_Forest03_C", name: "Echo Log Non Mission Geranium Forest 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier01.Challenge_EchoLog_NonMission_Geranium_Frontier01_C", name: "Echo Log Non Mission Geranium Frontier 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier02.Challenge_EchoLog_NonMission_Geranium_Frontier02_C", name: "Echo Log Non Mission Geranium Frontier 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier03.Challenge_EchoLog_NonMission_Geranium_Frontier03_C", name: "Echo Log Non Mission Geranium Frontier 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier04.Challenge_EchoLog_NonMission_Geranium_Frontier04_C", name: "Echo Log Non Mission Geranium Frontier 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier05.Challenge_EchoLog_NonMission_Geranium_Frontier05_C", name: "Echo Log Non Mission Geranium Frontier 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier06.Challenge_EchoLog_NonMission_Geranium_Frontier06_C", name: "Echo Log Non Mission Geranium Frontier 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier07.Challenge_EchoLog_NonMission_Geranium_Frontier07_C", name: "Echo Log Non Mission Geranium Frontier 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Frontier08.Challenge_EchoLog_NonMission_Geranium_Frontier08_C", name: "Echo Log Non Mission Geranium Frontier 08" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Lodge01.Challenge_EchoLog_NonMission_Geranium_Lodge01_C", name: "Echo Log Non Mission Geranium Lodge 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Lodge02.Challenge_EchoLog_NonMission_Geranium_Lodge02_C", name: "Echo Log Non Mission Geranium Lodge 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Lodge03.Challenge_EchoLog_NonMission_Geranium_Lodge03_C", name: "Echo Log Non Mission Geranium Lodge 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Town01.Challenge_EchoLog_NonMission_Geranium_Town01_C", name: "Echo Log Non Mission Geranium Town 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Town02.Challenge_EchoLog_NonMission_Geranium_Town02_C", name: "Echo Log Non Mission Geranium Town 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Geranium_Town03.Challenge_EchoLog_NonMission_Geranium_Town03_C", name: "Echo Log Non Mission Geranium Town 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_GhostStories.Challenge_EchoLog_NonMission_GhostStories_C", name: "Echo Log Non Mission Ghost Stories" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_GhostStories2.Challenge_EchoLog_NonMission_GhostStories2_C", name: "Echo Log Non Mission Ghost Stories 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_GhostStories3.Challenge_EchoLog_NonMission_GhostStories3_C", name: "Echo Log Non Mission Ghost Stories 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_GhostStories4.Challenge_EchoLog_NonMission_GhostStories4_C", name: "Echo Log Non Mission Ghost Stories 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_GhostStories5.Challenge_EchoLog_NonMission_GhostStories5_C", name: "Echo Log Non Mission Ghost Stories 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_GhostStories6.Challenge_EchoLog_NonMission_GhostStories6_C", name: "Echo Log Non Mission Ghost Stories 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Enemy/Challenge_Enemy_Ger_Biobeast.Challenge_Enemy_Ger_Biobeast_C", name: "Enemy Ger Biobeast" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Enemy/Challenge_Enemy_Ger_GyroTink.Challenge_Enemy_Ger_GyroTink_C", name: "Enemy Ger Gyro Tink" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Enemy/Challenge_Enemy_Ger_Pterodactyl.Challenge_Enemy_Ger_Pterodactyl_C", name: "Enemy Ger Pterodactyl" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Enemy/Challenge_Enemy_Ger_Rider.Challenge_Enemy_Ger_Rider_C", name: "Enemy Ger Rider" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Enemy/Challenge_Enemy_Ger_Slagtooth.Challenge_Enemy_Ger_Slagtooth_C", name: "Enemy Ger Slagtooth" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Enemy/Challenge_Enemy_Ger_Snake.Challenge_Enemy_Ger_Snake_C", name: "Enemy Ger Snake" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Enemy/Challenge_Enemy_HorseKills.Challenge_Enemy_HorseKills_C", name: "Enemy Horse Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_CraterBoss_01.Challenge_FastTravel_CraterBoss_01_C", name: "Fast Travel Crater Boss 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_CraterBoss_Boss.Challenge_FastTravel_CraterBoss_Boss_C", name: "Fast Travel Crater Boss Boss" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Facility_01.Challenge_FastTravel_Facility_01_C", name: "Fast Travel Facility 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Facility_Mid.Challenge_FastTravel_Facility_Mid_C", name: "Fast Travel Facility Mid" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Facility_SendOnly.Challenge_FastTravel_Facility_SendOnly_C", name: "Fast Travel Facility Send Only" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Forest_01.Challenge_FastTravel_Forest_01_C", name: "Fast Travel Forest 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Forest_Mid.Challenge_FastTravel_Forest_Mid_C", name: "Fast Travel Forest Mid" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Frontier_01.Challenge_FastTravel_Frontier_01_C", name: "Fast Travel Frontier 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Frontier_Fort.Challenge_FastTravel_Frontier_Fort_C", name: "Fast Travel Frontier Fort" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Frontier_GasStation.Challenge_FastTravel_Frontier_GasStation_C", name: "Fast Travel Frontier Gas Station" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Lodge_01.Challenge_FastTravel_Lodge_01_C", name: "Fast Travel Lodge 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Lodge_Boss.Challenge_FastTravel_Lodge_Boss_C", name: "Fast Travel Lodge Boss" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Lodge_Mid.Challenge_FastTravel_Lodge_Mid_C", name: "Fast Travel Lodge Mid" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Town_01.Challenge_FastTravel_Town_01_C", name: "Fast Travel Town 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/IO/Challenge_IO_EnemiesCharmed.Challenge_IO_EnemiesCharmed_C", name: "IO Enemies Charmed" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/IO/Challenge_IO_RocksploderKills.Challenge_IO_RocksploderKills_C", name: "IO Rocksploder Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Plot_Ger_01_WestlandWelcome.Challenge_Mission_Plot_Ger_01_WestlandWelcome_C", name: "Mission Plot Ger 01 Westland Welcome" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Plot_Ger_02_JakobsBathhouse.Challenge_Mission_Plot_Ger_02_JakobsBathhouse_C", name: "Mission Plot Ger 02 Jakobs Bathhouse" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Plot_Ger_03_ObsidianForest.Challenge_Mission_Plot_Ger_03_ObsidianForest_C", name: "Mission Plot Ger 03 Obsidian Forest" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Plot_Ger_04_JakobsLaunchFacility.Challenge_Mission_Plot_Ger_04_JakobsLaunchFacility_C", name: "Mission Plot Ger 04 Jakobs Launch Facility" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Plot_Ger_05_CraterBoss.Challenge_Mission_Plot_Ger_05_CraterBoss_C", name: "Mission Plot Ger 05 Crater Boss" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Facility_GhostStories.Challenge_Mission_Side_Ger_Facility_GhostStories_C", name: "Mission Side Ger Facility Ghost Stories" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Facility_LoveBarNone.Challenge_Mission_Side_Ger_Facility_LoveBarNone_C", name: "Mission Side Ger Facility Love Bar None" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Forest_AnimalControl.Challenge_Mission_Side_Ger_Forest_AnimalControl_C", name: "Mission Side Ger Forest Animal Control" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Forest_BloodAndBeans.Challenge_Mission_Side_Ger_Forest_BloodAndBeans_C", name: "Mission Side Ger Forest Blood And Beans" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Frontier_MoneyBackGuarantee.Challenge_Mission_Side_Ger_Frontier_MoneyBackGuarantee_C", name: "Mission Side Ger Frontier Money Back Guarantee" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Frontier_SaurdewValley.Challenge_Mission_Side_Ger_Frontier_SaurdewValley_C", name: "Mission Side Ger Frontier Saurdew Valley" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Frontier_SkagOil.Challenge_Mission_Side_Ger_Frontier_SkagOil_C", name: "Mission Side Ger Frontier Skag Oil" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Lodge_DirtyDeeds.Challenge_Mission_Side_Ger_Lodge_DirtyDeeds_C", name: "Mission Side Ger Lodge Dirty Deeds" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Lodge_McSmugger.Challenge_Mission_Side_Ger_Lodge_McSmugger_C", name: "Mission Side Ger Lodge Mc Smugger" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Missions/Challenge_Mission_Side_Ger_Town_GunsAndRose.Challenge_Mission_Side_Ger_Town_GunsAndRose_C", name: "Mission Side Ger Town Guns And Rose" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Facility_01.Challenge_RedChests_Facility_01_C", name: "Red Chests Facility 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Facility_02.Challenge_RedChests_Facility_02_C", name: "Red Chests Facility 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Forest_01.Challenge_RedChests_Forest_01_C", name: "Red Chests Forest 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Forest_02.Challenge_RedChests_Forest_02_C", name: "Red Chests Forest 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Forest_03.Challenge_RedChests_Forest_03_C", name: "Red Chests Forest 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Frontier_01.Challenge_RedChests_Frontier_01_C", name: "Red Chests Frontier 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Lodge_01.Challenge_RedChests_Lodge_01_C", name: "Red Chests Lodge 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Lodge_02.Challenge_RedChests_Lodge_02_C", name: "Red Chests Lodge 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/RedChest/Challenge_RedChests_Lodge_03.Challenge_RedChests_Lodge_03_C", name: "Red Chests Lodge 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Vehicle/Challenge_VehicleKill_Horse.Challenge_VehicleKill_Horse_C", name: "Vehicle Kill Horse" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/Vehicle/Challenge_VehicleKill_HorseRamming.Challenge_VehicleKill_HorseRamming_C", name: "Vehicle Kill Horse Ramming" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/GaigeGifts/_Stat/Challenge_DLC2_Gifts_Stat_AllDone.Challenge_DLC2_Gifts_Stat_AllDone_C", name: "DLC 2 Gifts Stat All Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/GaigeGifts/Challenge_DLC2_Gifts_Archives.Challenge_DLC2_Gifts_Archives_C", name: "DLC 2 Gifts Archives" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/GaigeGifts/Challenge_DLC2_Gifts_Camp.Challenge_DLC2_Gifts_Camp_C", name: "DLC 2 Gifts Camp" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/GaigeGifts/Challenge_DLC2_Gifts_Lake.Challenge_DLC2_Gifts_Lake_C", name: "DLC 2 Gifts Lake" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/GaigeGifts/Challenge_DLC2_Gifts_Village.Challenge_DLC2_Gifts_Village_C", name: "DLC 2 Gifts Village" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/GaigeGifts/Challenge_DLC2_Gifts_Woods.Challenge_DLC2_Gifts_Woods_C", name: "DLC 2 Gifts Woods" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/HammerlockHunt/_Stat/Challenge_DLC2_Hunt_Stat_AllDone.Challenge_DLC2_Hunt_Stat_AllDone_C", name: "DLC 2 Hunt Stat All Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/HammerlockHunt/Challenge_DLC2_Hunt_Camp.Challenge_DLC2_Hunt_Camp_C", name: "DLC 2 Hunt Camp" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/HammerlockHunt/Challenge_DLC2_Hunt_Lake.Challenge_DLC2_Hunt_Lake_C", name: "DLC 2 Hunt Lake" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/HammerlockHunt/Challenge_DLC2_Hunt_Venue.Challenge_DLC2_Hunt_Venue_C", name: "DLC 2 Hunt Venue" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/HammerlockHunt/Challenge_DLC2_Hunt_Village.Challenge_DLC2_Hunt_Village_C", name: "DLC 2 Hunt Village" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/HammerlockHunt/Challenge_DLC2_Hunt_Woods.Challenge_DLC2_Hunt_Woods_C", name: "DLC 2 Hunt Woods" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/MancubusStatues/_Stat/Challenge_DLC2_Statues_Stat_AllDone.Challenge_DLC2_Statues_Stat_AllDone_C", name: "DLC 2 Statues Stat All Done" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/MancubusStatues/Challenge_DLC2_Statues_Archives.Challenge_DLC2_Statues_Archives_C", name: "DLC 2 Statues Archives" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/MancubusStatues/Challenge_DLC2_Statues_Camp.Challenge_DLC2_Statues_Camp_C", name: "DLC 2 Statues Camp" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/MancubusStatues/Challenge_DLC2_Statues_Venue.Challenge_DLC2_Statues_Venue_C", name: "DLC 2 Statues Venue" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/MancubusStatues/Challenge_DLC2_Statues_Village.Challenge_DLC2_Statues_Village_C", name: "DLC 2 Statues Village" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/CrewChallenges/MancubusStatues/Challenge_DLC2_Statues_Woods.Challenge_DLC2_Statues_Woods_C", name: "DLC 2 Statues Woods" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Archive/Challenge_Discovery_Archive_AWing.Challenge_Discovery_Archive_AWing_C", name: "Discovery Archive A Wing" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Archive/Challenge_Discovery_Archive_Holo.Challenge_Discovery_Archive_Holo_C", name: "Discovery Archive Holo" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Archive/Challenge_Discovery_Archive_Main.Challenge_Discovery_Archive_Main_C", name: "Discovery Archive Main" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Archive/Challenge_Discovery_Archive_MWing.Challenge_Discovery_Archive_MWing_C", name: "Discovery Archive M Wing" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Archive/Challenge_Discovery_Archive_Office.Challenge_Discovery_Archive_Office_C", name: "Discovery Archive Office" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Archive/Challenge_Discovery_Archive_ResRoom.Challenge_Discovery_Archive_ResRoom_C", name: "Discovery Archive Res Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Bar/Challenge_Discovery_Bar_Lodge.Challenge_Discovery_Bar_Lodge_C", name: "Discovery Bar Lodge" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Camp/Challenge_Discovery_Camp_DahlShip.Challenge_Discovery_Camp_DahlShip_C", name: "Discovery Camp Dahl Ship" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Camp/Challenge_Discovery_Camp_DahlWall.Challenge_Discovery_Camp_DahlWall_C", name: "Discovery Camp Dahl Wall" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Camp/Challenge_Discovery_Camp_EridianRuins.Challenge_Discovery_Camp_EridianRuins_C", name: "Discovery Camp Eridian Ruins" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Camp/Challenge_Discovery_Camp_Laboratory.Challenge_Discovery_Camp_Laboratory_C", name: "Discovery Camp Laboratory" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_Amourettes.Challenge_Discovery_Lake_Amourettes_C", name: "Discovery Lake Amourettes" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_CrashSite.Challenge_Discovery_Lake_CrashSite_C", name: "Discovery Lake Crash Site" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_Excavation.Challenge_Discovery_Lake_Excavation_C", name: "Discovery Lake Excavation" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_FBiteCanyon.Challenge_Discovery_Lake_FBiteCanyon_C", name: "Discovery Lake F Bite Canyon" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_FBiterCamp.Challenge_Discovery_Lake_FBiterCamp_C", name: "Discovery Lake F Biter Camp" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_FishVillage.Challenge_Discovery_Lake_FishVillage_C", name: "Discovery Lake Fish Village" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_GhostTown.Challenge_Discovery_Lake_GhostTown_C", name: "Discovery Lake Ghost Town" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_Gondola.Challenge_Discovery_Lake_Gondola_C", name: "Discovery Lake Gondola" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_Lake.Challenge_Discovery_Lake_Lake_C", name: "Discovery Lake Lake" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Lake/Challenge_Discovery_Lake_Thermal.Challenge_Discovery_Lake_Thermal_C", name: "Discovery Lake Thermal" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Venue/Challenge_Discovery_Venue_EggRoom.Challenge_Discovery_Venue_EggRoom_C", name: "Discovery Venue Egg Room" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Venue/Challenge_Discovery_Venue_Guts.Challenge_Discovery_Venue_Guts_C", name: "Discovery Venue Guts" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Venue/Challenge_Discovery_Venue_InnerLabs.Challenge_Discovery_Venue_InnerLabs_C", name: "Discovery Venue Inner Labs" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Venue/Challenge_Discovery_Venue_Lounge.Challenge_Discovery_Venue_Lounge_C", name: "Discovery Venue Lounge" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Village/Challenge_Discovery_Village_FogTown.Challenge_Discovery_Village_FogTown_C", name: "Discovery Village Fog Town" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Village/Challenge_Discovery_Village_Gardens.Challenge_Discovery_Village_Gardens_C", name: "Discovery Village Gardens" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Village/Challenge_Discovery_Village_Graveyard.Challenge_Discovery_Village_Graveyard_C", name: "Discovery Village Graveyard" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Village/Challenge_Discovery_Village_Pit.Challenge_Discovery_Village_Pit_C", name: "Discovery Village Pit" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Village/Challenge_Discovery_Village_Square.Challenge_Discovery_Village_Square_C", name: "Discovery Village Square" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Woods/Challenge_Discovery_Woods_Brewery.Challenge_Discovery_Woods_Brewery_C", name: "Discovery Woods Brewery" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Woods/Challenge_Discovery_Woods_Cabin.Challenge_Discovery_Woods_Cabin_C", name: "Discovery Woods Cabin" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Woods/Challenge_Discovery_Woods_MushVillage.Challenge_Discovery_Woods_MushVillage_C", name: "Discovery Woods Mush Village" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Discovery/Woods/Challenge_Discovery_Woods_Wendigo.Challenge_Discovery_Woods_Wendigo_C", name: "Discovery Woods Wendigo" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Archives1.Challenge_DLC2_EchoLog_Archives1_C", name: "DLC 2 Echo Log Archives 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Archives2.Challenge_DLC2_EchoLog_Archives2_C", name: "DLC 2 Echo Log Archives 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Bar1.Challenge_DLC2_EchoLog_Bar1_C", name: "DLC 2 Echo Log Bar 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Bar2.Challenge_DLC2_EchoLog_Bar2_C", name: "DLC 2 Echo Log Bar 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Camp1.Challenge_DLC2_EchoLog_Camp1_C", name: "DLC 2 Echo Log Camp 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Camp2.Challenge_DLC2_EchoLog_Camp2_C", name: "DLC 2 Echo Log Camp 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Camp3.Challenge_DLC2_EchoLog_Camp3_C", name: "DLC 2 Echo Log Camp 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Camp4.Challenge_DLC2_EchoLog_Camp4_C", name: "DLC 2 Echo Log Camp 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Lake1.Challenge_DLC2_EchoLog_Lake1_C", name: "DLC 2 Echo Log Lake 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Lake2.Challenge_DLC2_EchoLog_Lake2_C", name: "DLC 2 Echo Log Lake 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Venue1.Challenge_DLC2_EchoLog_Venue1_C", name: "DLC 2 Echo Log Venue 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Venue2.Challenge_DLC2_EchoLog_Venue2_C", name: "DLC 2 Echo Log Venue 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Venue3.Challenge_DLC2_EchoLog_Venue3_C", name: "DLC 2 Echo Log Venue 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Venue4.Challenge_DLC2_EchoLog_Venue4_C", name: "DLC 2 Echo Log Venue 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Village1.Challenge_DLC2_EchoLog_Village1_C", name: "DLC 2 Echo Log Village 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Village2.Challenge_DLC2_EchoLog_Village2_C", name: "DLC 2 Echo Log Village 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Woods1.Challenge_DLC2_EchoLog_Woods1_C", name: "DLC 2 Echo Log Woods 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/EchoLogs/Challenge_DLC2_EchoLog_Woods2.Challenge_DLC2_EchoLog_Woods2_C", name: "DLC 2 Echo Log Woods 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Enemy/Challenge_Enemy_Bonded.Challenge_Enemy_Bonded_C", name: "Enemy Bonded" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Enemy/Challenge_Enemy_Frostbiters.Challenge_Enemy_Frostbiters_C", name: "Enemy Frostbiters" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Enemy/Challenge_Enemy_Kriches.Challenge_Enemy_Kriches_C", name: "Enemy Kriches" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Enemy/Challenge_Enemy_Wolven.Challenge_Enemy_Wolven_C", name: "Enemy Wolven" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Archive/Challenge_FastTravel_Archive1.Challenge_FastTravel_Archive1_C", name: "Fast Travel Archive 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Bar/Challenge_FastTravel_Bar1.Challenge_FastTravel_Bar1_C", name: "Fast Travel Bar 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Camp/Challenge_FastTravel_Camp1.Challenge_FastTravel_Camp1_C", name: "Fast Travel Camp 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Lake/Challenge_FastTravel_Lake1.Challenge_FastTravel_Lake1_C", name: "Fast Travel Lake 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Lake/Challenge_FastTravel_Lake2.Challenge_FastTravel_Lake2_C", name: "Fast Travel Lake 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Lake/Challenge_FastTravel_Lake3.Challenge_FastTravel_Lake3_C", name: "Fast Travel Lake 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Venue/Challenge_FastTravel_Venue1.Challenge_FastTravel_Venue1_C", name: "Fast Travel Venue 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Venue/Challenge_FastTravel_Venue2.Challenge_FastTravel_Venue2_C", name: "Fast Travel Venue 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village1.Challenge_FastTravel_Village1_C", name: "Fast Travel Village 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village2.Challenge_FastTravel_Village2_C", name: "Fast Travel Village 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village3.Challenge_FastTravel_Village3_C", name: "Fast Travel Village 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village4.Challenge_FastTravel_Village4_C", name: "Fast Travel Village 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Woods/Challenge_FastTravel_Woods1.Challenge_FastTravel_Woods1_C", name: "Fast Travel Woods 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/IO/Challenge_IO_CursehavenShutIns.Challenge_IO_CursehavenShutIns_C", name: "IO Cursehaven Shut Ins" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/IO/Challenge_IO_MancubitesPet.Challenge_IO_MancubitesPet_C", name: "IO Mancubites Pet" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC2_EP01.Challenge_Mission_Plot_DLC2_EP01_C", name: "Mission Plot DLC 2 EP 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC2_EP02.Challenge_Mission_Plot_DLC2_EP02_C", name: "Mission Plot DLC 2 EP 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC2_EP03.Challenge_Mission_Plot_DLC2_EP03_C", name: "Mission Plot DLC 2 EP 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC2_EP04.Challenge_Mission_Plot_DLC2_EP04_C", name: "Mission Plot DLC 2 EP 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC2_EP05.Challenge_Mission_Plot_DLC2_EP05_C", name: "Mission Plot DLC 2 EP 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Plot/Challenge_Mission_Plot_DLC2_EP06.Challenge_Mission_Plot_DLC2_EP06_C", name: "Mission Plot DLC 2 EP 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_BusinessAsUsual.Challenge_Mission_Side_DLC2_BusinessAsUsual_C", name: "Mission Side DLC 2 Business As Usual" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_DebtCollector.Challenge_Mission_Side_DLC2_DebtCollector_C", name: "Mission Side DLC 2 Debt Collector" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_HappilyEverAfter.Challenge_Mission_Side_DLC2_HappilyEverAfter_C", name: "Mission Side DLC 2 Happily Ever After" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_IntoTheDeep.Challenge_Mission_Side_DLC2_IntoTheDeep_C", name: "Mission Side DLC 2 Into The Deep" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_MaxSkyReturn.Challenge_Mission_Side_DLC2_MaxSkyReturn_C", name: "Mission Side DLC 2 Max Sky Return" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_Necrocookmicon.Challenge_Mission_Side_DLC2_Necrocookmicon_C", name: "Mission Side DLC 2 Necrocookmicon" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_PrivateEye_01.Challenge_Mission_Side_DLC2_PrivateEye_01_C", name: "Mission Side DLC 2 Private Eye 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_PrivateEye_02.Challenge_Mission_Side_DLC2_PrivateEye_02_C", name: "Mission Side DLC 2 Private Eye 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_PrivateEye_03.Challenge_Mission_Side_DLC2_PrivateEye_03_C", name: "Mission Side DLC 2 Private Eye 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_SinisterSounds.Challenge_Mission_Side_DLC2_SinisterSounds_C", name: "Mission Side DLC 2 Sinister Sounds" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_WeSlass_01.Challenge_Mission_Side_DLC2_WeSlass_01_C", name: "Mission Side DLC 2 We Slass 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_WeSlass_02.Challenge_Mission_Side_DLC2_WeSlass_02_C", name: "Mission Side DLC 2 We Slass 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_WeSlass_03.Challenge_Mission_Side_DLC2_WeSlass_03_C", name: "Mission Side DLC 2 We Slass 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_WhereIBelong.Challenge_Mission_Side_DLC2_WhereIBelong_C", name: "Mission Side DLC 2 Where I Belong" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/Challenge_Mission_Side_DLC2_WhereIBelong_LakeMail.Challenge_Mission_Side_DLC2_WhereIBelong_LakeMail_C", name: "Mission Side DLC 2 Where I Belong Lake Mail" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_DLC2_Rare_1.Challenge_Mission_Side_DLC2_Rare_1_C", name: "Mission Side DLC 2 Rare 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_DLC2_Rare_2.Challenge_Mission_Side_DLC2_Rare_2_C", name: "Mission Side DLC 2 Rare 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_DLC2_Rare_3.Challenge_Mission_Side_DLC2_Rare_3_C", name: "Mission Side DLC 2 Rare 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/Missions/Side/RareSpawn/Challenge_Mission_Side_DLC2_Rare_4.Challenge_Mission_Side_DLC2_Rare_4_C", name: "Mission Side DLC 2 Rare 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Archive/Challenge_RedChests_Archive1.Challenge_RedChests_Archive1_C", name: "Red Chests Archive 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Archive/Challenge_RedChests_Archive2.Challenge_RedChests_Archive2_C", name: "Red Chests Archive 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Camp/Challenge_RedChests_Camp1.Challenge_RedChests_Camp1_C", name: "Red Chests Camp 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Camp/Challenge_RedChests_Camp2.Challenge_RedChests_Camp2_C", name: "Red Chests Camp 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Lake/Challenge_RedChests_Lake1.Challenge_RedChests_Lake1_C", name: "Red Chests Lake 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Lake/Challenge_RedChests_Lake2.Challenge_RedChests_Lake2_C", name: "Red Chests Lake 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Venue/Challenge_RedChests_Venue1.Challenge_RedChests_Venue1_C", name: "Red Chests Venue 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Venue/Challenge_RedChests_Venue2.Challenge_RedChests_Venue2_C", name: "Red Chests Venue 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Village/Challenge_RedChests_Village1.Challenge_RedChests_Village1_C", name: "Red Chests Village 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Village/Challenge_RedChests_Village2.Challenge_RedChests_Village2_C", name: "Red Chests Village 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Woods/Challenge_RedChests_Woods1.Challenge_RedChests_Woods1_C", name: "Red Chests Woods 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/Challenges/RedChests/Woods/Challenge_RedChests_Woods2.Challenge_RedChests_Woods2_C", name: "Red Chests Woods 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_53_DLC2.BP_Challenge_Console_53_DLC2_C", name: "Console 53 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_54_DLC2.BP_Challenge_Console_54_DLC2_C", name: "Console 54 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_55_DLC2.BP_Challenge_Console_55_DLC2_C", name: "Console 55 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_56_DLC2.BP_Challenge_Console_56_DLC2_C", name: "Console 56 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_57_DLC2.BP_Challenge_Console_57_DLC2_C", name: "Console 57 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_58_DLC2.BP_Challenge_Console_58_DLC2_C", name: "Console 58 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_59_DLC2.BP_Challenge_Console_59_DLC2_C", name: "Console 59 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/BP_Challenge_Console_60_DLC2.BP_Challenge_Console_60_DLC2_C", name: "Console 60 DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Achievements/BP_Challenge_Ixora_Console_76.BP_Challenge_Ixora_Console_76_C", name: "Ixora Console 76" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Achievements/BP_Challenge_Ixora_Console_77.BP_Challenge_Ixora_Console_77_C", name: "Ixora Console 77" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Achievements/BP_Challenge_Ixora_Console_78.BP_Challenge_Ixora_Console_78_C", name: "Ixora Console 78" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area01.DiscoveryChallege_Ixora_Area01_C", name: "Discovery Challege Ixora Area 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area02.DiscoveryChallege_Ixora_Area02_C", name: "Discovery Challege Ixora Area 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area03.DiscoveryChallege_Ixora_Area03_C", name: "Discovery Challege Ixora Area 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area04.DiscoveryChallege_Ixora_Area04_C", name: "Discovery Challege Ixora Area 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area05.DiscoveryChallege_Ixora_Area05_C", name: "Discovery Challege Ixora Area 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area06.DiscoveryChallege_Ixora_Area06_C", name: "Discovery Challege Ixora Area 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area07.DiscoveryChallege_Ixora_Area07_C", name: "Discovery Challege Ixora Area 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area08.DiscoveryChallege_Ixora_Area08_C", name: "Discovery Challege Ixora Area 08" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area09.DiscoveryChallege_Ixora_Area09_C", name: "Discovery Challege Ixora Area 09" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area10.DiscoveryChallege_Ixora_Area10_C", name: "Discovery Challege Ixora Area 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area11.DiscoveryChallege_Ixora_Area11_C", name: "Discovery Challege Ixora Area 11" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area12.DiscoveryChallege_Ixora_Area12_C", name: "Discovery Challege Ixora Area 12" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area13.DiscoveryChallege_Ixora_Area13_C", name: "Discovery Challege Ixora Area 13" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area14.DiscoveryChallege_Ixora_Area14_C", name: "Discovery Challege Ixora Area 14" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area15.DiscoveryChallege_Ixora_Area15_C", name: "Discovery Challege Ixora Area 15" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallege_Ixora_Area18.DiscoveryChallege_Ixora_Area18_C", name: "Discovery Challege Ixora Area 18" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora_Area16.DiscoveryChallenge_Ixora_Area16_C", name: "Discovery Challenge Ixora Area 16" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora_Area17.DiscoveryChallenge_Ixora_Area17_C", name: "Discovery Challenge Ixora Area 17" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora_Area19.DiscoveryChallenge_Ixora_Area19_C", name: "Discovery Challenge Ixora Area 19" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora_Area20.DiscoveryChallenge_Ixora_Area20_C", name: "Discovery Challenge Ixora Area 20" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Map/BP_Challenge_Ixora_Map_100Ext.BP_Challenge_Ixora_Map_100Ext_C", name: "Ixora Map 100Ext" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Map/BP_Challenge_Ixora_Map_AllTreasureRooms.BP_Challenge_Ixora_Map_AllTreasureRooms_C", name: "Ixora Map All Treasure Rooms" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Map/BP_Challenge_Ixora_Map_Explored.BP_Challenge_Ixora_Map_Explored_C", name: "Ixora Map Explored" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Map/BP_Challenge_Ixora_Map_Plunderdome.BP_Challenge_Ixora_Map_Plunderdome_C", name: "Ixora Map Plunderdome" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Map/BP_Challenge_Ixora_Map_Sewers.BP_Challenge_Ixora_Map_Sewers_C", name: "Ixora Map Sewers" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Map/BP_Challenge_Ixora_Map_Shipping.BP_Challenge_Ixora_Map_Shipping_C", name: "Ixora Map Shipping" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Challenges/Missions/ChallengeMission_Ixora_Frostsite.ChallengeMission_Ixora_Frostsite_C", name: "Mission Ixora Frostsite" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/ChallengeIxora2_DefeatHemovorous.ChallengeIxora2_DefeatHemovorous_C", name: "Ixora 2 Defeat Hemovorous" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/ChallengeIxora2_HiddenEridium.ChallengeIxora2_HiddenEridium_C", name: "Ixora 2 Hidden Eridium" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/ChallengeIxora2_HiddenEridium_FirstParty.ChallengeIxora2_HiddenEridium_FirstParty_C", name: "Ixora 2 Hidden Eridium First Party" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/ChallengeIxora2Mission_AvaPodcast.ChallengeIxora2Mission_AvaPodcast_C", name: "Ixora 2Mission Ava Podcast" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Cabin1.DiscoveryChallenge_Ixora2_Cabin1_C", name: "Discovery Challenge Ixora 2 Cabin 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Cabin2.DiscoveryChallenge_Ixora2_Cabin2_C", name: "Discovery Challenge Ixora 2 Cabin 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Cabin3.DiscoveryChallenge_Ixora2_Cabin3_C", name: "Discovery Challenge Ixora 2 Cabin 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Cabin4.DiscoveryChallenge_Ixora2_Cabin4_C", name: "Discovery Challenge Ixora 2 Cabin 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Cabin5.DiscoveryChallenge_Ixora2_Cabin5_C", name: "Discovery Challenge Ixora 2 Cabin 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Cabin6.DiscoveryChallenge_Ixora2_Cabin6_C", name: "Discovery Challenge Ixora 2 Cabin 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Nekro1.DiscoveryChallenge_Ixora2_Nekro1_C", name: "Discovery Challenge Ixora 2 Nekro 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Nekro2.DiscoveryChallenge_Ixora2_Nekro2_C", name: "Discovery Challenge Ixora 2 Nekro 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Nekro3.DiscoveryChallenge_Ixora2_Nekro3_C", name: "Discovery Challenge Ixora 2 Nekro 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Nekro4.DiscoveryChallenge_Ixora2_Nekro4_C", name: "Discovery Challenge Ixora 2 Nekro 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Nekro5.DiscoveryChallenge_Ixora2_Nekro5_C", name: "Discovery Challenge Ixora 2 Nekro 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Noir1.DiscoveryChallenge_Ixora2_Noir1_C", name: "Discovery Challenge Ixora 2 Noir 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Noir2.DiscoveryChallenge_Ixora2_Noir2_C", name: "Discovery Challenge Ixora 2 Noir 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Noir3.DiscoveryChallenge_Ixora2_Noir3_C", name: "Discovery Challenge Ixora 2 Noir 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Noir4.DiscoveryChallenge_Ixora2_Noir4_C", name: "Discovery Challenge Ixora 2 Noir 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Pandora1.DiscoveryChallenge_Ixora2_Pandora1_C", name: "Discovery Challenge Ixora 2 Pandora 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Pandora2.DiscoveryChallenge_Ixora2_Pandora2_C", name: "Discovery Challenge Ixora 2 Pandora 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Pandora3.DiscoveryChallenge_Ixora2_Pandora3_C", name: "Discovery Challenge Ixora 2 Pandora 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Pandora4.DiscoveryChallenge_Ixora2_Pandora4_C", name: "Discovery Challenge Ixora 2 Pandora 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_Pandora5.DiscoveryChallenge_Ixora2_Pandora5_C", name: "Discovery Challenge Ixora 2 Pandora 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_SacrificeBoss1.DiscoveryChallenge_Ixora2_SacrificeBoss1_C", name: "Discovery Challenge Ixora 2 Sacrifice Boss 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora2/GameData/Challenges/Discovery/DiscoveryChallenge_Ixora2_SacrificeBoss2.DiscoveryChallenge_Ixora2_SacrificeBoss2_C", name: "Discovery Challenge Ixora 2 Sacrifice Boss 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area01.Challenge_Discovery_MTD_Area01_C", name: "Discovery MTD Area 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area02.Challenge_Discovery_MTD_Area02_C", name: "Discovery MTD Area 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area03.Challenge_Discovery_MTD_Area03_C", name: "Discovery MTD Area 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area04.Challenge_Discovery_MTD_Area04_C", name: "Discovery MTD Area 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area05.Challenge_Discovery_MTD_Area05_C", name: "Discovery MTD Area 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area06.Challenge_Discovery_MTD_Area06_C", name: "Discovery MTD Area 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area07.Challenge_Discovery_MTD_Area07_C", name: "Discovery MTD Area 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area08.Challenge_Discovery_MTD_Area08_C", name: "Discovery MTD Area 08" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area09.Challenge_Discovery_MTD_Area09_C", name: "Discovery MTD Area 09" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area10.Challenge_Discovery_MTD_Area10_C", name: "Discovery MTD Area 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Discovery/MaliwanTakedown/Challenge_Discovery_MTD_Area11.Challenge_Discovery_MTD_Area11_C", name: "Discovery MTD Area 11" },
+    GameDataKv { ident: "/Game/PatchDLC/Raid1/GameData/Challenges/Missions/Challenge_Mission_Raid1_Takedown.Challenge_Mission_Raid1_Takedown_C", name: "Mission Raid 1 Takedown" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area1.Challenge_Discovery_TD2_Area1_C", name: "Discovery TD 2 Area 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area10.Challenge_Discovery_TD2_Area10_C", name: "Discovery TD 2 Area 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area11.Challenge_Discovery_TD2_Area11_C", name: "Discovery TD 2 Area 11" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area12.Challenge_Discovery_TD2_Area12_C", name: "Discovery TD 2 Area 12" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area2.Challenge_Discovery_TD2_Area2_C", name: "Discovery TD 2 Area 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area3.Challenge_Discovery_TD2_Area3_C", name: "Discovery TD 2 Area 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area4.Challenge_Discovery_TD2_Area4_C", name: "Discovery TD 2 Area 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area5.Challenge_Discovery_TD2_Area5_C", name: "Discovery TD 2 Area 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area6.Challenge_Discovery_TD2_Area6_C", name: "Discovery TD 2 Area 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area7.Challenge_Discovery_TD2_Area7_C", name: "Discovery TD 2 Area 7" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area8.Challenge_Discovery_TD2_Area8_C", name: "Discovery TD 2 Area 8" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Discovery/Challenge_Discovery_TD2_Area9.Challenge_Discovery_TD2_Area9_C", name: "Discovery TD 2 Area 9" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/ChallengeLists/Missions/Challenge_Mission_Takedown2.Challenge_Mission_Takedown2_C", name: "Mission Takedown 2" },
+    GameDataKv { ident: "/Game/PatchDLC/VaultCard/Challenges/Daily/VC1/VC1_DailyChallenge_KillNogs15.VC1_DailyChallenge_KillNogs15_C", name: "VC 1 Daily Challenge Kill Nogs 15" },
+];
+
+pub const GAME_STATS: [GameDataKv; 284] = [
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Stats/Stat_BloodyHarvest_LeagueLootCollected.Stat_BloodyHarvest_LeagueLootCollected", name: "Bloody Harvest League Loot Collected" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Stats/Stat_BloodyHarvest_LeagueWeaponKills.Stat_BloodyHarvest_LeagueWeaponKills", name: "Bloody Harvest League Weapon Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_CaptainHaunt.Stat_Kill_CaptainHaunt", name: "Captain Haunt Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Ghosts.Stat_Kill_Ghosts", name: "Ghosts Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Ghosts_Loot.Stat_Kill_Ghosts_Loot", name: "Ghosts Loot Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Ghosts_Mayhem.Stat_Kill_Ghosts_Mayhem", name: "Ghosts Mayhem Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Collect_DLC1.Stat_ChallengeIntro_Collect_DLC1", name: "Challenge Intro Collect DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Sabotage_DLC1.Stat_ChallengeIntro_Sabotage_DLC1", name: "Challenge Intro Sabotage DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_TorgueCollect_DLC1.Stat_GameSystem_TorgueCollect_DLC1", name: "Torgue Collect DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/InteractiveObjects/Stat_IO_BlackjackChestsOpened.Stat_IO_BlackjackChestsOpened", name: "IO Blackjack Chests Opened" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/InteractiveObjects/Stat_IO_SlotMachinesOpened.Stat_IO_SlotMachinesOpened", name: "IO Slot Machines Opened" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_Constructor.Stat_Kill_Constructor", name: "Constructor Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_Loader.Stat_Kill_Loader", name: "Loader Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_SlotMachine.Stat_Kill_SlotMachine", name: "Slot Machine Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_Surveyor.Stat_Kill_Surveyor", name: "Surveyor Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Looters/Stat_Kill_Army_Looters.Stat_Kill_Army_Looters", name: "Army Looters Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Neutral/Stat_Kill_CasinoBots.Stat_Kill_CasinoBots", name: "Casino Bots Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Neutral/Stat_Kill_FeralClaptrap.Stat_Kill_FeralClaptrap", name: "Feral Claptrap Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_MauriceSideMission_GiftPicked.Stat_MauriceSideMission_GiftPicked", name: "Maurice Side Mission Gift Picked" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_BossTrapDamageCheck.Stat_Season02_BossTrapDamageCheck", name: "Season 02 Boss Trap Damage Check" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_Cartel_ID.Stat_Season02_Cartel_ID", name: "Season 02 Cartel ID" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsBossKillCount.Stat_Season02_CartelsBossKillCount", name: "Season 02 Cartels Boss Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsBossKillCount_Mayhem.Stat_Season02_CartelsBossKillCount_Mayhem", name: "Season 02 Cartels Boss Kill Count Mayhem" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsEnemiesKillCount.Stat_Season02_CartelsEnemiesKillCount", name: "Season 02 Cartels Enemies Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsUnderbossKillCount.Stat_Season02_CartelsUnderbossKillCount", name: "Season 02 Cartels Underboss Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsUnderbossKillCount_Mayhem.Stat_Season02_CartelsUnderbossKillCount_Mayhem", name: "Season 02 Cartels Underboss Kill Count Mayhem" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_EridiumDustPiles.Stat_Season02_EridiumDustPiles", name: "Season 02 Eridium Dust Piles" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_FrancoFirewallSpecialKillCount.Stat_Season02_FrancoFirewallSpecialKillCount", name: "Season 02 Franco Firewall Special Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_HasPlayerDied.Stat_Season02_HasPlayerDied", name: "Season 2 Has Player Died" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_Pupsicles.Stat_Season02_Pupsicles", name: "Season 02 Pupsicles" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_RandKeySpawn.Stat_Season02_RandKeySpawn", name: "Season 02 Rand Key Spawn" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_ShootMeat.Stat_Season02_ShootMeat", name: "Season 02 Shoot Meat" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_ShootTiny.Stat_Season02_ShootTiny", name: "Season 02 Shoot Tiny" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_TenderizerSpecialKillCount.Stat_Season02_TenderizerSpecialKillCount", name: "Season 02 Tenderizer Special Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Stats/Stat_ValentinesDay_HeartsBroken.Stat_ValentinesDay_HeartsBroken", name: "Valentines Day Hearts Broken" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_01.Stat_Trophy_Cemetery_01", name: "Trophy Cemetery 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_02.Stat_Trophy_Cemetery_02", name: "Trophy Cemetery 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_03.Stat_Trophy_Cemetery_03", name: "Trophy Cemetery 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_04.Stat_Trophy_Cemetery_04", name: "Trophy Cemetery 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_05.Stat_Trophy_Cemetery_05", name: "Trophy Cemetery 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_06.Stat_Trophy_Cemetery_06", name: "Trophy Cemetery 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_07.Stat_Trophy_Cemetery_07", name: "Trophy Cemetery 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_09.Stat_Trophy_Cemetery_09", name: "Trophy Cemetery 09" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_10.Stat_Trophy_Cemetery_10", name: "Trophy Cemetery 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_Challenge_JakobsJournalsFound.Stat_Challenge_JakobsJournalsFound", name: "Challenge Jakobs Journals Found" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_CemeteryOpportunity.Stat_ChallengeIntro_CemeteryOpportunity", name: "Challenge Intro Cemetery Opportunity" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_CreatureFeature.Stat_ChallengeIntro_CreatureFeature", name: "Challenge Intro Creature Feature" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_EdenJournals.Stat_ChallengeIntro_EdenJournals", name: "Challenge Intro Eden Journals" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_Tannery.Stat_ChallengeIntro_Tannery", name: "Challenge Intro Tannery" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_Treasure.Stat_ChallengeIntro_Treasure", name: "Challenge Intro Treasure" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_1.Stat_Trophy_TanneryPart_1", name: "Trophy Tannery Part 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_3.Stat_Trophy_TanneryPart_3", name: "Trophy Tannery Part 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_4.Stat_Trophy_TanneryPart_4", name: "Trophy Tannery Part 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_5.Stat_Trophy_TanneryPart_5", name: "Trophy Tannery Part 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_6.Stat_Trophy_TanneryPart_6", name: "Trophy Tannery Part 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_7.Stat_Trophy_TanneryPart_7", name: "Trophy Tannery Part 7" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_8.Stat_Trophy_TanneryPart_8", name: "Trophy Tannery Part 8" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_BiobeastKills.Stat_BiobeastKills", name: "Biobeast Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_EnemiesTamed.Stat_EnemiesTamed", name: "Enemies Tamed" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_EnemyHorseKills.Stat_EnemyHorseKills", name: "Enemy Horse Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_GyroTinkKills.Stat_GyroTinkKills", name: "Gyro Tink Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_PterodactylKills.Stat_PterodactylKills", name: "Pterodactyl Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_RiderKills.Stat_RiderKills", name: "Rider Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_SlagtoothKills.Stat_SlagtoothKills", name: "Slagtooth Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_SnakeKills.Stat_SnakeKills", name: "Snake Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_VehicleKills_Horse.Stat_VehicleKills_Horse", name: "Vehicle Kills Horse" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/Stat_Challenge_56_DeathtrapKill.Stat_Challenge_56_DeathtrapKill", name: "Challenge 56 Deathtrap Kill" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/Stat_Challenge_60_FishJokes.Stat_Challenge_60_FishJokes", name: "Challenge 60 Fish Jokes" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeIntro_Gifts_DLC2.Stat_ChallengeIntro_Gifts_DLC2", name: "Challenge Intro Gifts DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeIntro_Hunt_DLC2.Stat_ChallengeIntro_Hunt_DLC2", name: "Challenge Intro Hunt DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeIntro_Statues_DLC2.Stat_ChallengeIntro_Statues_DLC2", name: "Challenge Intro Statues DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeReward_Gifts_DLC2.Stat_ChallengeReward_Gifts_DLC2", name: "Challenge Reward Gifts DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeReward_Hunt_DLC2.Stat_ChallengeReward_Hunt_DLC2", name: "Challenge Reward Hunt DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeReward_Statues_DLC2.Stat_ChallengeReward_Statues_DLC2", name: "Challenge Reward Statues DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/IO/Stat_IO_CursehavenShutIns.Stat_IO_CursehavenShutIns", name: "IO Cursehaven Shut Ins" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/IO/Stat_IO_MancubitePetting.Stat_IO_MancubitePetting", name: "IO Mancubite Petting" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Bonded.Stat_Kill_Bonded", name: "Bonded Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Frostbiters.Stat_Kill_Frostbiters", name: "Frostbiters Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Kriches.Stat_Kill_Kriches", name: "Kriches Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Wolven.Stat_Kill_Wolven", name: "Wolven Killed" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_FrostbiteDragon.Stat_Async_FrostbiteDragon", name: "Async Frostbite Dragon" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_MushroomGiant.Stat_Async_MushroomGiant", name: "Async Mushroom Giant" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Shocker.Stat_Async_Shocker", name: "Async Shocker" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_ZealotPilfer.Stat_Async_ZealotPilfer", name: "Async Zealot Pilfer" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Stats/Stat_GearUpIntroMission_Discovery.Stat_GearUpIntroMission_Discovery", name: "Gear Up Intro Mission Discovery" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/GameData/NumPlaythrough_Takedown2.NumPlaythrough_Takedown2", name: "Num Playthrough Takedown 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/GameData/Stat_LilithTakedown2Intro.Stat_LilithTakedown2Intro", name: "Lilith Takedown 2Intro" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_DeadDrop.Stat_ChallengeIntro_DeadDrop", name: "Challenge Intro Dead Drop" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Hijack.Stat_ChallengeIntro_Hijack", name: "Challenge Intro Hijack" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Hunt.Stat_ChallengeIntro_Hunt", name: "Challenge Intro Hunt" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Journal.Stat_ChallengeIntro_Journal", name: "Challenge Intro Journal" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Kill.Stat_ChallengeIntro_Kill", name: "Challenge Intro Kill" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Sabotage.Stat_ChallengeIntro_Sabotage", name: "Challenge Intro Sabotage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Salvage.Stat_ChallengeIntro_Salvage", name: "Challenge Intro Salvage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeRewardCompletion_Salvage.Stat_ChallengeRewardCompletion_Salvage", name: "Challenge Reward Completion Salvage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeRewardIntro_Salvage.Stat_ChallengeRewardIntro_Salvage", name: "Challenge Reward Intro Salvage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/HuntTrophy/Stat_Challenge_HuntTrophy_Chupacabratch.Stat_Challenge_HuntTrophy_Chupacabratch", name: "Challenge Hunt Trophy Chupacabratch" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/HuntTrophy/Stat_Challenge_HuntTrophy_LCrawly.Stat_Challenge_HuntTrophy_LCrawly", name: "Challenge Hunt Trophy L Crawly" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_DominateEnemies.Stat_Character_Beastmaster_DominateEnemies", name: "Character Beastmaster Dominate Enemies" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_HulkedOutKills.Stat_Character_Beastmaster_HulkedOutKills", name: "Character Beastmaster Hulked Out Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_JabberKills.Stat_Character_Beastmaster_JabberKills", name: "Character Beastmaster Jabber Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_RakkAttackKills.Stat_Character_Beastmaster_RakkAttackKills", name: "Character Beastmaster Rakk Attack Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_SkagKills.Stat_Character_Beastmaster_SkagKills", name: "Character Beastmaster Skag Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_SpiderantKills.Stat_Character_Beastmaster_SpiderantKills", name: "Character Beastmaster Spiderant Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_GuardianRank.Stat_Character_GuardianRank", name: "Guardian Rank" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Gunner_Minigun.Stat_Character_Gunner_Minigun", name: "Character Gunner Minigun" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Level.Stat_Character_Level", name: "Character Level" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Blitz.Stat_Character_Siren_Blitz", name: "Character Siren Blitz" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Glamour.Stat_Character_Siren_Glamour", name: "Character Siren Glamour" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Phasecast.Stat_Character_Siren_Phasecast", name: "Character Siren Phasecast" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Phasegrasp.Stat_Character_Siren_Phasegrasp", name: "Character Siren Phasegrasp" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_SoulSap.Stat_Character_Siren_SoulSap", name: "Character Siren Soul Sap" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Stillness.Stat_Character_Siren_Stillness", name: "Character Siren Stillness" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageCorrosive.Stat_Combat_KillWith_DamageCorrosive", name: "Kill With Damage Corrosive" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageCryo.Stat_Combat_KillWith_DamageCryo", name: "Kill With Damage Cryo" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageFire.Stat_Combat_KillWith_DamageFire", name: "Kill With Damage Fire" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageRadiation.Stat_Combat_KillWith_DamageRadiation", name: "Kill With Damage Radiation" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageShock.Stat_Combat_KillWith_DamageShock", name: "Kill With Damage Shock" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Grenade/Stat_Grenade_GrenadeKills.Stat_Grenade_GrenadeKills", name: "Grenade Grenade Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Grenade/Stat_Grenade_Suicide.Stat_Grenade_Suicide", name: "Grenade Suicide" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_BadassSecondWinds.Stat_HealthAndRecovery_BadassSecondWinds", name: "Health And Recovery Badass Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_BurnDoTSecondWinds.Stat_HealthAndRecovery_BurnDoTSecondWinds", name: "Health And Recovery Burn Do T Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_CoopRevives.Stat_HealthAndRecovery_CoopRevives", name: "Health And Recovery Coop Revives" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_CorrosiveDoTSecondWinds.Stat_HealthAndRecovery_CorrosiveDoTSecondWinds", name: "Health And Recovery Corrosive Do T Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_FrozenSecondWinds.Stat_HealthAndRecovery_FrozenSecondWinds", name: "Health And Recovery Frozen Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_HealthVialPickups.Stat_HealthAndRecovery_HealthVialPickups", name: "Health And Recovery Health Vial Pickups" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_SecondWinds.Stat_HealthAndRecovery_SecondWinds", name: "Health And Recovery Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_ShockDoTSecondWinds.Stat_HealthAndRecovery_ShockDoTSecondWinds", name: "Health And Recovery Shock Do T Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Melee/Stat_Combat_KillWith_Melee.Stat_Combat_KillWith_Melee", name: "Kill With Melee" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Melee/Stat_Melee_GroundSlamKills.Stat_Melee_GroundSlamKills", name: "Melee Ground Slam Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Shield/Stat_Shield_AmmoAbsorbed.Stat_Shield_AmmoAbsorbed", name: "Shield Ammo Absorbed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Shield/Stat_Shield_AmplifyKills.Stat_Shield_AmplifyKills", name: "Shield Amplify Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Shield/Stat_Shield_Kills.Stat_Shield_Kills", name: "Shield Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsPlayed.Stat_Combat_DuelsPlayed", name: "Duels Played" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsPlayed_2Players.Stat_Combat_DuelsPlayed_2Players", name: "Duels Played 2Players" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsPlayed_3Players.Stat_Combat_DuelsPlayed_3Players", name: "Duels Played 3Players" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsWon.Stat_Combat_DuelsWon", name: "Duels Won" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_KillWith_Barrel.Stat_Combat_KillWith_Barrel", name: "Kill With Barrel" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_Vehicle_KillsWith_Ramming.Stat_Vehicle_KillsWith_Ramming", name: "Vehicle Kills With Ramming" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_Vehicle_KillsWith_VehicleWeapons.Stat_Vehicle_KillsWith_VehicleWeapons", name: "Vehicle Kills With Vehicle Weapons" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_VehicleKills_Outrunner.Stat_VehicleKills_Outrunner", name: "Vehicle Kills Outrunner" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_VehicleKills_Revolver.Stat_VehicleKills_Revolver", name: "Vehicle Kills Revolver" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_VehicleKills_Technical.Stat_VehicleKills_Technical", name: "Vehicle Kills Technical" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_AssaultRifleKills.Stat_Weapon_AssaultRifleKills", name: "Weapon Assault Rifle Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_HeavyKills.Stat_Weapon_HeavyKills", name: "Weapon Heavy Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_PistolKills.Stat_Weapon_PistolKills", name: "Weapon Pistol Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_AssaultRifleKills.Stat_Weapon_SecondWind_AssaultRifleKills", name: "Weapon Second Wind Assault Rifle Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_HeavyKills.Stat_Weapon_SecondWind_HeavyKills", name: "Weapon Second Wind Heavy Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_PistolKills.Stat_Weapon_SecondWind_PistolKills", name: "Weapon Second Wind Pistol Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_ShotgunKills.Stat_Weapon_SecondWind_ShotgunKills", name: "Weapon Second Wind Shotgun Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_SMGKills.Stat_Weapon_SecondWind_SMGKills", name: "Weapon Second Wind SMG Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_SniperKills.Stat_Weapon_SecondWind_SniperKills", name: "Weapon Second Wind Sniper Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_ShotgunKills.Stat_Weapon_ShotgunKills", name: "Weapon Shotgun Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SMGKills.Stat_Weapon_SMGKills", name: "Weapon SMG Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SniperKills.Stat_Weapon_SniperKills", name: "Weapon Sniper Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/EnemySpecial/Stat_Enemy_Goliath_HelmetPop.Stat_Enemy_Goliath_HelmetPop", name: "Enemy Goliath Helmet Pop" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_CashLooted.Stat_GameSystem_CashLooted", name: "Cash Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_CosmeticPurchases.Stat_GameSystem_CosmeticPurchases", name: "Cosmetic Purchases" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_FiringRangePerfectScore.Stat_GameSystem_FiringRangePerfectScore", name: "Firing Range Perfect Score" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_ItemOfTheDayBuys.Stat_GameSystem_ItemOfTheDayBuys", name: "Item Of The Day Buys" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_ItemsSold.Stat_GameSystem_ItemsSold", name: "Items Sold" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_SlotMachineGrenades.Stat_GameSystem_SlotMachineGrenades", name: "Slot Machine Grenades" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_SlotMachineWins.Stat_GameSystem_SlotMachineWins", name: "Slot Machine Wins" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_SlotsMachinePlayed.Stat_GameSystem_SlotsMachinePlayed", name: "Slots Machine Played" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_BlueItemsLooted.Stat_GameSystem_BlueItemsLooted", name: "Rare Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_Customization_PlayerUnlocks.Stat_GameSystem_Customization_PlayerUnlocks", name: "Customization Player Unlocks" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_GoldenChestsOpened.Stat_GameSystem_GoldenChestsOpened", name: "Golden Chests Opened" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_GreenItemsLooted.Stat_GameSystem_GreenItemsLooted", name: "Uncommon Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_LootablesOpened.Stat_GameSystem_LootablesOpened", name: "Lootables Opened" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_OrangeItemsLooted.Stat_GameSystem_OrangeItemsLooted", name: "Legendary Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_PurpleItemsLooted.Stat_GameSystem_PurpleItemsLooted", name: "Epic Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_RedChestsOpened.Stat_GameSystem_RedChestsOpened", name: "Red Chests Opened" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_WeaponsCollected.Stat_GameSystem_WeaponsCollected", name: "Weapons Collected" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_WhiteItemsLooted.Stat_GameSystem_WhiteItemsLooted", name: "Common Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_AntennaClaptrap.Stat_GameSystem_AntennaClaptrap", name: "Antenna Claptrap" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_AntennaClaptrapGF.Stat_GameSystem_AntennaClaptrapGF", name: "Antenna Claptrap GF" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_BeachCubeSolved.Stat_GameSystem_BeachCubeSolved", name: "Beach Cube Solved" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_MailSent.Stat_GameSystem_MailSent", name: "Mail Sent" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_MoxxiSabotageTowers.Stat_GameSystem_MoxxiSabotageTowers", name: "Moxxi Sabotage Towers" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_RhysMustache.Stat_GameSystem_RhysMustache", name: "Rhys Mustache" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_TipMoxxi.Stat_GameSystem_TipMoxxi", name: "Tip Moxxi" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_VehiclePartsUnlocked.Stat_GameSystem_VehiclePartsUnlocked", name: "Vehicle Parts Unlocked" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_VehicleTutorial.Stat_GameSystem_VehicleTutorial", name: "Vehicle Tutorial" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_CaptTraunt.Stat_Kill_CaptTraunt", name: "Capt Traunt Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Graveward.Stat_Kill_Graveward", name: "Graveward Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Killavolt.Stat_Kill_Killavolt", name: "Killavolt Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Mouthpiece.Stat_Kill_Mouthpiece", name: "Mouthpiece Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Rampager.Stat_Kill_Rampager", name: "Rampager Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Troy.Stat_Kill_Troy", name: "Troy Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Tyreen.Stat_Kill_Tyreen", name: "Tyreen Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Valkyries.Stat_Kill_Valkyries", name: "Valkyries Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Wotan.Stat_Kill_Wotan", name: "Wotan Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Army_COV.Stat_Kill_Army_COV", name: "Army COV Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Enforcers.Stat_Kill_Enforcers", name: "Enforcers Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Goliaths.Stat_Kill_Goliaths", name: "Goliaths Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Goons.Stat_Kill_Goons", name: "Goons Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Psychos.Stat_Kill_Psychos", name: "Psychos Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Punks.Stat_Kill_Punks", name: "Punks Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Tinks.Stat_Kill_Tinks", name: "Tinks Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Turrets.Stat_Kill_Turrets", name: "Turrets Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Guardian/Stat_Kill_Guardians.Stat_Kill_Guardians", name: "Guardians Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Ape.Stat_Kill_Ape", name: "Ape Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous.Stat_Kill_Indigenous", name: "Indigenous Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Eden6.Stat_Kill_Indigenous_Eden6", name: "Indigenous Eden 6 Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Nekro.Stat_Kill_Indigenous_Nekro", name: "Indigenous Nekro Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Pandora.Stat_Kill_Indigenous_Pandora", name: "Indigenous Pandora Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Promethea.Stat_Kill_Indigenous_Promethea", name: "Indigenous Promethea Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Nekrobug.Stat_Kill_Nekrobug", name: "Nekrobug Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Rakk.Stat_Kill_Rakk", name: "Rakk Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Ratch.Stat_Kill_Ratch", name: "Ratch Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Saurian.Stat_Kill_Saurian", name: "Saurian Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_ServiceBots.Stat_Kill_ServiceBots", name: "Service Bots Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Skags.Stat_Kill_Skags", name: "Skags Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Spiderant.Stat_Kill_Spiderant", name: "Spiderant Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Varkid.Stat_Kill_Varkid", name: "Varkid Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Army_Maliwan.Stat_Kill_Army_Maliwan", name: "Army Maliwan Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Heavies.Stat_Kill_Heavies", name: "Heavies Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Mechs.Stat_Kill_Mechs", name: "Mechs Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Nogs.Stat_Kill_Nogs", name: "Nogs Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Overspheres.Stat_Kill_Overspheres", name: "Overspheres Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_RobotDog.Stat_Kill_RobotDog", name: "Robot Dog Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_RobotWalker.Stat_Kill_RobotWalker", name: "Robot Walker Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Troopers.Stat_Kill_Troopers", name: "Troopers Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Rare/Stat_Kill_CaptainThunkAndSloth.Stat_Kill_CaptainThunkAndSloth", name: "Captain Thunk And Sloth Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Rare/Stat_Kill_VicAndWarty.Stat_Kill_VicAndWarty", name: "Vic And Warty Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Army_Hyperion.Stat_Kill_Army_Hyperion", name: "Army Hyperion Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Badass.Stat_Kill_Badass", name: "Badasses Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Boss.Stat_Kill_Boss", name: "Bosses Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_BossRaid.Stat_Kill_BossRaid", name: "Boss Raid Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Enemies.Stat_Kill_Enemies", name: "Enemies Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_VaultBoss.Stat_Kill_VaultBoss", name: "Vault Boss Killed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Atlas.Stat_Combat_ManufacturerKill_Atlas", name: "Manufacturer Kill Atlas" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_CoV.Stat_Combat_ManufacturerKill_CoV", name: "Manufacturer Kill Co V" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Dahl.Stat_Combat_ManufacturerKill_Dahl", name: "Manufacturer Kill Dahl" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Hyperion.Stat_Combat_ManufacturerKill_Hyperion", name: "Manufacturer Kill Hyperion" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Jakobs.Stat_Combat_ManufacturerKill_Jakobs", name: "Manufacturer Kill Jakobs" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Maliwan.Stat_Combat_ManufacturerKill_Maliwan", name: "Manufacturer Kill Maliwan" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Tediore.Stat_Combat_ManufacturerKill_Tediore", name: "Manufacturer Kill Tediore" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Torgue.Stat_Combat_ManufacturerKill_Torgue", name: "Manufacturer Kill Torgue" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Vladof.Stat_Combat_ManufacturerKill_Vladof", name: "Manufacturer Kill Vladof" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Atlas_DartTags.Stat_Manufacturer_Atlas_DartTags", name: "Manufacturer Atlas Dart Tags" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Atlas_GrenadeTags.Stat_Manufacturer_Atlas_GrenadeTags", name: "Manufacturer Atlas Grenade Tags" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Atlas_PuckTags.Stat_Manufacturer_Atlas_PuckTags", name: "Manufacturer Atlas Puck Tags" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Hyperion_WeaponShieldBulletsAbsorbed.Stat_Manufacturer_Hyperion_WeaponShieldBulletsAbsorbed", name: "Manufacturer Hyperion Weapon Shield Bullets Absorbed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Jakobs_RicochetKill.Stat_Manufacturer_Jakobs_RicochetKill", name: "Manufacturer Jakobs Ricochet Kill" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Tediore_TurretKills.Stat_Manufacturer_Tediore_TurretKills", name: "Manufacturer Tediore Turret Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Atlas.Stat_ManufacturerRewards_Atlas", name: "Manufacturer Rewards Atlas" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_CoV.Stat_ManufacturerRewards_CoV", name: "Manufacturer Rewards Co V" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Dahl.Stat_ManufacturerRewards_Dahl", name: "Manufacturer Rewards Dahl" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Hyperion.Stat_ManufacturerRewards_Hyperion", name: "Manufacturer Rewards Hyperion" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Jakobs.Stat_ManufacturerRewards_Jakobs", name: "Manufacturer Rewards Jakobs" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Maliwan.Stat_ManufacturerRewards_Maliwan", name: "Manufacturer Rewards Maliwan" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Tediore.Stat_ManufacturerRewards_Tediore", name: "Manufacturer Rewards Tediore" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Torgue.Stat_ManufacturerRewards_Torgue", name: "Manufacturer Rewards Torgue" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_UniqueCount.Stat_ManufacturerRewards_UniqueCount", name: "Manufacturer Rewards Unique Count" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Vladof.Stat_ManufacturerRewards_Vladof", name: "Manufacturer Rewards Vladof" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial1.Stat_Maps_Completed_Trial1", name: "Maps Completed Trial 1" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial4.Stat_Maps_Completed_Trial4", name: "Maps Completed Trial 4" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial5.Stat_Maps_Completed_Trial5", name: "Maps Completed Trial 5" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial6.Stat_Maps_Completed_Trial6", name: "Maps Completed Trial 6" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial7.Stat_Maps_Completed_Trial7", name: "Maps Completed Trial 7" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial8.Stat_Maps_Completed_Trial8", name: "Maps Completed Trial 8" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trials_All.Stat_Maps_Completed_Trials_All", name: "Maps Completed Trials All" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/Slaughters/Stat_Maps_Completed_CoVSlaughter.Stat_Maps_Completed_CoVSlaughter", name: "Maps Completed Co V Slaughter" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/Slaughters/Stat_Maps_Completed_Slaughters_All.Stat_Maps_Completed_Slaughters_All", name: "Maps Completed Slaughters All" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/Slaughters/Stat_Maps_Completed_TechSlaughter.Stat_Maps_Completed_TechSlaughter", name: "Maps Completed Tech Slaughter" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Mission/Stat_Mission_OptionalObjectives.Stat_Mission_OptionalObjectives", name: "Mission Optional Objectives" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Mission/Stat_Mission_SideMissions_MainGame.Stat_Mission_SideMissions_MainGame", name: "Mission Side Missions Main Game" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_BormanNates.Stat_Async_BormanNates", name: "Async Borman Nates" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_CaptainThunkAndSloth.Stat_Async_CaptainThunkAndSloth", name: "Async Captain Thunk And Sloth" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_DemoSkag.Stat_Async_DemoSkag", name: "Async Demo Skag" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Dinklebot.Stat_Async_Dinklebot", name: "Async Dinklebot" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_ElDragonJr.Stat_Async_ElDragonJr", name: "Async El Dragon Jr" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_EnforcerUrist.Stat_Async_EnforcerUrist", name: "Async Enforcer Urist" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_IndoTyrant.Stat_Async_IndoTyrant", name: "Async Indo Tyrant" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Maxitrillion.Stat_Async_Maxitrillion", name: "Async Maxitrillion" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_PowerTroopers.Stat_Async_PowerTroopers", name: "Async Power Troopers" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Rakkman.Stat_Async_Rakkman", name: "Async Rakkman" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_RedJabber.Stat_Async_RedJabber", name: "Async Red Jabber" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_RoadDog.Stat_Async_RoadDog", name: "Async Road Dog" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Tarantella.Stat_Async_Tarantella", name: "Async Tarantella" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_TheUnstoppable.Stat_Async_TheUnstoppable", name: "Async The Unstoppable" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_ThreeDragons.Stat_Async_ThreeDragons", name: "Async Three Dragons" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_VicAndWarty.Stat_Async_VicAndWarty", name: "Async Vic And Warty" },
+];
+
+pub const ECHO_LOGS: [GameDataKv; 231] = [
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_1.EchoLog_Journal_AtlasHQ_1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_2.EchoLog_Journal_AtlasHQ_2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_3.EchoLog_Journal_AtlasHQ_3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach1.EchoLog_Journal_Beach1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach2.EchoLog_Journal_Beach2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach3.EchoLog_Journal_Beach3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City1.EchoLog_Journal_City1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City2.EchoLog_Journal_City2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City3.EchoLog_Journal_City3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault1.EchoLog_Journal_CityVault1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault2.EchoLog_Journal_CityVault2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault3.EchoLog_Journal_CityVault3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert1.EchoLog_Journal_Desert1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert2.EchoLog_Journal_Desert2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert3.EchoLog_Journal_Desert3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault1.EchoLog_Journal_DesertVault1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault2.EchoLog_Journal_DesertVault2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault3.EchoLog_Journal_DesertVault3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate1.EchoLog_Journal_Desolate1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate2.EchoLog_Journal_Desolate2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate3.EchoLog_Journal_Desolate3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion1.EchoLog_Journal_Mansion1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion2.EchoLog_Journal_Mansion2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion3.EchoLog_Journal_Mansion3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields1.EchoLog_Journal_Marshfields1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields2.EchoLog_Journal_Marshfields2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields3.EchoLog_Journal_Marshfields3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine1.EchoLog_Journal_Mine1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine2.EchoLog_Journal_Mine2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine3.EchoLog_Journal_Mine3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery1.EchoLog_Journal_Monastery1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery2.EchoLog_Journal_Monastery2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery3.EchoLog_Journal_Monastery3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade1.EchoLog_Journal_Motorcade1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade2.EchoLog_Journal_Motorcade2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade3.EchoLog_Journal_Motorcade3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival1.EchoLog_Journal_MotorcadeFestival1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival2.EchoLog_Journal_MotorcadeFestival2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival3.EchoLog_Journal_MotorcadeFestival3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform1.EchoLog_Journal_OrbitalPlatform1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform2.EchoLog_Journal_OrbitalPlatform2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform3.EchoLog_Journal_OrbitalPlatform3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts1.EchoLog_Journal_Outskirts1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts2.EchoLog_Journal_Outskirts2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts3.EchoLog_Journal_Outskirts3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison1.EchoLog_Journal_Prison1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison2.EchoLog_Journal_Prison2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison3.EchoLog_Journal_Prison3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue1.EchoLog_Journal_Prologue1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue2.EchoLog_Journal_Prologue2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue3.EchoLog_Journal_Prologue3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice1.EchoLog_Journal_Sacrifice1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice2.EchoLog_Journal_Sacrifice2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice3.EchoLog_Journal_Sacrifice3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers1.EchoLog_Journal_Towers1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers2.EchoLog_Journal_Towers2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers3.EchoLog_Journal_Towers3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership1.EchoLog_Journal_Watership1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership2.EchoLog_Journal_Watership2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership3.EchoLog_Journal_Watership3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands1.EchoLog_Journal_Wetlands1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands2.EchoLog_Journal_Wetlands2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands3.EchoLog_Journal_Wetlands3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault1.EchoLog_Journal_WetlandsVault1", name: "Typhon Log 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault2.EchoLog_Journal_WetlandsVault2", name: "Typhon Log 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault3.EchoLog_Journal_WetlandsVault3", name: "Typhon Log 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Eden6.EchoLog_EridianKeystone_Eden6", name: "Eridian Keystone: Eden-6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Nekrotafeyo.EchoLog_EridianKeystone_Nekrotafeyo", name: "Eridian Keystone: Nekrotafeyo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Pandora.EchoLog_EridianKeystone_Pandora", name: "Eridian Keystone: Pandora" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_01.EchoLog_EridianWriting_01", name: "Eridian Writing 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_02.EchoLog_EridianWriting_02", name: "Eridian Writing 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_03.EchoLog_EridianWriting_03", name: "Eridian Writing 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_04.EchoLog_EridianWriting_04", name: "Eridian Writing 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_05.EchoLog_EridianWriting_05", name: "Eridian Writing 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_06.EchoLog_EridianWriting_06", name: "Eridian Writing 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_07.EchoLog_EridianWriting_07", name: "Eridian Writing 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_08.EchoLog_EridianWriting_08", name: "Eridian Writing 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_09.EchoLog_EridianWriting_09", name: "Eridian Writing 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_10.EchoLog_EridianWriting_10", name: "Eridian Writing 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_11.EchoLog_EridianWriting_11", name: "Eridian Writing 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_12.EchoLog_EridianWriting_12", name: "Eridian Writing 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_13.EchoLog_EridianWriting_13", name: "Eridian Writing 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_14.EchoLog_EridianWriting_14", name: "Eridian Writing 14" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_15.EchoLog_EridianWriting_15", name: "Eridian Writing 15" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_16.EchoLog_EridianWriting_16", name: "Eridian Writing 16" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_17.EchoLog_EridianWriting_17", name: "Eridian Writing 17" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_18.EchoLog_EridianWriting_18", name: "Eridian Writing 18" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_19.EchoLog_EridianWriting_19", name: "Eridian Writing 19" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_20.EchoLog_EridianWriting_20", name: "Eridian Writing 20" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_21.EchoLog_EridianWriting_21", name: "Eridian Writing 21" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_22.EchoLog_EridianWriting_22", name: "Eridian Writing 22" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_23.EchoLog_EridianWriting_23", name: "Eridian Writing 23" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_24.EchoLog_EridianWriting_24", name: "Eridian Writing 24" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_25.EchoLog_EridianWriting_25", name: "Eridian Writing 25" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_26.EchoLog_EridianWriting_26", name: "Eridian Writing 26" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_27.EchoLog_EridianWriting_27", name: "Eridian Writing 27" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_28.EchoLog_EridianWriting_28", name: "Eridian Writing 28" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_29.EchoLog_EridianWriting_29", name: "Eridian Writing 29" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_30.EchoLog_EridianWriting_30", name: "Eridian Writing 30" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_Intro.EchoLog_EridianWriting_Intro", name: "Eridian Writing Intro" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ1.EchoLogData_NonMission_AtlasHQ1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ2.EchoLogData_NonMission_AtlasHQ2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ3.EchoLogData_NonMission_AtlasHQ3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ4.EchoLogData_NonMission_AtlasHQ4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ5.EchoLogData_NonMission_AtlasHQ5", name: "Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach1.EchoLogData_NonMission_Beach1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach2.EchoLogData_NonMission_Beach2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach3.EchoLogData_NonMission_Beach3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City1.EchoLogData_NonMission_City1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City2.EchoLogData_NonMission_City2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City3.EchoLogData_NonMission_City3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City4.EchoLogData_NonMission_City4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City5.EchoLogData_NonMission_City5", name: "Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault1.EchoLogData_NonMission_CityVault1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault2.EchoLogData_NonMission_CityVault2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault3.EchoLogData_NonMission_CityVault3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert2.EchoLogData_NonMission_Desert2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert3.EchoLogData_NonMission_Desert3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert4.EchoLogData_NonMission_Desert4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_DesertVault2.EchoLogData_NonMission_DesertVault2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desolate1.EchoLogData_NonMission_Desolate1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desolate2.EchoLogData_NonMission_Desolate2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mansion1.EchoLogData_NonMission_Mansion1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mansion2.EchoLogData_NonMission_Mansion2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields1.EchoLogData_NonMission_Marshfields1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields2.EchoLogData_NonMission_Marshfields2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields3.EchoLogData_NonMission_Marshfields3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields4.EchoLogData_NonMission_Marshfields4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine1.EchoLogData_NonMission_Mine1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine2.EchoLogData_NonMission_Mine2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine3.EchoLogData_NonMission_Mine3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery1.EchoLogData_NonMission_Monastery1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery2.EchoLogData_NonMission_Monastery2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery3.EchoLogData_NonMission_Monastery3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Motorcade5.EchoLogData_NonMission_Motorcade5", name: "Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeFestival1.EchoLogData_NonMission_MotorcadeFestival1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior1.EchoLogData_NonMission_MotorcadeInterior1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior2.EchoLogData_NonMission_MotorcadeInterior2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior3.EchoLogData_NonMission_MotorcadeInterior3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_OrbitalPlatform1.EchoLogData_NonMission_OrbitalPlatform1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts1.EchoLogData_NonMission_Outskirts1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts2.EchoLogData_NonMission_Outskirts2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts3.EchoLogData_NonMission_Outskirts3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts4.EchoLogData_NonMission_Outskirts4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prison1.EchoLogData_NonMission_Prison1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prison2.EchoLogData_NonMission_Prison2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue1.EchoLogData_NonMission_Prologue1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue2.EchoLogData_NonMission_Prologue2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue3.EchoLogData_NonMission_Prologue3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue4.EchoLogData_NonMission_Prologue4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue5.EchoLogData_NonMission_Prologue5", name: "Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue6.EchoLogData_NonMission_Prologue6", name: "Echo Log 6" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice1.EchoLogData_NonMission_Sacrifice1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice2.EchoLogData_NonMission_Sacrifice2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice3.EchoLogData_NonMission_Sacrifice3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary1.EchoLogData_NonMission_Sanctuary1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary2.EchoLogData_NonMission_Sanctuary2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary3.EchoLogData_NonMission_Sanctuary3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary4.EchoLogData_NonMission_Sanctuary4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Towers1.EchoLogData_NonMission_Towers1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Towers2.EchoLogData_NonMission_Towers2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Watership1.EchoLogData_NonMission_Watership1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Watership2.EchoLogData_NonMission_Watership2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands1.EchoLogData_NonMission_Wetlands1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands2.EchoLogData_NonMission_Wetlands2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands3.EchoLogData_NonMission_Wetlands3", name: "Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands4.EchoLogData_NonMission_Wetlands4", name: "Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_WetlandsVault1.EchoLogData_NonMission_WetlandsVault1", name: "Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_WetlandsVault2.EchoLogData_NonMission_WetlandsVault2", name: "Echo Log 2" },
+    GameDataKv { ident: "/Game/Missions/Plot/Ep05_OvercomeHQBlockade/EchoLog_OvercomeHQBlockade_TechnicalEchoLog.EchoLog_OvercomeHQBlockade_TechnicalEchoLog", name: "Overcome HQ Blockade Technical Echo Log" },
+    GameDataKv { ident: "/Game/Missions/Plot/MarshFields/Pickups/SpyEchoLog03/EchoLog_MarshFields_DeadDrop1.EchoLog_MarshFields_DeadDrop1", name: "Marshfields Dead Drop 1" },
+    GameDataKv { ident: "/Game/Missions/Plot/MarshFields/Pickups/SpyEchoLog03/EchoLog_MarshFields_DeadDrop2.EchoLog_MarshFields_DeadDrop2", name: "Marshfields Dead Drop 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment01.EchoLogData_HeadCase_MemoryFragment01", name: "Head Case Memory Fragment 01" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment02.EchoLogData_HeadCase_MemoryFragment02", name: "Head Case Memory Fragment 02" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment03.EchoLogData_HeadCase_MemoryFragment03", name: "Head Case Memory Fragment 03" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment04.EchoLogData_HeadCase_MemoryFragment04", name: "Head Case Memory Fragment 04" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/PandorasNextTopMouthpiece/EchoLogData_PandorasNTM.EchoLogData_PandorasNTM", name: "Pandora's Next Top Mouthpiece" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_AliveSpy.EchoLogData_OppResearch_AliveSpy", name: "Opposition Research Alive Spy" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_Interrogation.EchoLogData_OppResearch_Interrogation", name: "Opposition Research Interrogation" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_Toilet.EchoLogData_OppResearch_Toilet", name: "Opposition Research Toilet" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Mansion/AureliasSkeletons/EchoLogData_AureliasSkeletons_Echo1.EchoLogData_AureliasSkeletons_Echo1", name: "Aurelia's Skeletons Echo 1" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Mansion/AureliasSkeletons/EchoLogData_AureliasSkeletons_Echo2.EchoLogData_AureliasSkeletons_Echo2", name: "Aurelia's Skeletons Echo 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue01.EchoLogData_MalevolentPractice_Clue01", name: "Malevolent Practice Clue 01" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue02.EchoLogData_MalevolentPractice_Clue02", name: "Malevolent Practice Clue 02" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue03.EchoLogData_MalevolentPractice_Clue03", name: "Malevolent Practice Clue 03" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue04.EchoLogData_MalevolentPractice_Clue04", name: "Malevolent Practice Clue 04" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_ECHO_Cage.EchoLog_RumbleJungle_ECHO_Cage", name: "Rumble Jungle ECHO Cage" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_KingBoboECHO.EchoLog_RumbleJungle_KingBoboECHO", name: "Rumble Jungle King Bobo ECHO" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_LabECHO.EchoLog_RumbleJungle_LabECHO", name: "Rumble Jungle Lab ECHO" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_3/Mine/BridgeInTheDark/EchoLogData_BridgeInTheDark_LostAlkonostLog.EchoLogData_BridgeInTheDark_LostAlkonostLog", name: "Bridge In The Dark Lost Alkonost Log" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_3/Mine/BridgeInTheDark/EchoLogData_BridgeInTheDark_TombLog.EchoLogData_BridgeInTheDark_TombLog", name: "Bridge In The Dark Tomb Log" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_1.EchoLogData_BetterTimes_GetEchoLog_1", name: "Better Times Get Echo Log 1" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_2.EchoLogData_BetterTimes_GetEchoLog_2", name: "Better Times Get Echo Log 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_3.EchoLogData_BetterTimes_GetEchoLog_3", name: "Better Times Get Echo Log 3" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/DestroyerOfWorlds/EchoLogData_DestroyerOfWorlds_FindCartridge.EchoLogData_DestroyerOfWorlds_FindCartridge", name: "Destroyer Of Worlds Find Cartridge" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC1_Trashtown4.EchoLogData_DLC1_Trashtown4", name: "Trashtown Echo Log 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_1.EchoLogData_MauriceSideMission_1", name: "Maurice Side Mission 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_2.EchoLogData_MauriceSideMission_2", name: "Maurice Side Mission 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_3.EchoLogData_MauriceSideMission_3", name: "Maurice Side Mission 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_01.EchoLog_JakobsJournal_01", name: "Jakobs Journal 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_02.EchoLog_JakobsJournal_02", name: "Jakobs Journal 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_05.EchoLog_JakobsJournal_05", name: "Jakobs Journal 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_06.EchoLog_JakobsJournal_06", name: "Jakobs Journal 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Facility01.EchoLogData_NonMission_Geranium_Facility01", name: "Facility Echo Log 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Facility02.EchoLogData_NonMission_Geranium_Facility02", name: "Facility Echo Log 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Frontier05.EchoLogData_NonMission_Geranium_Frontier05", name: "Frontier Echo Log 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Frontier07.EchoLogData_NonMission_Geranium_Frontier07", name: "Frontier Echo Log 7" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Lodge03.EchoLogData_NonMission_Geranium_Lodge03", name: "Lodge Echo Log 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Miracle01.EchoLogData_NonMission_Geranium_Miracle01", name: "Miracle Echo Log 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Miracle03.EchoLogData_NonMission_Geranium_Miracle03", name: "Miracle Echo Log 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories.EchoLogData_NonMission_GhostStories", name: "Ghost Stories 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories3.EchoLogData_NonMission_GhostStories3", name: "Ghost Stories 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories6.EchoLogData_NonMission_GhostStories6", name: "Ghost Stories 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/Missions/Plot/Facility/EchoLog_DigilineUpgrade.EchoLog_DigilineUpgrade", name: "Digiline Upgrade" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/Missions/Side/MoneyBackGuarantee/EchoLog_MoneyBackEchoLog.EchoLog_MoneyBackEchoLog", name: "Money Back Echo Log" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Archives2.EchoLogData_DLC2_Archives2", name: "Archives Echo Log 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Bar1.EchoLogData_DLC2_Bar1", name: "Bar Echo Log 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Bar2.EchoLogData_DLC2_Bar2", name: "Bar Echo Log 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Camp3.EchoLogData_DLC2_Camp3", name: "Camp Echo Log 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Camp4.EchoLogData_DLC2_Camp4", name: "Camp Echo Log 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Lake2.EchoLogData_DLC2_Lake2", name: "Lake Echo Log 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue1.EchoLogData_DLC2_Venue1", name: "Venue Echo Log 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue2.EchoLogData_DLC2_Venue2", name: "Venue Echo Log 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue4.EchoLogData_DLC2_Venue4", name: "Venue Echo Log 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Woods1.EchoLogData_DLC2_Woods1", name: "Woods Echo Log 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Woods2.EchoLogData_DLC2_Woods2", name: "Woods Echo Log 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_1.EchoLogData_IntoTheDeep_1", name: "Into The Deep 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_2.EchoLogData_IntoTheDeep_2", name: "Into The Deep 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_3.EchoLogData_IntoTheDeep_3", name: "Into The Deep 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_SinisterSounds_1.EchoLogData_SinisterSounds_1", name: "Sinister Sounds 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/Hib_EchoLogData_PrivateEye_Crypt.Hib_EchoLogData_PrivateEye_Crypt", name: "Private Eye Crypt" },
+];
+
+pub const VEHICLE_LOADOUT_PARTS: [GameDataKv; 15] = [
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/CAR_Vehicle_Outrunner.CAR_Vehicle_Outrunner", name: "" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/CAR_Vehicle_Revolver.CAR_Vehicle_Revolver", name: "" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/CAR_Vehicle_Technical.CAR_Vehicle_Technical", name: "" },
+    GameDataKv { ident: "/Geranium/UI/CatchARideMenu/VehiclesAndParts/CAR_Vehicle_Horse.CAR_Vehicle_Horse", name: "" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Outrunner/CAR_Armor_Outrunner_NoArmor.CAR_Armor_Outrunner_NoArmor", name: "" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Revolver/CAR_Armor_Revolver_NoArmor.CAR_Armor_Revolver_NoArmor", name: "" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Technical/CAR_Armor_Technical_NoArmor.CAR_Armor_Technical_NoArmor", name: "" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Outrunner/CAR_Wheel_Outrunner_DuneBuggy.CAR_Wheel_Outrunner_DuneBuggy", name: "/Game/Vehicles/Outrunner/Design/WT_Outrunner_BuggyWheels.WT_Outrunner_BuggyWheels" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Revolver/CAR_Wheel_Revolver_Monowheel.CAR_Wheel_Revolver_Monowheel", name: "/Game/Vehicles/Revolver/Design/WT_Revolver_MonoWheel.WT_Revolver_MonoWheel" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Outrunner/CAR_Armor_Outrunner_FullArmor.CAR_Armor_Outrunner_FullArmor", name: "/Game/Vehicles/Outrunner/Design/Parts/Armor/VehiclePart_Outrunner_Armor_HeavyArmor.VehiclePart_Outrunner_Armor_HeavyArmor" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Revolver/CAR_Armor_Revolver_FullArmor.CAR_Armor_Revolver_FullArmor", name: "/Game/Vehicles/Revolver/Design/Parts/Armor/VehiclePart_Revolver_Armor_HeavyArmor.VehiclePart_Revolver_Armor_HeavyArmor" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Technical/CAR_Armor_Technical_SteelPlates.CAR_Armor_Technical_SteelPlates", name: "/Game/Vehicles/Technical/Design/Parts/Armor/VehiclePart_Techincal_Armor_BasicArmor.VehiclePart_Techincal_Armor_BasicArmor" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Technical/CAR_CoreMod_Technical_PassengerSeats.CAR_CoreMod_Technical_PassengerSeats", name: "/Game/Vehicles/Technical/Design/Parts/Accessory/FlatBed/VehiclePart_CoreMod_Flatbed.VehiclePart_CoreMod_Flatbed" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Outrunner/CAR_Material_Outrunner_Psycho-mobile.CAR_Material_Outrunner_Psycho-mobile", name: "/Game/Vehicles/Outrunner/Design/Parts/Materials/VehiclePart_Mat_VehiclePart_Outrunner_COV.VehiclePart_Mat_VehiclePart_Outrunner_COV" },
+    GameDataKv { ident: "/Game/UI/CatchARideMenu/VehicleAndParts/Revolver/CAR_Material_Revolver_Bubblegum.CAR_Material_Revolver_Bubblegum", name: "/Game/Vehicles/Revolver/Design/Parts/Materials/VehiclePart_Mat_VehiclePart_Revolver_HubbaBubba.VehiclePart_Mat_VehiclePart_Revolver_HubbaBubba" },
+];
+
+pub const REGIONS: [GameDataKv; 70] = [
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Recruitment_A.Region_Zone0_Recruitment_A", name: "Covenant Pass (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Recruitment_B.Region_Zone0_Recruitment_B", name: "Covenant Pass (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Prologue_A.Region_Zone0_Prologue_A", name: "Droughts (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Prologue_B.Region_Zone0_Prologue_B", name: "Droughts (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Prologue_C.Region_Zone0_Prologue_C", name: "Droughts (C)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Prologue_E.Region_Zone0_Prologue_E", name: "Droughts (E)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Prologue_F.Region_Zone0_Prologue_F", name: "Droughts (F)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Prologue_Skagzilla.Region_Zone0_Prologue_Skagzilla", name: "Droughts (Skagzilla)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Sacrifice_A.Region_Zone0_Sacrifice_A", name: "Ascension Bluff (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Sacrifice_B.Region_Zone0_Sacrifice_B", name: "Ascension Bluff (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Sacrifice_C.Region_Zone0_Sacrifice_C", name: "Ascension Bluff (C)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_Sacrifice_Moutpiece.Region_Zone0_Sacrifice_Moutpiece", name: "Ascension Bluff (Mouthpiece)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Zone0_FinalBoss.Region_Zone0_FinalBoss", name: "Destroyer's Rift" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_AtlasHQ.Region_Zone1_AtlasHQ", name: "Atlas HQ" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_AtlasHQ_B.Region_Zone1_AtlasHQ_B", name: "Atlas HQ (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_AtlasHQ_KatagawaJr.Region_Zone1_AtlasHQ_KatagawaJr", name: "Atlas HQ (Katagawa Jr)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_City_A.Region_Zone1_City_A", name: "Meridian Metroplex (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_City_B.Region_Zone1_City_B", name: "Meridian Metroplex (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_City_C.Region_Zone1_City_C", name: "Meridian Metroplex (C)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_City_Vault.Region_Zone1_City_Vault", name: "Neon Arterial" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_CityBoss.Region_Zone1_CityBoss", name: "Forgotten Basilica" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Monastery_A.Region_Zone1_Monastery_A", name: "Athenas (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Monastery_B.Region_Zone1_Monastery_B", name: "Athenas (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Monastery_Beans.Region_Zone1_Monastery_Beans", name: "Athenas (Beans)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Monastery_CaptTraunt.Region_Zone1_Monastery_CaptTraunt", name: "Athenas (Captain Traunt)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_OrbitalPlatform_A.Region_Zone1_OrbitalPlatform_A", name: "Skywell-27 (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_OrbitalPlatform_B.Region_Zone1_OrbitalPlatform_B", name: "Skywell-27 (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_OrbitalPlatform_KatagawaSphere.Region_Zone1_OrbitalPlatform_KatagawaSphere", name: "Skywell-27 (Katagawa Ball)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Outskirts_A.Region_Zone1_Outskirts_A", name: "Meridian Outskirts (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Outskirts_B.Region_Zone1_Outskirts_B", name: "Meridian Outskirts (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Towers_A.Region_Zone1_Towers_A", name: "Lectra City (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Towers_B.Region_Zone1_Towers_B", name: "Lectra City (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone1/Region_Zone1_Towers_KillaVolt.Region_Zone1_Towers_KillaVolt", name: "Lectra City (Killavolt)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Mansion_A.Region_Zone2_Mansion_A", name: "Jakobs Estate (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Mansion_B.Region_Zone2_Mansion_B", name: "Jakobs Estate (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_MarshFields_A.Region_Zone2_MarshFields_A", name: "Ambermire (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_MarshFields_B.Region_Zone2_MarshFields_B", name: "Ambermire (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Prison_A.Region_Zone2_Prison_A", name: "Anvil (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Prison_B.Region_Zone2_Prison_B", name: "Anvil (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Prison_C.Region_Zone2_Prison_C", name: "Anvil (C)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Prison_Warden.Region_Zone2_Prison_Warden", name: "Anvil (Warden)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Watership.Region_Zone2_Watership", name: "Voracious Canopy" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Watership-GeneVIV.Region_Zone2_Watership-GeneVIV", name: "Voracious Canopy (GenIVIV)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Wetlands_A.Region_Zone2_Wetlands_A", name: "Floodmoor Basin (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Wetlands_B.Region_Zone2_Wetlands_B", name: "Floodmoor Basin (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_Wetlands_C.Region_Zone2_Wetlands_C", name: "Floodmoor Basin (C)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_WetlandsBoss.Region_Zone2_WetlandsBoss", name: "Floating Tomb" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone2/Region_Zone2_WetlandsVault.Region_Zone2_WetlandsVault", name: "Blackbarrel Cellars" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_Desert_A.Region_Zone3_Desert_A", name: "Devil's Razor (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_Desert_B.Region_Zone3_Desert_B", name: "Devil's Razor (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_Desert_C.Region_Zone3_Desert_C", name: "Devil's Razor (C)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_DesertBoss.Region_Zone3_DesertBoss", name: "Great Vault" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_DesertVault.Region_Zone3_DesertVault", name: "Cathedral of the Twin Gods" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_Grotto.Region_Zone3_Grotto", name: "Holy Broadcast Center" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_Mine.Region_Zone3_Mine", name: "Konrad's Hold" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_Motorcade.Region_Zone3_Motorcade", name: "Splinterlands" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_MotorcadeFest.Region_Zone3_MotorcadeFest", name: "Carnivora" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone3/Region_Zone3_Motorcade_Interior.Region_Zone3_Motorcade_Interior", name: "Guts of Carnivora" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone4/Region_Zone4_Beach.Region_Zone4_Beach", name: "Tazendeer Ruins" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone4/Region_Zone4_Desolate_A.Region_Zone4_Desolate_A", name: "Desolation's Edge (A)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone4/Region_Zone4_Desolate_B.Region_Zone4_Desolate_B", name: "Desolation's Edge (B)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone4/Region_Zone4_Desolate_C.Region_Zone4_Desolate_C", name: "Desolation's Edge (C)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone4/Region_Zone4_Desolate_Crypt.Region_Zone4_Desolate_Crypt", name: "Pyre of Stars" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone4/Region_Zone4_Desolate_Traunt.Region_Zone4_Desolate_Traunt", name: "Desolation's Edge (Traunt)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Region_ProvingGrounds.Region_ProvingGrounds", name: "Proving Grounds" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Sanctuary.Region_Sanctuary", name: "Sanctuary" },
+    GameDataKv { ident: "/Game/GameData/Regions/Zone0/Region_Sanctuary_LevelOne.Region_Sanctuary_LevelOne", name: "Sanctuary (Level One)" },
+    GameDataKv { ident: "/Game/GameData/Regions/Slaughters/Region_Slaughter_CoV.Region_Slaughter_CoV", name: "Slaughter Shaft" },
+    GameDataKv { ident: "/Game/GameData/Regions/Slaughters/Region_Slaughter_Creature.Region_Slaughter_Creature", name: "Cistern of Slaughter" },
+    GameDataKv { ident: "/Game/GameData/Regions/Slaughters/Region_Slaughter_Tech.Region_Slaughter_Tech", name: "Slaughterstar 3000" },
+];
+
+pub const CREW_QUARTERS_ROOMS: [GameDataKv; 4] = [
+    GameDataKv { ident: "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_BeastMaster.PlayerQuarterActorData_BeastMaster", name: "Beastmaster Quarters" },
+    GameDataKv { ident: "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Gunner.PlayerQuarterActorData_Gunner", name: "Gunner Quarters" },
+    GameDataKv { ident: "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Operative.PlayerQuarterActorData_Operative", name: "Operative Quarters" },
+    GameDataKv { ident: "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Siren.PlayerQuarterActorData_Siren", name: "Siren Quarters" },
+];
+
+pub const PROFILE_TUTORIALS: [GameDataKv; 10] = [
+    GameDataKv { ident: "BeastmasterScreenUnlockTutorial", name: "Beastmaster Skill Screen" },
+    GameDataKv { ident: "CARMenuMainTutorial", name: "Catch-A-Ride Menu" },
+    GameDataKv { ident: "FastTravel", name: "Fast Travel" },
+    GameDataKv { ident: "FirstTime", name: "First Time" },
+    GameDataKv { ident: "GunnerScreenUnlockTutorial", name: "Gunner Skill Screen" },
+    GameDataKv { ident: "InventoryMenuMainTutorial", name: "Inventory Menu" },
+    GameDataKv { ident: "OperativeScreenUnlockTutorial", name: "Operative Skill Screen" },
+    GameDataKv { ident: "PurchasePassive", name: "Purchase Passive Skill" },
+    GameDataKv { ident: "SirenScreenUnlockTutorial", name: "Siren Skill Screen" },
+    GameDataKv { ident: "WingtipTutorial", name: "Wingtip" },
+];
+
 pub const VEHICLE_CHASSIS_OUTRUNNER: [&str; 4] = [
     "/Game/Vehicles/Outrunner/Design/WT_Outrunner_BuggyWheels.WT_Outrunner_BuggyWheels",
     "/Game/Vehicles/Outrunner/Design/WT_Outrunner_HoverWheels.WT_Outrunner_HoverWheels",
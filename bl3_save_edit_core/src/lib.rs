@@ -1,4 +1,6 @@
+pub mod anonymize;
 pub mod arbitrary_bits;
+pub mod backup_store;
 pub mod bl3_item;
 pub mod bl3_profile;
 pub mod bl3_save;
@@ -9,4 +11,6 @@ pub mod models;
 pub mod parser;
 pub mod protos;
 pub mod resources;
+#[cfg(test)]
+pub(crate) mod test_helper;
 pub mod vehicle_data;
@@ -31,6 +31,8 @@ const INVENTORY_INV_DATA_COMPRESSED: &[u8] =
 const INVENTORY_MANUFACTURER_PARTS_COMPRESSED: &[u8] =
     include_bytes!("../../resources/INVENTORY_MANUFACTURER_PARTS.ron.sz");
 
+pub const NEW_CHARACTER_HEADER: &[u8] = include_bytes!("../../resources/NEW_CHARACTER_HEADER.sav");
+
 const LOOTLEMON_ITEMS_COMPRESSED: &[u8] = include_bytes!("../../resources/LOOTLEMON_ITEMS.ron.sz");
 
 pub static INVENTORY_SERIAL_DB: Lazy<InventorySerialDb> =
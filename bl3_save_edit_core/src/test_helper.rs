@@ -0,0 +1,23 @@
+use std::fs;
+use std::path::Path;
+
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::Bl3Save;
+use crate::parser::HeaderType;
+
+pub(crate) fn read_test_save(file_name: &str, header_type: HeaderType) -> Bl3Save {
+    let filename = Path::new("./test_files").join(file_name);
+
+    let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+    Bl3Save::from_bytes(&filename, &save_file_data, header_type).expect("failed to read test save")
+}
+
+pub(crate) fn read_test_profile(file_name: &str, header_type: HeaderType) -> Bl3Profile {
+    let filename = Path::new("./test_files").join(file_name);
+
+    let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+    Bl3Profile::from_bytes(&filename, &profile_file_data, header_type)
+        .expect("failed to read test profile")
+}
@@ -1,11 +1,14 @@
-use strum::Display;
+use once_cell::sync::Lazy;
+use strum::{Display, EnumIter, IntoEnumIterator};
 
 use crate::game_data::{
     VEHICLE_CHASSIS_CYCLONE, VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER,
-    VEHICLE_CHASSIS_TECHNICAL, VEHICLE_PARTS_CYCLONE, VEHICLE_PARTS_JETBEAST,
-    VEHICLE_PARTS_OUTRUNNER, VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE,
-    VEHICLE_SKINS_JETBEAST, VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
+    VEHICLE_CHASSIS_TECHNICAL, VEHICLE_LOADOUT_PARTS, VEHICLE_PARTS_CYCLONE,
+    VEHICLE_PARTS_JETBEAST, VEHICLE_PARTS_OUTRUNNER, VEHICLE_PARTS_TECHNICAL,
+    VEHICLE_SKINS_CYCLONE, VEHICLE_SKINS_JETBEAST, VEHICLE_SKINS_OUTRUNNER,
+    VEHICLE_SKINS_TECHNICAL,
 };
+use crate::protos::oak_save::OakCARMenuVehicleConfigSaveData;
 
 #[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct VehicleData {
@@ -22,6 +25,23 @@ impl VehicleData {
     }
 }
 
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct VehicleAsset {
+    pub name: String,
+    pub path: String,
+    pub unlocked: bool,
+}
+
+impl VehicleAsset {
+    pub fn new(path: &str, unlocked: bool) -> Self {
+        VehicleAsset {
+            name: path.rsplit('.').next().unwrap_or(path).to_owned(),
+            path: path.to_owned(),
+            unlocked,
+        }
+    }
+}
+
 #[derive(Debug, Eq, Display, PartialEq, Ord, PartialOrd, Clone)]
 pub enum VehicleType {
     Outrunner(VehicleSubType),
@@ -44,6 +64,17 @@ impl std::default::Default for VehicleType {
 }
 
 impl VehicleType {
+    // Catch-A-Ride loadouts refer to vehicles by their internal names, e.g. CAR_Vehicle_Revolver
+    pub fn from_loadout_vehicle_name(name: &str, sub_type: VehicleSubType) -> Option<Self> {
+        match name {
+            "Outrunner" => Some(VehicleType::Outrunner(sub_type)),
+            "Horse" => Some(VehicleType::Jetbeast(sub_type)),
+            "Technical" => Some(VehicleType::Technical(sub_type)),
+            "Revolver" => Some(VehicleType::Cyclone(sub_type)),
+            _ => None,
+        }
+    }
+
     pub fn subtype(&self) -> &VehicleSubType {
         match self {
             VehicleType::Outrunner(sub_type) => sub_type,
@@ -81,7 +112,7 @@ impl VehicleType {
         }
     }
 
-    pub fn data_set(&self) -> Vec<&str> {
+    pub fn data_set(&self) -> Vec<&'static str> {
         match self {
             VehicleType::Outrunner(sub_type) => match sub_type {
                 VehicleSubType::Chassis => VEHICLE_CHASSIS_OUTRUNNER.to_vec(),
@@ -106,3 +137,557 @@ impl VehicleType {
         }
     }
 }
+
+#[derive(Debug, Display, EnumIter, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub enum VehicleLoadoutSlot {
+    Body,
+    Wheels,
+    Armor,
+    #[strum(to_string = "Core Mod")]
+    CoreMod,
+    #[strum(to_string = "Gunner Weapon")]
+    GunnerWeapon,
+    #[strum(to_string = "Driver Weapon")]
+    DriverWeapon,
+    Ornament,
+    Decal,
+    Material,
+}
+
+impl VehicleLoadoutSlot {
+    pub fn asset_prefix(&self) -> Option<&'static str> {
+        match self {
+            VehicleLoadoutSlot::Body => Some("CAR_Vehicle_"),
+            VehicleLoadoutSlot::Wheels => Some("CAR_Wheel_"),
+            VehicleLoadoutSlot::Armor => Some("CAR_Armor_"),
+            VehicleLoadoutSlot::CoreMod => Some("CAR_CoreMod_"),
+            VehicleLoadoutSlot::GunnerWeapon => Some("CAR_TurretWeapon_"),
+            VehicleLoadoutSlot::DriverWeapon => Some("CAR_DriverWeapon_"),
+            VehicleLoadoutSlot::Material => Some("CAR_Material_"),
+            VehicleLoadoutSlot::Ornament | VehicleLoadoutSlot::Decal => None,
+        }
+    }
+
+    pub fn from_loadout_asset(path: &str) -> Option<Self> {
+        let asset_name = loadout_asset_name(path);
+
+        VehicleLoadoutSlot::iter().find(|slot| {
+            slot.asset_prefix()
+                .map(|prefix| asset_name.starts_with(prefix))
+                .unwrap_or(false)
+        })
+    }
+
+    pub fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            VehicleLoadoutSlot::GunnerWeapon
+                | VehicleLoadoutSlot::Ornament
+                | VehicleLoadoutSlot::Decal
+        )
+    }
+}
+
+pub fn loadout_asset_name(path: &str) -> &str {
+    path.rsplit('.').next().unwrap_or(path)
+}
+
+// CAR_<Slot>_<Vehicle>_<Name>, e.g. CAR_Wheel_Outrunner_DuneBuggy -> Outrunner
+pub fn loadout_asset_vehicle_name(path: &str) -> Option<&str> {
+    loadout_asset_name(path).split('_').nth(2)
+}
+
+const LOADOUT_VEHICLE_NAMES: [&str; 4] = ["Outrunner", "Technical", "Revolver", "Horse"];
+
+/// A Catch-A-Ride asset a loadout slot can be set to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VehicleLoadoutPart {
+    pub slot: VehicleLoadoutSlot,
+    pub path: String,
+    /// The chassis, part or skin which has to be unlocked before it can be picked.
+    pub unlock_path: Option<&'static str>,
+}
+
+/// Every chassis, part and skin in the `VEHICLE_*` tables along with the Catch-A-Ride asset which
+/// selects it. Most assets are named after the part, `VEHICLE_LOADOUT_PARTS` holds the ones which
+/// aren't (i.e. `CAR_Wheel_Outrunner_DuneBuggy` for `WT_Outrunner_BuggyWheels`) and the bodies and
+/// armor which don't need anything unlocked. There are no ornament or decal tables.
+pub static VEHICLE_LOADOUT_CATALOGUE: Lazy<Vec<VehicleLoadoutPart>> = Lazy::new(|| {
+    let mut catalogue = VEHICLE_LOADOUT_PARTS
+        .iter()
+        .filter_map(|gd| {
+            Some(VehicleLoadoutPart {
+                slot: VehicleLoadoutSlot::from_loadout_asset(gd.ident)?,
+                path: gd.ident.to_owned(),
+                unlock_path: Some(gd.name).filter(|n| !n.is_empty()),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for vehicle_name in LOADOUT_VEHICLE_NAMES {
+        for sub_type in [
+            VehicleSubType::Chassis,
+            VehicleSubType::Parts,
+            VehicleSubType::Skins,
+        ] {
+            let vehicle_type = match VehicleType::from_loadout_vehicle_name(vehicle_name, sub_type)
+            {
+                Some(vehicle_type) => vehicle_type,
+                None => continue,
+            };
+
+            for unlock_path in vehicle_type.data_set() {
+                if catalogue.iter().any(|p| p.unlock_path == Some(unlock_path)) {
+                    continue;
+                }
+
+                let (slot, name) =
+                    loadout_part_name(vehicle_name, vehicle_type.subtype(), unlock_path);
+
+                let asset_name = format!(
+                    "{}{}_{}",
+                    slot.asset_prefix().unwrap_or_default(),
+                    vehicle_name,
+                    name
+                );
+
+                let folder = if vehicle_name == "Horse" {
+                    "/Geranium/UI/CatchARideMenu/VehiclesAndParts"
+                } else {
+                    "/Game/UI/CatchARideMenu/VehicleAndParts"
+                };
+
+                catalogue.push(VehicleLoadoutPart {
+                    slot,
+                    path: format!("{}/{}/{}.{}", folder, vehicle_name, asset_name, asset_name),
+                    unlock_path: Some(unlock_path),
+                });
+            }
+        }
+    }
+
+    catalogue
+});
+
+// i.e. WT_Outrunner_HoverWheels -> Hover, VehiclePart_WeaponDriver_OutrunnerMachineGun_Native ->
+// MachineGun, VehiclePart_Mat_VehiclePart_Revolver_Jakobs -> Jakobs
+fn loadout_part_name<'a>(
+    vehicle_name: &str,
+    sub_type: &VehicleSubType,
+    unlock_path: &'a str,
+) -> (VehicleLoadoutSlot, &'a str) {
+    let object_name = loadout_asset_name(unlock_path);
+
+    let without_vehicle_name = |name: &'a str| {
+        name.strip_prefix(vehicle_name)
+            .map(|n| n.trim_start_matches('_'))
+            .or_else(|| {
+                name.strip_suffix(vehicle_name)
+                    .map(|n| n.trim_end_matches('_'))
+            })
+            .unwrap_or(name)
+    };
+
+    match sub_type {
+        VehicleSubType::Chassis => {
+            let name = object_name.trim_start_matches("WT_");
+
+            (
+                VehicleLoadoutSlot::Wheels,
+                without_vehicle_name(name).trim_end_matches("Wheels"),
+            )
+        }
+        VehicleSubType::Skins => {
+            let name = object_name.trim_start_matches("VehiclePart_Mat_VehiclePart_");
+
+            (VehicleLoadoutSlot::Material, without_vehicle_name(name))
+        }
+        VehicleSubType::Parts => {
+            let name = object_name
+                .trim_start_matches("VehiclePart_")
+                .trim_end_matches("_Native");
+
+            if let Some((_, armor)) = name.split_once("_Armor_") {
+                (VehicleLoadoutSlot::Armor, armor)
+            } else if let Some(weapon) = name.strip_prefix("WeaponDriver_") {
+                (
+                    VehicleLoadoutSlot::DriverWeapon,
+                    without_vehicle_name(weapon),
+                )
+            } else if let Some(weapon) = name.strip_prefix("Weapon_") {
+                (
+                    VehicleLoadoutSlot::GunnerWeapon,
+                    without_vehicle_name(weapon),
+                )
+            } else {
+                (
+                    VehicleLoadoutSlot::CoreMod,
+                    without_vehicle_name(name.trim_start_matches("CoreMod_")),
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct VehicleLoadout {
+    pub name: String,
+    pub body: String,
+    pub wheels: String,
+    pub armor: String,
+    pub core_mod: String,
+    pub gunner_weapon: String,
+    pub driver_weapon: String,
+    pub ornament: String,
+    pub decal: String,
+    pub material: String,
+    pub color_index_1: i32,
+    pub color_index_2: i32,
+    pub color_index_3: i32,
+}
+
+impl VehicleLoadout {
+    pub fn from_save_data(save_data: &OakCARMenuVehicleConfigSaveData) -> Self {
+        VehicleLoadout {
+            name: save_data.loadout_save_name.clone(),
+            body: save_data.body_asset_path.clone(),
+            wheels: save_data.wheel_asset_path.clone(),
+            armor: save_data.armor_asset_path.clone(),
+            core_mod: save_data.core_mod_asset_path.clone(),
+            gunner_weapon: save_data.gunner_weapon_asset_path.clone(),
+            driver_weapon: save_data.driver_weapon_asset_path.clone(),
+            ornament: save_data.ornament_asset_path.clone(),
+            // The game stores the selected CAR_Material_* asset in material_decal_asset_path
+            decal: save_data.material_asset_path.clone(),
+            material: save_data.material_decal_asset_path.clone(),
+            color_index_1: save_data.color_index_1,
+            color_index_2: save_data.color_index_2,
+            color_index_3: save_data.color_index_3,
+        }
+    }
+
+    pub fn apply_to_save_data(&self, save_data: &mut OakCARMenuVehicleConfigSaveData) {
+        save_data.loadout_save_name = self.name.clone();
+        save_data.body_asset_path = self.body.clone();
+        save_data.wheel_asset_path = self.wheels.clone();
+        save_data.armor_asset_path = self.armor.clone();
+        save_data.core_mod_asset_path = self.core_mod.clone();
+        save_data.gunner_weapon_asset_path = self.gunner_weapon.clone();
+        save_data.driver_weapon_asset_path = self.driver_weapon.clone();
+        save_data.ornament_asset_path = self.ornament.clone();
+        save_data.material_asset_path = self.decal.clone();
+        save_data.material_decal_asset_path = self.material.clone();
+        save_data.color_index_1 = self.color_index_1;
+        save_data.color_index_2 = self.color_index_2;
+        save_data.color_index_3 = self.color_index_3;
+    }
+
+    pub fn vehicle_name(&self) -> Option<&str> {
+        loadout_asset_vehicle_name(&self.body)
+    }
+
+    pub fn slot(&self, slot: VehicleLoadoutSlot) -> &str {
+        match slot {
+            VehicleLoadoutSlot::Body => &self.body,
+            VehicleLoadoutSlot::Wheels => &self.wheels,
+            VehicleLoadoutSlot::Armor => &self.armor,
+            VehicleLoadoutSlot::CoreMod => &self.core_mod,
+            VehicleLoadoutSlot::GunnerWeapon => &self.gunner_weapon,
+            VehicleLoadoutSlot::DriverWeapon => &self.driver_weapon,
+            VehicleLoadoutSlot::Ornament => &self.ornament,
+            VehicleLoadoutSlot::Decal => &self.decal,
+            VehicleLoadoutSlot::Material => &self.material,
+        }
+    }
+
+    pub fn set_slot(&mut self, slot: VehicleLoadoutSlot, path: String) {
+        let slot_path = match slot {
+            VehicleLoadoutSlot::Body => &mut self.body,
+            VehicleLoadoutSlot::Wheels => &mut self.wheels,
+            VehicleLoadoutSlot::Armor => &mut self.armor,
+            VehicleLoadoutSlot::CoreMod => &mut self.core_mod,
+            VehicleLoadoutSlot::GunnerWeapon => &mut self.gunner_weapon,
+            VehicleLoadoutSlot::DriverWeapon => &mut self.driver_weapon,
+            VehicleLoadoutSlot::Ornament => &mut self.ornament,
+            VehicleLoadoutSlot::Decal => &mut self.decal,
+            VehicleLoadoutSlot::Material => &mut self.material,
+        };
+
+        *slot_path = path;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::bl3_save::Bl3Save;
+    use crate::file_helper::Bl3FileType;
+    use crate::parser::HeaderType;
+    use crate::test_helper::read_test_save;
+
+    use super::*;
+
+    #[test]
+    fn test_loadout_asset_vehicle_name() {
+        let wheels = "/Game/UI/CatchARideMenu/VehicleAndParts/Outrunner/CAR_Wheel_Outrunner_DuneBuggy.CAR_Wheel_Outrunner_DuneBuggy";
+
+        assert_eq!(loadout_asset_name(wheels), "CAR_Wheel_Outrunner_DuneBuggy");
+        assert_eq!(loadout_asset_vehicle_name(wheels), Some("Outrunner"));
+        assert_eq!(
+            VehicleType::from_loadout_vehicle_name("Horse", VehicleSubType::Parts),
+            Some(VehicleType::Jetbeast(VehicleSubType::Parts))
+        );
+        assert_eq!(
+            VehicleType::from_loadout_vehicle_name("Unknown", VehicleSubType::Parts),
+            None
+        );
+    }
+
+    #[test]
+    fn test_vehicle_loadouts() {
+        let mut bl3_save = read_test_save("19.sav", HeaderType::PcSave);
+
+        let character = &bl3_save.character_data.character;
+
+        // Reading and applying a loadout leaves the save data as it was
+        for save_data in character.vehicle_loadouts.iter() {
+            let mut applied = save_data.clone();
+
+            VehicleLoadout::from_save_data(save_data).apply_to_save_data(&mut applied);
+
+            assert_eq!(applied, *save_data);
+        }
+
+        let loadouts = bl3_save.character_data.vehicle_loadouts();
+
+        assert_eq!(loadouts.len(), character.vehicle_loadouts.len());
+        assert!(!loadouts.is_empty());
+
+        let mut loadout = loadouts[0].clone();
+        loadout.name = "Test Loadout".to_owned();
+        loadout.color_index_1 = 3;
+        loadout.set_slot(
+            VehicleLoadoutSlot::Wheels,
+            "/Game/UI/CatchARideMenu/VehicleAndParts/Outrunner/CAR_Wheel_Outrunner_Hover.CAR_Wheel_Outrunner_Hover".to_owned(),
+        );
+
+        bl3_save
+            .character_data
+            .set_vehicle_loadout(0, &loadout)
+            .expect("failed to set vehicle loadout");
+
+        assert!(bl3_save
+            .character_data
+            .set_vehicle_loadout(loadouts.len(), &loadout)
+            .is_err());
+
+        assert!(bl3_save
+            .character_data
+            .set_vehicle_last_loadout_index(loadouts.len() as i32)
+            .is_err());
+        assert!(bl3_save
+            .character_data
+            .set_vehicle_last_loadout_index(-2)
+            .is_err());
+        bl3_save
+            .character_data
+            .set_vehicle_last_loadout_index(0)
+            .expect("failed to set last vehicle loadout");
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        assert_eq!(bl3_save.character_data.vehicle_loadouts()[0], loadout);
+        assert_eq!(bl3_save.character_data.vehicle_last_loadout_index(), 0);
+    }
+
+    #[test]
+    fn test_vehicle_asset_unlocks() {
+        let mut bl3_save = read_test_save("19.sav", HeaderType::PcSave);
+
+        let chassis = VehicleType::Outrunner(VehicleSubType::Chassis);
+
+        for asset in bl3_save.character_data.vehicle_assets(&chassis) {
+            bl3_save
+                .character_data
+                .set_vehicle_asset_unlocked(&chassis, &asset.path, false)
+                .expect("failed to lock vehicle asset");
+        }
+
+        assert!(bl3_save
+            .character_data
+            .set_vehicle_asset_unlocked(&chassis, VEHICLE_CHASSIS_JETBEAST[0], true)
+            .is_err());
+
+        let current = |bl3_save: &Bl3Save| {
+            bl3_save
+                .character_data
+                .vehicle_data()
+                .iter()
+                .find(|vd| vd.vehicle_type == chassis)
+                .map(|vd| vd.current)
+        };
+
+        assert_eq!(current(&bl3_save), Some(0));
+
+        // Bodies can only be picked for vehicles which have a chassis unlocked
+        let has_outrunner_body = |bl3_save: &Bl3Save| {
+            bl3_save
+                .character_data
+                .vehicle_loadout_options(VehicleLoadoutSlot::Body, None)
+                .iter()
+                .any(|o| loadout_asset_vehicle_name(o) == Some("Outrunner"))
+        };
+
+        assert!(!has_outrunner_body(&bl3_save));
+
+        bl3_save
+            .character_data
+            .set_vehicle_asset_unlocked(&chassis, VEHICLE_CHASSIS_OUTRUNNER[0], true)
+            .expect("failed to unlock vehicle asset");
+
+        assert_eq!(current(&bl3_save), Some(1));
+        assert!(has_outrunner_body(&bl3_save));
+
+        let (_, bl3_save) = bl3_save.as_bytes().expect("failed to write test save");
+
+        assert_eq!(
+            bl3_save
+                .character_data
+                .vehicle_assets(&chassis)
+                .iter()
+                .filter(|a| a.unlocked)
+                .map(|a| a.path.as_str())
+                .collect::<Vec<_>>(),
+            vec![VEHICLE_CHASSIS_OUTRUNNER[0]]
+        );
+    }
+
+    #[test]
+    fn test_vehicle_loadout_catalogue() {
+        let vehicle_types = LOADOUT_VEHICLE_NAMES.iter().flat_map(|v| {
+            [
+                VehicleSubType::Chassis,
+                VehicleSubType::Parts,
+                VehicleSubType::Skins,
+            ]
+            .into_iter()
+            .filter_map(move |sub_type| VehicleType::from_loadout_vehicle_name(v, sub_type))
+        });
+
+        // Every chassis, part and skin can be picked through exactly one asset
+        for vehicle_type in vehicle_types {
+            for unlock_path in vehicle_type.data_set() {
+                assert_eq!(
+                    VEHICLE_LOADOUT_CATALOGUE
+                        .iter()
+                        .filter(|p| p.unlock_path == Some(unlock_path))
+                        .count(),
+                    1,
+                    "{}",
+                    unlock_path
+                );
+            }
+        }
+
+        for part in VEHICLE_LOADOUT_CATALOGUE.iter() {
+            assert_eq!(
+                VehicleLoadoutSlot::from_loadout_asset(&part.path),
+                Some(part.slot)
+            );
+            assert!(LOADOUT_VEHICLE_NAMES
+                .iter()
+                .any(|v| loadout_asset_vehicle_name(&part.path) == Some(v)));
+            assert_eq!(
+                VEHICLE_LOADOUT_CATALOGUE
+                    .iter()
+                    .filter(|p| p.path == part.path)
+                    .count(),
+                1,
+                "{}",
+                part.path
+            );
+        }
+
+        let find = |unlock_path: &str| {
+            VEHICLE_LOADOUT_CATALOGUE
+                .iter()
+                .find(|p| p.unlock_path == Some(unlock_path))
+                .map(|p| loadout_asset_name(&p.path))
+        };
+
+        assert_eq!(
+            find(
+                "/Game/Vehicles/Outrunner/Design/WT_Outrunner_HoverWheels.WT_Outrunner_HoverWheels"
+            ),
+            Some("CAR_Wheel_Outrunner_Hover")
+        );
+        assert_eq!(
+            find("/Game/Vehicles/VehicleWeapons/DriverWeapons/Type_MachineGun/OutrunnerMachineGun/VehiclePart_WeaponDriver_OutrunnerMachineGun_Native.VehiclePart_WeaponDriver_OutrunnerMachineGun_Native"),
+            Some("CAR_DriverWeapon_Outrunner_MachineGun")
+        );
+        assert_eq!(
+            find("/Geranium/Vehicles/Horse/Design/Parts/CoreMod/TwinEngine/VehiclePart_TwinEngine_Horse.VehiclePart_TwinEngine_Horse"),
+            Some("CAR_CoreMod_Horse_TwinEngine")
+        );
+        assert_eq!(
+            find("/Game/Vehicles/Revolver/Design/Parts/Materials/VehiclePart_Mat_VehiclePart_Revolver_Jakobs.VehiclePart_Mat_VehiclePart_Revolver_Jakobs"),
+            Some("CAR_Material_Revolver_Jakobs")
+        );
+    }
+
+    #[test]
+    fn test_test_file_loadouts_resolve() {
+        let mut loadout_count = 0;
+
+        for entry in fs::read_dir("./test_files").expect("failed to read test_files") {
+            let path = entry.expect("failed to read test_files entry").path();
+
+            let data = fs::read(&path).expect("failed to read test_file");
+
+            let bl3_save = match Bl3FileType::from_unknown_data(&path, &data) {
+                Ok(Bl3FileType::PcSave(save)) | Ok(Bl3FileType::Ps4Save(save)) => save,
+                _ => continue,
+            };
+
+            let character_data = &bl3_save.character_data;
+
+            for loadout in character_data.vehicle_loadouts() {
+                loadout_count += 1;
+
+                for slot in VehicleLoadoutSlot::iter() {
+                    let asset = loadout.slot(slot);
+
+                    if asset.is_empty() {
+                        continue;
+                    }
+
+                    let part = VEHICLE_LOADOUT_CATALOGUE
+                        .iter()
+                        .find(|p| p.path == asset)
+                        .unwrap_or_else(|| panic!("{} isn't in the catalogue", asset));
+
+                    assert_eq!(part.slot, slot, "{}", asset);
+
+                    // A loadout can only use what the character has unlocked
+                    if let Some(unlock_path) = part.unlock_path {
+                        assert!(
+                            character_data.is_vehicle_asset_unlocked(unlock_path),
+                            "{} needs {} in {}",
+                            asset,
+                            unlock_path,
+                            path.display()
+                        );
+                    }
+
+                    assert!(character_data
+                        .vehicle_loadout_options(slot, loadout.vehicle_name())
+                        .iter()
+                        .any(|o| o == asset));
+                }
+            }
+        }
+
+        assert!(loadout_count > 0);
+    }
+}
@@ -3,19 +3,29 @@ use std::path::PathBuf;
 
 use iced::alignment::Horizontal;
 use iced::{
-    button, pick_list, svg, tooltip, Alignment, Application, Button, Color, Column, Command,
-    Container, Element, Length, PickList, Row, Svg, Text, Tooltip,
+    button, pick_list, svg, text_input, tooltip, Alignment, Application, Button, Color, Column,
+    Command, Container, Element, Length, PickList, Row, Svg, Text, Tooltip,
 };
 use tracing::{error, info};
 
+use bl3_save_edit_core::anonymize::Pseudonyms;
+use bl3_save_edit_core::bl3_profile::guardian_injection::{
+    guardian_injection_plans, GuardianInjectionPlan,
+};
+use bl3_save_edit_core::bl3_profile::key_bindings::KeyBindings;
 use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
+use bl3_save_edit_core::bl3_profile::settings::ProfileSettingsPreset;
+use bl3_save_edit_core::bl3_profile::vault_card::vault_card_gear;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
-use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
+use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
 use bl3_save_edit_core::bl3_save::util::{experience_to_level, REQUIRED_XP_LIST};
-use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::bl3_save::{save_file_name, Bl3Save};
+use bl3_save_edit_core::error::FileChangedError;
 use bl3_save_edit_core::file_helper::Bl3FileType;
 use bl3_save_edit_core::parser::HeaderType;
+use bl3_save_edit_core::vehicle_data::VehicleLoadoutSlot;
 
 use crate::bl3_ui_style::{
     Bl3UiContentStyle, Bl3UiMenuBarStyle, Bl3UiPositiveButtonStyle, Bl3UiStyle, Bl3UiTooltipStyle,
@@ -29,34 +39,53 @@ use crate::resources::svgs::REFRESH;
 use crate::state_mappers::{manage_profile, manage_save};
 use crate::update::Release;
 use crate::util::ErrorExt;
+use crate::views::backups::{BackupsInteractionMessage, BackupsState};
 use crate::views::choose_save_directory::{
     ChooseSaveDirectoryState, ChooseSaveInteractionMessage, ChooseSaveMessage,
 };
 use crate::views::initialization::InitializationMessage;
 use crate::views::item_editor::ItemEditorFileType;
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
+use crate::views::manage_profile::game_settings::{
+    GameSettingsInteractionMessage, GAME_SETTINGS_PRESETS_DIR,
+};
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
+use crate::views::manage_profile::guardian_injection::{
+    GuardianInjectionInteractionMessage, GuardianInjectionState,
+};
+use crate::views::manage_profile::key_bindings::{
+    KeyBindingsInteractionMessage, KEY_BINDINGS_PRESETS_DIR,
+};
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
 use crate::views::manage_profile::main::{ProfileTabBarInteractionMessage, ProfileTabBarView};
 use crate::views::manage_profile::profile::{
     GuardianRewardMessage, ProfileInteractionMessage, SduMessage, SkinUnlockedMessage,
 };
+use crate::views::manage_profile::vault_card::VaultCardInteractionMessage;
 use crate::views::manage_profile::{
     ManageProfileInteractionMessage, ManageProfileState, ManageProfileView,
 };
+use crate::views::manage_save::challenges::{
+    get_filtered_challenges, SaveChallengesInteractionMessage,
+};
 use crate::views::manage_save::character::{
-    CharacterAmmoMessage, CharacterGearUnlockedMessage, CharacterSduMessage,
+    CharacterAmmoMessage, CharacterColorMessage, CharacterGearUnlockedMessage, CharacterSduMessage,
     CharacterSkinSelectedMessage, SaveCharacterInteractionMessage,
 };
 use crate::views::manage_save::currency::SaveCurrencyInteractionMessage;
+use crate::views::manage_save::echo_logs::{
+    get_filtered_echo_logs, SaveEchoLogsInteractionMessage,
+};
 use crate::views::manage_save::general::SaveGeneralInteractionMessage;
 use crate::views::manage_save::inventory::SaveInventoryInteractionMessage;
 use crate::views::manage_save::main::{SaveTabBarInteractionMessage, SaveTabBarView};
+use crate::views::manage_save::regions::SaveRegionsInteractionMessage;
 use crate::views::manage_save::vehicle::{SaveVehicleInteractionMessage, VehicleUnlockedMessage};
 use crate::views::manage_save::{ManageSaveInteractionMessage, ManageSaveState, ManageSaveView};
 use crate::views::settings::{SettingsInteractionMessage, SettingsState};
 use crate::views::InteractionExt;
 use crate::widgets::notification::{Notification, NotificationSentiment};
+use crate::widgets::number_input::NumberInput;
 use crate::{state_mappers, update, views, VERSION};
 
 #[derive(Debug, Default)]
@@ -68,15 +97,23 @@ pub struct Bl3Application {
     pub manage_profile_state: ManageProfileState,
     loaded_files_selector: pick_list::State<Bl3FileType>,
     pub loaded_files_selected: Box<Bl3FileType>,
-    loaded_files: Vec<Bl3FileType>,
+    pub loaded_files: Vec<Bl3FileType>,
     refresh_button_state: button::State,
+    duplicate_button_state: button::State,
+    new_character_class_selector: pick_list::State<PlayerClass>,
+    new_character_class_selected: PlayerClass,
+    new_character_level_input_state: text_input::State,
+    new_character_level: i32,
+    new_character_button_state: button::State,
     update_button_state: button::State,
     save_file_button_state: button::State,
     notification: Option<Notification>,
     latest_release: Option<Release>,
     is_updating: bool,
     is_reloading_saves: bool,
+    file_changed_on_disk: bool,
     settings_state: SettingsState,
+    pseudonyms: Pseudonyms,
 }
 
 #[derive(Debug, Clone)]
@@ -89,7 +126,10 @@ pub enum Bl3Message {
     Interaction(InteractionMessage),
     ChooseSave(ChooseSaveMessage),
     SaveFileCompleted(MessageResult<Bl3Save>),
+    DuplicateCharacterCompleted(MessageResult<Bl3Save>),
+    NewCharacterCompleted(MessageResult<Bl3Save>),
     SaveProfileCompleted(MessageResult<Bl3Profile>),
+    FileChangedOnDisk(String),
     FilesLoadedAfterSave(MessageResult<(Bl3FileType, Vec<Bl3FileType>)>),
     ClearNotification,
 }
@@ -129,6 +169,10 @@ pub enum InteractionMessage {
     SettingsInteraction(SettingsInteractionMessage),
     LoadedFileSelected(Box<Bl3FileType>),
     RefreshSavesDirectory,
+    DuplicateCharacterPressed,
+    NewCharacterClassSelected(PlayerClass),
+    NewCharacterLevel(i32),
+    NewCharacterPressed,
     Ignore,
 }
 
@@ -147,6 +191,41 @@ impl std::default::Default for ViewState {
     }
 }
 
+impl Bl3Application {
+    fn next_save_slot(&self) -> u32 {
+        self.loaded_files
+            .iter()
+            .filter_map(|f| match f {
+                Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s) => {
+                    Some(s.character_data.character.save_game_id)
+                }
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn select_created_save(&mut self, save: Bl3Save, msg: String) {
+        self.notification = Some(Notification::new(msg, NotificationSentiment::Positive));
+
+        let bl3_file_type = match save.header_type {
+            HeaderType::Ps4Save => Bl3FileType::Ps4Save(save),
+            _ => Bl3FileType::PcSave(save),
+        };
+
+        self.loaded_files.push(bl3_file_type.clone());
+        self.loaded_files.sort();
+
+        self.loaded_files_selected = Box::new(bl3_file_type);
+
+        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+            "Failed to map loaded file to editor",
+            &mut self.notification,
+        );
+    }
+}
+
 impl Application for Bl3Application {
     type Executor = tokio::runtime::Runtime;
     type Message = Bl3Message;
@@ -167,6 +246,9 @@ impl Application for Bl3Application {
         let backup_dir_input = config.backup_dir().to_string_lossy().to_string();
         let ui_scale_factor = config.ui_scale_factor();
 
+        let mut backups_state = BackupsState::default();
+        backups_state.set_retention(config.backup_retention());
+
         (
             Bl3Application {
                 config,
@@ -176,8 +258,10 @@ impl Application for Bl3Application {
                     backup_dir_input,
                     saves_dir_input,
                     ui_scale_factor,
+                    backups_state,
                     ..SettingsState::default()
                 },
+                new_character_level: 1,
                 ..Bl3Application::default()
             },
             Command::batch(startup_commands),
@@ -317,10 +401,40 @@ impl Application for Bl3Application {
                                             ManageSaveView::TabBar(SaveTabBarView::Vehicle),
                                         )
                                     }
+                                    SaveTabBarInteractionMessage::Challenges => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Challenges),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::EchoLogs => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::EchoLogs),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::Regions => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Regions),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::CrewQuarters => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::CrewQuarters),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::Stats => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Stats),
+                                        )
+                                    }
                                     SaveTabBarInteractionMessage::Settings => {
                                         self.view_state = ViewState::ManageSave(
                                             ManageSaveView::TabBar(SaveTabBarView::Settings),
-                                        )
+                                        );
+
+                                        return load_backups_command(
+                                            &self.config,
+                                            &mut self.settings_state.backups_state,
+                                        );
                                     }
                                 }
                             }
@@ -333,7 +447,7 @@ impl Application for Bl3Application {
                                         .guid_input = guid;
                                 }
                                 SaveGeneralInteractionMessage::Slot(slot) => {
-                                    let filename = format!("{:x}.sav", slot);
+                                    let filename = save_file_name(slot);
 
                                     self.manage_save_state
                                         .save_view_state
@@ -362,6 +476,56 @@ impl Application for Bl3Application {
                                         .general_state
                                         .save_type_selected = save_type;
                                 }
+                                SaveGeneralInteractionMessage::ZoneMapRevealed(i, revealed) => {
+                                    // Maps already revealed in the save can't be hidden again
+                                    let already_revealed = self
+                                        .manage_save_state
+                                        .current_file
+                                        .character_data
+                                        .zone_map_levels()
+                                        .get(i)
+                                        .map(|l| l.fully_revealed)
+                                        .unwrap_or(false);
+
+                                    if let Some(level) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .zone_map_levels
+                                        .get_mut(i)
+                                    {
+                                        level.fully_revealed = revealed || already_revealed;
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::RevealAllZoneMapsPressed => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .zone_map_levels
+                                        .iter_mut()
+                                        .for_each(|l| l.fully_revealed = true);
+                                }
+                                SaveGeneralInteractionMessage::AnonymizePressed => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .anonymize = true;
+                                }
+                                SaveGeneralInteractionMessage::ResetUiTrackingPressed => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .ui_tracking
+                                        .reset();
+                                }
+                                SaveGeneralInteractionMessage::MarkUiTrackingSeenPressed => {
+                                    let general_state =
+                                        &mut self.manage_save_state.save_view_state.general_state;
+
+                                    general_state
+                                        .ui_tracking
+                                        .mark_all_seen(&general_state.unlockable_inventory_slots);
+                                }
                             },
                             ManageSaveInteractionMessage::Character(character_msg) => {
                                 match character_msg {
@@ -406,11 +570,12 @@ impl Application for Bl3Application {
                                             .ability_points_input = points;
                                     }
                                     SaveCharacterInteractionMessage::SduMessage(sdu_message) => {
-                                        let sdu_unlocker = &mut self
+                                        let character_state = &mut self
                                             .manage_save_state
                                             .save_view_state
-                                            .character_state
-                                            .sdu_unlocker;
+                                            .character_state;
+
+                                        let sdu_unlocker = &mut character_state.sdu_unlocker;
 
                                         match sdu_message {
                                             CharacterSduMessage::Backpack(level) => {
@@ -438,13 +603,22 @@ impl Application for Bl3Application {
                                                 sdu_unlocker.heavy.input = level;
                                             }
                                         }
+
+                                        for sdu in character_state.sdu_unlocker.fields() {
+                                            character_state.ammo_setter.set_sdu_level(
+                                                &sdu.sdu_slot,
+                                                sdu.input,
+                                                character_state.sdu_unlocker.refill_ammo,
+                                            );
+                                        }
                                     }
                                     SaveCharacterInteractionMessage::MaxSduSlotsPressed => {
-                                        let sdu_unlocker = &mut self
+                                        let character_state = &mut self
                                             .manage_save_state
                                             .save_view_state
-                                            .character_state
-                                            .sdu_unlocker;
+                                            .character_state;
+
+                                        let sdu_unlocker = &mut character_state.sdu_unlocker;
 
                                         sdu_unlocker.backpack.input =
                                             SaveSduSlot::Backpack.maximum();
@@ -463,6 +637,23 @@ impl Application for Bl3Application {
                                             SaveSduSlot::Ar.maximum();
 
                                         sdu_unlocker.heavy.input = SaveSduSlot::Heavy.maximum();
+
+                                        for sdu in character_state.sdu_unlocker.fields() {
+                                            character_state.ammo_setter.set_sdu_level(
+                                                &sdu.sdu_slot,
+                                                sdu.input,
+                                                character_state.sdu_unlocker.refill_ammo,
+                                            );
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::RefillAmmoOnSduChange(
+                                        refill_ammo,
+                                    ) => {
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .sdu_unlocker
+                                            .refill_ammo = refill_ammo;
                                     }
                                     SaveCharacterInteractionMessage::AmmoMessage(ammo_message) => {
                                         let ammo_setter = &mut self
@@ -496,25 +687,11 @@ impl Application for Bl3Application {
                                         }
                                     }
                                     SaveCharacterInteractionMessage::MaxAmmoAmountsPressed => {
-                                        let ammo_setter = &mut self
-                                            .manage_save_state
+                                        self.manage_save_state
                                             .save_view_state
                                             .character_state
-                                            .ammo_setter;
-
-                                        ammo_setter.sniper.input = AmmoPool::Sniper.maximum();
-
-                                        ammo_setter.shotgun.input = AmmoPool::Shotgun.maximum();
-
-                                        ammo_setter.pistol.input = AmmoPool::Pistol.maximum();
-
-                                        ammo_setter.grenade.input = AmmoPool::Grenade.maximum();
-
-                                        ammo_setter.smg.input = AmmoPool::Smg.maximum();
-
-                                        ammo_setter.assault_rifle.input = AmmoPool::Ar.maximum();
-
-                                        ammo_setter.heavy.input = AmmoPool::Heavy.maximum();
+                                            .ammo_setter
+                                            .max_all();
                                     }
                                     SaveCharacterInteractionMessage::PlayerClassSelected(
                                         player_class,
@@ -543,6 +720,59 @@ impl Application for Bl3Application {
                                             CharacterSkinSelectedMessage::EchoTheme(selected) => {
                                                 skin_selectors.echo_theme.selected = selected;
                                             }
+                                            CharacterSkinSelectedMessage::Emote(i, selected) => {
+                                                if let Some(emote) =
+                                                    skin_selectors.emotes.get_mut(i)
+                                                {
+                                                    emote.selected = selected;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::ColorMessage(color_msg) => {
+                                        let colors = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .color_editor
+                                            .colors;
+
+                                        match color_msg {
+                                            CharacterColorMessage::AppliedColor(i, channel, v) => {
+                                                if let Some(c) = colors.get_mut(i) {
+                                                    c.applied_rgb[channel] = v;
+                                                }
+                                            }
+                                            CharacterColorMessage::SplitColor(i, channel, v) => {
+                                                if let Some(c) = colors.get_mut(i) {
+                                                    c.split_rgb[channel] = v;
+                                                }
+                                            }
+                                            CharacterColorMessage::UseDefaultColor(i, selected) => {
+                                                if let Some(c) = colors.get_mut(i) {
+                                                    c.use_default_color = selected;
+                                                }
+                                            }
+                                            CharacterColorMessage::UseDefaultSplitColor(
+                                                i,
+                                                selected,
+                                            ) => {
+                                                if let Some(c) = colors.get_mut(i) {
+                                                    c.use_default_split_color = selected;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::GuardianPerk(i, enabled) => {
+                                        if let Some(p) = self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .guardian_perk_editor
+                                            .perks
+                                            .get_mut(i)
+                                        {
+                                            p.enabled = enabled;
                                         }
                                     }
                                     SaveCharacterInteractionMessage::GearMessage(gear_msg) => {
@@ -693,8 +923,352 @@ impl Application for Bl3Application {
                                             vehicle_unlocker.cyclone_skins.is_unlocked = selected;
                                         }
                                     }
+
+                                    let vehicle_state =
+                                        &mut self.manage_save_state.save_view_state.vehicle_state;
+
+                                    let unlocked_types = vehicle_state
+                                        .unlocker
+                                        .all_checkboxes()
+                                        .into_iter()
+                                        .filter(|vd| vd.is_unlocked)
+                                        .map(|vd| vd.vehicle_data.vehicle_type.clone())
+                                        .collect::<Vec<_>>();
+
+                                    vehicle_state
+                                        .asset_unlocker
+                                        .assets
+                                        .iter_mut()
+                                        .filter(|a| unlocked_types.contains(&a.category.0))
+                                        .for_each(|a| a.asset.unlocked = true);
+
+                                    manage_save::vehicle::map_vehicle_unlocks_to_loadout_options(
+                                        &mut self.manage_save_state,
+                                    );
+                                }
+                                SaveVehicleInteractionMessage::AssetCategorySelected(category) => {
+                                    let asset_unlocker = &mut self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .asset_unlocker;
+
+                                    asset_unlocker.category_selected = category;
+                                    asset_unlocker.asset_list_scrollable_state.snap_to(0.0);
+                                }
+                                SaveVehicleInteractionMessage::AssetUnlocked(index, unlocked) => {
+                                    if let Some(entry) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .asset_unlocker
+                                        .assets
+                                        .get_mut(index)
+                                    {
+                                        entry.asset.unlocked = unlocked;
+                                    }
+
+                                    manage_save::vehicle::map_vehicle_unlocks_to_loadout_options(
+                                        &mut self.manage_save_state,
+                                    );
+                                }
+                                SaveVehicleInteractionMessage::LoadoutSelected(loadout) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .loadout_editor
+                                        .loadout_selected = loadout;
+
+                                    manage_save::vehicle::map_vehicle_unlocks_to_loadout_options(
+                                        &mut self.manage_save_state,
+                                    );
+                                }
+                                SaveVehicleInteractionMessage::LoadoutSlot(slot, asset) => {
+                                    if slot == VehicleLoadoutSlot::Body {
+                                        let character_data =
+                                            manage_save::vehicle::character_data_with_vehicle_unlocks(
+                                                &self.manage_save_state,
+                                            );
+
+                                        self.manage_save_state
+                                            .save_view_state
+                                            .vehicle_state
+                                            .loadout_editor
+                                            .set_body(asset.0, &character_data);
+                                    } else if let Some(loadout) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .loadout_editor
+                                        .selected_loadout_mut()
+                                    {
+                                        loadout.set_slot(slot, asset.0);
+                                    }
+                                }
+                                SaveVehicleInteractionMessage::LoadoutColorIndex(n, value) => {
+                                    if let Some(loadout) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .loadout_editor
+                                        .selected_loadout_mut()
+                                    {
+                                        match n {
+                                            1 => loadout.color_index_1 = value,
+                                            2 => loadout.color_index_2 = value,
+                                            _ => loadout.color_index_3 = value,
+                                        }
+                                    }
+                                }
+                                SaveVehicleInteractionMessage::LastLoadoutIndex(index) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .loadout_editor
+                                        .last_loadout_index_input = index;
                                 }
                             },
+                            ManageSaveInteractionMessage::Challenges(challenges_msg) => {
+                                let challenges_state =
+                                    &mut self.manage_save_state.save_view_state.challenges_state;
+
+                                match challenges_msg {
+                                    SaveChallengesInteractionMessage::SearchInputChanged(
+                                        search_query,
+                                    ) => {
+                                        challenges_state.search_input = search_query.to_lowercase();
+                                    }
+                                    SaveChallengesInteractionMessage::CategorySelected(
+                                        category,
+                                    ) => {
+                                        challenges_state.category_selected = category;
+                                        challenges_state
+                                            .challenge_list_scrollable_state
+                                            .snap_to(0.0);
+                                    }
+                                    SaveChallengesInteractionMessage::CompleteAllPressed => {
+                                        let search_query = &challenges_state.search_input;
+                                        let category = challenges_state.category_selected;
+
+                                        challenges_state
+                                            .challenges
+                                            .iter_mut()
+                                            .filter(|c| {
+                                                get_filtered_challenges(
+                                                    search_query,
+                                                    category,
+                                                    &c.challenge,
+                                                )
+                                            })
+                                            .for_each(|c| {
+                                                c.challenge.currently_completed = true;
+                                                c.challenge.is_active = false;
+                                                c.challenge.completed_count =
+                                                    c.challenge.completed_count.max(1);
+                                            });
+                                    }
+                                    SaveChallengesInteractionMessage::CategoryCompletion(
+                                        category,
+                                        percentage,
+                                    ) => {
+                                        if let Some(input) = challenges_state
+                                            .category_completion_inputs
+                                            .get_mut(category as usize)
+                                        {
+                                            *input = percentage;
+                                        }
+                                    }
+                                    SaveChallengesInteractionMessage::Completed(i, completed) => {
+                                        if let Some(c) = challenges_state.challenges.get_mut(i) {
+                                            c.challenge.currently_completed = completed;
+                                            c.challenge.is_active = !completed;
+
+                                            if completed {
+                                                c.challenge.completed_count =
+                                                    c.challenge.completed_count.max(1);
+                                            }
+                                        }
+                                    }
+                                    SaveChallengesInteractionMessage::CompletedCount(i, count) => {
+                                        if let Some(c) = challenges_state.challenges.get_mut(i) {
+                                            c.challenge.completed_count = count;
+                                        }
+                                    }
+                                    SaveChallengesInteractionMessage::CompletedProgressLevel(
+                                        i,
+                                        level,
+                                    ) => {
+                                        if let Some(c) = challenges_state.challenges.get_mut(i) {
+                                            c.challenge.completed_progress_level = level;
+                                        }
+                                    }
+                                    SaveChallengesInteractionMessage::ProgressCounter(
+                                        i,
+                                        progress,
+                                    ) => {
+                                        if let Some(c) = challenges_state.challenges.get_mut(i) {
+                                            c.challenge.progress_counter = progress;
+                                        }
+                                    }
+                                    SaveChallengesInteractionMessage::StatValue(
+                                        i,
+                                        stat_index,
+                                        value,
+                                    ) => {
+                                        if let Some(stat) = challenges_state
+                                            .challenges
+                                            .get_mut(i)
+                                            .and_then(|c| c.challenge.stats.get_mut(stat_index))
+                                        {
+                                            stat.value = value;
+                                        }
+                                    }
+                                    SaveChallengesInteractionMessage::RewardClaimed(
+                                        i,
+                                        reward_index,
+                                        claimed,
+                                    ) => {
+                                        if let Some(reward_claimed) =
+                                            challenges_state.challenges.get_mut(i).and_then(|c| {
+                                                c.challenge.rewards_claimed.get_mut(reward_index)
+                                            })
+                                        {
+                                            *reward_claimed = claimed;
+                                        }
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::EchoLogs(echo_logs_msg) => {
+                                let echo_logs_state =
+                                    &mut self.manage_save_state.save_view_state.echo_logs_state;
+
+                                let search_query = &echo_logs_state.search_input;
+                                let group_selected = echo_logs_state.group_selected;
+
+                                match echo_logs_msg {
+                                    SaveEchoLogsInteractionMessage::SearchInputChanged(
+                                        search_query,
+                                    ) => {
+                                        echo_logs_state.search_input = search_query.to_lowercase();
+                                    }
+                                    SaveEchoLogsInteractionMessage::GroupSelected(group) => {
+                                        echo_logs_state.group_selected = group;
+                                        echo_logs_state.echo_log_list_scrollable_state.snap_to(0.0);
+                                    }
+                                    SaveEchoLogsInteractionMessage::UnlockAllPressed => {
+                                        echo_logs_state
+                                            .echo_logs
+                                            .iter_mut()
+                                            .filter(|e| {
+                                                get_filtered_echo_logs(
+                                                    search_query,
+                                                    group_selected,
+                                                    e,
+                                                )
+                                            })
+                                            .for_each(|e| e.unlocked = true);
+                                    }
+                                    SaveEchoLogsInteractionMessage::LockAllPressed => {
+                                        echo_logs_state
+                                            .echo_logs
+                                            .iter_mut()
+                                            .filter(|e| {
+                                                get_filtered_echo_logs(
+                                                    search_query,
+                                                    group_selected,
+                                                    e,
+                                                )
+                                            })
+                                            .for_each(|e| {
+                                                e.unlocked = false;
+                                                e.seen = false;
+                                            });
+                                    }
+                                    SaveEchoLogsInteractionMessage::MarkAllSeenPressed => {
+                                        echo_logs_state
+                                            .echo_logs
+                                            .iter_mut()
+                                            .filter(|e| {
+                                                e.unlocked
+                                                    && get_filtered_echo_logs(
+                                                        search_query,
+                                                        group_selected,
+                                                        e,
+                                                    )
+                                            })
+                                            .for_each(|e| e.seen = true);
+                                    }
+                                    SaveEchoLogsInteractionMessage::Unlocked(i, unlocked) => {
+                                        if let Some(echo_log) = echo_logs_state.echo_logs.get_mut(i)
+                                        {
+                                            echo_log.unlocked = unlocked;
+
+                                            if !unlocked {
+                                                echo_log.seen = false;
+                                            }
+                                        }
+                                    }
+                                    SaveEchoLogsInteractionMessage::Seen(i, seen) => {
+                                        if let Some(echo_log) = echo_logs_state.echo_logs.get_mut(i)
+                                        {
+                                            echo_log.seen = seen;
+
+                                            if seen {
+                                                echo_log.unlocked = true;
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::Regions(regions_msg) => {
+                                let regions_state =
+                                    &mut self.manage_save_state.save_view_state.regions_state;
+
+                                match regions_msg {
+                                    SaveRegionsInteractionMessage::PlaythroughSelected(
+                                        playthrough,
+                                    ) => {
+                                        regions_state.playthrough_selected = playthrough;
+                                        regions_state.region_list_scrollable_state.snap_to(0.0);
+                                    }
+                                    SaveRegionsInteractionMessage::GameStage(i, game_stage) => {
+                                        if let Some(r) = regions_state.regions.get_mut(i) {
+                                            r.region.game_stage = game_stage;
+                                        }
+                                    }
+                                    SaveRegionsInteractionMessage::SetAllGameStageInput(
+                                        game_stage,
+                                    ) => {
+                                        regions_state.set_all_input = game_stage;
+                                    }
+                                    SaveRegionsInteractionMessage::SetAllGameStagePressed => {
+                                        let playthrough_index =
+                                            regions_state.playthrough_selected.0;
+                                        let game_stage = regions_state.set_all_input;
+
+                                        regions_state
+                                            .regions
+                                            .iter_mut()
+                                            .filter(|r| {
+                                                r.region.playthrough_index == playthrough_index
+                                            })
+                                            .for_each(|r| r.region.game_stage = game_stage);
+                                    }
+                                    SaveRegionsInteractionMessage::ResetLevelPersistencePressed => {
+                                        regions_state.reset_level_persistence = true;
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::CrewQuarters(crew_quarters_msg) => {
+                                crew_quarters_msg.update_state(
+                                    &mut self.manage_save_state.save_view_state.crew_quarters_state,
+                                );
+                            }
+                            ManageSaveInteractionMessage::Stats(stats_msg) => {
+                                stats_msg.update_state(
+                                    &mut self.manage_save_state.save_view_state.stats_state,
+                                );
+                            }
                             ManageSaveInteractionMessage::SaveFilePressed => {
                                 //Lets not make any modifications to the current file just in case we have any errors
                                 let mut current_file = self.manage_save_state.current_file.clone();
@@ -702,6 +1276,7 @@ impl Application for Bl3Application {
                                 if let Err(e) = manage_save::map_all_states_to_save(
                                     &mut self.manage_save_state,
                                     &mut current_file,
+                                    &mut self.pseudonyms,
                                 ) {
                                     let msg = format!("Failed to save file: {}", e);
 
@@ -729,11 +1304,15 @@ impl Application for Bl3Application {
                                                 output,
                                                 self.manage_save_state.current_file.clone(),
                                                 save_file,
+                                                self.file_changed_on_disk,
                                             ),
-                                            |r| {
-                                                Bl3Message::SaveFileCompleted(
+                                            |r| match r {
+                                                Err(e) if e.is::<FileChangedError>() => {
+                                                    Bl3Message::FileChangedOnDisk(e.to_string())
+                                                }
+                                                r => Bl3Message::SaveFileCompleted(
                                                     MessageResult::handle_result(r),
-                                                )
+                                                ),
                                             },
                                         );
                                     }
@@ -765,6 +1344,11 @@ impl Application for Bl3Application {
                                             ManageProfileView::TabBar(ProfileTabBarView::Profile),
                                         )
                                     }
+                                    ProfileTabBarInteractionMessage::Science => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Science),
+                                        )
+                                    }
                                     ProfileTabBarInteractionMessage::Keys => {
                                         self.view_state = ViewState::ManageProfile(
                                             ManageProfileView::TabBar(ProfileTabBarView::Keys),
@@ -775,10 +1359,51 @@ impl Application for Bl3Application {
                                             ManageProfileView::TabBar(ProfileTabBarView::Bank),
                                         )
                                     }
+                                    ProfileTabBarInteractionMessage::Stats => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Stats),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::GameSettings => {
+                                        self.view_state =
+                                            ViewState::ManageProfile(ManageProfileView::TabBar(
+                                                ProfileTabBarView::GameSettings,
+                                            ));
+
+                                        return load_game_settings_presets_command(&self.config);
+                                    }
+                                    ProfileTabBarInteractionMessage::KeyBindings => {
+                                        self.view_state =
+                                            ViewState::ManageProfile(ManageProfileView::TabBar(
+                                                ProfileTabBarView::KeyBindings,
+                                            ));
+
+                                        return load_key_bindings_presets_command(&self.config);
+                                    }
+                                    ProfileTabBarInteractionMessage::Cosmetics => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Cosmetics),
+                                        );
+                                    }
+                                    ProfileTabBarInteractionMessage::VaultCard => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::VaultCard),
+                                        );
+                                    }
+                                    ProfileTabBarInteractionMessage::FirstRun => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::FirstRun),
+                                        );
+                                    }
                                     ProfileTabBarInteractionMessage::Settings => {
                                         self.view_state = ViewState::ManageProfile(
                                             ManageProfileView::TabBar(ProfileTabBarView::Settings),
-                                        )
+                                        );
+
+                                        return load_backups_command(
+                                            &self.config,
+                                            &mut self.settings_state.backups_state,
+                                        );
                                     }
                                 }
                             }
@@ -792,6 +1417,12 @@ impl Application for Bl3Application {
                                             .general_state
                                             .profile_type_selected = profile_type;
                                     }
+                                    ProfileGeneralInteractionMessage::AnonymizePressed => {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .general_state
+                                            .anonymize = true;
+                                    }
                                 }
                             }
                             ManageProfileInteractionMessage::Profile(profile_msg) => {
@@ -804,22 +1435,6 @@ impl Application for Bl3Application {
                                             .profile_state
                                             .guardian_rank_tokens_input = guardian_rank_tokens;
                                     }
-                                    ProfileInteractionMessage::ScienceLevelSelected(
-                                        science_level,
-                                    ) => {
-                                        self.manage_profile_state
-                                            .profile_view_state
-                                            .profile_state
-                                            .science_level_selected = science_level;
-                                    }
-                                    ProfileInteractionMessage::ScienceTokens(
-                                        science_level_tokens,
-                                    ) => {
-                                        self.manage_profile_state
-                                            .profile_view_state
-                                            .profile_state
-                                            .science_tokens_input = science_level_tokens;
-                                    }
                                     ProfileInteractionMessage::SkinMessage(skin_message) => {
                                         let skin_unlocker = &mut self
                                             .manage_profile_state
@@ -990,6 +1605,16 @@ impl Application for Bl3Application {
                                     }
                                 }
                             }
+                            ManageProfileInteractionMessage::BorderlandsScience(
+                                borderlands_science_msg,
+                            ) => {
+                                borderlands_science_msg.update_state(
+                                    &mut self
+                                        .manage_profile_state
+                                        .profile_view_state
+                                        .borderlands_science_state,
+                                );
+                            }
                             ManageProfileInteractionMessage::Keys(keys_message) => {
                                 let keys_state =
                                     &mut self.manage_profile_state.profile_view_state.keys_state;
@@ -1089,15 +1714,556 @@ impl Application for Bl3Application {
                                     }
                                 }
                             }
-                            ManageProfileInteractionMessage::SaveProfilePressed => {
-                                //Lets not make any modifications to the current file just in case we have any errors
-                                let mut current_file =
-                                    self.manage_profile_state.current_file.clone();
+                            ManageProfileInteractionMessage::Stats(stats_msg) => {
+                                stats_msg.update_state(
+                                    &mut self.manage_profile_state.profile_view_state.stats_state,
+                                );
+                            }
+                            ManageProfileInteractionMessage::KeyBindings(key_bindings_msg) => {
+                                let header_type =
+                                    self.manage_profile_state.current_file.header_type;
+
+                                let key_bindings_state = &mut self
+                                    .manage_profile_state
+                                    .profile_view_state
+                                    .key_bindings_state;
+
+                                let presets_dir = interaction::presets::presets_dir(
+                                    self.config.config_dir(),
+                                    KEY_BINDINGS_PRESETS_DIR,
+                                );
+
+                                match key_bindings_msg {
+                                    KeyBindingsInteractionMessage::PresetsLoaded(res) => {
+                                        match res {
+                                            MessageResult::Success(presets) => {
+                                                if !key_bindings_state
+                                                    .selected_preset
+                                                    .as_ref()
+                                                    .map(|p| presets.contains(p))
+                                                    .unwrap_or(true)
+                                                {
+                                                    key_bindings_state.selected_preset = None;
+                                                }
+
+                                                key_bindings_state.presets = presets;
+                                            }
+                                            MessageResult::Error(e) => {
+                                                let msg =
+                                                    format!("Failed to load key bindings: {}.", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    KeyBindingsInteractionMessage::ExportPressed => {
+                                        let data = match key_bindings_state.key_bindings.to_json() {
+                                            Ok(data) => data,
+                                            Err(e) => {
+                                                let msg = format!(
+                                                    "Failed to export key bindings: {}.",
+                                                    e
+                                                );
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+
+                                                return Command::none();
+                                            }
+                                        };
+
+                                        return Command::perform(
+                                            interaction::presets::write_preset(
+                                                presets_dir,
+                                                key_bindings_state.preset_name_input.clone(),
+                                                interaction::presets::JSON_EXTENSION,
+                                                data,
+                                            ),
+                                            |r| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::KeyBindings(
+                                                            KeyBindingsInteractionMessage::ExportCompleted(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    KeyBindingsInteractionMessage::ExportCompleted(res) => {
+                                        match res {
+                                            MessageResult::Success(path) => {
+                                                let msg = format!(
+                                                    "Exported key bindings to: {}",
+                                                    path.display()
+                                                );
+
+                                                info!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Positive,
+                                                ));
+
+                                                return load_key_bindings_presets_command(
+                                                    &self.config,
+                                                );
+                                            }
+                                            MessageResult::Error(e) => {
+                                                let msg =
+                                                    format!("Failed to export key bindings: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    KeyBindingsInteractionMessage::ImportPressed => {
+                                        let name = match &key_bindings_state.selected_preset {
+                                            Some(name) => name.clone(),
+                                            None => {
+                                                self.notification = Some(Notification::new(
+                                                    "Choose key bindings to import first.",
+                                                    NotificationSentiment::Negative,
+                                                ));
+
+                                                return Command::none();
+                                            }
+                                        };
+
+                                        return Command::perform(
+                                            interaction::presets::read_preset(
+                                                presets_dir,
+                                                name,
+                                                interaction::presets::JSON_EXTENSION,
+                                            ),
+                                            |r| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::KeyBindings(
+                                                            KeyBindingsInteractionMessage::ImportCompleted(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    KeyBindingsInteractionMessage::ImportCompleted(res) => {
+                                        let key_bindings = match res {
+                                            MessageResult::Success(data) => {
+                                                KeyBindings::from_json(&data)
+                                                    .map_err(|e| e.to_string())
+                                            }
+                                            MessageResult::Error(e) => Err(e),
+                                        };
+
+                                        match key_bindings {
+                                            Ok(mut key_bindings) => {
+                                                let removed = key_bindings
+                                                    .retain_supported_keys(&header_type);
+
+                                                key_bindings_state.set_key_bindings(key_bindings);
+
+                                                let msg = if removed > 0 {
+                                                    format!(
+                                                        "Imported key bindings, {} key(s) not supported by a {} were skipped. Save the profile to keep them.",
+                                                        removed, header_type
+                                                    )
+                                                } else {
+                                                    "Imported key bindings, save the profile to keep them.".to_owned()
+                                                };
+
+                                                info!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            Err(e) => {
+                                                let msg =
+                                                    format!("Failed to import key bindings: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    KeyBindingsInteractionMessage::OpenPresetsDirPressed => {
+                                        return Command::perform(
+                                            async move {
+                                                tokio::fs::create_dir_all(&presets_dir).await?;
+
+                                                interaction::settings::open_dir(presets_dir).await
+                                            },
+                                            |r| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::KeyBindings(
+                                                            KeyBindingsInteractionMessage::OpenPresetsDirCompleted(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    KeyBindingsInteractionMessage::OpenPresetsDirCompleted(res) => {
+                                        res.handle_ui_error(
+                                            "Failed to open key bindings folder",
+                                            &mut self.notification,
+                                        );
+                                    }
+                                    key_bindings_msg => {
+                                        key_bindings_msg.update_state(key_bindings_state)
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::Cosmetics(cosmetics_msg) => {
+                                cosmetics_msg.update_state(
+                                    &mut self
+                                        .manage_profile_state
+                                        .profile_view_state
+                                        .cosmetics_state,
+                                );
+                            }
+                            ManageProfileInteractionMessage::VaultCard(vault_card_msg) => {
+                                let profile_view_state =
+                                    &mut self.manage_profile_state.profile_view_state;
+
+                                match vault_card_msg {
+                                    VaultCardInteractionMessage::GearAddToBankPressed(
+                                        gear_index,
+                                    ) => {
+                                        let vault_card_id =
+                                            profile_view_state.vault_card_state.card_selected.0;
+
+                                        let gear = vault_card_gear(vault_card_id)
+                                            .into_iter()
+                                            .find(|g| g.gear_index == gear_index);
+
+                                        match gear.as_ref().and_then(|g| g.item().map(|i| (g, i))) {
+                                            Some((gear, item)) => {
+                                                profile_view_state
+                                                    .bank_state
+                                                    .item_editor_state
+                                                    .add_item(item);
+
+                                                let msg =
+                                                    format!("Added {} to your bank.", gear.name);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            None => {
+                                                let msg =
+                                                    "Failed to find an item for this gear reward.";
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    vault_card_msg => vault_card_msg
+                                        .update_state(&mut profile_view_state.vault_card_state),
+                                }
+                            }
+                            ManageProfileInteractionMessage::GameSettings(game_settings_msg) => {
+                                let game_settings_state = &mut self
+                                    .manage_profile_state
+                                    .profile_view_state
+                                    .game_settings_state;
+
+                                let presets_dir = interaction::presets::presets_dir(
+                                    self.config.config_dir(),
+                                    GAME_SETTINGS_PRESETS_DIR,
+                                );
+
+                                match game_settings_msg {
+                                    GameSettingsInteractionMessage::PresetsLoaded(res) => match res
+                                    {
+                                        MessageResult::Success(presets) => {
+                                            if !game_settings_state
+                                                .selected_preset
+                                                .as_ref()
+                                                .map(|p| presets.contains(p))
+                                                .unwrap_or(true)
+                                            {
+                                                game_settings_state.selected_preset = None;
+                                            }
+
+                                            game_settings_state.presets = presets;
+                                        }
+                                        MessageResult::Error(e) => {
+                                            let msg = format!("Failed to load presets: {}.", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    },
+                                    GameSettingsInteractionMessage::ExportPressed => {
+                                        let preset = game_settings_state.preset();
+
+                                        let data = match preset.to_ron() {
+                                            Ok(data) => data,
+                                            Err(e) => {
+                                                let msg =
+                                                    format!("Failed to export preset: {}.", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+
+                                                return Command::none();
+                                            }
+                                        };
+
+                                        return Command::perform(
+                                            interaction::presets::write_preset(
+                                                presets_dir,
+                                                game_settings_state.preset_name_input.clone(),
+                                                interaction::presets::RON_EXTENSION,
+                                                data,
+                                            ),
+                                            |r| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::GameSettings(
+                                                            GameSettingsInteractionMessage::ExportCompleted(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    GameSettingsInteractionMessage::ExportCompleted(res) => {
+                                        match res {
+                                            MessageResult::Success(path) => {
+                                                let msg = format!(
+                                                    "Exported game settings to: {}",
+                                                    path.display()
+                                                );
+
+                                                info!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Positive,
+                                                ));
+
+                                                return load_game_settings_presets_command(
+                                                    &self.config,
+                                                );
+                                            }
+                                            MessageResult::Error(e) => {
+                                                let msg = format!("Failed to export preset: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    GameSettingsInteractionMessage::ImportPressed => {
+                                        let name = match &game_settings_state.selected_preset {
+                                            Some(name) => name.clone(),
+                                            None => {
+                                                self.notification = Some(Notification::new(
+                                                    "Choose a preset to import first.",
+                                                    NotificationSentiment::Negative,
+                                                ));
+
+                                                return Command::none();
+                                            }
+                                        };
+
+                                        return Command::perform(
+                                            interaction::presets::read_preset(
+                                                presets_dir,
+                                                name,
+                                                interaction::presets::RON_EXTENSION,
+                                            ),
+                                            |r| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::GameSettings(
+                                                            GameSettingsInteractionMessage::ImportCompleted(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    GameSettingsInteractionMessage::ImportCompleted(res) => {
+                                        let preset = match res {
+                                            MessageResult::Success(data) => {
+                                                ProfileSettingsPreset::from_ron(&data)
+                                                    .map_err(|e| e.to_string())
+                                            }
+                                            MessageResult::Error(e) => Err(e),
+                                        };
+
+                                        match preset {
+                                            Ok(preset) => {
+                                                game_settings_state.apply_preset(&preset);
+
+                                                let msg = format!(
+                                                    "Imported {} game setting(s), save the profile to keep them.",
+                                                    preset.settings.len()
+                                                );
+
+                                                info!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            Err(e) => {
+                                                let msg = format!("Failed to import preset: {}", e);
+
+                                                error!("{}", msg);
+
+                                                self.notification = Some(Notification::new(
+                                                    msg,
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    GameSettingsInteractionMessage::OpenPresetsDirPressed => {
+                                        return Command::perform(
+                                            async move {
+                                                tokio::fs::create_dir_all(&presets_dir).await?;
+
+                                                interaction::settings::open_dir(presets_dir).await
+                                            },
+                                            |r| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::GameSettings(
+                                                            GameSettingsInteractionMessage::OpenPresetsDirCompleted(
+                                                                MessageResult::handle_result(r),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    GameSettingsInteractionMessage::OpenPresetsDirCompleted(
+                                        res,
+                                    ) => {
+                                        res.handle_ui_error(
+                                            "Failed to open presets folder",
+                                            &mut self.notification,
+                                        );
+                                    }
+                                    game_settings_msg => {
+                                        game_settings_msg.update_state(game_settings_state)
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::FirstRun(first_run_msg) => {
+                                first_run_msg.update_state(
+                                    &mut self
+                                        .manage_profile_state
+                                        .profile_view_state
+                                        .first_run_state,
+                                );
+                            }
+                            ManageProfileInteractionMessage::GuardianInjection(injection_msg) => {
+                                let guardian_injection_state = &mut self
+                                    .manage_profile_state
+                                    .profile_view_state
+                                    .guardian_injection_state;
+
+                                match injection_msg {
+                                    GuardianInjectionInteractionMessage::Selected(i, selected) => {
+                                        if let Some(entry) =
+                                            guardian_injection_state.entries.get_mut(i)
+                                        {
+                                            entry.selected = selected;
+                                        }
+                                    }
+                                    GuardianInjectionInteractionMessage::ConfirmPressed => {
+                                        let guardian_injection_state =
+                                            mem::take(guardian_injection_state);
+
+                                        let selected_plans =
+                                            guardian_injection_state.selected_plans();
+
+                                        if let Some(new_profile) =
+                                            guardian_injection_state.pending_profile
+                                        {
+                                            return save_profile_command(
+                                                &self.config,
+                                                self.manage_profile_state.current_file.clone(),
+                                                new_profile,
+                                                selected_plans,
+                                                self.file_changed_on_disk,
+                                                &mut self.notification,
+                                            );
+                                        }
+                                    }
+                                    GuardianInjectionInteractionMessage::CancelPressed => {
+                                        *guardian_injection_state =
+                                            GuardianInjectionState::default();
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::SaveProfilePressed => {
+                                //Lets not make any modifications to the current file just in case we have any errors
+                                let mut current_file =
+                                    self.manage_profile_state.current_file.clone();
 
                                 let guardian_data_injection_required =
                                     match manage_profile::map_all_states_to_profile(
                                         &mut self.manage_profile_state,
                                         &mut current_file,
+                                        &mut self.pseudonyms,
                                     ) {
                                         Ok(injection_required) => injection_required,
                                         Err(e) => {
@@ -1114,41 +2280,34 @@ impl Application for Bl3Application {
                                         }
                                     };
 
-                                let output_file = self
-                                    .config
-                                    .saves_dir()
-                                    .join(&self.manage_profile_state.current_file.file_name);
+                                if guardian_data_injection_required {
+                                    let saves = self.loaded_files.iter().filter_map(|f| match f {
+                                        Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s) => Some(s),
+                                        _ => None,
+                                    });
 
-                                match current_file.as_bytes() {
-                                    Ok((output, profile)) => {
-                                        return Command::perform(
-                                            interaction::file_save::save_profile(
-                                                self.config.backup_dir().to_path_buf(),
-                                                self.config.saves_dir().to_path_buf(),
-                                                output_file,
-                                                output,
-                                                self.manage_profile_state.current_file.clone(),
-                                                profile,
-                                                guardian_data_injection_required,
-                                            ),
-                                            |r| {
-                                                Bl3Message::SaveProfileCompleted(
-                                                    MessageResult::handle_result(r),
-                                                )
-                                            },
-                                        );
-                                    }
-                                    Err(e) => {
-                                        let msg = format!("Failed to save file: {}", e);
+                                    let plans =
+                                        guardian_injection_plans(&current_file.profile_data, saves);
 
-                                        error!("{}", msg);
+                                    // Let the user choose which saves receive the new guardian data before writing anything.
+                                    if !plans.is_empty() {
+                                        self.manage_profile_state
+                                            .profile_view_state
+                                            .guardian_injection_state =
+                                            GuardianInjectionState::new(current_file, plans);
 
-                                        self.notification = Some(Notification::new(
-                                            msg,
-                                            NotificationSentiment::Negative,
-                                        ));
+                                        return Command::none();
                                     }
-                                };
+                                }
+
+                                return save_profile_command(
+                                    &self.config,
+                                    self.manage_profile_state.current_file.clone(),
+                                    current_file,
+                                    Vec::new(),
+                                    self.file_changed_on_disk,
+                                    &mut self.notification,
+                                );
                             }
                         }
                     }
@@ -1336,9 +2495,205 @@ impl Application for Bl3Application {
                                 });
                             }
                         }
+                        SettingsInteractionMessage::Backups(backups_msg) => match backups_msg {
+                            BackupsInteractionMessage::RefreshPressed => {
+                                return load_backups_command(
+                                    &self.config,
+                                    &mut self.settings_state.backups_state,
+                                );
+                            }
+                            BackupsInteractionMessage::Loaded(res) => {
+                                self.settings_state.backups_state.is_busy = false;
+
+                                match res {
+                                    MessageResult::Success(backups) => {
+                                        self.settings_state.backups_state.set_backups(backups);
+                                    }
+                                    MessageResult::Error(e) => {
+                                        let msg = format!("Failed to load backups: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                            BackupsInteractionMessage::BackupSelected(i) => {
+                                self.settings_state
+                                    .backups_state
+                                    .select(i, &self.loaded_files);
+                            }
+                            BackupsInteractionMessage::RestorePressed => {
+                                if let Some(backup) =
+                                    self.settings_state.backups_state.selected_backup()
+                                {
+                                    let backup = backup.clone();
+
+                                    self.settings_state.backups_state.is_busy = true;
+
+                                    return Command::perform(
+                                        interaction::backups::restore_backup(
+                                            self.config.backup_dir().to_path_buf(),
+                                            backup,
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::SettingsInteraction(
+                                                    SettingsInteractionMessage::Backups(
+                                                        BackupsInteractionMessage::RestoreCompleted(
+                                                            MessageResult::handle_result(r),
+                                                        ),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                            }
+                            BackupsInteractionMessage::RestoreCompleted(res) => {
+                                self.settings_state.backups_state.is_busy = false;
+
+                                match res {
+                                    MessageResult::Success(path) => {
+                                        let msg = format!("Restored backup to: {}", path.display());
+
+                                        info!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Positive,
+                                        ));
+
+                                        self.view_state = ViewState::Loading;
+                                        self.file_changed_on_disk = false;
+
+                                        return Command::batch(vec![
+                                            Command::perform(
+                                                interaction::choose_save_directory::load_files_in_directory(
+                                                    self.config.saves_dir().to_path_buf(),
+                                                ),
+                                                |r| {
+                                                    Bl3Message::ChooseSave(
+                                                        ChooseSaveMessage::FilesLoaded(
+                                                            MessageResult::handle_result(r),
+                                                        ),
+                                                    )
+                                                },
+                                            ),
+                                            load_backups_command(
+                                                &self.config,
+                                                &mut self.settings_state.backups_state,
+                                            ),
+                                        ]);
+                                    }
+                                    MessageResult::Error(e) => {
+                                        let msg = format!("Failed to restore backup: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                            BackupsInteractionMessage::KeepPerFile(keep_per_file) => {
+                                self.settings_state.backups_state.keep_per_file_input =
+                                    keep_per_file;
+
+                                self.config.set_backup_retention(
+                                    self.settings_state.backups_state.retention(),
+                                );
+
+                                return Command::perform(self.config.clone().save(), |r| {
+                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                        MessageResult::handle_result(r),
+                                    ))
+                                });
+                            }
+                            BackupsInteractionMessage::MaxAgeDays(max_age_days) => {
+                                self.settings_state.backups_state.max_age_days_input = max_age_days;
+
+                                self.config.set_backup_retention(
+                                    self.settings_state.backups_state.retention(),
+                                );
+
+                                return Command::perform(self.config.clone().save(), |r| {
+                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                        MessageResult::handle_result(r),
+                                    ))
+                                });
+                            }
+                            BackupsInteractionMessage::PrunePressed => {
+                                let retention = self.config.backup_retention();
+
+                                if !retention.is_enabled() {
+                                    self.notification = Some(Notification::new(
+                                        "Set how many backups to keep per file or a maximum age before removing old backups.",
+                                        NotificationSentiment::Negative,
+                                    ));
+                                } else {
+                                    self.settings_state.backups_state.is_busy = true;
+
+                                    return Command::perform(
+                                        interaction::backups::prune_backups(
+                                            self.config.backup_dir().to_path_buf(),
+                                            retention,
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::SettingsInteraction(
+                                                    SettingsInteractionMessage::Backups(
+                                                        BackupsInteractionMessage::PruneCompleted(
+                                                            MessageResult::handle_result(r),
+                                                        ),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                            }
+                            BackupsInteractionMessage::PruneCompleted(res) => {
+                                self.settings_state.backups_state.is_busy = false;
+
+                                match res {
+                                    MessageResult::Success(removed) => {
+                                        let msg = format!("Removed {} old backup(s).", removed);
+
+                                        info!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Positive,
+                                        ));
+
+                                        return load_backups_command(
+                                            &self.config,
+                                            &mut self.settings_state.backups_state,
+                                        );
+                                    }
+                                    MessageResult::Error(e) => {
+                                        let msg = format!("Failed to remove old backups: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                        },
                     },
                     InteractionMessage::LoadedFileSelected(loaded_file) => {
                         self.loaded_files_selected = loaded_file;
+                        self.file_changed_on_disk = false;
 
                         state_mappers::map_loaded_file_to_state(self).handle_ui_error(
                             "Failed to map loaded file to editor",
@@ -1347,6 +2702,7 @@ impl Application for Bl3Application {
                     }
                     InteractionMessage::RefreshSavesDirectory => {
                         self.view_state = ViewState::Loading;
+                        self.file_changed_on_disk = false;
 
                         return Command::perform(
                             interaction::choose_save_directory::load_files_in_directory(
@@ -1359,6 +2715,57 @@ impl Application for Bl3Application {
                             },
                         );
                     }
+                    InteractionMessage::DuplicateCharacterPressed => {
+                        if let Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) =
+                            &*self.loaded_files_selected
+                        {
+                            let next_slot = self.next_save_slot();
+
+                            let name = format!(
+                                "{} (Copy)",
+                                save.character_data.character.preferred_character_name
+                            );
+
+                            return Command::perform(
+                                interaction::file_save::duplicate_save(
+                                    self.config.saves_dir().to_path_buf(),
+                                    save.clone(),
+                                    next_slot,
+                                    name,
+                                ),
+                                |r| {
+                                    Bl3Message::DuplicateCharacterCompleted(
+                                        MessageResult::handle_result(r),
+                                    )
+                                },
+                            );
+                        }
+                    }
+                    InteractionMessage::NewCharacterClassSelected(player_class) => {
+                        self.new_character_class_selected = player_class;
+                    }
+                    InteractionMessage::NewCharacterLevel(level) => {
+                        self.new_character_level = level;
+                    }
+                    InteractionMessage::NewCharacterPressed => {
+                        let header_type = match &*self.loaded_files_selected {
+                            Bl3FileType::Ps4Save(_) | Bl3FileType::Ps4Profile(_) => {
+                                HeaderType::Ps4Save
+                            }
+                            _ => HeaderType::PcSave,
+                        };
+
+                        return Command::perform(
+                            interaction::file_save::new_character(
+                                self.config.saves_dir().to_path_buf(),
+                                self.new_character_class_selected,
+                                self.new_character_level,
+                                header_type,
+                                self.next_save_slot(),
+                            ),
+                            |r| Bl3Message::NewCharacterCompleted(MessageResult::handle_result(r)),
+                        );
+                    }
                     InteractionMessage::Ignore => {}
                 }
             }
@@ -1435,6 +2842,10 @@ impl Application for Bl3Application {
                         NotificationSentiment::Positive,
                     ));
 
+                    self.file_changed_on_disk = false;
+
+                    self.manage_save_state.current_file.file_fingerprint = save.file_fingerprint;
+
                     self.is_reloading_saves = true;
 
                     let bl3_file_type = match save.header_type {
@@ -1465,6 +2876,44 @@ impl Application for Bl3Application {
                         Some(Notification::new(msg, NotificationSentiment::Negative));
                 }
             },
+            Bl3Message::DuplicateCharacterCompleted(res) => match res {
+                MessageResult::Success(save) => {
+                    let msg = format!(
+                        "Duplicated character to {} (slot {})",
+                        save.file_name, save.character_data.character.save_game_id
+                    );
+
+                    self.select_created_save(save, msg);
+                }
+                MessageResult::Error(e) => {
+                    let msg = format!("Failed to duplicate character: {}", e);
+
+                    error!("{}", msg);
+
+                    self.notification =
+                        Some(Notification::new(msg, NotificationSentiment::Negative));
+                }
+            },
+            Bl3Message::NewCharacterCompleted(res) => match res {
+                MessageResult::Success(save) => {
+                    let msg = format!(
+                        "Created new {} in {} (slot {})",
+                        save.character_data.player_class(),
+                        save.file_name,
+                        save.character_data.character.save_game_id
+                    );
+
+                    self.select_created_save(save, msg);
+                }
+                MessageResult::Error(e) => {
+                    let msg = format!("Failed to create new character: {}", e);
+
+                    error!("{}", msg);
+
+                    self.notification =
+                        Some(Notification::new(msg, NotificationSentiment::Negative));
+                }
+            },
             Bl3Message::SaveProfileCompleted(res) => match res {
                 MessageResult::Success(profile) => {
                     self.notification = Some(Notification::new(
@@ -1472,6 +2921,11 @@ impl Application for Bl3Application {
                         NotificationSentiment::Positive,
                     ));
 
+                    self.file_changed_on_disk = false;
+
+                    self.manage_profile_state.current_file.file_fingerprint =
+                        profile.file_fingerprint;
+
                     self.is_reloading_saves = true;
 
                     let bl3_file_type = match profile.header_type {
@@ -1502,6 +2956,18 @@ impl Application for Bl3Application {
                         Some(Notification::new(msg, NotificationSentiment::Negative));
                 }
             },
+            Bl3Message::FileChangedOnDisk(e) => {
+                let msg = format!(
+                    "Failed to save: {}. Refresh the saves folder to load the changes or press Force Save to overwrite them",
+                    e
+                );
+
+                error!("{}", msg);
+
+                self.file_changed_on_disk = true;
+
+                self.notification = Some(Notification::new(msg, NotificationSentiment::Negative));
+            }
             Bl3Message::FilesLoadedAfterSave(res) => {
                 match res {
                     MessageResult::Success((saved_file, mut files)) => {
@@ -1588,6 +3054,90 @@ impl Application for Bl3Application {
         .size(17)
         .style(Bl3UiTooltipStyle);
 
+        let mut duplicate_button = Button::new(
+            &mut self.duplicate_button_state,
+            Text::new("Duplicate").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .padding(10)
+        .style(Bl3UiStyle);
+
+        if !self.is_reloading_saves {
+            duplicate_button =
+                duplicate_button.on_press(InteractionMessage::DuplicateCharacterPressed);
+        }
+
+        let duplicate_button = Tooltip::new(
+            duplicate_button.into_element(),
+            "Duplicate character into a new save slot",
+            tooltip::Position::Bottom,
+        )
+        .gap(10)
+        .padding(10)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .style(Bl3UiTooltipStyle);
+
+        let new_character_class_picklist = PickList::new(
+            &mut self.new_character_class_selector,
+            &PlayerClass::ALL[..],
+            Some(self.new_character_class_selected),
+            InteractionMessage::NewCharacterClassSelected,
+        )
+        .font(JETBRAINS_MONO)
+        .text_size(17)
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        let new_character_level_input = Tooltip::new(
+            NumberInput::new(
+                &mut self.new_character_level_input_state,
+                self.new_character_level,
+                1,
+                Some(MAX_CHARACTER_LEVEL as i32),
+                InteractionMessage::NewCharacterLevel,
+            )
+            .0
+            .width(Length::Units(60))
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+            format!("Level must be between 1 and {}", MAX_CHARACTER_LEVEL),
+            tooltip::Position::Bottom,
+        )
+        .gap(10)
+        .padding(10)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .style(Bl3UiTooltipStyle);
+
+        let mut new_character_button = Button::new(
+            &mut self.new_character_button_state,
+            Text::new("New Character")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .padding(10)
+        .style(Bl3UiStyle);
+
+        if !self.is_reloading_saves {
+            new_character_button =
+                new_character_button.on_press(InteractionMessage::NewCharacterPressed);
+        }
+
+        let new_character_button = Tooltip::new(
+            new_character_button.into_element(),
+            "Create a new character of this class and level in a new save slot",
+            tooltip::Position::Bottom,
+        )
+        .gap(10)
+        .padding(10)
+        .font(JETBRAINS_MONO)
+        .size(17)
+        .style(Bl3UiTooltipStyle);
+
         let all_saves_picklist = if !self.is_reloading_saves {
             PickList::new(
                 &mut self.loaded_files_selector,
@@ -1622,9 +3172,17 @@ impl Application for Bl3Application {
             ManageProfileView::TabBar(ProfileTabBarView::General),
         ));
 
+        let save_button_text = if self.file_changed_on_disk {
+            "Force Save"
+        } else {
+            "Save"
+        };
+
         let mut save_button = Button::new(
             &mut self.save_file_button_state,
-            Text::new("Save").font(JETBRAINS_MONO_BOLD).size(17),
+            Text::new(save_button_text)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
         )
         .padding(10)
         .style(Bl3UiStyle);
@@ -1648,6 +3206,16 @@ impl Application for Bl3Application {
         {
             menu_bar_editor_content = menu_bar_editor_content.push(refresh_button);
             menu_bar_editor_content = menu_bar_editor_content.push(all_saves_picklist);
+
+            if view_state_discrim == manage_save_discrim {
+                menu_bar_editor_content = menu_bar_editor_content.push(duplicate_button);
+            }
+
+            menu_bar_editor_content = menu_bar_editor_content
+                .push(new_character_class_picklist)
+                .push(new_character_level_input)
+                .push(new_character_button);
+
             menu_bar_editor_content = menu_bar_editor_content.push(save_button.into_element());
         }
 
@@ -1735,3 +3303,97 @@ impl Application for Bl3Application {
         self.settings_state.ui_scale_factor
     }
 }
+
+fn load_backups_command(
+    config: &Bl3Config,
+    backups_state: &mut BackupsState,
+) -> Command<Bl3Message> {
+    backups_state.is_busy = true;
+
+    Command::perform(
+        interaction::backups::load_backups(
+            config.backup_dir().to_path_buf(),
+            config.saves_dir().to_path_buf(),
+        ),
+        |r| {
+            Bl3Message::Interaction(InteractionMessage::SettingsInteraction(
+                SettingsInteractionMessage::Backups(BackupsInteractionMessage::Loaded(
+                    MessageResult::handle_result(r),
+                )),
+            ))
+        },
+    )
+}
+
+fn load_game_settings_presets_command(config: &Bl3Config) -> Command<Bl3Message> {
+    Command::perform(
+        interaction::presets::list_presets(
+            interaction::presets::presets_dir(config.config_dir(), GAME_SETTINGS_PRESETS_DIR),
+            interaction::presets::RON_EXTENSION,
+        ),
+        |r| {
+            Bl3Message::Interaction(InteractionMessage::ManageProfileInteraction(
+                ManageProfileInteractionMessage::GameSettings(
+                    GameSettingsInteractionMessage::PresetsLoaded(MessageResult::handle_result(r)),
+                ),
+            ))
+        },
+    )
+}
+
+fn load_key_bindings_presets_command(config: &Bl3Config) -> Command<Bl3Message> {
+    Command::perform(
+        interaction::presets::list_presets(
+            interaction::presets::presets_dir(config.config_dir(), KEY_BINDINGS_PRESETS_DIR),
+            interaction::presets::JSON_EXTENSION,
+        ),
+        |r| {
+            Bl3Message::Interaction(InteractionMessage::ManageProfileInteraction(
+                ManageProfileInteractionMessage::KeyBindings(
+                    KeyBindingsInteractionMessage::PresetsLoaded(MessageResult::handle_result(r)),
+                ),
+            ))
+        },
+    )
+}
+
+fn save_profile_command(
+    config: &Bl3Config,
+    existing_profile: Bl3Profile,
+    new_profile: Bl3Profile,
+    guardian_injection_plans: Vec<GuardianInjectionPlan>,
+    force: bool,
+    notification: &mut Option<Notification>,
+) -> Command<Bl3Message> {
+    let output_file = config.saves_dir().join(&existing_profile.file_name);
+
+    match new_profile.as_bytes() {
+        Ok((output, profile)) => Command::perform(
+            interaction::file_save::save_profile(
+                config.backup_dir().to_path_buf(),
+                config.saves_dir().to_path_buf(),
+                output_file,
+                output,
+                existing_profile,
+                profile,
+                guardian_injection_plans,
+                force,
+            ),
+            |r| match r {
+                Err(e) if e.is::<FileChangedError>() => {
+                    Bl3Message::FileChangedOnDisk(e.to_string())
+                }
+                r => Bl3Message::SaveProfileCompleted(MessageResult::handle_result(r)),
+            },
+        ),
+        Err(e) => {
+            let msg = format!("Failed to save file: {}", e);
+
+            error!("{}", msg);
+
+            *notification = Some(Notification::new(msg, NotificationSentiment::Negative));
+
+            Command::none()
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use pico_args::Arguments;
+
+use bl3_save_edit_core::anonymize::Pseudonyms;
+use bl3_save_edit_core::backup_store::BackupStore;
+use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::file_helper::{write_new_file_atomic, Bl3FileType};
+use bl3_save_edit_core::parser::HeaderType;
+
+use crate::config::Bl3Config;
+use crate::VERSION;
+
+const USAGE: &str = "\
+Usage:
+  bl3_save_edit_ui new-character --class <CLASS> --slot <SLOT> [--level <LEVEL>] [--name <NAME>] [--platform pc|ps4] [--saves-dir <DIR>]
+  bl3_save_edit_ui backups list [--file <FILE_NAME>] [--backup-dir <DIR>]
+  bl3_save_edit_ui backups restore --id <ID> [--to <FILE>] [--backup-dir <DIR>]
+  bl3_save_edit_ui anonymize --file <FILE> --to <FILE> [--file <FILE> --to <FILE>...]
+
+Classes: Beastmaster, Gunner, Operative, Siren";
+
+/// Release builds use the windows subsystem so they don't get a console of their own, attach to
+/// the console the command was run from so the output is visible.
+#[cfg(all(target_os = "windows", not(debug_assertions)))]
+pub fn attach_parent_console() {
+    use winapi::um::wincon::{AttachConsole, ATTACH_PARENT_PROCESS};
+
+    // Fails when not run from a console, there's nowhere to write to in that case anyway
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+#[cfg(not(all(target_os = "windows", not(debug_assertions))))]
+pub fn attach_parent_console() {}
+
+pub fn run(subcommand: &str, mut pargs: Arguments, config: &Bl3Config) -> Result<()> {
+    match subcommand {
+        "new-character" => new_character(&mut pargs, config)?,
+        "backups" => backups(&mut pargs, config)?,
+        "anonymize" => anonymize(&mut pargs)?,
+        "help" => println!("{}", USAGE),
+        _ => bail!("Unknown command: {}\n\n{}", subcommand, USAGE),
+    }
+
+    let remaining = pargs.finish();
+
+    if !remaining.is_empty() {
+        bail!("Unexpected arguments: {:?}\n\n{}", remaining, USAGE);
+    }
+
+    Ok(())
+}
+
+fn saves_dir(pargs: &mut Arguments, config: &Bl3Config) -> Result<PathBuf> {
+    let saves_dir = pargs
+        .opt_value_from_str::<_, PathBuf>("--saves-dir")?
+        .unwrap_or_else(|| config.saves_dir().to_path_buf());
+
+    if !saves_dir.is_dir() {
+        bail!(
+            "Saves folder does not exist, pass one with --saves-dir: {}",
+            saves_dir.display()
+        );
+    }
+
+    Ok(saves_dir)
+}
+
+fn new_character(pargs: &mut Arguments, config: &Bl3Config) -> Result<()> {
+    let class = pargs
+        .value_from_str::<_, String>("--class")
+        .with_context(|| format!("missing --class\n\n{}", USAGE))?;
+
+    let player_class = PlayerClass::ALL
+        .iter()
+        .find(|c| c.to_string().eq_ignore_ascii_case(&class))
+        .with_context(|| format!("Unknown class: {}\n\n{}", class, USAGE))?;
+
+    let slot = pargs
+        .value_from_str::<_, u32>("--slot")
+        .with_context(|| format!("missing --slot\n\n{}", USAGE))?;
+
+    let level = pargs.opt_value_from_str::<_, i32>("--level")?.unwrap_or(1);
+    let name = pargs.opt_value_from_str::<_, String>("--name")?;
+
+    let header_type = match pargs
+        .opt_value_from_str::<_, String>("--platform")?
+        .as_deref()
+    {
+        None | Some("pc") => HeaderType::PcSave,
+        Some("ps4") => HeaderType::Ps4Save,
+        Some(p) => bail!("Unknown platform: {}\n\n{}", p, USAGE),
+    };
+
+    let saves_dir = saves_dir(pargs, config)?;
+
+    let save = Bl3Save::new_character(*player_class, level, header_type)?;
+
+    let name = name.unwrap_or_else(|| {
+        save.character_data
+            .character
+            .preferred_character_name
+            .clone()
+    });
+
+    let save = save.clone_as(&saves_dir, slot, &name)?;
+
+    println!(
+        "Created {} ({}) - Level {} in {}",
+        save.character_data.character.preferred_character_name,
+        save.character_data.player_class(),
+        save.character_data.player_level(),
+        saves_dir.join(&save.file_name).display()
+    );
+
+    Ok(())
+}
+
+fn backups(pargs: &mut Arguments, config: &Bl3Config) -> Result<()> {
+    let action = pargs
+        .subcommand()?
+        .with_context(|| format!("missing backups command\n\n{}", USAGE))?;
+
+    let backup_dir = pargs
+        .opt_value_from_str::<_, PathBuf>("--backup-dir")?
+        .unwrap_or_else(|| config.backup_dir().to_path_buf());
+
+    match action.as_str() {
+        "list" => {
+            let file_name = pargs.opt_value_from_str::<_, String>("--file")?;
+
+            let store = BackupStore::open(&backup_dir)?;
+
+            let mut entries = store
+                .entries()
+                .iter()
+                .filter(|e| {
+                    file_name
+                        .as_ref()
+                        .map(|f| e.original_file_name().eq_ignore_ascii_case(f))
+                        .unwrap_or(true)
+                })
+                .collect::<Vec<_>>();
+
+            entries.sort_by(|a, b| {
+                a.original_path
+                    .cmp(&b.original_path)
+                    .then(b.timestamp.cmp(&a.timestamp))
+            });
+
+            for entry in entries {
+                let created = chrono::DateTime::<chrono::Local>::from(entry.created());
+
+                println!(
+                    "{:>5}  {}  {}  v{}  {}",
+                    entry.id,
+                    created.format("%d-%m-%Y %H:%M:%S"),
+                    &entry.hash[..12],
+                    entry.editor_version,
+                    entry.original_path.display()
+                );
+            }
+        }
+        "restore" => {
+            let id = pargs
+                .value_from_str::<_, u64>("--id")
+                .with_context(|| format!("missing --id\n\n{}", USAGE))?;
+
+            let output_file = pargs.opt_value_from_str::<_, PathBuf>("--to")?;
+
+            let restored =
+                BackupStore::open(&backup_dir)?.restore(id, output_file.as_deref(), VERSION)?;
+
+            println!("Restored backup {} to {}", id, restored.display());
+        }
+        _ => bail!("Unknown backups command: {}\n\n{}", action, USAGE),
+    }
+
+    Ok(())
+}
+
+/// Takes several `--file`/`--to` pairs so a save and its profile can be anonymized together,
+/// a player which shows up in more than one of them gets the same pseudonym in each.
+fn anonymize(pargs: &mut Arguments) -> Result<()> {
+    let input_files = pargs.values_from_str::<_, PathBuf>("--file")?;
+    let output_files = pargs.values_from_str::<_, PathBuf>("--to")?;
+
+    if input_files.is_empty() {
+        bail!("missing --file\n\n{}", USAGE);
+    }
+
+    if input_files.len() != output_files.len() {
+        bail!("every --file needs a matching --to\n\n{}", USAGE);
+    }
+
+    if let Some(output_file) = output_files.iter().find(|f| f.exists()) {
+        bail!("Output file already exists: {}", output_file.display());
+    }
+
+    let mut pseudonyms = Pseudonyms::default();
+
+    for (input_file, output_file) in input_files.iter().zip(output_files.iter()) {
+        let data = std::fs::read(input_file)
+            .with_context(|| format!("failed to read file: {}", input_file.display()))?;
+
+        let output = match Bl3FileType::from_unknown_data(input_file, &data)? {
+            Bl3FileType::PcSave(mut save) | Bl3FileType::Ps4Save(mut save) => {
+                save.anonymize(&mut pseudonyms);
+                save.as_bytes()?.0
+            }
+            Bl3FileType::PcProfile(mut profile) | Bl3FileType::Ps4Profile(mut profile) => {
+                profile.anonymize(&mut pseudonyms);
+                profile.as_bytes()?.0
+            }
+        };
+
+        write_new_file_atomic(output_file, &output)?;
+
+        println!(
+            "Anonymized {} to {}",
+            input_file.display(),
+            output_file.display()
+        );
+    }
+
+    Ok(())
+}
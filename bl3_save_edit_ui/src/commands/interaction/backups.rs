@@ -0,0 +1,164 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use chrono::Local;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use tracing::{info, warn};
+
+use bl3_save_edit_core::backup_store::{BackupEntry, BackupRetention, BackupStore};
+use bl3_save_edit_core::bl3_save::save_file_name;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::VERSION;
+
+const PROFILE_FILE_NAME: &str = "profile.sav";
+
+const LEGACY_EDITOR_VERSION: &str = "unknown";
+
+const LEGACY_DIR_NAME: &str = "legacy";
+
+#[derive(Debug, Clone)]
+pub struct Backup {
+    pub entry: BackupEntry,
+    pub original_file_name: String,
+    pub file: Bl3FileType,
+}
+
+impl Backup {
+    pub fn created(&self) -> String {
+        chrono::DateTime::<Local>::from(self.entry.created())
+            .format("%d-%m-%Y %H:%M:%S")
+            .to_string()
+    }
+}
+
+/// Reads every backup in the store that can be parsed, grouped by the file it was taken from and
+/// newest first.
+pub async fn load_backups(backup_dir: PathBuf, saves_dir: PathBuf) -> Result<Vec<Backup>> {
+    tokio::task::spawn_blocking(move || {
+        import_legacy_backups(&backup_dir, &saves_dir)?;
+
+        let store = BackupStore::open(&backup_dir)?;
+
+        let mut backups = store
+            .entries()
+            .par_iter()
+            .filter_map(|entry| {
+                let file = store
+                    .read(entry)
+                    .and_then(|data| Bl3FileType::from_unknown_data(&entry.original_path, &data))
+                    .map_err(|e| warn!("Failed to read backup {}: {}", entry.id, e))
+                    .ok()?;
+
+                Some(Backup {
+                    entry: entry.clone(),
+                    original_file_name: entry.original_file_name(),
+                    file,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        backups.sort_by(|a, b| {
+            a.original_file_name
+                .cmp(&b.original_file_name)
+                .then(b.entry.timestamp.cmp(&a.entry.timestamp))
+        });
+
+        Ok(backups)
+    })
+    .await?
+}
+
+/// Imports the full copies older versions wrote into the backups folder into the store. Each copy is
+/// only moved into the `legacy` folder once it has been read back from the store, it is never
+/// deleted.
+fn import_legacy_backups(backup_dir: &Path, saves_dir: &Path) -> Result<()> {
+    let legacy_files = std::fs::read_dir(backup_dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().and_then(OsStr::to_str) == Some("sav"))
+        .collect::<Vec<_>>();
+
+    if legacy_files.is_empty() {
+        return Ok(());
+    }
+
+    let mut store = BackupStore::open(backup_dir)?;
+
+    for path in legacy_files {
+        let data = std::fs::read(&path)?;
+
+        let original_file_name = match Bl3FileType::from_unknown_data(&path, &data) {
+            Ok(Bl3FileType::PcSave(save)) | Ok(Bl3FileType::Ps4Save(save)) => {
+                save_file_name(save.character_data.character.save_game_id)
+            }
+            Ok(Bl3FileType::PcProfile(_)) | Ok(Bl3FileType::Ps4Profile(_)) => {
+                PROFILE_FILE_NAME.to_owned()
+            }
+            Err(e) => {
+                warn!("Skipping unreadable backup: {} - {}", path.display(), e);
+                continue;
+            }
+        };
+
+        let timestamp = std::fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        info!("Importing backup into store: {}", path.display());
+
+        let entry = store.add_with_timestamp(
+            &saves_dir.join(original_file_name),
+            &data,
+            LEGACY_EDITOR_VERSION,
+            timestamp,
+        )?;
+
+        if store.read(&entry)? != data {
+            bail!("imported backup did not match: {}", path.display());
+        }
+
+        let legacy_dir = backup_dir.join(LEGACY_DIR_NAME);
+
+        std::fs::create_dir_all(&legacy_dir)?;
+
+        if let Some(file_name) = path.file_name() {
+            info!("Moving imported backup to: {}", legacy_dir.display());
+
+            std::fs::rename(&path, legacy_dir.join(file_name))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the backup over the live file it was taken from, backing up the live file first.
+pub async fn restore_backup(backup_dir: PathBuf, backup: Backup) -> Result<PathBuf> {
+    tokio::task::spawn_blocking(move || {
+        BackupStore::open(&backup_dir)?.restore(backup.entry.id, None, VERSION)
+    })
+    .await?
+}
+
+/// Deletes the backups the retention policy no longer keeps, returning how many were removed.
+pub async fn prune_backups(backup_dir: PathBuf, retention: BackupRetention) -> Result<usize> {
+    if !retention.is_enabled() {
+        return Ok(0);
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let mut store = BackupStore::open(&backup_dir)?;
+
+        let to_remove = store.select_prunable(retention, SystemTime::now());
+
+        info!("Removing {} old backup(s)", to_remove.len());
+
+        store.remove(&to_remove)
+    })
+    .await?
+}
@@ -1,51 +1,99 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
-use chrono::Local;
-use tracing::info;
+use anyhow::{Context, Result};
+use tracing::{error, info};
 
+use bl3_save_edit_core::backup_store::{BackupEntry, BackupStore};
+use bl3_save_edit_core::bl3_profile::guardian_injection::GuardianInjectionPlan;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
 use bl3_save_edit_core::bl3_save::Bl3Save;
-use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::file_helper::{
+    write_file_atomic, Bl3FileType, FileFingerprint, WrittenFiles,
+};
+use bl3_save_edit_core::parser::HeaderType;
 
 use crate::commands::interaction::choose_save_directory;
-use crate::state_mappers;
+use crate::VERSION;
 
 pub async fn save_file(
     backup_dir: PathBuf,
     output_file: PathBuf,
     output: Vec<u8>,
     existing_save: Bl3Save,
-    new_save: Bl3Save,
+    mut new_save: Bl3Save,
+    force: bool,
 ) -> Result<Bl3Save> {
+    if !force {
+        if let Some(fingerprint) = &existing_save.file_fingerprint {
+            fingerprint.ensure_unchanged(&output_file)?;
+        }
+    }
+
+    backup_save(&backup_dir, &output_file, &existing_save).await?;
+
+    info!("Saving file: {}", new_save.file_name);
+
+    new_save.file_fingerprint = Some(write_atomic(output_file, output).await?);
+
+    Ok(new_save)
+}
+
+pub async fn write_atomic(path: PathBuf, output: Vec<u8>) -> Result<FileFingerprint> {
+    tokio::task::spawn_blocking(move || {
+        write_file_atomic(&path, &output)?;
+
+        Ok(FileFingerprint::new(&path, &output))
+    })
+    .await?
+}
+
+async fn backup_save(
+    backup_dir: &Path,
+    original_path: &Path,
+    existing_save: &Bl3Save,
+) -> Result<()> {
     info!(
         "Making a backup of existing save: {}",
         existing_save.file_name
     );
 
-    let current_time = Local::now().format("%d-%m-%Y_%H.%M.%S");
+    let (existing_save_output, _) = existing_save.as_bytes()?;
 
-    let backup_name = format!(
-        "{}_{}-{}.sav",
-        existing_save.character_data.player_class(),
-        existing_save
-            .character_data
-            .character
-            .preferred_character_name,
-        current_time
-    );
+    store_backup(backup_dir, original_path, existing_save_output).await?;
 
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    Ok(())
+}
 
-    let (existing_save_output, _) = existing_save.as_bytes()?;
+async fn backup_profile(
+    backup_dir: &Path,
+    original_path: &Path,
+    existing_profile: &Bl3Profile,
+) -> Result<()> {
+    info!(
+        "Making a backup of existing profile: {}",
+        existing_profile.file_name
+    );
 
-    tokio::fs::write(backup_dir.join(backup_name), existing_save_output).await?;
+    let (existing_profile_output, _) = existing_profile.as_bytes()?;
 
-    info!("Saving file: {}", new_save.file_name);
+    store_backup(backup_dir, original_path, existing_profile_output).await?;
 
-    tokio::fs::write(output_file, output).await?;
+    Ok(())
+}
 
-    Ok(new_save)
+async fn store_backup(
+    backup_dir: &Path,
+    original_path: &Path,
+    data: Vec<u8>,
+) -> Result<BackupEntry> {
+    let backup_dir = backup_dir.to_path_buf();
+    let original_path = original_path.to_path_buf();
+
+    tokio::task::spawn_blocking(move || {
+        BackupStore::open(&backup_dir)?.add(&original_path, &data, VERSION)
+    })
+    .await?
 }
 
 pub async fn save_profile(
@@ -54,50 +102,163 @@ pub async fn save_profile(
     output_file: PathBuf,
     output: Vec<u8>,
     existing_profile: Bl3Profile,
-    new_profile: Bl3Profile,
-    guardian_data_injection_required: bool,
+    mut new_profile: Bl3Profile,
+    guardian_injection_plans: Vec<GuardianInjectionPlan>,
+    force: bool,
 ) -> Result<Bl3Profile> {
-    info!(
-        "Making a backup of existing profile: {}",
-        existing_profile.file_name
-    );
+    if !force {
+        if let Some(fingerprint) = &existing_profile.file_fingerprint {
+            fingerprint.ensure_unchanged(&output_file)?;
+        }
+    }
 
-    let current_time = Local::now().format("%d-%m-%Y_%H.%M.%S");
+    backup_profile(&backup_dir, &output_file, &existing_profile).await?;
 
-    let backup_name = format!(
-        "{}-{}.sav",
-        existing_profile.file_name.replace(".sav", ""),
-        current_time
-    );
+    // Keep the original contents of every file we touch so a failure part way through can be undone.
+    let mut written_files = WrittenFiles::default();
 
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    let result = write_profile_and_inject_guardian_data(
+        &backup_dir,
+        &saves_dir,
+        output_file.clone(),
+        output.clone(),
+        &new_profile,
+        &guardian_injection_plans,
+        &mut written_files,
+    )
+    .await;
 
-    let (existing_profile_output, _) = existing_profile.as_bytes()?;
+    if let Err(e) = result {
+        error!(
+            "Failed to save profile, rolling back all written files: {}",
+            e
+        );
+
+        let rollback_errors = tokio::task::spawn_blocking(move || written_files.rollback()).await?;
+
+        for (path, rollback_err) in rollback_errors {
+            error!(
+                "Failed to roll back file: {} - {}",
+                path.display(),
+                rollback_err
+            );
+        }
+
+        return Err(e.context("all changes have been rolled back"));
+    }
 
-    tokio::fs::write(&backup_dir.join(backup_name), existing_profile_output).await?;
+    new_profile.file_fingerprint = Some(FileFingerprint::new(&output_file, &output));
 
+    Ok(new_profile)
+}
+
+async fn write_profile_and_inject_guardian_data(
+    backup_dir: &Path,
+    saves_dir: &Path,
+    output_file: PathBuf,
+    output: Vec<u8>,
+    new_profile: &Bl3Profile,
+    guardian_injection_plans: &[GuardianInjectionPlan],
+    written_files: &mut WrittenFiles,
+) -> Result<()> {
     info!("Saving profile: {}", new_profile.file_name);
 
-    tokio::fs::write(output_file, output).await?;
+    write_tracked(output_file, output, written_files).await?;
+
+    if guardian_injection_plans.is_empty() {
+        return Ok(());
+    }
+
+    info!("Injecting guardian data into selected saves...");
+
+    let (_, all_files) =
+        choose_save_directory::load_files_in_directory(saves_dir.to_path_buf()).await?;
+
+    for plan in guardian_injection_plans {
+        let existing_save = all_files
+            .iter()
+            .find_map(|f| match f {
+                Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s)
+                    if s.file_name == plan.file_name =>
+                {
+                    Some(s)
+                }
+                _ => None,
+            })
+            .with_context(|| format!("failed to find save: {}", plan.file_name))?;
 
-    if guardian_data_injection_required {
-        let guardian_rank = new_profile.profile_data.guardian_rank();
+        let mut new_save = existing_save.clone();
 
-        let guardian_tokens = new_profile.profile_data.guardian_tokens();
+        plan.apply(&mut new_save)?;
 
-        let guardian_rewards = new_profile.profile_data.guardian_rewards();
+        let (output, _) = new_save.as_bytes()?;
 
-        state_mappers::inject_guardian_data_into_saves(
-            backup_dir,
-            saves_dir,
-            guardian_rank,
-            guardian_tokens,
-            guardian_rewards,
-        )
-        .await?;
+        let save_file = saves_dir.join(&new_save.file_name);
+
+        backup_save(backup_dir, &save_file, existing_save).await?;
+
+        info!("Saving file: {}", new_save.file_name);
+
+        write_tracked(save_file, output, written_files).await?;
     }
 
-    Ok(new_profile)
+    Ok(())
+}
+
+async fn write_tracked(
+    path: PathBuf,
+    output: Vec<u8>,
+    written_files: &mut WrittenFiles,
+) -> Result<()> {
+    let mut files = std::mem::take(written_files);
+
+    let (files, result) = tokio::task::spawn_blocking(move || {
+        let result = files.write(&path, &output);
+
+        (files, result)
+    })
+    .await?;
+
+    *written_files = files;
+
+    result
+}
+
+pub async fn duplicate_save(
+    saves_dir: PathBuf,
+    save: Bl3Save,
+    slot: u32,
+    name: String,
+) -> Result<Bl3Save> {
+    info!("Duplicating save: {} into slot: {}", save.file_name, slot);
+
+    tokio::task::spawn_blocking(move || save.clone_as(&saves_dir, slot, &name)).await?
+}
+
+pub async fn new_character(
+    saves_dir: PathBuf,
+    player_class: PlayerClass,
+    level: i32,
+    header_type: HeaderType,
+    slot: u32,
+) -> Result<Bl3Save> {
+    info!(
+        "Creating new level {} {} character in slot: {}",
+        level, player_class, slot
+    );
+
+    tokio::task::spawn_blocking(move || {
+        let save = Bl3Save::new_character(player_class, level, header_type)?;
+
+        let name = save
+            .character_data
+            .character
+            .preferred_character_name
+            .clone();
+
+        save.clone_as(&saves_dir, slot, &name)
+    })
+    .await?
 }
 
 pub async fn load_files_after_save(
@@ -2,9 +2,11 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 
+pub mod backups;
 pub mod choose_save_directory;
 pub mod file_save;
 pub mod manage_save;
+pub mod presets;
 pub mod settings;
 
 #[cfg(not(target_os = "macos"))]
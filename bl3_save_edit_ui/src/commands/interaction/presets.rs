@@ -0,0 +1,87 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use tracing::info;
+
+const PRESETS_DIR: &str = "presets";
+pub const RON_EXTENSION: &str = "ron";
+pub const JSON_EXTENSION: &str = "json";
+
+pub fn presets_dir(config_dir: &Path, kind: &str) -> PathBuf {
+    config_dir.join(PRESETS_DIR).join(kind)
+}
+
+/// Lists the names of the presets with `extension` saved in `presets_dir`, sorted by name.
+pub async fn list_presets(presets_dir: PathBuf, extension: &'static str) -> Result<Vec<String>> {
+    if !presets_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = tokio::fs::read_dir(&presets_dir).await?;
+
+    let mut presets = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+
+        if path.extension().and_then(OsStr::to_str) != Some(extension) {
+            continue;
+        }
+
+        if let Some(name) = path.file_stem().and_then(OsStr::to_str) {
+            presets.push(name.to_owned());
+        }
+    }
+
+    presets.sort();
+
+    Ok(presets)
+}
+
+pub async fn write_preset(
+    presets_dir: PathBuf,
+    name: String,
+    extension: &'static str,
+    data: String,
+) -> Result<PathBuf> {
+    let name = sanitize_preset_name(&name)?;
+
+    tokio::fs::create_dir_all(&presets_dir).await?;
+
+    let output_file = presets_dir.join(format!("{}.{}", name, extension));
+
+    info!("Writing preset: {}", output_file.display());
+
+    tokio::fs::write(&output_file, data)
+        .await
+        .with_context(|| format!("failed to write preset: {}", output_file.display()))?;
+
+    Ok(output_file)
+}
+
+pub async fn read_preset(
+    presets_dir: PathBuf,
+    name: String,
+    extension: &'static str,
+) -> Result<String> {
+    let input_file = presets_dir.join(format!("{}.{}", name, extension));
+
+    tokio::fs::read_to_string(&input_file)
+        .await
+        .with_context(|| format!("failed to read preset: {}", input_file.display()))
+}
+
+fn sanitize_preset_name(name: &str) -> Result<String> {
+    let name = name
+        .trim()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .collect::<String>();
+
+    if name.is_empty() {
+        bail!("Enter a name for the preset first.");
+    }
+
+    Ok(name)
+}
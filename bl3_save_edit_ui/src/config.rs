@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use tokio::io::AsyncWriteExt;
 use tracing::info;
 
+use bl3_save_edit_core::backup_store::BackupRetention;
+
 use crate::bl3_ui::MessageResult;
 
 const CONFIG_DIR: &str = "bl3_save_editor";
@@ -24,6 +26,8 @@ pub struct Bl3Config {
     saves_dir: PathBuf,
     #[serde(default = "default_scale_factor")]
     ui_scale_factor: f64,
+    #[serde(default)]
+    backup_retention: BackupRetention,
 }
 
 fn default_scale_factor() -> f64 {
@@ -69,6 +73,7 @@ impl Bl3Config {
                 backup_dir,
                 saves_dir: Default::default(),
                 ui_scale_factor: default_scale_factor(),
+                backup_retention: BackupRetention::default(),
             }
         }
     }
@@ -123,4 +128,12 @@ impl Bl3Config {
     pub fn set_ui_scale_factor(&mut self, ui_scale_factor: f64) {
         self.ui_scale_factor = ui_scale_factor;
     }
+
+    pub fn backup_retention(&self) -> BackupRetention {
+        self.backup_retention
+    }
+
+    pub fn set_backup_retention(&mut self, backup_retention: BackupRetention) {
+        self.backup_retention = backup_retention;
+    }
 }
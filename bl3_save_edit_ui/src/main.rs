@@ -14,6 +14,7 @@ use crate::update::remove_file;
 
 mod bl3_ui;
 mod bl3_ui_style;
+mod cli;
 mod commands;
 mod config;
 mod resources;
@@ -50,6 +51,18 @@ fn main() -> Result<()> {
 
     tracing_subscriber::fmt().with_writer(non_blocking).init();
 
+    if let Some(subcommand) = pargs.subcommand()? {
+        cli::attach_parent_console();
+
+        let result = cli::run(&subcommand, pargs, &config);
+
+        if let Err(e) = &result {
+            error!("Command {} failed: {:#}", subcommand, e);
+        }
+
+        return result;
+    }
+
     let previous_update_cleanup_path: Result<String> = pargs
         .value_from_str("--cleanup_previous_path")
         .context("No previous update path passed so ignoring it.");
@@ -14,11 +14,22 @@ pub const CURRENCY: &[u8] = include_bytes!("../../resources/svg/currency.svg");
 pub const VEHICLE: &[u8] = include_bytes!("../../resources/svg/vehicle.svg");
 pub const FAVORITE: &[u8] = include_bytes!("../../resources/svg/favorite.svg");
 pub const JUNK: &[u8] = include_bytes!("../../resources/svg/junk.svg");
+pub const CHALLENGES: &[u8] = include_bytes!("../../resources/svg/challenges.svg");
+pub const ECHO_LOGS: &[u8] = include_bytes!("../../resources/svg/echo_logs.svg");
+pub const REGIONS: &[u8] = include_bytes!("../../resources/svg/regions.svg");
+pub const CREW_QUARTERS: &[u8] = include_bytes!("../../resources/svg/crew_quarters.svg");
+pub const STATS: &[u8] = include_bytes!("../../resources/svg/stats.svg");
 
 //Profile editor
 pub const PROFILE: &[u8] = include_bytes!("../../resources/svg/profile.svg");
 pub const KEYS: &[u8] = include_bytes!("../../resources/svg/keys.svg");
+pub const SCIENCE: &[u8] = include_bytes!("../../resources/svg/science.svg");
 pub const BANK: &[u8] = include_bytes!("../../resources/svg/bank.svg");
+pub const GAME_SETTINGS: &[u8] = include_bytes!("../../resources/svg/game_settings.svg");
+pub const KEY_BINDINGS: &[u8] = include_bytes!("../../resources/svg/key_bindings.svg");
+pub const COSMETICS: &[u8] = include_bytes!("../../resources/svg/cosmetics.svg");
+pub const VAULT_CARD: &[u8] = include_bytes!("../../resources/svg/vault_card.svg");
+pub const FIRST_RUN: &[u8] = include_bytes!("../../resources/svg/first_run.svg");
 
 // Notification
 pub const POSITIVE_CLOSE: &[u8] = include_bytes!("../../resources/svg/positive_close.svg");
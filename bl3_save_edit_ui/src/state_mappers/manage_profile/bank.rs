@@ -39,6 +39,12 @@ pub fn map_profile_to_bank_state(manage_profile_state: &mut ManageProfileState)
         .item_editor_state
         .items_mut() = bank_items;
 
+    manage_profile_state
+        .profile_view_state
+        .bank_state
+        .item_editor_state
+        .item_capacity = Some(profile.profile_data.bank_capacity() as usize);
+
     manage_profile_state
         .profile_view_state
         .bank_state
@@ -80,6 +86,12 @@ pub fn map_bank_state_to_profile(
 
     bank_items.par_sort_by_key(|(i, _)| *i);
 
+    let allow_over_capacity = manage_bank_state
+        .profile_view_state
+        .bank_state
+        .item_editor_state
+        .allow_over_capacity;
+
     // Here we don't modify the save items just yet, we first modify
     // the mapped list and then set the save items equal to this mapped list
     for (i, edited_item) in bank_items {
@@ -101,7 +113,9 @@ pub fn map_bank_state_to_profile(
             // Otherwise insert our new item in this slot
             info!("Inserting bank item at index: {}", i);
 
-            profile.profile_data.insert_bank_item(i, edited_item)?;
+            profile
+                .profile_data
+                .insert_bank_item(i, edited_item, allow_over_capacity)?;
         }
     }
 
@@ -0,0 +1,94 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::borderlands_science::{
+    BorderlandsScienceBoosterField, BorderlandsScienceState,
+};
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_borderlands_science_state(manage_profile_state: &mut ManageProfileState) {
+    let science_info = manage_profile_state
+        .current_file
+        .profile_data
+        .borderlands_science_info();
+
+    let borderlands_science_state = BorderlandsScienceState {
+        level_selected: science_info.science_level,
+        tokens_input: science_info.tokens,
+        has_seen_intro_video: science_info.has_seen_intro_video,
+        tutorial_done: science_info.tutorial_done,
+        streamer_booster_tier_input: science_info.streamer_booster_tier,
+        ..Default::default()
+    };
+
+    let boosters = borderlands_science_state
+        .boosters
+        .iter()
+        .map(|b| BorderlandsScienceBoosterField::new(b.slot, *science_info.booster(b.slot)))
+        .collect();
+
+    manage_profile_state
+        .profile_view_state
+        .borderlands_science_state = BorderlandsScienceState {
+        boosters,
+        ..borderlands_science_state
+    };
+}
+
+pub fn map_borderlands_science_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let borderlands_science_state = &manage_profile_state
+        .profile_view_state
+        .borderlands_science_state;
+
+    profile
+        .profile_data
+        .set_borderlands_science_level(&borderlands_science_state.level_selected);
+
+    profile
+        .profile_data
+        .set_borderlands_science_tokens(borderlands_science_state.tokens_input);
+
+    // Setting the level marks the intro and tutorial as done so the flags are applied after it.
+    profile
+        .profile_data
+        .set_borderlands_science_intro_video_seen(borderlands_science_state.has_seen_intro_video);
+
+    profile
+        .profile_data
+        .set_borderlands_science_tutorial_done(borderlands_science_state.tutorial_done);
+
+    profile
+        .profile_data
+        .set_borderlands_science_streamer_booster_tier(
+            borderlands_science_state.streamer_booster_tier_input,
+        )?;
+
+    for b in &borderlands_science_state.boosters {
+        if b.booster
+            == *profile
+                .profile_data
+                .borderlands_science_info()
+                .booster(b.slot)
+        {
+            continue;
+        }
+
+        if b.booster.is_active() {
+            profile.profile_data.apply_borderlands_science_booster(
+                b.slot,
+                b.booster.index,
+                b.booster.total_seconds,
+            )?;
+        } else {
+            profile
+                .profile_data
+                .clear_borderlands_science_booster(b.slot);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_cosmetics_state(manage_profile_state: &mut ManageProfileState) {
+    let cosmetics = manage_profile_state.current_file.profile_data.cosmetics();
+
+    manage_profile_state
+        .profile_view_state
+        .cosmetics_state
+        .cosmetics = cosmetics;
+}
+
+pub fn map_cosmetics_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    // Compare against the loaded file so only the items changed in this tab are applied and the
+    // unlock all options on the profile tab are left alone.
+    let original_cosmetics = manage_profile_state.current_file.profile_data.cosmetics();
+
+    for (cosmetic, original) in manage_profile_state
+        .profile_view_state
+        .cosmetics_state
+        .cosmetics
+        .iter()
+        .zip(original_cosmetics.iter())
+    {
+        if cosmetic.unlocked != original.unlocked {
+            profile.profile_data.set_cosmetic_unlocked(
+                &cosmetic.skin_type,
+                cosmetic.ident,
+                cosmetic.unlocked,
+            )?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::game_data::PROFILE_TUTORIALS;
+
+use crate::views::manage_profile::first_run::{FirstRunState, FirstRunTutorial};
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_first_run_state(manage_profile_state: &mut ManageProfileState) {
+    let first_run_data = manage_profile_state
+        .current_file
+        .profile_data
+        .first_run_data();
+
+    let tutorials = PROFILE_TUTORIALS
+        .iter()
+        .map(|t| FirstRunTutorial {
+            ident: t.ident.to_owned(),
+            name: t.name.to_owned(),
+        })
+        .chain(
+            first_run_data
+                .unknown_seen_tutorials()
+                .into_iter()
+                .map(|ident| FirstRunTutorial {
+                    name: ident.clone(),
+                    ident,
+                }),
+        )
+        .collect();
+
+    manage_profile_state.profile_view_state.first_run_state = FirstRunState {
+        first_run_data,
+        tutorials,
+        ..Default::default()
+    };
+}
+
+pub fn map_first_run_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) {
+    let first_run_data = &manage_profile_state
+        .profile_view_state
+        .first_run_state
+        .first_run_data;
+
+    if *first_run_data != profile.profile_data.first_run_data() {
+        profile.profile_data.set_first_run_data(first_run_data);
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::{bail, Result};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_profile::settings::ProfileSetting;
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::game_settings::GameSettingEditor;
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_game_settings_state(manage_profile_state: &mut ManageProfileState) {
+    let profile = &manage_profile_state.current_file;
+
+    manage_profile_state
+        .profile_view_state
+        .game_settings_state
+        .settings = ProfileSetting::iter()
+        .map(|setting| GameSettingEditor::new(setting, profile.profile_data.setting(&setting)))
+        .collect();
+}
+
+pub fn map_game_settings_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let game_settings_state = &manage_profile_state.profile_view_state.game_settings_state;
+
+    if let Some(error) = game_settings_state
+        .settings
+        .iter()
+        .find_map(|e| e.error.as_ref())
+    {
+        bail!("{}", error);
+    }
+
+    for editor in &game_settings_state.settings {
+        if profile.profile_data.setting(&editor.setting) != editor.value {
+            profile
+                .profile_data
+                .set_setting(&editor.setting, editor.value)?;
+        }
+    }
+
+    Ok(())
+}
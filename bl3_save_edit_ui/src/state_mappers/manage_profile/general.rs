@@ -1,3 +1,4 @@
+use bl3_save_edit_core::anonymize::Pseudonyms;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 
 use crate::views::manage_profile::ManageProfileState;
@@ -14,11 +15,17 @@ pub fn map_profile_to_general_state(manage_profile_state: &mut ManageProfileStat
         .profile_view_state
         .general_state
         .profile_type_selected = profile.header_type;
+
+    manage_profile_state
+        .profile_view_state
+        .general_state
+        .anonymize = false;
 }
 
 pub fn map_general_state_to_profile(
     manage_profile_state: &mut ManageProfileState,
     profile: &mut Bl3Profile,
+    pseudonyms: &mut Pseudonyms,
 ) {
     profile.file_name = manage_profile_state
         .profile_view_state
@@ -30,4 +37,12 @@ pub fn map_general_state_to_profile(
         .profile_view_state
         .general_state
         .profile_type_selected;
+
+    if manage_profile_state
+        .profile_view_state
+        .general_state
+        .anonymize
+    {
+        profile.anonymize(pseudonyms);
+    }
 }
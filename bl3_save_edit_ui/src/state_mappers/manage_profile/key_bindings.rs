@@ -0,0 +1,33 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_key_bindings_state(manage_profile_state: &mut ManageProfileState) {
+    let key_bindings = manage_profile_state
+        .current_file
+        .profile_data
+        .key_bindings();
+
+    manage_profile_state
+        .profile_view_state
+        .key_bindings_state
+        .set_key_bindings(key_bindings);
+}
+
+pub fn map_key_bindings_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let key_bindings = &manage_profile_state
+        .profile_view_state
+        .key_bindings_state
+        .key_bindings;
+
+    if *key_bindings != profile.profile_data.key_bindings() {
+        profile.profile_data.set_key_bindings(key_bindings)?;
+    }
+
+    Ok(())
+}
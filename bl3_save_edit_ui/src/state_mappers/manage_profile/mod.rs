@@ -1,27 +1,66 @@
 use anyhow::Result;
 
+use bl3_save_edit_core::anonymize::Pseudonyms;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 
 use crate::state_mappers::manage_profile;
 use crate::views::manage_profile::ManageProfileState;
 
 pub mod bank;
+pub mod borderlands_science;
+pub mod cosmetics;
+pub mod first_run;
+pub mod game_settings;
 pub mod general;
+pub mod key_bindings;
 pub mod keys;
 pub mod profile;
+pub mod stats;
+pub mod vault_card;
 
 pub fn map_all_states_to_profile(
     manage_profile_state: &mut ManageProfileState,
     current_file: &mut Bl3Profile,
+    pseudonyms: &mut Pseudonyms,
 ) -> Result<bool> {
-    manage_profile::general::map_general_state_to_profile(manage_profile_state, current_file);
+    manage_profile::general::map_general_state_to_profile(
+        manage_profile_state,
+        current_file,
+        pseudonyms,
+    );
 
     let guardian_data_injection_required =
         manage_profile::profile::map_profile_state_to_profile(manage_profile_state, current_file)?;
 
+    manage_profile::borderlands_science::map_borderlands_science_state_to_profile(
+        manage_profile_state,
+        current_file,
+    )?;
+
     manage_profile::keys::map_keys_state_to_profile(manage_profile_state, current_file)?;
 
     manage_profile::bank::map_bank_state_to_profile(manage_profile_state, current_file)?;
 
+    manage_profile::stats::map_stats_state_to_profile(manage_profile_state, current_file);
+
+    manage_profile::game_settings::map_game_settings_state_to_profile(
+        manage_profile_state,
+        current_file,
+    )?;
+
+    manage_profile::key_bindings::map_key_bindings_state_to_profile(
+        manage_profile_state,
+        current_file,
+    )?;
+
+    manage_profile::cosmetics::map_cosmetics_state_to_profile(manage_profile_state, current_file)?;
+
+    manage_profile::vault_card::map_vault_card_state_to_profile(
+        manage_profile_state,
+        current_file,
+    )?;
+
+    manage_profile::first_run::map_first_run_state_to_profile(manage_profile_state, current_file);
+
     Ok(guardian_data_injection_required)
 }
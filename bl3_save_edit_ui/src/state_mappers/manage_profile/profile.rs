@@ -16,19 +16,6 @@ pub fn map_profile_to_profile_state(manage_profile_state: &mut ManageProfileStat
         .profile_state
         .guardian_rank_tokens_input = profile.profile_data.guardian_tokens();
 
-    manage_profile_state
-        .profile_view_state
-        .profile_state
-        .science_level_selected = profile
-        .profile_data
-        .borderlands_science_info()
-        .science_level;
-
-    manage_profile_state
-        .profile_view_state
-        .profile_state
-        .science_tokens_input = profile.profile_data.borderlands_science_info().tokens;
-
     let mut skin_unlocker = SkinUnlocker::default();
 
     skin_unlocker.character_heads.skin_data.current =
@@ -143,14 +130,6 @@ pub fn map_profile_state_to_profile(
 
     let profile_state = &manage_profile_state.profile_view_state.profile_state;
 
-    profile
-        .profile_data
-        .set_borderlands_science_level(&profile_state.science_level_selected);
-
-    profile
-        .profile_data
-        .set_borderlands_science_tokens(profile_state.science_tokens_input);
-
     let skin_unlocker = &profile_state.skin_unlocker;
 
     let all_skin_unlock_boxes = [
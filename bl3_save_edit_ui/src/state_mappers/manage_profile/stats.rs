@@ -0,0 +1,40 @@
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::game_stats::{GameStatEditor, GameStatsState};
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_stats_state(manage_profile_state: &mut ManageProfileState) {
+    let profile = &manage_profile_state.current_file;
+
+    manage_profile_state.profile_view_state.stats_state = GameStatsState {
+        stats: profile
+            .profile_data
+            .game_stats()
+            .into_iter()
+            .map(GameStatEditor::new)
+            .collect(),
+        playtime_seconds_input: profile.profile_data.total_playtime_seconds(),
+        ..Default::default()
+    };
+}
+
+pub fn map_stats_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) {
+    let stats_state = &manage_profile_state.profile_view_state.stats_state;
+
+    let existing_stats = manage_profile_state.current_file.profile_data.game_stats();
+
+    for editor in &stats_state.stats {
+        if !existing_stats.contains(&editor.stat) {
+            profile
+                .profile_data
+                .set_game_stat(&editor.stat.path, editor.stat.value);
+        }
+    }
+
+    profile
+        .profile_data
+        .set_total_playtime_seconds(stats_state.playtime_seconds_input);
+}
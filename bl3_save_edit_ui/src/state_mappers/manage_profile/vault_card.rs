@@ -0,0 +1,115 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_vault_card_state(manage_profile_state: &mut ManageProfileState) {
+    let vault_card_data = manage_profile_state
+        .current_file
+        .profile_data
+        .vault_card_data();
+
+    manage_profile_state
+        .profile_view_state
+        .vault_card_state
+        .set_vault_card_data(vault_card_data);
+}
+
+pub fn map_vault_card_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let vault_card_data = &manage_profile_state
+        .profile_view_state
+        .vault_card_state
+        .vault_card_data;
+
+    let original_data = manage_profile_state
+        .current_file
+        .profile_data
+        .vault_card_data();
+
+    if vault_card_data == &original_data {
+        return Ok(());
+    }
+
+    for card in &vault_card_data.cards {
+        let original = match original_data.card(card.id) {
+            Some(original) => original,
+            None => continue,
+        };
+
+        if card.experience != original.experience {
+            profile
+                .profile_data
+                .set_vault_card_experience(card.id, card.experience)?;
+        }
+
+        for reward in original
+            .unlocked_rewards
+            .iter()
+            .filter(|r| !card.is_reward_unlocked(r))
+        {
+            profile
+                .profile_data
+                .set_vault_card_reward_unlocked(card.id, *reward, false)?;
+        }
+
+        for reward in card
+            .unlocked_rewards
+            .iter()
+            .filter(|r| !original.is_reward_unlocked(r))
+        {
+            profile
+                .profile_data
+                .set_vault_card_reward_unlocked(card.id, *reward, true)?;
+        }
+
+        for reward in card
+            .unlocked_rewards
+            .iter()
+            .filter(|r| card.is_reward_redeemed(r) != original.is_reward_redeemed(r))
+        {
+            profile.profile_data.set_vault_card_reward_redeemed(
+                card.id,
+                *reward,
+                card.is_reward_redeemed(reward),
+            )?;
+        }
+
+        for gear_reward in original
+            .gear_rewards
+            .iter()
+            .filter(|g| card.gear_reward(g.gear_index).is_none())
+        {
+            profile.profile_data.set_vault_card_gear_reward_unlocked(
+                card.id,
+                gear_reward.gear_index,
+                false,
+            )?;
+        }
+
+        for gear_reward in card
+            .gear_rewards
+            .iter()
+            .filter(|g| original.gear_reward(g.gear_index).is_none())
+        {
+            profile.profile_data.set_vault_card_gear_reward_unlocked(
+                card.id,
+                gear_reward.gear_index,
+                true,
+            )?;
+        }
+    }
+
+    if vault_card_data.last_active_vault_card_id != original_data.last_active_vault_card_id
+        && vault_card_data.last_active_vault_card_id != 0
+    {
+        profile
+            .profile_data
+            .set_last_active_vault_card(vault_card_data.last_active_vault_card_id)?;
+    }
+
+    Ok(())
+}
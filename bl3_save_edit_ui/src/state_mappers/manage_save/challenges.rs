@@ -0,0 +1,66 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::challenges::{ChallengeEditor, ChallengesState};
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_challenges_state(manage_save_state: &mut ManageSaveState) {
+    let save = &manage_save_state.current_file;
+
+    let challenges = save.character_data.challenges();
+
+    let mut categories = challenges.iter().map(|c| c.category).collect::<Vec<_>>();
+
+    categories.dedup();
+
+    let category_selected = categories.first().copied().unwrap_or_default();
+
+    let mut category_completion_inputs = ChallengesState::default().category_completion_inputs;
+
+    for completion in save.character_data.challenge_category_completion() {
+        if let Some(input) = category_completion_inputs.get_mut(completion.category as usize) {
+            *input = completion.percentage;
+        }
+    }
+
+    manage_save_state.save_view_state.challenges_state = ChallengesState {
+        challenges: challenges.into_iter().map(ChallengeEditor::new).collect(),
+        categories,
+        category_selected,
+        category_completion_inputs,
+        ..Default::default()
+    };
+}
+
+pub fn map_challenges_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let challenges_state = &manage_save_state.save_view_state.challenges_state;
+
+    let existing_challenges = save.character_data.challenges();
+
+    for editor in &challenges_state.challenges {
+        if existing_challenges
+            .binary_search(&editor.challenge)
+            .is_err()
+        {
+            save.character_data.set_challenge(&editor.challenge)?;
+        }
+    }
+
+    for completion in save.character_data.challenge_category_completion() {
+        if let Some(percentage) = challenges_state
+            .category_completion_inputs
+            .get(completion.category as usize)
+        {
+            if *percentage != completion.percentage {
+                save.character_data
+                    .set_challenge_category_completion(completion.category, *percentage);
+            }
+        }
+    }
+
+    Ok(())
+}
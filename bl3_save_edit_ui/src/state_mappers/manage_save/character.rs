@@ -1,10 +1,13 @@
 use anyhow::Result;
+use tracing::info;
 
 use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
 use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
+use bl3_save_edit_core::bl3_save::player_color::{color_to_rgb8, rgb8_to_color};
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::views::manage_save::character::colors::PlayerColorEditor;
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
@@ -57,6 +60,32 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .echo_theme
         .selected = save.character_data.echo_theme_selected();
 
+    manage_save_state
+        .save_view_state
+        .character_state
+        .skin_selectors
+        .emotes
+        .iter_mut()
+        .zip(save.character_data.equipped_emotes())
+        .for_each(|(emote_selector, emote)| emote_selector.selected = emote);
+
+    manage_save_state
+        .save_view_state
+        .character_state
+        .color_editor
+        .colors = save
+        .character_data
+        .player_colors()
+        .into_iter()
+        .map(PlayerColorEditor::new)
+        .collect();
+
+    manage_save_state
+        .save_view_state
+        .character_state
+        .guardian_perk_editor
+        .perks = save.character_data.guardian_perks();
+
     let mut gear_unlocker = std::mem::take(
         &mut manage_save_state
             .save_view_state
@@ -153,10 +182,15 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
             SaveSduSlot::Heavy => sdu_unlocker.heavy.input = s.current,
         });
 
-    manage_save_state
-        .save_view_state
-        .character_state
-        .sdu_unlocker = sdu_unlocker;
+    let character_state = &mut manage_save_state.save_view_state.character_state;
+
+    for sdu in sdu_unlocker.fields() {
+        character_state
+            .ammo_setter
+            .set_sdu_level(&sdu.sdu_slot, sdu.input, false);
+    }
+
+    character_state.sdu_unlocker = sdu_unlocker;
 }
 
 pub fn map_character_state_to_save(
@@ -176,13 +210,17 @@ pub fn map_character_state_to_save(
             .experience_points_input,
     )?;
 
-    save.character_data.set_player_class(
+    let player_class_change = save.character_data.set_player_class(
         manage_save_state
             .save_view_state
             .character_state
             .player_class_selected_class,
     )?;
 
+    if let Some(player_class_change) = &player_class_change {
+        info!("{}", player_class_change);
+    }
+
     save.character_data.set_ability_points(
         manage_save_state
             .save_view_state
@@ -190,23 +228,39 @@ pub fn map_character_state_to_save(
             .ability_points_input,
     )?;
 
-    save.character_data.set_head_skin_selected(
-        &manage_save_state
+    // The selected cosmetics belong to the previous class so keep the new class's defaults
+    if player_class_change.is_none() {
+        save.character_data.set_head_skin_selected(
+            &manage_save_state
+                .save_view_state
+                .character_state
+                .skin_selectors
+                .head_skin
+                .selected,
+        );
+
+        save.character_data.set_character_skin_selected(
+            &manage_save_state
+                .save_view_state
+                .character_state
+                .skin_selectors
+                .character_skin
+                .selected,
+        );
+
+        let emotes = manage_save_state
             .save_view_state
             .character_state
             .skin_selectors
-            .head_skin
-            .selected,
-    );
+            .emotes
+            .iter()
+            .map(|e| e.selected)
+            .collect::<Vec<_>>();
 
-    save.character_data.set_character_skin_selected(
-        &manage_save_state
-            .save_view_state
-            .character_state
-            .skin_selectors
-            .character_skin
-            .selected,
-    );
+        if emotes != save.character_data.equipped_emotes() {
+            save.character_data.set_equipped_emotes(&emotes)?;
+        }
+    }
 
     save.character_data.set_echo_theme_selected(
         &manage_save_state
@@ -217,6 +271,43 @@ pub fn map_character_state_to_save(
             .selected,
     );
 
+    for c in &manage_save_state
+        .save_view_state
+        .character_state
+        .color_editor
+        .colors
+    {
+        let mut player_color = c.player_color.clone();
+
+        if c.applied_rgb != color_to_rgb8(player_color.applied_color) {
+            player_color.applied_color = rgb8_to_color(c.applied_rgb);
+        }
+
+        if c.split_rgb != color_to_rgb8(player_color.split_color) {
+            player_color.split_color = rgb8_to_color(c.split_rgb);
+        }
+
+        player_color.use_default_color = c.use_default_color;
+        player_color.use_default_split_color = c.use_default_split_color;
+
+        if player_color != c.player_color {
+            save.character_data.set_player_color(&player_color);
+        }
+    }
+
+    let current_guardian_perks = save.character_data.guardian_perks();
+
+    for p in &manage_save_state
+        .save_view_state
+        .character_state
+        .guardian_perk_editor
+        .perks
+    {
+        if !current_guardian_perks.contains(p) {
+            save.character_data.set_guardian_perk(&p.perk, p.enabled)?;
+        }
+    }
+
     let gear_unlocker = &manage_save_state
         .save_view_state
         .character_state
@@ -242,25 +333,6 @@ pub fn map_character_state_to_save(
         }
     }
 
-    let ammo_setter = &manage_save_state
-        .save_view_state
-        .character_state
-        .ammo_setter;
-
-    let all_ammo_pools = [
-        &ammo_setter.grenade,
-        &ammo_setter.assault_rifle,
-        &ammo_setter.heavy,
-        &ammo_setter.pistol,
-        &ammo_setter.shotgun,
-        &ammo_setter.smg,
-        &ammo_setter.sniper,
-    ];
-
-    for a in all_ammo_pools {
-        save.character_data.set_ammo_pool(&a.ammo_pool, a.input)?;
-    }
-
     let sdu_unlocker = &manage_save_state
         .save_view_state
         .character_state
@@ -281,5 +353,24 @@ pub fn map_character_state_to_save(
         save.character_data.set_sdu_slot(&s.sdu_slot, s.input);
     }
 
+    let ammo_setter = &manage_save_state
+        .save_view_state
+        .character_state
+        .ammo_setter;
+
+    let all_ammo_pools = [
+        &ammo_setter.grenade,
+        &ammo_setter.assault_rifle,
+        &ammo_setter.heavy,
+        &ammo_setter.pistol,
+        &ammo_setter.shotgun,
+        &ammo_setter.smg,
+        &ammo_setter.sniper,
+    ];
+
+    for a in all_ammo_pools {
+        save.character_data.set_ammo_pool(&a.ammo_pool, a.input)?;
+    }
+
     Ok(())
 }
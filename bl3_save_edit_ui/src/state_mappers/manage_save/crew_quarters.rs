@@ -0,0 +1,101 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::crew_quarters::{
+    crew_quarters_decoration_path, placeable_crew_quarters_decorations, CrewQuartersData,
+    CrewQuartersDecorationData, CREW_QUARTERS_DEFAULT_DECORATION,
+};
+use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::game_data::{CREW_QUARTERS_ROOMS, PROFILE_ROOM_DECORATIONS};
+
+use crate::views::manage_save::crew_quarters::{
+    CrewQuartersDecorationField, CrewQuartersDecorationOption, CrewQuartersState,
+};
+use crate::views::manage_save::ManageSaveState;
+
+/// `unlocked_decorations` comes from the loaded profile, without one every base game decoration
+/// is offered.
+pub fn map_save_to_crew_quarters_state(
+    manage_save_state: &mut ManageSaveState,
+    unlocked_decorations: Option<Vec<String>>,
+) {
+    let crew_quarters = manage_save_state
+        .current_file
+        .character_data
+        .crew_quarters();
+
+    let placeable_decorations = match &unlocked_decorations {
+        Some(unlocked_decorations) => placeable_crew_quarters_decorations(unlocked_decorations),
+        None => {
+            let all_decorations = PROFILE_ROOM_DECORATIONS
+                .iter()
+                .map(|d| d.ident.to_owned())
+                .collect::<Vec<_>>();
+
+            placeable_crew_quarters_decorations(&all_decorations)
+        }
+    };
+
+    let mut decoration_options = std::iter::once(CrewQuartersDecorationOption::new(
+        &CrewQuartersDecorationData {
+            index: 0,
+            decoration_path: CREW_QUARTERS_DEFAULT_DECORATION.to_owned(),
+        },
+    ))
+    .chain(placeable_decorations.iter().filter_map(|d| {
+        crew_quarters_decoration_path(d.ident).map(|path| CrewQuartersDecorationOption {
+            path,
+            name: d.name.to_owned(),
+        })
+    }))
+    .collect::<Vec<_>>();
+
+    // Keep whatever is already placed selectable so it isn't lost when other slots are changed.
+    for d in &crew_quarters.decorations {
+        if !decoration_options
+            .iter()
+            .any(|o| o.path.eq_ignore_ascii_case(&d.decoration_path))
+        {
+            decoration_options.push(CrewQuartersDecorationOption::new(d));
+        }
+    }
+
+    manage_save_state.save_view_state.crew_quarters_state = CrewQuartersState {
+        room_data_path: crew_quarters.room_data_path,
+        preferred_room_assignment: crew_quarters.preferred_room_assignment,
+        rooms: CREW_QUARTERS_ROOMS.to_vec(),
+        decorations: crew_quarters
+            .decorations
+            .into_iter()
+            .map(CrewQuartersDecorationField::new)
+            .collect(),
+        decoration_options,
+        unlocked_decorations,
+        ..Default::default()
+    };
+}
+
+pub fn map_crew_quarters_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let crew_quarters_state = &manage_save_state.save_view_state.crew_quarters_state;
+
+    let crew_quarters = CrewQuartersData {
+        room_data_path: crew_quarters_state.room_data_path.clone(),
+        preferred_room_assignment: crew_quarters_state.preferred_room_assignment,
+        decorations: crew_quarters_state
+            .decorations
+            .iter()
+            .map(|d| d.decoration.clone())
+            .collect(),
+    };
+
+    if crew_quarters != save.character_data.crew_quarters() {
+        save.character_data.set_crew_quarters(
+            &crew_quarters,
+            crew_quarters_state.unlocked_decorations.as_deref(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::echo_logs::{EchoLogGroupFilter, EchoLogsState};
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_echo_logs_state(manage_save_state: &mut ManageSaveState) {
+    let save = &manage_save_state.current_file;
+
+    let echo_logs = save.character_data.echo_logs();
+
+    let mut groups = echo_logs
+        .iter()
+        .map(|e| EchoLogGroupFilter::Group(e.group))
+        .collect::<Vec<_>>();
+
+    groups.dedup();
+
+    groups.insert(0, EchoLogGroupFilter::All);
+
+    manage_save_state.save_view_state.echo_logs_state = EchoLogsState {
+        echo_logs,
+        groups,
+        ..Default::default()
+    };
+}
+
+pub fn map_echo_logs_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let echo_logs_state = &manage_save_state.save_view_state.echo_logs_state;
+
+    let existing_echo_logs = save.character_data.echo_logs();
+
+    for echo_log in &echo_logs_state.echo_logs {
+        if existing_echo_logs.binary_search(echo_log).is_ok() {
+            continue;
+        }
+
+        if echo_log.unlocked {
+            save.character_data.unlock_echo_log(&echo_log.path);
+            save.character_data
+                .set_echo_log_seen(&echo_log.path, echo_log.seen)?;
+        } else {
+            save.character_data.lock_echo_log(&echo_log.path);
+        }
+    }
+
+    Ok(())
+}
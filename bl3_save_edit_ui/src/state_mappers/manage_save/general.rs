@@ -1,3 +1,5 @@
+use anyhow::Result;
+
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
 use crate::views::manage_save::ManageSaveState;
@@ -20,9 +22,26 @@ pub fn map_save_to_general_state(manage_save_state: &mut ManageSaveState) {
         .save_view_state
         .general_state
         .save_type_selected = save.header_type;
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .zone_map_levels = save.character_data.zone_map_levels();
+
+    manage_save_state.save_view_state.general_state.anonymize = false;
+
+    manage_save_state.save_view_state.general_state.ui_tracking = save.character_data.ui_tracking();
+
+    manage_save_state
+        .save_view_state
+        .general_state
+        .unlockable_inventory_slots = save.character_data.unlockable_inventory_slots().clone();
 }
 
-pub fn map_general_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
+pub fn map_general_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
     save.file_name = manage_save_state
         .save_view_state
         .general_state
@@ -42,4 +61,33 @@ pub fn map_general_state_to_save(manage_save_state: &mut ManageSaveState, save:
         .save_view_state
         .general_state
         .save_type_selected;
+
+    let existing_zone_map_levels = save.character_data.zone_map_levels();
+
+    let levels_to_reveal = manage_save_state
+        .save_view_state
+        .general_state
+        .zone_map_levels
+        .iter()
+        .filter(|l| {
+            l.fully_revealed
+                && existing_zone_map_levels
+                    .iter()
+                    .any(|e| e.level_name == l.level_name && !e.fully_revealed)
+        })
+        .map(|l| l.level_name.clone())
+        .collect::<Vec<_>>();
+
+    if !levels_to_reveal.is_empty() {
+        save.character_data
+            .reveal_zone_map_levels(&levels_to_reveal)?;
+    }
+
+    let ui_tracking = &manage_save_state.save_view_state.general_state.ui_tracking;
+
+    if *ui_tracking != save.character_data.ui_tracking() {
+        save.character_data.set_ui_tracking(ui_tracking);
+    }
+
+    Ok(())
 }
@@ -39,6 +39,16 @@ pub fn map_save_to_inventory_state(manage_save_state: &mut ManageSaveState) -> R
         .item_editor_state
         .items_mut() = inventory_items;
 
+    let equipped_item_count =
+        save.character_data.inventory_items().len() - save.character_data.backpack_item_count();
+
+    manage_save_state
+        .save_view_state
+        .inventory_state
+        .item_editor_state
+        .item_capacity =
+        Some(save.character_data.backpack_capacity() as usize + equipped_item_count);
+
     manage_save_state
         .save_view_state
         .inventory_state
@@ -80,6 +90,12 @@ pub fn map_inventory_state_to_save(
 
     inventory_items.par_sort_by_key(|(i, _)| *i);
 
+    let allow_over_capacity = manage_save_state
+        .save_view_state
+        .inventory_state
+        .item_editor_state
+        .allow_over_capacity;
+
     for (i, edited_item) in inventory_items {
         if let Some(original_item) = save.character_data.character.inventory_items.get(i) {
             let original_serial_number = &original_item.item_serial_number;
@@ -100,8 +116,12 @@ pub fn map_inventory_state_to_save(
             // Otherwise insert our new item in this slot
             info!("Inserting item at index: {}", i);
 
-            save.character_data
-                .insert_inventory_item(i as i32, i, edited_item)?;
+            save.character_data.insert_inventory_item(
+                i as i32,
+                i,
+                edited_item,
+                allow_over_capacity,
+            )?;
         }
     }
 
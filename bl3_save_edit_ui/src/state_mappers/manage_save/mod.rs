@@ -1,21 +1,28 @@
 use anyhow::Result;
 
+use bl3_save_edit_core::anonymize::Pseudonyms;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
 use crate::state_mappers::manage_save;
 use crate::views::manage_save::ManageSaveState;
 
+pub mod challenges;
 pub mod character;
+pub mod crew_quarters;
 pub mod currency;
+pub mod echo_logs;
 pub mod general;
 pub mod inventory;
+pub mod regions;
+pub mod stats;
 pub mod vehicle;
 
 pub fn map_all_states_to_save(
     manage_save_state: &mut ManageSaveState,
     current_file: &mut Bl3Save,
+    pseudonyms: &mut Pseudonyms,
 ) -> Result<()> {
-    manage_save::general::map_general_state_to_save(manage_save_state, current_file);
+    manage_save::general::map_general_state_to_save(manage_save_state, current_file)?;
 
     manage_save::character::map_character_state_to_save(manage_save_state, current_file)?;
 
@@ -23,7 +30,22 @@ pub fn map_all_states_to_save(
 
     manage_save::currency::map_currrency_state_to_save(manage_save_state, current_file)?;
 
-    manage_save::vehicle::map_vehicle_state_to_save(manage_save_state, current_file);
+    manage_save::vehicle::map_vehicle_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::challenges::map_challenges_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::echo_logs::map_echo_logs_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::regions::map_regions_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::crew_quarters::map_crew_quarters_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::stats::map_stats_state_to_save(manage_save_state, current_file);
+
+    // Done last so the name and guid aren't overwritten by the character and general states
+    if manage_save_state.save_view_state.general_state.anonymize {
+        current_file.anonymize(pseudonyms);
+    }
 
     Ok(())
 }
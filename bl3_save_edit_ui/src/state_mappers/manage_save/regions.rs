@@ -0,0 +1,61 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::regions::{RegionGameStageField, RegionPlaythrough, RegionsState};
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_regions_state(manage_save_state: &mut ManageSaveState) {
+    let save = &manage_save_state.current_file;
+
+    let regions = save.character_data.region_game_stages();
+
+    let mut playthroughs = regions
+        .iter()
+        .map(|r| r.playthrough_index)
+        .collect::<Vec<_>>();
+
+    playthroughs.sort_unstable();
+    playthroughs.dedup();
+
+    let playthroughs = playthroughs
+        .into_iter()
+        .map(RegionPlaythrough)
+        .collect::<Vec<_>>();
+
+    manage_save_state.save_view_state.regions_state = RegionsState {
+        regions: regions.into_iter().map(RegionGameStageField::new).collect(),
+        playthrough_selected: playthroughs.first().copied().unwrap_or_default(),
+        playthroughs,
+        set_all_input: save.character_data.player_level(),
+        level_persistence_actor_count: save.character_data.level_persistence_actors().len(),
+        ..Default::default()
+    };
+}
+
+pub fn map_regions_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let regions_state = &manage_save_state.save_view_state.regions_state;
+
+    let existing_regions = save.character_data.region_game_stages();
+
+    for r in &regions_state.regions {
+        if existing_regions.binary_search(&r.region).is_ok() {
+            continue;
+        }
+
+        save.character_data.set_region_game_stage(
+            &r.region.region_path,
+            r.region.playthrough_index,
+            r.region.game_stage,
+        )?;
+    }
+
+    if regions_state.reset_level_persistence {
+        save.character_data.reset_level_persistence();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::game_stats::{GameStatEditor, GameStatsState};
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_stats_state(manage_save_state: &mut ManageSaveState) {
+    let save = &manage_save_state.current_file;
+
+    manage_save_state.save_view_state.stats_state = GameStatsState {
+        stats: save
+            .character_data
+            .game_stats()
+            .into_iter()
+            .map(GameStatEditor::new)
+            .collect(),
+        playtime_seconds_input: save.character_data.time_played_seconds() as i32,
+        ..Default::default()
+    };
+}
+
+pub fn map_stats_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
+    let stats_state = &manage_save_state.save_view_state.stats_state;
+
+    let existing_stats = manage_save_state.current_file.character_data.game_stats();
+
+    // Only write stats that were edited so we don't clobber values set by other tabs (e.g. level)
+    for editor in &stats_state.stats {
+        if !existing_stats.contains(&editor.stat) {
+            save.character_data
+                .set_game_stat(&editor.stat.path, editor.stat.value);
+        }
+    }
+
+    save.character_data
+        .set_time_played_seconds(stats_state.playtime_seconds_input as u32);
+}
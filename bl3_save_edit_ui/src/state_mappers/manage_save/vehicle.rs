@@ -1,7 +1,18 @@
+use anyhow::Result;
+use tracing::error;
+
+use bl3_save_edit_core::bl3_save::character_data::CharacterData;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 use bl3_save_edit_core::vehicle_data::{VehicleSubType, VehicleType};
 
+use crate::views::manage_save::vehicle::vehicle_asset_unlocker::{
+    VehicleAssetCategory, VehicleAssetEntry, VehicleAssetUnlocker,
+};
+use crate::views::manage_save::vehicle::vehicle_loadout_editor::{
+    VehicleLoadoutEditor, VehicleLoadoutOption,
+};
 use crate::views::manage_save::vehicle::vehicle_unlocker::VehicleUnlocker;
+use crate::views::manage_save::vehicle::VehicleState;
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_vehicle_state(manage_save_state: &mut ManageSaveState) {
@@ -42,33 +53,116 @@ pub fn map_save_to_vehicle_state(manage_save_state: &mut ManageSaveState) {
         }
     }
 
-    manage_save_state.save_view_state.vehicle_state.unlocker = unlocker;
+    let categories = VehicleAssetCategory::all();
+
+    let assets = categories
+        .iter()
+        .flat_map(|category| {
+            save.character_data
+                .vehicle_assets(&category.0)
+                .into_iter()
+                .map(|asset| VehicleAssetEntry {
+                    category: category.clone(),
+                    asset,
+                })
+        })
+        .collect::<Vec<_>>();
+
+    let asset_unlocker = VehicleAssetUnlocker {
+        category_selected: categories.first().cloned().unwrap_or_default(),
+        categories,
+        assets,
+        ..Default::default()
+    };
+
+    let loadouts = save.character_data.vehicle_loadouts();
+
+    let mut loadout_editor = VehicleLoadoutEditor {
+        loadout_options: (0..loadouts.len()).map(VehicleLoadoutOption).collect(),
+        loadouts,
+        last_loadout_index_input: save.character_data.vehicle_last_loadout_index(),
+        ..Default::default()
+    };
+
+    loadout_editor.refresh_slot_options(&save.character_data);
+
+    manage_save_state.save_view_state.vehicle_state = VehicleState {
+        unlocker,
+        asset_unlocker,
+        loadout_editor,
+        ..Default::default()
+    };
 }
 
-pub fn map_vehicle_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
-    let vehicle_state = &manage_save_state.save_view_state.vehicle_state;
+// Loadout choices depend on what is unlocked, so refresh them against the pending unlocks.
+pub fn map_vehicle_unlocks_to_loadout_options(manage_save_state: &mut ManageSaveState) {
+    let character_data = character_data_with_vehicle_unlocks(manage_save_state);
 
-    let unlocker = &vehicle_state.unlocker;
-
-    let all_vehicle_unlock_boxes = [
-        &unlocker.outrunner_chassis,
-        &unlocker.outrunner_parts,
-        &unlocker.outrunner_skins,
-        &unlocker.jetbeast_chassis,
-        &unlocker.jetbeast_parts,
-        &unlocker.jetbeast_skins,
-        &unlocker.technical_chassis,
-        &unlocker.technical_parts,
-        &unlocker.technical_skins,
-        &unlocker.cyclone_chassis,
-        &unlocker.cyclone_parts,
-        &unlocker.cyclone_skins,
-    ];
-
-    for vd in all_vehicle_unlock_boxes {
+    manage_save_state
+        .save_view_state
+        .vehicle_state
+        .loadout_editor
+        .refresh_slot_options(&character_data);
+}
+
+pub fn character_data_with_vehicle_unlocks(manage_save_state: &ManageSaveState) -> CharacterData {
+    let mut character_data = manage_save_state.current_file.character_data.clone();
+
+    if let Err(e) = map_vehicle_unlocks_to_character_data(
+        &manage_save_state.save_view_state.vehicle_state,
+        &mut character_data,
+    ) {
+        error!("Failed to map vehicle unlocks: {}", e);
+    }
+
+    character_data
+}
+
+fn map_vehicle_unlocks_to_character_data(
+    vehicle_state: &VehicleState,
+    character_data: &mut CharacterData,
+) -> Result<()> {
+    for vd in vehicle_state.unlocker.all_checkboxes() {
         if vd.is_unlocked {
-            save.character_data
-                .unlock_vehicle_data(&vd.vehicle_data.vehicle_type)
+            character_data.unlock_vehicle_data(&vd.vehicle_data.vehicle_type)
         }
     }
+
+    for entry in &vehicle_state.asset_unlocker.assets {
+        if entry.asset.unlocked != character_data.is_vehicle_asset_unlocked(&entry.asset.path) {
+            character_data.set_vehicle_asset_unlocked(
+                &entry.category.0,
+                &entry.asset.path,
+                entry.asset.unlocked,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn map_vehicle_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let vehicle_state = &manage_save_state.save_view_state.vehicle_state;
+
+    map_vehicle_unlocks_to_character_data(vehicle_state, &mut save.character_data)?;
+
+    let loadout_editor = &vehicle_state.loadout_editor;
+
+    let existing_loadouts = save.character_data.vehicle_loadouts();
+
+    for (i, loadout) in loadout_editor.loadouts.iter().enumerate() {
+        if existing_loadouts.get(i) != Some(loadout) {
+            save.character_data.set_vehicle_loadout(i, loadout)?;
+        }
+    }
+
+    if loadout_editor.last_loadout_index_input != save.character_data.vehicle_last_loadout_index() {
+        save.character_data
+            .set_vehicle_last_loadout_index(loadout_editor.last_loadout_index_input)?;
+    }
+
+    Ok(())
 }
@@ -1,15 +1,11 @@
 use std::mem;
-use std::path::PathBuf;
 
 use anyhow::Result;
 
-use bl3_save_edit_core::bl3_profile::guardian_reward::GuardianRewardData;
 use bl3_save_edit_core::file_helper::Bl3FileType;
 
 use crate::bl3_ui::Bl3Application;
 use crate::bl3_ui::ViewState;
-use crate::commands::interaction;
-use crate::commands::interaction::choose_save_directory;
 use crate::views::manage_profile::main::ProfileTabBarView;
 use crate::views::manage_profile::ManageProfileView;
 use crate::views::manage_save::main::SaveTabBarView;
@@ -34,6 +30,28 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
 
             manage_save::vehicle::map_save_to_vehicle_state(&mut main_state.manage_save_state);
 
+            manage_save::challenges::map_save_to_challenges_state(
+                &mut main_state.manage_save_state,
+            );
+
+            manage_save::echo_logs::map_save_to_echo_logs_state(&mut main_state.manage_save_state);
+
+            manage_save::regions::map_save_to_regions_state(&mut main_state.manage_save_state);
+
+            let unlocked_decorations = main_state.loaded_files.iter().find_map(|f| match f {
+                Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => {
+                    Some(p.profile_data.unlocked_crew_quarters_decorations())
+                }
+                _ => None,
+            });
+
+            manage_save::crew_quarters::map_save_to_crew_quarters_state(
+                &mut main_state.manage_save_state,
+                unlocked_decorations,
+            );
+
+            manage_save::stats::map_save_to_stats_state(&mut main_state.manage_save_state);
+
             if mem::discriminant(&main_state.view_state)
                 != mem::discriminant(&ViewState::ManageSave(ManageSaveView::TabBar(
                     SaveTabBarView::General,
@@ -54,10 +72,36 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
                 &mut main_state.manage_profile_state,
             );
 
+            manage_profile::borderlands_science::map_profile_to_borderlands_science_state(
+                &mut main_state.manage_profile_state,
+            );
+
             manage_profile::keys::map_profile_to_keys_state(&mut main_state.manage_profile_state);
 
             manage_profile::bank::map_profile_to_bank_state(&mut main_state.manage_profile_state)?;
 
+            manage_profile::stats::map_profile_to_stats_state(&mut main_state.manage_profile_state);
+
+            manage_profile::game_settings::map_profile_to_game_settings_state(
+                &mut main_state.manage_profile_state,
+            );
+
+            manage_profile::key_bindings::map_profile_to_key_bindings_state(
+                &mut main_state.manage_profile_state,
+            );
+
+            manage_profile::cosmetics::map_profile_to_cosmetics_state(
+                &mut main_state.manage_profile_state,
+            );
+
+            manage_profile::vault_card::map_profile_to_vault_card_state(
+                &mut main_state.manage_profile_state,
+            );
+
+            manage_profile::first_run::map_profile_to_first_run_state(
+                &mut main_state.manage_profile_state,
+            );
+
             if mem::discriminant(&main_state.view_state)
                 != mem::discriminant(&ViewState::ManageProfile(ManageProfileView::TabBar(
                     ProfileTabBarView::General,
@@ -71,44 +115,3 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
 
     Ok(())
 }
-
-pub async fn inject_guardian_data_into_saves(
-    backup_dir: PathBuf,
-    saves_dir: PathBuf,
-    guardian_rank: i32,
-    guardian_tokens: i32,
-    guardian_rewards: &[GuardianRewardData],
-) -> Result<()> {
-    let (_, all_files) = choose_save_directory::load_files_in_directory(saves_dir.clone()).await?;
-
-    for file in all_files {
-        match file {
-            Bl3FileType::PcSave(mut s) | Bl3FileType::Ps4Save(mut s) => {
-                s.character_data
-                    .set_guardian_rank(guardian_rank, Some(guardian_tokens));
-
-                for g in guardian_rewards {
-                    s.character_data.set_guardian_reward(&g.reward, g.current)?;
-                }
-
-                let output_file = saves_dir.join(&s.file_name);
-
-                let (output, new_save) = s.as_bytes()?;
-
-                let existing_save = s.clone();
-
-                interaction::file_save::save_file(
-                    backup_dir.clone(),
-                    output_file,
-                    output,
-                    existing_save,
-                    new_save,
-                )
-                .await?;
-            }
-            _ => (),
-        }
-    }
-
-    Ok(())
-}
@@ -0,0 +1,362 @@
+use std::path::PathBuf;
+
+use iced::alignment::Horizontal;
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Color, Column, Container, Length, Row,
+    Scrollable, Text,
+};
+
+use bl3_save_edit_core::backup_store::BackupRetention;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::commands::interaction::backups::Backup;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::item_editor::item_button_style::ItemEditorButtonStyle;
+use crate::views::settings::SettingsInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug)]
+pub struct BackupListItem {
+    pub backup: Backup,
+    button_state: button::State,
+}
+
+#[derive(Debug)]
+pub struct BackupComparisonRow {
+    pub field: &'static str,
+    pub backup: String,
+    pub current: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct BackupsState {
+    pub backups: Vec<BackupListItem>,
+    pub selected: Option<usize>,
+    pub comparison: Vec<BackupComparisonRow>,
+    pub is_busy: bool,
+    pub keep_per_file_input: i32,
+    keep_per_file_input_state: text_input::State,
+    pub max_age_days_input: i32,
+    max_age_days_input_state: text_input::State,
+    refresh_button_state: button::State,
+    restore_button_state: button::State,
+    prune_button_state: button::State,
+    list_scrollable_state: scrollable::State,
+    comparison_scrollable_state: scrollable::State,
+}
+
+impl BackupsState {
+    pub fn set_backups(&mut self, backups: Vec<Backup>) {
+        self.backups = backups
+            .into_iter()
+            .map(|backup| BackupListItem {
+                backup,
+                button_state: button::State::default(),
+            })
+            .collect();
+
+        self.selected = None;
+        self.comparison.clear();
+    }
+
+    pub fn set_retention(&mut self, retention: BackupRetention) {
+        self.keep_per_file_input = retention.keep_per_file as i32;
+        self.max_age_days_input = retention.max_age_days as i32;
+    }
+
+    pub fn retention(&self) -> BackupRetention {
+        BackupRetention {
+            keep_per_file: self.keep_per_file_input.max(0) as usize,
+            max_age_days: self.max_age_days_input.max(0) as u64,
+        }
+    }
+
+    pub fn selected_backup(&self) -> Option<&Backup> {
+        self.selected
+            .and_then(|i| self.backups.get(i))
+            .map(|b| &b.backup)
+    }
+
+    pub fn select(&mut self, index: usize, loaded_files: &[Bl3FileType]) {
+        self.selected = Some(index);
+
+        self.comparison = match self.selected_backup() {
+            Some(backup) => {
+                let current = loaded_files
+                    .iter()
+                    .find(|f| f.filename() == backup.original_file_name)
+                    .map(|f| f.summary());
+
+                backup
+                    .file
+                    .summary()
+                    .into_iter()
+                    .map(|(field, value)| BackupComparisonRow {
+                        field,
+                        backup: value,
+                        current: current.as_ref().and_then(|c| {
+                            c.iter()
+                                .find(|(f, _)| *f == field)
+                                .map(|(_, v)| v.to_owned())
+                        }),
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BackupsInteractionMessage {
+    RefreshPressed,
+    Loaded(MessageResult<Vec<Backup>>),
+    BackupSelected(usize),
+    RestorePressed,
+    RestoreCompleted(MessageResult<PathBuf>),
+    KeepPerFile(i32),
+    MaxAgeDays(i32),
+    PrunePressed,
+    PruneCompleted(MessageResult<usize>),
+}
+
+fn interaction(message: BackupsInteractionMessage) -> InteractionMessage {
+    InteractionMessage::SettingsInteraction(SettingsInteractionMessage::Backups(message))
+}
+
+fn comparison_text(text: &str, color: Color) -> Text {
+    Text::new(text)
+        .font(JETBRAINS_MONO)
+        .size(16)
+        .color(color)
+        .width(Length::FillPortion(1))
+}
+
+pub fn view(backups_state: &mut BackupsState) -> Container<Bl3Message> {
+    let selected = backups_state.selected;
+
+    let mut last_file_name = None;
+
+    let backup_list = backups_state.backups.iter_mut().enumerate().fold(
+        Column::new().spacing(1),
+        |mut column, (i, item)| {
+            if last_file_name.as_ref() != Some(&item.backup.original_file_name) {
+                last_file_name = Some(item.backup.original_file_name.clone());
+
+                column = column.push(
+                    Container::new(
+                        Text::new(&item.backup.original_file_name)
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(16)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .padding(10),
+                );
+            }
+
+            let label = format!(
+                "{} - {} (v{})",
+                item.backup.created(),
+                item.backup.file,
+                item.backup.entry.editor_version
+            );
+
+            column.push(
+                Button::new(
+                    &mut item.button_state,
+                    Text::new(label).font(JETBRAINS_MONO).size(16),
+                )
+                .on_press(interaction(BackupsInteractionMessage::BackupSelected(i)))
+                .padding(10)
+                .width(Length::Fill)
+                .style(ItemEditorButtonStyle {
+                    is_active: selected == Some(i),
+                })
+                .into_element(),
+            )
+        },
+    );
+
+    let backup_list: Column<Bl3Message> = if backups_state.backups.is_empty() {
+        Column::new().push(
+            Container::new(
+                Text::new("No backups found.")
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .padding(10),
+        )
+    } else {
+        backup_list
+    };
+
+    let comparison_header = Row::new()
+        .push(comparison_text("Field", Color::from_rgb8(242, 203, 5)))
+        .push(comparison_text("Backup", Color::from_rgb8(242, 203, 5)))
+        .push(comparison_text("Current", Color::from_rgb8(242, 203, 5)))
+        .spacing(10);
+
+    let comparison = backups_state.comparison.iter().fold(
+        Column::new().push(comparison_header).spacing(10),
+        |column, row| {
+            let current = row.current.as_deref().unwrap_or("-");
+
+            let value_color = if row.current.as_deref() == Some(row.backup.as_str()) {
+                Color::from_rgb8(220, 220, 220)
+            } else {
+                Color::from_rgb8(242, 203, 5)
+            };
+
+            column.push(
+                Row::new()
+                    .push(comparison_text(row.field, Color::from_rgb8(220, 220, 220)))
+                    .push(comparison_text(&row.backup, value_color))
+                    .push(comparison_text(current, value_color))
+                    .spacing(10),
+            )
+        },
+    );
+
+    let mut refresh_button = Button::new(
+        &mut backups_state.refresh_button_state,
+        Text::new("Refresh").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut restore_button = Button::new(
+        &mut backups_state.restore_button_state,
+        Text::new("Restore Backup")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut prune_button = Button::new(
+        &mut backups_state.prune_button_state,
+        Text::new("Remove Old Backups")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !backups_state.is_busy {
+        refresh_button =
+            refresh_button.on_press(interaction(BackupsInteractionMessage::RefreshPressed));
+
+        if selected.is_some() {
+            restore_button =
+                restore_button.on_press(interaction(BackupsInteractionMessage::RestorePressed));
+        }
+
+        prune_button = prune_button.on_press(interaction(BackupsInteractionMessage::PrunePressed));
+    }
+
+    let retention = Row::new()
+        .push(
+            LabelledElement::create(
+                "Keep per file",
+                Length::Units(140),
+                NumberInput::new(
+                    &mut backups_state.keep_per_file_input_state,
+                    backups_state.keep_per_file_input,
+                    0,
+                    Some(1000),
+                    |v| interaction(BackupsInteractionMessage::KeepPerFile(v)),
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .width(Length::FillPortion(1))
+            .align_items(Alignment::Center),
+        )
+        .push(
+            LabelledElement::create(
+                "Max age (days)",
+                Length::Units(140),
+                NumberInput::new(
+                    &mut backups_state.max_age_days_input_state,
+                    backups_state.max_age_days_input,
+                    0,
+                    Some(3650),
+                    |v| interaction(BackupsInteractionMessage::MaxAgeDays(v)),
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .width(Length::FillPortion(1))
+            .align_items(Alignment::Center),
+        )
+        .push(prune_button.into_element())
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let contents = Column::new()
+        .push(
+            Container::new(
+                Text::new("Backups")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .padding(10)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        )
+        .push(
+            Container::new(
+                Column::new()
+                    .push(
+                        Row::new()
+                            .push(
+                                Scrollable::new(&mut backups_state.list_scrollable_state)
+                                    .push(backup_list)
+                                    .width(Length::FillPortion(3))
+                                    .height(Length::Fill),
+                            )
+                            .push(
+                                Scrollable::new(&mut backups_state.comparison_scrollable_state)
+                                    .push(comparison)
+                                    .width(Length::FillPortion(2))
+                                    .height(Length::Fill),
+                            )
+                            .spacing(20)
+                            .height(Length::Fill),
+                    )
+                    .push(
+                        Row::new()
+                            .push(refresh_button.into_element())
+                            .push(restore_button.into_element())
+                            .spacing(20),
+                    )
+                    .push(retention)
+                    .spacing(20),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle),
+        );
+
+    Container::new(contents)
+        .width(Length::Fill)
+        .height(Length::Fill)
+}
@@ -0,0 +1,212 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    scrollable, text_input, Alignment, Color, Column, Container, Length, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::game_stats::GameStat;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct GameStatsState {
+    pub stats: Vec<GameStatEditor>,
+    pub playtime_seconds_input: i32,
+    pub playtime_seconds_input_state: text_input::State,
+    pub search_input: String,
+    pub search_input_state: text_input::State,
+    pub stat_list_scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Default)]
+pub struct GameStatEditor {
+    pub stat: GameStat,
+    value_input_state: text_input::State,
+}
+
+impl GameStatEditor {
+    pub fn new(stat: GameStat) -> Self {
+        Self {
+            stat,
+            ..Default::default()
+        }
+    }
+
+    pub fn view<F>(&mut self, stat_index: usize, interaction_message: F) -> Row<Bl3Message>
+    where
+        F: Fn(GameStatsInteractionMessage) -> InteractionMessage + 'static + Copy,
+    {
+        let stat = &self.stat;
+
+        let mut name_column = Column::new().push(
+            Text::new(&stat.name)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        );
+
+        if stat.is_known {
+            name_column = name_column.push(
+                Text::new(&stat.path)
+                    .font(JETBRAINS_MONO)
+                    .size(13)
+                    .color(Color::from_rgb8(160, 160, 160)),
+            );
+        }
+
+        let value_input =
+            NumberInput::new(&mut self.value_input_state, stat.value, 0, None, move |v| {
+                interaction_message(GameStatsInteractionMessage::StatValue(stat_index, v))
+            })
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(5)
+            .size(17)
+            .width(Length::Units(150))
+            .style(Bl3UiStyle)
+            .into_element();
+
+        Row::new()
+            .push(name_column.spacing(5).width(Length::Fill))
+            .push(value_input)
+            .padding(10)
+            .spacing(20)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum GameStatsInteractionMessage {
+    SearchInputChanged(String),
+    PlaytimeSeconds(i32),
+    StatValue(usize, i32),
+}
+
+impl GameStatsInteractionMessage {
+    pub fn update_state(self, game_stats_state: &mut GameStatsState) {
+        match self {
+            GameStatsInteractionMessage::SearchInputChanged(search_query) => {
+                game_stats_state.search_input = search_query.to_lowercase();
+                game_stats_state.stat_list_scrollable_state.snap_to(0.0);
+            }
+            GameStatsInteractionMessage::PlaytimeSeconds(playtime_seconds) => {
+                game_stats_state.playtime_seconds_input = playtime_seconds;
+            }
+            GameStatsInteractionMessage::StatValue(stat_index, value) => {
+                if let Some(editor) = game_stats_state.stats.get_mut(stat_index) {
+                    editor.stat.value = value;
+                }
+            }
+        }
+    }
+}
+
+pub fn get_filtered_stats(search_query: &str, stat: &GameStat) -> bool {
+    let search_query = search_query.trim();
+
+    search_query.is_empty()
+        || stat.name.to_lowercase().contains(search_query)
+        || stat.path.to_lowercase().contains(search_query)
+}
+
+pub fn view<F>(
+    game_stats_state: &mut GameStatsState,
+    interaction_message: F,
+) -> Container<Bl3Message>
+where
+    F: Fn(GameStatsInteractionMessage) -> InteractionMessage + 'static + Copy,
+{
+    let playtime_seconds = Container::new(
+        LabelledElement::create(
+            "Playtime (seconds)",
+            Length::Units(190),
+            NumberInput::new(
+                &mut game_stats_state.playtime_seconds_input_state,
+                game_stats_state.playtime_seconds_input,
+                0,
+                None,
+                move |v| interaction_message(GameStatsInteractionMessage::PlaytimeSeconds(v)),
+            )
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let search_input = TextInputLimited::new(
+        &mut game_stats_state.search_input_state,
+        "Search stats...",
+        &game_stats_state.search_input,
+        500,
+        move |s| interaction_message(GameStatsInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .width(Length::FillPortion(3))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let filter_row = Row::new()
+        .push(playtime_seconds)
+        .push(search_input)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let search_query = &game_stats_state.search_input;
+
+    let has_results = game_stats_state
+        .stats
+        .iter()
+        .any(|s| get_filtered_stats(search_query, &s.stat));
+
+    let stat_list = game_stats_state
+        .stats
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, s)| get_filtered_stats(search_query, &s.stat))
+        .fold(Column::new(), |column, (i, s)| {
+            column.push(s.view(i, interaction_message))
+        });
+
+    let stat_list = if has_results {
+        Container::new(
+            Scrollable::new(&mut game_stats_state.stat_list_scrollable_state)
+                .push(stat_list)
+                .height(Length::Fill),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    let all_contents = Column::new().push(filter_row).push(stat_list).spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
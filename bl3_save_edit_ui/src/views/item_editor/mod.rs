@@ -6,8 +6,8 @@ use derivative::Derivative;
 use heck::TitleCase;
 use iced::alignment::{Horizontal, Vertical};
 use iced::{
-    button, scrollable, text_input, tooltip, Alignment, Button, Color, Column, Command, Container,
-    Length, Row, Scrollable, Text, Tooltip,
+    button, scrollable, text_input, tooltip, Alignment, Button, Checkbox, Color, Column, Command,
+    Container, Length, Row, Scrollable, Text, Tooltip,
 };
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rayon::slice::ParallelSliceMut;
@@ -74,6 +74,8 @@ pub struct ItemEditorState {
     pub item_list_tab_type: ItemListTabType,
     pub item_list_items_tab_button_state: button::State,
     pub item_list_lootlemon_tab_button_state: button::State,
+    pub item_capacity: Option<usize>,
+    pub allow_over_capacity: bool,
 }
 
 #[derive(Debug)]
@@ -271,6 +273,7 @@ pub enum ItemEditorInteractionMessage {
     ItemListLootlemonOpenWebsitePressed(usize),
     ItemListLootlemonOpenWebsiteCompleted(MessageResult<()>),
     ShowAllAvailablePartsSelected(bool),
+    AllowOverCapacitySelected(bool),
     AvailablePartsSearchInputChanged(String),
     AvailablePartsTabPressed,
     AvailableAnointmentsTabPressed,
@@ -405,6 +408,9 @@ impl ItemEditorInteractionMessage {
                     notification = Some(Notification::new(msg, NotificationSentiment::Negative));
                 }
             }
+            ItemEditorInteractionMessage::AllowOverCapacitySelected(selected) => {
+                item_editor_state.allow_over_capacity = selected;
+            }
             ItemEditorInteractionMessage::ShowAllAvailablePartsSelected(selected) => {
                 item_editor_state
                     .map_current_item_if_exists(|i| {
@@ -1122,6 +1128,16 @@ where
         &item_editor_state.lootlemon_items.items,
     );
 
+    let item_count_label = match item_editor_state.item_capacity {
+        Some(capacity) => format!("({}/{})", number_of_items, capacity),
+        None => format!("({})", number_of_items),
+    };
+
+    let is_over_capacity = item_editor_state
+        .item_capacity
+        .map(|capacity| number_of_items > capacity)
+        .unwrap_or(false);
+
     let item_list_title_row = Row::new()
         .push(
             Container::new(tab_bar_button(
@@ -1129,7 +1145,7 @@ where
                 ItemListTabType::Items,
                 &item_editor_state.item_list_tab_type,
                 interaction_message(ItemEditorInteractionMessage::ItemListItemTabPressed),
-                Some(format!("({})", number_of_items)),
+                Some(item_count_label),
             ))
             .padding(1)
             .width(Length::FillPortion(2)),
@@ -1177,7 +1193,7 @@ where
         ),
     };
 
-    let item_list_search_row = Row::new()
+    let mut item_list_search_row = Row::new()
         .push(
             item_list_search_input
                 .0
@@ -1189,6 +1205,43 @@ where
         )
         .align_items(Alignment::Center);
 
+    if item_list_tab_type == &ItemListTabType::Items && item_editor_state.item_capacity.is_some() {
+        let allow_over_capacity_text_color = if is_over_capacity {
+            Color::from_rgb8(220, 120, 120)
+        } else {
+            Color::from_rgb8(220, 220, 220)
+        };
+
+        item_list_search_row = item_list_search_row
+            .push(
+                Tooltip::new(
+                    Checkbox::new(
+                        item_editor_state.allow_over_capacity,
+                        "Allow over capacity",
+                        move |c| {
+                            interaction_message(
+                                ItemEditorInteractionMessage::AllowOverCapacitySelected(c),
+                            )
+                        },
+                    )
+                    .size(20)
+                    .font(JETBRAINS_MONO)
+                    .text_color(allow_over_capacity_text_color)
+                    .text_size(17)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                    "Allow saving more items than the SDU level holds, extra items may be lost in game",
+                    tooltip::Position::Left,
+                )
+                .gap(10)
+                .padding(10)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
+            )
+            .spacing(10);
+    }
+
     let mut item_editor = None;
 
     let mut inventory_item_categories = HashSet::new();
@@ -0,0 +1,438 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, text_input, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    PickList, Row, Text,
+};
+
+use bl3_save_edit_core::bl3_profile::science_levels::{
+    BorderlandsScienceBooster, BorderlandsScienceBoosterSlot, BorderlandsScienceLevel,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+const DEFAULT_BOOSTER_DURATION_MINUTES: i32 = 60;
+
+#[derive(Debug)]
+pub struct BorderlandsScienceState {
+    pub level_selector: pick_list::State<BorderlandsScienceLevel>,
+    pub level_selected: BorderlandsScienceLevel,
+    pub tokens_input: i32,
+    pub tokens_input_state: text_input::State,
+    pub has_seen_intro_video: bool,
+    pub tutorial_done: bool,
+    pub streamer_booster_tier_input: i32,
+    pub streamer_booster_tier_input_state: text_input::State,
+    pub boosters: Vec<BorderlandsScienceBoosterField>,
+}
+
+impl std::default::Default for BorderlandsScienceState {
+    fn default() -> Self {
+        Self {
+            level_selector: pick_list::State::default(),
+            level_selected: BorderlandsScienceLevel::default(),
+            tokens_input: 0,
+            tokens_input_state: text_input::State::default(),
+            has_seen_intro_video: false,
+            tutorial_done: false,
+            streamer_booster_tier_input: 0,
+            streamer_booster_tier_input_state: text_input::State::default(),
+            boosters: BorderlandsScienceBoosterSlot::ALL
+                .into_iter()
+                .map(|slot| BorderlandsScienceBoosterField::new(slot, Default::default()))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BorderlandsScienceBoosterField {
+    pub slot: BorderlandsScienceBoosterSlot,
+    pub booster: BorderlandsScienceBooster,
+    pub index_input: i32,
+    index_input_state: text_input::State,
+    pub duration_minutes_input: i32,
+    duration_minutes_input_state: text_input::State,
+    apply_button_state: button::State,
+    clear_button_state: button::State,
+}
+
+impl BorderlandsScienceBoosterField {
+    pub fn new(slot: BorderlandsScienceBoosterSlot, booster: BorderlandsScienceBooster) -> Self {
+        let duration_minutes_input = if booster.total_seconds > 0 {
+            ((booster.total_seconds + 59) / 60) as i32
+        } else {
+            DEFAULT_BOOSTER_DURATION_MINUTES
+        };
+
+        Self {
+            slot,
+            booster,
+            index_input: booster.index,
+            index_input_state: text_input::State::default(),
+            duration_minutes_input,
+            duration_minutes_input_state: text_input::State::default(),
+            apply_button_state: button::State::default(),
+            clear_button_state: button::State::default(),
+        }
+    }
+
+    fn view(&mut self, field_index: usize) -> Container<Bl3Message> {
+        let status = if self.booster.is_active() {
+            format!(
+                "Booster {} active, {} of {} remaining",
+                self.booster.index,
+                format_duration(self.booster.remaining_seconds),
+                format_duration(self.booster.total_seconds)
+            )
+        } else {
+            "No booster active".to_owned()
+        };
+
+        let index_input = Container::new(
+            LabelledElement::create(
+                "Booster Index",
+                Length::Units(130),
+                NumberInput::new(
+                    &mut self.index_input_state,
+                    self.index_input,
+                    0,
+                    None,
+                    move |v| {
+                        borderlands_science_interaction(
+                            BorderlandsScienceInteractionMessage::BoosterIndex(field_index, v),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::FillPortion(2))
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let duration_input = Container::new(
+            LabelledElement::create(
+                "Duration (Minutes)",
+                Length::Units(175),
+                NumberInput::new(
+                    &mut self.duration_minutes_input_state,
+                    self.duration_minutes_input,
+                    1,
+                    None,
+                    move |v| {
+                        borderlands_science_interaction(
+                            BorderlandsScienceInteractionMessage::BoosterDuration(field_index, v),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::FillPortion(2))
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let apply_button = Button::new(
+            &mut self.apply_button_state,
+            Text::new("Apply").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(borderlands_science_interaction(
+            BorderlandsScienceInteractionMessage::ApplyBoosterPressed(field_index),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        let clear_button = Button::new(
+            &mut self.clear_button_state,
+            Text::new("Clear").font(JETBRAINS_MONO_BOLD).size(17),
+        )
+        .on_press(borderlands_science_interaction(
+            BorderlandsScienceInteractionMessage::ClearBoosterPressed(field_index),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        let contents = Column::new()
+            .push(
+                Row::new()
+                    .push(
+                        Text::new(self.slot.to_string())
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        Text::new(status)
+                            .font(JETBRAINS_MONO)
+                            .size(14)
+                            .color(Color::from_rgb8(160, 160, 160)),
+                    )
+                    .align_items(Alignment::Center),
+            )
+            .push(
+                Row::new()
+                    .push(index_input)
+                    .push(duration_input)
+                    .push(apply_button)
+                    .push(clear_button)
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+            )
+            .spacing(15);
+
+        Container::new(contents)
+            .width(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BorderlandsScienceInteractionMessage {
+    LevelSelected(BorderlandsScienceLevel),
+    Tokens(i32),
+    HasSeenIntroVideo(bool),
+    TutorialDone(bool),
+    StreamerBoosterTier(i32),
+    BoosterIndex(usize, i32),
+    BoosterDuration(usize, i32),
+    ApplyBoosterPressed(usize),
+    ClearBoosterPressed(usize),
+}
+
+impl BorderlandsScienceInteractionMessage {
+    pub fn update_state(self, borderlands_science_state: &mut BorderlandsScienceState) {
+        match self {
+            BorderlandsScienceInteractionMessage::LevelSelected(level) => {
+                borderlands_science_state.level_selected = level;
+            }
+            BorderlandsScienceInteractionMessage::Tokens(tokens) => {
+                borderlands_science_state.tokens_input = tokens;
+            }
+            BorderlandsScienceInteractionMessage::HasSeenIntroVideo(seen) => {
+                borderlands_science_state.has_seen_intro_video = seen;
+            }
+            BorderlandsScienceInteractionMessage::TutorialDone(done) => {
+                borderlands_science_state.tutorial_done = done;
+            }
+            BorderlandsScienceInteractionMessage::StreamerBoosterTier(tier) => {
+                borderlands_science_state.streamer_booster_tier_input = tier;
+            }
+            BorderlandsScienceInteractionMessage::BoosterIndex(i, index) => {
+                if let Some(b) = borderlands_science_state.boosters.get_mut(i) {
+                    b.index_input = index;
+                }
+            }
+            BorderlandsScienceInteractionMessage::BoosterDuration(i, minutes) => {
+                if let Some(b) = borderlands_science_state.boosters.get_mut(i) {
+                    b.duration_minutes_input = minutes;
+                }
+            }
+            BorderlandsScienceInteractionMessage::ApplyBoosterPressed(i) => {
+                if let Some(b) = borderlands_science_state.boosters.get_mut(i) {
+                    let duration_seconds = b.duration_minutes_input.max(1) as u32 * 60;
+
+                    b.booster = BorderlandsScienceBooster {
+                        index: b.index_input,
+                        remaining_seconds: duration_seconds,
+                        total_seconds: duration_seconds,
+                    };
+                }
+            }
+            BorderlandsScienceInteractionMessage::ClearBoosterPressed(i) => {
+                if let Some(b) = borderlands_science_state.boosters.get_mut(i) {
+                    b.booster = BorderlandsScienceBooster::default();
+                }
+            }
+        }
+    }
+}
+
+fn borderlands_science_interaction(
+    message: BorderlandsScienceInteractionMessage,
+) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(
+        ManageProfileInteractionMessage::BorderlandsScience(message),
+    )
+}
+
+fn format_duration(seconds: u32) -> String {
+    format!("{}m {:02}s", seconds / 60, seconds % 60)
+}
+
+pub fn view(borderlands_science_state: &mut BorderlandsScienceState) -> Container<Bl3Message> {
+    let level = Container::new(
+        LabelledElement::create(
+            "Borderlands Science Level",
+            Length::Units(215),
+            PickList::new(
+                &mut borderlands_science_state.level_selector,
+                &BorderlandsScienceLevel::ALL[..],
+                Some(borderlands_science_state.level_selected),
+                |l| {
+                    borderlands_science_interaction(
+                        BorderlandsScienceInteractionMessage::LevelSelected(l),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let tokens = Container::new(
+        LabelledElement::create(
+            "Borderlands Science Tokens",
+            Length::Units(225),
+            NumberInput::new(
+                &mut borderlands_science_state.tokens_input_state,
+                borderlands_science_state.tokens_input,
+                0,
+                None,
+                |v| {
+                    borderlands_science_interaction(BorderlandsScienceInteractionMessage::Tokens(v))
+                },
+            )
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let level_row = Row::new().push(level).push(tokens).spacing(20);
+
+    let streamer_booster_tier = Container::new(
+        LabelledElement::create(
+            "Streamer Booster Tier",
+            Length::Units(195),
+            NumberInput::new(
+                &mut borderlands_science_state.streamer_booster_tier_input_state,
+                borderlands_science_state.streamer_booster_tier_input,
+                0,
+                None,
+                |v| {
+                    borderlands_science_interaction(
+                        BorderlandsScienceInteractionMessage::StreamerBoosterTier(v),
+                    )
+                },
+            )
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let flags = Container::new(
+        Row::new()
+            .push(
+                Checkbox::new(
+                    borderlands_science_state.has_seen_intro_video,
+                    "Intro Video Seen",
+                    |c| {
+                        borderlands_science_interaction(
+                            BorderlandsScienceInteractionMessage::HasSeenIntroVideo(c),
+                        )
+                    },
+                )
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Checkbox::new(
+                    borderlands_science_state.tutorial_done,
+                    "Tutorial Done",
+                    |c| {
+                        borderlands_science_interaction(
+                            BorderlandsScienceInteractionMessage::TutorialDone(c),
+                        )
+                    },
+                )
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(30)
+            .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .align_x(Horizontal::Center)
+    .align_y(Vertical::Center)
+    .style(Bl3UiStyle);
+
+    let flags_row = Row::new()
+        .push(streamer_booster_tier)
+        .push(flags)
+        .spacing(20);
+
+    let boosters = borderlands_science_state
+        .boosters
+        .iter_mut()
+        .enumerate()
+        .fold(Column::new().spacing(20), |column, (i, b)| {
+            column.push(b.view(i))
+        });
+
+    let all_contents = Column::new()
+        .push(level_row)
+        .push(flags_row)
+        .push(boosters)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
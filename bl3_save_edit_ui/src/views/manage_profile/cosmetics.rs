@@ -0,0 +1,394 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_profile::skins::{ProfileCosmetic, ProfileSkinType};
+use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug)]
+pub struct CosmeticsState {
+    pub cosmetics: Vec<ProfileCosmetic>,
+    pub types: Vec<CosmeticTypeFilter>,
+    pub type_selector: pick_list::State<CosmeticTypeFilter>,
+    pub type_selected: CosmeticTypeFilter,
+    pub classes: Vec<CosmeticClassFilter>,
+    pub class_selector: pick_list::State<CosmeticClassFilter>,
+    pub class_selected: CosmeticClassFilter,
+    pub search_input: String,
+    pub search_input_state: text_input::State,
+    pub unlock_all_button_state: button::State,
+    pub lock_all_button_state: button::State,
+    pub cosmetic_list_scrollable_state: scrollable::State,
+}
+
+impl std::default::Default for CosmeticsState {
+    fn default() -> Self {
+        let types = std::iter::once(CosmeticTypeFilter::All)
+            .chain(
+                ProfileSkinType::ALL
+                    .iter()
+                    .map(|t| CosmeticTypeFilter::Type(*t)),
+            )
+            .collect();
+
+        let classes = [CosmeticClassFilter::All, CosmeticClassFilter::Shared]
+            .into_iter()
+            .chain(PlayerClass::ALL.into_iter().map(CosmeticClassFilter::Class))
+            .collect();
+
+        Self {
+            cosmetics: Vec::new(),
+            types,
+            type_selector: pick_list::State::default(),
+            type_selected: CosmeticTypeFilter::default(),
+            classes,
+            class_selector: pick_list::State::default(),
+            class_selected: CosmeticClassFilter::default(),
+            search_input: String::new(),
+            search_input_state: text_input::State::default(),
+            unlock_all_button_state: button::State::default(),
+            lock_all_button_state: button::State::default(),
+            cosmetic_list_scrollable_state: scrollable::State::default(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CosmeticTypeFilter {
+    All,
+    Type(ProfileSkinType),
+}
+
+impl std::default::Default for CosmeticTypeFilter {
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+impl std::fmt::Display for CosmeticTypeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CosmeticTypeFilter::All => write!(f, "All"),
+            CosmeticTypeFilter::Type(skin_type) => write!(f, "{}", skin_type),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CosmeticClassFilter {
+    All,
+    Shared,
+    Class(PlayerClass),
+}
+
+impl std::default::Default for CosmeticClassFilter {
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+impl std::fmt::Display for CosmeticClassFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CosmeticClassFilter::All => write!(f, "All"),
+            CosmeticClassFilter::Shared => write!(f, "Shared"),
+            CosmeticClassFilter::Class(class) => write!(f, "{}", class),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CosmeticsInteractionMessage {
+    SearchInputChanged(String),
+    TypeSelected(CosmeticTypeFilter),
+    ClassSelected(CosmeticClassFilter),
+    UnlockAllPressed,
+    LockAllPressed,
+    Unlocked(usize, bool),
+}
+
+impl CosmeticsInteractionMessage {
+    pub fn update_state(self, cosmetics_state: &mut CosmeticsState) {
+        let search_query = cosmetics_state.search_input.clone();
+        let type_selected = cosmetics_state.type_selected;
+        let class_selected = cosmetics_state.class_selected;
+
+        match self {
+            CosmeticsInteractionMessage::SearchInputChanged(search_query) => {
+                cosmetics_state.search_input = search_query.to_lowercase();
+            }
+            CosmeticsInteractionMessage::TypeSelected(skin_type) => {
+                cosmetics_state.type_selected = skin_type;
+                cosmetics_state.cosmetic_list_scrollable_state.snap_to(0.0);
+            }
+            CosmeticsInteractionMessage::ClassSelected(class) => {
+                cosmetics_state.class_selected = class;
+                cosmetics_state.cosmetic_list_scrollable_state.snap_to(0.0);
+            }
+            CosmeticsInteractionMessage::UnlockAllPressed => {
+                cosmetics_state
+                    .cosmetics
+                    .iter_mut()
+                    .filter(|c| {
+                        get_filtered_cosmetics(&search_query, type_selected, class_selected, c)
+                    })
+                    .for_each(|c| c.unlocked = true);
+            }
+            CosmeticsInteractionMessage::LockAllPressed => {
+                cosmetics_state
+                    .cosmetics
+                    .iter_mut()
+                    .filter(|c| {
+                        get_filtered_cosmetics(&search_query, type_selected, class_selected, c)
+                    })
+                    .for_each(|c| c.unlocked = false);
+            }
+            CosmeticsInteractionMessage::Unlocked(i, unlocked) => {
+                if let Some(cosmetic) = cosmetics_state.cosmetics.get_mut(i) {
+                    cosmetic.unlocked = unlocked;
+                }
+            }
+        }
+    }
+}
+
+fn cosmetics_interaction(message: CosmeticsInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::Cosmetics(
+        message,
+    ))
+}
+
+pub fn get_filtered_cosmetics(
+    search_query: &str,
+    type_selected: CosmeticTypeFilter,
+    class_selected: CosmeticClassFilter,
+    cosmetic: &ProfileCosmetic,
+) -> bool {
+    let search_query = search_query.trim();
+
+    let type_matches = match type_selected {
+        CosmeticTypeFilter::All => true,
+        CosmeticTypeFilter::Type(skin_type) => cosmetic.skin_type == skin_type,
+    };
+
+    let class_matches = match class_selected {
+        CosmeticClassFilter::All => true,
+        CosmeticClassFilter::Shared => cosmetic.class.is_none(),
+        CosmeticClassFilter::Class(class) => cosmetic.class == Some(class),
+    };
+
+    type_matches
+        && class_matches
+        && (search_query.is_empty()
+            || cosmetic.name.to_lowercase().contains(search_query)
+            || cosmetic.dlc.to_lowercase().contains(search_query)
+            || cosmetic.ident.to_lowercase().contains(search_query))
+}
+
+fn cosmetic_row(cosmetic_index: usize, cosmetic: &ProfileCosmetic) -> Container<Bl3Message> {
+    let unlocked_checkbox = Checkbox::new(cosmetic.unlocked, cosmetic.name, move |c| {
+        cosmetics_interaction(CosmeticsInteractionMessage::Unlocked(cosmetic_index, c))
+    })
+    .size(20)
+    .font(JETBRAINS_MONO)
+    .text_color(Color::from_rgb8(220, 220, 220))
+    .text_size(17)
+    .width(Length::Fill)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let class = cosmetic
+        .class
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| "Shared".to_owned());
+
+    let contents = Column::new()
+        .push(unlocked_checkbox)
+        .push(
+            Text::new(format!(
+                "{} - {} - {}",
+                cosmetic.skin_type, class, cosmetic.dlc
+            ))
+            .font(JETBRAINS_MONO)
+            .size(14)
+            .color(Color::from_rgb8(160, 160, 160)),
+        )
+        .spacing(10);
+
+    Container::new(contents)
+        .width(Length::Fill)
+        .padding(10)
+        .style(Bl3UiStyle)
+}
+
+pub fn view(cosmetics_state: &mut CosmeticsState) -> Container<Bl3Message> {
+    let type_selector = Container::new(
+        LabelledElement::create(
+            "Type",
+            Length::Units(60),
+            PickList::new(
+                &mut cosmetics_state.type_selector,
+                &cosmetics_state.types[..],
+                Some(cosmetics_state.type_selected),
+                |t| cosmetics_interaction(CosmeticsInteractionMessage::TypeSelected(t)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(2))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let class_selector = Container::new(
+        LabelledElement::create(
+            "Class",
+            Length::Units(70),
+            PickList::new(
+                &mut cosmetics_state.class_selector,
+                &cosmetics_state.classes[..],
+                Some(cosmetics_state.class_selected),
+                |c| cosmetics_interaction(CosmeticsInteractionMessage::ClassSelected(c)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(2))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let search_input = TextInputLimited::new(
+        &mut cosmetics_state.search_input_state,
+        "Search cosmetics...",
+        &cosmetics_state.search_input,
+        500,
+        |s| cosmetics_interaction(CosmeticsInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .width(Length::FillPortion(3))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let filter_row = Row::new()
+        .push(type_selector)
+        .push(class_selector)
+        .push(search_input)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let unlock_all_button = Button::new(
+        &mut cosmetics_state.unlock_all_button_state,
+        Text::new("Unlock All").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(cosmetics_interaction(
+        CosmeticsInteractionMessage::UnlockAllPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let lock_all_button = Button::new(
+        &mut cosmetics_state.lock_all_button_state,
+        Text::new("Lock All").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(cosmetics_interaction(
+        CosmeticsInteractionMessage::LockAllPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let search_query = &cosmetics_state.search_input;
+    let type_selected = cosmetics_state.type_selected;
+    let class_selected = cosmetics_state.class_selected;
+
+    let filtered_cosmetics = cosmetics_state
+        .cosmetics
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| get_filtered_cosmetics(search_query, type_selected, class_selected, c))
+        .collect::<Vec<_>>();
+
+    let unlocked_count = filtered_cosmetics
+        .iter()
+        .filter(|(_, c)| c.unlocked)
+        .count();
+
+    let button_row = Row::new()
+        .push(
+            Text::new(format!(
+                "{} / {} unlocked",
+                unlocked_count,
+                filtered_cosmetics.len()
+            ))
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .color(Color::from_rgb8(220, 220, 220))
+            .width(Length::Fill),
+        )
+        .push(unlock_all_button)
+        .push(lock_all_button)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let cosmetic_list = if !filtered_cosmetics.is_empty() {
+        let cosmetic_list = filtered_cosmetics
+            .into_iter()
+            .fold(Column::new().spacing(10), |column, (i, c)| {
+                column.push(cosmetic_row(i, c))
+            });
+
+        Container::new(
+            Scrollable::new(&mut cosmetics_state.cosmetic_list_scrollable_state)
+                .push(cosmetic_list)
+                .height(Length::Fill),
+        )
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    let all_contents = Column::new()
+        .push(filter_row)
+        .push(button_row)
+        .push(cosmetic_list)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
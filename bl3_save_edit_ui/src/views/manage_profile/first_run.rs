@@ -0,0 +1,276 @@
+use iced::alignment::Horizontal;
+use iced::{
+    button, scrollable, Alignment, Button, Checkbox, Color, Column, Container, Element, Length,
+    Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_profile::first_run::{ProfileEvent, ProfileFirstRunData};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Default)]
+pub struct FirstRunState {
+    pub first_run_data: ProfileFirstRunData,
+    pub tutorials: Vec<FirstRunTutorial>,
+    pub reset_onboarding_button_state: button::State,
+    pub mark_all_tutorials_seen_button_state: button::State,
+    pub reset_news_button_state: button::State,
+    pub tutorial_list_scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct FirstRunTutorial {
+    pub ident: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum FirstRunInteractionMessage {
+    HasSeenFirstBoot(bool),
+    TutorialsDisabled(bool),
+    TutorialSeen(usize, bool),
+    MarkAllTutorialsSeenPressed,
+    ResetOnboardingPressed,
+    ResetNewsPressed,
+    EventEnabled(ProfileEvent, bool),
+    DisableEventContent(bool),
+}
+
+impl FirstRunInteractionMessage {
+    pub fn update_state(self, first_run_state: &mut FirstRunState) {
+        let first_run_data = &mut first_run_state.first_run_data;
+
+        match self {
+            FirstRunInteractionMessage::HasSeenFirstBoot(seen) => {
+                first_run_data.has_seen_first_boot = seen;
+            }
+            FirstRunInteractionMessage::TutorialsDisabled(disabled) => {
+                first_run_data.tutorials_disabled = disabled;
+            }
+            FirstRunInteractionMessage::TutorialSeen(i, seen) => {
+                if let Some(tutorial) = first_run_state.tutorials.get(i) {
+                    first_run_data.set_tutorial_seen(&tutorial.ident, seen);
+                }
+            }
+            FirstRunInteractionMessage::MarkAllTutorialsSeenPressed => {
+                first_run_data.mark_all_tutorials_seen();
+            }
+            FirstRunInteractionMessage::ResetOnboardingPressed => {
+                first_run_data.reset_onboarding();
+            }
+            FirstRunInteractionMessage::ResetNewsPressed => {
+                first_run_data.seen_news_items.clear();
+                first_run_data.news_hashes.clear();
+            }
+            FirstRunInteractionMessage::EventEnabled(event, enabled) => {
+                first_run_data.set_event_enabled(event, enabled);
+            }
+            FirstRunInteractionMessage::DisableEventContent(disabled) => {
+                first_run_data.disable_event_content = disabled;
+            }
+        }
+    }
+}
+
+fn first_run_interaction(message: FirstRunInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::FirstRun(message))
+}
+
+fn section_header(title: &str) -> Container<Bl3Message> {
+    Container::new(
+        Text::new(title)
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17)
+            .color(Color::from_rgb8(242, 203, 5)),
+    )
+    .padding(10)
+    .align_x(Horizontal::Center)
+    .width(Length::Fill)
+    .style(Bl3UiStyle)
+}
+
+fn flag_checkbox<'a, F>(checked: bool, label: &str, f: F) -> Element<'a, Bl3Message>
+where
+    F: 'static + Fn(bool) -> FirstRunInteractionMessage,
+{
+    Checkbox::new(checked, label, move |c| first_run_interaction(f(c)))
+        .size(20)
+        .font(JETBRAINS_MONO)
+        .text_color(Color::from_rgb8(220, 220, 220))
+        .text_size(17)
+        .style(Bl3UiStyle)
+        .into_element()
+}
+
+pub fn view(first_run_state: &mut FirstRunState) -> Container<Bl3Message> {
+    let first_run_data = &first_run_state.first_run_data;
+
+    let onboarding = Container::new(
+        Row::new()
+            .push(
+                Row::new()
+                    .push(flag_checkbox(
+                        first_run_data.has_seen_first_boot,
+                        "First Boot Seen",
+                        FirstRunInteractionMessage::HasSeenFirstBoot,
+                    ))
+                    .push(flag_checkbox(
+                        first_run_data.tutorials_disabled,
+                        "Tutorials Disabled",
+                        FirstRunInteractionMessage::TutorialsDisabled,
+                    ))
+                    .spacing(30)
+                    .width(Length::Fill),
+            )
+            .push(
+                Button::new(
+                    &mut first_run_state.reset_onboarding_button_state,
+                    Text::new("Reset Onboarding")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(first_run_interaction(
+                    FirstRunInteractionMessage::ResetOnboardingPressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let news_text = Text::new(format!(
+        "{} news items and {} news hashes seen",
+        first_run_data.seen_news_items.len(),
+        first_run_data.news_hashes.len()
+    ))
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .color(Color::from_rgb8(220, 220, 220))
+    .width(Length::Fill);
+
+    let news = Container::new(
+        Row::new()
+            .push(news_text)
+            .push(
+                Button::new(
+                    &mut first_run_state.reset_news_button_state,
+                    Text::new("Reset News").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(first_run_interaction(
+                    FirstRunInteractionMessage::ResetNewsPressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let events = ProfileEvent::ALL.into_iter().fold(
+        Row::new().spacing(30).align_items(Alignment::Center),
+        |row, event| {
+            row.push(flag_checkbox(
+                first_run_data.event_enabled(event),
+                &event.to_string(),
+                move |c| FirstRunInteractionMessage::EventEnabled(event, c),
+            ))
+        },
+    );
+
+    let events = Container::new(
+        Column::new()
+            .push(events)
+            .push(flag_checkbox(
+                first_run_data.disable_event_content,
+                "Disable Event Content",
+                FirstRunInteractionMessage::DisableEventContent,
+            ))
+            .spacing(15),
+    )
+    .width(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let seen_count = first_run_state
+        .tutorials
+        .iter()
+        .filter(|t| first_run_data.is_tutorial_seen(&t.ident))
+        .count();
+
+    let tutorials_header = Row::new()
+        .push(
+            Text::new(format!(
+                "{} / {} tutorials seen",
+                seen_count,
+                first_run_state.tutorials.len()
+            ))
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .color(Color::from_rgb8(220, 220, 220))
+            .width(Length::Fill),
+        )
+        .push(
+            Button::new(
+                &mut first_run_state.mark_all_tutorials_seen_button_state,
+                Text::new("Mark All Seen")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(first_run_interaction(
+                FirstRunInteractionMessage::MarkAllTutorialsSeenPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .align_items(Alignment::Center);
+
+    let tutorial_list = first_run_state.tutorials.iter().enumerate().fold(
+        Column::new().spacing(15),
+        |column, (i, tutorial)| {
+            column.push(flag_checkbox(
+                first_run_data.is_tutorial_seen(&tutorial.ident),
+                &tutorial.name,
+                move |c| FirstRunInteractionMessage::TutorialSeen(i, c),
+            ))
+        },
+    );
+
+    let tutorials = Container::new(
+        Column::new()
+            .push(tutorials_header)
+            .push(
+                Scrollable::new(&mut first_run_state.tutorial_list_scrollable_state)
+                    .push(tutorial_list)
+                    .height(Length::Fill),
+            )
+            .spacing(15),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let all_contents = Column::new()
+        .push(section_header("Onboarding"))
+        .push(onboarding)
+        .push(news)
+        .push(section_header("Events"))
+        .push(events)
+        .push(section_header("Tutorials"))
+        .push(tutorials)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
@@ -0,0 +1,313 @@
+use std::path::PathBuf;
+
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text, TextInput,
+};
+
+use bl3_save_edit_core::bl3_profile::settings::{
+    ProfileSetting, ProfileSettingKind, ProfileSettingValue, ProfileSettingsPreset,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+
+pub const GAME_SETTINGS_PRESETS_DIR: &str = "game_settings";
+
+#[derive(Debug, Default)]
+pub struct GameSettingsState {
+    pub settings: Vec<GameSettingEditor>,
+    pub preset_name_input: String,
+    preset_name_input_state: text_input::State,
+    pub presets: Vec<String>,
+    pub selected_preset: Option<String>,
+    presets_selector: pick_list::State<String>,
+    export_button_state: button::State,
+    import_button_state: button::State,
+    open_presets_dir_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl GameSettingsState {
+    pub fn preset(&self) -> ProfileSettingsPreset {
+        ProfileSettingsPreset {
+            settings: self
+                .settings
+                .iter()
+                .filter(|e| e.error.is_none())
+                .filter_map(|e| e.setting.validate(e.value).ok().map(|v| (e.setting, v)))
+                .collect(),
+        }
+    }
+
+    pub fn apply_preset(&mut self, preset: &ProfileSettingsPreset) {
+        for editor in self.settings.iter_mut() {
+            if let Some(value) = preset.settings.get(&editor.setting) {
+                editor.set_value(*value);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GameSettingEditor {
+    pub setting: ProfileSetting,
+    pub value: ProfileSettingValue,
+    pub input: String,
+    pub error: Option<String>,
+    input_state: text_input::State,
+}
+
+impl GameSettingEditor {
+    pub fn new(setting: ProfileSetting, value: ProfileSettingValue) -> Self {
+        Self {
+            setting,
+            value,
+            input: value.to_string(),
+            error: None,
+            input_state: text_input::State::default(),
+        }
+    }
+
+    pub fn set_value(&mut self, value: ProfileSettingValue) {
+        self.value = value;
+        self.input = value.to_string();
+        self.error = None;
+    }
+
+    fn set_input(&mut self, input: String) {
+        match self.setting.parse(&input) {
+            Ok(value) => {
+                self.value = value;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+
+        self.input = input;
+    }
+
+    fn view(&mut self, index: usize) -> Row<Bl3Message> {
+        let name_color = if self.error.is_some() {
+            Color::from_rgb8(220, 80, 80)
+        } else {
+            Color::from_rgb8(220, 220, 220)
+        };
+
+        let mut name_column = Column::new().push(
+            Text::new(self.setting.to_string())
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(name_color),
+        );
+
+        if let Some(error) = &self.error {
+            name_column = name_column.push(
+                Text::new(error)
+                    .font(JETBRAINS_MONO)
+                    .size(13)
+                    .color(Color::from_rgb8(220, 80, 80)),
+            );
+        }
+
+        let editor = match (self.setting.kind(), self.value) {
+            (ProfileSettingKind::Bool, ProfileSettingValue::Bool(checked)) => {
+                Checkbox::new(checked, "", move |c| {
+                    interaction(GameSettingsInteractionMessage::Toggled(index, c))
+                })
+                .size(20)
+                .style(Bl3UiStyle)
+                .into_element()
+            }
+            (kind, _) => TextInput::new(
+                &mut self.input_state,
+                &kind.to_string(),
+                &self.input,
+                move |s| interaction(GameSettingsInteractionMessage::ValueChanged(index, s)),
+            )
+            .font(JETBRAINS_MONO)
+            .padding(5)
+            .size(17)
+            .width(Length::Units(150))
+            .style(Bl3UiStyle)
+            .into_element(),
+        };
+
+        let mut row = Row::new().push(name_column.spacing(5).width(Length::Fill));
+
+        if let ProfileSettingKind::Float { minimum, maximum } = self.setting.kind() {
+            row = row.push(range_text(minimum, maximum));
+        } else if let ProfileSettingKind::Integer { minimum, maximum } = self.setting.kind() {
+            row = row.push(range_text(minimum, maximum));
+        }
+
+        row.push(editor)
+            .padding(10)
+            .spacing(20)
+            .align_items(Alignment::Center)
+    }
+}
+
+fn range_text<T: std::fmt::Display>(minimum: T, maximum: T) -> Text {
+    Text::new(format!("{} - {}", minimum, maximum))
+        .font(JETBRAINS_MONO)
+        .size(15)
+        .color(Color::from_rgb8(160, 160, 160))
+}
+
+#[derive(Debug, Clone)]
+pub enum GameSettingsInteractionMessage {
+    Toggled(usize, bool),
+    ValueChanged(usize, String),
+    PresetNameChanged(String),
+    PresetSelected(String),
+    PresetsLoaded(MessageResult<Vec<String>>),
+    ExportPressed,
+    ExportCompleted(MessageResult<PathBuf>),
+    ImportPressed,
+    ImportCompleted(MessageResult<String>),
+    OpenPresetsDirPressed,
+    OpenPresetsDirCompleted(MessageResult<()>),
+}
+
+impl GameSettingsInteractionMessage {
+    pub fn update_state(self, game_settings_state: &mut GameSettingsState) {
+        match self {
+            GameSettingsInteractionMessage::Toggled(index, checked) => {
+                if let Some(editor) = game_settings_state.settings.get_mut(index) {
+                    editor.set_value(ProfileSettingValue::Bool(checked));
+                }
+            }
+            GameSettingsInteractionMessage::ValueChanged(index, input) => {
+                if let Some(editor) = game_settings_state.settings.get_mut(index) {
+                    editor.set_input(input);
+                }
+            }
+            GameSettingsInteractionMessage::PresetNameChanged(name) => {
+                game_settings_state.preset_name_input = name;
+            }
+            GameSettingsInteractionMessage::PresetSelected(name) => {
+                game_settings_state.preset_name_input = name.clone();
+                game_settings_state.selected_preset = Some(name);
+            }
+            _ => (),
+        }
+    }
+}
+
+fn interaction(message: GameSettingsInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::GameSettings(
+        message,
+    ))
+}
+
+pub fn view(game_settings_state: &mut GameSettingsState) -> Container<Bl3Message> {
+    let preset_name = TextInput::new(
+        &mut game_settings_state.preset_name_input_state,
+        "Preset name...",
+        &game_settings_state.preset_name_input,
+        |s| interaction(GameSettingsInteractionMessage::PresetNameChanged(s)),
+    )
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .width(Length::FillPortion(2))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let presets = PickList::new(
+        &mut game_settings_state.presets_selector,
+        &game_settings_state.presets[..],
+        game_settings_state.selected_preset.clone(),
+        |p| interaction(GameSettingsInteractionMessage::PresetSelected(p)),
+    )
+    .font(JETBRAINS_MONO)
+    .text_size(17)
+    .width(Length::FillPortion(2))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let preset_row = Row::new()
+        .push(preset_name)
+        .push(
+            Button::new(
+                &mut game_settings_state.export_button_state,
+                Text::new("Export").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction(GameSettingsInteractionMessage::ExportPressed))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(presets)
+        .push(
+            Button::new(
+                &mut game_settings_state.import_button_state,
+                Text::new("Import").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction(GameSettingsInteractionMessage::ImportPressed))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(
+            Button::new(
+                &mut game_settings_state.open_presets_dir_button_state,
+                Text::new("Open Folder").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction(
+                GameSettingsInteractionMessage::OpenPresetsDirPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let mut last_group = None;
+
+    let settings_list = game_settings_state.settings.iter_mut().enumerate().fold(
+        Column::new(),
+        |mut column, (i, editor)| {
+            let group = editor.setting.group();
+
+            if last_group != Some(group) {
+                last_group = Some(group);
+
+                column = column.push(
+                    Container::new(
+                        Text::new(group.to_string())
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .padding(10),
+                );
+            }
+
+            column.push(editor.view(i))
+        },
+    );
+
+    let settings_list = Container::new(
+        Scrollable::new(&mut game_settings_state.scrollable_state)
+            .push(settings_list)
+            .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(1)
+    .style(Bl3UiStyle);
+
+    let all_contents = Column::new()
+        .push(preset_row)
+        .push(settings_list)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
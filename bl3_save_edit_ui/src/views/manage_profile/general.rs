@@ -1,13 +1,13 @@
 use iced::{
-    pick_list, text_input, tooltip, Alignment, Column, Container, Length, PickList, TextInput,
-    Tooltip,
+    button, pick_list, text_input, tooltip, Alignment, Button, Color, Column, Container, Length,
+    PickList, Row, Text, TextInput, Tooltip,
 };
 
 use bl3_save_edit_core::parser::HeaderType;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
-use crate::resources::fonts::JETBRAINS_MONO;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::manage_profile::ManageProfileInteractionMessage;
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
@@ -18,11 +18,14 @@ pub struct GeneralState {
     pub filename_input_state: text_input::State,
     pub profile_type_selector: pick_list::State<HeaderType>,
     pub profile_type_selected: HeaderType,
+    pub anonymize: bool,
+    pub anonymize_button_state: button::State,
 }
 
 #[derive(Debug, Clone)]
 pub enum ProfileGeneralInteractionMessage {
     ProfileTypeSelected(HeaderType),
+    AnonymizePressed,
 }
 
 pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
@@ -89,7 +92,46 @@ pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
-    let all_contents = Column::new().push(file).push(profile_type).spacing(20);
+    let anonymize_text = if general_state.anonymize {
+        "Friend and player ids will be anonymized when saving"
+    } else {
+        "Strip friend encounters, recently met players and mail sender ids before sharing"
+    };
+
+    let anonymize = Container::new(
+        Row::new()
+            .push(
+                Text::new(anonymize_text)
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::Fill),
+            )
+            .push(
+                Button::new(
+                    &mut general_state.anonymize_button_state,
+                    Text::new("Anonymize").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::General(
+                        ProfileGeneralInteractionMessage::AnonymizePressed,
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let all_contents = Column::new()
+        .push(file)
+        .push(profile_type)
+        .push(anonymize)
+        .spacing(20);
 
     Container::new(all_contents).padding(30)
 }
@@ -0,0 +1,196 @@
+use iced::alignment::Horizontal;
+use iced::{
+    button, scrollable, Alignment, Button, Checkbox, Color, Column, Container, Length, Row,
+    Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_profile::guardian_injection::GuardianInjectionPlan;
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Clone)]
+pub enum GuardianInjectionInteractionMessage {
+    Selected(usize, bool),
+    ConfirmPressed,
+    CancelPressed,
+}
+
+#[derive(Debug)]
+pub struct GuardianInjectionEntry {
+    pub plan: GuardianInjectionPlan,
+    pub selected: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct GuardianInjectionState {
+    pub pending_profile: Option<Bl3Profile>,
+    pub entries: Vec<GuardianInjectionEntry>,
+    confirm_button_state: button::State,
+    cancel_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl GuardianInjectionState {
+    pub fn new(pending_profile: Bl3Profile, plans: Vec<GuardianInjectionPlan>) -> Self {
+        Self {
+            pending_profile: Some(pending_profile),
+            entries: plans
+                .into_iter()
+                .map(|plan| GuardianInjectionEntry {
+                    plan,
+                    selected: true,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.pending_profile.is_some()
+    }
+
+    pub fn selected_plans(&self) -> Vec<GuardianInjectionPlan> {
+        self.entries
+            .iter()
+            .filter(|e| e.selected)
+            .map(|e| e.plan.clone())
+            .collect()
+    }
+}
+
+fn plan_summary(plan: &GuardianInjectionPlan) -> String {
+    let mut summary = format!(
+        "Rank: {} -> {}, Tokens: {} -> {}",
+        plan.current_rank, plan.new_rank, plan.current_tokens, plan.new_tokens
+    );
+
+    if !plan.reward_changes.is_empty() {
+        let reward_changes = plan
+            .reward_changes
+            .iter()
+            .map(|r| format!("{}: {} -> {}", r.reward, r.current, r.new))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        summary.push_str(&format!("\nRewards: {}", reward_changes));
+    }
+
+    summary
+}
+
+fn interaction(message: GuardianInjectionInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(
+        ManageProfileInteractionMessage::GuardianInjection(message),
+    )
+}
+
+pub fn view(guardian_injection_state: &mut GuardianInjectionState) -> Container<Bl3Message> {
+    let entries = guardian_injection_state.entries.iter().enumerate().fold(
+        Column::new().spacing(20),
+        |column, (i, e)| {
+            let plan = &e.plan;
+
+            let label = format!(
+                "{} - {} ({})",
+                plan.character_name, plan.player_class, plan.file_name
+            );
+
+            column.push(
+                Column::new()
+                    .push(
+                        Checkbox::new(e.selected, label, move |c| {
+                            interaction(GuardianInjectionInteractionMessage::Selected(i, c))
+                        })
+                        .size(20)
+                        .font(JETBRAINS_MONO_BOLD)
+                        .text_color(Color::from_rgb8(220, 220, 220))
+                        .text_size(17)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .push(
+                        Text::new(plan_summary(plan))
+                            .font(JETBRAINS_MONO)
+                            .size(15)
+                            .color(Color::from_rgb8(180, 180, 180)),
+                    )
+                    .spacing(10),
+            )
+        },
+    );
+
+    let buttons = Row::new()
+        .push(
+            Button::new(
+                &mut guardian_injection_state.confirm_button_state,
+                Text::new("Save Profile and Selected Saves")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(interaction(
+                GuardianInjectionInteractionMessage::ConfirmPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(
+            Button::new(
+                &mut guardian_injection_state.cancel_button_state,
+                Text::new("Cancel").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction(
+                GuardianInjectionInteractionMessage::CancelPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let contents = Column::new()
+        .push(
+            Container::new(
+                Text::new("Guardian Rank Injection")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .padding(10)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        )
+        .push(
+            Container::new(
+                Column::new()
+                    .push(
+                        Text::new(
+                            "The profile's Guardian Rank has changed. Choose which saves should receive it.",
+                        )
+                        .font(JETBRAINS_MONO)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                    )
+                    .push(
+                        Scrollable::new(&mut guardian_injection_state.scrollable_state)
+                            .push(entries)
+                            .height(Length::Fill),
+                    )
+                    .push(buttons)
+                    .spacing(20),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle),
+        );
+
+    Container::new(contents).padding(30)
+}
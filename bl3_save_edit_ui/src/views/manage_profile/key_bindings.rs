@@ -0,0 +1,384 @@
+use std::path::PathBuf;
+
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text, TextInput,
+};
+
+use bl3_save_edit_core::bl3_profile::key_bindings::{AxisKey, KeyBindings};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+
+pub const KEY_BINDINGS_PRESETS_DIR: &str = "key_bindings";
+
+#[derive(Debug, Default)]
+pub struct KeyBindingsState {
+    pub key_bindings: KeyBindings,
+    pub editors: Vec<KeyBindingEditor>,
+    pub preset_name_input: String,
+    preset_name_input_state: text_input::State,
+    pub presets: Vec<String>,
+    pub selected_preset: Option<String>,
+    presets_selector: pick_list::State<String>,
+    export_button_state: button::State,
+    import_button_state: button::State,
+    open_presets_dir_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl KeyBindingsState {
+    pub fn set_key_bindings(&mut self, key_bindings: KeyBindings) {
+        self.editors = key_bindings
+            .categories
+            .iter()
+            .enumerate()
+            .flat_map(|(category_index, category)| {
+                let buttons = category.buttons.iter().enumerate().map(move |(i, b)| {
+                    KeyBindingEditor::new(
+                        category_index,
+                        KeyBindingKind::Button,
+                        i,
+                        &b.action,
+                        b.keys.join(", "),
+                    )
+                });
+
+                let axes = category.axes.iter().enumerate().map(move |(i, a)| {
+                    KeyBindingEditor::new(
+                        category_index,
+                        KeyBindingKind::Axis,
+                        i,
+                        &a.action,
+                        a.keys
+                            .iter()
+                            .map(|k| k.key.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )
+                });
+
+                buttons.chain(axes)
+            })
+            .collect();
+
+        self.key_bindings = key_bindings;
+    }
+
+    fn set_keys(&mut self, editor_index: usize, keys_input: String) {
+        let editor = match self.editors.get_mut(editor_index) {
+            Some(editor) => editor,
+            None => return,
+        };
+
+        let keys = keys_input
+            .split(',')
+            .map(|k| k.trim().to_owned())
+            .filter(|k| !k.is_empty())
+            .collect::<Vec<_>>();
+
+        editor.keys_input = keys_input;
+
+        let category = match self.key_bindings.categories.get_mut(editor.category) {
+            Some(category) => category,
+            None => return,
+        };
+
+        match editor.kind {
+            KeyBindingKind::Button => {
+                if let Some(button) = category.buttons.get_mut(editor.index) {
+                    button.keys = keys;
+                }
+            }
+            KeyBindingKind::Axis => {
+                if let Some(axis) = category.axes.get_mut(editor.index) {
+                    // Keep the scale of the key that was in the same position before
+                    axis.keys = keys
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, key)| AxisKey {
+                            key,
+                            scale: axis.keys.get(i).map(|e| e.scale).unwrap_or([1.0, 0.0, 0.0]),
+                        })
+                        .collect();
+                }
+            }
+        }
+    }
+
+    fn remove(&mut self, editor_index: usize) {
+        let editor = match self.editors.get(editor_index) {
+            Some(editor) => editor,
+            None => return,
+        };
+
+        if let Some(category) = self.key_bindings.categories.get_mut(editor.category) {
+            match editor.kind {
+                KeyBindingKind::Button if editor.index < category.buttons.len() => {
+                    category.buttons.remove(editor.index);
+                }
+                KeyBindingKind::Axis if editor.index < category.axes.len() => {
+                    category.axes.remove(editor.index);
+                }
+                _ => (),
+            }
+        }
+
+        self.set_key_bindings(self.key_bindings.clone());
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum KeyBindingKind {
+    Button,
+    Axis,
+}
+
+#[derive(Debug)]
+pub struct KeyBindingEditor {
+    pub category: usize,
+    pub kind: KeyBindingKind,
+    pub index: usize,
+    pub action: String,
+    pub keys_input: String,
+    keys_input_state: text_input::State,
+    remove_button_state: button::State,
+}
+
+impl KeyBindingEditor {
+    fn new(
+        category: usize,
+        kind: KeyBindingKind,
+        index: usize,
+        action: &str,
+        keys_input: String,
+    ) -> Self {
+        Self {
+            category,
+            kind,
+            index,
+            action: action.to_owned(),
+            keys_input,
+            keys_input_state: text_input::State::default(),
+            remove_button_state: button::State::default(),
+        }
+    }
+
+    fn view(&mut self, editor_index: usize) -> Row<Bl3Message> {
+        let action = match self.kind {
+            KeyBindingKind::Button => self.action.clone(),
+            KeyBindingKind::Axis => format!("{} (Axis)", self.action),
+        };
+
+        Row::new()
+            .push(
+                Text::new(action)
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::FillPortion(2)),
+            )
+            .push(
+                TextInput::new(
+                    &mut self.keys_input_state,
+                    "Keys, separated by commas...",
+                    &self.keys_input,
+                    move |s| {
+                        interaction(KeyBindingsInteractionMessage::KeysChanged(editor_index, s))
+                    },
+                )
+                .font(JETBRAINS_MONO)
+                .padding(5)
+                .size(17)
+                .width(Length::FillPortion(3))
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Button::new(
+                    &mut self.remove_button_state,
+                    Text::new("Reset to Default")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(15),
+                )
+                .on_press(interaction(KeyBindingsInteractionMessage::RemovePressed(
+                    editor_index,
+                )))
+                .padding(5)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .padding(10)
+            .spacing(20)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum KeyBindingsInteractionMessage {
+    KeysChanged(usize, String),
+    RemovePressed(usize),
+    PresetNameChanged(String),
+    PresetSelected(String),
+    PresetsLoaded(MessageResult<Vec<String>>),
+    ExportPressed,
+    ExportCompleted(MessageResult<PathBuf>),
+    ImportPressed,
+    ImportCompleted(MessageResult<String>),
+    OpenPresetsDirPressed,
+    OpenPresetsDirCompleted(MessageResult<()>),
+}
+
+impl KeyBindingsInteractionMessage {
+    pub fn update_state(self, key_bindings_state: &mut KeyBindingsState) {
+        match self {
+            KeyBindingsInteractionMessage::KeysChanged(index, keys_input) => {
+                key_bindings_state.set_keys(index, keys_input);
+            }
+            KeyBindingsInteractionMessage::RemovePressed(index) => {
+                key_bindings_state.remove(index);
+            }
+            KeyBindingsInteractionMessage::PresetNameChanged(name) => {
+                key_bindings_state.preset_name_input = name;
+            }
+            KeyBindingsInteractionMessage::PresetSelected(name) => {
+                key_bindings_state.preset_name_input = name.clone();
+                key_bindings_state.selected_preset = Some(name);
+            }
+            _ => (),
+        }
+    }
+}
+
+fn interaction(message: KeyBindingsInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::KeyBindings(
+        message,
+    ))
+}
+
+pub fn view(key_bindings_state: &mut KeyBindingsState) -> Container<Bl3Message> {
+    let preset_name = TextInput::new(
+        &mut key_bindings_state.preset_name_input_state,
+        "Export name...",
+        &key_bindings_state.preset_name_input,
+        |s| interaction(KeyBindingsInteractionMessage::PresetNameChanged(s)),
+    )
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .width(Length::FillPortion(2))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let presets = PickList::new(
+        &mut key_bindings_state.presets_selector,
+        &key_bindings_state.presets[..],
+        key_bindings_state.selected_preset.clone(),
+        |p| interaction(KeyBindingsInteractionMessage::PresetSelected(p)),
+    )
+    .font(JETBRAINS_MONO)
+    .text_size(17)
+    .width(Length::FillPortion(2))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let preset_row = Row::new()
+        .push(preset_name)
+        .push(
+            Button::new(
+                &mut key_bindings_state.export_button_state,
+                Text::new("Export").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction(KeyBindingsInteractionMessage::ExportPressed))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(presets)
+        .push(
+            Button::new(
+                &mut key_bindings_state.import_button_state,
+                Text::new("Import").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction(KeyBindingsInteractionMessage::ImportPressed))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(
+            Button::new(
+                &mut key_bindings_state.open_presets_dir_button_state,
+                Text::new("Open Folder").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction(
+                KeyBindingsInteractionMessage::OpenPresetsDirPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let categories = &key_bindings_state.key_bindings.categories;
+
+    let mut last_category = None;
+
+    let bindings_list = key_bindings_state.editors.iter_mut().enumerate().fold(
+        Column::new(),
+        |mut column, (i, editor)| {
+            if last_category != Some(editor.category) {
+                last_category = Some(editor.category);
+
+                if let Some(category) = categories.get(editor.category) {
+                    column = column.push(
+                        Container::new(
+                            Text::new(format!("{} ({})", category.name, category.context))
+                                .font(JETBRAINS_MONO_BOLD)
+                                .size(17)
+                                .color(Color::from_rgb8(242, 203, 5)),
+                        )
+                        .padding(10),
+                    );
+                }
+            }
+
+            column.push(editor.view(i))
+        },
+    );
+
+    let bindings_list = if key_bindings_state.editors.is_empty() {
+        Column::new().push(
+            Container::new(
+                Text::new("This profile uses the default key bindings.")
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .padding(10),
+        )
+    } else {
+        bindings_list
+    };
+
+    let bindings_list = Container::new(
+        Scrollable::new(&mut key_bindings_state.scrollable_state)
+            .push(bindings_list)
+            .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(1)
+    .style(Bl3UiStyle);
+
+    let all_contents = Column::new()
+        .push(preset_row)
+        .push(bindings_list)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
@@ -2,14 +2,26 @@ use iced::{button, svg, Column, Container, Length, Row};
 use strum::Display;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::svgs::{BANK, GENERAL, KEYS, PROFILE, SETTINGS};
+use crate::resources::svgs::{
+    BANK, COSMETICS, FIRST_RUN, GAME_SETTINGS, GENERAL, KEYS, KEY_BINDINGS, PROFILE, SCIENCE,
+    SETTINGS, STATS, VAULT_CARD,
+};
 use crate::views;
+use crate::views::game_stats::GameStatsState;
 use crate::views::manage_profile::bank::BankState;
+use crate::views::manage_profile::borderlands_science::BorderlandsScienceState;
+use crate::views::manage_profile::cosmetics::CosmeticsState;
+use crate::views::manage_profile::first_run::FirstRunState;
+use crate::views::manage_profile::game_settings::GameSettingsState;
 use crate::views::manage_profile::general::GeneralState;
+use crate::views::manage_profile::guardian_injection::GuardianInjectionState;
+use crate::views::manage_profile::key_bindings::KeyBindingsState;
 use crate::views::manage_profile::keys::KeysState;
 use crate::views::manage_profile::profile::ProfileState;
+use crate::views::manage_profile::vault_card::VaultCardState;
 use crate::views::manage_profile::{
-    bank, general, keys, profile, ManageProfileInteractionMessage, ManageProfileState,
+    bank, borderlands_science, cosmetics, first_run, game_settings, general, guardian_injection,
+    key_bindings, keys, profile, vault_card, ManageProfileInteractionMessage, ManageProfileState,
 };
 use crate::views::settings::SettingsState;
 use crate::views::{tab_bar_button, ManageTabBarStyle};
@@ -19,16 +31,31 @@ pub struct ProfileViewState {
     tab_bar_state: ProfileTabBarState,
     pub general_state: GeneralState,
     pub profile_state: ProfileState,
+    pub borderlands_science_state: BorderlandsScienceState,
     pub keys_state: KeysState,
     pub bank_state: BankState,
+    pub stats_state: GameStatsState,
+    pub game_settings_state: GameSettingsState,
+    pub key_bindings_state: KeyBindingsState,
+    pub cosmetics_state: CosmeticsState,
+    pub vault_card_state: VaultCardState,
+    pub first_run_state: FirstRunState,
+    pub guardian_injection_state: GuardianInjectionState,
 }
 
 #[derive(Debug, Default)]
 pub struct ProfileTabBarState {
     general_button_state: button::State,
     profile_button_state: button::State,
+    science_button_state: button::State,
     keys_button_state: button::State,
     bank_button_state: button::State,
+    stats_button_state: button::State,
+    game_settings_button_state: button::State,
+    key_bindings_button_state: button::State,
+    cosmetics_button_state: button::State,
+    vault_card_button_state: button::State,
+    first_run_button_state: button::State,
     settings_button_state: button::State,
 }
 
@@ -36,8 +63,15 @@ pub struct ProfileTabBarState {
 pub enum ProfileTabBarInteractionMessage {
     General,
     Profile,
+    Science,
     Keys,
     Bank,
+    Stats,
+    GameSettings,
+    KeyBindings,
+    Cosmetics,
+    VaultCard,
+    FirstRun,
     Settings,
 }
 
@@ -46,8 +80,15 @@ pub enum ProfileTabBarInteractionMessage {
 pub enum ProfileTabBarView {
     General,
     Profile,
+    Science,
     Keys,
     Bank,
+    Stats,
+    GameSettings,
+    KeyBindings,
+    Cosmetics,
+    VaultCard,
+    FirstRun,
     Settings,
 }
 
@@ -84,6 +125,20 @@ pub fn view<'a>(
         100,
     );
 
+    let science_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .science_button_state,
+        ProfileTabBarView::Science,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Science,
+        )),
+        svg::Handle::from_memory(SCIENCE),
+        110,
+    );
+
     let keys_button = tab_bar_button(
         &mut manage_profile_state
             .profile_view_state
@@ -112,6 +167,90 @@ pub fn view<'a>(
         75,
     );
 
+    let stats_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .stats_button_state,
+        ProfileTabBarView::Stats,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Stats,
+        )),
+        svg::Handle::from_memory(STATS),
+        85,
+    );
+
+    let game_settings_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .game_settings_button_state,
+        ProfileTabBarView::GameSettings,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::GameSettings,
+        )),
+        svg::Handle::from_memory(GAME_SETTINGS),
+        150,
+    );
+
+    let key_bindings_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .key_bindings_button_state,
+        ProfileTabBarView::KeyBindings,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::KeyBindings,
+        )),
+        svg::Handle::from_memory(KEY_BINDINGS),
+        140,
+    );
+
+    let cosmetics_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .cosmetics_button_state,
+        ProfileTabBarView::Cosmetics,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Cosmetics,
+        )),
+        svg::Handle::from_memory(COSMETICS),
+        120,
+    );
+
+    let vault_card_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .vault_card_button_state,
+        ProfileTabBarView::VaultCard,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::VaultCard,
+        )),
+        svg::Handle::from_memory(VAULT_CARD),
+        130,
+    );
+
+    let first_run_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .first_run_button_state,
+        ProfileTabBarView::FirstRun,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::FirstRun,
+        )),
+        svg::Handle::from_memory(FIRST_RUN),
+        125,
+    );
+
     let settings_button = tab_bar_button(
         &mut manage_profile_state
             .profile_view_state
@@ -130,26 +269,72 @@ pub fn view<'a>(
         Row::new()
             .push(general_button)
             .push(profile_button)
+            .push(science_button)
             .push(keys_button)
             .push(bank_button)
+            .push(stats_button)
+            .push(game_settings_button)
+            .push(key_bindings_button)
+            .push(cosmetics_button)
+            .push(vault_card_button)
+            .push(first_run_button)
             .push(settings_button),
     )
     .width(Length::Fill)
     .style(ManageTabBarStyle);
 
     let tab_content = match tab_bar_view {
+        _ if manage_profile_state
+            .profile_view_state
+            .guardian_injection_state
+            .is_pending() =>
+        {
+            guardian_injection::view(
+                &mut manage_profile_state
+                    .profile_view_state
+                    .guardian_injection_state,
+            )
+        }
         ProfileTabBarView::General => {
             general::view(&mut manage_profile_state.profile_view_state.general_state)
         }
         ProfileTabBarView::Profile => {
             profile::view(&mut manage_profile_state.profile_view_state.profile_state)
         }
+        ProfileTabBarView::Science => borderlands_science::view(
+            &mut manage_profile_state
+                .profile_view_state
+                .borderlands_science_state,
+        ),
         ProfileTabBarView::Keys => {
             keys::view(&mut manage_profile_state.profile_view_state.keys_state)
         }
         ProfileTabBarView::Bank => {
             bank::view(&mut manage_profile_state.profile_view_state.bank_state)
         }
+        ProfileTabBarView::Stats => views::game_stats::view(
+            &mut manage_profile_state.profile_view_state.stats_state,
+            |s| {
+                InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::Stats(s),
+                )
+            },
+        ),
+        ProfileTabBarView::GameSettings => {
+            game_settings::view(&mut manage_profile_state.profile_view_state.game_settings_state)
+        }
+        ProfileTabBarView::KeyBindings => {
+            key_bindings::view(&mut manage_profile_state.profile_view_state.key_bindings_state)
+        }
+        ProfileTabBarView::Cosmetics => {
+            cosmetics::view(&mut manage_profile_state.profile_view_state.cosmetics_state)
+        }
+        ProfileTabBarView::VaultCard => {
+            vault_card::view(&mut manage_profile_state.profile_view_state.vault_card_state)
+        }
+        ProfileTabBarView::FirstRun => {
+            first_run::view(&mut manage_profile_state.profile_view_state.first_run_state)
+        }
         ProfileTabBarView::Settings => views::settings::view(settings_state),
     };
 
@@ -1,18 +1,33 @@
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 
+use crate::views::game_stats::GameStatsInteractionMessage;
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
+use crate::views::manage_profile::borderlands_science::BorderlandsScienceInteractionMessage;
+use crate::views::manage_profile::cosmetics::CosmeticsInteractionMessage;
+use crate::views::manage_profile::first_run::FirstRunInteractionMessage;
+use crate::views::manage_profile::game_settings::GameSettingsInteractionMessage;
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
+use crate::views::manage_profile::guardian_injection::GuardianInjectionInteractionMessage;
+use crate::views::manage_profile::key_bindings::KeyBindingsInteractionMessage;
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
 use crate::views::manage_profile::main::{
     ProfileTabBarInteractionMessage, ProfileTabBarView, ProfileViewState,
 };
 use crate::views::manage_profile::profile::ProfileInteractionMessage;
+use crate::views::manage_profile::vault_card::VaultCardInteractionMessage;
 
 pub mod bank;
+pub mod borderlands_science;
+pub mod cosmetics;
+pub mod first_run;
+pub mod game_settings;
 pub mod general;
+pub mod guardian_injection;
+pub mod key_bindings;
 pub mod keys;
 pub mod main;
 pub mod profile;
+pub mod vault_card;
 
 #[derive(Debug, Default)]
 pub struct ManageProfileState {
@@ -25,8 +40,16 @@ pub enum ManageProfileInteractionMessage {
     TabBar(ProfileTabBarInteractionMessage),
     General(ProfileGeneralInteractionMessage),
     Profile(ProfileInteractionMessage),
+    BorderlandsScience(BorderlandsScienceInteractionMessage),
     Keys(ProfileKeysInteractionMessage),
     Bank(ProfileBankInteractionMessage),
+    Stats(GameStatsInteractionMessage),
+    GameSettings(GameSettingsInteractionMessage),
+    KeyBindings(KeyBindingsInteractionMessage),
+    Cosmetics(CosmeticsInteractionMessage),
+    VaultCard(VaultCardInteractionMessage),
+    FirstRun(FirstRunInteractionMessage),
+    GuardianInjection(GuardianInjectionInteractionMessage),
     SaveProfilePressed,
 }
 
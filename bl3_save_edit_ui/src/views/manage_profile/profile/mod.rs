@@ -1,6 +1,4 @@
-use iced::{pick_list, text_input, Alignment, Column, Container, Length, PickList, Row};
-
-use bl3_save_edit_core::bl3_profile::science_levels::BorderlandsScienceLevel;
+use iced::{text_input, Alignment, Column, Container, Length, Row};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::Bl3UiStyle;
@@ -21,10 +19,6 @@ pub mod skin_unlocker;
 pub struct ProfileState {
     pub guardian_rank_tokens_input: i32,
     pub guardian_rank_tokens_input_state: text_input::State,
-    pub science_level_selector: pick_list::State<BorderlandsScienceLevel>,
-    pub science_level_selected: BorderlandsScienceLevel,
-    pub science_tokens_input: i32,
-    pub science_tokens_input_state: text_input::State,
     pub skin_unlocker: SkinUnlocker,
     pub sdu_unlocker: SduUnlocker,
     pub guardian_reward_unlocker: GuardianRewardUnlocker,
@@ -33,8 +27,6 @@ pub struct ProfileState {
 #[derive(Debug, Clone)]
 pub enum ProfileInteractionMessage {
     GuardianRankTokens(i32),
-    ScienceLevelSelected(BorderlandsScienceLevel),
-    ScienceTokens(i32),
     SkinMessage(SkinUnlockedMessage),
     SduMessage(SduMessage),
     MaxSduSlotsPressed,
@@ -113,72 +105,6 @@ pub fn view(profile_state: &mut ProfileState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
-    let borderlands_science_level = Container::new(
-        LabelledElement::create(
-            "Borderlands Science Level",
-            Length::Units(215),
-            PickList::new(
-                &mut profile_state.science_level_selector,
-                &BorderlandsScienceLevel::ALL[..],
-                Some(profile_state.science_level_selected),
-                |h| {
-                    InteractionMessage::ManageProfileInteraction(
-                        ManageProfileInteractionMessage::Profile(
-                            ProfileInteractionMessage::ScienceLevelSelected(h),
-                        ),
-                    )
-                },
-            )
-            .font(JETBRAINS_MONO)
-            .text_size(17)
-            .width(Length::Fill)
-            .padding(10)
-            .style(Bl3UiStyle)
-            .into_element(),
-        )
-        .spacing(15)
-        .align_items(Alignment::Center),
-    )
-    .width(Length::FillPortion(2))
-    .height(Length::Units(36))
-    .style(Bl3UiStyle);
-
-    let borderlands_science_tokens = Container::new(
-        LabelledElement::create(
-            "Borderlands Science Tokens",
-            Length::Units(225),
-            NumberInput::new(
-                &mut profile_state.science_tokens_input_state,
-                profile_state.science_tokens_input,
-                0,
-                None,
-                |v| {
-                    InteractionMessage::ManageProfileInteraction(
-                        ManageProfileInteractionMessage::Profile(
-                            ProfileInteractionMessage::ScienceTokens(v),
-                        ),
-                    )
-                },
-            )
-            .0
-            .font(JETBRAINS_MONO)
-            .padding(10)
-            .size(17)
-            .style(Bl3UiStyle)
-            .into_element(),
-        )
-        .spacing(15)
-        .align_items(Alignment::Center),
-    )
-    .width(Length::FillPortion(2))
-    .height(Length::Units(36))
-    .style(Bl3UiStyle);
-
-    let borderlands_science_row = Row::new()
-        .push(borderlands_science_level)
-        .push(borderlands_science_tokens)
-        .spacing(20);
-
     let guardian_reward_unlocker = profile_state
         .guardian_reward_unlocker
         .view()
@@ -187,7 +113,6 @@ pub fn view(profile_state: &mut ProfileState) -> Container<Bl3Message> {
     let main_column = Container::new(
         Column::new()
             .push(guardian_rank_tokens)
-            .push(borderlands_science_row)
             .push(guardian_reward_unlocker)
             .spacing(20),
     )
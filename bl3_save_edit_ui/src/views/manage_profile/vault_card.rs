@@ -0,0 +1,526 @@
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_profile::vault_card::{
+    vault_card_experience_for_level, vault_card_gear, VaultCard, VaultCardData, VaultCardGear,
+    VaultCardGearRewardData, VaultCardRewardData, VAULT_CARD_IDS, VAULT_CARD_MAX_LEVEL,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug)]
+pub struct VaultCardState {
+    pub vault_card_data: VaultCardData,
+    pub cards: Vec<VaultCardSelection>,
+    pub card_selector: pick_list::State<VaultCardSelection>,
+    pub card_selected: VaultCardSelection,
+    pub level_input_state: text_input::State,
+    pub reward_column_input: i32,
+    pub reward_column_input_state: text_input::State,
+    pub reward_row_input: i32,
+    pub reward_row_input_state: text_input::State,
+    pub add_reward_button_state: button::State,
+    pub reward_remove_button_states: Vec<button::State>,
+    pub gear_add_to_bank_button_states: Vec<button::State>,
+    pub scrollable_state: scrollable::State,
+}
+
+impl std::default::Default for VaultCardState {
+    fn default() -> Self {
+        Self {
+            vault_card_data: VaultCardData::default(),
+            cards: VAULT_CARD_IDS
+                .iter()
+                .map(|id| VaultCardSelection(*id))
+                .collect(),
+            card_selector: pick_list::State::default(),
+            card_selected: VaultCardSelection(VAULT_CARD_IDS[0]),
+            level_input_state: text_input::State::default(),
+            reward_column_input: 1,
+            reward_column_input_state: text_input::State::default(),
+            reward_row_input: 1,
+            reward_row_input_state: text_input::State::default(),
+            add_reward_button_state: button::State::default(),
+            reward_remove_button_states: Vec::new(),
+            gear_add_to_bank_button_states: Vec::new(),
+            scrollable_state: scrollable::State::default(),
+        }
+    }
+}
+
+impl VaultCardState {
+    pub fn set_vault_card_data(&mut self, vault_card_data: VaultCardData) {
+        self.vault_card_data = vault_card_data;
+        self.select_card(self.card_selected);
+    }
+
+    pub fn selected_card(&self) -> Option<&VaultCard> {
+        self.vault_card_data.card(self.card_selected.0)
+    }
+
+    fn selected_card_mut(&mut self) -> Option<&mut VaultCard> {
+        let id = self.card_selected.0;
+
+        self.vault_card_data.cards.iter_mut().find(|c| c.id == id)
+    }
+
+    fn select_card(&mut self, card: VaultCardSelection) {
+        self.card_selected = card;
+
+        let reward_count = self
+            .selected_card()
+            .map(|c| c.unlocked_rewards.len())
+            .unwrap_or(0);
+
+        self.reward_remove_button_states = (0..reward_count)
+            .map(|_| button::State::default())
+            .collect();
+
+        self.gear_add_to_bank_button_states = (0..vault_card_gear(card.0).len())
+            .map(|_| button::State::default())
+            .collect();
+
+        self.scrollable_state.snap_to(0.0);
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct VaultCardSelection(pub u32);
+
+impl std::fmt::Display for VaultCardSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Vault Card {}", self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum VaultCardInteractionMessage {
+    CardSelected(VaultCardSelection),
+    Level(i32),
+    ActiveToggled(bool),
+    RewardColumn(i32),
+    RewardRow(i32),
+    AddRewardPressed,
+    RewardRedeemed(usize, bool),
+    RemoveRewardPressed(usize),
+    GearUnlocked(i32, bool),
+    GearAddToBankPressed(i32),
+}
+
+impl VaultCardInteractionMessage {
+    pub fn update_state(self, vault_card_state: &mut VaultCardState) {
+        match self {
+            VaultCardInteractionMessage::CardSelected(card) => {
+                vault_card_state.select_card(card);
+            }
+            VaultCardInteractionMessage::Level(level) => {
+                if let Some(card) = vault_card_state.selected_card_mut() {
+                    card.experience = vault_card_experience_for_level(level, card.experience);
+                }
+            }
+            VaultCardInteractionMessage::ActiveToggled(active) => {
+                if active {
+                    vault_card_state.vault_card_data.last_active_vault_card_id =
+                        vault_card_state.card_selected.0;
+                }
+            }
+            VaultCardInteractionMessage::RewardColumn(column) => {
+                vault_card_state.reward_column_input = column;
+            }
+            VaultCardInteractionMessage::RewardRow(row) => {
+                vault_card_state.reward_row_input = row;
+            }
+            VaultCardInteractionMessage::AddRewardPressed => {
+                let reward = VaultCardRewardData {
+                    column: vault_card_state.reward_column_input - 1,
+                    row: vault_card_state.reward_row_input - 1,
+                };
+
+                let added = match vault_card_state.selected_card_mut() {
+                    Some(card) if !card.is_reward_unlocked(&reward) => {
+                        card.unlocked_rewards.push(reward);
+                        true
+                    }
+                    _ => false,
+                };
+
+                if added {
+                    vault_card_state
+                        .reward_remove_button_states
+                        .push(button::State::default());
+                }
+            }
+            VaultCardInteractionMessage::RewardRedeemed(i, redeemed) => {
+                if let Some(card) = vault_card_state.selected_card_mut() {
+                    if let Some(reward) = card.unlocked_rewards.get(i).copied() {
+                        if redeemed && !card.is_reward_redeemed(&reward) {
+                            card.redeemed_rewards.push(reward);
+                        } else if !redeemed {
+                            card.redeemed_rewards.retain(|r| *r != reward);
+                        }
+                    }
+                }
+            }
+            VaultCardInteractionMessage::RemoveRewardPressed(i) => {
+                let removed = match vault_card_state.selected_card_mut() {
+                    Some(card) if i < card.unlocked_rewards.len() => {
+                        let reward = card.unlocked_rewards.remove(i);
+                        card.redeemed_rewards.retain(|r| *r != reward);
+                        true
+                    }
+                    _ => false,
+                };
+
+                if removed {
+                    vault_card_state.reward_remove_button_states.pop();
+                }
+            }
+            VaultCardInteractionMessage::GearUnlocked(gear_index, unlocked) => {
+                if let Some(card) = vault_card_state.selected_card_mut() {
+                    if unlocked && card.gear_reward(gear_index).is_none() {
+                        card.gear_rewards.push(VaultCardGearRewardData {
+                            gear_index,
+                            repurchase_count: 0,
+                        });
+                    } else if !unlocked {
+                        card.gear_rewards.retain(|g| g.gear_index != gear_index);
+                    }
+                }
+            }
+            VaultCardInteractionMessage::GearAddToBankPressed(_) => (),
+        }
+    }
+}
+
+fn vault_card_interaction(message: VaultCardInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::VaultCard(
+        message,
+    ))
+}
+
+fn section_header(title: &str) -> Container<Bl3Message> {
+    Container::new(
+        Text::new(title)
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17)
+            .color(Color::from_rgb8(242, 203, 5)),
+    )
+    .padding(10)
+}
+
+fn reward_row<'a>(
+    reward_index: usize,
+    reward: &VaultCardRewardData,
+    redeemed: bool,
+    remove_button_state: &'a mut button::State,
+) -> Container<'a, Bl3Message> {
+    let contents = Row::new()
+        .push(
+            Text::new(reward.to_string())
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220))
+                .width(Length::Fill),
+        )
+        .push(
+            Checkbox::new(redeemed, "Redeemed", move |c| {
+                vault_card_interaction(VaultCardInteractionMessage::RewardRedeemed(reward_index, c))
+            })
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(
+            Button::new(
+                remove_button_state,
+                Text::new("Remove").font(JETBRAINS_MONO_BOLD).size(15),
+            )
+            .on_press(vault_card_interaction(
+                VaultCardInteractionMessage::RemoveRewardPressed(reward_index),
+            ))
+            .padding(5)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    Container::new(contents)
+        .width(Length::Fill)
+        .padding(10)
+        .style(Bl3UiStyle)
+}
+
+fn gear_row<'a>(
+    gear: &VaultCardGear,
+    unlocked: bool,
+    add_to_bank_button_state: &'a mut button::State,
+) -> Container<'a, Bl3Message> {
+    let gear_index = gear.gear_index;
+
+    let contents = Row::new()
+        .push(
+            Checkbox::new(unlocked, gear.name, move |c| {
+                vault_card_interaction(VaultCardInteractionMessage::GearUnlocked(gear_index, c))
+            })
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .width(Length::Fill)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(
+            Button::new(
+                add_to_bank_button_state,
+                Text::new("Add to Bank").font(JETBRAINS_MONO_BOLD).size(15),
+            )
+            .on_press(vault_card_interaction(
+                VaultCardInteractionMessage::GearAddToBankPressed(gear_index),
+            ))
+            .padding(5)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    Container::new(contents)
+        .width(Length::Fill)
+        .padding(10)
+        .style(Bl3UiStyle)
+}
+
+pub fn view(vault_card_state: &mut VaultCardState) -> Container<Bl3Message> {
+    let selected_id = vault_card_state.card_selected.0;
+
+    let card = vault_card_state
+        .vault_card_data
+        .card(selected_id)
+        .cloned()
+        .unwrap_or_else(|| VaultCard::new(selected_id));
+
+    let is_active = vault_card_state.vault_card_data.last_active_vault_card_id == selected_id;
+
+    let card_selector = Container::new(
+        LabelledElement::create(
+            "Card",
+            Length::Units(60),
+            PickList::new(
+                &mut vault_card_state.card_selector,
+                &vault_card_state.cards[..],
+                Some(vault_card_state.card_selected),
+                |c| vault_card_interaction(VaultCardInteractionMessage::CardSelected(c)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(2))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let level = Container::new(
+        LabelledElement::create(
+            "Level",
+            Length::Units(70),
+            NumberInput::new(
+                &mut vault_card_state.level_input_state,
+                card.level(),
+                1,
+                Some(VAULT_CARD_MAX_LEVEL),
+                |v| vault_card_interaction(VaultCardInteractionMessage::Level(v)),
+            )
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(1))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(1))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let active_checkbox = Checkbox::new(is_active, "Active Card", |c| {
+        vault_card_interaction(VaultCardInteractionMessage::ActiveToggled(c))
+    })
+    .size(20)
+    .font(JETBRAINS_MONO)
+    .text_color(Color::from_rgb8(220, 220, 220))
+    .text_size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let card_row = Row::new()
+        .push(card_selector)
+        .push(level)
+        .push(active_checkbox)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let card_info = Text::new(format!(
+        "{} experience - {} chests, {} opened - {} keys spent",
+        card.experience, card.chests, card.chests_opened, card.keys_spent
+    ))
+    .font(JETBRAINS_MONO)
+    .size(14)
+    .color(Color::from_rgb8(160, 160, 160));
+
+    let approximate_note = Text::new(
+        "Experience per level and the gear reward order are approximate, they haven't been checked against a profile with Vault Card progress.",
+    )
+    .font(JETBRAINS_MONO)
+    .size(14)
+    .color(Color::from_rgb8(160, 160, 160));
+
+    let reward_column = LabelledElement::create(
+        "Column",
+        Length::Units(80),
+        NumberInput::new(
+            &mut vault_card_state.reward_column_input_state,
+            vault_card_state.reward_column_input,
+            1,
+            None,
+            |v| vault_card_interaction(VaultCardInteractionMessage::RewardColumn(v)),
+        )
+        .0
+        .font(JETBRAINS_MONO)
+        .padding(10)
+        .size(17)
+        .style(Bl3UiStyle)
+        .into_element(),
+    )
+    .spacing(15)
+    .width(Length::FillPortion(1))
+    .align_items(Alignment::Center);
+
+    let reward_row_input = LabelledElement::create(
+        "Row",
+        Length::Units(50),
+        NumberInput::new(
+            &mut vault_card_state.reward_row_input_state,
+            vault_card_state.reward_row_input,
+            1,
+            None,
+            |v| vault_card_interaction(VaultCardInteractionMessage::RewardRow(v)),
+        )
+        .0
+        .font(JETBRAINS_MONO)
+        .padding(10)
+        .size(17)
+        .style(Bl3UiStyle)
+        .into_element(),
+    )
+    .spacing(15)
+    .width(Length::FillPortion(1))
+    .align_items(Alignment::Center);
+
+    let add_reward_row = Row::new()
+        .push(
+            Container::new(reward_column)
+                .width(Length::FillPortion(1))
+                .height(Length::Units(36))
+                .style(Bl3UiStyle),
+        )
+        .push(
+            Container::new(reward_row_input)
+                .width(Length::FillPortion(1))
+                .height(Length::Units(36))
+                .style(Bl3UiStyle),
+        )
+        .push(
+            Button::new(
+                &mut vault_card_state.add_reward_button_state,
+                Text::new("Unlock Reward")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(vault_card_interaction(
+                VaultCardInteractionMessage::AddRewardPressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let rewards = card
+        .unlocked_rewards
+        .iter()
+        .zip(vault_card_state.reward_remove_button_states.iter_mut())
+        .enumerate()
+        .fold(
+            Column::new()
+                .push(section_header("Unlocked Rewards"))
+                .spacing(10),
+            |column, (i, (reward, remove_button_state))| {
+                column.push(reward_row(
+                    i,
+                    reward,
+                    card.is_reward_redeemed(reward),
+                    remove_button_state,
+                ))
+            },
+        );
+
+    let gear = vault_card_gear(selected_id)
+        .iter()
+        .zip(vault_card_state.gear_add_to_bank_button_states.iter_mut())
+        .fold(
+            Column::new()
+                .push(section_header("Gear Rewards (Approximate Order)"))
+                .spacing(10),
+            |column, (gear, add_to_bank_button_state)| {
+                column.push(gear_row(
+                    gear,
+                    card.gear_reward(gear.gear_index).is_some(),
+                    add_to_bank_button_state,
+                ))
+            },
+        );
+
+    let card_contents = Container::new(
+        Scrollable::new(&mut vault_card_state.scrollable_state)
+            .push(rewards)
+            .push(gear)
+            .spacing(20)
+            .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill);
+
+    let all_contents = Column::new()
+        .push(card_row)
+        .push(card_info)
+        .push(approximate_note)
+        .push(add_reward_row)
+        .push(card_contents)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
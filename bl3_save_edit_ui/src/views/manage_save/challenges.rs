@@ -0,0 +1,404 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Element, Length, PickList, Row, Scrollable, Text,
+};
+
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_save::challenge_data::{
+    ChallengeCategory, ChallengeMenuCategory, ChallengeProgress,
+    CHALLENGE_CATEGORY_COMPLETION_COUNT,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct ChallengesState {
+    pub challenges: Vec<ChallengeEditor>,
+    pub categories: Vec<ChallengeCategory>,
+    pub category_selector: pick_list::State<ChallengeCategory>,
+    pub category_selected: ChallengeCategory,
+    pub search_input: String,
+    pub search_input_state: text_input::State,
+    pub complete_all_button_state: button::State,
+    pub category_completion_inputs: [u8; CHALLENGE_CATEGORY_COMPLETION_COUNT],
+    pub category_completion_input_states: [text_input::State; CHALLENGE_CATEGORY_COMPLETION_COUNT],
+    pub challenge_list_scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Default)]
+pub struct ChallengeEditor {
+    pub challenge: ChallengeProgress,
+    completed_count_input_state: text_input::State,
+    completed_progress_level_input_state: text_input::State,
+    progress_counter_input_state: text_input::State,
+    stat_input_states: Vec<text_input::State>,
+}
+
+impl ChallengeEditor {
+    pub fn new(challenge: ChallengeProgress) -> Self {
+        let stat_input_states = challenge
+            .stats
+            .iter()
+            .map(|_| text_input::State::default())
+            .collect();
+
+        Self {
+            challenge,
+            stat_input_states,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self, challenge_index: usize) -> Container<Bl3Message> {
+        let challenge = &self.challenge;
+
+        let completed_checkbox =
+            Checkbox::new(challenge.currently_completed, &challenge.name, move |c| {
+                challenges_interaction(SaveChallengesInteractionMessage::Completed(
+                    challenge_index,
+                    c,
+                ))
+            })
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .width(Length::FillPortion(4))
+            .style(Bl3UiStyle)
+            .into_element();
+
+        let completed_count = challenge_number_input(
+            "Count",
+            &mut self.completed_count_input_state,
+            challenge.completed_count,
+            move |v| SaveChallengesInteractionMessage::CompletedCount(challenge_index, v),
+        );
+
+        let completed_progress_level = challenge_number_input(
+            "Level",
+            &mut self.completed_progress_level_input_state,
+            challenge.completed_progress_level,
+            move |v| SaveChallengesInteractionMessage::CompletedProgressLevel(challenge_index, v),
+        );
+
+        let progress_counter = challenge_number_input(
+            "Progress",
+            &mut self.progress_counter_input_state,
+            challenge.progress_counter,
+            move |v| SaveChallengesInteractionMessage::ProgressCounter(challenge_index, v),
+        );
+
+        let mut contents = Column::new()
+            .push(
+                Row::new()
+                    .push(completed_checkbox)
+                    .push(completed_count)
+                    .push(completed_progress_level)
+                    .push(progress_counter)
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+            )
+            .push(
+                Text::new(format!("{} - {}", challenge.category, challenge.path))
+                    .font(JETBRAINS_MONO)
+                    .size(14)
+                    .color(Color::from_rgb8(160, 160, 160)),
+            )
+            .spacing(10);
+
+        for (stat_index, (stat, stat_input_state)) in challenge
+            .stats
+            .iter()
+            .zip(self.stat_input_states.iter_mut())
+            .enumerate()
+        {
+            contents = contents.push(challenge_number_input(
+                stat.name(),
+                stat_input_state,
+                stat.value,
+                move |v| {
+                    SaveChallengesInteractionMessage::StatValue(challenge_index, stat_index, v)
+                },
+            ));
+        }
+
+        if !challenge.rewards_claimed.is_empty() {
+            let rewards = challenge.rewards_claimed.iter().enumerate().fold(
+                Row::new().spacing(15),
+                |row, (reward_index, claimed)| {
+                    row.push(
+                        Checkbox::new(
+                            *claimed,
+                            format!("Reward {} Claimed", reward_index + 1),
+                            move |c| {
+                                challenges_interaction(
+                                    SaveChallengesInteractionMessage::RewardClaimed(
+                                        challenge_index,
+                                        reward_index,
+                                        c,
+                                    ),
+                                )
+                            },
+                        )
+                        .size(20)
+                        .font(JETBRAINS_MONO)
+                        .text_color(Color::from_rgb8(220, 220, 220))
+                        .text_size(17)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                },
+            );
+
+            contents = contents.push(rewards);
+        }
+
+        Container::new(contents)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveChallengesInteractionMessage {
+    SearchInputChanged(String),
+    CategorySelected(ChallengeCategory),
+    CompleteAllPressed,
+    CategoryCompletion(ChallengeMenuCategory, u8),
+    Completed(usize, bool),
+    CompletedCount(usize, i32),
+    CompletedProgressLevel(usize, i32),
+    ProgressCounter(usize, i32),
+    StatValue(usize, usize, i32),
+    RewardClaimed(usize, usize, bool),
+}
+
+fn challenges_interaction(message: SaveChallengesInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Challenges(message))
+}
+
+fn challenge_number_input<'a, S, F>(
+    label: S,
+    state: &'a mut text_input::State,
+    value: i32,
+    on_change: F,
+) -> Element<'a, Bl3Message>
+where
+    S: std::fmt::Display,
+    F: 'static + Fn(i32) -> SaveChallengesInteractionMessage,
+{
+    LabelledElement::create(
+        label,
+        Length::Units(90),
+        NumberInput::new(state, value, 0, None, move |v| {
+            challenges_interaction(on_change(v))
+        })
+        .0
+        .font(JETBRAINS_MONO)
+        .padding(5)
+        .size(17)
+        .style(Bl3UiStyle)
+        .into_element(),
+    )
+    .spacing(10)
+    .width(Length::FillPortion(1))
+    .align_items(Alignment::Center)
+    .into()
+}
+
+pub fn get_filtered_challenges(
+    search_query: &str,
+    category: ChallengeCategory,
+    challenge: &ChallengeProgress,
+) -> bool {
+    let search_query = search_query.trim();
+
+    challenge.category == category
+        && (search_query.is_empty()
+            || challenge.name.to_lowercase().contains(search_query)
+            || challenge.path.to_lowercase().contains(search_query))
+}
+
+fn category_completion_row<'a>(
+    states: &'a mut [text_input::State],
+    values: &[u8],
+    categories: &[ChallengeMenuCategory],
+) -> Row<'a, Bl3Message> {
+    states.iter_mut().zip(values).zip(categories).fold(
+        Row::new().spacing(10).align_items(Alignment::Center),
+        |row, ((state, value), category)| {
+            let category = *category;
+
+            row.push(
+                LabelledElement::create(
+                    category,
+                    Length::Units(100),
+                    NumberInput::new(state, *value, 0, Some(100), move |v| {
+                        challenges_interaction(
+                            SaveChallengesInteractionMessage::CategoryCompletion(category, v),
+                        )
+                    })
+                    .0
+                    .font(JETBRAINS_MONO)
+                    .padding(10)
+                    .size(17)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(5)
+                .width(Length::FillPortion(1))
+                .align_items(Alignment::Center),
+            )
+        },
+    )
+}
+
+pub fn view(challenges_state: &mut ChallengesState) -> Container<Bl3Message> {
+    let categories = ChallengeMenuCategory::iter().collect::<Vec<_>>();
+
+    let (first_states, second_states) = challenges_state
+        .category_completion_input_states
+        .split_at_mut(CHALLENGE_CATEGORY_COMPLETION_COUNT / 2);
+
+    let (first_values, second_values) = challenges_state
+        .category_completion_inputs
+        .split_at(CHALLENGE_CATEGORY_COMPLETION_COUNT / 2);
+
+    let (first_categories, second_categories) =
+        categories.split_at(CHALLENGE_CATEGORY_COMPLETION_COUNT / 2);
+
+    let category_completion = Container::new(
+        LabelledElement::create(
+            "Menu %",
+            Length::Units(90),
+            Column::new()
+                .push(category_completion_row(
+                    first_states,
+                    first_values,
+                    first_categories,
+                ))
+                .push(category_completion_row(
+                    second_states,
+                    second_values,
+                    second_categories,
+                ))
+                .spacing(10),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .style(Bl3UiStyle);
+
+    let category_selector = Container::new(
+        LabelledElement::create(
+            "Category",
+            Length::Units(90),
+            PickList::new(
+                &mut challenges_state.category_selector,
+                &challenges_state.categories[..],
+                Some(challenges_state.category_selected),
+                |c| challenges_interaction(SaveChallengesInteractionMessage::CategorySelected(c)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(3))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let search_input = TextInputLimited::new(
+        &mut challenges_state.search_input_state,
+        "Search challenges...",
+        &challenges_state.search_input,
+        500,
+        |s| challenges_interaction(SaveChallengesInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .width(Length::FillPortion(5))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let complete_all_button = Button::new(
+        &mut challenges_state.complete_all_button_state,
+        Text::new("Complete All").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(challenges_interaction(
+        SaveChallengesInteractionMessage::CompleteAllPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let filter_row = Row::new()
+        .push(category_selector)
+        .push(search_input)
+        .push(complete_all_button)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let search_query = &challenges_state.search_input;
+    let category_selected = challenges_state.category_selected;
+
+    let has_results = challenges_state
+        .challenges
+        .iter()
+        .any(|c| get_filtered_challenges(search_query, category_selected, &c.challenge));
+
+    let challenge_list = challenges_state
+        .challenges
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, c)| get_filtered_challenges(search_query, category_selected, &c.challenge))
+        .fold(Column::new().spacing(10), |column, (i, c)| {
+            column.push(c.view(i))
+        });
+
+    let challenge_list = if has_results {
+        Container::new(
+            Scrollable::new(&mut challenges_state.challenge_list_scrollable_state)
+                .push(challenge_list)
+                .height(Length::Fill),
+        )
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    let all_contents = Column::new()
+        .push(category_completion)
+        .push(filter_row)
+        .push(challenge_list)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
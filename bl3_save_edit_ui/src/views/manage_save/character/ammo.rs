@@ -8,6 +8,7 @@ use iced::{
 };
 
 use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
+use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
@@ -25,6 +26,7 @@ pub struct AmmoSetterField {
     text_margin: usize,
     pub ammo_pool: AmmoPool,
     pub input: i32,
+    pub sdu_level: i32,
     input_state: text_input::State,
     #[derivative(
         Debug = "ignore",
@@ -47,10 +49,14 @@ impl AmmoSetterField {
         }
     }
 
+    pub fn capacity(&self) -> i32 {
+        self.ammo_pool.capacity(self.sdu_level)
+    }
+
     pub fn view(&mut self) -> Row<Bl3Message> {
         let on_changed = self.on_changed.clone();
         let minimum = 0;
-        let maximum = self.ammo_pool.maximum();
+        let maximum = self.capacity();
 
         Row::new()
             .push(
@@ -83,7 +89,10 @@ impl AmmoSetterField {
                     .size(17)
                     .style(Bl3UiStyle)
                     .into_element(),
-                    format!("Amount must be between {} and {}", minimum, maximum),
+                    format!(
+                        "Amount must be between {} and {} at SDU level {}",
+                        minimum, maximum, self.sdu_level
+                    ),
                     tooltip::Position::Top,
                 )
                 .gap(10)
@@ -92,6 +101,13 @@ impl AmmoSetterField {
                 .size(17)
                 .style(Bl3UiTooltipStyle),
             )
+            .push(
+                Text::new(format!("/ {}", maximum))
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(180, 180, 180))
+                    .width(Length::FillPortion(2)),
+            )
             .width(Length::Fill)
             .align_items(Alignment::Center)
     }
@@ -129,6 +145,44 @@ impl std::default::Default for AmmoSetter {
 }
 
 impl AmmoSetter {
+    fn fields_mut(&mut self) -> [&mut AmmoSetterField; 7] {
+        [
+            &mut self.sniper,
+            &mut self.heavy,
+            &mut self.shotgun,
+            &mut self.grenade,
+            &mut self.smg,
+            &mut self.assault_rifle,
+            &mut self.pistol,
+        ]
+    }
+
+    /// Updates the capacity of the pool linked to `sdu_slot`, clamping the amount to it or
+    /// filling the pool when `refill` is set and the level has changed.
+    pub fn set_sdu_level(&mut self, sdu_slot: &SaveSduSlot, level: i32, refill: bool) {
+        if let Some(field) = self
+            .fields_mut()
+            .into_iter()
+            .find(|f| f.ammo_pool.sdu_slot() == *sdu_slot)
+        {
+            let changed = field.sdu_level != level;
+
+            field.sdu_level = level;
+
+            if changed && refill {
+                field.input = field.capacity();
+            } else {
+                field.input = field.input.min(field.capacity());
+            }
+        }
+    }
+
+    pub fn max_all(&mut self) {
+        for field in self.fields_mut() {
+            field.input = field.capacity();
+        }
+    }
+
     pub fn view(&mut self) -> Container<Bl3Message> {
         Container::new(
             Column::new()
@@ -0,0 +1,160 @@
+use iced::alignment::Horizontal;
+use iced::{text_input, Alignment, Checkbox, Color, Column, Container, Length, Row, Text};
+
+use bl3_save_edit_core::bl3_save::player_color::{color_to_rgb8, PlayerColor};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::character::{
+    CharacterColorMessage, SaveCharacterInteractionMessage,
+};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default)]
+pub struct PlayerColorEditor {
+    pub player_color: PlayerColor,
+    pub applied_rgb: [u8; 3],
+    pub split_rgb: [u8; 3],
+    pub use_default_color: bool,
+    pub use_default_split_color: bool,
+    applied_input_states: [text_input::State; 3],
+    split_input_states: [text_input::State; 3],
+}
+
+impl PlayerColorEditor {
+    pub fn new(player_color: PlayerColor) -> Self {
+        Self {
+            applied_rgb: color_to_rgb8(player_color.applied_color),
+            split_rgb: color_to_rgb8(player_color.split_color),
+            use_default_color: player_color.use_default_color,
+            use_default_split_color: player_color.use_default_split_color,
+            player_color,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self, color_index: usize) -> Row<Bl3Message> {
+        let use_default_color = Checkbox::new(self.use_default_color, "Default", move |c| {
+            color_interaction(CharacterColorMessage::UseDefaultColor(color_index, c))
+        })
+        .size(20)
+        .font(JETBRAINS_MONO)
+        .text_color(Color::from_rgb8(220, 220, 220))
+        .text_size(17)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        let use_default_split_color =
+            Checkbox::new(self.use_default_split_color, "Default Split", move |c| {
+                color_interaction(CharacterColorMessage::UseDefaultSplitColor(color_index, c))
+            })
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .style(Bl3UiStyle)
+            .into_element();
+
+        let applied_inputs = rgb_inputs(
+            &mut self.applied_input_states,
+            self.applied_rgb,
+            move |channel, v| CharacterColorMessage::AppliedColor(color_index, channel, v),
+        );
+
+        let split_inputs = rgb_inputs(
+            &mut self.split_input_states,
+            self.split_rgb,
+            move |channel, v| CharacterColorMessage::SplitColor(color_index, channel, v),
+        );
+
+        Row::new()
+            .push(
+                Text::new(self.player_color.name())
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::Units(100)),
+            )
+            .push(use_default_color)
+            .push(applied_inputs)
+            .push(use_default_split_color)
+            .push(split_inputs)
+            .spacing(20)
+            .align_items(Alignment::Center)
+    }
+}
+
+fn color_interaction(message: CharacterColorMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Character(
+        SaveCharacterInteractionMessage::ColorMessage(message),
+    ))
+}
+
+fn rgb_inputs<F>(
+    input_states: &mut [text_input::State; 3],
+    rgb: [u8; 3],
+    on_changed: F,
+) -> Row<Bl3Message>
+where
+    F: 'static + Fn(usize, u8) -> CharacterColorMessage + Copy,
+{
+    input_states.iter_mut().zip(rgb).enumerate().fold(
+        Row::new().spacing(10),
+        |row, (channel, (state, value))| {
+            row.push(
+                NumberInput::new(state, value, 0, Some(u8::MAX), move |v| {
+                    color_interaction(on_changed(channel, v))
+                })
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .width(Length::Units(65))
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+pub struct ColorEditor {
+    pub colors: Vec<PlayerColorEditor>,
+}
+
+impl ColorEditor {
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let color_rows = self
+            .colors
+            .iter_mut()
+            .enumerate()
+            .fold(Column::new().spacing(15), |column, (i, c)| {
+                column.push(c.view(i))
+            });
+
+        Container::new(
+            Column::new()
+                .push(
+                    Container::new(
+                        Text::new("Colors (RGB)")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .padding(10)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyle),
+                )
+                .push(
+                    Container::new(color_rows)
+                        .width(Length::Fill)
+                        .padding(15)
+                        .style(Bl3UiStyle),
+                ),
+        )
+    }
+}
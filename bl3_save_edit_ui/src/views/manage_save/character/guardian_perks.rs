@@ -0,0 +1,82 @@
+use iced::alignment::Horizontal;
+use iced::{Checkbox, Color, Column, Container, Length, Row, Text};
+
+use bl3_save_edit_core::bl3_save::guardian_perk::GuardianPerkData;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::character::SaveCharacterInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Default)]
+pub struct GuardianPerkEditor {
+    pub perks: Vec<GuardianPerkData>,
+}
+
+impl GuardianPerkEditor {
+    pub fn view(&self) -> Container<Bl3Message> {
+        // Perks are named "<Tree> <Tokens>", give each tree its own column.
+        let mut trees: Vec<(String, Vec<(usize, &GuardianPerkData)>)> = Vec::new();
+
+        for (i, p) in self.perks.iter().enumerate() {
+            let perk_name = p.perk.to_string();
+            let tree_name = perk_name.split(' ').next().unwrap_or_default();
+
+            match trees.iter_mut().find(|(name, _)| name == tree_name) {
+                Some((_, perks)) => perks.push((i, p)),
+                None => trees.push((tree_name.to_owned(), vec![(i, p)])),
+            }
+        }
+
+        let perk_columns = trees
+            .into_iter()
+            .fold(Row::new().spacing(20), |row, (_, perks)| {
+                let column = perks.into_iter().fold(
+                    Column::new().spacing(15).width(Length::FillPortion(1)),
+                    |column, (i, p)| {
+                        column.push(
+                            Checkbox::new(p.enabled, p.perk.to_string(), move |c| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::Character(
+                                        SaveCharacterInteractionMessage::GuardianPerk(i, c),
+                                    ),
+                                )
+                            })
+                            .size(20)
+                            .font(JETBRAINS_MONO)
+                            .text_color(Color::from_rgb8(220, 220, 220))
+                            .text_size(17)
+                            .style(Bl3UiStyle)
+                            .into_element(),
+                        )
+                    },
+                );
+
+                row.push(column)
+            });
+
+        Container::new(
+            Column::new()
+                .push(
+                    Container::new(
+                        Text::new("Guardian Perks")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .padding(10)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyle),
+                )
+                .push(
+                    Container::new(perk_columns)
+                        .width(Length::Fill)
+                        .padding(15)
+                        .style(Bl3UiStyle),
+                ),
+        )
+    }
+}
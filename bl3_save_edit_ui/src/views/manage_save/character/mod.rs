@@ -1,5 +1,6 @@
 use iced::{
-    pick_list, text_input, tooltip, Alignment, Column, Container, Length, PickList, Row, Tooltip,
+    pick_list, scrollable, text_input, tooltip, Alignment, Column, Container, Length, PickList,
+    Row, Scrollable, Tooltip,
 };
 
 use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
@@ -11,7 +12,9 @@ use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::JETBRAINS_MONO;
 use crate::views::manage_save::character::ammo::AmmoSetter;
+use crate::views::manage_save::character::colors::ColorEditor;
 use crate::views::manage_save::character::gear::GearUnlocker;
+use crate::views::manage_save::character::guardian_perks::GuardianPerkEditor;
 use crate::views::manage_save::character::sdu::SduUnlocker;
 use crate::views::manage_save::character::skins::SkinSelectors;
 use crate::views::manage_save::ManageSaveInteractionMessage;
@@ -21,7 +24,9 @@ use crate::widgets::number_input::NumberInput;
 use crate::widgets::text_input_limited::TextInputLimited;
 
 mod ammo;
+pub mod colors;
 mod gear;
+mod guardian_perks;
 mod sdu;
 mod skins;
 
@@ -41,6 +46,9 @@ pub struct CharacterState {
     pub gear_unlocker: GearUnlocker,
     pub ammo_setter: AmmoSetter,
     pub sdu_unlocker: SduUnlocker,
+    pub color_editor: ColorEditor,
+    pub guardian_perk_editor: GuardianPerkEditor,
+    pub scrollable_state: scrollable::State,
 }
 
 #[derive(Debug, Clone)]
@@ -54,7 +62,10 @@ pub enum SaveCharacterInteractionMessage {
     GearMessage(CharacterGearUnlockedMessage),
     SduMessage(CharacterSduMessage),
     AmmoMessage(CharacterAmmoMessage),
+    ColorMessage(CharacterColorMessage),
+    GuardianPerk(usize, bool),
     MaxSduSlotsPressed,
+    RefillAmmoOnSduChange(bool),
     MaxAmmoAmountsPressed,
 }
 
@@ -75,6 +86,15 @@ pub enum CharacterSkinSelectedMessage {
     HeadSkin(GameDataKv),
     CharacterSkin(GameDataKv),
     EchoTheme(GameDataKv),
+    Emote(usize, GameDataKv),
+}
+
+#[derive(Debug, Clone)]
+pub enum CharacterColorMessage {
+    AppliedColor(usize, usize, u8),
+    SplitColor(usize, usize, u8),
+    UseDefaultColor(usize, bool),
+    UseDefaultSplitColor(usize, bool),
 }
 
 #[derive(Debug, Clone)]
@@ -319,12 +339,26 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
         .push(sdu_unlocker)
         .spacing(20);
 
+    let color_editor = character_state.color_editor.view().width(Length::Fill);
+
+    let guardian_perk_editor = character_state
+        .guardian_perk_editor
+        .view()
+        .width(Length::Fill);
+
     let all_contents = Column::new()
         .push(name_class_row)
         .push(experience_and_level_row)
         .push(skin_unlocker)
         .push(slot_sdu_row)
+        .push(color_editor)
+        .push(guardian_perk_editor)
         .spacing(20);
 
-    Container::new(all_contents).padding(30)
+    Container::new(
+        Scrollable::new(&mut character_state.scrollable_state)
+            .push(all_contents)
+            .height(Length::Fill),
+    )
+    .padding(30)
 }
@@ -3,8 +3,8 @@ use std::rc::Rc;
 use derivative::Derivative;
 use iced::alignment::Horizontal;
 use iced::{
-    button, text_input, tooltip, Alignment, Button, Color, Column, Container, Length, Row, Text,
-    Tooltip,
+    button, text_input, tooltip, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    Row, Text, Tooltip,
 };
 
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
@@ -107,6 +107,7 @@ pub struct SduUnlocker {
     pub smg: SduUnlockField,
     pub assault_rifle: SduUnlockField,
     pub pistol: SduUnlockField,
+    pub refill_ammo: bool,
     unlock_all_button_state: button::State,
 }
 
@@ -125,12 +126,26 @@ impl std::default::Default for SduUnlocker {
                 CharacterSduMessage::AssaultRifle,
             ),
             pistol: SduUnlockField::new(4, SaveSduSlot::Pistol, CharacterSduMessage::Pistol),
+            refill_ammo: false,
             unlock_all_button_state: button::State::default(),
         }
     }
 }
 
 impl SduUnlocker {
+    pub fn fields(&self) -> [&SduUnlockField; 8] {
+        [
+            &self.backpack,
+            &self.sniper,
+            &self.heavy,
+            &self.shotgun,
+            &self.grenade,
+            &self.smg,
+            &self.assault_rifle,
+            &self.pistol,
+        ]
+    }
+
     pub fn view(&mut self) -> Container<Bl3Message> {
         Container::new(
             Column::new()
@@ -161,6 +176,25 @@ impl SduUnlocker {
                                     .push(self.assault_rifle.view())
                                     .push(self.pistol.view()),
                             )
+                            .push(
+                                Checkbox::new(
+                                    self.refill_ammo,
+                                    "Refill ammo when SDU levels change",
+                                    |c| {
+                                        InteractionMessage::ManageSaveInteraction(
+                                            ManageSaveInteractionMessage::Character(
+                                                SaveCharacterInteractionMessage::RefillAmmoOnSduChange(c),
+                                            ),
+                                        )
+                                    },
+                                )
+                                .size(20)
+                                .font(JETBRAINS_MONO)
+                                .text_color(Color::from_rgb8(220, 220, 220))
+                                .text_size(17)
+                                .style(Bl3UiStyle)
+                                .into_element(),
+                            )
                             .push(
                                 Container::new(
                                     Button::new(
@@ -4,10 +4,12 @@ use derivative::Derivative;
 use iced::{pick_list, Alignment, Column, Container, Length, PickList, Row};
 use rayon::prelude::ParallelSliceMut;
 
+use bl3_save_edit_core::bl3_save::character_data::EQUIPPED_EMOTE_COUNT;
 use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
 use bl3_save_edit_core::game_data::{
-    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS,
-    PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
+    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES,
+    PROFILE_EMOTES_DEFAULTS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS,
+    PROFILE_SKINS_DEFAULTS,
 };
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
@@ -122,6 +124,7 @@ pub struct SkinSelectors {
     pub head_skin: SkinPickList,
     pub character_skin: SkinPickList,
     pub echo_theme: SkinPickList,
+    pub emotes: Vec<SkinPickList>,
 }
 
 impl std::default::Default for SkinSelectors {
@@ -148,6 +151,17 @@ impl std::default::Default for SkinSelectors {
                 &PROFILE_ECHO_THEMES,
                 CharacterSkinSelectedMessage::EchoTheme,
             ),
+            emotes: (0..EQUIPPED_EMOTE_COUNT)
+                .map(|i| {
+                    SkinPickList::new(
+                        format!("Emote {}", i + 1),
+                        80,
+                        &PROFILE_EMOTES_DEFAULTS,
+                        &PROFILE_EMOTES,
+                        move |e| CharacterSkinSelectedMessage::Emote(i, e),
+                    )
+                })
+                .collect(),
         }
     }
 }
@@ -158,10 +172,18 @@ impl SkinSelectors {
         let character_skin = self.character_skin.view(Some(player_class));
         let echo_theme = self.echo_theme.view(None);
 
+        let emotes = self
+            .emotes
+            .iter_mut()
+            .fold(Row::new().spacing(20), |row, e| {
+                row.push(e.view(Some(player_class)))
+            });
+
         Container::new(
             Column::new()
                 .push(Row::new().push(head_skin).push(character_skin).spacing(20))
                 .push(echo_theme)
+                .push(emotes)
                 .spacing(20),
         )
     }
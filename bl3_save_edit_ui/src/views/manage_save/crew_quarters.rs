@@ -0,0 +1,302 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::crew_quarters::{
+    CrewQuartersDecorationData, CREW_QUARTERS_DEFAULT_DECORATION,
+};
+use bl3_save_edit_core::game_data::GameDataKv;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default)]
+pub struct CrewQuartersState {
+    pub room_data_path: String,
+    pub preferred_room_assignment: i32,
+    pub rooms: Vec<GameDataKv>,
+    pub room_selector: pick_list::State<GameDataKv>,
+    pub decorations: Vec<CrewQuartersDecorationField>,
+    pub decoration_options: Vec<CrewQuartersDecorationOption>,
+    pub unlocked_decorations: Option<Vec<String>>,
+    pub add_decoration_button_state: button::State,
+    pub decoration_list_scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Default)]
+pub struct CrewQuartersDecorationField {
+    pub decoration: CrewQuartersDecorationData,
+    index_input_state: text_input::State,
+    decoration_selector: pick_list::State<CrewQuartersDecorationOption>,
+    remove_button_state: button::State,
+}
+
+impl CrewQuartersDecorationField {
+    pub fn new(decoration: CrewQuartersDecorationData) -> Self {
+        Self {
+            decoration,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct CrewQuartersDecorationOption {
+    pub path: String,
+    pub name: String,
+}
+
+impl CrewQuartersDecorationOption {
+    pub fn new(decoration: &CrewQuartersDecorationData) -> Self {
+        Self {
+            path: decoration.decoration_path.clone(),
+            name: decoration.name(),
+        }
+    }
+}
+
+impl std::fmt::Display for CrewQuartersDecorationOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveCrewQuartersInteractionMessage {
+    RoomSelected(GameDataKv),
+    DecorationIndex(usize, i32),
+    DecorationSelected(usize, CrewQuartersDecorationOption),
+    AddDecorationPressed,
+    RemoveDecorationPressed(usize),
+}
+
+impl SaveCrewQuartersInteractionMessage {
+    pub fn update_state(self, crew_quarters_state: &mut CrewQuartersState) {
+        match self {
+            SaveCrewQuartersInteractionMessage::RoomSelected(room) => {
+                crew_quarters_state.room_data_path = room.ident.to_owned();
+            }
+            SaveCrewQuartersInteractionMessage::DecorationIndex(i, index) => {
+                if let Some(d) = crew_quarters_state.decorations.get_mut(i) {
+                    d.decoration.index = index;
+                }
+            }
+            SaveCrewQuartersInteractionMessage::DecorationSelected(i, decoration) => {
+                if let Some(d) = crew_quarters_state.decorations.get_mut(i) {
+                    d.decoration.decoration_path = decoration.path;
+                }
+            }
+            SaveCrewQuartersInteractionMessage::AddDecorationPressed => {
+                let index = crew_quarters_state
+                    .decorations
+                    .iter()
+                    .map(|d| d.decoration.index)
+                    .max()
+                    .unwrap_or(0)
+                    + 1;
+
+                crew_quarters_state
+                    .decorations
+                    .push(CrewQuartersDecorationField::new(
+                        CrewQuartersDecorationData {
+                            index,
+                            decoration_path: CREW_QUARTERS_DEFAULT_DECORATION.to_owned(),
+                        },
+                    ));
+            }
+            SaveCrewQuartersInteractionMessage::RemoveDecorationPressed(i) => {
+                if i < crew_quarters_state.decorations.len() {
+                    crew_quarters_state.decorations.remove(i);
+                }
+            }
+        }
+    }
+}
+
+fn crew_quarters_interaction(message: SaveCrewQuartersInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::CrewQuarters(message))
+}
+
+pub fn view(crew_quarters_state: &mut CrewQuartersState) -> Container<Bl3Message> {
+    let room_selected = crew_quarters_state
+        .rooms
+        .iter()
+        .find(|r| {
+            r.ident
+                .eq_ignore_ascii_case(&crew_quarters_state.room_data_path)
+        })
+        .copied();
+
+    let room_selector = Container::new(
+        LabelledElement::create(
+            "Room",
+            Length::Units(60),
+            PickList::new(
+                &mut crew_quarters_state.room_selector,
+                &crew_quarters_state.rooms[..],
+                room_selected,
+                |r| crew_quarters_interaction(SaveCrewQuartersInteractionMessage::RoomSelected(r)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::Fill)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let add_decoration_button = Button::new(
+        &mut crew_quarters_state.add_decoration_button_state,
+        Text::new("Add Decoration")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .on_press(crew_quarters_interaction(
+        SaveCrewQuartersInteractionMessage::AddDecorationPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let room_row = Row::new()
+        .push(room_selector)
+        .push(add_decoration_button)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let unlocked_text = if crew_quarters_state.unlocked_decorations.is_some() {
+        "Only decorations unlocked in the loaded profile can be placed"
+    } else {
+        "Load the profile to only offer the decorations it has unlocked"
+    };
+
+    let unlocked_text = Text::new(unlocked_text)
+        .font(JETBRAINS_MONO)
+        .size(14)
+        .color(Color::from_rgb8(160, 160, 160));
+
+    let decoration_options = &crew_quarters_state.decoration_options;
+
+    let decoration_list = if !crew_quarters_state.decorations.is_empty() {
+        let decoration_list = crew_quarters_state.decorations.iter_mut().enumerate().fold(
+            Column::new().spacing(10),
+            |column, (i, d)| {
+                let selected = decoration_options
+                    .iter()
+                    .find(|o| o.path.eq_ignore_ascii_case(&d.decoration.decoration_path))
+                    .cloned()
+                    .unwrap_or_else(|| CrewQuartersDecorationOption::new(&d.decoration));
+
+                let index_input = Container::new(
+                    LabelledElement::create(
+                        "Index",
+                        Length::Units(60),
+                        NumberInput::new(
+                            &mut d.index_input_state,
+                            d.decoration.index,
+                            1,
+                            None,
+                            move |v| {
+                                crew_quarters_interaction(
+                                    SaveCrewQuartersInteractionMessage::DecorationIndex(i, v),
+                                )
+                            },
+                        )
+                        .0
+                        .font(JETBRAINS_MONO)
+                        .padding(10)
+                        .size(17)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+                )
+                .width(Length::Units(160))
+                .height(Length::Units(36))
+                .style(Bl3UiStyle);
+
+                let decoration_selector = PickList::new(
+                    &mut d.decoration_selector,
+                    &decoration_options[..],
+                    Some(selected),
+                    move |o| {
+                        crew_quarters_interaction(
+                            SaveCrewQuartersInteractionMessage::DecorationSelected(i, o),
+                        )
+                    },
+                )
+                .font(JETBRAINS_MONO)
+                .text_size(17)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element();
+
+                let remove_button = Button::new(
+                    &mut d.remove_button_state,
+                    Text::new("Remove").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(crew_quarters_interaction(
+                    SaveCrewQuartersInteractionMessage::RemoveDecorationPressed(i),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element();
+
+                column.push(
+                    Container::new(
+                        Row::new()
+                            .push(index_input)
+                            .push(decoration_selector)
+                            .push(remove_button)
+                            .spacing(15)
+                            .align_items(Alignment::Center),
+                    )
+                    .width(Length::Fill)
+                    .padding(10)
+                    .style(Bl3UiStyle),
+                )
+            },
+        );
+
+        Container::new(
+            Scrollable::new(&mut crew_quarters_state.decoration_list_scrollable_state)
+                .push(decoration_list)
+                .height(Length::Fill),
+        )
+    } else {
+        Container::new(
+            Text::new("No decorations have been placed")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    let all_contents = Column::new()
+        .push(room_row)
+        .push(unlocked_text)
+        .push(decoration_list)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
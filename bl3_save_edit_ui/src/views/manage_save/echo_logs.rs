@@ -0,0 +1,277 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::echo_log_data::{EchoLog, EchoLogGroup};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct EchoLogsState {
+    pub echo_logs: Vec<EchoLog>,
+    pub groups: Vec<EchoLogGroupFilter>,
+    pub group_selector: pick_list::State<EchoLogGroupFilter>,
+    pub group_selected: EchoLogGroupFilter,
+    pub search_input: String,
+    pub search_input_state: text_input::State,
+    pub unlock_all_button_state: button::State,
+    pub lock_all_button_state: button::State,
+    pub mark_all_seen_button_state: button::State,
+    pub echo_log_list_scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum EchoLogGroupFilter {
+    All,
+    Group(EchoLogGroup),
+}
+
+impl std::default::Default for EchoLogGroupFilter {
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+impl std::fmt::Display for EchoLogGroupFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EchoLogGroupFilter::All => write!(f, "All"),
+            EchoLogGroupFilter::Group(group) => write!(f, "{}", group),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveEchoLogsInteractionMessage {
+    SearchInputChanged(String),
+    GroupSelected(EchoLogGroupFilter),
+    UnlockAllPressed,
+    LockAllPressed,
+    MarkAllSeenPressed,
+    Unlocked(usize, bool),
+    Seen(usize, bool),
+}
+
+fn echo_logs_interaction(message: SaveEchoLogsInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::EchoLogs(message))
+}
+
+pub fn get_filtered_echo_logs(
+    search_query: &str,
+    group: EchoLogGroupFilter,
+    echo_log: &EchoLog,
+) -> bool {
+    let search_query = search_query.trim();
+
+    let group_matches = match group {
+        EchoLogGroupFilter::All => true,
+        EchoLogGroupFilter::Group(group) => echo_log.group == group,
+    };
+
+    group_matches
+        && (search_query.is_empty()
+            || echo_log.name.to_lowercase().contains(search_query)
+            || echo_log.path.to_lowercase().contains(search_query))
+}
+
+fn echo_log_row(echo_log_index: usize, echo_log: &EchoLog) -> Container<Bl3Message> {
+    let unlocked_checkbox = Checkbox::new(echo_log.unlocked, &echo_log.name, move |c| {
+        echo_logs_interaction(SaveEchoLogsInteractionMessage::Unlocked(echo_log_index, c))
+    })
+    .size(20)
+    .font(JETBRAINS_MONO)
+    .text_color(Color::from_rgb8(220, 220, 220))
+    .text_size(17)
+    .width(Length::FillPortion(4))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let seen_checkbox = Checkbox::new(echo_log.seen, "Seen", move |c| {
+        echo_logs_interaction(SaveEchoLogsInteractionMessage::Seen(echo_log_index, c))
+    })
+    .size(20)
+    .font(JETBRAINS_MONO)
+    .text_color(Color::from_rgb8(220, 220, 220))
+    .text_size(17)
+    .width(Length::FillPortion(1))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let contents = Column::new()
+        .push(
+            Row::new()
+                .push(unlocked_checkbox)
+                .push(seen_checkbox)
+                .spacing(15)
+                .align_items(Alignment::Center),
+        )
+        .push(
+            Text::new(format!("{} - {}", echo_log.group, echo_log.path))
+                .font(JETBRAINS_MONO)
+                .size(14)
+                .color(Color::from_rgb8(160, 160, 160)),
+        )
+        .spacing(10);
+
+    Container::new(contents)
+        .width(Length::Fill)
+        .padding(10)
+        .style(Bl3UiStyle)
+}
+
+pub fn view(echo_logs_state: &mut EchoLogsState) -> Container<Bl3Message> {
+    let group_selector = Container::new(
+        LabelledElement::create(
+            "Location",
+            Length::Units(90),
+            PickList::new(
+                &mut echo_logs_state.group_selector,
+                &echo_logs_state.groups[..],
+                Some(echo_logs_state.group_selected),
+                |g| echo_logs_interaction(SaveEchoLogsInteractionMessage::GroupSelected(g)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(3))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let search_input = TextInputLimited::new(
+        &mut echo_logs_state.search_input_state,
+        "Search echo logs...",
+        &echo_logs_state.search_input,
+        500,
+        |s| echo_logs_interaction(SaveEchoLogsInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .width(Length::FillPortion(3))
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let filter_row = Row::new()
+        .push(group_selector)
+        .push(search_input)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let unlock_all_button = Button::new(
+        &mut echo_logs_state.unlock_all_button_state,
+        Text::new("Unlock All").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(echo_logs_interaction(
+        SaveEchoLogsInteractionMessage::UnlockAllPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let lock_all_button = Button::new(
+        &mut echo_logs_state.lock_all_button_state,
+        Text::new("Lock All").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(echo_logs_interaction(
+        SaveEchoLogsInteractionMessage::LockAllPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let mark_all_seen_button = Button::new(
+        &mut echo_logs_state.mark_all_seen_button_state,
+        Text::new("Mark All Seen")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .on_press(echo_logs_interaction(
+        SaveEchoLogsInteractionMessage::MarkAllSeenPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let search_query = &echo_logs_state.search_input;
+    let group_selected = echo_logs_state.group_selected;
+
+    let filtered_echo_logs = echo_logs_state
+        .echo_logs
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| get_filtered_echo_logs(search_query, group_selected, e))
+        .collect::<Vec<_>>();
+
+    let unlocked_count = filtered_echo_logs
+        .iter()
+        .filter(|(_, e)| e.unlocked)
+        .count();
+
+    let button_row = Row::new()
+        .push(
+            Text::new(format!(
+                "{} / {} unlocked",
+                unlocked_count,
+                filtered_echo_logs.len()
+            ))
+            .font(JETBRAINS_MONO)
+            .size(17)
+            .color(Color::from_rgb8(220, 220, 220))
+            .width(Length::Fill),
+        )
+        .push(unlock_all_button)
+        .push(lock_all_button)
+        .push(mark_all_seen_button)
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let echo_log_list = if !filtered_echo_logs.is_empty() {
+        let echo_log_list = filtered_echo_logs
+            .into_iter()
+            .fold(Column::new().spacing(10), |column, (i, e)| {
+                column.push(echo_log_row(i, e))
+            });
+
+        Container::new(
+            Scrollable::new(&mut echo_logs_state.echo_log_list_scrollable_state)
+                .push(echo_log_list)
+                .height(Length::Fill),
+        )
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    let all_contents = Column::new()
+        .push(filter_row)
+        .push(button_row)
+        .push(echo_log_list)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
@@ -1,8 +1,12 @@
+use iced::alignment::Horizontal;
 use iced::{
-    button, pick_list, text_input, tooltip, Alignment, Button, Column, Container, Length, PickList,
-    Row, Text, TextInput, Tooltip,
+    button, pick_list, scrollable, text_input, tooltip, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text, TextInput, Tooltip,
 };
 
+use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlotData;
+use bl3_save_edit_core::bl3_save::ui_tracking::UiTrackingData;
+use bl3_save_edit_core::bl3_save::zone_map_fod::ZoneMapLevel;
 use bl3_save_edit_core::parser::HeaderType;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
@@ -25,6 +29,15 @@ pub struct GeneralState {
     pub generate_guid_button_state: button::State,
     pub save_type_selector: pick_list::State<HeaderType>,
     pub save_type_selected: HeaderType,
+    pub zone_map_levels: Vec<ZoneMapLevel>,
+    pub reveal_all_zone_maps_button_state: button::State,
+    pub zone_map_scrollable_state: scrollable::State,
+    pub anonymize: bool,
+    pub anonymize_button_state: button::State,
+    pub ui_tracking: UiTrackingData,
+    pub unlockable_inventory_slots: Vec<InventorySlotData>,
+    pub reset_ui_tracking_button_state: button::State,
+    pub mark_ui_tracking_seen_button_state: button::State,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +46,51 @@ pub enum SaveGeneralInteractionMessage {
     Slot(u32),
     GenerateGuidPressed,
     SaveTypeSelected(HeaderType),
+    ZoneMapRevealed(usize, bool),
+    RevealAllZoneMapsPressed,
+    AnonymizePressed,
+    ResetUiTrackingPressed,
+    MarkUiTrackingSeenPressed,
+}
+
+fn general_interaction(message: SaveGeneralInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::General(message))
+}
+
+fn zone_map_level_row(level_index: usize, level: &ZoneMapLevel) -> Row<Bl3Message> {
+    let discovery_percentage = if level.fully_revealed {
+        100.0
+    } else if level.discovery_percentage.is_finite() {
+        level.discovery_percentage.clamp(0.0, 1.0) * 100.0
+    } else {
+        0.0
+    };
+
+    Row::new()
+        .push(
+            Checkbox::new(level.fully_revealed, &level.level_name, move |c| {
+                general_interaction(SaveGeneralInteractionMessage::ZoneMapRevealed(
+                    level_index,
+                    c,
+                ))
+            })
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .width(Length::FillPortion(4))
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(
+            Text::new(format!("{:.1}% discovered", discovery_percentage))
+                .font(JETBRAINS_MONO)
+                .size(15)
+                .color(Color::from_rgb8(160, 160, 160))
+                .width(Length::FillPortion(1)),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center)
 }
 
 pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
@@ -193,11 +251,173 @@ pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
+    let revealed_count = general_state
+        .zone_map_levels
+        .iter()
+        .filter(|l| l.fully_revealed)
+        .count();
+
+    let zone_map_levels = general_state
+        .zone_map_levels
+        .iter()
+        .enumerate()
+        .fold(Column::new().spacing(10), |column, (i, l)| {
+            column.push(zone_map_level_row(i, l))
+        });
+
+    let zone_map = Column::new()
+        .push(
+            Container::new(
+                Text::new("Zone Map")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .padding(10)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        )
+        .push(
+            Container::new(
+                Column::new()
+                    .push(
+                        Row::new()
+                            .push(
+                                Text::new(format!(
+                                    "{} / {} maps revealed",
+                                    revealed_count,
+                                    general_state.zone_map_levels.len()
+                                ))
+                                .font(JETBRAINS_MONO)
+                                .size(17)
+                                .color(Color::from_rgb8(220, 220, 220))
+                                .width(Length::Fill),
+                            )
+                            .push(
+                                Button::new(
+                                    &mut general_state.reveal_all_zone_maps_button_state,
+                                    Text::new("Reveal All").font(JETBRAINS_MONO_BOLD).size(17),
+                                )
+                                .on_press(general_interaction(
+                                    SaveGeneralInteractionMessage::RevealAllZoneMapsPressed,
+                                ))
+                                .padding(10)
+                                .style(Bl3UiStyle)
+                                .into_element(),
+                            )
+                            .align_items(Alignment::Center),
+                    )
+                    .push(
+                        Scrollable::new(&mut general_state.zone_map_scrollable_state)
+                            .push(zone_map_levels)
+                            .height(Length::Fill),
+                    )
+                    .spacing(15),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle),
+        );
+
+    let anonymize_text = if general_state.anonymize {
+        "Name, GUID and player ids will be anonymized when saving"
+    } else {
+        "Strip the character name, GUID and player ids before sharing this save"
+    };
+
+    let anonymize = Container::new(
+        Row::new()
+            .push(
+                Text::new(anonymize_text)
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::Fill),
+            )
+            .push(
+                Button::new(
+                    &mut general_state.anonymize_button_state,
+                    Text::new("Anonymize").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(general_interaction(
+                    SaveGeneralInteractionMessage::AnonymizePressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
+    let prompts_seen = general_state
+        .ui_tracking
+        .prompts()
+        .iter()
+        .filter(|(_, seen)| *seen)
+        .count();
+
+    let ui_tracking_text = format!(
+        "{} / {} first run prompts and {} inventory slot unlocks seen",
+        prompts_seen,
+        general_state.ui_tracking.prompts().len(),
+        general_state.ui_tracking.inventory_slot_unlocks_seen.len()
+    );
+
+    let ui_tracking = Container::new(
+        Row::new()
+            .push(
+                Text::new(ui_tracking_text)
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::Fill),
+            )
+            .push(
+                Button::new(
+                    &mut general_state.reset_ui_tracking_button_state,
+                    Text::new("Reset").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(general_interaction(
+                    SaveGeneralInteractionMessage::ResetUiTrackingPressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Button::new(
+                    &mut general_state.mark_ui_tracking_seen_button_state,
+                    Text::new("Mark All Seen")
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17),
+                )
+                .on_press(general_interaction(
+                    SaveGeneralInteractionMessage::MarkUiTrackingSeenPressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .padding(15)
+    .style(Bl3UiStyle);
+
     let all_contents = Column::new()
         .push(file)
         .push(save_guid)
         .push(save_slot)
         .push(save_type)
+        .push(anonymize)
+        .push(ui_tracking)
+        .push(zone_map)
         .spacing(20);
 
     Container::new(all_contents).padding(30)
@@ -2,15 +2,24 @@ use iced::{button, svg, Column, Container, Length, Row};
 use strum::Display;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::svgs::{CHARACTER, CURRENCY, GENERAL, INVENTORY, SETTINGS, VEHICLE};
+use crate::resources::svgs::{
+    CHALLENGES, CHARACTER, CREW_QUARTERS, CURRENCY, ECHO_LOGS, GENERAL, INVENTORY, REGIONS,
+    SETTINGS, STATS, VEHICLE,
+};
 use crate::views;
+use crate::views::game_stats::GameStatsState;
+use crate::views::manage_save::challenges::ChallengesState;
 use crate::views::manage_save::character::CharacterState;
+use crate::views::manage_save::crew_quarters::CrewQuartersState;
 use crate::views::manage_save::currency::CurrencyState;
+use crate::views::manage_save::echo_logs::EchoLogsState;
 use crate::views::manage_save::general::GeneralState;
 use crate::views::manage_save::inventory::InventoryState;
+use crate::views::manage_save::regions::RegionsState;
 use crate::views::manage_save::vehicle::VehicleState;
 use crate::views::manage_save::{
-    character, currency, general, inventory, vehicle, ManageSaveInteractionMessage, ManageSaveState,
+    challenges, character, crew_quarters, currency, echo_logs, general, inventory, regions,
+    vehicle, ManageSaveInteractionMessage, ManageSaveState,
 };
 use crate::views::settings::SettingsState;
 use crate::views::{tab_bar_button, ManageTabBarStyle};
@@ -23,6 +32,11 @@ pub struct SaveViewState {
     pub inventory_state: InventoryState,
     pub currency_state: CurrencyState,
     pub vehicle_state: VehicleState,
+    pub challenges_state: ChallengesState,
+    pub echo_logs_state: EchoLogsState,
+    pub regions_state: RegionsState,
+    pub crew_quarters_state: CrewQuartersState,
+    pub stats_state: GameStatsState,
 }
 
 #[derive(Debug, Default)]
@@ -32,6 +46,11 @@ pub struct SaveTabBarState {
     inventory_button_state: button::State,
     currency_button_state: button::State,
     vehicle_button_state: button::State,
+    challenges_button_state: button::State,
+    echo_logs_button_state: button::State,
+    regions_button_state: button::State,
+    crew_quarters_button_state: button::State,
+    stats_button_state: button::State,
     settings_button_state: button::State,
 }
 
@@ -42,6 +61,11 @@ pub enum SaveTabBarInteractionMessage {
     Inventory,
     Currency,
     Vehicle,
+    Challenges,
+    EchoLogs,
+    Regions,
+    CrewQuarters,
+    Stats,
     Settings,
 }
 
@@ -53,6 +77,11 @@ pub enum SaveTabBarView {
     Inventory,
     Currency,
     Vehicle,
+    Challenges,
+    EchoLogs,
+    Regions,
+    CrewQuarters,
+    Stats,
     Settings,
 }
 
@@ -131,6 +160,76 @@ pub fn view<'a>(
         100,
     );
 
+    let challenges_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .challenges_button_state,
+        SaveTabBarView::Challenges,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Challenges,
+        )),
+        svg::Handle::from_memory(CHALLENGES),
+        125,
+    );
+
+    let echo_logs_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .echo_logs_button_state,
+        SaveTabBarView::EchoLogs,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::EchoLogs,
+        )),
+        svg::Handle::from_memory(ECHO_LOGS),
+        125,
+    );
+
+    let regions_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .regions_button_state,
+        SaveTabBarView::Regions,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Regions,
+        )),
+        svg::Handle::from_memory(REGIONS),
+        125,
+    );
+
+    let crew_quarters_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .crew_quarters_button_state,
+        SaveTabBarView::CrewQuarters,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::CrewQuarters,
+        )),
+        svg::Handle::from_memory(CREW_QUARTERS),
+        160,
+    );
+
+    let stats_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .stats_button_state,
+        SaveTabBarView::Stats,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Stats,
+        )),
+        svg::Handle::from_memory(STATS),
+        85,
+    );
+
     let settings_button = tab_bar_button(
         &mut manage_save_state
             .save_view_state
@@ -152,6 +251,11 @@ pub fn view<'a>(
             .push(inventory_button)
             .push(currency_button)
             .push(vehicle_button)
+            .push(challenges_button)
+            .push(echo_logs_button)
+            .push(regions_button)
+            .push(crew_quarters_button)
+            .push(stats_button)
             .push(settings_button),
     )
     .width(Length::Fill)
@@ -173,6 +277,23 @@ pub fn view<'a>(
         SaveTabBarView::Vehicle => {
             vehicle::view(&mut manage_save_state.save_view_state.vehicle_state)
         }
+        SaveTabBarView::Challenges => {
+            challenges::view(&mut manage_save_state.save_view_state.challenges_state)
+        }
+        SaveTabBarView::EchoLogs => {
+            echo_logs::view(&mut manage_save_state.save_view_state.echo_logs_state)
+        }
+        SaveTabBarView::Regions => {
+            regions::view(&mut manage_save_state.save_view_state.regions_state)
+        }
+        SaveTabBarView::CrewQuarters => {
+            crew_quarters::view(&mut manage_save_state.save_view_state.crew_quarters_state)
+        }
+        SaveTabBarView::Stats => {
+            views::game_stats::view(&mut manage_save_state.save_view_state.stats_state, |s| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Stats(s))
+            })
+        }
         SaveTabBarView::Settings => views::settings::view(settings_state),
     };
 
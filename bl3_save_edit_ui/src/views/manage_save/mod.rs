@@ -1,19 +1,28 @@
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::views::game_stats::GameStatsInteractionMessage;
+use crate::views::manage_save::challenges::SaveChallengesInteractionMessage;
 use crate::views::manage_save::character::SaveCharacterInteractionMessage;
+use crate::views::manage_save::crew_quarters::SaveCrewQuartersInteractionMessage;
 use crate::views::manage_save::currency::SaveCurrencyInteractionMessage;
+use crate::views::manage_save::echo_logs::SaveEchoLogsInteractionMessage;
 use crate::views::manage_save::general::SaveGeneralInteractionMessage;
 use crate::views::manage_save::inventory::SaveInventoryInteractionMessage;
 use crate::views::manage_save::main::{
     SaveTabBarInteractionMessage, SaveTabBarView, SaveViewState,
 };
+use crate::views::manage_save::regions::SaveRegionsInteractionMessage;
 use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
 
+pub mod challenges;
 pub mod character;
+pub mod crew_quarters;
 pub mod currency;
+pub mod echo_logs;
 pub mod general;
 pub mod inventory;
 pub mod main;
+pub mod regions;
 pub mod vehicle;
 
 #[derive(Debug, Default)]
@@ -30,6 +39,11 @@ pub enum ManageSaveInteractionMessage {
     Inventory(SaveInventoryInteractionMessage),
     Currency(SaveCurrencyInteractionMessage),
     Vehicle(SaveVehicleInteractionMessage),
+    Challenges(SaveChallengesInteractionMessage),
+    EchoLogs(SaveEchoLogsInteractionMessage),
+    Regions(SaveRegionsInteractionMessage),
+    CrewQuarters(SaveCrewQuartersInteractionMessage),
+    Stats(GameStatsInteractionMessage),
     SaveFilePressed,
 }
 
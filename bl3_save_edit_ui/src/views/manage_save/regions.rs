@@ -0,0 +1,258 @@
+use iced::alignment::Horizontal;
+use iced::{
+    button, pick_list, scrollable, text_input, tooltip, Alignment, Button, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text, Tooltip,
+};
+
+use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::bl3_save::region_data::RegionGameStage;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default)]
+pub struct RegionsState {
+    pub regions: Vec<RegionGameStageField>,
+    pub playthroughs: Vec<RegionPlaythrough>,
+    pub playthrough_selector: pick_list::State<RegionPlaythrough>,
+    pub playthrough_selected: RegionPlaythrough,
+    pub set_all_input: i32,
+    pub set_all_input_state: text_input::State,
+    pub set_all_button_state: button::State,
+    pub level_persistence_actor_count: usize,
+    pub reset_level_persistence: bool,
+    pub reset_level_persistence_button_state: button::State,
+    pub region_list_scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Default)]
+pub struct RegionGameStageField {
+    pub region: RegionGameStage,
+    input_state: text_input::State,
+}
+
+impl RegionGameStageField {
+    pub fn new(region: RegionGameStage) -> Self {
+        Self {
+            region,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct RegionPlaythrough(pub i32);
+
+impl std::fmt::Display for RegionPlaythrough {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            0 => write!(f, "Normal Mode"),
+            1 => write!(f, "True Vault Hunter Mode"),
+            i => write!(f, "Playthrough {}", i + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveRegionsInteractionMessage {
+    PlaythroughSelected(RegionPlaythrough),
+    GameStage(usize, i32),
+    SetAllGameStageInput(i32),
+    SetAllGameStagePressed,
+    ResetLevelPersistencePressed,
+}
+
+fn regions_interaction(message: SaveRegionsInteractionMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Regions(message))
+}
+
+fn game_stage_input<'a>(
+    state: &'a mut text_input::State,
+    value: i32,
+    on_change: impl 'static + Fn(i32) -> InteractionMessage,
+) -> Tooltip<'a, Bl3Message> {
+    let maximum = MAX_CHARACTER_LEVEL as i32;
+
+    Tooltip::new(
+        NumberInput::new(state, value, 1, Some(maximum), on_change)
+            .0
+            .width(Length::Units(120))
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        format!("Game stage must be between 1 and {}", maximum),
+        tooltip::Position::Top,
+    )
+    .gap(10)
+    .padding(10)
+    .font(JETBRAINS_MONO)
+    .size(17)
+    .style(Bl3UiTooltipStyle)
+}
+
+pub fn view(regions_state: &mut RegionsState) -> Container<Bl3Message> {
+    let playthrough_selected = regions_state.playthrough_selected;
+
+    let playthrough_selector = Container::new(
+        LabelledElement::create(
+            "Playthrough",
+            Length::Units(110),
+            PickList::new(
+                &mut regions_state.playthrough_selector,
+                &regions_state.playthroughs[..],
+                Some(playthrough_selected),
+                |p| regions_interaction(SaveRegionsInteractionMessage::PlaythroughSelected(p)),
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(3))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let set_all_row = Row::new()
+        .push(playthrough_selector)
+        .push(game_stage_input(
+            &mut regions_state.set_all_input_state,
+            regions_state.set_all_input,
+            |v| regions_interaction(SaveRegionsInteractionMessage::SetAllGameStageInput(v)),
+        ))
+        .push(
+            Button::new(
+                &mut regions_state.set_all_button_state,
+                Text::new("Set All Game Stages")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17),
+            )
+            .on_press(regions_interaction(
+                SaveRegionsInteractionMessage::SetAllGameStagePressed,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let region_list = regions_state
+        .regions
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, r)| r.region.playthrough_index == playthrough_selected.0)
+        .fold(Column::new().spacing(10), |column, (i, r)| {
+            column.push(
+                Container::new(
+                    Row::new()
+                        .push(
+                            Column::new()
+                                .push(
+                                    Text::new(&r.region.name)
+                                        .font(JETBRAINS_MONO)
+                                        .size(17)
+                                        .color(Color::from_rgb8(220, 220, 220)),
+                                )
+                                .push(
+                                    Text::new(&r.region.region_path)
+                                        .font(JETBRAINS_MONO)
+                                        .size(14)
+                                        .color(Color::from_rgb8(160, 160, 160)),
+                                )
+                                .spacing(10)
+                                .width(Length::Fill),
+                        )
+                        .push(game_stage_input(
+                            &mut r.input_state,
+                            r.region.game_stage,
+                            move |v| {
+                                regions_interaction(SaveRegionsInteractionMessage::GameStage(i, v))
+                            },
+                        ))
+                        .spacing(15)
+                        .align_items(Alignment::Center),
+                )
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle),
+            )
+        });
+
+    let level_persistence_text = if regions_state.reset_level_persistence {
+        "Level persistence will be reset when saving".to_owned()
+    } else {
+        format!(
+            "{} saved actor timers (bosses, red chests)",
+            regions_state.level_persistence_actor_count
+        )
+    };
+
+    let level_persistence = Column::new()
+        .push(
+            Container::new(
+                Text::new("Level Persistence")
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            )
+            .padding(10)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        )
+        .push(
+            Container::new(
+                Row::new()
+                    .push(
+                        Text::new(level_persistence_text)
+                            .font(JETBRAINS_MONO)
+                            .size(17)
+                            .color(Color::from_rgb8(220, 220, 220))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        Button::new(
+                            &mut regions_state.reset_level_persistence_button_state,
+                            Text::new("Reset Level Persistence")
+                                .font(JETBRAINS_MONO_BOLD)
+                                .size(17),
+                        )
+                        .on_press(regions_interaction(
+                            SaveRegionsInteractionMessage::ResetLevelPersistencePressed,
+                        ))
+                        .padding(10)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .align_items(Alignment::Center),
+            )
+            .width(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle),
+        );
+
+    let all_contents = Column::new()
+        .push(set_all_row)
+        .push(
+            Scrollable::new(&mut regions_state.region_list_scrollable_state)
+                .push(region_list)
+                .height(Length::Fill),
+        )
+        .push(level_persistence)
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
@@ -1,18 +1,37 @@
-use iced::{Column, Container, Length};
+use iced::{scrollable, Column, Container, Length, Row, Scrollable};
+
+use bl3_save_edit_core::vehicle_data::VehicleLoadoutSlot;
 
 use crate::bl3_ui::Bl3Message;
+use crate::views::manage_save::vehicle::vehicle_asset_unlocker::{
+    VehicleAssetCategory, VehicleAssetUnlocker,
+};
+use crate::views::manage_save::vehicle::vehicle_loadout_editor::{
+    VehicleLoadoutAsset, VehicleLoadoutEditor, VehicleLoadoutOption,
+};
 use crate::views::manage_save::vehicle::vehicle_unlocker::VehicleUnlocker;
 
+pub mod vehicle_asset_unlocker;
+pub mod vehicle_loadout_editor;
 pub mod vehicle_unlocker;
 
 #[derive(Debug, Default)]
 pub struct VehicleState {
     pub unlocker: VehicleUnlocker,
+    pub asset_unlocker: VehicleAssetUnlocker,
+    pub loadout_editor: VehicleLoadoutEditor,
+    pub scrollable_state: scrollable::State,
 }
 
 #[derive(Debug, Clone)]
 pub enum SaveVehicleInteractionMessage {
     UnlockMessage(VehicleUnlockedMessage),
+    AssetCategorySelected(VehicleAssetCategory),
+    AssetUnlocked(usize, bool),
+    LoadoutSelected(VehicleLoadoutOption),
+    LoadoutSlot(VehicleLoadoutSlot, VehicleLoadoutAsset),
+    LoadoutColorIndex(usize, i32),
+    LastLoadoutIndex(i32),
 }
 
 #[derive(Debug, Clone)]
@@ -32,9 +51,29 @@ pub enum VehicleUnlockedMessage {
 }
 
 pub fn view(vehicle_state: &mut VehicleState) -> Container<Bl3Message> {
-    let vehicle_unlocker = vehicle_state.unlocker.view().width(Length::Fill);
+    let vehicle_unlocker = vehicle_state.unlocker.view().width(Length::FillPortion(1));
+
+    let vehicle_asset_unlocker = vehicle_state
+        .asset_unlocker
+        .view()
+        .width(Length::FillPortion(1));
+
+    let vehicle_loadout_editor = vehicle_state.loadout_editor.view().width(Length::Fill);
 
-    let all_contents = Column::new().push(vehicle_unlocker).spacing(20);
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(vehicle_unlocker)
+                .push(vehicle_asset_unlocker)
+                .spacing(20),
+        )
+        .push(vehicle_loadout_editor)
+        .spacing(20);
 
-    Container::new(all_contents).padding(30)
+    Container::new(
+        Scrollable::new(&mut vehicle_state.scrollable_state)
+            .push(all_contents)
+            .height(Length::Fill),
+    )
+    .padding(30)
 }
@@ -0,0 +1,145 @@
+use iced::alignment::Horizontal;
+use iced::{
+    pick_list, scrollable, Checkbox, Color, Column, Container, Length, PickList, Scrollable, Text,
+};
+
+use bl3_save_edit_core::vehicle_data::{VehicleAsset, VehicleSubType, VehicleType};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VehicleAssetCategory(pub VehicleType);
+
+impl std::default::Default for VehicleAssetCategory {
+    fn default() -> Self {
+        Self(VehicleType::default())
+    }
+}
+
+impl std::fmt::Display for VehicleAssetCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.0, self.0.subtype_name())
+    }
+}
+
+impl VehicleAssetCategory {
+    pub fn all() -> Vec<Self> {
+        let vehicle_types: [fn(VehicleSubType) -> VehicleType; 4] = [
+            VehicleType::Outrunner,
+            VehicleType::Jetbeast,
+            VehicleType::Technical,
+            VehicleType::Cyclone,
+        ];
+
+        vehicle_types
+            .into_iter()
+            .flat_map(|vehicle_type| {
+                [
+                    VehicleSubType::Chassis,
+                    VehicleSubType::Parts,
+                    VehicleSubType::Skins,
+                ]
+                .into_iter()
+                .map(move |sub_type| Self(vehicle_type(sub_type)))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct VehicleAssetEntry {
+    pub category: VehicleAssetCategory,
+    pub asset: VehicleAsset,
+}
+
+#[derive(Debug, Default)]
+pub struct VehicleAssetUnlocker {
+    pub categories: Vec<VehicleAssetCategory>,
+    pub category_selector: pick_list::State<VehicleAssetCategory>,
+    pub category_selected: VehicleAssetCategory,
+    pub assets: Vec<VehicleAssetEntry>,
+    pub asset_list_scrollable_state: scrollable::State,
+}
+
+impl VehicleAssetUnlocker {
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let category_selector = PickList::new(
+            &mut self.category_selector,
+            &self.categories[..],
+            Some(self.category_selected.clone()),
+            |c| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Vehicle(
+                    SaveVehicleInteractionMessage::AssetCategorySelected(c),
+                ))
+            },
+        )
+        .font(JETBRAINS_MONO)
+        .text_size(17)
+        .width(Length::Fill)
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        let category_selected = &self.category_selected;
+
+        let asset_list = self
+            .assets
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.category == *category_selected)
+            .fold(Column::new().spacing(15), |column, (i, a)| {
+                column.push(
+                    Checkbox::new(a.asset.unlocked, &a.asset.name, move |c| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Vehicle(
+                                SaveVehicleInteractionMessage::AssetUnlocked(i, c),
+                            ),
+                        )
+                    })
+                    .size(20)
+                    .font(JETBRAINS_MONO)
+                    .text_color(Color::from_rgb8(220, 220, 220))
+                    .text_size(15)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+            });
+
+        Container::new(
+            Column::new()
+                .push(
+                    Container::new(
+                        Text::new("Vehicle Asset Unlocker")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .padding(10)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyle),
+                )
+                .push(
+                    Container::new(
+                        Column::new()
+                            .push(category_selector)
+                            .push(
+                                Scrollable::new(&mut self.asset_list_scrollable_state)
+                                    .push(asset_list)
+                                    .height(Length::Fill),
+                            )
+                            .spacing(15),
+                    )
+                    .width(Length::Fill)
+                    .padding(15)
+                    .height(Length::Units(440))
+                    .style(Bl3UiStyle),
+                ),
+        )
+    }
+}
@@ -0,0 +1,381 @@
+use iced::alignment::Horizontal;
+use iced::{
+    pick_list, text_input, Alignment, Color, Column, Container, Element, Length, PickList, Row,
+    Text,
+};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_save::character_data::CharacterData;
+use bl3_save_edit_core::vehicle_data::{
+    loadout_asset_name, loadout_asset_vehicle_name, VehicleLoadout, VehicleLoadoutSlot,
+    VehicleSubType, VehicleType,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct VehicleLoadoutAsset(pub String);
+
+impl std::fmt::Display for VehicleLoadoutAsset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "None");
+        }
+
+        let name = loadout_asset_name(&self.0);
+
+        // CAR_Vehicle_<Vehicle> and CAR_<Slot>_<Vehicle>_<Name>
+        match name.splitn(4, '_').nth(3) {
+            Some(part_name) => write!(f, "{}", part_name),
+            None => match loadout_asset_vehicle_name(&self.0)
+                .and_then(|v| VehicleType::from_loadout_vehicle_name(v, VehicleSubType::Chassis))
+            {
+                Some(vehicle_type) => write!(f, "{}", vehicle_type),
+                None => write!(f, "{}", name),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct VehicleLoadoutOption(pub usize);
+
+impl std::fmt::Display for VehicleLoadoutOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Loadout {}", self.0 + 1)
+    }
+}
+
+#[derive(Debug)]
+pub struct VehicleLoadoutSlotSelector {
+    pub slot: VehicleLoadoutSlot,
+    pub options: Vec<VehicleLoadoutAsset>,
+    selector: pick_list::State<VehicleLoadoutAsset>,
+}
+
+impl VehicleLoadoutSlotSelector {
+    pub fn new(slot: VehicleLoadoutSlot) -> Self {
+        Self {
+            slot,
+            options: Vec::new(),
+            selector: pick_list::State::default(),
+        }
+    }
+
+    pub fn view(&mut self, selected: &str) -> Element<Bl3Message> {
+        let slot = self.slot;
+
+        Container::new(
+            LabelledElement::create(
+                slot,
+                Length::Units(140),
+                PickList::new(
+                    &mut self.selector,
+                    &self.options[..],
+                    Some(VehicleLoadoutAsset(selected.to_owned())),
+                    move |a| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Vehicle(
+                                SaveVehicleInteractionMessage::LoadoutSlot(slot, a),
+                            ),
+                        )
+                    },
+                )
+                .font(JETBRAINS_MONO)
+                .text_size(16)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle)
+        .into()
+    }
+}
+
+#[derive(Debug)]
+pub struct VehicleLoadoutEditor {
+    pub loadouts: Vec<VehicleLoadout>,
+    pub loadout_options: Vec<VehicleLoadoutOption>,
+    pub loadout_selector: pick_list::State<VehicleLoadoutOption>,
+    pub loadout_selected: VehicleLoadoutOption,
+    pub slot_selectors: Vec<VehicleLoadoutSlotSelector>,
+    pub color_index_1_input_state: text_input::State,
+    pub color_index_2_input_state: text_input::State,
+    pub color_index_3_input_state: text_input::State,
+    pub last_loadout_index_input: i32,
+    pub last_loadout_index_input_state: text_input::State,
+}
+
+impl std::default::Default for VehicleLoadoutEditor {
+    fn default() -> Self {
+        Self {
+            loadouts: Vec::new(),
+            loadout_options: Vec::new(),
+            loadout_selector: pick_list::State::default(),
+            loadout_selected: VehicleLoadoutOption::default(),
+            slot_selectors: VehicleLoadoutSlot::iter()
+                .map(VehicleLoadoutSlotSelector::new)
+                .collect(),
+            color_index_1_input_state: text_input::State::default(),
+            color_index_2_input_state: text_input::State::default(),
+            color_index_3_input_state: text_input::State::default(),
+            last_loadout_index_input: -1,
+            last_loadout_index_input_state: text_input::State::default(),
+        }
+    }
+}
+
+impl VehicleLoadoutEditor {
+    pub fn selected_loadout_mut(&mut self) -> Option<&mut VehicleLoadout> {
+        self.loadouts.get_mut(self.loadout_selected.0)
+    }
+
+    // Keep whatever the loadout currently uses selectable, even if it isn't in our catalogue.
+    pub fn refresh_slot_options(&mut self, character_data: &CharacterData) {
+        let loadout = match self.loadouts.get(self.loadout_selected.0) {
+            Some(loadout) => loadout,
+            None => return,
+        };
+
+        for slot_selector in self.slot_selectors.iter_mut() {
+            let slot = slot_selector.slot;
+
+            let mut options = character_data
+                .vehicle_loadout_options(slot, loadout.vehicle_name())
+                .into_iter()
+                .map(VehicleLoadoutAsset)
+                .collect::<Vec<_>>();
+
+            let current = VehicleLoadoutAsset(loadout.slot(slot).to_owned());
+
+            if !current.0.is_empty() && !options.contains(&current) {
+                options.push(current);
+            }
+
+            if slot.is_optional() || loadout.slot(slot).is_empty() {
+                options.insert(0, VehicleLoadoutAsset::default());
+            }
+
+            slot_selector.options = options;
+        }
+    }
+
+    // Switching vehicles swaps any parts belonging to the previous vehicle for the first available one.
+    pub fn set_body(&mut self, body: String, character_data: &CharacterData) {
+        let loadout = match self.loadouts.get_mut(self.loadout_selected.0) {
+            Some(loadout) => loadout,
+            None => return,
+        };
+
+        loadout.body = body;
+
+        let vehicle_name = loadout.vehicle_name().map(|v| v.to_owned());
+
+        for slot in VehicleLoadoutSlot::iter().filter(|s| *s != VehicleLoadoutSlot::Body) {
+            let current = loadout.slot(slot);
+
+            if current.is_empty() || loadout_asset_vehicle_name(current) == vehicle_name.as_deref()
+            {
+                continue;
+            }
+
+            let replacement = character_data
+                .vehicle_loadout_options(slot, vehicle_name.as_deref())
+                .into_iter()
+                .next()
+                .unwrap_or_default();
+
+            loadout.set_slot(slot, replacement);
+        }
+
+        self.refresh_slot_options(character_data);
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let loadout_selector = Container::new(
+            LabelledElement::create(
+                "Loadout",
+                Length::Units(140),
+                PickList::new(
+                    &mut self.loadout_selector,
+                    &self.loadout_options[..],
+                    Some(self.loadout_selected),
+                    |l| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Vehicle(
+                                SaveVehicleInteractionMessage::LoadoutSelected(l),
+                            ),
+                        )
+                    },
+                )
+                .font(JETBRAINS_MONO)
+                .text_size(16)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let last_loadout_index = Container::new(
+            LabelledElement::create(
+                "Last Used",
+                Length::Units(140),
+                NumberInput::new(
+                    &mut self.last_loadout_index_input_state,
+                    self.last_loadout_index_input,
+                    -1,
+                    Some(self.loadouts.len() as i32 - 1),
+                    |v| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Vehicle(
+                                SaveVehicleInteractionMessage::LastLoadoutIndex(v),
+                            ),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(16)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let mut contents = Column::new().push(
+            Row::new()
+                .push(loadout_selector)
+                .push(last_loadout_index)
+                .spacing(20),
+        );
+
+        if let Some(loadout) = self.loadouts.get(self.loadout_selected.0) {
+            let slot_selectors = self
+                .slot_selectors
+                .iter_mut()
+                .map(|s| {
+                    let selected = loadout.slot(s.slot);
+                    s.view(selected)
+                })
+                .collect::<Vec<_>>();
+
+            let mut left_column = Column::new().spacing(15).width(Length::FillPortion(1));
+            let mut right_column = Column::new().spacing(15).width(Length::FillPortion(1));
+
+            for (i, slot_selector) in slot_selectors.into_iter().enumerate() {
+                if i % 2 == 0 {
+                    left_column = left_column.push(slot_selector);
+                } else {
+                    right_column = right_column.push(slot_selector);
+                }
+            }
+
+            let color_inputs = [
+                (
+                    &mut self.color_index_1_input_state,
+                    loadout.color_index_1,
+                    "Color 1",
+                    1,
+                ),
+                (
+                    &mut self.color_index_2_input_state,
+                    loadout.color_index_2,
+                    "Color 2",
+                    2,
+                ),
+                (
+                    &mut self.color_index_3_input_state,
+                    loadout.color_index_3,
+                    "Color 3",
+                    3,
+                ),
+            ]
+            .into_iter()
+            .fold(Row::new().spacing(20), |row, (state, value, label, n)| {
+                row.push(
+                    Container::new(
+                        LabelledElement::create(
+                            label,
+                            Length::Units(90),
+                            NumberInput::new(state, value, -1, None, move |v| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::Vehicle(
+                                        SaveVehicleInteractionMessage::LoadoutColorIndex(n, v),
+                                    ),
+                                )
+                            })
+                            .0
+                            .font(JETBRAINS_MONO)
+                            .padding(10)
+                            .size(16)
+                            .style(Bl3UiStyle)
+                            .into_element(),
+                        )
+                        .spacing(15)
+                        .align_items(Alignment::Center),
+                    )
+                    .width(Length::FillPortion(1))
+                    .height(Length::Units(36))
+                    .style(Bl3UiStyle),
+                )
+            });
+
+            contents = contents
+                .push(Row::new().push(left_column).push(right_column).spacing(20))
+                .push(color_inputs);
+        } else {
+            contents = contents.push(
+                Text::new("This character has no vehicle loadouts.")
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            );
+        }
+
+        Container::new(
+            Column::new()
+                .push(
+                    Container::new(
+                        Text::new("Vehicle Loadouts")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .padding(10)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyle),
+                )
+                .push(
+                    Container::new(contents.spacing(15))
+                        .width(Length::Fill)
+                        .padding(15)
+                        .style(Bl3UiStyle),
+                ),
+        )
+    }
+}
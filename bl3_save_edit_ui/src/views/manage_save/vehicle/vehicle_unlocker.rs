@@ -150,6 +150,23 @@ impl std::default::Default for VehicleUnlocker {
 }
 
 impl VehicleUnlocker {
+    pub fn all_checkboxes(&self) -> [&VehicleUnlockCheckbox; 12] {
+        [
+            &self.outrunner_chassis,
+            &self.outrunner_parts,
+            &self.outrunner_skins,
+            &self.jetbeast_chassis,
+            &self.jetbeast_parts,
+            &self.jetbeast_skins,
+            &self.technical_chassis,
+            &self.technical_parts,
+            &self.technical_skins,
+            &self.cyclone_chassis,
+            &self.cyclone_parts,
+            &self.cyclone_skins,
+        ]
+    }
+
     pub fn view(&mut self) -> Container<Bl3Message> {
         Container::new(
             Column::new()
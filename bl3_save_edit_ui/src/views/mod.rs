@@ -6,7 +6,9 @@ use iced::{button, container, svg, Alignment, Button, Color, Element, Length, Ro
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::resources::fonts::JETBRAINS_MONO_BOLD;
 
+pub mod backups;
 pub mod choose_save_directory;
+pub mod game_stats;
 pub mod initialization;
 pub mod item_editor;
 pub mod loading;
@@ -7,6 +7,7 @@ use iced::{
 use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::Bl3UiStyle;
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::backups::{self, BackupsInteractionMessage, BackupsState};
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
 
@@ -28,6 +29,7 @@ pub struct SettingsState {
     pub decrease_ui_scale_button_state: button::State,
     pub increase_ui_scale_button_state: button::State,
     pub ui_scale_factor: f64,
+    pub backups_state: BackupsState,
 }
 
 #[derive(Debug, Clone)]
@@ -44,6 +46,7 @@ pub enum SettingsInteractionMessage {
     ChangeSavesDirCompleted(MessageResult<PathBuf>),
     DecreaseUIScale,
     IncreaseUIScale,
+    Backups(BackupsInteractionMessage),
 }
 
 pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
@@ -248,6 +251,7 @@ pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
         .push(backup_dir)
         .push(saves_dir)
         .push(ui_scale)
+        .push(backups::view(&mut settings_state.backups_state))
         .spacing(20);
 
     Container::new(all_contents).padding(30)